@@ -35,6 +35,12 @@ pub struct Env {
     #[serde(flatten)]
     pub llm: hypr_llm_proxy::Env,
     #[serde(flatten)]
+    pub llm_usage: hypr_llm_proxy::UsageEnv,
+    #[serde(flatten)]
+    pub llm_cache: hypr_llm_proxy::CacheEnv,
+    #[serde(flatten)]
+    pub llm_routing: hypr_llm_proxy::RoutingEnv,
+    #[serde(flatten)]
     pub stt: hypr_transcribe_proxy::Env,
 }
 
@@ -36,8 +36,36 @@ async fn app() -> Router {
         Arc::new(builder.build())
     };
 
-    let llm_config =
-        hypr_llm_proxy::LlmProxyConfig::new(&env.llm).with_analytics(analytics.clone());
+    let llm_usage = {
+        let ledger = hypr_llm_proxy::SupabaseLedger::new(
+            &env.supabase.supabase_url,
+            &env.supabase.supabase_service_role_key,
+        );
+        let usage =
+            hypr_llm_proxy::UsageConfig::new(Arc::new(ledger)).with_quotas((&env.llm_usage).into());
+        match &env.llm_usage.llm_usage_admin_token {
+            Some(token) => usage.with_admin_token(token),
+            None => usage,
+        }
+    };
+    let llm_config = hypr_llm_proxy::LlmProxyConfig::new(&env.llm)
+        .with_analytics(analytics.clone())
+        .with_usage(llm_usage.clone());
+    let llm_config = match env.llm_cache.cache_config() {
+        Some(cache) => llm_config.with_cache(cache),
+        None => llm_config,
+    };
+    let llm_config = env
+        .llm_routing
+        .upstreams()
+        .into_iter()
+        .fold(llm_config, |config, upstream| {
+            config.with_additional_provider(upstream.provider, upstream.api_key)
+        });
+    let llm_config = match env.llm_routing.resolver() {
+        Some(resolver) => llm_config.with_model_resolver(Arc::new(resolver)),
+        None => llm_config,
+    };
     let stt_config = hypr_transcribe_proxy::SttProxyConfig::new(&env.stt, &env.supabase)
         .with_hyprnote_routing(hypr_transcribe_proxy::HyprnoteRoutingConfig::default())
         .with_analytics(analytics.clone());
@@ -117,6 +145,8 @@ async fn app() -> Router {
 
     let calendar_config = hypr_api_calendar::CalendarConfig {
         google: true,
+        caldav: true,
+        ics: true,
         ..Default::default()
     };
 
@@ -171,6 +201,7 @@ async fn app() -> Router {
         .route("/openapi.json", axum::routing::get(openapi_json))
         .merge(support_routes)
         .merge(webhook_routes)
+        .nest("/admin/llm", hypr_llm_proxy::usage_router(llm_usage))
         .merge(pro_routes)
         .merge(integration_routes)
         .merge(auth_routes)
@@ -144,6 +144,15 @@ impl App {
                     self.partials = delta.partials;
                 }
             }
+            SessionDataEvent::SpeakerIdentified {
+                channel,
+                speaker_index,
+                human_id,
+                ..
+            } => {
+                self.transcript
+                    .set_speaker_identity(channel, speaker_index, Some(human_id));
+            }
         }
     }
 }
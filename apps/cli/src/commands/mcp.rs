@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+use hypr_vault::mcp::VaultMcpServer;
+
+pub async fn run(vault: Option<PathBuf>) {
+    let vault = super::vault::open(vault);
+
+    // stdout carries the protocol, so errors go to stderr only.
+    if let Err(e) = hypr_mcp::serve_stdio(VaultMcpServer::new(vault)).await {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
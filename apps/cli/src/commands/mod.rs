@@ -1,2 +1,7 @@
 pub mod auth;
+pub mod mcp;
+pub mod search;
+pub mod sessions;
+pub mod transcribe;
 pub mod tui;
+mod vault;
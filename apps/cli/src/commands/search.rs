@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use hypr_vault::{SearchMatch, SearchQuery, Vault};
+
+use super::vault::{FilterArgs, format_created_at};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Words to look for in titles, notes and transcripts
+    pub query: String,
+
+    #[arg(long, env = "CHAR_VAULT")]
+    pub vault: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub filter: FilterArgs,
+
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub fn run(args: Args) {
+    let vault = super::vault::open(args.vault.clone());
+
+    let matches = search(&vault, &args).unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    });
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&matches).unwrap());
+        return;
+    }
+
+    for m in matches {
+        println!(
+            "{}  {}  {}",
+            format_created_at(m.created_at.as_deref()),
+            m.session_id,
+            m.title
+        );
+        if let Some(snippet) = m.snippet {
+            println!("    {}", snippet.replace('\n', " "));
+        }
+    }
+}
+
+fn search(vault: &Vault, args: &Args) -> Result<Vec<SearchMatch>, String> {
+    let filter = args.filter.to_filter();
+    let mut matches = vault
+        .scan(&SearchQuery {
+            query: args.query.clone(),
+            since: filter.since,
+            until: filter.until,
+            limit: usize::MAX,
+        })
+        .map_err(|e| e.to_string())?;
+
+    // Search only knows about dates; tags and participants come from the
+    // session listing.
+    if !filter.tags.is_empty() || filter.participant.is_some() {
+        let allowed: HashSet<String> = vault
+            .find_sessions(&filter)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|session| session.id)
+            .collect();
+        matches.retain(|m| allowed.contains(&m.session_id));
+    }
+
+    matches.truncate(args.limit);
+    Ok(matches)
+}
@@ -0,0 +1,258 @@
+use std::path::{Path, PathBuf};
+
+use hypr_note_export::{Document, Metadata, TranscriptSegment};
+use hypr_vault::{Session, SessionFilter, Vault};
+
+use super::vault::{FilterArgs, format_created_at};
+
+#[derive(clap::Subcommand)]
+pub enum Command {
+    /// List sessions, newest first
+    List {
+        #[command(flatten)]
+        filter: FilterArgs,
+
+        /// Show at most this many sessions
+        #[arg(long)]
+        limit: Option<usize>,
+
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print a session's notes and transcript as Markdown
+    Show {
+        id: String,
+
+        #[arg(long)]
+        json: bool,
+
+        /// Leave out the transcript
+        #[arg(long)]
+        no_transcript: bool,
+    },
+    /// Write sessions to files, one per session
+    Export {
+        /// Sessions to export; without any, every session matching the filters
+        ids: Vec<String>,
+
+        #[command(flatten)]
+        filter: FilterArgs,
+
+        #[arg(long, value_enum, default_value_t = ExportFormat::Md)]
+        format: ExportFormat,
+
+        #[arg(long, short = 'o', default_value = ".")]
+        output_dir: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Md,
+    Json,
+    /// Standalone page with images inlined
+    Html,
+    /// Word document
+    Docx,
+}
+
+impl ExportFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Md => "md",
+            Self::Json => "json",
+            Self::Html => "html",
+            Self::Docx => "docx",
+        }
+    }
+
+    /// `session_dir` is where relative image paths in the notes point to.
+    fn render(&self, session: &Session, session_dir: Option<&Path>) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Md => Ok(session.to_markdown().into_bytes()),
+            Self::Json => Ok((serde_json::to_string_pretty(session).unwrap() + "\n").into_bytes()),
+            Self::Html => Ok(to_document(session, session_dir)?.to_html().into_bytes()),
+            Self::Docx => to_document(session, session_dir)?
+                .to_docx()
+                .map_err(|e| e.to_string()),
+        }
+    }
+}
+
+fn to_document(session: &Session, session_dir: Option<&Path>) -> Result<Document, String> {
+    let participants = session
+        .participants
+        .iter()
+        .map(|human| {
+            if human.name.is_empty() {
+                human.id.clone()
+            } else {
+                human.name.clone()
+            }
+        })
+        .collect();
+    let event_title = session
+        .event
+        .as_ref()
+        .and_then(|event| event.get("title"))
+        .and_then(|title| title.as_str())
+        .map(str::to_string);
+    let duration = session
+        .transcript
+        .last()
+        .map(|utterance| format_duration(utterance.end_ms));
+
+    let mut document = Document::new(&session.title).with_metadata(Metadata {
+        created_at: session.created_at.clone(),
+        event_title,
+        participants,
+        duration,
+    });
+    if let Some(dir) = session_dir {
+        document = document.with_asset_dir(dir);
+    }
+
+    if !session.memo.is_empty() {
+        let memo = hypr_tiptap::md_to_tiptap_json(&session.memo)?;
+        document = document.with_note(Some("Notes"), &memo);
+    }
+    for note in &session.notes {
+        let content = hypr_tiptap::md_to_tiptap_json(&note.content)?;
+        let title = note.title.as_deref().unwrap_or("Generated notes");
+        document = document.with_note(Some(title), &content);
+    }
+
+    let transcript = session
+        .transcript
+        .iter()
+        .map(|utterance| TranscriptSegment {
+            speaker: Some(utterance.speaker.clone()),
+            text: utterance.text.clone(),
+            start_ms: u64::try_from(utterance.start_ms).ok(),
+        })
+        .collect();
+    Ok(document.with_transcript(transcript))
+}
+
+/// `1h 5m`, `12m`, or `40s` for very short recordings.
+fn format_duration(ms: i64) -> String {
+    let seconds = ms.max(0) / 1000;
+    let (hours, minutes) = (seconds / 3600, (seconds / 60) % 60);
+    match (hours, minutes) {
+        (0, 0) => format!("{seconds}s"),
+        (0, minutes) => format!("{minutes}m"),
+        (hours, minutes) => format!("{hours}h {minutes}m"),
+    }
+}
+
+pub fn run(vault: Option<PathBuf>, command: Command) {
+    let vault = super::vault::open(vault);
+
+    let result = match command {
+        Command::List {
+            filter,
+            limit,
+            json,
+        } => list(&vault, &filter.to_filter(), limit, json),
+        Command::Show {
+            id,
+            json,
+            no_transcript,
+        } => show(&vault, &id, json, no_transcript),
+        Command::Export {
+            ids,
+            filter,
+            format,
+            output_dir,
+        } => export(&vault, ids, &filter.to_filter(), format, &output_dir),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn list(
+    vault: &Vault,
+    filter: &SessionFilter,
+    limit: Option<usize>,
+    json: bool,
+) -> Result<(), String> {
+    let mut sessions = vault.find_sessions(filter).map_err(|e| e.to_string())?;
+    if let Some(limit) = limit {
+        sessions.truncate(limit);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&sessions).unwrap());
+        return Ok(());
+    }
+
+    for session in sessions {
+        let mut line = format!(
+            "{}  {}  {}",
+            format_created_at(session.created_at.as_deref()),
+            session.id,
+            session.title
+        );
+        for tag in &session.tags {
+            line.push_str(&format!("  #{tag}"));
+        }
+        if !session.participants.is_empty() {
+            line.push_str(&format!("  ({})", session.participants.join(", ")));
+        }
+        println!("{line}");
+    }
+    Ok(())
+}
+
+fn show(vault: &Vault, id: &str, json: bool, no_transcript: bool) -> Result<(), String> {
+    let mut session = vault.session(id).map_err(|e| e.to_string())?;
+    if no_transcript {
+        session.transcript.clear();
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&session).unwrap());
+    } else {
+        print!("{}", session.to_markdown());
+    }
+    Ok(())
+}
+
+fn export(
+    vault: &Vault,
+    ids: Vec<String>,
+    filter: &SessionFilter,
+    format: ExportFormat,
+    output_dir: &Path,
+) -> Result<(), String> {
+    let ids = if ids.is_empty() {
+        vault
+            .find_sessions(filter)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|session| session.id)
+            .collect()
+    } else {
+        ids
+    };
+    if ids.is_empty() {
+        return Err("no sessions match".to_string());
+    }
+
+    std::fs::create_dir_all(output_dir).map_err(|e| format!("{}: {e}", output_dir.display()))?;
+
+    for id in ids {
+        let session = vault.session(&id).map_err(|e| format!("{id}: {e}"))?;
+        let session_dir = vault.session_dir(&id).ok();
+        let content = format
+            .render(&session, session_dir.as_deref())
+            .map_err(|e| format!("{id}: {e}"))?;
+        let path = output_dir.join(format!("{id}.{}", format.extension()));
+        std::fs::write(&path, content).map_err(|e| format!("{}: {e}", path.display()))?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
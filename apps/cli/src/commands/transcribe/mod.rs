@@ -0,0 +1,527 @@
+mod render;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures_util::StreamExt;
+use hypr_listener2_core::{BatchEvent, BatchParams, BatchProvider, BatchRuntime};
+use hypr_transcript::{CorrectorChain, FinalizedWord, VocabularyCorrector, WordState};
+use owhisper_interface::Word2;
+use owhisper_interface::stream::StreamResponse;
+use tokio::sync::mpsc;
+
+pub use render::OutputFormat;
+
+const AUDIO_EXTENSIONS: &[&str] = &[
+    "aac", "flac", "m4a", "mp3", "mp4", "ogg", "opus", "wav", "webm",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Provider {
+    Deepgram,
+    Soniox,
+    Assemblyai,
+    /// Stream the file to `--base-url`; the adapter is picked from the URL
+    /// (OpenAI, Gladia, ElevenLabs, Mistral, a local Argmax server, ...)
+    Realtime,
+    /// Local whisper.cpp with on-device diarization; `--model` is the
+    /// path to a ggml model file
+    Whisper,
+}
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// Audio file, or a directory of audio files
+    pub path: PathBuf,
+
+    #[arg(long, value_enum, default_value_t = Provider::Deepgram)]
+    pub provider: Provider,
+
+    #[arg(long, env = "CHAR_BASE_URL", default_value = "")]
+    pub base_url: String,
+
+    #[arg(long, env = "CHAR_API_KEY", default_value = "")]
+    pub api_key: String,
+
+    #[arg(long, env = "CHAR_MODEL")]
+    pub model: Option<String>,
+
+    /// Spoken language; repeat (or comma-separate) for multilingual audio
+    #[arg(long = "language", env = "CHAR_LANGUAGE", value_delimiter = ',')]
+    pub languages: Vec<String>,
+
+    #[arg(long, value_enum, default_value_t = OutputFormat::Txt)]
+    pub format: OutputFormat,
+
+    /// Label speakers in the transcript
+    #[arg(long)]
+    pub diarize: bool,
+
+    /// Number of files transcribed at once
+    #[arg(long, short = 'j', default_value_t = 2)]
+    pub jobs: usize,
+
+    /// Descend into subdirectories
+    #[arg(long, short = 'r')]
+    pub recursive: bool,
+
+    /// Write transcripts here instead of next to each audio file
+    #[arg(long, short = 'o')]
+    pub output_dir: Option<PathBuf>,
+
+    /// Transcribe files that already have a transcript
+    #[arg(long)]
+    pub force: bool,
+}
+
+struct Job {
+    input: PathBuf,
+    output: PathBuf,
+}
+
+pub async fn run(args: Args) {
+    let languages = args
+        .languages
+        .iter()
+        .map(|code| code.parse::<hypr_language::Language>())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|e| fail(&e.to_string()));
+
+    if args.provider == Provider::Whisper && args.model.is_none() {
+        fail("--model must point to a ggml model file when using --provider whisper");
+    }
+    if args.provider == Provider::Realtime && args.base_url.is_empty() {
+        fail("--base-url (or CHAR_BASE_URL) is required when using --provider realtime");
+    }
+
+    let inputs = collect_inputs(&args.path, args.recursive)
+        .unwrap_or_else(|e| fail(&format!("{}: {e}", args.path.display())));
+    if inputs.is_empty() {
+        fail(&format!("no audio files found in {}", args.path.display()));
+    }
+
+    let outputs = output_paths(&inputs, &args.path, args.output_dir.as_deref(), args.format)
+        .unwrap_or_else(|e| fail(&e));
+
+    if args.output_dir.is_some() {
+        for dir in outputs.iter().filter_map(|output| output.parent()) {
+            if let Err(e) = std::fs::create_dir_all(dir) {
+                fail(&format!("{}: {e}", dir.display()));
+            }
+        }
+    }
+
+    let (jobs, skipped): (Vec<Job>, Vec<Job>) = inputs
+        .into_iter()
+        .zip(outputs)
+        .map(|(input, output)| Job { input, output })
+        .partition(|job| args.force || !job.output.exists());
+
+    for job in &skipped {
+        eprintln!(
+            "skipped {} ({} exists)",
+            job.input.display(),
+            job.output.display()
+        );
+    }
+
+    let total = jobs.len();
+    let args = Arc::new(args);
+    let languages = Arc::new(languages);
+    let vocabulary = Arc::new(super::vault::transcript_vocabulary());
+
+    let results: Vec<bool> = futures_util::stream::iter(jobs.into_iter().enumerate())
+        .map(|(i, job)| {
+            let args = args.clone();
+            let languages = languages.clone();
+            let vocabulary = vocabulary.clone();
+            async move {
+                let label = format!("[{}/{total}] {}", i + 1, job.input.display());
+                transcribe_job(&args, &languages, &vocabulary, &job, &label).await
+            }
+        })
+        .buffer_unordered(args.jobs.max(1))
+        .collect()
+        .await;
+
+    let failed = results.iter().filter(|ok| !**ok).count();
+    eprintln!(
+        "{} transcribed, {} skipped, {failed} failed",
+        total - failed,
+        skipped.len()
+    );
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+async fn transcribe_job(
+    args: &Args,
+    languages: &[hypr_language::Language],
+    vocabulary: &[String],
+    job: &Job,
+    label: &str,
+) -> bool {
+    eprintln!("{label}: transcribing");
+    let started = Instant::now();
+
+    let batch_provider = match args.provider {
+        Provider::Deepgram => Some(BatchProvider::Deepgram),
+        Provider::Soniox => Some(BatchProvider::Soniox),
+        Provider::Assemblyai => Some(BatchProvider::AssemblyAI),
+        Provider::Realtime => Some(BatchProvider::Am),
+        Provider::Whisper => None,
+    };
+    let words = match batch_provider {
+        Some(provider) => transcribe_batch(args, provider, languages, &job.input, label).await,
+        None => transcribe_local(args, &job.input).await,
+    };
+
+    let written = words.and_then(|words| {
+        let words = render::to_vtt_words(correct_words(words, vocabulary), args.diarize);
+        let title = job
+            .input
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let content = render::render(&words, args.format, &title);
+        std::fs::write(&job.output, content).map_err(|e| e.to_string())
+    });
+
+    match written {
+        Ok(()) => {
+            eprintln!(
+                "{label}: wrote {} in {:.1}s",
+                job.output.display(),
+                started.elapsed().as_secs_f64()
+            );
+            true
+        }
+        Err(e) => {
+            eprintln!("{label}: failed: {e}");
+            false
+        }
+    }
+}
+
+async fn transcribe_local(args: &Args, input: &Path) -> Result<Vec<Word2>, String> {
+    let model = args.model.clone().unwrap_or_default();
+    let input = input.to_path_buf();
+    let diarize = args.diarize;
+
+    tokio::task::spawn_blocking(move || {
+        hypr_transcribe_whisper_local::process_recorded(model, input, diarize)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Applies the user's vocabulary, as the desktop does to live transcripts.
+fn correct_words(words: Vec<Word2>, vocabulary: &[String]) -> Vec<Word2> {
+    let corrector = vocabulary
+        .iter()
+        .fold(VocabularyCorrector::new(), |corrector, entry| {
+            corrector.with_entry(entry)
+        });
+    if corrector.is_empty() {
+        return words;
+    }
+
+    let finalized: Vec<FinalizedWord> = words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| FinalizedWord {
+            id: i.to_string(),
+            text: format!(" {}", word.text.trim()),
+            start_ms: word.start_ms.unwrap_or_default() as i64,
+            end_ms: word.end_ms.unwrap_or_default() as i64,
+            channel: 0,
+            state: WordState::Final,
+        })
+        .collect();
+    let Some(corrected) = CorrectorChain::new().with(corrector).apply(&finalized) else {
+        return words;
+    };
+
+    // Correctors only merge runs of words, so each corrected word spans
+    // from its own id up to the next one's.
+    let firsts: Vec<usize> = corrected
+        .iter()
+        .map(|word| word.id.parse().unwrap_or_default())
+        .collect();
+    corrected
+        .into_iter()
+        .enumerate()
+        .map(|(i, word)| {
+            let last = firsts.get(i + 1).copied().unwrap_or(words.len()) - 1;
+            Word2 {
+                text: word.text.trim_start().to_string(),
+                end_ms: words[last].end_ms,
+                ..words[firsts[i]].clone()
+            }
+        })
+        .collect()
+}
+
+/// Forwards batch events of a single file to the job awaiting them.
+struct JobRuntime {
+    tx: mpsc::UnboundedSender<BatchEvent>,
+}
+
+impl BatchRuntime for JobRuntime {
+    fn emit(&self, event: BatchEvent) {
+        let _ = self.tx.send(event);
+    }
+}
+
+async fn transcribe_batch(
+    args: &Args,
+    provider: BatchProvider,
+    languages: &[hypr_language::Language],
+    input: &Path,
+    label: &str,
+) -> Result<Vec<Word2>, String> {
+    let params = BatchParams {
+        session_id: uuid::Uuid::new_v4().to_string(),
+        provider,
+        file_path: input.to_string_lossy().into_owned(),
+        model: args.model.clone(),
+        base_url: args.base_url.clone(),
+        api_key: args.api_key.clone(),
+        languages: languages.to_vec(),
+        keywords: vec![],
+        diarize: args.diarize,
+    };
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    hypr_listener2_core::run_batch(Arc::new(JobRuntime { tx }), params)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Streamed batches keep running after `run_batch` returns. The channel
+    // closes once the batch actor (the last owner of the runtime) stops.
+    let mut words = Vec::new();
+    let mut reported = 0;
+    while let Some(event) = rx.recv().await {
+        match event {
+            BatchEvent::BatchStarted { .. } => {}
+            BatchEvent::BatchResponse { response, .. } => {
+                for channel in response.results.channels {
+                    if let Some(alternative) = channel.alternatives.into_iter().next() {
+                        words.extend(alternative.words.into_iter().map(Word2::from));
+                    }
+                }
+            }
+            BatchEvent::BatchResponseStreamed {
+                response,
+                percentage,
+                ..
+            } => {
+                if let StreamResponse::TranscriptResponse {
+                    is_final: true,
+                    channel,
+                    ..
+                } = response
+                    && let Some(alternative) = channel.alternatives.into_iter().next()
+                {
+                    words.extend(alternative.words.into_iter().map(Word2::from));
+                }
+
+                let percent = (percentage * 100.0) as u32 / 10 * 10;
+                if percent > reported {
+                    reported = percent;
+                    eprintln!("{label}: {percent}%");
+                }
+            }
+            BatchEvent::BatchFailed { error, .. } => return Err(error),
+        }
+    }
+
+    Ok(words)
+}
+
+fn collect_inputs(path: &Path, recursive: bool) -> std::io::Result<Vec<PathBuf>> {
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut inputs = Vec::new();
+    for entry in std::fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if recursive {
+                inputs.extend(collect_inputs(&path, true)?);
+            }
+        } else if is_audio(&path) {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+fn is_audio(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| AUDIO_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Where each input's transcript goes. Under `output_dir`, inputs keep
+/// their path relative to `root`. Inputs that only differ by extension
+/// ("a.wav", "a.mp3") keep it in the output name ("a.wav.txt").
+fn output_paths(
+    inputs: &[PathBuf],
+    root: &Path,
+    output_dir: Option<&Path>,
+    format: OutputFormat,
+) -> Result<Vec<PathBuf>, String> {
+    let by_stem: Vec<PathBuf> = inputs
+        .iter()
+        .map(|input| output_path(input, root, output_dir, format, false))
+        .collect();
+
+    let mut counts: HashMap<&Path, usize> = HashMap::new();
+    for output in &by_stem {
+        *counts.entry(output).or_default() += 1;
+    }
+
+    let outputs: Vec<PathBuf> = inputs
+        .iter()
+        .zip(&by_stem)
+        .map(|(input, output)| {
+            if counts[output.as_path()] > 1 {
+                output_path(input, root, output_dir, format, true)
+            } else {
+                output.clone()
+            }
+        })
+        .collect();
+
+    let mut seen: HashMap<&Path, &Path> = HashMap::new();
+    for (input, output) in inputs.iter().zip(&outputs) {
+        if let Some(other) = seen.insert(output, input) {
+            return Err(format!(
+                "{} and {} would both be written to {}",
+                other.display(),
+                input.display(),
+                output.display()
+            ));
+        }
+    }
+
+    Ok(outputs)
+}
+
+fn output_path(
+    input: &Path,
+    root: &Path,
+    output_dir: Option<&Path>,
+    format: OutputFormat,
+    keep_extension: bool,
+) -> PathBuf {
+    let parent = input.parent().unwrap_or(Path::new("."));
+    let dir = match output_dir {
+        Some(output_dir) => output_dir.join(parent.strip_prefix(root).unwrap_or(Path::new(""))),
+        None => parent.to_path_buf(),
+    };
+
+    let name = if keep_extension {
+        input.file_name()
+    } else {
+        input.file_stem()
+    };
+    let mut name = name.unwrap_or(input.as_os_str()).to_os_string();
+    name.push(".");
+    name.push(format.extension());
+    dir.join(name)
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("error: {message}");
+    std::process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_path() {
+        let root = Path::new("/calls");
+        let input = Path::new("/calls/2024/interview.final.m4a");
+
+        assert_eq!(
+            output_path(input, root, None, OutputFormat::Srt, false),
+            Path::new("/calls/2024/interview.final.srt")
+        );
+        assert_eq!(
+            output_path(
+                input,
+                root,
+                Some(Path::new("/out")),
+                OutputFormat::Md,
+                false
+            ),
+            Path::new("/out/2024/interview.final.md")
+        );
+        assert_eq!(
+            output_path(
+                input,
+                input,
+                Some(Path::new("/out")),
+                OutputFormat::Md,
+                true
+            ),
+            Path::new("/out/interview.final.m4a.md")
+        );
+    }
+
+    #[test]
+    fn test_output_paths() {
+        let root = Path::new("/calls");
+        let inputs = [
+            PathBuf::from("/calls/a/standup.m4a"),
+            PathBuf::from("/calls/b/standup.m4a"),
+            PathBuf::from("/calls/b/standup.wav"),
+            PathBuf::from("/calls/b/retro.wav"),
+        ];
+
+        assert_eq!(
+            output_paths(&inputs, root, Some(Path::new("/out")), OutputFormat::Txt).unwrap(),
+            [
+                Path::new("/out/a/standup.txt"),
+                Path::new("/out/b/standup.m4a.txt"),
+                Path::new("/out/b/standup.wav.txt"),
+                Path::new("/out/b/retro.txt"),
+            ]
+        );
+        assert_eq!(
+            output_paths(&inputs, root, None, OutputFormat::Txt).unwrap(),
+            [
+                Path::new("/calls/a/standup.txt"),
+                Path::new("/calls/b/standup.m4a.txt"),
+                Path::new("/calls/b/standup.wav.txt"),
+                Path::new("/calls/b/retro.txt"),
+            ]
+        );
+
+        let inputs = [
+            PathBuf::from("/calls/standup.wav"),
+            PathBuf::from("/calls/standup.mp3"),
+            PathBuf::from("/calls/standup.wav.mp3"),
+        ];
+        let err = output_paths(&inputs, root, None, OutputFormat::Txt).unwrap_err();
+        assert!(err.contains("/calls/standup.wav.txt"), "{err}");
+    }
+
+    #[test]
+    fn test_is_audio() {
+        assert!(is_audio(Path::new("a.WAV")));
+        assert!(is_audio(Path::new("dir/b.m4a")));
+        assert!(!is_audio(Path::new("a.txt")));
+        assert!(!is_audio(Path::new("noext")));
+    }
+}
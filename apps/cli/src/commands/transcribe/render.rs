@@ -0,0 +1,174 @@
+use std::fmt::Write as _;
+
+use hypr_listener2_core::{CueOptions, TranscriptFormat, VttWord, render_transcript};
+use owhisper_interface::{SpeakerIdentity, Word2};
+
+/// Pause that starts a new paragraph in Markdown output.
+const PARAGRAPH_PAUSE_MS: u64 = 3000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Txt,
+    Srt,
+    Vtt,
+    Md,
+}
+
+impl OutputFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Txt => "txt",
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Md => "md",
+        }
+    }
+}
+
+/// Words in time order, speakers labelled "Speaker 1", "Speaker 2", ...
+/// Speakers are dropped unless `diarize` is set.
+pub fn to_vtt_words(words: Vec<Word2>, diarize: bool) -> Vec<VttWord> {
+    let mut words: Vec<VttWord> = words
+        .into_iter()
+        .map(|word| VttWord {
+            text: word.text,
+            start_ms: word.start_ms.unwrap_or_default(),
+            end_ms: word.end_ms.or(word.start_ms).unwrap_or_default(),
+            speaker: word.speaker.filter(|_| diarize).map(speaker_label),
+        })
+        .collect();
+    words.sort_by_key(|w| w.start_ms);
+    words
+}
+
+fn speaker_label(speaker: SpeakerIdentity) -> String {
+    match speaker {
+        SpeakerIdentity::Unassigned { index } => format!("Speaker {}", index as u32 + 1),
+        SpeakerIdentity::Assigned { label, .. } => label,
+    }
+}
+
+pub fn render(words: &[VttWord], format: OutputFormat, title: &str) -> String {
+    let options = CueOptions::default();
+
+    match format {
+        OutputFormat::Json => serde_json::to_string_pretty(words).unwrap() + "\n",
+        OutputFormat::Txt => render_transcript(words, TranscriptFormat::Txt, &options),
+        OutputFormat::Srt => render_transcript(words, TranscriptFormat::Srt, &options),
+        OutputFormat::Vtt => render_transcript(words, TranscriptFormat::Vtt, &options),
+        OutputFormat::Md => render_markdown(words, title),
+    }
+}
+
+/// One paragraph per speaker turn (or long pause), each headed by its
+/// speaker and start time.
+fn render_markdown(words: &[VttWord], title: &str) -> String {
+    let mut out = format!("# {title}\n");
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut previous: Option<&VttWord> = None;
+
+    for word in words {
+        let text = word.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let starts_paragraph = match previous {
+            None => true,
+            Some(prev) => {
+                prev.speaker != word.speaker
+                    || word.start_ms.saturating_sub(prev.end_ms) > PARAGRAPH_PAUSE_MS
+            }
+        };
+
+        if starts_paragraph {
+            if !paragraph.is_empty() {
+                let _ = writeln!(out, "{}", paragraph.join(" "));
+                paragraph.clear();
+            }
+            out.push('\n');
+            let timestamp = format_timestamp(word.start_ms);
+            match &word.speaker {
+                Some(speaker) => {
+                    let _ = writeln!(out, "**{speaker}** `{timestamp}`");
+                }
+                None => {
+                    let _ = writeln!(out, "`{timestamp}`");
+                }
+            }
+        }
+
+        paragraph.push(text);
+        previous = Some(word);
+    }
+
+    if !paragraph.is_empty() {
+        let _ = writeln!(out, "{}", paragraph.join(" "));
+    }
+    out
+}
+
+fn format_timestamp(ms: u64) -> String {
+    let seconds = ms / 1000;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start_ms: u64, end_ms: u64, speaker: Option<u8>) -> Word2 {
+        Word2 {
+            text: text.to_string(),
+            speaker: speaker.map(|index| SpeakerIdentity::Unassigned { index }),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        }
+    }
+
+    fn sample() -> Vec<Word2> {
+        vec![
+            word(" there.", 450, 900, Some(0)),
+            word(" Hello", 0, 400, Some(0)),
+            word(" Hi.", 1000, 1300, Some(1)),
+            word(" Later", 65_000, 65_400, Some(1)),
+        ]
+    }
+
+    #[test]
+    fn test_to_vtt_words() {
+        let words = to_vtt_words(sample(), true);
+        assert_eq!(words[0].text, " Hello");
+        assert_eq!(words[0].speaker.as_deref(), Some("Speaker 1"));
+        assert_eq!(words[2].speaker.as_deref(), Some("Speaker 2"));
+
+        let words = to_vtt_words(sample(), false);
+        assert!(words.iter().all(|w| w.speaker.is_none()));
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let words = to_vtt_words(sample(), true);
+        assert_eq!(
+            render(&words, OutputFormat::Md, "interview"),
+            "# interview\n\n\
+             **Speaker 1** `00:00:00`\nHello there.\n\n\
+             **Speaker 2** `00:00:01`\nHi.\n\n\
+             **Speaker 2** `00:01:05`\nLater\n"
+        );
+
+        let words = to_vtt_words(sample(), false);
+        assert_eq!(
+            render(&words, OutputFormat::Md, "interview"),
+            "# interview\n\n`00:00:00`\nHello there. Hi.\n\n`00:01:05`\nLater\n"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use hypr_listener_core::actors::{RootActor, RootArgs, RootMsg, SessionParams};
+use hypr_pyannote_local::identity::{VOICEPRINTS_FILENAME, Voiceprint, VoiceprintStore};
 use ractor::Actor;
 
 use crate::{
@@ -25,6 +26,20 @@ fn setup_panic_hook() {
     }));
 }
 
+/// Voices enrolled in the desktop app, so the TUI recognizes the same people.
+fn load_voiceprints() -> Vec<Voiceprint> {
+    let Some(base) = super::vault::default_app_data_dir() else {
+        return vec![];
+    };
+    match VoiceprintStore::load(base.join(VOICEPRINTS_FILENAME)) {
+        Ok(store) => store.voiceprints().to_vec(),
+        Err(e) => {
+            eprintln!("warning: could not load voiceprints: {e}");
+            vec![]
+        }
+    }
+}
+
 pub async fn run(args: Args) {
     let languages = vec![
         args.language
@@ -57,6 +72,8 @@ pub async fn run(args: Args) {
         base_url: args.base_url,
         api_key: args.api_key,
         keywords: vec![],
+        voiceprints: load_voiceprints(),
+        vocabulary: super::vault::transcript_vocabulary(),
     };
 
     let started = ractor::call!(root_ref, RootMsg::StartSession, params)
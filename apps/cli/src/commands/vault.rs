@@ -0,0 +1,180 @@
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, Utc};
+use hypr_vault::{SessionFilter, Vault};
+
+/// Desktop builds, most common first. Their data directories are tried in
+/// this order unless `CHAR_BUNDLE_ID` names one.
+const BUNDLE_IDS: &[&str] = &[
+    "com.hyprnote.stable",
+    "com.hyprnote.nightly",
+    "com.hyprnote.Hyprnote",
+    "com.hyprnote.staging",
+    "com.hyprnote.dev",
+];
+
+#[derive(clap::Args)]
+pub struct FilterArgs {
+    /// Only sessions on or after this date (YYYY-MM-DD or RFC 3339)
+    #[arg(long, value_parser = parse_since)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only sessions on or before this date (YYYY-MM-DD or RFC 3339)
+    #[arg(long, value_parser = parse_until)]
+    pub until: Option<DateTime<Utc>>,
+
+    /// Only sessions with this tag; repeat to require several
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+
+    /// Only sessions with a participant whose name or email contains this
+    #[arg(long)]
+    pub participant: Option<String>,
+}
+
+impl FilterArgs {
+    pub fn to_filter(&self) -> SessionFilter {
+        SessionFilter {
+            since: self.since,
+            until: self.until,
+            tags: self.tags.clone(),
+            participant: self.participant.clone(),
+        }
+    }
+}
+
+pub fn open(vault: Option<PathBuf>) -> Vault {
+    let Some(root) = vault.or_else(default_vault_base) else {
+        eprintln!("error: could not locate the vault, pass --vault (or CHAR_VAULT)");
+        std::process::exit(1);
+    };
+    Vault::new(root)
+}
+
+/// Same resolution as the desktop app: a custom vault location if one was
+/// chosen, otherwise the app's data directory.
+fn default_vault_base() -> Option<PathBuf> {
+    let base = default_app_data_dir()?;
+    Some(hypr_storage::vault::resolve_custom(&base, &base).unwrap_or(base))
+}
+
+/// The transcript vocabulary from the desktop app's settings, one
+/// `aliases => Term` entry each.
+pub fn transcript_vocabulary() -> Vec<String> {
+    let Some(path) =
+        default_vault_base().map(|base| hypr_storage::vault::compute_settings_path(&base))
+    else {
+        return vec![];
+    };
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return vec![];
+    };
+    let settings: serde_json::Value = match serde_json::from_str(&content) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("warning: could not read {}: {e}", path.display());
+            return vec![];
+        }
+    };
+
+    settings
+        .pointer("/transcription/vocabulary")
+        .and_then(|value| value.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.as_str().map(str::to_string))
+        .collect()
+}
+
+/// The desktop app's data directory, where app-wide files such as
+/// voiceprints live.
+pub fn default_app_data_dir() -> Option<PathBuf> {
+    let bundle_ids = match std::env::var("CHAR_BUNDLE_ID") {
+        Ok(bundle_id) if !bundle_id.is_empty() => vec![bundle_id],
+        _ => BUNDLE_IDS.iter().map(|id| id.to_string()).collect(),
+    };
+    app_data_dir(&bundle_ids, |base| base.is_dir())
+}
+
+/// The first installed app's data directory, or the first candidate's when
+/// none is.
+fn app_data_dir(bundle_ids: &[String], exists: impl Fn(&Path) -> bool) -> Option<PathBuf> {
+    let bases: Vec<PathBuf> = bundle_ids
+        .iter()
+        .filter_map(|id| hypr_storage::global::compute_default_base(id))
+        .collect();
+    bases
+        .iter()
+        .find(|base| exists(base))
+        .or(bases.first())
+        .cloned()
+}
+
+/// `created_at` as a local `YYYY-MM-DD HH:MM`, for listings.
+pub fn format_created_at(created_at: Option<&str>) -> String {
+    created_at
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| "-".repeat(16))
+}
+
+fn parse_since(s: &str) -> Result<DateTime<Utc>, String> {
+    parse_date(s, NaiveTime::MIN)
+}
+
+/// A bare date includes the whole day.
+fn parse_until(s: &str) -> Result<DateTime<Utc>, String> {
+    parse_date(s, NaiveTime::from_hms_milli_opt(23, 59, 59, 999).unwrap())
+}
+
+fn parse_date(s: &str, time_of_day: NaiveTime) -> Result<DateTime<Utc>, String> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| format!("expected YYYY-MM-DD or an RFC 3339 timestamp, got {s:?}"))?;
+    date.and_time(time_of_day)
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| format!("{s} does not exist in the local timezone"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(
+            parse_since("2024-03-05T10:00:00+02:00").unwrap(),
+            DateTime::parse_from_rfc3339("2024-03-05T08:00:00Z").unwrap()
+        );
+
+        let since = parse_since("2024-03-05").unwrap();
+        let until = parse_until("2024-03-05").unwrap();
+        assert_eq!((until - since).num_milliseconds(), 86_399_999);
+
+        assert!(parse_since("last tuesday").is_err());
+    }
+
+    #[test]
+    fn test_app_data_dir() {
+        let bundle_ids: Vec<String> = BUNDLE_IDS.iter().map(|id| id.to_string()).collect();
+        let staging = hypr_storage::global::compute_default_base("com.hyprnote.staging").unwrap();
+
+        assert_eq!(
+            app_data_dir(&bundle_ids, |base| base == staging),
+            Some(staging)
+        );
+        assert_eq!(
+            app_data_dir(&bundle_ids, |_| false),
+            hypr_storage::global::compute_default_base(BUNDLE_IDS[0])
+        );
+    }
+}
@@ -31,6 +31,23 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     Auth,
+    /// Serve the local meeting vault to MCP clients over stdio
+    Mcp {
+        #[arg(long, env = "CHAR_VAULT")]
+        vault: Option<std::path::PathBuf>,
+    },
+    /// Transcribe an audio file, or every audio file in a directory
+    Transcribe(commands::transcribe::Args),
+    /// Browse and export sessions in the local vault
+    Sessions {
+        #[arg(long, env = "CHAR_VAULT")]
+        vault: Option<std::path::PathBuf>,
+
+        #[command(subcommand)]
+        command: commands::sessions::Command,
+    },
+    /// Search notes and transcripts in the local vault
+    Search(commands::search::Args),
 }
 
 #[tokio::main]
@@ -39,6 +56,10 @@ async fn main() {
 
     match cli.command {
         Some(Commands::Auth) => commands::auth::run(),
+        Some(Commands::Mcp { vault }) => commands::mcp::run(vault).await,
+        Some(Commands::Transcribe(args)) => commands::transcribe::run(args).await,
+        Some(Commands::Sessions { vault, command }) => commands::sessions::run(vault, command),
+        Some(Commands::Search(args)) => commands::search::run(args),
         None => {
             let base_url = cli.base_url.unwrap_or_else(|| {
                 eprintln!("error: --base-url (or CHAR_BASE_URL) is required");
@@ -87,6 +87,7 @@ pub async fn main() {
         .plugin(tauri_plugin_db2::init())
         .plugin(tauri_plugin_tracing::init())
         .plugin(tauri_plugin_hooks::init())
+        .plugin(tauri_plugin_webhook::init())
         .plugin(tauri_plugin_icon::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_permissions::init())
@@ -115,6 +116,7 @@ pub async fn main() {
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_store2::init())
         .plugin(tauri_plugin_settings::init())
+        .plugin(tauri_plugin_git::init())
         .plugin(tauri_plugin_sfx::init())
         .plugin(tauri_plugin_windows::init())
         .plugin(tauri_plugin_js::init())
@@ -9,11 +9,13 @@ import {
 import { getCurrentWebviewWindowLabel } from "@hypr/plugin-windows";
 
 import { useConfigValue } from "../config/use-config";
+import { emitTranscriptFinalized } from "../services/hook-events";
 import * as main from "../store/tinybase/store/main";
 import {
   createSession,
   getOrCreateSessionForEventId,
 } from "../store/tinybase/store/sessions";
+import { listenerStore } from "../store/zustand/listener/instance";
 import { useTabs } from "../store/zustand/tabs";
 
 function useUpdaterEvents() {
@@ -153,9 +155,31 @@ function useNotificationEvents() {
   }, []);
 }
 
+function useTranscriptFinalizedEvents() {
+  const store = main.UI.useStore(main.STORE_ID);
+
+  useEffect(() => {
+    if (getCurrentWebviewWindowLabel() !== "main" || !store) {
+      return;
+    }
+
+    return listenerStore.subscribe((state, prev) => {
+      const sessionId = prev.live.sessionId;
+      if (
+        sessionId &&
+        prev.live.status !== "inactive" &&
+        state.live.status === "inactive"
+      ) {
+        void emitTranscriptFinalized(store, sessionId);
+      }
+    });
+  }, [store]);
+}
+
 export function EventListeners() {
   useUpdaterEvents();
   useNotificationEvents();
+  useTranscriptFinalizedEvents();
 
   return null;
 }
@@ -1,6 +1,7 @@
 import { SearchIcon } from "lucide-react";
 import { useCallback } from "react";
 
+import { useAudioSeek } from "../../../../store/zustand/audio-seek";
 import { type Tab, useTabs } from "../../../../store/zustand/tabs";
 import { StandardTabWrapper } from "../index";
 import { type TabItem, TabItemBase } from "../shared";
@@ -80,12 +81,25 @@ function SearchView({ tab }: { tab: Extract<Tab, { type: "search" }> }) {
     [openNew],
   );
 
+  const handleMomentClick = useCallback(
+    (sessionId: string, startMs: number) => {
+      useAudioSeek.getState().requestSeek(sessionId, startMs);
+      openNew({
+        type: "sessions",
+        id: sessionId,
+        state: { view: { type: "transcript" }, autoStart: null },
+      });
+    },
+    [openNew],
+  );
+
   return (
     <AdvancedSearchView
       initialQuery={tab.state.initialQuery ?? undefined}
       selectedTypes={selectedTypes}
       setSelectedTypes={setSelectedTypes}
       onResultClick={handleResultClick}
+      onMomentClick={handleMomentClick}
     />
   );
 }
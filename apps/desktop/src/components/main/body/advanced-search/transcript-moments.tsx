@@ -0,0 +1,67 @@
+import { AudioLinesIcon } from "lucide-react";
+
+import { cn } from "@hypr/utils";
+
+import type { TranscriptMatch } from "../../../../contexts/search/engine";
+
+export function TranscriptMoments({
+  matches,
+  onMomentClick,
+}: {
+  matches: TranscriptMatch[];
+  onMomentClick: (sessionId: string, startMs: number) => void;
+}) {
+  if (matches.length === 0) {
+    return null;
+  }
+
+  return (
+    <div className="mb-6">
+      <h3 className="text-sm font-semibold text-neutral-900 mb-3">
+        Transcript moments
+      </h3>
+      <div className="space-y-1">
+        {matches.map((match) => (
+          <div
+            key={match.sessionId}
+            className="flex items-start gap-3 p-3 rounded-lg"
+          >
+            <div className="mt-0.5 shrink-0">
+              <AudioLinesIcon className="h-4 w-4 text-neutral-400" />
+            </div>
+            <div className="flex-1 min-w-0">
+              <div className="font-medium text-neutral-900 truncate">
+                {match.title}
+              </div>
+              <div className="flex flex-wrap gap-1.5 mt-1.5">
+                {match.moments.map((moment) => (
+                  <button
+                    key={moment.start_ms}
+                    onClick={() =>
+                      onMomentClick(match.sessionId, moment.start_ms)
+                    }
+                    className={cn([
+                      "px-2 py-0.5 rounded-md text-xs",
+                      "bg-neutral-100 text-neutral-600",
+                      "hover:bg-neutral-200 transition-colors",
+                    ])}
+                  >
+                    {formatTimestamp(moment.start_ms)}
+                    {moment.speaker && ` · ${moment.speaker}`}
+                  </button>
+                ))}
+              </div>
+            </div>
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+}
+
+function formatTimestamp(ms: number): string {
+  const totalSeconds = Math.floor(ms / 1000);
+  const mins = Math.floor(totalSeconds / 60);
+  const secs = totalSeconds % 60;
+  return `${mins.toString().padStart(2, "0")}:${secs.toString().padStart(2, "0")}`;
+}
@@ -4,13 +4,17 @@ import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 import { Badge } from "@hypr/ui/components/ui/badge";
 import { cn } from "@hypr/utils";
 
-import { useSearchEngine } from "../../../../contexts/search/engine";
+import {
+  type TranscriptMatch,
+  useSearchEngine,
+} from "../../../../contexts/search/engine";
 import {
   type GroupedSearchResults,
   groupSearchResults,
   type SearchEntityType,
 } from "../../../../contexts/search/ui";
 import { ResultItem } from "./result-item";
+import { TranscriptMoments } from "./transcript-moments";
 
 const FILTER_OPTIONS: { type: SearchEntityType; label: string }[] = [
   { type: "session", label: "Meeting note" },
@@ -64,6 +68,7 @@ interface AdvancedSearchViewProps {
   selectedTypes: string[] | null;
   setSelectedTypes: (types: string[] | null) => void;
   onResultClick: (type: string, id: string) => void;
+  onMomentClick: (sessionId: string, startMs: number) => void;
 }
 
 export function AdvancedSearchView({
@@ -71,11 +76,15 @@ export function AdvancedSearchView({
   selectedTypes,
   setSelectedTypes,
   onResultClick,
+  onMomentClick,
 }: AdvancedSearchViewProps) {
-  const { search, isIndexing } = useSearchEngine();
+  const { search, searchTranscripts, isIndexing } = useSearchEngine();
   const [localQuery, setLocalQuery] = useState(initialQuery ?? "");
   const [query, setQuery] = useState(initialQuery ?? "");
   const [results, setResults] = useState<GroupedSearchResults | null>(null);
+  const [transcriptMatches, setTranscriptMatches] = useState<
+    TranscriptMatch[]
+  >([]);
   const [isSearching, setIsSearching] = useState(false);
   const [activeDatePreset, setActiveDatePreset] = useState<DatePreset | null>(
     null,
@@ -98,6 +107,7 @@ export function AdvancedSearchView({
   useEffect(() => {
     if (query.trim().length < 1) {
       setResults(null);
+      setTranscriptMatches([]);
       setIsSearching(false);
       return;
     }
@@ -105,9 +115,13 @@ export function AdvancedSearchView({
     let cancelled = false;
     setIsSearching(true);
 
-    search(query, dateFilter).then((hits) => {
+    Promise.all([
+      search(query, dateFilter),
+      searchTranscripts(query, dateFilter),
+    ]).then(([hits, matches]) => {
       if (!cancelled) {
         setResults(groupSearchResults(hits, query.trim()));
+        setTranscriptMatches(matches);
         setIsSearching(false);
       }
     });
@@ -115,7 +129,7 @@ export function AdvancedSearchView({
     return () => {
       cancelled = true;
     };
-  }, [query, search, dateFilter]);
+  }, [query, search, searchTranscripts, dateFilter]);
 
   useEffect(() => {
     inputRef.current?.focus();
@@ -154,6 +168,14 @@ export function AdvancedSearchView({
     };
   }, [results, selectedTypes]);
 
+  const visibleTranscriptMatches = useMemo(
+    () =>
+      !selectedTypes || selectedTypes.includes("session")
+        ? transcriptMatches
+        : [],
+    [transcriptMatches, selectedTypes],
+  );
+
   const [selectedIndex, setSelectedIndex] = useState(-1);
 
   const flatResults = useMemo(() => {
@@ -184,7 +206,9 @@ export function AdvancedSearchView({
 
   const showLoading = isSearching || isIndexing;
   const hasQuery = query.trim().length > 0;
-  const hasResults = filteredResults && filteredResults.totalResults > 0;
+  const hasResults =
+    (filteredResults && filteredResults.totalResults > 0) ||
+    visibleTranscriptMatches.length > 0;
 
   return (
     <div className="flex flex-col h-full">
@@ -299,8 +323,10 @@ export function AdvancedSearchView({
           />
         ) : hasResults ? (
           <SearchResultsView
-            results={filteredResults!}
+            results={filteredResults}
+            transcriptMatches={visibleTranscriptMatches}
             onResultClick={onResultClick}
+            onMomentClick={onMomentClick}
             selectedId={selectedId}
           />
         ) : (
@@ -351,16 +377,20 @@ function SuggestionsView({
 
 function SearchResultsView({
   results,
+  transcriptMatches,
   onResultClick,
+  onMomentClick,
   selectedId,
 }: {
-  results: GroupedSearchResults;
+  results: GroupedSearchResults | null;
+  transcriptMatches: TranscriptMatch[];
   onResultClick: (type: string, id: string) => void;
+  onMomentClick: (sessionId: string, startMs: number) => void;
   selectedId: string | null;
 }) {
   return (
     <div className="pl-[14px] pr-3 pt-3">
-      {results.groups.map((group) => (
+      {results?.groups.map((group) => (
         <div key={group.key} className="mb-6">
           <h3 className="text-sm font-semibold text-neutral-900 mb-3">
             {group.title}
@@ -377,6 +407,10 @@ function SearchResultsView({
           </div>
         </div>
       ))}
+      <TranscriptMoments
+        matches={transcriptMatches}
+        onMomentClick={onMomentClick}
+      />
     </div>
   );
 }
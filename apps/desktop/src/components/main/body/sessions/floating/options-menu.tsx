@@ -8,6 +8,7 @@ import { useCallback, useState } from "react";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import { commands as fsSyncCommands } from "@hypr/plugin-fs-sync";
 import { commands as listener2Commands } from "@hypr/plugin-listener2";
+import type { HumanStorage } from "@hypr/store";
 import { md2json } from "@hypr/tiptap/shared";
 import { Button } from "@hypr/ui/components/ui/button";
 import {
@@ -28,8 +29,10 @@ import { getEligibility } from "../../../../../hooks/autoEnhance/eligibility";
 import { useCreateEnhancedNote } from "../../../../../hooks/useEnhancedNotes";
 import { useLanguageModel } from "../../../../../hooks/useLLMConnection";
 import { useRunBatch } from "../../../../../hooks/useRunBatch";
+import { emitEnhancedNoteGenerated } from "../../../../../services/hook-events";
 import * as main from "../../../../../store/tinybase/store/main";
 import * as settings from "../../../../../store/tinybase/store/settings";
+import { subtitleTokensToWords } from "../../../../../store/transcript/subtitle";
 import { createTaskId } from "../../../../../store/zustand/ai-task/task-configs";
 import { type Tab, useTabs } from "../../../../../store/zustand/tabs";
 import { ChannelProfile } from "../../../../../utils/segment";
@@ -111,6 +114,7 @@ export function OptionsMenu({
           store.setPartialRow("enhanced_notes", enhancedNoteId, {
             content: JSON.stringify(jsonContent),
           });
+          void emitEnhancedNoteGenerated(store, sessionId, enhancedNoteId);
 
           const currentTitle = store.getCell("sessions", sessionId, "title");
           const trimmedTitle =
@@ -191,24 +195,72 @@ export function OptionsMenu({
               const transcriptId = crypto.randomUUID();
               const createdAt = new Date().toISOString();
 
-              const words = subtitle.tokens.map((token) => ({
+              const subtitleWords = subtitleTokensToWords(subtitle.tokens);
+              const words = subtitleWords.map((word) => ({
                 id: crypto.randomUUID(),
                 transcript_id: transcriptId,
-                text: token.text,
-                start_ms: token.start_time,
-                end_ms: token.end_time,
+                text: word.text,
+                start_ms: word.start_ms,
+                end_ms: word.end_ms,
                 channel: ChannelProfile.MixedCapture,
                 user_id: user_id ?? "",
-                created_at: new Date().toISOString(),
+                created_at: createdAt,
               }));
 
+              // Speaker names become people, matched by name so that
+              // re-importing an export keeps its speakers.
+              const humansByName = new Map<string, string>();
+              store.forEachRow("humans", (humanId, _forEachCell) => {
+                const name = store.getCell("humans", humanId, "name");
+                if (typeof name === "string" && name.trim()) {
+                  humansByName.set(name.trim().toLowerCase(), humanId);
+                }
+              });
+              const humanIdFor = (name: string) => {
+                const key = name.trim().toLowerCase();
+                let humanId = humansByName.get(key);
+                if (!humanId) {
+                  humanId = crypto.randomUUID();
+                  store.setRow("humans", humanId, {
+                    user_id: user_id ?? "",
+                    name: name.trim(),
+                    email: "",
+                    org_id: "",
+                    job_title: "",
+                    linkedin_username: "",
+                    memo: "",
+                    pinned: false,
+                  } satisfies HumanStorage);
+                  humansByName.set(key, humanId);
+                }
+                return humanId;
+              };
+
+              const speakerHints = subtitleWords.flatMap((word, i) =>
+                word.speaker
+                  ? [
+                      {
+                        id: crypto.randomUUID(),
+                        user_id: user_id ?? "",
+                        created_at: createdAt,
+                        transcript_id: transcriptId,
+                        word_id: words[i].id,
+                        type: "user_speaker_assignment",
+                        value: JSON.stringify({
+                          human_id: humanIdFor(word.speaker),
+                        }),
+                      },
+                    ]
+                  : [],
+              );
+
               store.setRow("transcripts", transcriptId, {
                 session_id: sessionId,
                 user_id: user_id ?? "",
                 created_at: createdAt,
                 started_at: Date.now(),
                 words: JSON.stringify(words),
-                speaker_hints: "[]",
+                speaker_hints: JSON.stringify(speakerHints),
               });
 
               void analyticsCommands.event({
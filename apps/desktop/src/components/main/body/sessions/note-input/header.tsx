@@ -33,6 +33,7 @@ import {
   useLLMConnectionStatus,
 } from "../../../../../hooks/useLLMConnection";
 import { useRunBatch } from "../../../../../hooks/useRunBatch";
+import { emitEnhancedNoteGenerated } from "../../../../../services/hook-events";
 import * as main from "../../../../../store/tinybase/store/main";
 import { createTaskId } from "../../../../../store/zustand/ai-task/task-configs";
 import { type TaskStepInfo } from "../../../../../store/zustand/ai-task/tasks";
@@ -314,6 +315,7 @@ function CreateOtherFormatButton({
           store.setPartialRow("enhanced_notes", pendingNote.id, {
             content: JSON.stringify(jsonContent),
           });
+          void emitEnhancedNoteGenerated(store, sessionId, pendingNote.id);
         } catch (error) {
           console.error("Failed to convert markdown to JSON:", error);
         }
@@ -635,6 +637,7 @@ function useEnhanceLogic(sessionId: string, enhancedNoteId: string) {
           store.setPartialRow("enhanced_notes", enhancedNoteId, {
             content: JSON.stringify(jsonContent),
           });
+          void emitEnhancedNoteGenerated(store, sessionId, enhancedNoteId);
         } catch (error) {
           console.error("Failed to convert markdown to JSON:", error);
         }
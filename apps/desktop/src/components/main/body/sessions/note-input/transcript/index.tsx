@@ -1,5 +1,8 @@
 import { type RefObject, useCallback } from "react";
 
+import { commands as listenerCommands } from "@hypr/plugin-listener";
+
+import { useListener } from "../../../../../../contexts/listener";
 import * as main from "../../../../../../store/tinybase/store/main";
 import type { SpeakerHintWithId } from "../../../../../../store/transcript/types";
 import {
@@ -9,6 +12,7 @@ import {
   updateTranscriptWords,
 } from "../../../../../../store/transcript/utils";
 import { id } from "../../../../../../utils";
+import { findProviderSpeakers } from "../../../../../../utils/speaker-hints";
 import { TranscriptContainer } from "./shared";
 
 type Store = NonNullable<ReturnType<typeof main.UI.useStore>>;
@@ -50,6 +54,9 @@ export function Transcript({
   const store = main.UI.useStore(main.STORE_ID);
   const indexes = main.UI.useIndexes(main.STORE_ID);
   const checkpoints = main.UI.useCheckpoints(main.STORE_ID);
+  const isLive = useListener(
+    (state) => state.getSessionMode(sessionId) === "active",
+  );
 
   const handleDeleteWord = useCallback(
     (wordId: string) => {
@@ -107,8 +114,18 @@ export function Transcript({
       updateTranscriptHints(store, transcriptId, [...hints, ...newHints]);
 
       checkpoints.addCheckpoint("assign_speaker");
+
+      // Enrol the voice so later sessions recognize this person. Only the
+      // live listener still has the speaker's audio.
+      if (isLive) {
+        findProviderSpeakers(hints, new Set(wordIds)).forEach(
+          ({ channel, speakerIndex }) => {
+            void listenerCommands.labelSpeaker(channel, speakerIndex, humanId);
+          },
+        );
+      }
     },
-    [store, indexes, checkpoints, sessionId],
+    [store, indexes, checkpoints, sessionId, isLive],
   );
 
   const operations = isEditing
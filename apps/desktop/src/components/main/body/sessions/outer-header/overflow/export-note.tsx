@@ -7,13 +7,18 @@ import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import { commands as openerCommands } from "@hypr/plugin-opener2";
 import {
   commands as pdfCommands,
+  type DocumentFormat,
   type PdfMetadata,
+  type PdfOptions,
+  type PdfParticipant,
   type TranscriptItem,
 } from "@hypr/plugin-pdf";
 import { json2md } from "@hypr/tiptap/shared";
 import { DropdownMenuItem } from "@hypr/ui/components/ui/dropdown-menu";
 
+import { useConfigValue } from "../../../../../../config/use-config";
 import { useSessionEvent } from "../../../../../../hooks/tinybase";
+import { emitExportCompleted } from "../../../../../../services/hook-events";
 import * as main from "../../../../../../store/tinybase/store/main";
 import {
   parseTranscriptHints,
@@ -51,12 +56,22 @@ function formatDuration(startMs: number, endMs: number): string {
   return `${minutes}m`;
 }
 
-export function ExportPDF({
+type ExportFormat = "pdf" | DocumentFormat;
+
+const FORMAT_LABELS: Record<ExportFormat, string> = {
+  pdf: "PDF",
+  docx: "Word",
+  html: "HTML",
+};
+
+export function ExportNote({
   sessionId,
   currentView,
+  format,
 }: {
   sessionId: string;
   currentView: EditorView;
+  format: ExportFormat;
 }) {
   const store = main.UI.useStore(main.STORE_ID);
   const queries = main.UI.useQueries(main.STORE_ID);
@@ -93,10 +108,13 @@ export function ExportPDF({
     main.STORE_ID,
   ) as string | undefined;
 
-  const participantNames = useMemo((): string[] => {
+  const companyName = useConfigValue("pdf_company_name");
+  const accentColor = useConfigValue("pdf_accent_color");
+
+  const participants = useMemo((): PdfParticipant[] => {
     if (!queries) return [];
 
-    const names: string[] = [];
+    const participants: PdfParticipant[] = [];
     queries.forEachResultRow(
       main.QUERIES.sessionParticipantsWithDetails,
       (rowId) => {
@@ -106,18 +124,27 @@ export function ExportPDF({
           "session_id",
         );
         if (participantSessionId === sessionId) {
-          const name = queries.getResultCell(
-            main.QUERIES.sessionParticipantsWithDetails,
-            rowId,
-            "human_name",
-          );
-          if (name && typeof name === "string") {
-            names.push(name);
+          const cell = (name: string) => {
+            const value = queries.getResultCell(
+              main.QUERIES.sessionParticipantsWithDetails,
+              rowId,
+              name,
+            );
+            return typeof value === "string" && value ? value : null;
+          };
+          const name = cell("human_name");
+          if (name) {
+            participants.push({
+              name,
+              email: cell("human_email"),
+              jobTitle: cell("human_job_title"),
+              organization: cell("org_name"),
+            });
           }
         }
       },
     );
-    return names;
+    return participants;
   }, [queries, sessionId]);
 
   const transcriptIds = main.UI.useSliceRowIds(
@@ -188,6 +215,8 @@ export function ExportPDF({
     return segments.map((segment) => ({
       speaker: SegmentKey.renderLabel(segment.key, ctx, manager),
       text: segment.words.map((w) => w.text).join(" "),
+      startMs: segment.words[0]?.start_ms ?? null,
+      endMs: segment.words[segment.words.length - 1]?.end_ms ?? null,
     }));
   }, [store, transcriptIds]);
 
@@ -230,14 +259,21 @@ export function ExportPDF({
       enhancedMd: string;
       transcript: { items: TranscriptItem[] } | null;
       metadata: PdfMetadata | null;
+      options: PdfOptions;
     } => {
       const metadata: PdfMetadata = {
         title: sessionTitle || "Untitled",
         createdAt: sessionCreatedAt ? formatDate(sessionCreatedAt) : "",
-        participants: participantNames,
+        participants,
         eventTitle: eventTitle || null,
         duration: transcriptDuration,
       };
+      const branding = companyName?.trim()
+        ? {
+            companyName: companyName.trim(),
+            accentColor: accentColor?.trim() || null,
+          }
+        : null;
 
       switch (currentView.type) {
         case "raw": {
@@ -254,6 +290,7 @@ export function ExportPDF({
             enhancedMd: memoMd,
             transcript: null,
             metadata,
+            options: { actionItems: true, branding },
           };
         }
         case "enhanced": {
@@ -270,6 +307,7 @@ export function ExportPDF({
             enhancedMd,
             transcript: null,
             metadata,
+            options: { tableOfContents: true, actionItems: true, branding },
           };
         }
         case "transcript": {
@@ -278,6 +316,7 @@ export function ExportPDF({
             transcript:
               transcriptItems.length > 0 ? { items: transcriptItems } : null,
             metadata,
+            options: { branding },
           };
         }
         default:
@@ -285,6 +324,7 @@ export function ExportPDF({
             enhancedMd: "",
             transcript: null,
             metadata,
+            options: { branding },
           };
       }
     };
@@ -295,21 +335,24 @@ export function ExportPDF({
     transcriptItems,
     sessionTitle,
     sessionCreatedAt,
-    participantNames,
+    participants,
     eventTitle,
     transcriptDuration,
+    companyName,
+    accentColor,
   ]);
 
   const getExportLabel = () => {
+    const label = FORMAT_LABELS[format];
     switch (currentView.type) {
       case "raw":
-        return "Export Memo to PDF";
+        return `Export Memo to ${label}`;
       case "enhanced":
-        return "Export Summary to PDF";
+        return `Export Summary to ${label}`;
       case "transcript":
-        return "Export Transcript to PDF";
+        return `Export Transcript to ${label}`;
       default:
-        return "Export to PDF";
+        return `Export to ${label}`;
     }
   };
 
@@ -320,11 +363,14 @@ export function ExportPDF({
         (sessionTitle ?? "Untitled").trim() || "Untitled"
       ).replace(/[<>:"/\\|?*]/g, "_");
       const timestamp = new Date().toISOString().replace(/[:.]/g, "-");
-      const filename = `${sanitizedTitle}_${timestamp}.pdf`;
+      const filename = `${sanitizedTitle}_${timestamp}.${format}`;
       const path = await join(downloadsPath, filename);
 
-      const exportContent = getExportContent();
-      const result = await pdfCommands.export(path, exportContent);
+      const exportContent = { ...getExportContent(), sessionId };
+      const result =
+        format === "pdf"
+          ? await pdfCommands.export(path, exportContent)
+          : await pdfCommands.exportDocument(path, exportContent, format);
 
       if (result.status === "error") {
         throw new Error(result.error);
@@ -336,7 +382,7 @@ export function ExportPDF({
       if (path) {
         void analyticsCommands.event({
           event: "session_exported",
-          format: "pdf",
+          format,
           view_type: currentView.type,
           has_transcript:
             currentView.type === "transcript" && transcriptItems.length > 0,
@@ -345,6 +391,9 @@ export function ExportPDF({
           has_memo: currentView.type === "raw" && !!rawMd,
         });
         void openerCommands.revealItemInDir(path);
+        if (store) {
+          emitExportCompleted(store, sessionId, format, path);
+        }
       }
     },
     onError: console.error,
@@ -5,10 +5,16 @@ import { useMemo } from "react";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import {
   commands as listener2Commands,
+  type TranscriptFormat,
   type VttWord,
 } from "@hypr/plugin-listener2";
 import { commands as openerCommands } from "@hypr/plugin-opener2";
-import { DropdownMenuItem } from "@hypr/ui/components/ui/dropdown-menu";
+import {
+  DropdownMenuItem,
+  DropdownMenuSub,
+  DropdownMenuSubContent,
+  DropdownMenuSubTrigger,
+} from "@hypr/ui/components/ui/dropdown-menu";
 
 import * as main from "../../../../../../store/tinybase/store/main";
 import {
@@ -22,6 +28,12 @@ import {
 } from "../../../../../../utils/segment/shared";
 import { convertStorageHintsToRuntime } from "../../../../../../utils/speaker-hints";
 
+const TRANSCRIPT_FORMATS: { format: TranscriptFormat; label: string }[] = [
+  { format: "srt", label: "Subtitles (SRT)" },
+  { format: "vtt", label: "WebVTT" },
+  { format: "txt", label: "Plain text" },
+];
+
 export function ExportTranscript({ sessionId }: { sessionId: string }) {
   const store = main.UI.useStore(main.STORE_ID);
 
@@ -113,17 +125,22 @@ export function ExportTranscript({ sessionId }: { sessionId: string }) {
   }, [store, transcriptIds]);
 
   const { mutate, isPending } = useMutation({
-    mutationFn: async () => {
-      const result = await listener2Commands.exportToVtt(sessionId, words);
+    mutationFn: async (format: TranscriptFormat) => {
+      const result = await listener2Commands.exportTranscript(
+        sessionId,
+        words,
+        format,
+        null,
+      );
       if (result.status === "error") {
         throw new Error(result.error);
       }
-      return result.data;
+      return { path: result.data, format };
     },
-    onSuccess: (path) => {
+    onSuccess: ({ path, format }) => {
       void analyticsCommands.event({
         event: "session_exported",
-        format: "vtt",
+        format,
         word_count: words.length,
       });
       openerCommands.openPath(path, null);
@@ -131,16 +148,33 @@ export function ExportTranscript({ sessionId }: { sessionId: string }) {
   });
 
   return (
-    <DropdownMenuItem
-      onClick={(e) => {
-        e.preventDefault();
-        mutate();
-      }}
-      disabled={isPending || words.length === 0}
-      className="cursor-pointer"
-    >
-      {isPending ? <Loader2Icon className="animate-spin" /> : <FileTextIcon />}
-      <span>{isPending ? "Exporting..." : "Export Transcript"}</span>
-    </DropdownMenuItem>
+    <DropdownMenuSub>
+      <DropdownMenuSubTrigger
+        disabled={isPending || words.length === 0}
+        className="cursor-pointer"
+      >
+        {isPending ? (
+          <Loader2Icon className="animate-spin" />
+        ) : (
+          <FileTextIcon />
+        )}
+        <span>{isPending ? "Exporting..." : "Export Transcript"}</span>
+      </DropdownMenuSubTrigger>
+      <DropdownMenuSubContent>
+        {TRANSCRIPT_FORMATS.map(({ format, label }) => (
+          <DropdownMenuItem
+            key={format}
+            onClick={(e) => {
+              e.preventDefault();
+              mutate(format);
+            }}
+            disabled={isPending}
+            className="cursor-pointer"
+          >
+            <span>{label}</span>
+          </DropdownMenuItem>
+        ))}
+      </DropdownMenuSubContent>
+    </DropdownMenuSub>
   );
 }
@@ -14,7 +14,7 @@ import {
 import type { EditorView } from "../../../../../../store/zustand/tabs/schema";
 import { useHasTranscript } from "../../shared";
 import { DeleteNote, DeleteRecording } from "./delete";
-import { ExportPDF } from "./export-pdf";
+import { ExportNote } from "./export-note";
 import { ExportTranscript } from "./export-transcript";
 import { Listening } from "./listening";
 import { Copy, Folder, RevealInFinder, ShowInFinder } from "./misc";
@@ -53,7 +53,21 @@ export function OverflowButton({
       <DropdownMenuContent align="end" className="w-56">
         <Copy />
         <Folder sessionId={sessionId} setOpen={setOpen} />
-        <ExportPDF sessionId={sessionId} currentView={currentView} />
+        <ExportNote
+          sessionId={sessionId}
+          currentView={currentView}
+          format="pdf"
+        />
+        <ExportNote
+          sessionId={sessionId}
+          currentView={currentView}
+          format="docx"
+        />
+        <ExportNote
+          sessionId={sessionId}
+          currentView={currentView}
+          format="html"
+        />
         {hasTranscript && <ExportTranscript sessionId={sessionId} />}
         <DropdownMenuSeparator />
         <Listening sessionId={sessionId} hasTranscript={hasTranscript} />
@@ -4,6 +4,7 @@ import { useShallow } from "zustand/shallow";
 
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 
+import { emitSessionCreated } from "../../services/hook-events";
 import { useTabs } from "../../store/zustand/tabs";
 import { id } from "../../utils";
 
@@ -36,6 +37,9 @@ export function useNewNote({
       event: "note_created",
       has_event_id: false,
     });
+    if (persistedStore) {
+      void emitSessionCreated(persistedStore, sessionId);
+    }
 
     const ff = behavior === "new" ? openNew : openCurrent;
     ff({ type: "sessions", id: sessionId });
@@ -64,6 +68,9 @@ export function useNewNoteAndListen() {
       event: "note_created",
       has_event_id: false,
     });
+    if (persistedStore) {
+      void emitSessionCreated(persistedStore, sessionId);
+    }
 
     openNew({
       type: "sessions",
@@ -4,6 +4,7 @@ import {
   LayoutTemplateIcon,
   Loader2Icon,
   MicIcon,
+  PaperclipIcon,
   UserIcon,
   UsersIcon,
 } from "lucide-react";
@@ -54,6 +55,11 @@ export function ImportPreview({
       label: "Templates",
       count: stats.templatesCount,
     },
+    {
+      icon: PaperclipIcon,
+      label: "Attachments",
+      count: stats.attachmentsCount,
+    },
   ];
 
   return (
@@ -15,17 +15,37 @@ import * as main from "../../../store/tinybase/store/main";
 import { save } from "../../../store/tinybase/store/save";
 import { StyledStreamdown } from "../ai/shared";
 import { ImportPreview } from "./import-preview";
+import { MarkdownVaultItem } from "./markdown-vault-item";
 import { SourceItem } from "./source-item";
 
+type ImportTarget =
+  | { type: "source"; source: ImportSourceKind }
+  | { type: "markdown_vault"; path: string };
+
 type DryRunResult = {
-  source: ImportSourceKind;
+  target: ImportTarget;
   stats: ImportStats;
 };
 
+function runImport(target: ImportTarget, userId: string) {
+  return target.type === "source"
+    ? commands.runImport(target.source, userId)
+    : commands.runImportMarkdownVault(target.path, userId);
+}
+
+function runImportDry(target: ImportTarget) {
+  return target.type === "source"
+    ? commands.runImportDry(target.source)
+    : commands.runImportMarkdownVaultDry(target.path);
+}
+
+function targetKind(target: ImportTarget) {
+  return target.type === "source" ? target.source : target.type;
+}
+
 export function Data() {
   const [dryRunResult, setDryRunResult] = useState<DryRunResult | null>(null);
-  const [successfulSource, setSuccessfulSource] =
-    useState<ImportSourceKind | null>(null);
+  const [successfulKind, setSuccessfulKind] = useState<string | null>(null);
   const store = main.UI.useStore(main.STORE_ID);
   const { user_id } = main.UI.useValues(main.STORE_ID);
 
@@ -41,8 +61,8 @@ export function Data() {
   });
 
   const importMutation = useMutation({
-    mutationFn: async (source: ImportSourceKind) => {
-      const result = await commands.runImport(source, user_id ?? "");
+    mutationFn: async (target: ImportTarget) => {
+      const result = await runImport(target, user_id ?? "");
       if (result.status === "error") {
         throw new Error(result.error);
       }
@@ -62,26 +82,24 @@ export function Data() {
 
       return result.data.stats;
     },
-    onSuccess: () => {
-      const source = dryRunResult?.source;
+    onSuccess: (_stats, target) => {
+      const source = targetKind(target);
       void analyticsCommands.event({
         event: "data_imported",
         source,
       });
-      if (source) {
-        setSuccessfulSource(source);
-      }
+      setSuccessfulKind(source);
       setDryRunResult(null);
     },
   });
 
   const dryImportMutation = useMutation({
-    mutationFn: async (source: ImportSourceKind) => {
-      const result = await commands.runImportDry(source);
+    mutationFn: async (target: ImportTarget) => {
+      const result = await runImportDry(target);
       if (result.status === "error") {
         throw new Error(result.error);
       }
-      return { source, stats: result.data };
+      return { target, stats: result.data };
     },
     onSuccess: (result) => {
       setDryRunResult(result);
@@ -92,10 +110,24 @@ export function Data() {
     setDryRunResult(null);
     dryImportMutation.reset();
     importMutation.reset();
-    setSuccessfulSource(null);
+    setSuccessfulKind(null);
+  };
+
+  const scan = (target: ImportTarget) => {
+    setSuccessfulKind(null);
+    dryImportMutation.mutate(target);
   };
 
   const isPending = importMutation.isPending || dryImportMutation.isPending;
+  const scanningKind =
+    dryImportMutation.isPending && dryImportMutation.variables
+      ? targetKind(dryImportMutation.variables)
+      : null;
+
+  const sourceName = (target: ImportTarget) =>
+    target.type === "source"
+      ? (sources?.find((s) => s.kind === target.source)?.name ?? "Unknown")
+      : (target.path.split(/[\\/]/).filter(Boolean).pop() ?? target.path);
 
   return (
     <div>
@@ -109,38 +141,37 @@ export function Data() {
         {dryRunResult ? (
           <ImportPreview
             stats={dryRunResult.stats}
-            sourceName={
-              sources?.find((s) => s.kind === dryRunResult.source)?.name ??
-              "Unknown"
-            }
-            onConfirm={() => importMutation.mutate(dryRunResult.source)}
+            sourceName={sourceName(dryRunResult.target)}
+            onConfirm={() => importMutation.mutate(dryRunResult.target)}
             onCancel={handleCancel}
             isPending={importMutation.isPending}
           />
         ) : (
-          sources
-            ?.filter(
-              (
-                source,
-              ): source is ImportSourceInfo & { kind: ImportSourceKind } =>
-                source.kind !== null,
-            )
-            .map((source) => (
-              <SourceItem
-                key={source.kind}
-                source={source}
-                onScan={() => {
-                  setSuccessfulSource(null);
-                  dryImportMutation.mutate(source.kind);
-                }}
-                disabled={isPending}
-                isScanning={
-                  dryImportMutation.isPending &&
-                  dryImportMutation.variables === source.kind
-                }
-                isSuccess={successfulSource === source.kind}
-              />
-            ))
+          <>
+            {sources
+              ?.filter(
+                (
+                  source,
+                ): source is ImportSourceInfo & { kind: ImportSourceKind } =>
+                  source.kind !== null,
+              )
+              .map((source) => (
+                <SourceItem
+                  key={source.kind}
+                  source={source}
+                  onScan={() => scan({ type: "source", source: source.kind })}
+                  disabled={isPending}
+                  isScanning={scanningKind === source.kind}
+                  isSuccess={successfulKind === source.kind}
+                />
+              ))}
+            <MarkdownVaultItem
+              onScan={(path) => scan({ type: "markdown_vault", path })}
+              disabled={isPending}
+              isScanning={scanningKind === "markdown_vault"}
+              isSuccess={successfulKind === "markdown_vault"}
+            />
+          </>
         )}
 
         {(importMutation.isError || dryImportMutation.isError) && (
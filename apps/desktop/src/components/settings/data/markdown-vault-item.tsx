@@ -0,0 +1,61 @@
+import { open as selectFolder } from "@tauri-apps/plugin-dialog";
+import { CheckIcon, Loader2Icon } from "lucide-react";
+
+import { Button } from "@hypr/ui/components/ui/button";
+
+export function MarkdownVaultItem({
+  onScan,
+  disabled,
+  isScanning,
+  isSuccess,
+}: {
+  onScan: (path: string) => void;
+  disabled: boolean;
+  isScanning: boolean;
+  isSuccess?: boolean;
+}) {
+  const handleChoose = async () => {
+    const selected = await selectFolder({
+      title: "Choose a Markdown vault",
+      directory: true,
+      multiple: false,
+    });
+
+    if (typeof selected === "string") {
+      onScan(selected);
+    }
+  };
+
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div className="flex-1 min-w-0">
+        <h3 className="text-sm font-medium mb-1">Markdown vault</h3>
+        <p className="text-xs text-neutral-600">
+          Import notes from an Obsidian or Logseq vault, or a Notion export
+        </p>
+      </div>
+      <div className="flex items-center gap-2 shrink-0">
+        <Button
+          size="sm"
+          variant="outline"
+          onClick={() => void handleChoose()}
+          disabled={disabled}
+        >
+          {isScanning ? (
+            <>
+              <Loader2Icon size={14} className="animate-spin mr-1" />
+              Scanning...
+            </>
+          ) : isSuccess ? (
+            <>
+              <CheckIcon size={14} className="text-green-600 mr-1" />
+              Choose folder
+            </>
+          ) : (
+            "Choose folder"
+          )}
+        </Button>
+      </div>
+    </div>
+  );
+}
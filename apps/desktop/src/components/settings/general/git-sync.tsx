@@ -0,0 +1,95 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+
+import { commands as gitCommands } from "@hypr/plugin-git";
+import { Switch } from "@hypr/ui/components/ui/switch";
+import { formatDistanceToNow } from "@hypr/utils";
+
+const AUTO_SYNC_INTERVAL_SECS = 5 * 60;
+
+export function GitSyncSettings({ path }: { path: string | undefined }) {
+  const queryClient = useQueryClient();
+
+  const status = useQuery({
+    queryKey: ["git-auto-sync-status"],
+    queryFn: async () => {
+      const result = await gitCommands.getAutoSyncStatus();
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+    refetchInterval: 30_000,
+  });
+
+  const toggle = useMutation({
+    mutationFn: async (enabled: boolean) => {
+      if (!enabled) {
+        const result = await gitCommands.stopAutoSync();
+        if (result.status === "error") {
+          throw new Error(result.error);
+        }
+        return;
+      }
+
+      if (!path) {
+        throw new Error("Content folder is not available");
+      }
+
+      const isRepo = await gitCommands.isRepo(path);
+      if (isRepo.status === "ok" && !isRepo.data) {
+        const init = await gitCommands.init(path);
+        if (init.status === "error") {
+          throw new Error(init.error);
+        }
+      }
+
+      const remotes = await gitCommands.listRemotes(path);
+      const remote =
+        remotes.status === "ok"
+          ? (remotes.data.find((r) => r.name === "origin") ?? remotes.data[0])
+          : undefined;
+
+      const result = await gitCommands.startAutoSync({
+        path,
+        interval_secs: AUTO_SYNC_INTERVAL_SECS,
+        options: { remote_name: remote?.name ?? null, branch: null },
+      });
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+    },
+    onSettled: () => {
+      void queryClient.invalidateQueries({
+        queryKey: ["git-auto-sync-status"],
+      });
+    },
+  });
+
+  const enabled = !!status.data?.config;
+  const lastSyncedAt = status.data?.last_result?.finished_at;
+
+  const description = toggle.isError
+    ? toggle.error.message
+    : status.data?.last_error
+      ? `Last sync failed: ${status.data.last_error}`
+      : enabled && lastSyncedAt
+        ? `Last synced ${formatDistanceToNow(new Date(lastSyncedAt * 1000), {
+            addSuffix: true,
+          })}`
+        : "Commits your content folder every 5 minutes and syncs it with " +
+          "its git remote";
+
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div className="flex-1 min-w-0">
+        <h3 className="text-sm font-medium mb-1">Git auto-sync</h3>
+        <p className="text-xs text-neutral-600">{description}</p>
+      </div>
+      <Switch
+        checked={enabled}
+        disabled={!path || toggle.isPending || status.isLoading}
+        onCheckedChange={(checked) => toggle.mutate(checked)}
+      />
+    </div>
+  );
+}
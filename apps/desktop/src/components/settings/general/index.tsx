@@ -14,10 +14,12 @@ import { AppSettingsView } from "./app-settings";
 import { Audio } from "./audio";
 import { MainLanguageView } from "./main-language";
 import { NotificationSettingsView } from "./notification";
+import { PdfBrandingSettings } from "./pdf-branding";
 import { Permissions } from "./permissions";
 import { SpokenLanguagesView } from "./spoken-languages";
 import { StorageSettingsView } from "./storage";
 import { TimezoneSelector } from "./timezone";
+import { VocabularySettings } from "./vocabulary";
 import { WeekStartSelector } from "./week-start";
 
 function useSettingsForm() {
@@ -208,11 +210,17 @@ export function SettingsApp() {
               />
             )}
           </form.Field>
+          <VocabularySettings />
         </div>
       </div>
 
       <StorageSettingsView />
 
+      <div>
+        <h2 className="text-lg font-semibold font-serif mb-4">Export</h2>
+        <PdfBrandingSettings />
+      </div>
+
       <div>
         <h2 className="text-lg font-semibold font-serif mb-4">Data</h2>
         <Data />
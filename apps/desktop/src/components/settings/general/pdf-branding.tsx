@@ -0,0 +1,57 @@
+import { Input } from "@hypr/ui/components/ui/input";
+
+import { useConfigValue } from "../../../config/use-config";
+import * as settings from "../../../store/tinybase/store/settings";
+
+export function PdfBrandingSettings() {
+  const companyName = useConfigValue("pdf_company_name");
+  const accentColor = useConfigValue("pdf_accent_color");
+
+  const setCompanyName = settings.UI.useSetValueCallback(
+    "pdf_company_name",
+    (val: string) => val,
+    [],
+    settings.STORE_ID,
+  );
+  const setAccentColor = settings.UI.useSetValueCallback(
+    "pdf_accent_color",
+    (val: string) => val,
+    [],
+    settings.STORE_ID,
+  );
+
+  return (
+    <div className="flex flex-col gap-6">
+      <div className="flex flex-row items-center justify-between">
+        <div>
+          <h3 className="text-sm font-medium mb-1">Company name</h3>
+          <p className="text-xs text-neutral-600">
+            Shown in the header of exported PDFs
+          </p>
+        </div>
+        <Input
+          type="text"
+          className="w-40"
+          placeholder="None"
+          value={companyName ?? ""}
+          onChange={(e) => setCompanyName(e.target.value)}
+        />
+      </div>
+      <div className="flex flex-row items-center justify-between">
+        <div>
+          <h3 className="text-sm font-medium mb-1">Accent color</h3>
+          <p className="text-xs text-neutral-600">
+            Hex color for headings and links, such as #2563eb
+          </p>
+        </div>
+        <Input
+          type="text"
+          className="w-40 font-mono"
+          placeholder="#2563eb"
+          value={accentColor ?? ""}
+          onChange={(e) => setAccentColor(e.target.value)}
+        />
+      </div>
+    </div>
+  );
+}
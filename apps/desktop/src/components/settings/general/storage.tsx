@@ -11,6 +11,8 @@ import {
   TooltipTrigger,
 } from "@hypr/ui/components/ui/tooltip";
 
+import { GitSyncSettings } from "./git-sync";
+
 export function StorageSettingsView() {
   const { data: othersBase } = useQuery({
     queryKey: ["others-base-path"],
@@ -64,6 +66,7 @@ export function StorageSettingsView() {
           description="Stores app-wide settings and configurations"
           path={othersBase}
         />
+        <GitSyncSettings path={contentBase} />
       </div>
     </div>
   );
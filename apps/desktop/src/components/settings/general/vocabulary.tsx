@@ -0,0 +1,43 @@
+import { useState } from "react";
+
+import { Textarea } from "@hypr/ui/components/ui/textarea";
+
+import { useConfigValue } from "../../../config/use-config";
+import * as settings from "../../../store/tinybase/store/settings";
+
+export function VocabularySettings() {
+  const vocabulary = useConfigValue("transcript_vocabulary");
+  const [draft, setDraft] = useState(vocabulary.join("\n"));
+
+  const setVocabulary = settings.UI.useSetValueCallback(
+    "transcript_vocabulary",
+    (val: string) =>
+      JSON.stringify(
+        val
+          .split("\n")
+          .map((line) => line.trim())
+          .filter(Boolean),
+      ),
+    [],
+    settings.STORE_ID,
+  );
+
+  return (
+    <div className="flex flex-col gap-2">
+      <div>
+        <h3 className="text-sm font-medium mb-1">Vocabulary</h3>
+        <p className="text-xs text-neutral-600">
+          Names and terms to spell correctly in transcripts, one per line. Add
+          common mishearings as "char note, car note =&gt; Char Note".
+        </p>
+      </div>
+      <Textarea
+        className="min-h-24 font-mono text-sm"
+        placeholder={"Kubernetes\nchar note, car note => Char Note"}
+        value={draft}
+        onChange={(e) => setDraft(e.target.value)}
+        onBlur={() => setVocabulary(draft)}
+      />
+    </div>
+  );
+}
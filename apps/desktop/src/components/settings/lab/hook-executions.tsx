@@ -0,0 +1,81 @@
+import { useQuery } from "@tanstack/react-query";
+import { RefreshCwIcon } from "lucide-react";
+
+import { commands as hooksCommands } from "@hypr/plugin-hooks";
+import { Button } from "@hypr/ui/components/ui/button";
+import { cn } from "@hypr/utils";
+
+const EXECUTION_LIMIT = 20;
+
+export function HookExecutions() {
+  const executions = useQuery({
+    queryKey: ["hook-executions"],
+    queryFn: async () => {
+      const result = await hooksCommands.listHookExecutions(EXECUTION_LIMIT);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+  });
+
+  return (
+    <div className="flex flex-col gap-2">
+      <div className="flex items-center justify-between gap-4">
+        <div className="flex-1">
+          <h3 className="text-sm font-medium mb-1">Hook Runs</h3>
+          <p className="text-xs text-neutral-600">
+            Recent runs of the hook commands in your settings.
+          </p>
+        </div>
+        <Button
+          variant="outline"
+          size="sm"
+          onClick={() => void executions.refetch()}
+          disabled={executions.isFetching}
+        >
+          <RefreshCwIcon className="h-3.5 w-3.5" />
+        </Button>
+      </div>
+
+      {executions.isError ? (
+        <p className="text-xs text-red-600">{executions.error.message}</p>
+      ) : executions.data && executions.data.length > 0 ? (
+        <div className="flex flex-col divide-y divide-neutral-100 rounded-lg border border-neutral-200">
+          {executions.data.map((execution, i) => (
+            <div key={i} className="flex flex-col gap-0.5 px-3 py-2 text-xs">
+              <div className="flex items-center gap-2">
+                <span
+                  className={cn([
+                    "h-1.5 w-1.5 rounded-full shrink-0",
+                    execution.success ? "bg-green-500" : "bg-red-500",
+                  ])}
+                />
+                <span className="font-mono truncate flex-1">
+                  {execution.command}
+                </span>
+                <span className="text-neutral-400 shrink-0">
+                  {new Date(execution.finished_at_ms).toLocaleString()}
+                </span>
+              </div>
+              <div className="text-neutral-500 pl-3.5">
+                {execution.event} · {execution.duration_ms}ms
+                {execution.attempts > 1 &&
+                  ` · ${execution.attempts} attempts`}
+                {execution.exit_code !== null &&
+                  ` · exit ${execution.exit_code}`}
+              </div>
+              {!execution.success && execution.stderr && (
+                <pre className="text-red-600 pl-3.5 whitespace-pre-wrap break-all">
+                  {execution.stderr}
+                </pre>
+              )}
+            </div>
+          ))}
+        </div>
+      ) : (
+        <p className="text-xs text-neutral-400">No hooks have run yet.</p>
+      )}
+    </div>
+  );
+}
@@ -7,6 +7,11 @@ import { commands as windowsCommands } from "@hypr/plugin-windows";
 import { Button } from "@hypr/ui/components/ui/button";
 import { cn } from "@hypr/utils";
 
+import { HookExecutions } from "./hook-executions";
+import { McpServerSettings } from "./mcp-server";
+import { SemanticSearchSettings } from "./semantic-search";
+import { WebhookSettings } from "./webhooks";
+
 export function SettingsLab() {
   const handleOpenControlWindow = async () => {
     await windowsCommands.windowShow({ type: "control" });
@@ -26,7 +31,15 @@ export function SettingsLab() {
         </Button>
       </div>
 
+      <SemanticSearchSettings />
+
+      <McpServerSettings />
+
       <DownloadButtons />
+
+      <HookExecutions />
+
+      <WebhookSettings />
     </div>
   );
 }
@@ -0,0 +1,93 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { CheckIcon, CopyIcon } from "lucide-react";
+import { useState } from "react";
+
+import { commands as mcpCommands } from "@hypr/plugin-mcp";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Switch } from "@hypr/ui/components/ui/switch";
+
+export function McpServerSettings() {
+  const queryClient = useQueryClient();
+
+  const info = useQuery({
+    queryKey: ["mcp-server-info"],
+    queryFn: async () => {
+      const result = await mcpCommands.getServerInfo();
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+  });
+
+  // The plugin persists whether the server is enabled and starts it again on
+  // launch, so the switch only has to start or stop it.
+  const toggle = useMutation({
+    mutationFn: async (enabled: boolean) => {
+      const result = enabled
+        ? await mcpCommands.startServer()
+        : await mcpCommands.stopServer();
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+    },
+    onSettled: () => {
+      void queryClient.invalidateQueries({ queryKey: ["mcp-server-info"] });
+    },
+  });
+
+  const running = !!info.data?.running;
+
+  return (
+    <div className="flex flex-col gap-2">
+      <div className="flex items-center justify-between gap-4">
+        <div className="flex-1">
+          <h3 className="text-sm font-medium mb-1">MCP Server</h3>
+          <p className="text-xs text-neutral-600">
+            Let AI agents on this computer search and read your meetings.
+          </p>
+        </div>
+        <Switch
+          checked={running}
+          disabled={toggle.isPending || info.isLoading}
+          onCheckedChange={(checked) => toggle.mutate(checked)}
+        />
+      </div>
+
+      {toggle.isError && (
+        <p className="text-xs text-red-600">{toggle.error.message}</p>
+      )}
+
+      {running && info.data && (
+        <div className="flex flex-col gap-1 rounded-lg border border-neutral-200 px-3 py-2 text-xs">
+          <CopyRow label="URL" value={info.data.url} />
+          <CopyRow label="Token" value={info.data.token} />
+        </div>
+      )}
+    </div>
+  );
+}
+
+function CopyRow({ label, value }: { label: string; value: string }) {
+  const [copied, setCopied] = useState(false);
+
+  const handleCopy = async () => {
+    await navigator.clipboard.writeText(value);
+    setCopied(true);
+    setTimeout(() => setCopied(false), 1500);
+  };
+
+  return (
+    <div className="flex items-center gap-2">
+      <span className="text-neutral-500 w-10 shrink-0">{label}</span>
+      <span className="font-mono truncate flex-1">{value}</span>
+      <Button variant="ghost" size="sm" onClick={() => void handleCopy()}>
+        {copied ? (
+          <CheckIcon className="h-3.5 w-3.5 text-green-600" />
+        ) : (
+          <CopyIcon className="h-3.5 w-3.5" />
+        )}
+      </Button>
+    </div>
+  );
+}
@@ -0,0 +1,75 @@
+import { Channel } from "@tauri-apps/api/core";
+import { useState } from "react";
+
+import { commands as tantivyCommands } from "@hypr/plugin-tantivy";
+import { Switch } from "@hypr/ui/components/ui/switch";
+
+import { useConfigValue } from "../../../config/use-config";
+import * as settings from "../../../store/tinybase/store/settings";
+
+export function SemanticSearchSettings() {
+  const enabled = useConfigValue("semantic_search");
+  const setEnabled = settings.UI.useSetValueCallback(
+    "semantic_search",
+    (val: boolean) => val,
+    [],
+    settings.STORE_ID,
+  );
+
+  const [progress, setProgress] = useState<number | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleChange = async (checked: boolean) => {
+    setError(null);
+
+    if (!checked) {
+      setEnabled(false);
+      return;
+    }
+
+    const downloaded = await tantivyCommands.isEmbeddingModelDownloaded();
+    if (downloaded.status === "ok" && downloaded.data) {
+      setEnabled(true);
+      return;
+    }
+
+    const channel = new Channel<number>();
+    channel.onmessage = (value) => {
+      if (value >= 0) {
+        setProgress(value);
+      }
+    };
+
+    setProgress(0);
+    const result = await tantivyCommands.downloadEmbeddingModel(channel);
+    setProgress(null);
+
+    if (result.status === "error") {
+      setError(result.error);
+      return;
+    }
+
+    setEnabled(true);
+  };
+
+  const isDownloading = progress !== null;
+
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div className="flex-1">
+        <h3 className="text-sm font-medium mb-1">Semantic Search</h3>
+        <p className="text-xs text-neutral-600">
+          {isDownloading
+            ? `Downloading embedding model... ${progress}%`
+            : (error ??
+              "Match notes by meaning as well as keywords. Downloads a small model on first use.")}
+        </p>
+      </div>
+      <Switch
+        checked={enabled}
+        disabled={isDownloading}
+        onCheckedChange={(checked) => void handleChange(checked)}
+      />
+    </div>
+  );
+}
@@ -0,0 +1,285 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { RotateCcwIcon, SendIcon, TrashIcon } from "lucide-react";
+import { useState } from "react";
+
+import {
+  commands as webhookCommands,
+  type DeliveryAttempt,
+  type WebhookEndpoint,
+  type WebhookEventType,
+} from "@hypr/plugin-webhook";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { Switch } from "@hypr/ui/components/ui/switch";
+import { cn } from "@hypr/utils";
+
+const EVENT_TYPES: { value: WebhookEventType; label: string }[] = [
+  { value: "recording.stopped", label: "Recording stopped" },
+  { value: "transcript.finalized", label: "Transcript finalized" },
+  { value: "summary.generated", label: "Summary generated" },
+];
+
+async function unwrap<T>(
+  promise: Promise<
+    { status: "ok"; data: T } | { status: "error"; error: string }
+  >,
+): Promise<T> {
+  const result = await promise;
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  return result.data;
+}
+
+export function WebhookSettings() {
+  const queryClient = useQueryClient();
+  const invalidate = () => {
+    void queryClient.invalidateQueries({ queryKey: ["webhook-endpoints"] });
+    void queryClient.invalidateQueries({ queryKey: ["webhook-dead-letters"] });
+  };
+
+  const endpoints = useQuery({
+    queryKey: ["webhook-endpoints"],
+    queryFn: () => unwrap(webhookCommands.listEndpoints()),
+  });
+
+  const deadLetters = useQuery({
+    queryKey: ["webhook-dead-letters"],
+    queryFn: () => unwrap(webhookCommands.listDeadLetters()),
+    refetchInterval: 30_000,
+  });
+
+  const retry = useMutation({
+    mutationFn: (deliveryId: string) =>
+      unwrap(webhookCommands.retryDeadLetter(deliveryId)),
+    onSettled: invalidate,
+  });
+
+  const clear = useMutation({
+    mutationFn: () => unwrap(webhookCommands.clearDeadLetters()),
+    onSettled: invalidate,
+  });
+
+  const endpointUrl = (id: string) =>
+    endpoints.data?.find((endpoint) => endpoint.id === id)?.url ?? id;
+
+  return (
+    <div className="flex flex-col gap-3">
+      <div>
+        <h3 className="text-sm font-medium mb-1">Webhooks</h3>
+        <p className="text-xs text-neutral-600">
+          POST signed recording, transcript and summary events to your own
+          endpoints.
+        </p>
+      </div>
+
+      <AddEndpointForm onAdded={invalidate} />
+
+      {endpoints.isError ? (
+        <p className="text-xs text-red-600">{endpoints.error.message}</p>
+      ) : (
+        endpoints.data &&
+        endpoints.data.length > 0 && (
+          <div className="flex flex-col divide-y divide-neutral-100 rounded-lg border border-neutral-200">
+            {endpoints.data.map((endpoint) => (
+              <EndpointRow
+                key={endpoint.id}
+                endpoint={endpoint}
+                onChanged={invalidate}
+              />
+            ))}
+          </div>
+        )
+      )}
+
+      {deadLetters.data && deadLetters.data.length > 0 && (
+        <div className="flex flex-col gap-2">
+          <div className="flex items-center justify-between gap-4">
+            <p className="text-xs font-medium">
+              Failed deliveries ({deadLetters.data.length})
+            </p>
+            <Button
+              variant="outline"
+              size="sm"
+              onClick={() => clear.mutate()}
+              disabled={clear.isPending}
+            >
+              Clear
+            </Button>
+          </div>
+          <div className="flex flex-col divide-y divide-neutral-100 rounded-lg border border-neutral-200">
+            {deadLetters.data.map((delivery) => (
+              <div
+                key={delivery.id}
+                className="flex items-center gap-2 px-3 py-2 text-xs"
+              >
+                <div className="flex-1 min-w-0">
+                  <div className="truncate">
+                    <span className="font-mono">{delivery.event_type}</span>
+                    <span className="text-neutral-400">
+                      {" → "}
+                      {endpointUrl(delivery.endpoint_id)}
+                    </span>
+                  </div>
+                  {delivery.last_error && (
+                    <div className="text-red-600 truncate">
+                      {delivery.last_error}
+                    </div>
+                  )}
+                </div>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onClick={() => retry.mutate(delivery.id)}
+                  disabled={retry.isPending}
+                >
+                  <RotateCcwIcon className="h-3.5 w-3.5" />
+                </Button>
+              </div>
+            ))}
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
+
+function AddEndpointForm({ onAdded }: { onAdded: () => void }) {
+  const [url, setUrl] = useState("");
+  const [events, setEvents] = useState<WebhookEventType[]>(
+    EVENT_TYPES.map((type) => type.value),
+  );
+
+  const add = useMutation({
+    mutationFn: () => unwrap(webhookCommands.addEndpoint(url.trim(), events)),
+    onSuccess: () => {
+      setUrl("");
+      onAdded();
+    },
+  });
+
+  const toggleEvent = (event: WebhookEventType) => {
+    setEvents((current) =>
+      current.includes(event)
+        ? current.filter((e) => e !== event)
+        : [...current, event],
+    );
+  };
+
+  return (
+    <div className="flex flex-col gap-2">
+      <div className="flex items-center gap-2">
+        <Input
+          value={url}
+          onChange={(e) => setUrl(e.target.value)}
+          placeholder="https://example.com/webhooks/char"
+          className="h-8 text-sm"
+        />
+        <Button
+          size="sm"
+          variant="outline"
+          onClick={() => add.mutate()}
+          disabled={!url.trim() || events.length === 0 || add.isPending}
+        >
+          Add
+        </Button>
+      </div>
+      <div className="flex flex-wrap gap-1.5">
+        {EVENT_TYPES.map((type) => (
+          <button
+            key={type.value}
+            onClick={() => toggleEvent(type.value)}
+            className={cn([
+              "px-2 py-0.5 rounded-md text-xs border transition-colors",
+              events.includes(type.value)
+                ? "bg-neutral-900 text-white border-neutral-900"
+                : "bg-white text-neutral-600 border-neutral-200",
+            ])}
+          >
+            {type.label}
+          </button>
+        ))}
+      </div>
+      {add.isError && (
+        <p className="text-xs text-red-600">{add.error.message}</p>
+      )}
+    </div>
+  );
+}
+
+function EndpointRow({
+  endpoint,
+  onChanged,
+}: {
+  endpoint: WebhookEndpoint;
+  onChanged: () => void;
+}) {
+  const [lastTest, setLastTest] = useState<DeliveryAttempt | null>(null);
+
+  const update = useMutation({
+    mutationFn: (enabled: boolean) =>
+      unwrap(
+        webhookCommands.updateEndpoint(endpoint.id, {
+          url: null,
+          events: null,
+          enabled,
+        }),
+      ),
+    onSettled: onChanged,
+  });
+
+  const remove = useMutation({
+    mutationFn: () => unwrap(webhookCommands.removeEndpoint(endpoint.id)),
+    onSettled: onChanged,
+  });
+
+  const test = useMutation({
+    mutationFn: () => unwrap(webhookCommands.sendTestEvent(endpoint.id)),
+    onSuccess: setLastTest,
+  });
+
+  return (
+    <div className="flex flex-col gap-1 px-3 py-2 text-xs">
+      <div className="flex items-center gap-2">
+        <span className="font-mono truncate flex-1">{endpoint.url}</span>
+        <Button
+          variant="ghost"
+          size="sm"
+          onClick={() => test.mutate()}
+          disabled={test.isPending}
+        >
+          <SendIcon className="h-3.5 w-3.5" />
+        </Button>
+        <Button
+          variant="ghost"
+          size="sm"
+          onClick={() => remove.mutate()}
+          disabled={remove.isPending}
+        >
+          <TrashIcon className="h-3.5 w-3.5" />
+        </Button>
+        <Switch
+          checked={endpoint.enabled}
+          disabled={update.isPending}
+          onCheckedChange={(checked) => update.mutate(checked)}
+        />
+      </div>
+      <div className="text-neutral-500">
+        {endpoint.events.join(", ")} · secret{" "}
+        <span className="font-mono select-all">{endpoint.secret}</span>
+      </div>
+      {test.isError ? (
+        <div className="text-red-600">{test.error.message}</div>
+      ) : (
+        lastTest && (
+          <div className={lastTest.error ? "text-red-600" : "text-green-600"}>
+            {lastTest.error
+              ? `Test failed: ${lastTest.error}`
+              : `Test delivered (HTTP ${lastTest.status}) in ` +
+                `${lastTest.duration_ms}ms`}
+          </div>
+        )
+      )}
+    </div>
+  );
+}
@@ -23,7 +23,11 @@ export type ConfigKey =
   | "current_llm_provider"
   | "current_llm_model"
   | "timezone"
-  | "week_start";
+  | "week_start"
+  | "pdf_company_name"
+  | "pdf_accent_color"
+  | "semantic_search"
+  | "transcript_vocabulary";
 
 type ConfigValueType<K extends ConfigKey> =
   (typeof CONFIG_REGISTRY)[K]["default"];
@@ -163,4 +167,24 @@ export const CONFIG_REGISTRY = {
     key: "week_start",
     default: undefined as "sunday" | "monday" | undefined,
   },
+
+  pdf_company_name: {
+    key: "pdf_company_name",
+    default: undefined as string | undefined,
+  },
+
+  pdf_accent_color: {
+    key: "pdf_accent_color",
+    default: undefined as string | undefined,
+  },
+
+  semantic_search: {
+    key: "semantic_search",
+    default: false,
+  },
+
+  transcript_vocabulary: {
+    key: "transcript_vocabulary",
+    default: [] as string[],
+  },
 } satisfies Record<ConfigKey, ConfigDefinition>;
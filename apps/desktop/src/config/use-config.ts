@@ -5,6 +5,12 @@ import { CONFIG_REGISTRY, type ConfigKey } from "./registry";
 type ConfigValueType<K extends ConfigKey> =
   (typeof CONFIG_REGISTRY)[K]["default"];
 
+const JSON_ARRAY_KEYS = new Set<ConfigKey>([
+  "ignored_platforms",
+  "spoken_languages",
+  "transcript_vocabulary",
+]);
+
 function tryParseJSON<T>(value: any, fallback: T): T {
   if (typeof value !== "string") {
     return value;
@@ -23,7 +29,7 @@ export function useConfigValue<K extends ConfigKey>(
   const definition = CONFIG_REGISTRY[key];
 
   if (storedValue !== undefined) {
-    if (key === "ignored_platforms" || key === "spoken_languages") {
+    if (JSON_ARRAY_KEYS.has(key)) {
       return tryParseJSON(
         storedValue,
         definition.default,
@@ -47,7 +53,7 @@ export function useConfigValues<K extends ConfigKey>(
     const definition = CONFIG_REGISTRY[key];
 
     if (storedValue !== undefined) {
-      if (key === "ignored_platforms" || key === "spoken_languages") {
+      if (JSON_ARRAY_KEYS.has(key)) {
         result[key] = tryParseJSON(
           storedValue,
           definition.default,
@@ -75,7 +81,7 @@ export function useConfigSideEffects() {
         const val = configs[k];
 
         if (val !== undefined) {
-          if (k === "ignored_platforms" || k === "spoken_languages") {
+          if (JSON_ARRAY_KEYS.has(k)) {
             return tryParseJSON(val, def.default) as ConfigValueType<K>;
           }
           return val as ConfigValueType<K>;
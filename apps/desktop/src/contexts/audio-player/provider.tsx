@@ -11,6 +11,8 @@ import WaveSurfer from "wavesurfer.js";
 
 import { commands as fsSyncCommands } from "@hypr/plugin-fs-sync";
 
+import { useAudioSeek } from "../../store/zustand/audio-seek";
+
 type AudioPlayerState = "playing" | "paused" | "stopped";
 
 interface AudioPlayerContextValue {
@@ -200,6 +202,27 @@ export function AudioPlayerProvider({
     [wavesurfer],
   );
 
+  const pendingSeekMs = useAudioSeek((s) => s.pendingMs[sessionId]);
+  const clearSeek = useAudioSeek((s) => s.clearSeek);
+
+  useEffect(() => {
+    if (!wavesurfer || pendingSeekMs === undefined) {
+      return;
+    }
+
+    const apply = () => {
+      wavesurfer.setTime(pendingSeekMs / 1000);
+      clearSeek(sessionId);
+    };
+
+    if (wavesurfer.getDuration() > 0) {
+      apply();
+      return;
+    }
+
+    return wavesurfer.once("ready", apply);
+  }, [wavesurfer, pendingSeekMs, clearSeek, sessionId]);
+
   return (
     <AudioPlayerContext.Provider
       value={{
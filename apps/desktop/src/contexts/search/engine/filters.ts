@@ -4,20 +4,25 @@ import type { SearchFilters } from "./types";
 
 export function buildTantivyFilters(
   filters: SearchFilters | null,
+  docType: string | null = null,
 ): TantivySearchFilters | undefined {
-  if (!filters || !filters.created_at) {
+  if (!docType && (!filters || !filters.created_at)) {
     return undefined;
   }
 
+  const createdAt = filters?.created_at;
+
   return {
-    created_at: {
-      gte: filters.created_at.gte ?? null,
-      lte: filters.created_at.lte ?? null,
-      gt: filters.created_at.gt ?? null,
-      lt: filters.created_at.lt ?? null,
-      eq: filters.created_at.eq ?? null,
-    },
-    doc_type: null,
+    created_at: createdAt
+      ? {
+          gte: createdAt.gte ?? null,
+          lte: createdAt.lte ?? null,
+          gt: createdAt.gt ?? null,
+          lt: createdAt.lt ?? null,
+          eq: createdAt.eq ?? null,
+        }
+      : null,
+    doc_type: docType,
     facet: null,
   };
 }
@@ -9,15 +9,28 @@ import {
 
 import { commands as tantivy } from "@hypr/plugin-tantivy";
 
+import { useConfigValue } from "../../../config/use-config";
 import { type Store as MainStore } from "../../../store/tinybase/store/main";
 import { buildTantivyFilters } from "./filters";
-import { indexHumans, indexOrganizations, indexSessions } from "./indexing";
+import {
+  indexHumans,
+  indexOrganizations,
+  indexSessions,
+  indexTranscripts,
+} from "./indexing";
 import {
   createHumanListener,
   createOrganizationListener,
   createSessionListener,
+  createTranscriptListener,
 } from "./listeners";
-import type { SearchEntityType, SearchFilters, SearchHit } from "./types";
+import { TRANSCRIPT_SEGMENT_DOC_TYPE } from "./transcript";
+import type {
+  SearchEntityType,
+  SearchFilters,
+  SearchHit,
+  TranscriptMatch,
+} from "./types";
 import { normalizeQuery } from "./utils";
 
 export type {
@@ -25,6 +38,8 @@ export type {
   SearchEntityType,
   SearchFilters,
   SearchHit,
+  TranscriptMatch,
+  TranscriptMoment,
 } from "./types";
 
 const SearchEngineContext = createContext<{
@@ -32,6 +47,10 @@ const SearchEngineContext = createContext<{
     query: string,
     filters?: SearchFilters | null,
   ) => Promise<SearchHit[]>;
+  searchTranscripts: (
+    query: string,
+    filters?: SearchFilters | null,
+  ) => Promise<TranscriptMatch[]>;
   isIndexing: boolean;
 } | null>(null);
 
@@ -42,6 +61,7 @@ export function SearchEngineProvider({
   children: React.ReactNode;
   store?: MainStore;
 }) {
+  const semanticSearch = useConfigValue("semantic_search");
   const [isIndexing, setIsIndexing] = useState(true);
   const listenerIds = useRef<string[]>([]);
 
@@ -57,6 +77,7 @@ export function SearchEngineProvider({
         await indexSessions(store);
         await indexHumans(store);
         await indexOrganizations(store);
+        await indexTranscripts(store);
 
         const listener1 = store.addRowListener(
           "sessions",
@@ -74,7 +95,13 @@ export function SearchEngineProvider({
           createOrganizationListener(),
         );
 
-        listenerIds.current = [listener1, listener2, listener3];
+        const listener4 = store.addRowListener(
+          "transcripts",
+          null,
+          createTranscriptListener(),
+        );
+
+        listenerIds.current = [listener1, listener2, listener3, listener4];
       } catch (error) {
         console.error("Failed to create search index:", error);
       } finally {
@@ -90,7 +117,7 @@ export function SearchEngineProvider({
       });
       listenerIds.current = [];
     };
-  }, [store]);
+  }, [store, semanticSearch]);
 
   const search = useCallback(
     async (
@@ -104,6 +131,7 @@ export function SearchEngineProvider({
         const result = await tantivy.search({
           query: normalizedQuery,
           filters: tantivyFilters,
+          mode: semanticSearch ? "hybrid" : "keyword",
         });
 
         if (result.status === "error") {
@@ -126,11 +154,71 @@ export function SearchEngineProvider({
         return [];
       }
     },
-    [],
+    [semanticSearch],
+  );
+
+  const searchTranscripts = useCallback(
+    async (
+      query: string,
+      filters: SearchFilters | null = null,
+    ): Promise<TranscriptMatch[]> => {
+      const normalizedQuery = normalizeQuery(query);
+      const tantivyFilters = buildTantivyFilters(
+        filters,
+        TRANSCRIPT_SEGMENT_DOC_TYPE,
+      );
+
+      try {
+        const result = await tantivy.search({
+          query: normalizedQuery,
+          filters: tantivyFilters,
+          mode: semanticSearch ? "hybrid" : "keyword",
+          options: {
+            fuzzy: null,
+            distance: null,
+            snippets: null,
+            snippet_max_chars: null,
+            phrase_slop: null,
+            group_segments: true,
+          },
+        });
+
+        if (result.status === "error") {
+          console.error("Transcript search failed:", result.error);
+          return [];
+        }
+
+        return result.data.hits.flatMap((hit) => {
+          const sessionId = hit.segments?.[0]?.session_id;
+          if (!sessionId) {
+            return [];
+          }
+
+          return [
+            {
+              sessionId,
+              title: hit.document.title,
+              moments: (hit.segments ?? [])
+                .map(({ start_ms, end_ms, speaker }) => ({
+                  start_ms,
+                  end_ms,
+                  speaker,
+                }))
+                .sort((a, b) => a.start_ms - b.start_ms),
+            },
+          ];
+        });
+      } catch (error) {
+        console.error("Transcript search failed:", error);
+        return [];
+      }
+    },
+    [semanticSearch],
   );
 
   const value = {
     search,
+    searchTranscripts,
     isIndexing,
   };
 
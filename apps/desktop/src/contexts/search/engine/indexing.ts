@@ -5,6 +5,7 @@ import {
   createHumanSearchableContent,
   createSessionSearchableContent,
 } from "./content";
+import { indexSessionTranscript } from "./transcript";
 import {
   collectCells,
   collectEnhancedNotesContent,
@@ -103,3 +104,18 @@ export async function indexOrganizations(store: MainStore): Promise<void> {
     await tantivy.updateDocuments(documents, null);
   }
 }
+
+export async function indexTranscripts(store: MainStore): Promise<void> {
+  const sessionIds = new Set<string>();
+
+  store.forEachRow("transcripts", (rowId: string, _forEachCell) => {
+    const sessionId = store.getCell("transcripts", rowId, "session_id");
+    if (typeof sessionId === "string" && sessionId) {
+      sessionIds.add(sessionId);
+    }
+  });
+
+  for (const sessionId of sessionIds) {
+    await indexSessionTranscript(store, sessionId);
+  }
+}
@@ -8,6 +8,7 @@ import {
   createHumanSearchableContent,
   createSessionSearchableContent,
 } from "./content";
+import { indexSessionTranscript } from "./transcript";
 import {
   collectCells,
   collectEnhancedNotesContent,
@@ -27,6 +28,7 @@ export function createSessionListener(): RowListener<
 
       if (!rowExists) {
         void tantivy.removeDocument(rowId, null);
+        void tantivy.removeTranscript(rowId, null);
       } else {
         const fields = [
           "user_id",
@@ -129,3 +131,33 @@ export function createOrganizationListener(): RowListener<
     }
   };
 }
+
+// Words stream in while listening; re-index a session once it goes quiet.
+const TRANSCRIPT_REINDEX_DELAY_MS = 5000;
+
+export function createTranscriptListener(): RowListener<
+  Schemas,
+  "transcripts",
+  null,
+  MainStore
+> {
+  const pending = new Map<string, ReturnType<typeof setTimeout>>();
+
+  return (store, _, rowId) => {
+    const sessionId = store.getCell("transcripts", rowId, "session_id");
+    if (typeof sessionId !== "string" || !sessionId) {
+      return;
+    }
+
+    clearTimeout(pending.get(sessionId));
+    pending.set(
+      sessionId,
+      setTimeout(() => {
+        pending.delete(sessionId);
+        indexSessionTranscript(store, sessionId).catch((error) => {
+          console.error("Failed to update transcript in search index:", error);
+        });
+      }, TRANSCRIPT_REINDEX_DELAY_MS),
+    );
+  };
+}
@@ -0,0 +1,29 @@
+import { commands as tantivy } from "@hypr/plugin-tantivy";
+
+import { type Store as MainStore } from "../../../store/tinybase/store/main";
+import { collectLabelledSegments } from "../../../store/transcript/labelled";
+import { toEpochMs, toTrimmedString } from "./utils";
+
+// Mirrors `TRANSCRIPT_SEGMENT_DOC_TYPE` in the tantivy plugin.
+export const TRANSCRIPT_SEGMENT_DOC_TYPE = "transcript_segment";
+
+export async function indexSessionTranscript(
+  store: MainStore,
+  sessionId: string,
+): Promise<void> {
+  const words = collectLabelledSegments(store, sessionId).flatMap(
+    ({ speaker, words }) => words.map((w) => ({ ...w, speaker })),
+  );
+  if (words.length === 0) {
+    return;
+  }
+
+  const title =
+    toTrimmedString(store.getCell("sessions", sessionId, "title")) ||
+    "Untitled";
+  const createdAt = toEpochMs(
+    store.getCell("sessions", sessionId, "created_at"),
+  );
+
+  await tantivy.indexTranscript(sessionId, title, createdAt, words, null);
+}
@@ -33,3 +33,15 @@ export type SearchHit = {
   score: number;
   document: SearchDocument;
 };
+
+export type TranscriptMoment = {
+  start_ms: number;
+  end_ms: number;
+  speaker: string | null;
+};
+
+export type TranscriptMatch = {
+  sessionId: string;
+  title: string;
+  moments: TranscriptMoment[];
+};
@@ -5,6 +5,7 @@ import { md2json } from "@hypr/tiptap/shared";
 
 import { useAITask } from "../../contexts/ai-task";
 import { useListener } from "../../contexts/listener";
+import { emitEnhancedNoteGenerated } from "../../services/hook-events";
 import * as main from "../../store/tinybase/store/main";
 import * as settings from "../../store/tinybase/store/settings";
 import { createTaskId } from "../../store/zustand/ai-task/task-configs";
@@ -94,6 +95,7 @@ export function useAutoEnhanceRunner(
         store.setPartialRow("enhanced_notes", noteId, {
           content: JSON.stringify(jsonContent),
         });
+        void emitEnhancedNoteGenerated(store, sessionId, noteId);
 
         const currentTitle = store.getCell("sessions", sessionId, "title");
         const trimmedTitle =
@@ -4,6 +4,7 @@ import type { BatchParams } from "@hypr/plugin-listener2";
 
 import { useConfigValue } from "../config/use-config";
 import { useListener } from "../contexts/listener";
+import { emitTranscriptFinalized } from "../services/hook-events";
 import * as main from "../store/tinybase/store/main";
 import type { SpeakerHintWithId, WordWithId } from "../store/transcript/types";
 import {
@@ -177,6 +178,7 @@ export const useRunBatch = (sessionId: string) => {
       };
 
       await runBatch(params, { handlePersist: persist, sessionId });
+      void emitTranscriptFinalized(store, sessionId);
     },
     [
       conn,
@@ -12,9 +12,11 @@ import {
   updateTranscriptHints,
   updateTranscriptWords,
 } from "../store/transcript/utils";
+import type { HandleSpeakerIdentifiedCallback } from "../store/zustand/listener/general";
 import type { HandlePersistCallback } from "../store/zustand/listener/transcript";
 import { id } from "../utils";
 import { getSessionEventById } from "../utils/session-event";
+import { findFirstWordOfSpeaker } from "../utils/speaker-hints";
 import { useKeywords } from "./useKeywords";
 import { useSTTConnection } from "./useSTTConnection";
 
@@ -119,6 +121,35 @@ export function useStartListening(sessionId: string) {
       });
     };
 
+    // A recognized voice is attributed like a manual assignment on the
+    // speaker's first word; later words of the speaker inherit it.
+    const handleSpeakerIdentified: HandleSpeakerIdentifiedCallback = (
+      speaker,
+      humanId,
+    ) => {
+      if (!store.hasRow("humans", humanId)) {
+        return;
+      }
+
+      store.transaction(() => {
+        const existingHints = parseTranscriptHints(store, transcriptId);
+        const wordId = findFirstWordOfSpeaker(existingHints, speaker);
+        if (!wordId) {
+          return;
+        }
+
+        updateTranscriptHints(store, transcriptId, [
+          ...existingHints,
+          {
+            id: id(),
+            word_id: wordId,
+            type: "user_speaker_assignment",
+            value: JSON.stringify({ human_id: humanId }),
+          },
+        ]);
+      });
+    };
+
     start(
       {
         session_id: sessionId,
@@ -132,6 +163,7 @@ export function useStartListening(sessionId: string) {
       },
       {
         handlePersist,
+        handleSpeakerIdentified,
       },
     );
   }, [
@@ -8,6 +8,7 @@ import { format, TZDate } from "@hypr/utils";
 import type * as main from "../../store/tinybase/store/main";
 import type * as settings from "../../store/tinybase/store/settings";
 import { findSessionByEventId } from "../../utils/session-event";
+import { emitHookEvent, getSessionParticipants } from "../hook-events";
 
 export const EVENT_NOTIFICATION_TASK_ID = "eventNotification";
 export const EVENT_NOTIFICATION_INTERVAL = 30 * 1000; // 30 sec
@@ -48,11 +49,13 @@ export function checkEventNotifications(
   settingsStore: settings.Store,
   notifiedEvents: NotifiedEventsMap,
 ) {
-  const notificationEnabled = settingsStore?.getValue("notification_event");
-  if (!notificationEnabled || !store) {
+  if (!store) {
     return;
   }
 
+  // Hooks still run for upcoming events when notifications are turned off.
+  const notificationEnabled = settingsStore?.getValue("notification_event");
+
   const now = Date.now();
 
   for (const [key, timestamp] of notifiedEvents) {
@@ -128,6 +131,28 @@ export function checkEventNotifications(
 
       notifiedEvents.set(notificationKey, now);
 
+      const sessionId = findSessionByEventId(store, eventId, timezone);
+
+      emitHookEvent({
+        calendarEventStarting: {
+          args: {
+            event_id: eventId,
+            title: String(event.title ?? ""),
+            started_at: String(event.started_at),
+            ended_at: String(event.ended_at ?? ""),
+            participants: sessionId
+              ? getSessionParticipants(store, sessionId)
+              : [],
+            meeting_link: (event.meeting_link as string) || null,
+            session_id: sessionId ?? null,
+          },
+        },
+      });
+
+      if (!notificationEnabled) {
+        return;
+      }
+
       const title = String(event.title || "Upcoming Event");
       const minutesUntil = Math.ceil(timeUntilStart / 60000);
 
@@ -139,7 +164,6 @@ export function checkEventNotifications(
       };
 
       let participants: Participant[] | null = null;
-      const sessionId = findSessionByEventId(store, eventId, timezone);
       if (sessionId) {
         const sessionParticipants = getParticipantsForSession(store, sessionId);
         if (sessionParticipants.length > 0) {
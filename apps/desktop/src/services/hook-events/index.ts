@@ -0,0 +1,197 @@
+import { sep } from "@tauri-apps/api/path";
+
+import {
+  commands as hooksCommands,
+  type HookEvent,
+  type HookParticipant,
+} from "@hypr/plugin-hooks";
+import {
+  commands as webhookCommands,
+  type WebhookContent,
+  type WebhookTranscriptSegment,
+} from "@hypr/plugin-webhook";
+
+import {
+  buildSessionPath,
+  getDataDir,
+  SESSION_TRANSCRIPT_FILE,
+} from "../../store/tinybase/persister/shared/paths";
+import {
+  getEnhancedNoteFilename,
+  tryParseAndConvertToMarkdown,
+} from "../../store/tinybase/persister/session/save/note";
+import type * as main from "../../store/tinybase/store/main";
+import { collectLabelledSegments } from "../../store/transcript/labelled";
+
+export function emitHookEvent(event: HookEvent): void {
+  void hooksCommands.runEventHooks(event).catch((error) => {
+    console.error(`[hooks] ${Object.keys(event)[0]} failed:`, error);
+  });
+}
+
+// Hook events with a webhook counterpart also go to subscribed endpoints,
+// carrying the content the hook only references by path.
+function emitHookAndWebhookEvent(
+  event: HookEvent,
+  content: WebhookContent,
+): void {
+  emitHookEvent(event);
+  void webhookCommands.dispatchEvent(event, content).catch((error) => {
+    console.error(`[webhook] ${Object.keys(event)[0]} failed:`, error);
+  });
+}
+
+function getTranscriptSegments(
+  store: main.Store,
+  sessionId: string,
+): WebhookTranscriptSegment[] {
+  return collectLabelledSegments(store, sessionId)
+    .filter(({ words }) => words.length > 0)
+    .map(({ speaker, words }) => ({
+      speaker: speaker || null,
+      text: words
+        .map((w) => w.text)
+        .join(" ")
+        .replace(/\s+/g, " ")
+        .trim(),
+      start_ms: words[0].start_ms,
+      end_ms: words[words.length - 1].end_ms,
+    }));
+}
+
+export function getSessionParticipants(
+  store: main.Store,
+  sessionId: string,
+): HookParticipant[] {
+  const participants: HookParticipant[] = [];
+
+  store.forEachRow("mapping_session_participant", (mappingId, _forEachCell) => {
+    const mapping = store.getRow("mapping_session_participant", mappingId);
+    if (mapping?.session_id !== sessionId || !mapping.human_id) return;
+
+    const human = store.getRow("humans", mapping.human_id);
+    participants.push({
+      human_id: mapping.human_id,
+      name: human?.name || "",
+      email: human?.email || null,
+    });
+  });
+
+  return participants;
+}
+
+function getSessionTags(store: main.Store, sessionId: string): string[] {
+  const tags: string[] = [];
+
+  store.forEachRow("mapping_tag_session", (mappingId, _forEachCell) => {
+    const mapping = store.getRow("mapping_tag_session", mappingId);
+    if (mapping?.session_id !== sessionId || !mapping.tag_id) return;
+
+    const name = store.getCell("tags", mapping.tag_id, "name");
+    if (name) tags.push(name);
+  });
+
+  return tags;
+}
+
+async function getSessionContext(store: main.Store, sessionId: string) {
+  const dataDir = await getDataDir();
+  const folderId = store.getCell("sessions", sessionId, "folder_id") ?? "";
+
+  return {
+    session_id: sessionId,
+    title: store.getCell("sessions", sessionId, "title") || null,
+    tags: getSessionTags(store, sessionId),
+    resource_dir: buildSessionPath(dataDir, sessionId, folderId),
+  };
+}
+
+export async function emitSessionCreated(
+  store: main.Store,
+  sessionId: string,
+): Promise<void> {
+  const context = await getSessionContext(store, sessionId);
+  emitHookEvent({ sessionCreated: { args: context } });
+}
+
+export function emitSessionDeleted(
+  sessionId: string,
+  title: string | null,
+): void {
+  emitHookEvent({
+    sessionDeleted: { args: { session_id: sessionId, title } },
+  });
+}
+
+export async function emitTranscriptFinalized(
+  store: main.Store,
+  sessionId: string,
+): Promise<void> {
+  const context = await getSessionContext(store, sessionId);
+  emitHookAndWebhookEvent(
+    {
+      transcriptFinalized: {
+        args: {
+          ...context,
+          participants: getSessionParticipants(store, sessionId),
+          transcript_path: [context.resource_dir, SESSION_TRANSCRIPT_FILE].join(
+            sep(),
+          ),
+        },
+      },
+    },
+    { transcript: getTranscriptSegments(store, sessionId) },
+  );
+}
+
+export async function emitEnhancedNoteGenerated(
+  store: main.Store,
+  sessionId: string,
+  enhancedNoteId: string,
+): Promise<void> {
+  const context = await getSessionContext(store, sessionId);
+  const templateId =
+    store.getCell("enhanced_notes", enhancedNoteId, "template_id") || null;
+  const content = store.getCell("enhanced_notes", enhancedNoteId, "content");
+
+  emitHookAndWebhookEvent(
+    {
+      enhancedNoteGenerated: {
+        args: {
+          ...context,
+          participants: getSessionParticipants(store, sessionId),
+          note_path: [
+            context.resource_dir,
+            getEnhancedNoteFilename(store, { template_id: templateId }),
+          ].join(sep()),
+          template_id: templateId,
+        },
+      },
+    },
+    {
+      note_markdown: content
+        ? (tryParseAndConvertToMarkdown(content) ?? null)
+        : null,
+      transcript: getTranscriptSegments(store, sessionId),
+    },
+  );
+}
+
+export function emitExportCompleted(
+  store: main.Store,
+  sessionId: string,
+  format: string,
+  outputPath: string,
+): void {
+  emitHookEvent({
+    exportCompleted: {
+      args: {
+        session_id: sessionId,
+        title: store.getCell("sessions", sessionId, "title") || null,
+        tags: getSessionTags(store, sessionId),
+        format,
+        output_path: outputPath,
+      },
+    },
+  });
+}
@@ -125,7 +125,7 @@ function buildOperations(
   return operations;
 }
 
-function tryParseAndConvertToMarkdown(content: string): string | undefined {
+export function tryParseAndConvertToMarkdown(content: string): string | undefined {
   let parsed: unknown;
   try {
     parsed = JSON.parse(content);
@@ -140,9 +140,9 @@ function tryParseAndConvertToMarkdown(content: string): string | undefined {
   return json2md(parsed);
 }
 
-function getEnhancedNoteFilename(
+export function getEnhancedNoteFilename(
   store: Store,
-  enhancedNote: ReturnType<typeof iterateTableRows<"enhanced_notes">>[number],
+  enhancedNote: { template_id?: string | null },
 ): string {
   if (enhancedNote.template_id) {
     const templateTitle = store.getCell(
@@ -10,6 +10,7 @@ const JSON_ARRAY_FIELDS = new Set([
   "spoken_languages",
   "ignored_platforms",
   "ignored_recurring_series",
+  "transcript_vocabulary",
 ]);
 
 function getByPath(obj: unknown, path: readonly [string, string]): unknown {
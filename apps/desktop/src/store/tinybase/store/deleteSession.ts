@@ -1,5 +1,6 @@
 import { commands as fsSyncCommands } from "@hypr/plugin-fs-sync";
 
+import { emitSessionDeleted } from "../../../services/hook-events";
 import type { DeletedSessionData } from "../../zustand/undo-delete";
 import * as main from "./main";
 
@@ -193,6 +194,8 @@ export function deleteSessionCascade(
   sessionId: string,
   options?: { skipAudio?: boolean },
 ): void {
+  const title = store.getCell("sessions", sessionId, "title") || null;
+
   if (!indexes) {
     store.delRow("sessions", sessionId);
   } else {
@@ -235,4 +238,6 @@ export function deleteSessionCascade(
   if (!options?.skipAudio) {
     void fsSyncCommands.audioDelete(sessionId);
   }
+
+  emitSessionDeleted(sessionId, title);
 }
@@ -8,6 +8,7 @@ import type {
 } from "@hypr/store";
 import { json2md } from "@hypr/tiptap/shared";
 
+import { emitSessionCreated } from "../../../services/hook-events";
 import { DEFAULT_USER_ID } from "../../../utils";
 import { id } from "../../../utils";
 import { findSessionByEventId } from "../../../utils/session-event";
@@ -27,6 +28,7 @@ export function createSession(store: Store, title?: string): string {
     event: "note_created",
     has_event_id: false,
   });
+  void emitSessionCreated(store, sessionId);
   return sessionId;
 }
 
@@ -80,6 +82,7 @@ export function getOrCreateSessionForEventId(
     event: "note_created",
     has_event_id: true,
   });
+  void emitSessionCreated(store, sessionId);
   return sessionId;
 }
 
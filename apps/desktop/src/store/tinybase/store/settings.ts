@@ -81,6 +81,22 @@ export const SETTINGS_MAPPING = {
       type: "string",
       path: ["general", "week_start"],
     },
+    pdf_company_name: {
+      type: "string",
+      path: ["export", "pdf_company_name"],
+    },
+    pdf_accent_color: {
+      type: "string",
+      path: ["export", "pdf_accent_color"],
+    },
+    semantic_search: {
+      type: "boolean",
+      path: ["search", "semantic_search"],
+    },
+    transcript_vocabulary: {
+      type: "string",
+      path: ["transcription", "vocabulary"],
+    },
     selected_template_id: {
       type: "string",
       path: ["general", "selected_template_id"],
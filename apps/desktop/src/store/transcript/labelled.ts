@@ -0,0 +1,97 @@
+import type { Store as MainStore } from "../tinybase/store/main";
+import { buildSegments, SegmentKey } from "../../utils/segment";
+import {
+  defaultRenderLabelContext,
+  SpeakerLabelManager,
+} from "../../utils/segment/shared";
+import { convertStorageHintsToRuntime } from "../../utils/speaker-hints";
+import { parseTranscriptHints, parseTranscriptWords } from "./utils";
+
+export type LabelledWord = {
+  text: string;
+  start_ms: number;
+  end_ms: number;
+  channel: number;
+};
+
+export type LabelledSegment = {
+  speaker: string;
+  words: LabelledWord[];
+};
+
+function getTranscriptIds(store: MainStore, sessionId: string): string[] {
+  const ids: string[] = [];
+  store.forEachRow("transcripts", (rowId, _forEachCell) => {
+    if (store.getCell("transcripts", rowId, "session_id") === sessionId) {
+      ids.push(rowId);
+    }
+  });
+
+  return ids.sort(
+    (a, b) =>
+      Number(store.getCell("transcripts", a, "started_at") ?? 0) -
+      Number(store.getCell("transcripts", b, "started_at") ?? 0),
+  );
+}
+
+// Finalized words of a session on one timeline, grouped and labelled with the
+// same speaker names the transcript view renders.
+export function collectLabelledSegments(
+  store: MainStore,
+  sessionId: string,
+): LabelledSegment[] {
+  const transcriptIds = getTranscriptIds(store, sessionId);
+  if (transcriptIds.length === 0) {
+    return [];
+  }
+
+  const wordIdToIndex = new Map<string, number>();
+  const collectedWords: Array<LabelledWord & { id: string }> = [];
+
+  const firstStartedAt = store.getCell(
+    "transcripts",
+    transcriptIds[0],
+    "started_at",
+  );
+
+  for (const transcriptId of transcriptIds) {
+    const startedAt = store.getCell("transcripts", transcriptId, "started_at");
+    const offset =
+      typeof startedAt === "number" && typeof firstStartedAt === "number"
+        ? startedAt - firstStartedAt
+        : 0;
+
+    for (const word of parseTranscriptWords(store, transcriptId)) {
+      if (word.text === undefined || word.start_ms === undefined) continue;
+      collectedWords.push({
+        id: word.id,
+        text: word.text,
+        start_ms: word.start_ms + offset,
+        end_ms: (word.end_ms ?? word.start_ms) + offset,
+        channel: word.channel ?? 0,
+      });
+    }
+  }
+
+  collectedWords.sort((a, b) => a.start_ms - b.start_ms);
+  collectedWords.forEach((w, i) => wordIdToIndex.set(w.id, i));
+
+  const speakerHints = convertStorageHintsToRuntime(
+    transcriptIds.flatMap((id) => parseTranscriptHints(store, id)),
+    wordIdToIndex,
+  );
+
+  const segments = buildSegments(collectedWords, [], speakerHints);
+  const ctx = defaultRenderLabelContext(store);
+  const manager = SpeakerLabelManager.fromSegments(segments, ctx);
+
+  return segments.map((segment) => ({
+    speaker: SegmentKey.renderLabel(segment.key, ctx, manager),
+    words: segment.words.map((w) => ({
+      text: w.text,
+      start_ms: w.start_ms,
+      end_ms: w.end_ms,
+      channel: w.channel,
+    })),
+  }));
+}
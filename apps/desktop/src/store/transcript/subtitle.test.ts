@@ -0,0 +1,26 @@
+import { describe, expect, test } from "vitest";
+
+import { subtitleTokensToWords } from "./subtitle";
+
+describe("subtitleTokensToWords", () => {
+  test("splits each cue into timed words with its speaker", () => {
+    const words = subtitleTokensToWords([
+      { text: "Hi there", start_time: 0, end_time: 700, speaker: "Alice" },
+      { text: "Ok", start_time: 1000, end_time: 1400, speaker: null },
+    ]);
+
+    expect(words).toEqual([
+      { text: " Hi", start_ms: 0, end_ms: 200, speaker: "Alice" },
+      { text: " there", start_ms: 200, end_ms: 700, speaker: "Alice" },
+      { text: " Ok", start_ms: 1000, end_ms: 1400, speaker: null },
+    ]);
+  });
+
+  test("skips empty cues", () => {
+    expect(
+      subtitleTokensToWords([
+        { text: "  ", start_time: 0, end_time: 500, speaker: null },
+      ]),
+    ).toEqual([]);
+  });
+});
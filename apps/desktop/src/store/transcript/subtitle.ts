@@ -0,0 +1,28 @@
+import type { Token } from "@hypr/plugin-listener2";
+
+export type SubtitleWord = {
+  text: string;
+  start_ms: number;
+  end_ms: number;
+  speaker: string | null;
+};
+
+// Cues hold whole phrases; split them back into words and spread the cue's
+// time span over them by length, so re-imported exports keep word timing.
+export function subtitleTokensToWords(tokens: Token[]): SubtitleWord[] {
+  return tokens.flatMap((token) => {
+    const texts = token.text.split(/\s+/).filter(Boolean);
+    const totalChars = texts.reduce((sum, text) => sum + text.length, 0);
+    const duration = Math.max(token.end_time - token.start_time, 0);
+
+    let elapsed = 0;
+    return texts.map((text) => {
+      const start_ms =
+        token.start_time + Math.round((elapsed / totalChars) * duration);
+      elapsed += text.length;
+      const end_ms =
+        token.start_time + Math.round((elapsed / totalChars) * duration);
+      return { text: ` ${text}`, start_ms, end_ms, speaker: token.speaker };
+    });
+  });
+}
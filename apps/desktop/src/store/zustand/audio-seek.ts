@@ -0,0 +1,20 @@
+import { create } from "zustand";
+
+// Seeks requested from outside a session tab, such as a transcript search
+// hit, applied by the session's audio player once it is ready.
+interface AudioSeekState {
+  pendingMs: Record<string, number>;
+  requestSeek: (sessionId: string, ms: number) => void;
+  clearSeek: (sessionId: string) => void;
+}
+
+export const useAudioSeek = create<AudioSeekState>((set) => ({
+  pendingMs: {},
+  requestSeek: (sessionId, ms) =>
+    set((state) => ({ pendingMs: { ...state.pendingMs, [sessionId]: ms } })),
+  clearSeek: (sessionId) =>
+    set((state) => {
+      const { [sessionId]: _, ...rest } = state.pendingMs;
+      return { pendingMs: rest };
+    }),
+}));
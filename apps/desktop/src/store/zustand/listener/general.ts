@@ -4,6 +4,10 @@ import { create as mutate } from "mutative";
 import type { StoreApi } from "zustand";
 
 import { commands as detectCommands } from "@hypr/plugin-detect";
+import {
+  commands as extensionsCommands,
+  type JsonValue,
+} from "@hypr/plugin-extensions";
 import { commands as hooksCommands } from "@hypr/plugin-hooks";
 import { commands as iconCommands } from "@hypr/plugin-icon";
 import {
@@ -23,8 +27,10 @@ import {
   events as listener2Events,
 } from "@hypr/plugin-listener2";
 import { commands as settingsCommands } from "@hypr/plugin-settings";
+import { commands as webhookCommands } from "@hypr/plugin-webhook";
 
 import { fromResult } from "../../../effect";
+import type { LiveSpeaker } from "../../../utils/speaker-hints";
 import { buildSessionPath } from "../../tinybase/persister/shared/paths";
 import type { BatchActions, BatchState } from "./batch";
 import type { HandlePersistCallback, TranscriptActions } from "./transcript";
@@ -56,10 +62,18 @@ export type GeneralState = {
   };
 };
 
+export type HandleSpeakerIdentifiedCallback = (
+  speaker: LiveSpeaker,
+  humanId: string,
+) => void;
+
 export type GeneralActions = {
   start: (
     params: SessionParams,
-    options?: { handlePersist?: HandlePersistCallback },
+    options?: {
+      handlePersist?: HandlePersistCallback;
+      handleSpeakerIdentified?: HandleSpeakerIdentifiedCallback;
+    },
   ) => void;
   stop: () => void;
   setMuted: (value: boolean) => void;
@@ -314,12 +328,22 @@ export const createGeneralSlice = <
       } else if (payload.type === "stream_response") {
         const response = payload.response;
         get().handleTranscriptResponse(response as unknown as StreamResponse);
+        void extensionsCommands.emitEvent({
+          type: "transcriptDelta",
+          sessionId: payload.session_id,
+          delta: response as unknown as JsonValue,
+        });
       } else if (payload.type === "mic_muted") {
         set((state) =>
           mutate(state, (draft) => {
             draft.live.muted = payload.value;
           }),
         );
+      } else if (payload.type === "speaker_identified") {
+        options?.handleSpeakerIdentified?.(
+          { channel: payload.channel, speakerIndex: payload.speaker_index },
+          payload.human_id,
+        );
       }
     };
 
@@ -435,6 +459,10 @@ export const createGeneralSlice = <
         },
         onSuccess: () => {
           if (sessionId) {
+            void extensionsCommands.emitEvent({
+              type: "sessionStopped",
+              sessionId,
+            });
             void Promise.all([
               settingsCommands.vaultBase().then((r) => {
                 if (r.status === "error") throw new Error(r.error);
@@ -444,7 +472,7 @@ export const createGeneralSlice = <
             ])
               .then(([dataDirPath, bundleId]) => {
                 const sessionPath = buildSessionPath(dataDirPath, sessionId);
-                return hooksCommands.runEventHooks({
+                const event = {
                   afterListeningStopped: {
                     args: {
                       resource_dir: sessionPath,
@@ -452,7 +480,11 @@ export const createGeneralSlice = <
                       app_meeting: null,
                     },
                   },
-                });
+                };
+                return Promise.all([
+                  hooksCommands.runEventHooks(event),
+                  webhookCommands.dispatchEvent(event, null),
+                ]);
               })
               .catch((error) => {
                 console.error("[hooks] AfterListeningStopped failed:", error);
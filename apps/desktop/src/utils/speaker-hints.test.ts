@@ -0,0 +1,44 @@
+import { describe, expect, test } from "vitest";
+
+import { findFirstWordOfSpeaker, findProviderSpeakers } from "./speaker-hints";
+
+const providerHint = (
+  wordId: string,
+  channel: number,
+  speakerIndex: number,
+) => ({
+  word_id: wordId,
+  type: "provider_speaker_index",
+  value: JSON.stringify({
+    provider: "deepgram",
+    channel,
+    speaker_index: speakerIndex,
+  }),
+});
+
+describe("speaker hints", () => {
+  const hints = [
+    providerHint("w1", 1, 0),
+    providerHint("w2", 1, 2),
+    providerHint("w3", 1, 2),
+    providerHint("w4", 0, 2),
+    { word_id: "w3", type: "user_speaker_assignment", value: "{}" },
+  ];
+
+  test("findProviderSpeakers dedupes speakers of the given words", () => {
+    expect(findProviderSpeakers(hints, new Set(["w2", "w3", "w4"]))).toEqual([
+      { channel: 1, speakerIndex: 2 },
+      { channel: 0, speakerIndex: 2 },
+    ]);
+    expect(findProviderSpeakers(hints, new Set(["w5"]))).toEqual([]);
+  });
+
+  test("findFirstWordOfSpeaker matches channel and speaker index", () => {
+    expect(findFirstWordOfSpeaker(hints, { channel: 1, speakerIndex: 2 })).toBe(
+      "w2",
+    );
+    expect(
+      findFirstWordOfSpeaker(hints, { channel: 0, speakerIndex: 0 }),
+    ).toBeUndefined();
+  });
+});
@@ -69,6 +69,55 @@ export function convertStorageHintsToRuntime(
   return hints;
 }
 
+export type LiveSpeaker = { channel: number; speakerIndex: number };
+
+// Provider speakers (per channel) that the given words were attributed to.
+export function findProviderSpeakers(
+  storageHints: SpeakerHintStorage[],
+  wordIds: ReadonlySet<string>,
+): LiveSpeaker[] {
+  const speakers = new Map<string, LiveSpeaker>();
+
+  storageHints.forEach((hint) => {
+    if (
+      hint.type !== "provider_speaker_index" ||
+      typeof hint.word_id !== "string" ||
+      !wordIds.has(hint.word_id)
+    ) {
+      return;
+    }
+
+    const parsed = parseProviderSpeakerIndex(hint.value);
+    if (parsed?.channel === undefined) {
+      return;
+    }
+
+    speakers.set(`${parsed.channel}:${parsed.speaker_index}`, {
+      channel: parsed.channel,
+      speakerIndex: parsed.speaker_index,
+    });
+  });
+
+  return [...speakers.values()];
+}
+
+// First word attributed to a provider speaker, if any was persisted yet.
+export function findFirstWordOfSpeaker(
+  storageHints: SpeakerHintStorage[],
+  { channel, speakerIndex }: LiveSpeaker,
+): string | undefined {
+  const hint = storageHints.find((hint) => {
+    if (hint.type !== "provider_speaker_index") {
+      return false;
+    }
+
+    const parsed = parseProviderSpeakerIndex(hint.value);
+    return parsed?.channel === channel && parsed.speaker_index === speakerIndex;
+  });
+
+  return typeof hint?.word_id === "string" ? hint.word_id : undefined;
+}
+
 const parseProviderSpeakerIndex = (
   raw: unknown,
 ): ProviderSpeakerIndexHint | undefined => {
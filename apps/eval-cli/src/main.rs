@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::process::ExitCode;
 use std::sync::Arc;
 
@@ -5,17 +6,19 @@ use clap::{Parser, Subcommand};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 
 mod report;
+mod stt;
 mod submissions;
 
 use hypr_eval::{
     DEFAULT_MODELS, EvalResult, Executor, ExecutorProgress, OpenRouterClient, parse_config,
 };
 use report::{render_json, render_results};
+use stt::{SttOptions, run_stt};
 use submissions::{all_cases, filter_cases};
 
 #[derive(Parser)]
 #[command(name = "evals")]
-#[command(about = "LLM evaluation runner")]
+#[command(about = "LLM and speech-to-text evaluation runner")]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
@@ -39,6 +42,34 @@ enum Commands {
         #[arg(long)]
         cache_dir: Option<String>,
     },
+    /// Score speech-to-text providers against a directory of audio files
+    /// with reference transcripts.
+    Stt {
+        #[arg(short, long)]
+        dataset: PathBuf,
+
+        #[arg(short, long, value_delimiter = ',', required = true)]
+        providers: Vec<String>,
+
+        /// Score recorded responses from `<dataset>/fixtures/<provider>/`
+        /// instead of calling the providers.
+        #[arg(long, conflicts_with = "record")]
+        replay: bool,
+
+        /// Save provider responses as fixtures for later `--replay` runs.
+        #[arg(long)]
+        record: bool,
+
+        /// Language for samples whose reference doesn't specify one.
+        #[arg(short, long)]
+        language: Option<String>,
+
+        #[arg(long)]
+        model: Option<String>,
+
+        #[arg(short, long, default_value = "table")]
+        output: String,
+    },
     List,
     Completion {
         #[arg(value_enum)]
@@ -71,6 +102,29 @@ fn main() -> ExitCode {
                 return ExitCode::FAILURE;
             }
         }
+        Commands::Stt {
+            dataset,
+            providers,
+            replay,
+            record,
+            language,
+            model,
+            output,
+        } => {
+            let opts = SttOptions {
+                dataset,
+                providers,
+                replay,
+                record,
+                language,
+                model,
+                output,
+            };
+            if let Err(e) = run_stt(opts) {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
         Commands::List => {
             list_cases();
         }
@@ -0,0 +1,227 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use comfy_table::{Cell, ContentArrangement, Table, presets::UTF8_FULL_CONDENSED};
+use indicatif::{ProgressBar, ProgressStyle};
+use owhisper_client::{
+    AssemblyAIAdapter, BatchClient, DeepgramAdapter, ElevenLabsAdapter, FireworksAdapter,
+    GladiaAdapter, MistralAdapter, OpenAIAdapter, Provider, SonioxAdapter,
+};
+use owhisper_interface::ListenParams;
+use owhisper_interface::batch::Response as BatchResponse;
+
+use hypr_eval::stt::{
+    Dataset, ProviderSummary, Sample, SampleScore, score_sample, suggested_priorities, summarize,
+};
+
+pub struct SttOptions {
+    pub dataset: PathBuf,
+    pub providers: Vec<String>,
+    pub replay: bool,
+    pub record: bool,
+    pub language: Option<String>,
+    pub model: Option<String>,
+    pub output: String,
+}
+
+pub fn run_stt(opts: SttOptions) -> Result<(), String> {
+    let dataset =
+        Dataset::load(&opts.dataset, opts.language.as_deref()).map_err(|e| e.to_string())?;
+    if dataset.samples.is_empty() {
+        return Err(format!(
+            "no audio files with reference transcripts in {}",
+            opts.dataset.display()
+        ));
+    }
+
+    let providers = opts
+        .providers
+        .iter()
+        .map(|name| Provider::from_str(name).map_err(|_| format!("unknown provider: {}", name)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let bar = if opts.output == "json" {
+        ProgressBar::hidden()
+    } else {
+        ProgressBar::new((dataset.samples.len() * providers.len()) as u64)
+    };
+    bar.set_style(
+        ProgressStyle::default_bar()
+            .template("{prefix:>12} [{bar:30.white}] {pos}/{len} {msg}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    bar.set_prefix("Samples");
+
+    let mut scores = Vec::new();
+    for provider in &providers {
+        let name = provider.to_string();
+        let api_key = std::env::var(provider.env_key_name()).ok();
+        if !opts.replay && api_key.is_none() {
+            return Err(format!(
+                "{} environment variable is not set",
+                provider.env_key_name()
+            ));
+        }
+
+        for sample in &dataset.samples {
+            bar.set_message(format!("{}/{}", name, sample.id));
+
+            let response = if opts.replay {
+                dataset
+                    .load_fixture(&name, sample)
+                    .map_err(|e| e.to_string())
+            } else {
+                let params = listen_params(sample, opts.model.clone());
+                let api_key = api_key.clone().unwrap_or_default();
+                runtime.block_on(transcribe(*provider, api_key, params, &sample.audio_path))
+            };
+
+            let score = match response {
+                Ok(response) => {
+                    if opts.record {
+                        dataset
+                            .save_fixture(&name, sample, &response)
+                            .map_err(|e| e.to_string())?;
+                    }
+                    score_sample(sample, &name, &response)
+                }
+                Err(e) => SampleScore::failed(sample, &name, e),
+            };
+            scores.push(score);
+            bar.inc(1);
+        }
+    }
+    bar.finish_and_clear();
+
+    let summaries = summarize(&scores);
+    if opts.output == "json" {
+        render_json(&scores, &summaries)
+    } else {
+        render_table(&scores, &summaries);
+        Ok(())
+    }
+}
+
+fn listen_params(sample: &Sample, model: Option<String>) -> ListenParams {
+    ListenParams {
+        model,
+        languages: sample
+            .reference
+            .language
+            .as_deref()
+            .and_then(|code| code.parse().ok())
+            .into_iter()
+            .collect(),
+        ..Default::default()
+    }
+}
+
+async fn transcribe(
+    provider: Provider,
+    api_key: String,
+    params: ListenParams,
+    audio_path: &Path,
+) -> Result<BatchResponse, String> {
+    let api_base = provider.default_api_base();
+
+    macro_rules! batch_transcribe {
+        ($adapter:ty) => {
+            BatchClient::<$adapter>::builder()
+                .api_base(api_base)
+                .api_key(api_key)
+                .params(params)
+                .build()
+                .transcribe_file(audio_path)
+                .await
+        };
+    }
+
+    let result = match provider {
+        Provider::Deepgram => batch_transcribe!(DeepgramAdapter),
+        Provider::AssemblyAI => batch_transcribe!(AssemblyAIAdapter),
+        Provider::Soniox => batch_transcribe!(SonioxAdapter),
+        Provider::OpenAI => batch_transcribe!(OpenAIAdapter),
+        Provider::Gladia => batch_transcribe!(GladiaAdapter),
+        Provider::ElevenLabs => batch_transcribe!(ElevenLabsAdapter),
+        Provider::Mistral => batch_transcribe!(MistralAdapter),
+        Provider::Fireworks => batch_transcribe!(FireworksAdapter),
+        Provider::DashScope => {
+            return Err(format!(
+                "{:?} does not support batch transcription",
+                provider
+            ));
+        }
+    };
+
+    result.map_err(|e| format!("{:?}", e))
+}
+
+fn percent(rate: f64) -> String {
+    format!("{:.1}%", rate * 100.0)
+}
+
+fn render_table(scores: &[SampleScore], summaries: &[ProviderSummary]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        "Language",
+        "Provider",
+        "Samples",
+        "Failed",
+        "WER",
+        "CER",
+        "SA-WER",
+        "Drift (mean)",
+        "Drift (max)",
+    ]);
+
+    for s in summaries {
+        let ms = |v: Option<f64>| {
+            v.map(|v| format!("{:.0}ms", v))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        table.add_row(vec![
+            Cell::new(&s.language),
+            Cell::new(&s.provider),
+            Cell::new(s.samples),
+            Cell::new(s.failures),
+            Cell::new(percent(s.wer)),
+            Cell::new(percent(s.cer)),
+            Cell::new(s.sa_wer.map(percent).unwrap_or_else(|| "-".to_string())),
+            Cell::new(ms(s.mean_drift_ms)),
+            Cell::new(ms(s.max_drift_ms)),
+        ]);
+    }
+    println!("{table}");
+
+    for score in scores {
+        if let Some(error) = &score.error {
+            eprintln!("{} / {}: {}", score.provider, score.sample_id, error);
+        }
+    }
+
+    println!("\nSuggested priorities:");
+    for (language, providers) in suggested_priorities(summaries) {
+        println!("  {}: {}", language, providers.join(", "));
+    }
+}
+
+fn render_json(scores: &[SampleScore], summaries: &[ProviderSummary]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&serde_json::json!({
+        "summaries": summaries,
+        "priorities": suggested_priorities(summaries),
+        "samples": scores,
+    }))
+    .map_err(|e| format!("Failed to encode JSON: {}", e))?;
+
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use axum::Router;
+use clap::Parser;
+use tracing_subscriber::prelude::*;
+
+use hypr_api_sync::{AppState, BlobStorage, DEFAULT_MAX_BLOB_BYTES, SyncConfig};
+
+/// Serves the session sync API for a team vault. Blobs are kept under
+/// `--data-dir` unless an S3-compatible bucket is configured.
+#[derive(Parser)]
+#[command(version)]
+struct Args {
+    #[arg(long, env = "PORT", default_value_t = 3030)]
+    port: u16,
+
+    #[arg(long, env = "SYNC_DATA_DIR", default_value = "./sync-data")]
+    data_dir: PathBuf,
+
+    /// Bearer token shared by every device syncing into the vault.
+    #[arg(long, env = "SYNC_TOKEN")]
+    token: Option<String>,
+
+    /// Accept Supabase-issued tokens, one vault per user.
+    #[arg(long, env = "SUPABASE_URL")]
+    supabase_url: Option<String>,
+
+    #[arg(long, env = "SYNC_MAX_BLOB_BYTES", default_value_t = DEFAULT_MAX_BLOB_BYTES)]
+    max_blob_bytes: usize,
+
+    #[arg(long, env = "S3_ENDPOINT_URL", requires_all = ["s3_bucket", "s3_access_key_id", "s3_secret_access_key"])]
+    s3_endpoint_url: Option<String>,
+
+    #[arg(long, env = "S3_BUCKET")]
+    s3_bucket: Option<String>,
+
+    #[arg(long, env = "S3_ACCESS_KEY_ID")]
+    s3_access_key_id: Option<String>,
+
+    #[arg(long, env = "S3_SECRET_ACCESS_KEY", hide_env_values = true)]
+    s3_secret_access_key: Option<String>,
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::from_default_env())
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let args = Args::parse();
+
+    if args.token.is_none() && args.supabase_url.is_none() {
+        eprintln!("error: set --token or --supabase-url, otherwise every request is rejected");
+        std::process::exit(2);
+    }
+
+    let mut config = SyncConfig::new(args.supabase_url.clone().unwrap_or_default(), "")
+        .with_max_blob_bytes(args.max_blob_bytes);
+    if let Some(token) = args.token {
+        config = config.with_shared_token(token);
+    }
+    if let Some(url) = &args.supabase_url {
+        config = config.with_auth(Arc::new(hypr_supabase_auth::SupabaseAuth::new(url)));
+    }
+
+    let storage = match (
+        args.s3_endpoint_url,
+        args.s3_bucket,
+        args.s3_access_key_id,
+        args.s3_secret_access_key,
+    ) {
+        (Some(endpoint_url), Some(bucket), Some(key_id), Some(secret)) => {
+            tracing::info!(%endpoint_url, %bucket, "using_s3_storage");
+            let client = hypr_s3::Client::builder()
+                .endpoint_url(endpoint_url)
+                .bucket(bucket)
+                .credentials(key_id, secret)
+                .build()
+                .await;
+            BlobStorage::s3(client)
+        }
+        _ => {
+            tracing::info!(data_dir = %args.data_dir.display(), "using_local_storage");
+            BlobStorage::local(args.data_dir)
+        }
+    };
+
+    let app = Router::new()
+        .route("/health", axum::routing::get(|| async { "ok" }))
+        .nest(
+            "/sync",
+            hypr_api_sync::router(AppState::new(config, storage)),
+        );
+
+    let addr = SocketAddr::from(([0, 0, 0, 0], args.port));
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    tracing::info!(%addr, "sync_server_listening");
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            tokio::signal::ctrl_c().await.ok();
+        })
+        .await
+        .unwrap();
+}
@@ -22,7 +22,6 @@ pub struct ErrorResponse {
 #[derive(Debug, Error)]
 pub enum CalendarError {
     #[error("Authentication error: {0}")]
-    #[allow(dead_code)]
     Auth(String),
 
     #[error("Invalid request: {0}")]
@@ -35,6 +34,25 @@ pub enum CalendarError {
     NangoConnection(#[from] hypr_api_nango::NangoConnectionError),
 }
 
+impl From<hypr_caldav_calendar::Error> for CalendarError {
+    fn from(error: hypr_caldav_calendar::Error) -> Self {
+        use hypr_caldav_calendar::Error;
+
+        match error {
+            Error::Status {
+                status: 401 | 403, ..
+            } => Self::Auth(error.to_string()),
+            Error::Status { status: 404, .. } | Error::Url(_) | Error::ForeignUrl(_) => {
+                Self::BadRequest(error.to_string())
+            }
+            Error::Http(ref e) if crate::providers::NonPublicAddress::caused(e) => {
+                Self::BadRequest("Calendar URL must be publicly reachable".into())
+            }
+            _ => Self::Internal(error.to_string()),
+        }
+    }
+}
+
 impl IntoResponse for CalendarError {
     fn into_response(self) -> Response {
         let internal_message = "Internal server error".to_string();
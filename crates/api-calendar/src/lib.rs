@@ -5,5 +5,8 @@ mod providers;
 mod routes;
 
 pub use openapi::openapi;
-pub use provider::CalendarConfig;
+pub use provider::{
+    CALDAV_PASSWORD_HEADER, CALDAV_URL_HEADER, CALDAV_USERNAME_HEADER, CalendarConfig,
+    ICS_URL_HEADER,
+};
 pub use routes::router;
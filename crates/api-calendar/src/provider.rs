@@ -3,10 +3,21 @@ use std::sync::Arc;
 use axum::extract::FromRequestParts;
 use axum::http::request::Parts;
 use hypr_api_nango::{GoogleCalendar, NangoConnection, NangoConnectionError, OutlookCalendar};
+use hypr_caldav_calendar::{CalDavClient, Credentials, IcsFeedClient};
 
 use crate::error::CalendarError;
+use crate::providers::caldav::CalDavAdapter;
 use crate::providers::google::GoogleAdapter;
+use crate::providers::ics::IcsAdapter;
 use crate::providers::outlook::OutlookAdapter;
+use crate::providers::{check_public_url, public_http_client};
+
+/// CalDAV and ICS have no OAuth connection to look up, so the client sends
+/// the server URL (and CalDAV credentials) with each request.
+pub const CALDAV_URL_HEADER: &str = "x-caldav-url";
+pub const CALDAV_USERNAME_HEADER: &str = "x-caldav-username";
+pub const CALDAV_PASSWORD_HEADER: &str = "x-caldav-password";
+pub const ICS_URL_HEADER: &str = "x-ics-url";
 
 pub struct ListCalendarsResult {
     pub calendars: Vec<serde_json::Value>,
@@ -25,11 +36,15 @@ pub struct CreateEventResult {
 pub struct CalendarConfig {
     pub google: bool,
     pub outlook: bool,
+    pub caldav: bool,
+    pub ics: bool,
 }
 
 pub enum CalendarClient {
     Google(GoogleAdapter),
     Outlook(OutlookAdapter),
+    CalDav(CalDavAdapter),
+    Ics(IcsAdapter),
 }
 
 impl CalendarClient {
@@ -37,6 +52,8 @@ impl CalendarClient {
         match self {
             Self::Google(a) => a.list_calendars().await,
             Self::Outlook(a) => a.list_calendars().await,
+            Self::CalDav(a) => a.list_calendars().await,
+            Self::Ics(a) => a.list_calendars().await,
         }
     }
 
@@ -47,6 +64,8 @@ impl CalendarClient {
         match self {
             Self::Google(a) => a.list_events(req).await,
             Self::Outlook(a) => a.list_events(req).await,
+            Self::CalDav(a) => a.list_events(req).await,
+            Self::Ics(a) => a.list_events(req).await,
         }
     }
 
@@ -57,6 +76,8 @@ impl CalendarClient {
         match self {
             Self::Google(a) => a.create_event(req).await,
             Self::Outlook(a) => a.create_event(req).await,
+            Self::CalDav(a) => a.create_event(req).await,
+            Self::Ics(a) => a.create_event(req).await,
         }
     }
 }
@@ -71,6 +92,35 @@ impl<S: Send + Sync> FromRequestParts<S> for CalendarClient {
             .ok_or(CalendarError::Internal("missing CalendarConfig".into()))?
             .clone();
 
+        if config.caldav
+            && let Some(url) = header(parts, CALDAV_URL_HEADER)?
+        {
+            check_public_url(url)?;
+            let client = CalDavClient::new(url)
+                .map_err(|e| CalendarError::BadRequest(format!("Invalid CalDAV URL: {e}")))?
+                .with_http_client(public_http_client());
+            let client = match header(parts, CALDAV_USERNAME_HEADER)? {
+                Some(username) => client.with_credentials(Credentials {
+                    username: username.to_string(),
+                    password: header(parts, CALDAV_PASSWORD_HEADER)?
+                        .unwrap_or_default()
+                        .to_string(),
+                }),
+                None => client,
+            };
+            return Ok(CalendarClient::CalDav(CalDavAdapter::new(client)));
+        }
+
+        if config.ics
+            && let Some(url) = header(parts, ICS_URL_HEADER)?
+        {
+            check_public_url(url)?;
+            let client = IcsFeedClient::new(url)
+                .map_err(|e| CalendarError::BadRequest(format!("Invalid ICS URL: {e}")))?
+                .with_http_client(public_http_client());
+            return Ok(CalendarClient::Ics(IcsAdapter::new(client)));
+        }
+
         if config.google {
             match NangoConnection::<GoogleCalendar>::from_request_parts(parts, state).await {
                 Ok(conn) => {
@@ -98,3 +148,15 @@ impl<S: Send + Sync> FromRequestParts<S> for CalendarClient {
         ))
     }
 }
+
+fn header<'a>(parts: &'a Parts, name: &str) -> Result<Option<&'a str>, CalendarError> {
+    parts
+        .headers
+        .get(name)
+        .map(|value| {
+            value
+                .to_str()
+                .map_err(|_| CalendarError::BadRequest(format!("Invalid {name} header")))
+        })
+        .transpose()
+}
@@ -0,0 +1,75 @@
+use hypr_caldav_calendar::{Attendee, AttendeeResponse, CalDavClient, NewEvent};
+
+use crate::error::CalendarError;
+use crate::provider::{CreateEventResult, ListCalendarsResult, ListEventsResult};
+use crate::routes::calendar::{CreateEventRequest, ListEventsRequest};
+
+use super::{event_time, event_window, paginate};
+
+pub struct CalDavAdapter {
+    client: CalDavClient,
+}
+
+impl CalDavAdapter {
+    pub fn new(client: CalDavClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn list_calendars(&self) -> Result<ListCalendarsResult, CalendarError> {
+        let calendars = self
+            .client
+            .list_calendars()
+            .await?
+            .iter()
+            .map(|c| serde_json::to_value(c).unwrap_or_default())
+            .collect();
+
+        Ok(ListCalendarsResult { calendars })
+    }
+
+    /// Recurring events always come back expanded, as if `single_events`
+    /// were set, ordered by start time.
+    pub async fn list_events(
+        &self,
+        req: ListEventsRequest,
+    ) -> Result<ListEventsResult, CalendarError> {
+        let (start, end) = event_window(&req)?;
+        let events = self
+            .client
+            .list_events(&req.calendar_id, start, end)
+            .await?;
+        paginate(events, &req)
+    }
+
+    pub async fn create_event(
+        &self,
+        req: CreateEventRequest,
+    ) -> Result<CreateEventResult, CalendarError> {
+        let (start, all_day) = event_time(&req.start, "start")?;
+        let (end, _) = event_time(&req.end, "end")?;
+
+        let event = NewEvent {
+            summary: req.summary,
+            start,
+            end,
+            all_day,
+            description: req.description,
+            location: req.location,
+            attendees: req
+                .attendees
+                .unwrap_or_default()
+                .into_iter()
+                .map(|a| Attendee {
+                    email: Some(a.email),
+                    name: a.display_name,
+                    optional: a.optional.unwrap_or(false),
+                    response: AttendeeResponse::NeedsAction,
+                })
+                .collect(),
+        };
+
+        let event = self.client.create_event(&req.calendar_id, event).await?;
+        let event = serde_json::to_value(event).unwrap_or_default();
+        Ok(CreateEventResult { event })
+    }
+}
@@ -0,0 +1,44 @@
+use hypr_caldav_calendar::IcsFeedClient;
+
+use crate::error::CalendarError;
+use crate::provider::{CreateEventResult, ListCalendarsResult, ListEventsResult};
+use crate::routes::calendar::{CreateEventRequest, ListEventsRequest};
+
+use super::{event_window, paginate};
+
+/// A read-only ICS subscription. It exposes a single calendar whose id is
+/// the feed URL.
+pub struct IcsAdapter {
+    client: IcsFeedClient,
+}
+
+impl IcsAdapter {
+    pub fn new(client: IcsFeedClient) -> Self {
+        Self { client }
+    }
+
+    pub async fn list_calendars(&self) -> Result<ListCalendarsResult, CalendarError> {
+        let calendar = self.client.calendar().await?;
+        Ok(ListCalendarsResult {
+            calendars: vec![serde_json::to_value(calendar).unwrap_or_default()],
+        })
+    }
+
+    pub async fn list_events(
+        &self,
+        req: ListEventsRequest,
+    ) -> Result<ListEventsResult, CalendarError> {
+        let (start, end) = event_window(&req)?;
+        let events = self.client.list_events(start, end).await?;
+        paginate(events, &req)
+    }
+
+    pub async fn create_event(
+        &self,
+        _req: CreateEventRequest,
+    ) -> Result<CreateEventResult, CalendarError> {
+        Err(CalendarError::BadRequest(
+            "ICS subscriptions are read-only".into(),
+        ))
+    }
+}
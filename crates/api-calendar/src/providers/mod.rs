@@ -1,2 +1,88 @@
+pub mod caldav;
 pub mod google;
+pub mod ics;
 pub mod outlook;
+mod public;
+
+use chrono::{DateTime, Utc};
+
+use crate::error::CalendarError;
+use crate::provider::ListEventsResult;
+use crate::routes::calendar::{EventDateTime, ListEventsRequest};
+
+pub(crate) use public::{NonPublicAddress, check_public_url, public_http_client};
+
+/// How far ahead CalDAV and ICS listings look when `time_max` is omitted;
+/// unlike Google and Outlook they have no server-side default.
+const DEFAULT_WINDOW_DAYS: i64 = 30;
+
+fn parse_time(value: Option<&str>, field: &str) -> Result<Option<DateTime<Utc>>, CalendarError> {
+    value
+        .map(|s| {
+            DateTime::parse_from_rfc3339(s)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| CalendarError::BadRequest(format!("Invalid {field}: {e}")))
+        })
+        .transpose()
+}
+
+pub(crate) fn event_window(
+    req: &ListEventsRequest,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), CalendarError> {
+    let start = parse_time(req.time_min.as_deref(), "time_min")?.unwrap_or_else(Utc::now);
+    let end = parse_time(req.time_max.as_deref(), "time_max")?
+        .unwrap_or(start + chrono::Duration::days(DEFAULT_WINDOW_DAYS));
+    Ok((start, end))
+}
+
+/// Pages through already-expanded events, using the offset as the page
+/// token.
+pub(crate) fn paginate(
+    events: Vec<hypr_caldav_calendar::Event>,
+    req: &ListEventsRequest,
+) -> Result<ListEventsResult, CalendarError> {
+    let offset = req
+        .page_token
+        .as_deref()
+        .map(|t| {
+            t.parse::<usize>()
+                .map_err(|_| CalendarError::BadRequest(format!("Invalid page_token: {t}")))
+        })
+        .transpose()?
+        .unwrap_or(0);
+    let limit = req.max_results.map_or(usize::MAX, |n| n as usize);
+
+    let total = events.len();
+    let events = events
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .map(|e| serde_json::to_value(e).unwrap_or_default())
+        .collect::<Vec<_>>();
+    let next = offset + events.len();
+
+    Ok(ListEventsResult {
+        events,
+        next_page_token: (next < total).then(|| next.to_string()),
+    })
+}
+
+/// Reads a request-side date or date-time as UTC, flagging all-day values.
+pub(crate) fn event_time(
+    dt: &EventDateTime,
+    field: &str,
+) -> Result<(DateTime<Utc>, bool), CalendarError> {
+    if let Some(date_time) = parse_time(dt.date_time.as_deref(), &format!("{field}.dateTime"))? {
+        return Ok((date_time, false));
+    }
+    match &dt.date {
+        Some(date) => {
+            let date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|e| CalendarError::BadRequest(format!("Invalid {field}.date: {e}")))?;
+            Ok((date.and_time(chrono::NaiveTime::MIN).and_utc(), true))
+        }
+        None => Err(CalendarError::BadRequest(
+            "Either date or dateTime must be provided".into(),
+        )),
+    }
+}
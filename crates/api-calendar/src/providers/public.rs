@@ -0,0 +1,254 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::{Arc, LazyLock};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::redirect;
+
+use crate::error::CalendarError;
+
+const MAX_REDIRECTS: usize = 10;
+
+/// Raised by the resolver and redirect policy when a CalDAV or ICS request
+/// would reach a non-public address.
+#[derive(Debug, thiserror::Error)]
+#[error("{0} is not a public address")]
+pub(crate) struct NonPublicAddress(String);
+
+impl NonPublicAddress {
+    /// Whether `error`, or anything it wraps, is a blocked address.
+    pub(crate) fn caused(error: &(dyn std::error::Error + 'static)) -> bool {
+        let mut source = Some(error);
+        while let Some(error) = source {
+            if error.is::<Self>() {
+                return true;
+            }
+            source = error.source();
+        }
+        false
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || a == 0
+        // Carrier-grade NAT, 100.64.0.0/10.
+        || (a == 100 && (b & 0xc0) == 64))
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_public_ipv4(v4);
+    }
+    let first = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // Unique local, fc00::/7.
+        || (first & 0xfe00) == 0xfc00
+        // Link-local, fe80::/10.
+        || (first & 0xffc0) == 0xfe80
+        // NAT64, 64:ff9b::/96, embeds an IPv4 address.
+        || (ip.segments()[..6] == [0x64, 0xff9b, 0, 0, 0, 0]
+            && !is_public_ipv4(Ipv4Addr::from_bits(ip.to_bits() as u32))))
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => is_public_ipv6(ip),
+    }
+}
+
+fn check_host(url: &url::Url, allow: fn(IpAddr) -> bool) -> Result<(), NonPublicAddress> {
+    let allowed = match url.host() {
+        Some(url::Host::Ipv4(ip)) => allow(ip.into()),
+        Some(url::Host::Ipv6(ip)) => allow(ip.into()),
+        // Names are checked once resolved, see `GuardedResolver`.
+        Some(url::Host::Domain(domain)) => {
+            !(domain.eq_ignore_ascii_case("localhost") || domain.ends_with(".localhost"))
+        }
+        None => false,
+    };
+    if allowed {
+        Ok(())
+    } else {
+        Err(NonPublicAddress(url.host_str().unwrap_or_default().into()))
+    }
+}
+
+/// CalDAV and ICS URLs come from the client, so refuse ones that point back
+/// into our own network. Names are only rejected once they resolve, by the
+/// client from [`public_http_client`].
+pub(crate) fn check_public_url(raw: &str) -> Result<(), CalendarError> {
+    let normalized = match raw.get(..9) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("webcal://") => {
+            format!("https://{}", &raw[9..])
+        }
+        _ => raw.to_string(),
+    };
+    let url = url::Url::parse(&normalized)
+        .map_err(|e| CalendarError::BadRequest(format!("Invalid calendar URL: {e}")))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(CalendarError::BadRequest(format!(
+            "Unsupported calendar URL scheme: {}",
+            url.scheme()
+        )));
+    }
+
+    check_host(&url, is_public_ip)
+        .map_err(|_| CalendarError::BadRequest("Calendar URL must be publicly reachable".into()))
+}
+
+/// Resolves names like the system resolver, but fails when any address is
+/// not allowed, so a public name can't be pointed at a private address.
+struct GuardedResolver {
+    allow: fn(IpAddr) -> bool,
+}
+
+impl Resolve for GuardedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let allow = self.allow;
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            if let Some(addr) = addrs.iter().find(|addr| !allow(addr.ip())) {
+                return Err(NonPublicAddress(format!("{host} ({})", addr.ip())).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+fn guarded_http_client(allow: fn(IpAddr) -> bool) -> reqwest::Client {
+    reqwest::Client::builder()
+        .dns_resolver(Arc::new(GuardedResolver { allow }))
+        // IP literals skip the resolver, so every hop is checked again.
+        .redirect(redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                return attempt.error("too many redirects");
+            }
+            match check_host(attempt.url(), allow) {
+                Ok(()) => attempt.follow(),
+                Err(e) => attempt.error(e),
+            }
+        }))
+        // A proxy would resolve names itself.
+        .no_proxy()
+        .build()
+        .expect("Failed to build calendar http client")
+}
+
+/// HTTP client for user-supplied CalDAV and ICS servers.
+pub(crate) fn public_http_client() -> reqwest::Client {
+    static CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| guarded_http_client(is_public_ip));
+    CLIENT.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_private_ipv6_and_mapped_literals() {
+        for url in [
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/",
+            "http://[fc00::1]/",
+            "http://[fd12:3456::1]/",
+            "http://[fe80::1]/",
+            "http://[::ffff:10.0.0.1]/",
+            "http://[::ffff:169.254.169.254]/",
+            "http://[64:ff9b::7f00:1]/",
+            "webcal://localhost/cal.ics",
+        ] {
+            assert!(check_public_url(url).is_err(), "{url}");
+        }
+
+        for url in [
+            "https://caldav.fastmail.com/dav/",
+            "webcal://p01-calendars.icloud.com/published/2/abc",
+            "http://93.184.215.14/cal.ics",
+            "http://[2606:4700::6810:84e5]/cal.ics",
+        ] {
+            assert!(check_public_url(url).is_ok(), "{url}");
+        }
+    }
+
+    async fn serve() -> (u16, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let hits = Arc::new(AtomicUsize::new(0));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let counter = hits.clone();
+        let app = axum::Router::new()
+            .route("/ok", axum::routing::get(|| async { "BEGIN:VCALENDAR" }))
+            .route(
+                "/hop",
+                axum::routing::get(move || async move {
+                    axum::response::Redirect::temporary(&format!("http://127.0.0.1:{port}/ok"))
+                }),
+            )
+            .route(
+                "/escape",
+                axum::routing::get(move || async move {
+                    axum::response::Redirect::temporary(&format!("http://127.0.0.2:{port}/ok"))
+                }),
+            )
+            .layer(axum::middleware::from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    next.run(req)
+                },
+            ));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (port, hits)
+    }
+
+    #[tokio::test]
+    async fn refuses_names_resolving_to_private_addresses() {
+        let (port, hits) = serve().await;
+
+        let err = public_http_client()
+            .get(format!("http://localhost:{port}/ok"))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(NonPublicAddress::caused(&err), "{err:?}");
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn checks_every_redirect_hop() {
+        let (port, _) = serve().await;
+        // Stands in for a public server: only 127.0.0.1 is allowed.
+        let client = guarded_http_client(|ip| ip == IpAddr::V4(Ipv4Addr::LOCALHOST));
+
+        let body = client
+            .get(format!("http://127.0.0.1:{port}/hop"))
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(body, "BEGIN:VCALENDAR");
+
+        let err = client
+            .get(format!("http://127.0.0.1:{port}/escape"))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(err.is_redirect(), "{err:?}");
+        assert!(NonPublicAddress::caused(&err), "{err:?}");
+    }
+}
@@ -16,6 +16,17 @@ where
     s.parse().map_err(serde::de::Error::custom)
 }
 
+pub fn optional_from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    filter_empty(deserializer)?
+        .map(|s| s.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
 #[derive(Clone, Deserialize)]
 pub struct SupabaseEnv {
     pub supabase_url: String,
@@ -0,0 +1,50 @@
+use axum::{extract::FromRequestParts, http::request::Parts};
+use hypr_supabase_auth::SupabaseAuth;
+
+use crate::error::SyncError;
+use crate::state::AppState;
+
+/// Everyone holding the shared token syncs into the same vault.
+pub(crate) const SHARED_USER_ID: &str = "shared";
+
+/// The account whose vault a request reads and writes.
+pub struct SyncUser {
+    pub id: String,
+}
+
+impl FromRequestParts<AppState> for SyncUser {
+    type Rejection = SyncError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .headers
+            .get("Authorization")
+            .and_then(|h| h.to_str().ok())
+            .ok_or_else(|| SyncError::Auth("missing authorization header".into()))?;
+        let token = SupabaseAuth::extract_token(header)
+            .ok_or_else(|| SyncError::Auth("invalid authorization header".into()))?;
+
+        if let Some(shared) = &state.config.shared_token
+            && constant_time_eq(shared.as_bytes(), token.as_bytes())
+        {
+            return Ok(Self {
+                id: SHARED_USER_ID.to_string(),
+            });
+        }
+
+        match &state.config.auth {
+            Some(auth) => {
+                let claims = auth.verify_token(token).await?;
+                Ok(Self { id: claims.sub })
+            }
+            None => Err(SyncError::Auth("invalid token".into())),
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
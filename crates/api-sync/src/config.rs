@@ -1,10 +1,16 @@
 use std::sync::Arc;
 
+/// Blobs larger than this are rejected unless overridden.
+pub const DEFAULT_MAX_BLOB_BYTES: usize = 512 * 1024 * 1024;
+
 #[derive(Clone)]
 pub struct SyncConfig {
     pub supabase_url: String,
     pub supabase_anon_key: String,
     pub auth: Option<Arc<hypr_supabase_auth::SupabaseAuth>>,
+    /// Static bearer token for self-hosted servers without Supabase.
+    pub shared_token: Option<String>,
+    pub max_blob_bytes: usize,
 }
 
 impl SyncConfig {
@@ -13,6 +19,8 @@ impl SyncConfig {
             supabase_url: supabase_url.into(),
             supabase_anon_key: supabase_anon_key.into(),
             auth: None,
+            shared_token: None,
+            max_blob_bytes: DEFAULT_MAX_BLOB_BYTES,
         }
     }
 
@@ -20,4 +28,14 @@ impl SyncConfig {
         self.auth = Some(auth);
         self
     }
+
+    pub fn with_shared_token(mut self, token: impl Into<String>) -> Self {
+        self.shared_token = Some(token.into());
+        self
+    }
+
+    pub fn with_max_blob_bytes(mut self, max_blob_bytes: usize) -> Self {
+        self.max_blob_bytes = max_blob_bytes;
+        self
+    }
 }
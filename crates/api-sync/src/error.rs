@@ -21,6 +21,9 @@ pub enum SyncError {
     #[error("Invalid request: {0}")]
     BadRequest(String),
 
+    #[error("Not found: {0}")]
+    NotFound(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -36,6 +39,7 @@ impl IntoResponse for SyncError {
         let (status, error_code) = match &self {
             Self::Auth(_) => (StatusCode::UNAUTHORIZED, "unauthorized"),
             Self::BadRequest(_) => (StatusCode::BAD_REQUEST, "bad_request"),
+            Self::NotFound(_) => (StatusCode::NOT_FOUND, "not_found"),
             Self::Internal(msg) => {
                 tracing::error!(error = %msg, "internal_error");
                 sentry::capture_message(msg, sentry::Level::Error);
@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ChangesResponse, FileChange, FileEntry, PushResponse, SyncConflict};
+
+pub(crate) const MAX_CHANGES_PAGE: usize = 1000;
+
+/// Per-user sync state: the latest entry of every file, keyed by
+/// `<session_id>/<path>`, and the content hashes uploaded so far.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct UserIndex {
+    pub revision: u64,
+    #[serde(default)]
+    pub files: BTreeMap<String, FileEntry>,
+    #[serde(default)]
+    pub blobs: BTreeMap<String, u64>,
+}
+
+impl UserIndex {
+    pub fn has_blob(&self, hash: &str) -> bool {
+        self.blobs.contains_key(hash)
+    }
+
+    pub fn add_blob(&mut self, hash: &str, size: u64) {
+        self.blobs.insert(hash.to_string(), size);
+    }
+
+    /// Applies each change whose `base_hash` still matches the server and
+    /// whose blob has been uploaded. Every applied change gets its own
+    /// revision.
+    pub fn apply(&mut self, device_id: &str, changes: Vec<FileChange>, now: &str) -> PushResponse {
+        let mut response = PushResponse::default();
+
+        for change in changes {
+            let key = file_key(&change.session_id, &change.path);
+            let current = self.files.get(&key);
+            let current_hash = current.and_then(|entry| entry.hash.as_deref());
+
+            if current_hash == change.hash.as_deref() {
+                continue;
+            }
+
+            if let Some(server) = current
+                && current_hash != change.base_hash.as_deref()
+            {
+                response.conflicts.push(SyncConflict {
+                    session_id: change.session_id,
+                    path: change.path,
+                    base_hash: change.base_hash,
+                    hash: change.hash,
+                    server: server.clone(),
+                });
+                continue;
+            }
+
+            if let Some(hash) = &change.hash
+                && !self.has_blob(hash)
+            {
+                if !response.missing_blobs.contains(hash) {
+                    response.missing_blobs.push(hash.clone());
+                }
+                continue;
+            }
+
+            self.revision += 1;
+            let entry = FileEntry {
+                session_id: change.session_id,
+                path: change.path,
+                size: if change.hash.is_some() {
+                    change.size
+                } else {
+                    0
+                },
+                hash: change.hash,
+                revision: self.revision,
+                device_id: device_id.to_string(),
+                updated_at: now.to_string(),
+            };
+            self.files.insert(key, entry.clone());
+            response.applied.push(entry);
+        }
+
+        response.revision = self.revision;
+        response
+    }
+
+    pub fn changes_since(&self, since: u64, limit: usize) -> ChangesResponse {
+        let limit = limit.clamp(1, MAX_CHANGES_PAGE);
+
+        let mut changes: Vec<_> = self
+            .files
+            .values()
+            .filter(|entry| entry.revision > since)
+            .cloned()
+            .collect();
+        changes.sort_by_key(|entry| entry.revision);
+
+        let has_more = changes.len() > limit;
+        changes.truncate(limit);
+        let next_since = match changes.last() {
+            Some(entry) if has_more => entry.revision,
+            _ => self.revision.max(since),
+        };
+
+        ChangesResponse {
+            revision: self.revision,
+            changes,
+            next_since,
+            has_more,
+        }
+    }
+}
+
+fn file_key(session_id: &str, path: &str) -> String {
+    format!("{}/{}", session_id, path)
+}
+
+pub(crate) fn validate_change(change: &FileChange) -> Result<(), String> {
+    validate_segment(&change.session_id)
+        .map_err(|e| format!("invalid session_id {:?}: {}", change.session_id, e))?;
+
+    if change.path.is_empty() || change.path.len() > 512 {
+        return Err(format!("invalid path {:?}", change.path));
+    }
+    for segment in change.path.split('/') {
+        validate_segment(segment).map_err(|e| format!("invalid path {:?}: {}", change.path, e))?;
+    }
+
+    for hash in [&change.base_hash, &change.hash].into_iter().flatten() {
+        if !is_valid_hash(hash) {
+            return Err(format!("invalid hash {:?}", hash));
+        }
+    }
+    Ok(())
+}
+
+fn validate_segment(segment: &str) -> Result<(), &'static str> {
+    if segment.is_empty() || segment == "." || segment == ".." {
+        return Err("empty or relative segment");
+    }
+    if segment.len() > 255 {
+        return Err("segment too long");
+    }
+    if segment.contains(['/', '\\', '\0']) {
+        return Err("contains a separator");
+    }
+    Ok(())
+}
+
+/// Lowercase hex SHA-256.
+pub(crate) fn is_valid_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(c: char) -> String {
+        c.to_string().repeat(64)
+    }
+
+    fn change(path: &str, base: Option<char>, new: Option<char>) -> FileChange {
+        FileChange {
+            session_id: "s1".to_string(),
+            path: path.to_string(),
+            base_hash: base.map(hash),
+            hash: new.map(hash),
+            size: 10,
+        }
+    }
+
+    fn index_with_blobs(blobs: &[char]) -> UserIndex {
+        let mut index = UserIndex::default();
+        for c in blobs {
+            index.add_blob(&hash(*c), 10);
+        }
+        index
+    }
+
+    #[test]
+    fn applies_changes_with_revisions() {
+        let mut index = index_with_blobs(&['a', 'b']);
+
+        let res = index.apply(
+            "laptop",
+            vec![
+                change("_meta.json", None, Some('a')),
+                change("_memo.md", None, Some('b')),
+            ],
+            "now",
+        );
+        assert_eq!(res.revision, 2);
+        assert_eq!(res.applied.len(), 2);
+        assert!(res.conflicts.is_empty());
+
+        let res = index.apply("laptop", vec![change("_memo.md", Some('b'), None)], "now");
+        assert_eq!(res.applied[0].hash, None);
+        assert_eq!(res.applied[0].revision, 3);
+
+        let res = index.apply("laptop", vec![change("_meta.json", None, Some('a'))], "now");
+        assert!(res.applied.is_empty() && res.conflicts.is_empty());
+        assert_eq!(res.revision, 3);
+    }
+
+    #[test]
+    fn reports_conflicts_and_missing_blobs() {
+        let mut index = index_with_blobs(&['a', 'b', 'c']);
+        index.apply(
+            "laptop",
+            vec![change("transcript.json", None, Some('a'))],
+            "now",
+        );
+        index.apply(
+            "desktop",
+            vec![change("transcript.json", Some('a'), Some('b'))],
+            "now",
+        );
+
+        let res = index.apply(
+            "laptop",
+            vec![
+                change("transcript.json", Some('a'), Some('c')),
+                change("audio.mp3", None, Some('d')),
+                change("audio.wav", None, Some('d')),
+            ],
+            "now",
+        );
+        assert!(res.applied.is_empty());
+        assert_eq!(res.conflicts.len(), 1);
+        assert_eq!(res.conflicts[0].server.hash, Some(hash('b')));
+        assert_eq!(res.conflicts[0].server.device_id, "desktop");
+        assert_eq!(res.missing_blobs, vec![hash('d')]);
+    }
+
+    #[test]
+    fn pages_change_feed() {
+        let mut index = index_with_blobs(&['a']);
+        let changes = (0..5)
+            .map(|i| change(&format!("attachments/{}.png", i), None, Some('a')))
+            .collect();
+        index.apply("laptop", changes, "now");
+
+        let first = index.changes_since(0, 2);
+        assert_eq!(first.changes.len(), 2);
+        assert!(first.has_more);
+        assert_eq!(first.next_since, 2);
+
+        let rest = index.changes_since(first.next_since, 10);
+        assert_eq!(
+            rest.changes.iter().map(|c| c.revision).collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+        assert!(!rest.has_more);
+        assert_eq!(rest.next_since, 5);
+        assert!(index.changes_since(5, 10).changes.is_empty());
+    }
+
+    #[test]
+    fn validates_paths_and_hashes() {
+        assert!(validate_change(&change("_meta.json", None, Some('a'))).is_ok());
+        assert!(validate_change(&change("attachments/a.png", None, Some('a'))).is_ok());
+        assert!(validate_change(&change("../escape", None, Some('a'))).is_err());
+        assert!(validate_change(&change("a//b", None, Some('a'))).is_err());
+        assert!(validate_change(&change("_meta.json", None, Some('A'))).is_err());
+
+        let mut bad_session = change("_meta.json", None, None);
+        bad_session.session_id = "..".to_string();
+        assert!(validate_change(&bad_session).is_err());
+    }
+}
@@ -1,9 +1,16 @@
+mod auth;
 mod config;
 mod error;
+mod index;
 mod routes;
 mod state;
+mod storage;
+mod types;
 
-pub use config::SyncConfig;
+pub use auth::SyncUser;
+pub use config::{DEFAULT_MAX_BLOB_BYTES, SyncConfig};
 pub use error::{Result, SyncError};
 pub use routes::{openapi, router};
 pub use state::AppState;
+pub use storage::BlobStorage;
+pub use types::*;
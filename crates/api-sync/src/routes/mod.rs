@@ -1,10 +1,32 @@
-use axum::Router;
+pub(crate) mod sync;
+
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+};
 use utoipa::OpenApi;
 
 use crate::state::AppState;
 
 #[derive(OpenApi)]
 #[openapi(
+    paths(
+        sync::list_changes,
+        sync::push,
+        sync::put_blob,
+        sync::get_blob,
+    ),
+    components(
+        schemas(
+            crate::types::FileEntry,
+            crate::types::FileChange,
+            crate::types::PushRequest,
+            crate::types::PushResponse,
+            crate::types::SyncConflict,
+            crate::types::ChangesResponse,
+        )
+    ),
     tags(
         (name = "sync", description = "Sync management")
     )
@@ -16,5 +38,16 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
 }
 
 pub fn router(state: AppState) -> Router {
-    Router::new().with_state(state)
+    let max_blob_bytes = state.config.max_blob_bytes;
+
+    Router::new()
+        .route("/changes", get(sync::list_changes))
+        .route("/push", post(sync::push))
+        .route(
+            "/blobs/{hash}",
+            get(sync::get_blob)
+                .put(sync::put_blob)
+                .layer(DefaultBodyLimit::max(max_blob_bytes)),
+        )
+        .with_state(state)
 }
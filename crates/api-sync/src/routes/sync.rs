@@ -0,0 +1,245 @@
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use sha2::{Digest, Sha256};
+
+use crate::auth::SyncUser;
+use crate::error::{Result, SyncError};
+use crate::index::{self, MAX_CHANGES_PAGE};
+use crate::state::AppState;
+use crate::storage::blob_key;
+use crate::types::{ChangesQuery, ChangesResponse, PushRequest, PushResponse};
+
+#[utoipa::path(
+    get,
+    path = "/changes",
+    params(ChangesQuery),
+    responses(
+        (status = 200, description = "Files changed after `since`, oldest first", body = ChangesResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "sync",
+)]
+pub async fn list_changes(
+    State(state): State<AppState>,
+    user: SyncUser,
+    Query(query): Query<ChangesQuery>,
+) -> Result<Json<ChangesResponse>> {
+    let index = state.index(&user.id).await?;
+    let limit = query.limit.unwrap_or(MAX_CHANGES_PAGE);
+
+    Ok(Json(index.changes_since(query.since, limit)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/push",
+    request_body = PushRequest,
+    responses(
+        (status = 200, description = "Changes applied; conflicts and missing blobs are reported", body = PushResponse),
+        (status = 400, description = "Invalid path or hash"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "sync",
+)]
+pub async fn push(
+    State(state): State<AppState>,
+    user: SyncUser,
+    Json(payload): Json<PushRequest>,
+) -> Result<Json<PushResponse>> {
+    if payload.device_id.trim().is_empty() {
+        return Err(SyncError::BadRequest("device_id is required".into()));
+    }
+    for change in &payload.changes {
+        index::validate_change(change).map_err(SyncError::BadRequest)?;
+    }
+
+    let mut index = state.index(&user.id).await?;
+    let mut next = index.clone();
+    let now = chrono::Utc::now().to_rfc3339();
+    let response = next.apply(&payload.device_id, payload.changes, &now);
+
+    if !response.applied.is_empty() {
+        state.save_index(&user.id, &next).await?;
+        *index = next;
+    }
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    put,
+    path = "/blobs/{hash}",
+    params(("hash" = String, Path, description = "Lowercase hex SHA-256 of the body")),
+    request_body(content = Vec<u8>, content_type = "application/octet-stream"),
+    responses(
+        (status = 204, description = "Blob stored"),
+        (status = 400, description = "Body does not match the hash"),
+        (status = 401, description = "Unauthorized"),
+        (status = 413, description = "Blob too large"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "sync",
+)]
+pub async fn put_blob(
+    State(state): State<AppState>,
+    user: SyncUser,
+    Path(hash): Path<String>,
+    body: Bytes,
+) -> Result<StatusCode> {
+    if !index::is_valid_hash(&hash) {
+        return Err(SyncError::BadRequest(format!("invalid hash {:?}", hash)));
+    }
+    if hex::encode(Sha256::digest(&body)) != hash {
+        return Err(SyncError::BadRequest("content does not match hash".into()));
+    }
+
+    if state.index(&user.id).await?.has_blob(&hash) {
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    // Upload outside the index lock; blobs are content-addressed, so a
+    // concurrent upload of the same hash writes identical bytes.
+    let size = body.len() as u64;
+    state
+        .storage
+        .put(&user.id, &blob_key(&hash), body.to_vec())
+        .await?;
+
+    let mut index = state.index(&user.id).await?;
+    let mut next = index.clone();
+    next.add_blob(&hash, size);
+    state.save_index(&user.id, &next).await?;
+    *index = next;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/blobs/{hash}",
+    params(("hash" = String, Path, description = "Lowercase hex SHA-256 of the content")),
+    responses(
+        (status = 200, description = "Blob content", body = Vec<u8>, content_type = "application/octet-stream"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Blob not uploaded"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "sync",
+)]
+pub async fn get_blob(
+    State(state): State<AppState>,
+    user: SyncUser,
+    Path(hash): Path<String>,
+) -> Result<Vec<u8>> {
+    if !index::is_valid_hash(&hash) || !state.index(&user.id).await?.has_blob(&hash) {
+        return Err(SyncError::NotFound(hash));
+    }
+
+    state
+        .storage
+        .get(&user.id, &blob_key(&hash))
+        .await?
+        .ok_or(SyncError::NotFound(hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Method, Request},
+    };
+    use tower::ServiceExt;
+
+    use crate::config::SyncConfig;
+    use crate::storage::BlobStorage;
+
+    use super::*;
+
+    const TOKEN: &str = "team-secret";
+
+    async fn send(
+        app: &Router,
+        method: Method,
+        uri: &str,
+        token: &str,
+        body: Body,
+    ) -> (StatusCode, Vec<u8>) {
+        let req = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Content-Type", "application/json")
+            .body(body)
+            .unwrap();
+        let res = app.clone().oneshot(req).await.unwrap();
+        let status = res.status();
+        (
+            status,
+            to_bytes(res.into_body(), usize::MAX)
+                .await
+                .unwrap()
+                .to_vec(),
+        )
+    }
+
+    #[tokio::test]
+    async fn push_and_pull_between_devices() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = SyncConfig::new("", "").with_shared_token(TOKEN);
+        let app = crate::router(AppState::new(config, BlobStorage::local(dir.path())));
+
+        let (status, _) = send(&app, Method::GET, "/changes", "wrong", Body::empty()).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let content = b"# Notes";
+        let hash = hex::encode(Sha256::digest(content));
+        let push = serde_json::json!({
+            "device_id": "laptop",
+            "changes": [{ "session_id": "s1", "path": "_memo.md", "hash": hash, "size": 7 }],
+        })
+        .to_string();
+
+        let (_, body) = send(&app, Method::POST, "/push", TOKEN, push.clone().into()).await;
+        let res: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(res["missing_blobs"], serde_json::json!([hash]));
+
+        let uri = format!("/blobs/{}", hash);
+        let (status, _) = send(&app, Method::PUT, &uri, TOKEN, b"tampered"[..].into()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = send(&app, Method::PUT, &uri, TOKEN, content[..].into()).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (_, body) = send(&app, Method::POST, "/push", TOKEN, push.into()).await;
+        let res: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(res["revision"], 1);
+
+        // A fresh server over the same directory sees the persisted index.
+        let config = SyncConfig::new("", "").with_shared_token(TOKEN);
+        let app = crate::router(AppState::new(config, BlobStorage::local(dir.path())));
+
+        let (_, body) = send(&app, Method::GET, "/changes?since=0", TOKEN, Body::empty()).await;
+        let res: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(res["changes"][0]["device_id"], "laptop");
+        assert_eq!(res["next_since"], 1);
+
+        let (status, body) = send(&app, Method::GET, &uri, TOKEN, Body::empty()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, content);
+
+        let stale = serde_json::json!({
+            "device_id": "desktop",
+            "changes": [{ "session_id": "s1", "path": "_memo.md", "hash": "0".repeat(64) }],
+        })
+        .to_string();
+        let (_, body) = send(&app, Method::POST, "/push", TOKEN, stale.into()).await;
+        let res: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(res["conflicts"][0]["server"]["hash"], hash);
+    }
+}
@@ -1,12 +1,56 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
 use crate::config::SyncConfig;
+use crate::error::{Result, SyncError};
+use crate::index::UserIndex;
+use crate::storage::{BlobStorage, INDEX_KEY};
+
+type IndexCache = Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<UserIndex>>>>>;
 
 #[derive(Clone)]
 pub struct AppState {
     pub config: SyncConfig,
+    pub storage: BlobStorage,
+    indexes: IndexCache,
 }
 
 impl AppState {
-    pub fn new(config: SyncConfig) -> Self {
-        Self { config }
+    pub fn new(config: SyncConfig, storage: BlobStorage) -> Self {
+        Self {
+            config,
+            storage,
+            indexes: Default::default(),
+        }
+    }
+
+    /// Locks the user's index, loading it from storage on first use. Requests
+    /// for the same user are serialized so revisions stay monotonic.
+    pub(crate) async fn index(
+        &self,
+        user_id: &str,
+    ) -> Result<tokio::sync::OwnedMutexGuard<UserIndex>> {
+        let slot = self
+            .indexes
+            .lock()
+            .unwrap()
+            .entry(user_id.to_string())
+            .or_default()
+            .clone();
+
+        let mut guard = slot.lock_owned().await;
+        if guard.revision == 0
+            && guard.blobs.is_empty()
+            && let Some(data) = self.storage.get(user_id, INDEX_KEY).await?
+        {
+            *guard = serde_json::from_slice(&data)
+                .map_err(|e| SyncError::Internal(format!("corrupt index: {}", e)))?;
+        }
+        Ok(guard)
+    }
+
+    pub(crate) async fn save_index(&self, user_id: &str, index: &UserIndex) -> Result<()> {
+        let data = serde_json::to_vec(index).map_err(|e| SyncError::Internal(e.to_string()))?;
+        self.storage.put(user_id, INDEX_KEY, data).await
     }
 }
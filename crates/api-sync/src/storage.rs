@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use crate::error::{Result, SyncError};
+
+pub(crate) const INDEX_KEY: &str = "index.json";
+
+pub(crate) fn blob_key(hash: &str) -> String {
+    format!("blobs/{}", hash)
+}
+
+/// Where blobs and per-user indexes live. Both backends namespace keys under
+/// `user_<id>/`, matching `hypr_s3::UserClient`.
+#[derive(Clone)]
+pub enum BlobStorage {
+    Local { root: PathBuf },
+    S3(hypr_s3::Client),
+}
+
+impl BlobStorage {
+    pub fn local(root: impl Into<PathBuf>) -> Self {
+        Self::Local { root: root.into() }
+    }
+
+    pub fn s3(client: hypr_s3::Client) -> Self {
+        Self::S3(client)
+    }
+
+    pub(crate) async fn get(&self, user_id: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        match self {
+            Self::Local { root } => {
+                let path = local_path(root, user_id, key)?;
+                match tokio::fs::read(&path).await {
+                    Ok(data) => Ok(Some(data)),
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                    Err(e) => Err(SyncError::Internal(e.to_string())),
+                }
+            }
+            Self::S3(client) => match client.for_user(user_id).get(key).await {
+                Ok(data) => Ok(Some(data.to_vec())),
+                Err(hypr_s3::ApiError::GetObjectError(e))
+                    if e.as_service_error().is_some_and(|e| e.is_no_such_key()) =>
+                {
+                    Ok(None)
+                }
+                Err(e) => Err(SyncError::Internal(e.to_string())),
+            },
+        }
+    }
+
+    pub(crate) async fn put(&self, user_id: &str, key: &str, data: Vec<u8>) -> Result<()> {
+        match self {
+            Self::Local { root } => {
+                let path = local_path(root, user_id, key)?;
+                if let Some(parent) = path.parent() {
+                    tokio::fs::create_dir_all(parent)
+                        .await
+                        .map_err(|e| SyncError::Internal(e.to_string()))?;
+                }
+
+                // Write-then-rename so readers never see a partial file.
+                let tmp = path.with_extension("tmp");
+                tokio::fs::write(&tmp, data)
+                    .await
+                    .map_err(|e| SyncError::Internal(e.to_string()))?;
+                tokio::fs::rename(&tmp, &path)
+                    .await
+                    .map_err(|e| SyncError::Internal(e.to_string()))
+            }
+            Self::S3(client) => client
+                .for_user(user_id)
+                .put(key, data)
+                .await
+                .map_err(|e| SyncError::Internal(e.to_string())),
+        }
+    }
+}
+
+fn local_path(root: &std::path::Path, user_id: &str, key: &str) -> Result<PathBuf> {
+    let valid = !user_id.is_empty()
+        && user_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(SyncError::Internal(format!(
+            "invalid user id {:?}",
+            user_id
+        )));
+    }
+
+    Ok(root.join(format!("user_{}", user_id)).join(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn local_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = BlobStorage::local(dir.path());
+
+        assert_eq!(storage.get("u1", INDEX_KEY).await.unwrap(), None);
+
+        let key = blob_key(&"a".repeat(64));
+        storage.put("u1", &key, b"hello".to_vec()).await.unwrap();
+        assert_eq!(
+            storage.get("u1", &key).await.unwrap().as_deref(),
+            Some(&b"hello"[..])
+        );
+        assert_eq!(storage.get("u2", &key).await.unwrap(), None);
+        assert!(dir.path().join("user_u1/blobs").is_dir());
+
+        assert!(storage.get("../u1", &key).await.is_err());
+    }
+}
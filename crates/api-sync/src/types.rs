@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// Latest known state of one file in a session bundle. `hash` is `None` once
+/// the file has been deleted, so deletions propagate through the change feed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FileEntry {
+    pub session_id: String,
+    /// Path inside the session directory, e.g. `_meta.json` or `transcript.json`.
+    pub path: String,
+    /// SHA-256 of the content, hex encoded.
+    pub hash: Option<String>,
+    pub size: u64,
+    /// Server revision at which this entry last changed.
+    pub revision: u64,
+    pub device_id: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct FileChange {
+    pub session_id: String,
+    pub path: String,
+    /// Hash the device last pulled for this file, or `None` if it never saw
+    /// one. The change is rejected as a conflict if the server has moved on.
+    #[serde(default)]
+    pub base_hash: Option<String>,
+    /// New content hash, or `None` to delete the file.
+    #[serde(default)]
+    pub hash: Option<String>,
+    #[serde(default)]
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct PushRequest {
+    pub device_id: String,
+    pub changes: Vec<FileChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct SyncConflict {
+    pub session_id: String,
+    pub path: String,
+    pub base_hash: Option<String>,
+    pub hash: Option<String>,
+    pub server: FileEntry,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+pub struct PushResponse {
+    pub revision: u64,
+    pub applied: Vec<FileEntry>,
+    pub conflicts: Vec<SyncConflict>,
+    /// Blobs that must be uploaded before the changes referencing them can
+    /// be applied. Those changes are skipped; push them again afterwards.
+    pub missing_blobs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ChangesQuery {
+    #[serde(default)]
+    pub since: u64,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ChangesResponse {
+    pub revision: u64,
+    pub changes: Vec<FileEntry>,
+    /// Pass as `since` to fetch the next page.
+    pub next_since: u64,
+    pub has_more: bool,
+}
@@ -0,0 +1,264 @@
+use chrono::{DateTime, Utc};
+use reqwest::{Method, header};
+use url::Url;
+
+use crate::error::Error;
+use crate::expand::events_in_range;
+use crate::ics;
+use crate::types::{AttendeeResponse, Calendar, Event, EventStatus, NewEvent};
+use crate::xml::{self, CALDAV, DAV, DavResponse};
+
+#[derive(Clone)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+/// A CalDAV (RFC 4791) client. `base_url` can be the server root, a
+/// principal or a calendar home; discovery starts from there.
+#[derive(Clone)]
+pub struct CalDavClient {
+    http: reqwest::Client,
+    base_url: Url,
+    credentials: Option<Credentials>,
+}
+
+impl CalDavClient {
+    pub fn new(base_url: &str) -> Result<Self, Error> {
+        Ok(Self {
+            http: reqwest::Client::new(),
+            base_url: Url::parse(base_url)?,
+            credentials: None,
+        })
+    }
+
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    pub async fn list_calendars(&self) -> Result<Vec<Calendar>, Error> {
+        let home = self.calendar_home().await?;
+        let body = propfind_body(&[
+            (DAV, "resourcetype"),
+            (DAV, "displayname"),
+            (DAV, "current-user-privilege-set"),
+            (CALDAV, "calendar-description"),
+            (CALDAV, "supported-calendar-component-set"),
+            ("http://apple.com/ns/ical/", "calendar-color"),
+            ("http://calendarserver.org/ns/", "getctag"),
+        ]);
+
+        let mut calendars = Vec::new();
+        for response in self.dav(propfind(), &home, "1", body).await? {
+            if !response.is_calendar || !response.supports_events {
+                continue;
+            }
+            let id = home.join(&response.href)?;
+            let name = response
+                .display_name
+                .unwrap_or_else(|| last_segment(&id).to_string());
+            calendars.push(Calendar {
+                id: id.to_string(),
+                name,
+                description: response.description,
+                color: response.color,
+                ctag: response.ctag,
+                read_only: response.read_only,
+            });
+        }
+        Ok(calendars)
+    }
+
+    /// Occurrences in `[start, end)`, with recurring series expanded
+    /// client-side since not every server supports `<C:expand>`.
+    pub async fn list_events(
+        &self,
+        calendar_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Event>, Error> {
+        let url = self.resolve(calendar_id)?;
+        let format = |dt: DateTime<Utc>| dt.format("%Y%m%dT%H%M%SZ").to_string();
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<C:calendar-query xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:prop><D:getetag/><C:calendar-data/></D:prop>
+  <C:filter>
+    <C:comp-filter name="VCALENDAR">
+      <C:comp-filter name="VEVENT">
+        <C:time-range start="{}" end="{}"/>
+      </C:comp-filter>
+    </C:comp-filter>
+  </C:filter>
+</C:calendar-query>"#,
+            format(start),
+            format(end),
+        );
+
+        let mut events = Vec::new();
+        for response in self.dav(report(), &url, "1", body).await? {
+            if let Some(data) = response.calendar_data {
+                events.extend(events_in_range(&data, calendar_id, start, end)?);
+            }
+        }
+        events.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.id.cmp(&b.id)));
+        Ok(events)
+    }
+
+    pub async fn create_event(&self, calendar_id: &str, event: NewEvent) -> Result<Event, Error> {
+        let collection = self.resolve(&with_trailing_slash(calendar_id))?;
+        let uid = uuid::Uuid::new_v4().to_string();
+        let url = collection.join(&format!("{uid}.ics"))?;
+        let body = ics::write_event(&uid, &event, Utc::now());
+
+        let request = self
+            .authorize(self.http.put(url.clone()))
+            .header(header::CONTENT_TYPE, "text/calendar; charset=utf-8")
+            .header(header::IF_NONE_MATCH, "*")
+            .body(body);
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            return Err(Error::Status {
+                status: response.status().as_u16(),
+                url: url.to_string(),
+            });
+        }
+
+        Ok(Event {
+            id: uid.clone(),
+            uid,
+            calendar_id: calendar_id.to_string(),
+            summary: event.summary,
+            description: event.description,
+            location: event.location,
+            url: None,
+            start: event.start,
+            end: event.end,
+            all_day: event.all_day,
+            time_zone: None,
+            status: EventStatus::Confirmed,
+            organizer: None,
+            attendees: event
+                .attendees
+                .into_iter()
+                .map(|a| crate::Attendee {
+                    response: AttendeeResponse::NeedsAction,
+                    ..a
+                })
+                .collect(),
+            is_recurring: false,
+        })
+    }
+
+    /// Follows `current-user-principal` and `calendar-home-set` (RFC 6764
+    /// §6). A base URL that already is a principal or home skips the steps
+    /// it doesn't answer.
+    async fn calendar_home(&self) -> Result<Url, Error> {
+        let principal = self
+            .dav(
+                propfind(),
+                &self.base_url,
+                "0",
+                propfind_body(&[(DAV, "current-user-principal")]),
+            )
+            .await?
+            .into_iter()
+            .find_map(|r| r.current_user_principal);
+        let principal = match principal {
+            Some(href) => self.resolve(&href)?,
+            None => self.base_url.clone(),
+        };
+
+        let home = self
+            .dav(
+                propfind(),
+                &principal,
+                "0",
+                propfind_body(&[(CALDAV, "calendar-home-set")]),
+            )
+            .await?
+            .into_iter()
+            .find_map(|r| r.calendar_home_set);
+        match home {
+            Some(href) => self.resolve(&href),
+            None => Ok(principal),
+        }
+    }
+
+    /// Resolves an href against the base URL, refusing other origins so
+    /// credentials never leave the configured server.
+    fn resolve(&self, href: &str) -> Result<Url, Error> {
+        let url = self.base_url.join(href)?;
+        if url.origin() != self.base_url.origin() {
+            return Err(Error::ForeignUrl(url.to_string()));
+        }
+        Ok(url)
+    }
+
+    fn authorize(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.credentials {
+            Some(c) => request.basic_auth(&c.username, Some(&c.password)),
+            None => request,
+        }
+    }
+
+    async fn dav(
+        &self,
+        method: Method,
+        url: &Url,
+        depth: &str,
+        body: String,
+    ) -> Result<Vec<DavResponse>, Error> {
+        let request = self
+            .authorize(self.http.request(method, url.clone()))
+            .header("Depth", depth)
+            .header(header::CONTENT_TYPE, "application/xml; charset=utf-8")
+            .body(body);
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            return Err(Error::Status {
+                status: response.status().as_u16(),
+                url: url.to_string(),
+            });
+        }
+        xml::parse_multistatus(&response.text().await?)
+    }
+}
+
+fn propfind() -> Method {
+    Method::from_bytes(b"PROPFIND").unwrap()
+}
+
+fn report() -> Method {
+    Method::from_bytes(b"REPORT").unwrap()
+}
+
+fn propfind_body(props: &[(&str, &str)]) -> String {
+    let props: String = props
+        .iter()
+        .map(|(ns, name)| format!(r#"<{name} xmlns="{}"/>"#, xml::escape(ns)))
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><propfind xmlns="DAV:"><prop>{props}</prop></propfind>"#
+    )
+}
+
+fn with_trailing_slash(href: &str) -> String {
+    if href.ends_with('/') {
+        href.to_string()
+    } else {
+        format!("{href}/")
+    }
+}
+
+fn last_segment(url: &Url) -> &str {
+    url.path_segments()
+        .and_then(|mut segments| segments.rfind(|s| !s.is_empty()))
+        .unwrap_or_else(|| url.as_str())
+}
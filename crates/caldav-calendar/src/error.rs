@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("HTTP client error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("Unexpected status {status} from {url}")]
+    Status { status: u16, url: String },
+
+    #[error("Invalid URL: {0}")]
+    Url(#[from] url::ParseError),
+
+    #[error("{0} is not on the configured server")]
+    ForeignUrl(String),
+
+    #[error("XML error: {0}")]
+    Xml(#[from] roxmltree::Error),
+
+    #[error("Invalid iCalendar data: {0}")]
+    Ics(String),
+
+    #[cfg(feature = "db")]
+    #[error("Database error: {0}")]
+    Db(#[from] hypr_db_user::Error),
+}
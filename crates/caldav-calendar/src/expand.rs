@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+
+use crate::error::Error;
+use crate::ics::{self, Component, DateValue, Property, Zone};
+use crate::rrule::RRule;
+use crate::types::{Attendee, AttendeeResponse, Event, EventStatus};
+
+/// Every event occurrence in `ics` that overlaps `[start, end)`, sorted by
+/// start. Recurring series are expanded, honouring `EXDATE`, `RDATE` and
+/// per-instance overrides.
+pub fn events_in_range(
+    ics: &str,
+    calendar_id: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<Event>, Error> {
+    let mut events = Vec::new();
+    for calendar in ics::parse(ics)? {
+        let default_tz = calendar
+            .property("X-WR-TIMEZONE")
+            .and_then(|p| match ics::resolve_tzid(&p.value) {
+                Zone::Tz(tz) => Some(tz),
+                _ => None,
+            })
+            .unwrap_or(Tz::UTC);
+        let vevents: Vec<&Component> = calendar
+            .children
+            .iter()
+            .filter(|c| c.name == "VEVENT")
+            .collect();
+        expand_calendar(&vevents, calendar_id, default_tz, (start, end), &mut events);
+    }
+
+    events.retain(|e| e.start < end && (e.end > start || (e.end == e.start && e.start >= start)));
+    events.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.id.cmp(&b.id)));
+    Ok(events)
+}
+
+/// The calendar's display name from `X-WR-CALNAME`, as ICS feeds carry it.
+pub(crate) fn calendar_name(ics: &str) -> Option<String> {
+    ics::parse(ics).ok()?.first()?.text("X-WR-CALNAME")
+}
+
+struct VEvent<'a> {
+    component: &'a Component,
+    uid: String,
+    start: DateValue,
+    duration: Duration,
+    recurrence_id: Option<DateValue>,
+}
+
+impl<'a> VEvent<'a> {
+    fn parse(component: &'a Component, default_tz: Tz) -> Result<Option<Self>, Error> {
+        let Some(dtstart) = component.property("DTSTART") else {
+            return Ok(None);
+        };
+        let start = DateValue::from_property(dtstart)?;
+
+        let duration = match (component.property("DTEND"), component.property("DURATION")) {
+            (Some(dtend), _) => {
+                DateValue::from_property(dtend)?.to_utc(default_tz) - start.to_utc(default_tz)
+            }
+            (None, Some(duration)) => ics::parse_duration(&duration.value)
+                .ok_or_else(|| Error::Ics(format!("invalid DURATION {:?}", duration.value)))?,
+            (None, None) if start.is_date => Duration::days(1),
+            (None, None) => Duration::zero(),
+        };
+
+        let uid = component
+            .property("UID")
+            .map(|p| p.value.trim().to_string())
+            .unwrap_or_else(|| {
+                format!(
+                    "{}-{}",
+                    start.local.format("%Y%m%dT%H%M%S"),
+                    component.text("SUMMARY").unwrap_or_default()
+                )
+            });
+
+        let recurrence_id = component
+            .property("RECURRENCE-ID")
+            .map(DateValue::from_property)
+            .transpose()?;
+
+        Ok(Some(Self {
+            component,
+            uid,
+            start,
+            duration: duration.max(Duration::zero()),
+            recurrence_id,
+        }))
+    }
+
+    fn is_cancelled(&self) -> bool {
+        status(self.component) == EventStatus::Cancelled
+    }
+}
+
+fn expand_calendar(
+    vevents: &[&Component],
+    calendar_id: &str,
+    default_tz: Tz,
+    (range_start, range_end): (DateTime<Utc>, DateTime<Utc>),
+    out: &mut Vec<Event>,
+) {
+    let mut masters = Vec::new();
+    let mut overrides: HashMap<String, Vec<VEvent>> = HashMap::new();
+    // One malformed or unsupported event shouldn't hide the rest of the
+    // calendar, so it's skipped with a warning.
+    for component in vevents {
+        let event = match VEvent::parse(component, default_tz) {
+            Ok(Some(event)) => event,
+            Ok(None) => continue,
+            Err(e) => {
+                tracing::warn!(uid = ?component.text("UID"), "skipping_calendar_event: {}", e);
+                continue;
+            }
+        };
+        match event.recurrence_id {
+            Some(_) => overrides.entry(event.uid.clone()).or_default().push(event),
+            None => masters.push(event),
+        }
+    }
+
+    for master in &masters {
+        let instance_overrides = overrides.remove(&master.uid).unwrap_or_default();
+        let rrule = match master
+            .component
+            .property("RRULE")
+            .map(|p| p.value.parse::<RRule>())
+            .transpose()
+        {
+            Ok(rrule) => rrule,
+            Err(e) => {
+                tracing::warn!(uid = %master.uid, "skipping_calendar_event: {}", e);
+                continue;
+            }
+        };
+        let rdates: Vec<DateValue> = master
+            .component
+            .properties("RDATE")
+            .flat_map(ics::date_list)
+            .collect();
+
+        if rrule.is_none() && rdates.is_empty() {
+            out.push(to_event(
+                master,
+                master.start,
+                calendar_id,
+                default_tz,
+                false,
+            ));
+            continue;
+        }
+
+        let zone = master.start.zone;
+        let to_local = |value: DateValue| value.local_in(zone, default_tz);
+        // Instances are generated in the series' wall-clock time; a day of
+        // slack covers any zone offset between that and UTC.
+        let not_after = DateValue {
+            local: range_end.naive_utc() + Duration::days(1),
+            zone: Zone::Utc,
+            is_date: false,
+        };
+
+        let mut locals = match &rrule {
+            Some(rule) => {
+                let until = rule.until.map(|until| {
+                    if until.is_date {
+                        until.local + Duration::days(1) - Duration::seconds(1)
+                    } else {
+                        to_local(until)
+                    }
+                });
+                rule.occurrences(master.start.local, until, to_local(not_after))
+            }
+            None => vec![master.start.local],
+        };
+        locals.extend(rdates.into_iter().map(to_local));
+        let exdates: Vec<DateValue> = master
+            .component
+            .properties("EXDATE")
+            .flat_map(ics::date_list)
+            .collect();
+        let excluded = |local: &chrono::NaiveDateTime| {
+            exdates.iter().any(|ex| {
+                if ex.is_date {
+                    ex.local.date() == local.date()
+                } else {
+                    to_local(*ex) == *local
+                }
+            })
+        };
+        let replaced: Vec<_> = instance_overrides
+            .iter()
+            .filter_map(|o| o.recurrence_id.map(to_local))
+            .collect();
+        locals.sort();
+        locals.dedup();
+        locals.retain(|local| !excluded(local) && !replaced.contains(local));
+
+        for local in locals {
+            let start = DateValue {
+                local,
+                ..master.start
+            };
+            if start.to_utc(default_tz) + master.duration < range_start {
+                continue;
+            }
+            out.push(to_event(master, start, calendar_id, default_tz, true));
+        }
+
+        for instance in instance_overrides.iter().filter(|o| !o.is_cancelled()) {
+            out.push(to_event(
+                instance,
+                instance.start,
+                calendar_id,
+                default_tz,
+                true,
+            ));
+        }
+    }
+
+    // Overrides whose series isn't in this response, e.g. a CalDAV server
+    // that only matched the moved instance.
+    for instance in overrides.values().flatten().filter(|o| !o.is_cancelled()) {
+        out.push(to_event(
+            instance,
+            instance.start,
+            calendar_id,
+            default_tz,
+            true,
+        ));
+    }
+}
+
+fn to_event(
+    vevent: &VEvent,
+    start: DateValue,
+    calendar_id: &str,
+    default_tz: Tz,
+    is_recurring: bool,
+) -> Event {
+    let component = vevent.component;
+    let start_utc = start.to_utc(default_tz);
+
+    let id = if is_recurring {
+        let original = vevent.recurrence_id.unwrap_or(start).to_utc(default_tz);
+        format!("{}_{}", vevent.uid, original.format("%Y%m%dT%H%M%SZ"))
+    } else {
+        vevent.uid.clone()
+    };
+
+    Event {
+        id,
+        uid: vevent.uid.clone(),
+        calendar_id: calendar_id.to_string(),
+        summary: component.text("SUMMARY").unwrap_or_default(),
+        description: component.text("DESCRIPTION"),
+        location: component.text("LOCATION"),
+        url: component.text("URL"),
+        start: start_utc,
+        end: start_utc + vevent.duration,
+        all_day: start.is_date,
+        time_zone: component
+            .property("DTSTART")
+            .and_then(|p| p.param("TZID"))
+            .map(str::to_string),
+        status: status(component),
+        organizer: component.property("ORGANIZER").map(attendee),
+        attendees: component.properties("ATTENDEE").map(attendee).collect(),
+        is_recurring,
+    }
+}
+
+fn status(component: &Component) -> EventStatus {
+    match component
+        .property("STATUS")
+        .map(|p| p.value.to_ascii_uppercase())
+    {
+        Some(s) if s == "CANCELLED" => EventStatus::Cancelled,
+        Some(s) if s == "TENTATIVE" => EventStatus::Tentative,
+        _ => EventStatus::Confirmed,
+    }
+}
+
+fn attendee(property: &Property) -> Attendee {
+    let address = property.value.trim();
+    let email = match address.get(..7) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("mailto:") => Some(&address[7..]),
+        _ => property.param("EMAIL"),
+    }
+    .filter(|e| e.contains('@'))
+    .map(str::to_string);
+
+    let optional = property.param("ROLE").is_some_and(|role| {
+        role.eq_ignore_ascii_case("OPT-PARTICIPANT") || role.eq_ignore_ascii_case("NON-PARTICIPANT")
+    });
+    let response = match property.param("PARTSTAT").map(str::to_ascii_uppercase) {
+        Some(s) if s == "ACCEPTED" => AttendeeResponse::Accepted,
+        Some(s) if s == "DECLINED" => AttendeeResponse::Declined,
+        Some(s) if s == "TENTATIVE" => AttendeeResponse::Tentative,
+        Some(s) if s == "DELEGATED" => AttendeeResponse::Delegated,
+        _ => AttendeeResponse::NeedsAction,
+    };
+
+    Attendee {
+        email,
+        name: property.param("CN").map(str::to_string),
+        optional,
+        response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn recurring_series_with_exdate_and_override() {
+        let ics = include_str!("../tests/fixtures/weekly_standup.ics");
+        let events =
+            events_in_range(ics, "cal", utc(2026, 3, 1, 0, 0), utc(2026, 4, 1, 0, 0)).unwrap();
+
+        let starts: Vec<_> = events.iter().map(|e| e.start).collect();
+        assert_eq!(
+            starts,
+            [
+                // 09:30 Berlin is 08:30 UTC before the DST switch on Mar 29...
+                utc(2026, 3, 2, 8, 30),
+                // ...Mar 9 is excluded, Mar 16 moved to the afternoon...
+                utc(2026, 3, 16, 13, 0),
+                utc(2026, 3, 23, 8, 30),
+                // ...and 07:30 UTC after it.
+                utc(2026, 3, 30, 7, 30),
+            ]
+        );
+
+        let first = &events[0];
+        assert!(first.is_recurring);
+        assert_eq!(first.uid, "standup-1@example.com");
+        assert_eq!(first.id, "standup-1@example.com_20260302T083000Z");
+        assert_eq!(first.end - first.start, Duration::minutes(15));
+        assert_eq!(first.time_zone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(first.attendees.len(), 2);
+        assert_eq!(first.attendees[1].name.as_deref(), Some("Bob"));
+        assert!(first.attendees[1].optional);
+        assert_eq!(first.attendees[0].response, AttendeeResponse::Accepted);
+        assert_eq!(
+            first.organizer.as_ref().unwrap().email.as_deref(),
+            Some("alice@example.com")
+        );
+
+        let moved = &events[1];
+        assert_eq!(moved.summary, "Standup (moved)");
+        assert_eq!(moved.id, "standup-1@example.com_20260316T083000Z");
+    }
+
+    #[test]
+    fn single_and_all_day_events() {
+        let ics = include_str!("../tests/fixtures/feed.ics");
+        let events =
+            events_in_range(ics, "feed", utc(2026, 5, 1, 0, 0), utc(2026, 6, 1, 0, 0)).unwrap();
+
+        assert_eq!(events.len(), 2);
+        let offsite = &events[0];
+        assert!(offsite.all_day);
+        assert!(!offsite.is_recurring);
+        // Dates are read in the feed's X-WR-TIMEZONE.
+        assert_eq!(offsite.start, utc(2026, 5, 3, 22, 0));
+        assert_eq!(offsite.end - offsite.start, Duration::days(2));
+
+        let review = &events[1];
+        assert_eq!(review.summary, "Design review, round 2");
+        assert_eq!(review.location.as_deref(), Some("Room 4; 2nd floor"));
+        assert_eq!(review.start, utc(2026, 5, 12, 16, 0));
+        assert_eq!(review.end, utc(2026, 5, 12, 17, 30));
+        assert_eq!(calendar_name(ics).as_deref(), Some("Team"));
+    }
+
+    #[test]
+    fn skips_unsupported_events() {
+        let ics = include_str!("../tests/fixtures/unsupported.ics");
+        let events =
+            events_in_range(ics, "cal", utc(2026, 5, 1, 0, 0), utc(2026, 6, 1, 0, 0)).unwrap();
+        let summaries: Vec<_> = events.iter().map(|e| e.summary.as_str()).collect();
+        assert_eq!(summaries, ["Review"]);
+    }
+}
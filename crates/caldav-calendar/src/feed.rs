@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use url::Url;
+
+use crate::error::Error;
+use crate::expand::{calendar_name, events_in_range};
+use crate::types::{Calendar, Event};
+
+/// A read-only `.ics` subscription, as published by Fastmail, Nextcloud,
+/// iCloud and most booking tools.
+#[derive(Clone)]
+pub struct IcsFeedClient {
+    http: reqwest::Client,
+    url: Url,
+}
+
+impl IcsFeedClient {
+    /// Accepts `webcal://` links as well as plain HTTP(S) URLs.
+    pub fn new(url: &str) -> Result<Self, Error> {
+        let url = match url.get(..9) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("webcal://") => {
+                format!("https://{}", &url[9..])
+            }
+            _ => url.to_string(),
+        };
+        Ok(Self {
+            http: reqwest::Client::new(),
+            url: Url::parse(&url)?,
+        })
+    }
+
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub async fn calendar(&self) -> Result<Calendar, Error> {
+        let ics = self.fetch().await?;
+        Ok(Calendar {
+            id: self.url.to_string(),
+            name: calendar_name(&ics)
+                .or_else(|| self.url.host_str().map(str::to_string))
+                .unwrap_or_else(|| self.url.to_string()),
+            description: None,
+            color: None,
+            ctag: None,
+            read_only: true,
+        })
+    }
+
+    pub async fn list_events(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Event>, Error> {
+        let ics = self.fetch().await?;
+        events_in_range(&ics, self.url.as_str(), start, end)
+    }
+
+    async fn fetch(&self) -> Result<String, Error> {
+        let response = self.http.get(self.url.clone()).send().await?;
+        if !response.status().is_success() {
+            return Err(Error::Status {
+                status: response.status().as_u16(),
+                url: self.url.to_string(),
+            });
+        }
+        Ok(response.text().await?)
+    }
+}
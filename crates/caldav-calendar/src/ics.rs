@@ -0,0 +1,499 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::error::Error;
+use crate::types::NewEvent;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Property {
+    pub name: String,
+    pub params: Vec<(String, String)>,
+    pub value: String,
+}
+
+impl Property {
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Component {
+    pub name: String,
+    pub properties: Vec<Property>,
+    pub children: Vec<Component>,
+}
+
+impl Component {
+    pub fn property(&self, name: &str) -> Option<&Property> {
+        self.properties.iter().find(|p| p.name == name)
+    }
+
+    pub fn properties<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Property> {
+        self.properties.iter().filter(move |p| p.name == name)
+    }
+
+    pub fn text(&self, name: &str) -> Option<String> {
+        self.property(name)
+            .map(|p| unescape_text(&p.value))
+            .filter(|s| !s.is_empty())
+    }
+}
+
+/// Parses every top-level component, normally one `VCALENDAR`.
+pub(crate) fn parse(input: &str) -> Result<Vec<Component>, Error> {
+    let mut stack: Vec<Component> = Vec::new();
+    let mut roots = Vec::new();
+
+    for line in unfold(input) {
+        let property = parse_line(&line)?;
+        match property.name.as_str() {
+            "BEGIN" => stack.push(Component {
+                name: property.value.to_ascii_uppercase(),
+                ..Default::default()
+            }),
+            "END" => {
+                let component = stack
+                    .pop()
+                    .ok_or_else(|| Error::Ics(format!("unmatched END:{}", property.value)))?;
+                if !component.name.eq_ignore_ascii_case(&property.value) {
+                    return Err(Error::Ics(format!(
+                        "END:{} closes BEGIN:{}",
+                        property.value, component.name
+                    )));
+                }
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(component),
+                    None => roots.push(component),
+                }
+            }
+            _ => match stack.last_mut() {
+                Some(current) => current.properties.push(property),
+                None => return Err(Error::Ics(format!("{} outside a component", property.name))),
+            },
+        }
+    }
+
+    if let Some(open) = stack.pop() {
+        return Err(Error::Ics(format!("BEGIN:{} is never closed", open.name)));
+    }
+    Ok(roots)
+}
+
+/// Joins continuation lines (RFC 5545 §3.1) and drops blank ones.
+fn unfold(input: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in input.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match raw.strip_prefix([' ', '\t']) {
+            Some(rest) if !lines.is_empty() => lines.last_mut().unwrap().push_str(rest),
+            _ if raw.trim().is_empty() => {}
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+fn parse_line(line: &str) -> Result<Property, Error> {
+    let mut in_quotes = false;
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => {
+                segments.push(&line[start..i]);
+                start = i + 1;
+            }
+            ':' if !in_quotes => {
+                segments.push(&line[start..i]);
+                let mut segments = segments.into_iter();
+                let name = segments.next().unwrap_or_default().to_ascii_uppercase();
+                let params = segments
+                    .filter_map(|segment| {
+                        let (key, value) = segment.split_once('=')?;
+                        Some((
+                            key.to_ascii_uppercase(),
+                            value.trim_matches('"').to_string(),
+                        ))
+                    })
+                    .collect();
+                return Ok(Property {
+                    name,
+                    params,
+                    value: line[i + 1..].to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+    Err(Error::Ics(format!("missing ':' in {line:?}")))
+}
+
+pub(crate) fn unescape_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+pub(crate) fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Serializes a new event as a single-`VEVENT` calendar object.
+pub(crate) fn write_event(uid: &str, event: &NewEvent, now: DateTime<Utc>) -> String {
+    let utc = |dt: DateTime<Utc>| dt.format("%Y%m%dT%H%M%SZ").to_string();
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Char//CalDAV//EN".to_string(),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{uid}"),
+        format!("DTSTAMP:{}", utc(now)),
+    ];
+    if event.all_day {
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            event.start.format("%Y%m%d")
+        ));
+        lines.push(format!("DTEND;VALUE=DATE:{}", event.end.format("%Y%m%d")));
+    } else {
+        lines.push(format!("DTSTART:{}", utc(event.start)));
+        lines.push(format!("DTEND:{}", utc(event.end)));
+    }
+    lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+    if let Some(description) = &event.description {
+        lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    if let Some(location) = &event.location {
+        lines.push(format!("LOCATION:{}", escape_text(location)));
+    }
+    for attendee in &event.attendees {
+        let Some(email) = &attendee.email else {
+            continue;
+        };
+        let mut line = String::from("ATTENDEE");
+        if let Some(name) = &attendee.name {
+            line.push_str(&format!(";CN=\"{}\"", name.replace('"', "")));
+        }
+        let role = if attendee.optional {
+            "OPT-PARTICIPANT"
+        } else {
+            "REQ-PARTICIPANT"
+        };
+        line.push_str(&format!(
+            ";ROLE={role};PARTSTAT=NEEDS-ACTION:mailto:{email}"
+        ));
+        lines.push(line);
+    }
+    lines.push("END:VEVENT".to_string());
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect()
+}
+
+/// Splits lines longer than 75 octets (RFC 5545 §3.1) and terminates them
+/// with CRLF.
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 4);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+    out
+}
+
+/// Where a `DATE-TIME` sits in time. Floating times and dates are read in
+/// the calendar's default zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Zone {
+    Utc,
+    Tz(Tz),
+    Floating,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DateValue {
+    pub local: NaiveDateTime,
+    pub zone: Zone,
+    pub is_date: bool,
+}
+
+impl DateValue {
+    pub fn from_property(property: &Property) -> Result<Self, Error> {
+        let is_date = property
+            .param("VALUE")
+            .is_some_and(|v| v.eq_ignore_ascii_case("DATE"))
+            || property.value.len() == 8;
+        let zone = property.param("TZID").map(resolve_tzid);
+        parse_date_value(&property.value, is_date, zone)
+    }
+
+    pub fn to_utc(self, default_tz: Tz) -> DateTime<Utc> {
+        match self.zone {
+            Zone::Utc => Utc.from_utc_datetime(&self.local),
+            Zone::Tz(tz) => local_to_utc(tz, self.local),
+            Zone::Floating => local_to_utc(default_tz, self.local),
+        }
+    }
+
+    /// The same instant expressed in `zone`'s wall-clock time, so it can be
+    /// compared with recurrence instances generated there.
+    pub fn local_in(self, zone: Zone, default_tz: Tz) -> NaiveDateTime {
+        if self.zone == zone || self.is_date {
+            return self.local;
+        }
+        let utc = self.to_utc(default_tz);
+        match zone {
+            Zone::Utc => utc.naive_utc(),
+            Zone::Tz(tz) => utc.with_timezone(&tz).naive_local(),
+            Zone::Floating => utc.with_timezone(&default_tz).naive_local(),
+        }
+    }
+}
+
+fn parse_date_value(value: &str, is_date: bool, zone: Option<Zone>) -> Result<DateValue, Error> {
+    let value = value.trim();
+    if is_date {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map_err(|e| Error::Ics(format!("invalid DATE {value:?}: {e}")))?;
+        return Ok(DateValue {
+            local: date.and_time(NaiveTime::MIN),
+            zone: Zone::Floating,
+            is_date: true,
+        });
+    }
+
+    let (value, utc) = match value.strip_suffix(['Z', 'z']) {
+        Some(rest) => (rest, true),
+        None => (value, false),
+    };
+    let local = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .map_err(|e| Error::Ics(format!("invalid DATE-TIME {value:?}: {e}")))?;
+    let zone = if utc {
+        Zone::Utc
+    } else {
+        zone.unwrap_or(Zone::Floating)
+    };
+    Ok(DateValue {
+        local,
+        zone,
+        is_date: false,
+    })
+}
+
+/// Parses a comma-separated `EXDATE`/`RDATE` list.
+pub(crate) fn date_list(property: &Property) -> Vec<DateValue> {
+    property
+        .value
+        .split(',')
+        .filter_map(|value| {
+            let single = Property {
+                value: value.to_string(),
+                ..property.clone()
+            };
+            DateValue::from_property(&single).ok()
+        })
+        .collect()
+}
+
+/// A time that doesn't exist because of a DST jump is moved forward by the
+/// size of the gap, like most clients do.
+fn local_to_utc(tz: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    match tz.from_local_datetime(&local).earliest() {
+        Some(dt) => dt.with_timezone(&Utc),
+        None => local_to_utc(tz, local + Duration::hours(1)) - Duration::hours(1),
+    }
+}
+
+/// Maps a `TZID` to an IANA zone. Handles plain names, the path-prefixed
+/// IDs some clients emit and the Windows names Exchange uses. Anything else
+/// is read as UTC.
+pub(crate) fn resolve_tzid(tzid: &str) -> Zone {
+    let tzid = tzid.trim().trim_matches('"');
+    if let Ok(tz) = Tz::from_str(tzid) {
+        return Zone::Tz(tz);
+    }
+    if let Some(tz) = tzid
+        .match_indices('/')
+        .find_map(|(i, _)| Tz::from_str(&tzid[i + 1..]).ok())
+    {
+        return Zone::Tz(tz);
+    }
+    if let Some(tz) = windows_zone(tzid).and_then(|name| Tz::from_str(name).ok()) {
+        return Zone::Tz(tz);
+    }
+    Zone::Utc
+}
+
+fn windows_zone(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "Coordinated Universal Time" => "UTC",
+        "GMT Standard Time" => "Europe/London",
+        "Pacific Standard Time" => "America/Los_Angeles",
+        "Mountain Standard Time" => "America/Denver",
+        "Central Standard Time" => "America/Chicago",
+        "Eastern Standard Time" => "America/New_York",
+        "Atlantic Standard Time" => "America/Halifax",
+        "Hawaiian Standard Time" => "Pacific/Honolulu",
+        "Alaskan Standard Time" => "America/Anchorage",
+        "E. South America Standard Time" => "America/Sao_Paulo",
+        "W. Europe Standard Time" => "Europe/Berlin",
+        "Romance Standard Time" => "Europe/Paris",
+        "Central Europe Standard Time" => "Europe/Budapest",
+        "Central European Standard Time" => "Europe/Warsaw",
+        "FLE Standard Time" => "Europe/Kiev",
+        "GTB Standard Time" => "Europe/Bucharest",
+        "Russian Standard Time" => "Europe/Moscow",
+        "India Standard Time" => "Asia/Kolkata",
+        "China Standard Time" => "Asia/Shanghai",
+        "Singapore Standard Time" => "Asia/Singapore",
+        "Tokyo Standard Time" => "Asia/Tokyo",
+        "Korea Standard Time" => "Asia/Seoul",
+        "AUS Eastern Standard Time" => "Australia/Sydney",
+        "New Zealand Standard Time" => "Pacific/Auckland",
+        _ => return None,
+    })
+}
+
+/// Parses an RFC 5545 `DURATION` such as `PT1H30M` or `-P1W`.
+pub(crate) fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let mut rest = value.strip_prefix('P')?;
+
+    let mut total = Duration::zero();
+    let mut in_time = false;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('T') {
+            in_time = true;
+            rest = after;
+            continue;
+        }
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let amount: i64 = rest[..digits].parse().ok()?;
+        let unit = rest[digits..].chars().next()?;
+        total += match (unit, in_time) {
+            ('W', false) => Duration::weeks(amount),
+            ('D', false) => Duration::days(amount),
+            ('H', true) => Duration::hours(amount),
+            ('M', true) => Duration::minutes(amount),
+            ('S', true) => Duration::seconds(amount),
+            _ => return None,
+        };
+        rest = &rest[digits + unit.len_utf8()..];
+    }
+
+    Some(if negative { -total } else { total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unfolds_and_splits_params() {
+        let input = "BEGIN:VEVENT\r\nATTENDEE;CN=\"Doe; Jane\";ROLE=OPT-PARTICIPANT:mailto:jane@\r\n example.com\r\nSUMMARY:a\\, b\\nc\r\nEND:VEVENT\r\n";
+        let components = parse(input).unwrap();
+        let event = &components[0];
+
+        let attendee = event.property("ATTENDEE").unwrap();
+        assert_eq!(attendee.param("cn"), Some("Doe; Jane"));
+        assert_eq!(attendee.param("ROLE"), Some("OPT-PARTICIPANT"));
+        assert_eq!(attendee.value, "mailto:jane@example.com");
+        assert_eq!(event.text("SUMMARY").unwrap(), "a, b\nc");
+    }
+
+    #[test]
+    fn written_events_parse_back() {
+        let event = NewEvent {
+            summary: "Sync, weekly".into(),
+            start: Utc.with_ymd_and_hms(2026, 6, 1, 9, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 6, 1, 9, 30, 0).unwrap(),
+            all_day: false,
+            description: Some("A very long agenda line that certainly needs folding once it passes seventy-five octets".into()),
+            location: None,
+            attendees: vec![crate::Attendee {
+                email: Some("bob@example.com".into()),
+                name: Some("Bob".into()),
+                optional: true,
+                response: crate::AttendeeResponse::NeedsAction,
+            }],
+        };
+        let ics = write_event("uid-1", &event, event.start);
+        assert!(ics.lines().all(|line| line.len() <= 75));
+
+        let components = parse(&ics).unwrap();
+        let vevent = &components[0].children[0];
+        assert_eq!(vevent.text("SUMMARY").unwrap(), "Sync, weekly");
+        assert_eq!(vevent.text("DESCRIPTION"), event.description);
+        assert_eq!(
+            vevent.property("ATTENDEE").unwrap().param("CN"),
+            Some("Bob")
+        );
+    }
+
+    #[test]
+    fn rejects_unbalanced_components() {
+        assert!(parse("BEGIN:VCALENDAR\nBEGIN:VEVENT\nEND:VCALENDAR\n").is_err());
+        assert!(parse("BEGIN:VEVENT\n").is_err());
+    }
+
+    #[test]
+    fn resolves_zones() {
+        assert_eq!(
+            resolve_tzid("Europe/Berlin"),
+            Zone::Tz(chrono_tz::Europe::Berlin)
+        );
+        assert_eq!(
+            resolve_tzid("/mozilla.org/20050126_1/America/New_York"),
+            Zone::Tz(chrono_tz::America::New_York)
+        );
+        assert_eq!(
+            resolve_tzid("Pacific Standard Time"),
+            Zone::Tz(chrono_tz::America::Los_Angeles)
+        );
+        assert_eq!(resolve_tzid("Custom Zone"), Zone::Utc);
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1W"), Some(Duration::weeks(1)));
+        assert_eq!(
+            parse_duration("-P1DT2H"),
+            Some(-(Duration::days(1) + Duration::hours(2)))
+        );
+        assert_eq!(parse_duration("1H"), None);
+    }
+}
@@ -0,0 +1,16 @@
+mod client;
+mod error;
+mod expand;
+mod feed;
+mod ics;
+mod rrule;
+#[cfg(feature = "db")]
+mod sync;
+mod types;
+mod xml;
+
+pub use client::{CalDavClient, Credentials};
+pub use error::Error;
+pub use expand::events_in_range;
+pub use feed::IcsFeedClient;
+pub use types::*;
@@ -0,0 +1,425 @@
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, Weekday};
+
+use crate::error::Error;
+use crate::ics::DateValue;
+
+/// Stops runaway rules (e.g. `FREQ=DAILY` since 1970 with no end) from
+/// spinning forever.
+const MAX_PERIODS: u32 = 50_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The subset of RFC 5545 §3.3.10 calendar apps write for meetings:
+/// `FREQ` down to daily, with `INTERVAL`, `COUNT`, `UNTIL`, `BYDAY`,
+/// `BYMONTHDAY`, `BYMONTH`, `BYSETPOS` and `WKST`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RRule {
+    pub freq: Freq,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<DateValue>,
+    pub by_day: Vec<(Option<i32>, Weekday)>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub by_set_pos: Vec<i32>,
+    pub week_start: Weekday,
+}
+
+impl FromStr for RRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |part: &str| Error::Ics(format!("unsupported RRULE part {part:?}"));
+
+        let mut freq = None;
+        let mut rule = RRule {
+            freq: Freq::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+
+        for part in s.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or_else(|| invalid(part))?;
+            let list = || value.split(',').map(str::trim);
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Freq::Daily,
+                        "WEEKLY" => Freq::Weekly,
+                        "MONTHLY" => Freq::Monthly,
+                        "YEARLY" => Freq::Yearly,
+                        _ => return Err(invalid(part)),
+                    })
+                }
+                "INTERVAL" => rule.interval = value.parse().map_err(|_| invalid(part))?,
+                "COUNT" => rule.count = Some(value.parse().map_err(|_| invalid(part))?),
+                "UNTIL" => {
+                    let property = crate::ics::Property {
+                        name: "UNTIL".into(),
+                        params: Vec::new(),
+                        value: value.to_string(),
+                    };
+                    rule.until = Some(DateValue::from_property(&property)?);
+                }
+                "BYDAY" => {
+                    rule.by_day = list()
+                        .map(|day| parse_by_day(day).ok_or_else(|| invalid(part)))
+                        .collect::<Result<_, _>>()?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = list()
+                        .map(|d| d.parse().map_err(|_| invalid(part)))
+                        .collect::<Result<_, _>>()?
+                }
+                "BYMONTH" => {
+                    rule.by_month = list()
+                        .map(|m| m.parse().map_err(|_| invalid(part)))
+                        .collect::<Result<_, _>>()?
+                }
+                "BYSETPOS" => {
+                    rule.by_set_pos = list()
+                        .map(|p| p.parse().map_err(|_| invalid(part)))
+                        .collect::<Result<_, _>>()?
+                }
+                "WKST" => rule.week_start = parse_weekday(value).ok_or_else(|| invalid(part))?,
+                // Week numbers and year days change which dates occur, so a
+                // series using them can't be expanded without them.
+                "BYWEEKNO" | "BYYEARDAY" => return Err(invalid(part)),
+                // Sub-daily parts (BYHOUR, BYMINUTE, BYSECOND) don't occur in
+                // meeting series; ignoring them keeps the rest of the rule
+                // usable.
+                _ => {}
+            }
+        }
+
+        rule.freq = freq.ok_or_else(|| Error::Ics(format!("RRULE without FREQ: {s:?}")))?;
+        rule.interval = rule.interval.max(1);
+        Ok(rule)
+    }
+}
+
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    Some(match s.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn parse_by_day(s: &str) -> Option<(Option<i32>, Weekday)> {
+    let split = s.len().checked_sub(2)?;
+    let weekday = parse_weekday(&s[split..])?;
+    let ordinal = match &s[..split] {
+        "" => None,
+        n => Some(n.trim_start_matches('+').parse().ok()?),
+    };
+    Some((ordinal, weekday))
+}
+
+impl RRule {
+    /// Instance start times in the series' own wall-clock time, from
+    /// `dtstart` until the rule ends or an instance falls after `not_after`.
+    /// `until` must already be expressed in the same wall-clock time.
+    pub fn occurrences(
+        &self,
+        dtstart: NaiveDateTime,
+        until: Option<NaiveDateTime>,
+        not_after: NaiveDateTime,
+    ) -> Vec<NaiveDateTime> {
+        let mut out = Vec::new();
+        let mut emitted = 0;
+
+        for period in 0..MAX_PERIODS {
+            let Some(mut candidates) = self.period_dates(dtstart.date(), period) else {
+                break;
+            };
+            candidates.sort();
+            candidates.dedup();
+            let candidates = self.apply_set_pos(candidates);
+
+            for date in candidates {
+                let instance = date.and_time(dtstart.time());
+                if instance < dtstart {
+                    continue;
+                }
+                if until.is_some_and(|until| instance > until) || instance > not_after {
+                    return out;
+                }
+                if self.count.is_some_and(|count| emitted >= count) {
+                    return out;
+                }
+                emitted += 1;
+                out.push(instance);
+            }
+        }
+        out
+    }
+
+    fn apply_set_pos(&self, candidates: Vec<NaiveDate>) -> Vec<NaiveDate> {
+        if self.by_set_pos.is_empty() {
+            return candidates;
+        }
+        let len = candidates.len() as i32;
+        let mut picked: Vec<NaiveDate> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|&pos| {
+                let index = if pos > 0 { pos - 1 } else { len + pos };
+                (0..len)
+                    .contains(&index)
+                    .then(|| candidates[index as usize])
+            })
+            .collect();
+        picked.sort();
+        picked.dedup();
+        picked
+    }
+
+    /// Candidate dates in the `period`-th interval after `start`, before
+    /// `BYSETPOS`. `None` once the calendar runs out.
+    fn period_dates(&self, start: NaiveDate, period: u32) -> Option<Vec<NaiveDate>> {
+        let step = period.checked_mul(self.interval)?;
+        let dates = match self.freq {
+            Freq::Daily => {
+                let day = start.checked_add_days(Days::new(step.into()))?;
+                std::iter::once(day)
+                    .filter(|d| self.matches_day(*d))
+                    .collect()
+            }
+            Freq::Weekly => {
+                let offset = start.weekday().days_since(self.week_start);
+                let week = start
+                    .checked_sub_days(Days::new(offset.into()))?
+                    .checked_add_days(Days::new(u64::from(step) * 7))?;
+                let weekdays: Vec<Weekday> = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.iter().map(|(_, wd)| *wd).collect()
+                };
+                weekdays
+                    .into_iter()
+                    .filter_map(|wd| {
+                        week.checked_add_days(Days::new(wd.days_since(self.week_start).into()))
+                    })
+                    .filter(|d| self.by_month.is_empty() || self.by_month.contains(&d.month()))
+                    .collect()
+            }
+            Freq::Monthly => {
+                let month = first_of_month(start).checked_add_months(Months::new(step))?;
+                if !self.by_month.is_empty() && !self.by_month.contains(&month.month()) {
+                    return Some(Vec::new());
+                }
+                self.month_dates(month, start.day())
+            }
+            Freq::Yearly => {
+                let year = start.year().checked_add(step as i32)?;
+                let months: Vec<u32> = if self.by_month.is_empty() {
+                    vec![start.month()]
+                } else {
+                    self.by_month.clone()
+                };
+
+                if self.by_month.is_empty() && !self.by_day.is_empty() {
+                    // `FREQ=YEARLY;BYDAY=20MO`: ordinals count through the year.
+                    let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                    let last = NaiveDate::from_ymd_opt(year, 12, 31)?;
+                    self.weekdays_between(first, last)
+                } else {
+                    months
+                        .into_iter()
+                        .filter_map(|m| NaiveDate::from_ymd_opt(year, m, 1))
+                        .flat_map(|month| self.month_dates(month, start.day()))
+                        .collect()
+                }
+            }
+        };
+        Some(dates)
+    }
+
+    /// Dates within the month starting at `month` selected by
+    /// `BYMONTHDAY`/`BYDAY`, or `default_day` when neither is set.
+    fn month_dates(&self, month: NaiveDate, default_day: u32) -> Vec<NaiveDate> {
+        let last = last_of_month(month);
+
+        let by_month_day: Vec<NaiveDate> = self
+            .by_month_day
+            .iter()
+            .filter_map(|&d| {
+                let day = if d > 0 { d } else { last.day() as i32 + d + 1 };
+                u32::try_from(day).ok().and_then(|day| month.with_day(day))
+            })
+            .collect();
+        let by_day = self.weekdays_between(month, last);
+
+        match (self.by_month_day.is_empty(), self.by_day.is_empty()) {
+            (true, true) => month.with_day(default_day).into_iter().collect(),
+            (false, true) => by_month_day,
+            (true, false) => by_day,
+            (false, false) => by_month_day
+                .into_iter()
+                .filter(|d| by_day.contains(d))
+                .collect(),
+        }
+    }
+
+    /// Applies `BYDAY` between two dates, counting ordinals within them.
+    fn weekdays_between(&self, first: NaiveDate, last: NaiveDate) -> Vec<NaiveDate> {
+        let mut out = Vec::new();
+        for (ordinal, weekday) in &self.by_day {
+            let days: Vec<NaiveDate> = first
+                .iter_days()
+                .take_while(|d| *d <= last)
+                .filter(|d| d.weekday() == *weekday)
+                .collect();
+            match ordinal {
+                None => out.extend(days),
+                Some(n) if *n > 0 => out.extend(days.get(*n as usize - 1)),
+                Some(n) => out.extend(
+                    days.len()
+                        .checked_sub(n.unsigned_abs() as usize)
+                        .map(|i| days[i]),
+                ),
+            }
+        }
+        out
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let last = last_of_month(date).day() as i32;
+        (self.by_month.is_empty() || self.by_month.contains(&date.month()))
+            && (self.by_month_day.is_empty()
+                || self.by_month_day.iter().any(|&d| {
+                    let day = if d > 0 { d } else { last + d + 1 };
+                    day == date.day() as i32
+                }))
+            && (self.by_day.is_empty() || self.by_day.iter().any(|(_, wd)| *wd == date.weekday()))
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap()
+}
+
+fn last_of_month(date: NaiveDate) -> NaiveDate {
+    first_of_month(date)
+        .checked_add_months(Months::new(1))
+        .and_then(|d| d.pred_opt())
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn expand(rule: &str, start: &str, not_after: &str) -> Vec<String> {
+        let rule: RRule = rule.parse().unwrap();
+        let until = rule.until.map(|u| u.local);
+        rule.occurrences(dt(start), until, dt(not_after))
+            .into_iter()
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn weekly_by_day_with_count() {
+        assert_eq!(
+            expand(
+                "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4",
+                "2026-03-04 10:00",
+                "2027-01-01 00:00"
+            ),
+            ["2026-03-04", "2026-03-09", "2026-03-11", "2026-03-16"]
+        );
+    }
+
+    #[test]
+    fn biweekly_until() {
+        assert_eq!(
+            expand(
+                "FREQ=WEEKLY;INTERVAL=2;UNTIL=20260401T000000Z",
+                "2026-03-02 09:00",
+                "2027-01-01 00:00"
+            ),
+            ["2026-03-02", "2026-03-16", "2026-03-30"]
+        );
+    }
+
+    #[test]
+    fn monthly_last_friday_and_skipped_days() {
+        assert_eq!(
+            expand(
+                "FREQ=MONTHLY;BYDAY=-1FR;COUNT=3",
+                "2026-01-30 15:00",
+                "2027-01-01 00:00"
+            ),
+            ["2026-01-30", "2026-02-27", "2026-03-27"]
+        );
+        assert_eq!(
+            expand(
+                "FREQ=MONTHLY;COUNT=3",
+                "2026-01-31 15:00",
+                "2027-01-01 00:00"
+            ),
+            ["2026-01-31", "2026-03-31", "2026-05-31"]
+        );
+    }
+
+    #[test]
+    fn monthly_last_weekday_via_set_pos() {
+        assert_eq!(
+            expand(
+                "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=2",
+                "2026-05-29 12:00",
+                "2027-01-01 00:00"
+            ),
+            ["2026-05-29", "2026-06-30"]
+        );
+    }
+
+    #[test]
+    fn yearly_and_window_cutoff() {
+        assert_eq!(
+            expand(
+                "FREQ=YEARLY;BYMONTH=11;BYDAY=4TH",
+                "2025-11-27 12:00",
+                "2027-12-31 00:00"
+            ),
+            ["2025-11-27", "2026-11-26", "2027-11-25"]
+        );
+        assert_eq!(
+            expand("FREQ=DAILY", "2026-01-01 08:00", "2026-01-03 08:00"),
+            ["2026-01-01", "2026-01-02", "2026-01-03"]
+        );
+    }
+
+    #[test]
+    fn rejects_rule_without_freq() {
+        assert!("INTERVAL=2".parse::<RRule>().is_err());
+        assert!("FREQ=FORTNIGHTLY".parse::<RRule>().is_err());
+        assert!("FREQ=YEARLY;BYWEEKNO=20".parse::<RRule>().is_err());
+        assert!("FREQ=YEARLY;BYYEARDAY=1,100".parse::<RRule>().is_err());
+    }
+}
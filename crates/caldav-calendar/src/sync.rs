@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use hypr_db_user::{EventParticipant, UserDatabase};
+
+use crate::client::CalDavClient;
+use crate::error::Error;
+use crate::feed::IcsFeedClient;
+use crate::types::{Event, EventStatus};
+
+impl CalDavClient {
+    /// Fetches `calendar`'s occurrences in `[start, end)` and syncs them into
+    /// the user's events. `calendar.tracking_id` is the collection URL.
+    pub async fn sync_events(
+        &self,
+        db: &UserDatabase,
+        calendar: &hypr_db_user::Calendar,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<hypr_db_user::Event>, Error> {
+        let events = self.list_events(&calendar.tracking_id, start, end).await?;
+        store(db, calendar, events, start, end).await
+    }
+}
+
+impl IcsFeedClient {
+    /// Fetches the feed's occurrences in `[start, end)` and syncs them into
+    /// the user's events.
+    pub async fn sync_events(
+        &self,
+        db: &UserDatabase,
+        calendar: &hypr_db_user::Calendar,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<hypr_db_user::Event>, Error> {
+        let events = self.list_events(start, end).await?;
+        store(db, calendar, events, start, end).await
+    }
+}
+
+async fn store(
+    db: &UserDatabase,
+    calendar: &hypr_db_user::Calendar,
+    events: Vec<Event>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<hypr_db_user::Event>, Error> {
+    let events = events
+        .into_iter()
+        .filter(|event| event.status != EventStatus::Cancelled)
+        .map(|event| to_db_event(calendar, event))
+        .collect();
+    Ok(db
+        .sync_calendar_events(&calendar.id, start, end, events)
+        .await?)
+}
+
+fn to_db_event(calendar: &hypr_db_user::Calendar, event: Event) -> hypr_db_user::Event {
+    let mut participants: Vec<EventParticipant> = Vec::new();
+    for attendee in event.organizer.iter().chain(&event.attendees) {
+        let duplicate =
+            attendee.email.is_some() && participants.iter().any(|p| p.email == attendee.email);
+        if !duplicate {
+            participants.push(EventParticipant {
+                name: attendee.name.clone(),
+                email: attendee.email.clone(),
+            });
+        }
+    }
+
+    hypr_db_user::Event {
+        id: uuid::Uuid::new_v4().to_string(),
+        user_id: calendar.user_id.clone(),
+        // Occurrence ids are only unique within a calendar; the same UID can
+        // appear in both a CalDAV collection and a feed of it.
+        tracking_id: format!("{}#{}", calendar.tracking_id, event.id),
+        calendar_id: Some(calendar.id.clone()),
+        name: event.summary,
+        note: event.description.unwrap_or_default(),
+        start_date: event.start,
+        end_date: event.end,
+        google_event_url: event.url,
+        participants: (!participants.is_empty())
+            .then(|| serde_json::to_string(&participants).ok())
+            .flatten(),
+        is_recurring: event.is_recurring,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Attendee, AttendeeResponse};
+    use hypr_db_user::Platform;
+
+    #[test]
+    fn converts_occurrences_to_user_events() {
+        let calendar = hypr_db_user::Calendar {
+            id: "cal-1".to_string(),
+            tracking_id: "https://example.com/team.ics".to_string(),
+            user_id: "user-1".to_string(),
+            platform: Platform::Ics,
+            name: "Team".to_string(),
+            selected: true,
+            source: Some("https://example.com/team.ics".to_string()),
+        };
+        let alice = Attendee {
+            email: Some("alice@example.com".to_string()),
+            name: Some("Alice".to_string()),
+            optional: false,
+            response: AttendeeResponse::Accepted,
+        };
+        let start = "2025-01-06T09:00:00Z".parse().unwrap();
+        let event = Event {
+            id: "standup_20250106T090000Z".to_string(),
+            uid: "standup".to_string(),
+            calendar_id: calendar.tracking_id.clone(),
+            summary: "Standup".to_string(),
+            description: Some("Daily sync".to_string()),
+            location: None,
+            url: Some("https://meet.example.com/standup".to_string()),
+            start,
+            end: start + chrono::Duration::minutes(15),
+            all_day: false,
+            time_zone: Some("Europe/Berlin".to_string()),
+            status: EventStatus::Confirmed,
+            organizer: Some(alice.clone()),
+            attendees: vec![
+                alice,
+                Attendee {
+                    email: Some("bob@example.com".to_string()),
+                    name: None,
+                    optional: true,
+                    response: AttendeeResponse::NeedsAction,
+                },
+            ],
+            is_recurring: true,
+        };
+
+        let converted = to_db_event(&calendar, event);
+        assert_eq!(
+            converted.tracking_id,
+            "https://example.com/team.ics#standup_20250106T090000Z"
+        );
+        assert_eq!(converted.calendar_id.as_deref(), Some("cal-1"));
+        assert_eq!(converted.user_id, "user-1");
+        assert_eq!(converted.name, "Standup");
+        assert_eq!(converted.note, "Daily sync");
+        assert_eq!(
+            converted.google_event_url.as_deref(),
+            Some("https://meet.example.com/standup")
+        );
+        assert!(converted.is_recurring);
+
+        let participants: Vec<EventParticipant> =
+            serde_json::from_str(converted.participants.as_deref().unwrap()).unwrap();
+        assert_eq!(
+            participants,
+            vec![
+                EventParticipant {
+                    name: Some("Alice".to_string()),
+                    email: Some("alice@example.com".to_string()),
+                },
+                EventParticipant {
+                    name: None,
+                    email: Some("bob@example.com".to_string()),
+                },
+            ]
+        );
+    }
+}
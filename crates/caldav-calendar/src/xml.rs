@@ -0,0 +1,172 @@
+use roxmltree::{Document, Node};
+
+use crate::error::Error;
+
+pub(crate) const DAV: &str = "DAV:";
+pub(crate) const CALDAV: &str = "urn:ietf:params:xml:ns:caldav";
+const CALENDARSERVER: &str = "http://calendarserver.org/ns/";
+const APPLE_ICAL: &str = "http://apple.com/ns/ical/";
+
+/// The properties this crate asks for, pulled out of one `<D:response>`
+/// of a `207 Multi-Status` body. Only `200` propstats are read.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct DavResponse {
+    pub href: String,
+    pub current_user_principal: Option<String>,
+    pub calendar_home_set: Option<String>,
+    pub is_calendar: bool,
+    pub supports_events: bool,
+    pub read_only: bool,
+    pub display_name: Option<String>,
+    pub description: Option<String>,
+    pub color: Option<String>,
+    pub ctag: Option<String>,
+    pub calendar_data: Option<String>,
+}
+
+pub(crate) fn parse_multistatus(body: &str) -> Result<Vec<DavResponse>, Error> {
+    let doc = Document::parse(body)?;
+    let responses = doc
+        .descendants()
+        .filter(|n| is(n, DAV, "response"))
+        .map(parse_response)
+        .collect();
+    Ok(responses)
+}
+
+fn parse_response(response: Node) -> DavResponse {
+    let mut out = DavResponse {
+        href: child(response, DAV, "href")
+            .and_then(|n| n.text())
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default(),
+        // Servers that don't report supported components accept events.
+        supports_events: true,
+        ..Default::default()
+    };
+
+    let ok_props = response
+        .children()
+        .filter(|n| is(n, DAV, "propstat"))
+        .filter(|propstat| {
+            child(*propstat, DAV, "status")
+                .and_then(|n| n.text())
+                .is_none_or(|status| status.contains(" 200 "))
+        })
+        .filter_map(|propstat| child(propstat, DAV, "prop"));
+
+    for prop in ok_props.flat_map(|p| p.children().filter(Node::is_element)) {
+        let ns = prop.tag_name().namespace().unwrap_or_default();
+        match (ns, prop.tag_name().name()) {
+            (DAV, "current-user-principal") => out.current_user_principal = href_in(prop),
+            (CALDAV, "calendar-home-set") => out.calendar_home_set = href_in(prop),
+            (DAV, "resourcetype") => out.is_calendar = child(prop, CALDAV, "calendar").is_some(),
+            (CALDAV, "supported-calendar-component-set") => {
+                out.supports_events = prop
+                    .children()
+                    .filter(|c| is(c, CALDAV, "comp"))
+                    .any(|c| c.attribute("name") == Some("VEVENT"));
+            }
+            (DAV, "current-user-privilege-set") => {
+                out.read_only = !prop.descendants().any(|n| {
+                    is(&n, DAV, "write") || is(&n, DAV, "write-content") || is(&n, DAV, "all")
+                });
+            }
+            (DAV, "displayname") => out.display_name = text(prop),
+            (CALDAV, "calendar-description") => out.description = text(prop),
+            (APPLE_ICAL, "calendar-color") => out.color = text(prop),
+            (CALENDARSERVER, "getctag") => out.ctag = text(prop),
+            (CALDAV, "calendar-data") => out.calendar_data = text(prop),
+            _ => {}
+        }
+    }
+
+    out
+}
+
+fn is(node: &Node, namespace: &str, name: &str) -> bool {
+    node.is_element()
+        && node.tag_name().name() == name
+        && node.tag_name().namespace() == Some(namespace)
+}
+
+fn child<'a, 'input>(
+    node: Node<'a, 'input>,
+    namespace: &str,
+    name: &str,
+) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| is(n, namespace, name))
+}
+
+fn href_in(node: Node) -> Option<String> {
+    child(node, DAV, "href")
+        .and_then(|n| n.text())
+        .map(|s| s.trim().to_string())
+}
+
+fn text(node: Node) -> Option<String> {
+    let text: String = node
+        .descendants()
+        .filter(Node::is_text)
+        .filter_map(|n| n.text())
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+pub(crate) fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_calendar_collections() {
+        let body = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav" xmlns:cs="http://calendarserver.org/ns/" xmlns:x="http://apple.com/ns/ical/">
+  <d:response>
+    <d:href>/dav/calendars/alice/</d:href>
+    <d:propstat>
+      <d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/dav/calendars/alice/work/</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:resourcetype><d:collection/><c:calendar/></d:resourcetype>
+        <d:displayname>Work &amp; meetings</d:displayname>
+        <x:calendar-color>#3478F6FF</x:calendar-color>
+        <cs:getctag>"42"</cs:getctag>
+        <c:supported-calendar-component-set><c:comp name="VEVENT"/></c:supported-calendar-component-set>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+    <d:propstat>
+      <d:prop><c:calendar-description/></d:prop>
+      <d:status>HTTP/1.1 404 Not Found</d:status>
+    </d:propstat>
+  </d:response>
+</d:multistatus>"#;
+
+        let responses = parse_multistatus(body).unwrap();
+        assert_eq!(responses.len(), 2);
+        assert!(!responses[0].is_calendar);
+
+        let work = &responses[1];
+        assert_eq!(work.href, "/dav/calendars/alice/work/");
+        assert!(work.is_calendar);
+        assert!(work.supports_events);
+        assert_eq!(work.display_name.as_deref(), Some("Work & meetings"));
+        assert_eq!(work.color.as_deref(), Some("#3478F6FF"));
+        assert_eq!(work.ctag.as_deref(), Some("\"42\""));
+        assert_eq!(work.description, None);
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{TimeZone, Utc};
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use caldav_calendar::{CalDavClient, Credentials, Error, IcsFeedClient};
+
+fn multistatus(body: &str) -> ResponseTemplate {
+    ResponseTemplate::new(207)
+        .insert_header("Content-Type", "application/xml; charset=utf-8")
+        .set_body_string(format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">{body}</d:multistatus>"#
+        ))
+}
+
+async fn mount_discovery(server: &MockServer) {
+    Mock::given(method("PROPFIND"))
+        .and(path("/"))
+        .and(body_string_contains("current-user-principal"))
+        .respond_with(multistatus(
+            "<d:response><d:href>/</d:href><d:propstat><d:prop><d:current-user-principal><d:href>/principals/alice/</d:href></d:current-user-principal></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>",
+        ))
+        .mount(server)
+        .await;
+
+    Mock::given(method("PROPFIND"))
+        .and(path("/principals/alice/"))
+        .respond_with(multistatus(
+            "<d:response><d:href>/principals/alice/</d:href><d:propstat><d:prop><c:calendar-home-set><d:href>/calendars/alice/</d:href></c:calendar-home-set></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>",
+        ))
+        .mount(server)
+        .await;
+
+    Mock::given(method("PROPFIND"))
+        .and(path("/calendars/alice/"))
+        .and(header("Depth", "1"))
+        .respond_with(multistatus(
+            r#"<d:response><d:href>/calendars/alice/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+<d:response><d:href>/calendars/alice/work/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/><c:calendar/></d:resourcetype><d:displayname>Work</d:displayname></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+<d:response><d:href>/calendars/alice/tasks/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/><c:calendar/></d:resourcetype><c:supported-calendar-component-set><c:comp name="VTODO"/></c:supported-calendar-component-set></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+        ))
+        .mount(server)
+        .await;
+}
+
+fn client(server: &MockServer) -> CalDavClient {
+    CalDavClient::new(&server.uri())
+        .unwrap()
+        .with_credentials(Credentials {
+            username: "alice".into(),
+            password: "secret".into(),
+        })
+}
+
+#[tokio::test]
+async fn discovers_event_calendars() {
+    let server = MockServer::start().await;
+    mount_discovery(&server).await;
+
+    let calendars = client(&server).list_calendars().await.unwrap();
+    assert_eq!(calendars.len(), 1);
+    assert_eq!(calendars[0].name, "Work");
+    assert_eq!(
+        calendars[0].id,
+        format!("{}/calendars/alice/work/", server.uri())
+    );
+}
+
+#[tokio::test]
+async fn expands_reported_events() {
+    let server = MockServer::start().await;
+    let ics = include_str!("fixtures/weekly_standup.ics").replace('&', "&amp;");
+    Mock::given(method("REPORT"))
+        .and(path("/calendars/alice/work/"))
+        .and(header("Authorization", "Basic YWxpY2U6c2VjcmV0"))
+        .and(body_string_contains(r#"start="20260301T000000Z""#))
+        .respond_with(multistatus(&format!(
+            "<d:response><d:href>/calendars/alice/work/standup.ics</d:href><d:propstat><d:prop><c:calendar-data>{ics}</c:calendar-data></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let calendar_id = format!("{}/calendars/alice/work/", server.uri());
+    let events = client(&server)
+        .list_events(
+            &calendar_id,
+            Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 20, 0, 0, 0).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let summaries: Vec<_> = events.iter().map(|e| e.summary.as_str()).collect();
+    assert_eq!(summaries, ["Standup", "Standup (moved)"]);
+    assert!(events.iter().all(|e| e.calendar_id == calendar_id));
+}
+
+#[tokio::test]
+async fn refuses_calendars_on_other_hosts() {
+    let server = MockServer::start().await;
+    let result = client(&server)
+        .list_events(
+            "https://attacker.example/calendars/",
+            Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap(),
+        )
+        .await;
+    assert!(matches!(result, Err(Error::ForeignUrl(_))));
+}
+
+#[tokio::test]
+async fn reads_ics_feed() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/team.ics"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "text/calendar")
+                .set_body_string(include_str!("fixtures/feed.ics")),
+        )
+        .mount(&server)
+        .await;
+
+    let feed = IcsFeedClient::new(&format!("{}/team.ics", server.uri())).unwrap();
+    let calendar = feed.calendar().await.unwrap();
+    assert_eq!(calendar.name, "Team");
+    assert!(calendar.read_only);
+
+    let events = feed
+        .list_events(
+            Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 5, 1, 0, 0, 0).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].summary, "Kickoff");
+
+    let webcal = IcsFeedClient::new("webcal://example.com/team.ics").unwrap();
+    assert_eq!(webcal.url().as_str(), "https://example.com/team.ics");
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "db")]
+
+use chrono::{TimeZone, Utc};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use caldav_calendar::IcsFeedClient;
+use hypr_db_user::{Calendar, Human, Platform, UserDatabase};
+
+async fn user_db() -> UserDatabase {
+    let db = hypr_db_core::DatabaseBuilder::default()
+        .memory()
+        .build()
+        .await
+        .unwrap();
+    let db = UserDatabase::from(db);
+    hypr_db_user::migrate(&db).await.unwrap();
+    db
+}
+
+#[tokio::test]
+async fn syncs_feed_events_into_user_events() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/team.ics"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Type", "text/calendar")
+                .set_body_string(include_str!("fixtures/feed.ics")),
+        )
+        .mount(&server)
+        .await;
+
+    let db = user_db().await;
+    let human = db.upsert_human(Human::default()).await.unwrap();
+    let url = format!("{}/team.ics", server.uri());
+    let calendar = db
+        .upsert_calendar(Calendar {
+            id: uuid::Uuid::new_v4().to_string(),
+            tracking_id: url.clone(),
+            user_id: human.id.clone(),
+            platform: Platform::Ics,
+            name: "Team".to_string(),
+            selected: true,
+            source: Some(url),
+        })
+        .await
+        .unwrap();
+
+    let start = Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+    let client = IcsFeedClient::new(calendar.source.as_deref().unwrap()).unwrap();
+    let synced = client
+        .sync_events(&db, &calendar, start, end)
+        .await
+        .unwrap();
+    assert_eq!(synced.len(), 3);
+
+    let mut names: Vec<_> = db
+        .list_events(None)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|e| e.calendar_id.as_deref() == Some(calendar.id.as_str()))
+        .map(|e| e.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["Design review, round 2", "Kickoff", "Offsite"]);
+
+    // A second sync with nothing new leaves the same rows in place.
+    let again = client
+        .sync_events(&db, &calendar, start, end)
+        .await
+        .unwrap();
+    let mut ids: Vec<_> = synced.iter().map(|e| e.id.clone()).collect();
+    let mut again_ids: Vec<_> = again.iter().map(|e| e.id.clone()).collect();
+    ids.sort();
+    again_ids.sort();
+    assert_eq!(ids, again_ids);
+}
@@ -181,4 +181,52 @@ mod tests {
         let calendars = db.list_calendars(&human.id).await.unwrap();
         assert_eq!(calendars.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_caldav_and_ics_calendars() {
+        let db = setup_db().await;
+
+        let human = db
+            .upsert_human(Human {
+                full_name: Some("yujonglee".to_string()),
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+
+        let collection = Calendar {
+            id: uuid::Uuid::new_v4().to_string(),
+            tracking_id: "https://dav.example.com/calendars/me/work/".to_string(),
+            user_id: human.id.clone(),
+            name: "Work".to_string(),
+            platform: Platform::CalDav,
+            selected: true,
+            source: Some("https://dav.example.com/".to_string()),
+        };
+        let feed = Calendar {
+            id: uuid::Uuid::new_v4().to_string(),
+            tracking_id: "https://example.com/team.ics".to_string(),
+            user_id: human.id.clone(),
+            name: "Team".to_string(),
+            platform: Platform::Ics,
+            selected: true,
+            source: Some("https://example.com/team.ics".to_string()),
+        };
+
+        assert_eq!(
+            db.upsert_calendar(collection.clone()).await.unwrap(),
+            collection
+        );
+        assert_eq!(db.upsert_calendar(feed.clone()).await.unwrap(), feed);
+
+        let mut platforms: Vec<_> = db
+            .list_calendars(&human.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.platform)
+            .collect();
+        platforms.sort_by_key(|p| p.to_string());
+        assert_eq!(platforms, vec![Platform::CalDav, Platform::Ics]);
+    }
 }
@@ -21,5 +21,9 @@ user_common_derives! {
         Google,
         #[strum(serialize = "Outlook")]
         Outlook,
+        #[strum(serialize = "CalDav")]
+        CalDav,
+        #[strum(serialize = "Ics")]
+        Ics,
     }
 }
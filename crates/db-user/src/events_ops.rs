@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 use super::{Event, ListEventFilter, ListEventFilterCommon, ListEventFilterSpecific, UserDatabase};
 
 impl UserDatabase {
@@ -130,6 +132,46 @@ impl UserDatabase {
         Ok(event)
     }
 
+    /// Makes `events` the calendar's events starting in `[start, end)`:
+    /// they are upserted by `tracking_id`, and ones the provider no longer
+    /// reports are deleted. Events outside the window are left alone.
+    pub async fn sync_calendar_events(
+        &self,
+        calendar_id: impl Into<String>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        events: Vec<Event>,
+    ) -> Result<Vec<Event>, crate::Error> {
+        let calendar_id = calendar_id.into();
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT id, tracking_id FROM events
+                WHERE calendar_id = ? AND start_date >= ? AND start_date < ?",
+                vec![calendar_id, start.to_rfc3339(), end.to_rfc3339()],
+            )
+            .await?;
+        let mut stale = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let id: String = row.get(0)?;
+            let tracking_id: String = row.get(1)?;
+            if !events.iter().any(|e| e.tracking_id == tracking_id) {
+                stale.push(id);
+            }
+        }
+
+        for id in stale {
+            self.delete_event(id).await?;
+        }
+
+        let mut synced = Vec::with_capacity(events.len());
+        for event in events {
+            synced.push(self.upsert_event(event).await?);
+        }
+        Ok(synced)
+    }
+
     pub async fn list_events(
         &self,
         filter: Option<ListEventFilter>,
@@ -257,4 +299,84 @@ mod tests {
         let events = db.list_events(None).await.unwrap();
         assert_eq!(events.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_sync_calendar_events() {
+        let db = setup_db().await;
+
+        let human = db
+            .upsert_human(Human {
+                full_name: Some("yujonglee".to_string()),
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+        let calendar = db
+            .upsert_calendar(Calendar {
+                id: uuid::Uuid::new_v4().to_string(),
+                tracking_id: "https://example.com/team.ics".to_string(),
+                user_id: human.id.clone(),
+                name: "Team".to_string(),
+                platform: Platform::Ics,
+                selected: true,
+                source: Some("https://example.com/team.ics".to_string()),
+            })
+            .await
+            .unwrap();
+
+        let start = "2025-01-06T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let end = start + chrono::Duration::days(7);
+        let event = |tracking_id: &str, name: &str, day: i64| Event {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: human.id.clone(),
+            tracking_id: tracking_id.to_string(),
+            calendar_id: Some(calendar.id.clone()),
+            name: name.to_string(),
+            note: String::new(),
+            start_date: start + chrono::Duration::days(day),
+            end_date: start + chrono::Duration::days(day) + chrono::Duration::minutes(30),
+            google_event_url: None,
+            participants: None,
+            is_recurring: true,
+        };
+
+        db.sync_calendar_events(
+            &calendar.id,
+            start,
+            end,
+            vec![
+                event("standup_1", "Standup", 0),
+                event("standup_2", "Standup", 1),
+                event("later", "Next week", 8),
+            ],
+        )
+        .await
+        .unwrap();
+        let first = db.list_events(None).await.unwrap();
+        assert_eq!(first.len(), 3);
+
+        let synced = db
+            .sync_calendar_events(
+                &calendar.id,
+                start,
+                end,
+                vec![event("standup_1", "Daily standup", 0)],
+            )
+            .await
+            .unwrap();
+        assert_eq!(synced[0].name, "Daily standup");
+
+        let mut tracking_ids: Vec<_> = db
+            .list_events(None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.tracking_id)
+            .collect();
+        tracking_ids.sort();
+        assert_eq!(tracking_ids, vec!["later", "standup_1"]);
+
+        let kept = first.iter().find(|e| e.tracking_id == "standup_1").unwrap();
+        assert_eq!(synced[0].id, kept.id);
+    }
 }
@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use hypr_onnx::{
+    ndarray::{Array2, ArrayView2, ArrayView3, Ix3},
+    ort::{self, session::Session, value::TensorRef},
+};
+use tokenizers::{Encoding, PaddingParams, Tokenizer, TruncationParams};
+
+const MAX_TOKENS: usize = 256;
+/// Tokens repeated at the start of each chunk from the end of the previous one.
+const CHUNK_OVERLAP: usize = 32;
+
+/// Sentence embedding model (BERT-style ONNX export + `tokenizer.json`),
+/// mean-pooled and L2-normalized. Runs on CPU.
+pub struct TextEmbedder {
+    session: Session,
+    tokenizer: Tokenizer,
+    uses_token_type_ids: bool,
+}
+
+impl TextEmbedder {
+    pub fn from_files(
+        model_path: impl AsRef<Path>,
+        tokenizer_path: impl AsRef<Path>,
+    ) -> Result<Self, crate::Error> {
+        let session = hypr_onnx::load_model_from_path(model_path)?;
+
+        let mut tokenizer = Tokenizer::from_file(tokenizer_path)
+            .map_err(|e| crate::Error::TokenizerError(e.to_string()))?;
+        tokenizer.with_padding(Some(PaddingParams::default()));
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: MAX_TOKENS,
+                stride: CHUNK_OVERLAP,
+                ..Default::default()
+            }))
+            .map_err(|e| crate::Error::TokenizerError(e.to_string()))?;
+
+        let uses_token_type_ids = session
+            .inputs
+            .iter()
+            .any(|input| input.name == "token_type_ids");
+
+        Ok(Self {
+            session,
+            tokenizer,
+            uses_token_type_ids,
+        })
+    }
+
+    /// One embedding per text. Texts longer than the model window are
+    /// truncated; use `embed_chunks` to cover them in full.
+    pub fn embed(&mut self, texts: &[&str]) -> Result<Vec<Vec<f32>>, crate::Error> {
+        if texts.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts.to_vec(), true)
+            .map_err(|e| crate::Error::TokenizerError(e.to_string()))?;
+
+        self.run(&encodings)
+    }
+
+    /// Splits `text` into overlapping windows of at most `MAX_TOKENS` and
+    /// embeds each, so no part of a long document is dropped.
+    pub fn embed_chunks(&mut self, text: &str) -> Result<Vec<Vec<f32>>, crate::Error> {
+        let mut encoding = self
+            .tokenizer
+            .encode(text, true)
+            .map_err(|e| crate::Error::TokenizerError(e.to_string()))?;
+
+        let overflowing = encoding.take_overflowing();
+        let encodings: Vec<Encoding> = std::iter::once(encoding).chain(overflowing).collect();
+
+        self.run(&encodings)
+    }
+
+    fn run(&mut self, encodings: &[Encoding]) -> Result<Vec<Vec<f32>>, crate::Error> {
+        let batch = encodings.len();
+        let seq_len = encodings.iter().map(|e| e.len()).max().unwrap_or(0);
+
+        let mut input_ids = Array2::<i64>::zeros((batch, seq_len));
+        let mut attention_mask = Array2::<i64>::zeros((batch, seq_len));
+        let mut token_type_ids = Array2::<i64>::zeros((batch, seq_len));
+
+        for (i, encoding) in encodings.iter().enumerate() {
+            let tokens = encoding
+                .get_ids()
+                .iter()
+                .zip(encoding.get_attention_mask())
+                .zip(encoding.get_type_ids());
+            for (j, ((id, mask), type_id)) in tokens.enumerate() {
+                input_ids[[i, j]] = *id as i64;
+                attention_mask[[i, j]] = *mask as i64;
+                token_type_ids[[i, j]] = *type_id as i64;
+            }
+        }
+
+        let outputs = if self.uses_token_type_ids {
+            self.session.run(ort::inputs![
+                "input_ids" => TensorRef::from_array_view(input_ids.view())?,
+                "attention_mask" => TensorRef::from_array_view(attention_mask.view())?,
+                "token_type_ids" => TensorRef::from_array_view(token_type_ids.view())?,
+            ])?
+        } else {
+            self.session.run(ort::inputs![
+                "input_ids" => TensorRef::from_array_view(input_ids.view())?,
+                "attention_mask" => TensorRef::from_array_view(attention_mask.view())?,
+            ])?
+        };
+
+        let hidden = outputs
+            .values()
+            .next()
+            .ok_or(crate::Error::MissingOutput)?
+            .try_extract_array::<f32>()?;
+        let shape = hidden.shape().to_vec();
+        let hidden = hidden
+            .into_dimensionality::<Ix3>()
+            .map_err(|_| crate::Error::UnexpectedOutputShape(shape))?;
+
+        Ok(mean_pool(hidden, attention_mask.view()))
+    }
+}
+
+/// Averages the hidden states of unmasked tokens per sequence, then
+/// L2-normalizes each result.
+fn mean_pool(hidden: ArrayView3<f32>, attention_mask: ArrayView2<i64>) -> Vec<Vec<f32>> {
+    let (batch, seq_len, dim) = hidden.dim();
+
+    let mut embeddings = Vec::with_capacity(batch);
+    for i in 0..batch {
+        let mut pooled = vec![0.0f32; dim];
+        let mut count = 0.0f32;
+
+        for j in 0..seq_len {
+            if attention_mask[[i, j]] == 0 {
+                continue;
+            }
+            count += 1.0;
+            for (k, value) in pooled.iter_mut().enumerate() {
+                *value += hidden[[i, j, k]];
+            }
+        }
+
+        if count > 0.0 {
+            pooled.iter_mut().for_each(|v| *v /= count);
+        }
+        embeddings.push(normalize(pooled));
+    }
+    embeddings
+}
+
+fn normalize(mut embedding: Vec<f32>) -> Vec<f32> {
+    let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        embedding.iter_mut().for_each(|x| *x /= norm);
+    }
+    embedding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_onnx::ndarray::{Array3, array};
+
+    #[test]
+    fn test_mean_pool_skips_padding() {
+        let hidden = Array3::from_shape_vec(
+            (2, 3, 2),
+            vec![
+                1.0, 0.0, 3.0, 0.0, 100.0, 100.0, //
+                0.0, 2.0, 0.0, 0.0, 0.0, 0.0,
+            ],
+        )
+        .unwrap();
+        let mask = array![[1, 1, 0], [1, 0, 0]];
+
+        let pooled = mean_pool(hidden.view(), mask.view());
+        assert_eq!(pooled, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_mean_pool_all_masked() {
+        let hidden = Array3::<f32>::ones((1, 2, 3));
+        let mask = array![[0, 0]];
+
+        assert_eq!(
+            mean_pool(hidden.view(), mask.view()),
+            vec![vec![0.0, 0.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(vec![3.0, 4.0]), vec![0.6, 0.8]);
+        assert_eq!(normalize(vec![0.0, 0.0]), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_from_files_missing_model() {
+        let dir = std::env::temp_dir().join("embedding-local-missing");
+        let result = TextEmbedder::from_files(dir.join("model.onnx"), dir.join("tokenizer.json"));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Serialize, ser::Serializer};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    HyprOnnxError(#[from] hypr_onnx::Error),
+    #[error(transparent)]
+    OrtError(#[from] hypr_onnx::ort::Error),
+    #[error("tokenizer error: {0}")]
+    TokenizerError(String),
+    #[error("model returned no outputs")]
+    MissingOutput,
+    #[error("unexpected model output shape: {0:?}")]
+    UnexpectedOutputShape(Vec<usize>),
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
@@ -0,0 +1,5 @@
+mod embedder;
+mod error;
+
+pub use embedder::*;
+pub use error::*;
@@ -0,0 +1,232 @@
+use std::path::{Path, PathBuf};
+
+use owhisper_interface::batch::Response as BatchResponse;
+
+const AUDIO_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "flac", "ogg", "opus", "webm"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum DatasetError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to parse {path}: {source}")]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+fn read(path: &Path) -> Result<String, DatasetError> {
+    std::fs::read_to_string(path).map_err(|source| DatasetError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn parse<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, DatasetError> {
+    serde_json::from_str(&read(path)?).map_err(|source| DatasetError::Json {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Ground truth for one audio file. Speakers and timestamps are optional;
+/// the metrics that need them are skipped when they're missing.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Reference {
+    #[serde(default)]
+    pub language: Option<String>,
+    pub segments: Vec<ReferenceSegment>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReferenceSegment {
+    #[serde(default)]
+    pub speaker: Option<String>,
+    #[serde(default)]
+    pub start: Option<f64>,
+    #[serde(default)]
+    pub end: Option<f64>,
+    pub text: String,
+}
+
+impl Reference {
+    pub fn text(&self) -> String {
+        self.segments
+            .iter()
+            .map(|s| s.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub id: String,
+    pub audio_path: PathBuf,
+    pub reference: Reference,
+}
+
+/// A directory of audio files, each next to a reference transcript with the
+/// same stem: `<id>.json` (see [`Reference`]) or a plain-text `<id>.txt`.
+///
+/// Recorded provider responses live under `fixtures/<provider>/<id>.json`, so
+/// a dataset can be re-scored without network access.
+#[derive(Debug, Clone)]
+pub struct Dataset {
+    pub root: PathBuf,
+    pub samples: Vec<Sample>,
+}
+
+impl Dataset {
+    /// Loads every audio file that has a reference. Samples without a
+    /// language fall back to `default_language`.
+    pub fn load(
+        root: impl AsRef<Path>,
+        default_language: Option<&str>,
+    ) -> Result<Self, DatasetError> {
+        let root = root.as_ref().to_path_buf();
+        let entries = std::fs::read_dir(&root).map_err(|source| DatasetError::Io {
+            path: root.clone(),
+            source,
+        })?;
+
+        let mut audio_paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| AUDIO_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+            })
+            .collect();
+        audio_paths.sort();
+
+        let mut samples = Vec::new();
+        for audio_path in audio_paths {
+            let Some(mut reference) = load_reference(&audio_path)? else {
+                continue;
+            };
+            if reference.language.is_none() {
+                reference.language = default_language.map(str::to_string);
+            }
+
+            let id = audio_path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            samples.push(Sample {
+                id,
+                audio_path,
+                reference,
+            });
+        }
+
+        Ok(Self { root, samples })
+    }
+
+    pub fn fixture_path(&self, provider: &str, sample: &Sample) -> PathBuf {
+        self.root
+            .join("fixtures")
+            .join(provider)
+            .join(format!("{}.json", sample.id))
+    }
+
+    pub fn load_fixture(
+        &self,
+        provider: &str,
+        sample: &Sample,
+    ) -> Result<BatchResponse, DatasetError> {
+        parse(&self.fixture_path(provider, sample))
+    }
+
+    pub fn save_fixture(
+        &self,
+        provider: &str,
+        sample: &Sample,
+        response: &BatchResponse,
+    ) -> Result<(), DatasetError> {
+        let path = self.fixture_path(provider, sample);
+        let io_error = |source| DatasetError::Io {
+            path: path.clone(),
+            source,
+        };
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let json = serde_json::to_string_pretty(response).map_err(|source| DatasetError::Json {
+            path: path.clone(),
+            source,
+        })?;
+        std::fs::write(&path, json).map_err(io_error)
+    }
+}
+
+fn load_reference(audio_path: &Path) -> Result<Option<Reference>, DatasetError> {
+    let json_path = audio_path.with_extension("json");
+    if json_path.exists() {
+        return parse(&json_path).map(Some);
+    }
+
+    let txt_path = audio_path.with_extension("txt");
+    if txt_path.exists() {
+        let text = read(&txt_path)?;
+        return Ok(Some(Reference {
+            language: None,
+            segments: vec![ReferenceSegment {
+                text: text.trim().to_string(),
+                ..Default::default()
+            }],
+        }));
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_samples_with_references() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        std::fs::write(root.join("a.wav"), b"").unwrap();
+        std::fs::write(
+            root.join("a.json"),
+            r#"{"language":"de","segments":[{"speaker":"A","start":0.0,"end":1.5,"text":"Guten Tag"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(root.join("b.mp3"), b"").unwrap();
+        std::fs::write(root.join("b.txt"), "hello world\n").unwrap();
+        std::fs::write(root.join("no-reference.wav"), b"").unwrap();
+
+        let dataset = Dataset::load(root, Some("en")).unwrap();
+        let summary: Vec<_> = dataset
+            .samples
+            .iter()
+            .map(|s| {
+                (
+                    s.id.as_str(),
+                    s.reference.language.as_deref(),
+                    s.reference.text(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("a", Some("de"), "Guten Tag".to_string()),
+                ("b", Some("en"), "hello world".to_string()),
+            ]
+        );
+        assert_eq!(
+            dataset.fixture_path("deepgram", &dataset.samples[0]),
+            root.join("fixtures").join("deepgram").join("a.json")
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+/// Edit operations needed to turn a reference into a hypothesis. Kept as raw
+/// counts so rates can be micro-averaged across samples.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ErrorCounts {
+    pub substitutions: usize,
+    pub deletions: usize,
+    pub insertions: usize,
+    pub reference_len: usize,
+}
+
+impl ErrorCounts {
+    pub fn errors(&self) -> usize {
+        self.substitutions + self.deletions + self.insertions
+    }
+
+    pub fn rate(&self) -> f64 {
+        match (self.reference_len, self.errors()) {
+            (0, 0) => 0.0,
+            (0, _) => 1.0,
+            (len, errors) => errors as f64 / len as f64,
+        }
+    }
+
+    pub fn add(&mut self, other: &ErrorCounts) {
+        self.substitutions += other.substitutions;
+        self.deletions += other.deletions;
+        self.insertions += other.insertions;
+        self.reference_len += other.reference_len;
+    }
+}
+
+/// Lowercases and strips punctuation, keeping in-word apostrophes.
+pub fn normalize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '\'' {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .map(|w| w.trim_matches('\'').to_string())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Levenshtein distance split into substitutions, deletions and insertions.
+pub fn edit_counts<T: PartialEq>(reference: &[T], hypothesis: &[T]) -> ErrorCounts {
+    // (cost, substitutions, deletions, insertions) per cell, two rows at a time.
+    type Cell = (usize, usize, usize, usize);
+
+    let mut prev: Vec<Cell> = (0..=hypothesis.len()).map(|j| (j, 0, 0, j)).collect();
+    let mut curr: Vec<Cell> = vec![(0, 0, 0, 0); hypothesis.len() + 1];
+
+    for (i, r) in reference.iter().enumerate() {
+        curr[0] = (i + 1, 0, i + 1, 0);
+        for (j, h) in hypothesis.iter().enumerate() {
+            let diagonal = if r == h {
+                prev[j]
+            } else {
+                let (c, s, d, n) = prev[j];
+                (c + 1, s + 1, d, n)
+            };
+            let deletion = {
+                let (c, s, d, n) = prev[j + 1];
+                (c + 1, s, d + 1, n)
+            };
+            let insertion = {
+                let (c, s, d, n) = curr[j];
+                (c + 1, s, d, n + 1)
+            };
+            curr[j + 1] = [diagonal, deletion, insertion]
+                .into_iter()
+                .min_by_key(|cell| cell.0)
+                .unwrap();
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let (_, substitutions, deletions, insertions) = prev[hypothesis.len()];
+    ErrorCounts {
+        substitutions,
+        deletions,
+        insertions,
+        reference_len: reference.len(),
+    }
+}
+
+pub fn word_errors(reference: &str, hypothesis: &str) -> ErrorCounts {
+    edit_counts(&normalize(reference), &normalize(hypothesis))
+}
+
+/// Character errors over the normalized text with whitespace removed, so
+/// languages written without spaces are scored the same way.
+pub fn char_errors(reference: &str, hypothesis: &str) -> ErrorCounts {
+    let chars = |text: &str| -> Vec<char> { normalize(text).concat().chars().collect() };
+    edit_counts(&chars(reference), &chars(hypothesis))
+}
+
+/// Concatenated-minimum-permutation WER: words are grouped per speaker, each
+/// reference speaker is paired with the hypothesis speaker that saves the most
+/// errors (greedily), and unpaired speakers count as fully deleted or
+/// inserted. Attributing words to the wrong speaker therefore costs errors
+/// even when the words themselves are right.
+pub fn speaker_attributed_errors<R: Ord, H: Ord>(
+    reference: &[(R, String)],
+    hypothesis: &[(H, String)],
+) -> ErrorCounts {
+    let ref_speakers = group_by_speaker(reference);
+    let hyp_speakers = group_by_speaker(hypothesis);
+
+    let mut pairs = Vec::new();
+    for (ri, ref_words) in ref_speakers.values().enumerate() {
+        for (hi, hyp_words) in hyp_speakers.values().enumerate() {
+            let counts = edit_counts(ref_words, hyp_words);
+            let saved = (ref_words.len() + hyp_words.len()) as i64 - counts.errors() as i64;
+            pairs.push((saved, ri, hi, counts));
+        }
+    }
+    pairs.sort_by_key(|pair| std::cmp::Reverse(pair.0));
+
+    let mut total = ErrorCounts::default();
+    let mut ref_used = vec![false; ref_speakers.len()];
+    let mut hyp_used = vec![false; hyp_speakers.len()];
+
+    for (_, ri, hi, counts) in pairs {
+        if ref_used[ri] || hyp_used[hi] {
+            continue;
+        }
+        ref_used[ri] = true;
+        hyp_used[hi] = true;
+        total.add(&counts);
+    }
+
+    for (words, _) in ref_speakers
+        .values()
+        .zip(&ref_used)
+        .filter(|(_, used)| !**used)
+    {
+        total.add(&ErrorCounts {
+            deletions: words.len(),
+            reference_len: words.len(),
+            ..Default::default()
+        });
+    }
+    for (words, _) in hyp_speakers
+        .values()
+        .zip(&hyp_used)
+        .filter(|(_, used)| !**used)
+    {
+        total.add(&ErrorCounts {
+            insertions: words.len(),
+            ..Default::default()
+        });
+    }
+
+    total
+}
+
+fn group_by_speaker<S: Ord>(items: &[(S, String)]) -> BTreeMap<&S, Vec<String>> {
+    let mut groups: BTreeMap<&S, Vec<String>> = BTreeMap::new();
+    for (speaker, text) in items {
+        groups.entry(speaker).or_default().extend(normalize(text));
+    }
+    groups
+}
+
+/// A span of reference text with known boundaries, in seconds.
+pub struct TimedText<'a> {
+    pub text: &'a str,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Absolute offsets, in milliseconds, between reference segment boundaries and
+/// the hypothesis words aligned to the first and last word of each segment.
+/// Boundaries whose word wasn't recognized are skipped.
+pub fn timing_drift_ms(reference: &[TimedText], hypothesis: &[TimedText]) -> Vec<f64> {
+    let mut ref_words = Vec::new();
+    let mut boundaries = Vec::new();
+    for segment in reference {
+        let words = normalize(segment.text);
+        if words.is_empty() {
+            continue;
+        }
+        boundaries.push((ref_words.len(), segment.start, true));
+        boundaries.push((ref_words.len() + words.len() - 1, segment.end, false));
+        ref_words.extend(words);
+    }
+
+    let mut hyp_words = Vec::new();
+    let mut hyp_times = Vec::new();
+    for word in hypothesis {
+        for w in normalize(word.text) {
+            hyp_words.push(w);
+            hyp_times.push((word.start, word.end));
+        }
+    }
+
+    let mut aligned: BTreeMap<usize, usize> = BTreeMap::new();
+    for op in similar::capture_diff_slices(similar::Algorithm::Myers, &ref_words, &hyp_words) {
+        if let similar::DiffOp::Equal {
+            old_index,
+            new_index,
+            len,
+        } = op
+        {
+            aligned.extend((0..len).map(|k| (old_index + k, new_index + k)));
+        }
+    }
+
+    boundaries
+        .into_iter()
+        .filter_map(|(word, time, is_start)| {
+            let (start, end) = hyp_times[*aligned.get(&word)?];
+            let hyp_time = if is_start { start } else { end };
+            Some((hyp_time - time).abs() * 1000.0)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_case_and_punctuation() {
+        assert_eq!(
+            normalize("Hello, World! It's 'fine'."),
+            vec!["hello", "world", "it's", "fine"]
+        );
+    }
+
+    #[test]
+    fn counts_word_errors() {
+        let counts = word_errors("the cat sat on the mat", "cat sit on the mat today");
+        assert_eq!(
+            counts,
+            ErrorCounts {
+                substitutions: 1,
+                deletions: 1,
+                insertions: 1,
+                reference_len: 6,
+            }
+        );
+        assert!((counts.rate() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn char_errors_ignore_spacing() {
+        assert_eq!(char_errors("你好 世界", "你好世界").errors(), 0);
+        assert_eq!(char_errors("abc", "abd").substitutions, 1);
+    }
+
+    #[test]
+    fn empty_reference() {
+        assert_eq!(word_errors("", "").rate(), 0.0);
+        assert_eq!(word_errors("", "noise").rate(), 1.0);
+    }
+
+    #[test]
+    fn speaker_attribution_penalizes_swapped_words() {
+        let reference = vec![
+            ("alice", "good morning everyone".to_string()),
+            ("bob", "hi alice".to_string()),
+        ];
+
+        let correct = vec![
+            (0, "good morning everyone".to_string()),
+            (1, "hi alice".to_string()),
+        ];
+        assert_eq!(speaker_attributed_errors(&reference, &correct).errors(), 0);
+
+        let merged = vec![(0, "good morning everyone hi alice".to_string())];
+        let counts = speaker_attributed_errors(&reference, &merged);
+        assert_eq!(counts.errors(), 4);
+        assert_eq!(counts.reference_len, 5);
+    }
+
+    #[test]
+    fn measures_boundary_drift() {
+        let reference = [
+            TimedText {
+                text: "hello there",
+                start: 1.0,
+                end: 2.0,
+            },
+            TimedText {
+                text: "general kenobi",
+                start: 3.0,
+                end: 4.0,
+            },
+        ];
+        let hypothesis = [
+            TimedText {
+                text: "Hello",
+                start: 1.1,
+                end: 1.4,
+            },
+            TimedText {
+                text: "there.",
+                start: 1.5,
+                end: 2.0,
+            },
+            TimedText {
+                text: "General",
+                start: 2.9,
+                end: 3.4,
+            },
+            TimedText {
+                text: "can-obi",
+                start: 3.5,
+                end: 4.2,
+            },
+        ];
+
+        let drift: Vec<i64> = timing_drift_ms(&reference, &hypothesis)
+            .into_iter()
+            .map(|ms| ms.round() as i64)
+            .collect();
+        assert_eq!(drift, vec![100, 0, 100]);
+    }
+}
@@ -0,0 +1,15 @@
+//! Offline evaluation of speech-to-text providers against reference
+//! transcripts: WER, CER, speaker-attributed WER and segment timing drift.
+
+mod dataset;
+mod metrics;
+mod report;
+mod score;
+
+pub use dataset::{Dataset, DatasetError, Reference, ReferenceSegment, Sample};
+pub use metrics::{
+    ErrorCounts, TimedText, char_errors, edit_counts, normalize, speaker_attributed_errors,
+    timing_drift_ms, word_errors,
+};
+pub use report::{ProviderSummary, UNKNOWN_LANGUAGE, suggested_priorities, summarize};
+pub use score::{HypothesisWord, SampleScore, hypothesis_words, score_sample};
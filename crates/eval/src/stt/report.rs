@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use super::metrics::ErrorCounts;
+use super::score::SampleScore;
+
+/// Scores for one provider on one language, micro-averaged over samples.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderSummary {
+    pub provider: String,
+    pub language: String,
+    pub samples: usize,
+    pub failures: usize,
+    pub wer: f64,
+    pub cer: f64,
+    pub sa_wer: Option<f64>,
+    pub mean_drift_ms: Option<f64>,
+    pub max_drift_ms: Option<f64>,
+}
+
+pub const UNKNOWN_LANGUAGE: &str = "unknown";
+
+/// Groups scores by (language, provider). Within a language, providers are
+/// ordered best first: by WER, then CER.
+pub fn summarize(scores: &[SampleScore]) -> Vec<ProviderSummary> {
+    let mut groups: BTreeMap<(String, String), Vec<&SampleScore>> = BTreeMap::new();
+    for score in scores {
+        let language = score
+            .language
+            .clone()
+            .unwrap_or_else(|| UNKNOWN_LANGUAGE.to_string());
+        groups
+            .entry((language, score.provider.clone()))
+            .or_default()
+            .push(score);
+    }
+
+    let mut summaries: Vec<ProviderSummary> = groups
+        .into_iter()
+        .map(|((language, provider), scores)| {
+            let succeeded: Vec<_> = scores.iter().filter(|s| s.error.is_none()).collect();
+
+            let mut wer = ErrorCounts::default();
+            let mut cer = ErrorCounts::default();
+            let mut sa_wer: Option<ErrorCounts> = None;
+            let mut drift = Vec::new();
+            for score in &succeeded {
+                wer.add(&score.wer);
+                cer.add(&score.cer);
+                if let Some(counts) = &score.sa_wer {
+                    sa_wer.get_or_insert_with(Default::default).add(counts);
+                }
+                drift.extend_from_slice(&score.drift_ms);
+            }
+
+            ProviderSummary {
+                provider,
+                language,
+                samples: scores.len(),
+                failures: scores.len() - succeeded.len(),
+                wer: wer.rate(),
+                cer: cer.rate(),
+                sa_wer: sa_wer.map(|c| c.rate()),
+                mean_drift_ms: (!drift.is_empty())
+                    .then(|| drift.iter().sum::<f64>() / drift.len() as f64),
+                max_drift_ms: drift.iter().copied().reduce(f64::max),
+            }
+        })
+        .collect();
+
+    summaries.sort_by(|a, b| {
+        a.language
+            .cmp(&b.language)
+            .then(a.failures.min(1).cmp(&b.failures.min(1)))
+            .then(a.wer.total_cmp(&b.wer))
+            .then(a.cer.total_cmp(&b.cer))
+    });
+    summaries
+}
+
+/// Suggested `HyprnoteRoutingConfig::priorities` per language. Providers that
+/// failed every sample are left out.
+pub fn suggested_priorities(summaries: &[ProviderSummary]) -> BTreeMap<String, Vec<String>> {
+    let mut priorities: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for summary in summaries {
+        if summary.failures == summary.samples {
+            continue;
+        }
+        priorities
+            .entry(summary.language.clone())
+            .or_default()
+            .push(summary.provider.clone());
+    }
+    priorities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(provider: &str, language: &str, errors: usize, error: Option<&str>) -> SampleScore {
+        SampleScore {
+            sample_id: "s".to_string(),
+            provider: provider.to_string(),
+            language: Some(language.to_string()),
+            wer: ErrorCounts {
+                substitutions: errors,
+                reference_len: 10,
+                ..Default::default()
+            },
+            cer: ErrorCounts::default(),
+            sa_wer: None,
+            drift_ms: vec![20.0, 40.0],
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn ranks_providers_per_language() {
+        let scores = vec![
+            score("deepgram", "en", 2, None),
+            score("deepgram", "en", 0, None),
+            score("soniox", "en", 0, None),
+            score("soniox", "de", 3, None),
+            score("deepgram", "de", 1, None),
+            score("gladia", "de", 0, Some("timeout")),
+        ];
+
+        let summaries = summarize(&scores);
+        let deepgram_en = summaries
+            .iter()
+            .find(|s| s.provider == "deepgram" && s.language == "en")
+            .unwrap();
+        assert!((deepgram_en.wer - 0.1).abs() < 1e-9);
+        assert_eq!(deepgram_en.mean_drift_ms, Some(30.0));
+        assert_eq!(deepgram_en.max_drift_ms, Some(40.0));
+
+        let priorities = suggested_priorities(&summaries);
+        assert_eq!(priorities["en"], vec!["soniox", "deepgram"]);
+        assert_eq!(priorities["de"], vec!["deepgram", "soniox"]);
+    }
+}
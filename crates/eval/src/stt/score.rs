@@ -0,0 +1,187 @@
+use owhisper_interface::batch::Response as BatchResponse;
+use serde::Serialize;
+
+use super::dataset::Sample;
+use super::metrics::{
+    ErrorCounts, TimedText, char_errors, speaker_attributed_errors, timing_drift_ms, word_errors,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HypothesisWord {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+    pub speaker: Option<usize>,
+}
+
+/// Flattens a batch response into time-ordered words. Multichannel responses
+/// without diarization use the channel index as the speaker.
+pub fn hypothesis_words(response: &BatchResponse) -> Vec<HypothesisWord> {
+    let channels = &response.results.channels;
+    let multichannel = channels.len() > 1;
+
+    let mut words: Vec<HypothesisWord> = channels
+        .iter()
+        .enumerate()
+        .filter_map(|(index, channel)| Some((index, channel.alternatives.first()?)))
+        .flat_map(|(index, alternative)| {
+            alternative.words.iter().map(move |word| HypothesisWord {
+                text: word
+                    .punctuated_word
+                    .clone()
+                    .unwrap_or_else(|| word.word.clone()),
+                start: word.start,
+                end: word.end,
+                speaker: word.speaker.or(multichannel.then_some(index)),
+            })
+        })
+        .collect();
+
+    words.sort_by(|a, b| a.start.total_cmp(&b.start));
+    words
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SampleScore {
+    pub sample_id: String,
+    pub provider: String,
+    pub language: Option<String>,
+    pub wer: ErrorCounts,
+    pub cer: ErrorCounts,
+    /// Only set when both the reference and the response carry speakers.
+    pub sa_wer: Option<ErrorCounts>,
+    pub drift_ms: Vec<f64>,
+    pub error: Option<String>,
+}
+
+impl SampleScore {
+    pub fn failed(sample: &Sample, provider: &str, error: impl ToString) -> Self {
+        Self {
+            sample_id: sample.id.clone(),
+            provider: provider.to_string(),
+            language: sample.reference.language.clone(),
+            wer: ErrorCounts::default(),
+            cer: ErrorCounts::default(),
+            sa_wer: None,
+            drift_ms: vec![],
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+pub fn score_sample(sample: &Sample, provider: &str, response: &BatchResponse) -> SampleScore {
+    let reference = &sample.reference;
+    let words = hypothesis_words(response);
+
+    let reference_text = reference.text();
+    let hypothesis_text = words
+        .iter()
+        .map(|w| w.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let sa_wer = {
+        let ref_speakers: Option<Vec<(&str, String)>> = reference
+            .segments
+            .iter()
+            .map(|s| Some((s.speaker.as_deref()?, s.text.clone())))
+            .collect();
+        let hyp_speakers: Option<Vec<(usize, String)>> = words
+            .iter()
+            .map(|w| Some((w.speaker?, w.text.clone())))
+            .collect();
+
+        match (ref_speakers, hyp_speakers) {
+            (Some(r), Some(h)) if !r.is_empty() => Some(speaker_attributed_errors(&r, &h)),
+            _ => None,
+        }
+    };
+
+    let timed_reference: Vec<TimedText> = reference
+        .segments
+        .iter()
+        .filter_map(|s| {
+            Some(TimedText {
+                text: &s.text,
+                start: s.start?,
+                end: s.end?,
+            })
+        })
+        .collect();
+    let timed_hypothesis: Vec<TimedText> = words
+        .iter()
+        .map(|w| TimedText {
+            text: &w.text,
+            start: w.start,
+            end: w.end,
+        })
+        .collect();
+
+    SampleScore {
+        sample_id: sample.id.clone(),
+        provider: provider.to_string(),
+        language: reference.language.clone(),
+        wer: word_errors(&reference_text, &hypothesis_text),
+        cer: char_errors(&reference_text, &hypothesis_text),
+        sa_wer,
+        drift_ms: timing_drift_ms(&timed_reference, &timed_hypothesis),
+        error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stt::dataset::{Reference, ReferenceSegment};
+
+    #[test]
+    fn scores_diarized_response() {
+        let sample = Sample {
+            id: "standup".to_string(),
+            audio_path: "standup.wav".into(),
+            reference: Reference {
+                language: Some("en".to_string()),
+                segments: vec![
+                    ReferenceSegment {
+                        speaker: Some("alice".to_string()),
+                        start: Some(0.0),
+                        end: Some(1.0),
+                        text: "Ship it.".to_string(),
+                    },
+                    ReferenceSegment {
+                        speaker: Some("bob".to_string()),
+                        start: Some(1.2),
+                        end: Some(2.0),
+                        text: "Agreed".to_string(),
+                    },
+                ],
+            },
+        };
+        let response: BatchResponse = serde_json::from_value(serde_json::json!({
+            "metadata": {},
+            "results": { "channels": [{ "alternatives": [{
+                "transcript": "ship it agreed",
+                "confidence": 0.9,
+                "words": [
+                    { "word": "ship", "start": 0.05, "end": 0.4, "confidence": 0.9, "speaker": 0, "punctuated_word": "Ship" },
+                    { "word": "it", "start": 0.5, "end": 0.95, "confidence": 0.9, "speaker": 0, "punctuated_word": "it." },
+                    { "word": "agreed", "start": 1.2, "end": 2.1, "confidence": 0.9, "speaker": 0, "punctuated_word": "Agreed" }
+                ]
+            }]}]}
+        }))
+        .unwrap();
+
+        let score = score_sample(&sample, "deepgram", &response);
+        assert_eq!(score.wer.errors(), 0);
+        assert_eq!(score.cer.errors(), 0);
+        assert_eq!(score.sa_wer.map(|c| c.errors()), Some(2));
+        assert_eq!(
+            score
+                .drift_ms
+                .iter()
+                .map(|ms| ms.round() as i64)
+                .collect::<Vec<_>>(),
+            vec![50, 50, 0, 100]
+        );
+    }
+}
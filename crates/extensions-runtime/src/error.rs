@@ -23,6 +23,13 @@ pub enum Error {
 
     #[error("Runtime unavailable: V8 engine failed to initialize")]
     RuntimeUnavailable,
+
+    #[error("Permission denied: {extension_id} has no {kind} access to {target}")]
+    PermissionDenied {
+        extension_id: String,
+        kind: crate::PermissionKind,
+        target: String,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,113 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{DbScope, Result};
+
+pub type HostFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// App data exposed to extensions through `hypr.db`. The embedder decides
+/// where it comes from; the runtime only checks the extension's `db` scopes
+/// before calling in. Calls are awaited by async ops, so implementations
+/// should move blocking work off the extensions thread.
+pub trait HostData: Send + Sync + 'static {
+    fn list_sessions(&self) -> HostFuture<'_, Vec<Value>>;
+    fn get_session<'a>(&'a self, session_id: &'a str) -> HostFuture<'a, Option<Value>>;
+    fn get_transcript<'a>(&'a self, session_id: &'a str) -> HostFuture<'a, Option<Value>>;
+    fn list_humans(&self) -> HostFuture<'_, Vec<Value>>;
+}
+
+/// Host without any data; every query returns nothing.
+pub struct EmptyHostData;
+
+impl HostData for EmptyHostData {
+    fn list_sessions(&self) -> HostFuture<'_, Vec<Value>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn get_session<'a>(&'a self, _session_id: &'a str) -> HostFuture<'a, Option<Value>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    fn get_transcript<'a>(&'a self, _session_id: &'a str) -> HostFuture<'a, Option<Value>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    fn list_humans(&self) -> HostFuture<'_, Vec<Value>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+}
+
+/// Events extensions can subscribe to with `hypr.events.on(name, handler)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum HostEvent {
+    #[serde(rename_all = "camelCase")]
+    TranscriptDelta { session_id: String, delta: Value },
+    #[serde(rename_all = "camelCase")]
+    SessionStopped { session_id: String },
+}
+
+impl HostEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TranscriptDelta { .. } => "transcriptDelta",
+            Self::SessionStopped { .. } => "sessionStopped",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "method", rename_all = "camelCase")]
+pub(crate) enum DbRequest {
+    ListSessions,
+    #[serde(rename_all = "camelCase")]
+    GetSession {
+        session_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    GetTranscript {
+        session_id: String,
+    },
+    ListHumans,
+}
+
+impl DbRequest {
+    pub fn scope(&self) -> DbScope {
+        match self {
+            Self::ListSessions | Self::GetSession { .. } => DbScope::Sessions,
+            Self::GetTranscript { .. } => DbScope::Transcripts,
+            Self::ListHumans => DbScope::Humans,
+        }
+    }
+
+    pub async fn run(self, data: &dyn HostData) -> Result<Value> {
+        let value = match self {
+            Self::ListSessions => Value::from(data.list_sessions().await?),
+            Self::GetSession { session_id } => data.get_session(&session_id).await?.into(),
+            Self::GetTranscript { session_id } => data.get_transcript(&session_id).await?.into(),
+            Self::ListHumans => Value::from(data.list_humans().await?),
+        };
+        Ok(value)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct FetchRequest {
+    pub url: String,
+    #[serde(default)]
+    pub method: Option<String>,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct FetchResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
@@ -1,8 +1,12 @@
 mod error;
+mod host;
 mod manifest;
 mod ops;
+mod permissions;
 mod runtime;
 
 pub use error::*;
+pub use host::*;
 pub use manifest::*;
+pub use permissions::*;
 pub use runtime::*;
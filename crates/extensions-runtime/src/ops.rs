@@ -1,4 +1,52 @@
-use deno_core::op2;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use deno_core::{OpState, op2};
+use serde_json::{Value, json};
+
+use crate::host::{DbRequest, FetchRequest, FetchResponse, HostData};
+use crate::{Error, PermissionSet, Result};
+
+/// Lives in the `OpState`. Each loaded extension gets a random token that
+/// only its own `hypr` object holds, so ops know which grants to check
+/// without trusting anything the script says about itself.
+pub(crate) struct HostState {
+    pub data: Arc<dyn HostData>,
+    pub grants: HashMap<String, Arc<PermissionSet>>,
+    pub http: reqwest::Client,
+}
+
+fn grant(state: &OpState, token: &str) -> Result<Arc<PermissionSet>> {
+    state
+        .borrow::<HostState>()
+        .grants
+        .get(token)
+        .cloned()
+        .ok_or_else(|| Error::RuntimeError("unknown extension".to_string()))
+}
+
+/// Ops never throw; failures come back as `{ error }` and the JS side turns
+/// them into `HyprError`s carrying the permission that was missing.
+fn reply(result: Result<Value>) -> Value {
+    match result {
+        Ok(value) => json!({ "ok": value }),
+        Err(Error::PermissionDenied {
+            extension_id,
+            kind,
+            target,
+        }) => json!({
+            "error": {
+                "name": "PermissionDenied",
+                "message": format!("{} has no {} access to {}", extension_id, kind, target),
+                "permission": kind,
+                "target": target,
+            }
+        }),
+        Err(e) => json!({ "error": { "name": "HostError", "message": e.to_string() } }),
+    }
+}
 
 #[op2]
 #[string]
@@ -20,3 +68,112 @@ pub fn op_hypr_log_warn(#[string] message: String) -> String {
     tracing::warn!(target: "extension", "{}", message);
     "ok".to_string()
 }
+
+#[op2(async)]
+#[serde]
+pub async fn op_hypr_db(
+    state: Rc<RefCell<OpState>>,
+    #[string] token: String,
+    #[serde] request: DbRequest,
+) -> Value {
+    let checked = {
+        let state = state.borrow();
+        grant(&state, &token)
+            .and_then(|grant| grant.check_db(request.scope()))
+            .map(|()| state.borrow::<HostState>().data.clone())
+    };
+
+    reply(match checked {
+        Ok(data) => request.run(data.as_ref()).await,
+        Err(e) => Err(e),
+    })
+}
+
+#[op2]
+#[serde]
+pub fn op_hypr_fs_read(
+    state: &mut OpState,
+    #[string] token: String,
+    #[string] path: String,
+) -> Value {
+    reply(grant(state, &token).and_then(|grant| {
+        let path = grant.check_read(&path)?;
+        Ok(std::fs::read_to_string(path)?.into())
+    }))
+}
+
+#[op2]
+#[serde]
+pub fn op_hypr_fs_write(
+    state: &mut OpState,
+    #[string] token: String,
+    #[string] path: String,
+    #[string] content: String,
+) -> Value {
+    reply(grant(state, &token).and_then(|grant| {
+        let path = grant.check_write(&path)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)?;
+        Ok(Value::Null)
+    }))
+}
+
+#[op2(async)]
+#[serde]
+pub async fn op_hypr_fetch(
+    state: Rc<RefCell<OpState>>,
+    #[string] token: String,
+    #[serde] request: FetchRequest,
+) -> Value {
+    let checked = {
+        let state = state.borrow();
+        grant(&state, &token)
+            .and_then(|grant| grant.check_url(&request.url))
+            .map(|url| (url, state.borrow::<HostState>().http.clone()))
+    };
+
+    reply(match checked {
+        Ok((url, http)) => fetch(http, url, request).await,
+        Err(e) => Err(e),
+    })
+}
+
+async fn fetch(http: reqwest::Client, url: url::Url, request: FetchRequest) -> Result<Value> {
+    let method = match request.method {
+        Some(method) => reqwest::Method::from_bytes(method.to_uppercase().as_bytes())
+            .map_err(|e| Error::RuntimeError(e.to_string()))?,
+        None => reqwest::Method::GET,
+    };
+
+    let mut builder = http.request(method, url);
+    for (name, value) in request.headers {
+        builder = builder.header(name, value);
+    }
+    if let Some(body) = request.body {
+        builder = builder.body(body);
+    }
+
+    let response = builder
+        .send()
+        .await
+        .map_err(|e| Error::RuntimeError(e.to_string()))?;
+
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| Error::RuntimeError(e.to_string()))?;
+
+    Ok(serde_json::to_value(FetchResponse {
+        status,
+        headers,
+        body,
+    })?)
+}
@@ -0,0 +1,362 @@
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Extension, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DbScope {
+    Sessions,
+    Transcripts,
+    Humans,
+}
+
+impl std::fmt::Display for DbScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sessions => write!(f, "sessions"),
+            Self::Transcripts => write!(f, "transcripts"),
+            Self::Humans => write!(f, "humans"),
+        }
+    }
+}
+
+impl std::str::FromStr for DbScope {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "sessions" => Ok(Self::Sessions),
+            "transcripts" => Ok(Self::Transcripts),
+            "humans" => Ok(Self::Humans),
+            _ => Err(Error::InvalidManifest(format!("unknown db scope: {}", s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PermissionKind {
+    Db,
+    Network,
+    FilesystemRead,
+    FilesystemWrite,
+}
+
+impl std::fmt::Display for PermissionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Db => write!(f, "db"),
+            Self::Network => write!(f, "network"),
+            Self::FilesystemRead => write!(f, "filesystem read"),
+            Self::FilesystemWrite => write!(f, "filesystem write"),
+        }
+    }
+}
+
+/// The `permissions` block of an extension's manifest, resolved into
+/// something the host API can check calls against.
+///
+/// - `db`: `sessions`, `transcripts`, `humans`.
+/// - `network`: host names, optionally with a port (`localhost:8080`) or a
+///   leading wildcard (`*.example.com`).
+/// - `filesystem`: `read:<path>` or `write:<path>` (write implies read). A
+///   bare path is read-only. Relative paths are resolved against the
+///   extension directory and `~` against the home directory.
+#[derive(Debug, Clone)]
+pub struct PermissionSet {
+    extension_id: String,
+    db: Vec<DbScope>,
+    hosts: Vec<String>,
+    read: Vec<PathBuf>,
+    write: Vec<PathBuf>,
+}
+
+impl PermissionSet {
+    pub fn for_extension(extension: &Extension) -> Result<Self> {
+        let permissions = &extension.manifest.permissions;
+
+        let db = permissions
+            .db
+            .iter()
+            .map(|scope| scope.parse())
+            .collect::<Result<Vec<_>>>()?;
+
+        let hosts = permissions
+            .network
+            .iter()
+            .map(|host| host.trim().to_lowercase())
+            .collect::<Vec<_>>();
+        if let Some(host) = hosts.iter().find(|h| h.is_empty() || h.contains('/')) {
+            return Err(Error::InvalidManifest(format!(
+                "network permissions take host names, got {:?}",
+                host
+            )));
+        }
+
+        let mut read = Vec::new();
+        let mut write = Vec::new();
+        for entry in &permissions.filesystem {
+            let (writable, path) = match entry.split_once(':') {
+                Some(("write", path)) => (true, path),
+                Some(("read", path)) => (false, path),
+                _ => (false, entry.as_str()),
+            };
+            let path = resolve_path(&extension.path, path)?;
+            if writable {
+                write.push(path.clone());
+            }
+            read.push(path);
+        }
+
+        Ok(Self {
+            extension_id: extension.manifest.id.clone(),
+            db,
+            hosts,
+            read,
+            write,
+        })
+    }
+
+    pub fn extension_id(&self) -> &str {
+        &self.extension_id
+    }
+
+    pub fn check_db(&self, scope: DbScope) -> Result<()> {
+        if self.db.contains(&scope) {
+            Ok(())
+        } else {
+            Err(self.denied(PermissionKind::Db, scope.to_string()))
+        }
+    }
+
+    pub fn check_url(&self, url: &str) -> Result<url::Url> {
+        let parsed = url::Url::parse(url)
+            .map_err(|e| Error::RuntimeError(format!("invalid url {:?}: {}", url, e)))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(self.denied(PermissionKind::Network, url.to_string()));
+        }
+
+        let host = parsed.host_str().unwrap_or_default().to_lowercase();
+        let host_with_port = match parsed.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.clone(),
+        };
+
+        let allowed = self.hosts.iter().any(|pattern| {
+            if let Some(suffix) = pattern.strip_prefix("*.") {
+                host.ends_with(&format!(".{}", suffix))
+            } else if pattern.contains(':') {
+                *pattern == host_with_port
+            } else {
+                *pattern == host
+            }
+        });
+
+        if allowed {
+            Ok(parsed)
+        } else {
+            Err(self.denied(PermissionKind::Network, host_with_port))
+        }
+    }
+
+    pub fn check_read(&self, path: &str) -> Result<PathBuf> {
+        self.check_path(path, &self.read, PermissionKind::FilesystemRead)
+    }
+
+    pub fn check_write(&self, path: &str) -> Result<PathBuf> {
+        self.check_path(path, &self.write, PermissionKind::FilesystemWrite)
+    }
+
+    fn check_path(&self, path: &str, granted: &[PathBuf], kind: PermissionKind) -> Result<PathBuf> {
+        if !path.starts_with("~/") && Path::new(path).is_relative() {
+            return Err(Error::RuntimeError(format!(
+                "filesystem paths must be absolute, got {:?}",
+                path
+            )));
+        }
+        let resolved = resolve_path(Path::new("/"), path)?;
+
+        if granted.iter().any(|root| resolved.starts_with(root)) {
+            Ok(resolved)
+        } else {
+            Err(self.denied(kind, resolved.display().to_string()))
+        }
+    }
+
+    fn denied(&self, kind: PermissionKind, target: String) -> Error {
+        Error::PermissionDenied {
+            extension_id: self.extension_id.clone(),
+            kind,
+            target,
+        }
+    }
+}
+
+/// Expands `~`, joins relative paths onto `base`, removes `.`/`..` and
+/// follows symlinks for the part of the path that exists, so a link inside
+/// a granted directory can't point outside of it.
+fn resolve_path(base: &Path, path: &str) -> Result<PathBuf> {
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => std::env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(rest))
+            .ok_or_else(|| Error::RuntimeError("HOME is not set".to_string()))?,
+        None => PathBuf::from(path),
+    };
+    let joined = base.join(path);
+
+    let mut normalized = PathBuf::new();
+    for component in joined.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+
+    let mut existing = normalized.as_path();
+    let mut rest = Vec::new();
+    loop {
+        if let Ok(canonical) = existing.canonicalize() {
+            return Ok(rest.iter().rev().fold(canonical, |acc, c| acc.join(c)));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                rest.push(name.to_os_string());
+                existing = parent;
+            }
+            _ => return Ok(normalized),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ExtensionManifest, ExtensionPermissions};
+
+    fn extension(dir: &Path, permissions: ExtensionPermissions) -> Extension {
+        Extension {
+            manifest: ExtensionManifest {
+                id: "sample".to_string(),
+                name: "Sample".to_string(),
+                version: "0.1.0".to_string(),
+                description: None,
+                api_version: crate::CURRENT_API_VERSION.to_string(),
+                entry: "main.js".to_string(),
+                panels: vec![],
+                permissions,
+            },
+            path: dir.to_path_buf(),
+        }
+    }
+
+    fn is_denied(result: Result<impl std::fmt::Debug>, expected: PermissionKind) -> bool {
+        matches!(result, Err(Error::PermissionDenied { kind, .. }) if kind == expected)
+    }
+
+    #[test]
+    fn db_scopes() {
+        let dir = tempfile::tempdir().unwrap();
+        let set = PermissionSet::for_extension(&extension(
+            dir.path(),
+            ExtensionPermissions {
+                db: vec!["sessions".to_string()],
+                ..Default::default()
+            },
+        ))
+        .unwrap();
+
+        assert!(set.check_db(DbScope::Sessions).is_ok());
+        assert!(is_denied(set.check_db(DbScope::Humans), PermissionKind::Db));
+
+        let invalid = extension(
+            dir.path(),
+            ExtensionPermissions {
+                db: vec!["everything".to_string()],
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            PermissionSet::for_extension(&invalid),
+            Err(Error::InvalidManifest(_))
+        ));
+    }
+
+    #[test]
+    fn network_hosts() {
+        let dir = tempfile::tempdir().unwrap();
+        let set = PermissionSet::for_extension(&extension(
+            dir.path(),
+            ExtensionPermissions {
+                network: vec!["api.example.com".to_string(), "*.notion.so".to_string()],
+                ..Default::default()
+            },
+        ))
+        .unwrap();
+
+        assert!(set.check_url("https://api.example.com/v1").is_ok());
+        assert!(set.check_url("https://www.notion.so/page").is_ok());
+        assert!(is_denied(
+            set.check_url("https://notion.so.evil.com/"),
+            PermissionKind::Network
+        ));
+        assert!(is_denied(
+            set.check_url("https://example.com/"),
+            PermissionKind::Network
+        ));
+        assert!(is_denied(
+            set.check_url("file:///etc/passwd"),
+            PermissionKind::Network
+        ));
+    }
+
+    #[test]
+    fn filesystem_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        std::fs::create_dir_all(root.join("data")).unwrap();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+
+        let set = PermissionSet::for_extension(&extension(
+            &root,
+            ExtensionPermissions {
+                filesystem: vec![
+                    "write:data".to_string(),
+                    root.join("docs").display().to_string(),
+                ],
+                ..Default::default()
+            },
+        ))
+        .unwrap();
+
+        let data = root.join("data/out.txt").display().to_string();
+        let docs = root.join("docs/readme.md").display().to_string();
+        let escape = root.join("data/../secret.txt").display().to_string();
+
+        assert!(set.check_write(&data).is_ok());
+        assert!(set.check_read(&docs).is_ok());
+        assert!(is_denied(
+            set.check_write(&docs),
+            PermissionKind::FilesystemWrite
+        ));
+        assert!(is_denied(
+            set.check_read(&escape),
+            PermissionKind::FilesystemRead
+        ));
+        assert!(set.check_read("data/out.txt").is_err());
+
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&root, root.join("data/link")).unwrap();
+            let linked = root.join("data/link/secret.txt").display().to_string();
+            assert!(is_denied(
+                set.check_read(&linked),
+                PermissionKind::FilesystemRead
+            ));
+        }
+    }
+}
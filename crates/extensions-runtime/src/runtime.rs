@@ -1,5 +1,5 @@
 use crate::ops::*;
-use crate::{Error, Extension, Result};
+use crate::{EmptyHostData, Error, Extension, HostData, HostEvent, PermissionSet, Result};
 use deno_core::JsRuntime;
 use deno_core::RuntimeOptions;
 use deno_core::serde_json::Value;
@@ -12,9 +12,208 @@ use tokio::sync::{mpsc, oneshot};
 
 deno_core::extension!(
     hypr_extension,
-    ops = [op_hypr_log, op_hypr_log_error, op_hypr_log_warn],
+    ops = [
+        op_hypr_log,
+        op_hypr_log_error,
+        op_hypr_log_warn,
+        op_hypr_db,
+        op_hypr_fs_read,
+        op_hypr_fs_write,
+        op_hypr_fetch,
+    ],
 );
 
+// Sets up the global `hypr.log` and returns the host API factory and the
+// event dispatcher. Everything here runs before any extension code:
+//
+// - Ops are captured and `Deno` is removed from the global scope, so scripts
+//   can only reach the host through their own `hypr` object.
+// - Builtins the host code calls are captured too, so an extension patching
+//   `Map.prototype` or `Object.freeze` never sees another extension's token,
+//   API object or event handlers.
+const INIT_SCRIPT: &str = r#"
+(() => {
+    const {
+        op_hypr_log,
+        op_hypr_log_error,
+        op_hypr_log_warn,
+        op_hypr_db,
+        op_hypr_fs_read,
+        op_hypr_fs_write,
+        op_hypr_fetch,
+    } = Deno.core.ops;
+    delete globalThis.Deno;
+    delete globalThis.__bootstrap;
+
+    const { apply } = Reflect;
+    const uncurryThis = (fn) => (self, ...args) => apply(fn, self, args);
+    const ObjectEntries = Object.entries;
+    const ObjectFreeze = Object.freeze;
+    const ObjectHasOwn = Object.hasOwn;
+    const ObjectValues = Object.values;
+    const SafeMap = Map;
+    const SafeSet = Set;
+    const MapPrototypeGet = uncurryThis(Map.prototype.get);
+    const MapPrototypeSet = uncurryThis(Map.prototype.set);
+    const MapPrototypeDelete = uncurryThis(Map.prototype.delete);
+    const SetPrototypeAdd = uncurryThis(Set.prototype.add);
+    const SetPrototypeDelete = uncurryThis(Set.prototype.delete);
+    const SetPrototypeForEach = uncurryThis(Set.prototype.forEach);
+    const ArrayPrototypePush = uncurryThis(Array.prototype.push);
+    const PromiseAllSettled = Promise.allSettled.bind(Promise);
+    const StringCtor = String;
+    const TypeErrorCtor = TypeError;
+
+    const EVENTS = ["transcriptDelta", "sessionStopped"];
+    const listeners = new SafeMap();
+
+    class HyprError extends Error {
+        constructor({ name, message, permission, target }) {
+            super(message);
+            this.name = name;
+            this.permission = permission;
+            this.target = target;
+        }
+    }
+    ObjectFreeze(HyprError.prototype);
+    ObjectFreeze(HyprError);
+
+    const unwrap = (reply) => {
+        if (ObjectHasOwn(reply, "error")) {
+            throw new HyprError(reply.error);
+        }
+        return reply.ok;
+    };
+
+    const deepFreeze = (obj) => {
+        const values = ObjectValues(obj);
+        for (let i = 0; i < values.length; i++) {
+            if (values[i] && typeof values[i] === "object") {
+                deepFreeze(values[i]);
+            }
+        }
+        return ObjectFreeze(obj);
+    };
+
+    // Backwards compatibility: `hypr.log(msg)` is `hypr.log.info(msg)`.
+    const log = ObjectFreeze(
+        Object.assign((msg) => op_hypr_log(StringCtor(msg)), {
+            info: (msg) => op_hypr_log(StringCtor(msg)),
+            error: (msg) => op_hypr_log_error(StringCtor(msg)),
+            warn: (msg) => op_hypr_log_warn(StringCtor(msg)),
+        }),
+    );
+
+    globalThis.hypr = {
+        log,
+        _internal: {
+            extensionId: null,
+        },
+    };
+
+    const createApi = (token, extensionId) => {
+        const db = async (request) => unwrap(await op_hypr_db(token, request));
+        const own = new SafeMap();
+        for (let i = 0; i < EVENTS.length; i++) {
+            MapPrototypeSet(own, EVENTS[i], new SafeSet());
+        }
+        MapPrototypeSet(listeners, token, own);
+
+        return deepFreeze({
+            log,
+            HyprError,
+            db: {
+                sessions: {
+                    list: () => db({ method: "listSessions" }),
+                    get: (sessionId) =>
+                        db({ method: "getSession", sessionId: StringCtor(sessionId) }),
+                },
+                transcripts: {
+                    get: (sessionId) =>
+                        db({ method: "getTranscript", sessionId: StringCtor(sessionId) }),
+                },
+                humans: {
+                    list: () => db({ method: "listHumans" }),
+                },
+            },
+            net: {
+                fetch: async (url, init = {}) => {
+                    // Fresh arrays from `Object.entries`; only own elements
+                    // are written, so no prototype setter runs.
+                    const headers = ObjectEntries(init.headers ?? {});
+                    for (let i = 0; i < headers.length; i++) {
+                        headers[i][0] = StringCtor(headers[i][0]);
+                        headers[i][1] = StringCtor(headers[i][1]);
+                    }
+                    const body = init.body == null ? null : StringCtor(init.body);
+                    return unwrap(
+                        await op_hypr_fetch(token, {
+                            url: StringCtor(url),
+                            method: init.method ?? null,
+                            headers,
+                            body,
+                        }),
+                    );
+                },
+            },
+            fs: {
+                readText: (path) => unwrap(op_hypr_fs_read(token, StringCtor(path))),
+                writeText: (path, content) => {
+                    unwrap(op_hypr_fs_write(token, StringCtor(path), StringCtor(content)));
+                },
+            },
+            events: {
+                on: (name, handler) => {
+                    const handlers = MapPrototypeGet(own, name);
+                    if (!handlers) {
+                        throw new TypeErrorCtor(`Unknown event: ${name}`);
+                    }
+                    if (typeof handler !== "function") {
+                        throw new TypeErrorCtor("Event handler must be a function");
+                    }
+                    SetPrototypeAdd(handlers, handler);
+                    return () => SetPrototypeDelete(handlers, handler);
+                },
+            },
+            _internal: {
+                extensionId,
+            },
+        });
+    };
+
+    // Resolves with the errors thrown by the extension's handlers. Handlers
+    // are snapshotted first, so ones added while dispatching wait for the
+    // next event.
+    const dispatch = async (token, name, payload) => {
+        const own = MapPrototypeGet(listeners, token);
+        const handlers = own && MapPrototypeGet(own, name);
+        const snapshot = new SafeSet();
+        if (handlers) {
+            SetPrototypeForEach(handlers, (handler) => SetPrototypeAdd(snapshot, handler));
+        }
+
+        const pending = [];
+        SetPrototypeForEach(snapshot, (handler) => {
+            ArrayPrototypePush(pending, (async () => handler(payload))());
+        });
+
+        const results = await PromiseAllSettled(pending);
+        const errors = [];
+        for (let i = 0; i < results.length; i++) {
+            if (results[i].status === "rejected") {
+                const reason = results[i].reason;
+                ArrayPrototypePush(errors, StringCtor(reason?.stack ?? reason));
+            }
+        }
+        return errors;
+    };
+
+    const forget = (token) => MapPrototypeDelete(listeners, token);
+
+    return { createApi, dispatch, forget };
+})()
+"#;
+
 pub enum RuntimeRequest {
     CallFunction {
         extension_id: String,
@@ -31,6 +230,10 @@ pub enum RuntimeRequest {
         code: String,
         responder: oneshot::Sender<Result<Value>>,
     },
+    Emit {
+        event: HostEvent,
+        responder: oneshot::Sender<Result<()>>,
+    },
     Shutdown,
 }
 
@@ -42,6 +245,11 @@ pub struct ExtensionsRuntime {
 
 impl ExtensionsRuntime {
     pub fn new() -> Self {
+        Self::with_host_data(Arc::new(EmptyHostData))
+    }
+
+    /// Runtime whose `hypr.db` API is backed by `data`.
+    pub fn with_host_data(data: Arc<dyn HostData>) -> Self {
         let (tx, rx) = mpsc::channel(100);
         let available = Arc::new(AtomicBool::new(false));
         let available_clone = available.clone();
@@ -56,7 +264,7 @@ impl ExtensionsRuntime {
                 available_clone.store(true, Ordering::SeqCst);
                 tracing::info!("extensions_runtime_initialized");
 
-                rt.block_on(runtime_loop(rx));
+                rt.block_on(runtime_loop(rx, data));
             }));
 
             if let Err(e) = result {
@@ -145,6 +353,23 @@ impl ExtensionsRuntime {
         rx.await.map_err(|_| Error::ChannelRecv)?
     }
 
+    /// Delivers `event` to every loaded extension subscribed to it. Errors
+    /// thrown by handlers are logged, not returned.
+    pub async fn emit(&self, event: HostEvent) -> Result<()> {
+        self.ensure_available()?;
+
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(RuntimeRequest::Emit {
+                event,
+                responder: tx,
+            })
+            .await
+            .map_err(|_| Error::ChannelSend)?;
+
+        rx.await.map_err(|_| Error::ChannelRecv)?
+    }
+
     pub async fn shutdown(&self) -> Result<()> {
         if !self.is_available() {
             return Ok(());
@@ -167,39 +392,45 @@ impl Default for ExtensionsRuntime {
 struct ExtensionState {
     #[allow(dead_code)]
     extension: Extension,
+    token: String,
     functions: HashMap<String, v8::Global<v8::Function>>,
 }
 
-async fn runtime_loop(mut rx: mpsc::Receiver<RuntimeRequest>) {
+struct HostFunctions {
+    create_api: v8::Global<v8::Function>,
+    dispatch: v8::Global<v8::Function>,
+    forget: v8::Global<v8::Function>,
+}
+
+async fn runtime_loop(mut rx: mpsc::Receiver<RuntimeRequest>, data: Arc<dyn HostData>) {
     let mut js_runtime = JsRuntime::new(RuntimeOptions {
         extensions: vec![hypr_extension::init_ops()],
         ..Default::default()
     });
 
-    js_runtime
-        .execute_script(
-            "<hypr:init>",
-            r#"
-            globalThis.hypr = {
-                log: {
-                    info: (msg) => Deno.core.ops.op_hypr_log(String(msg)),
-                    error: (msg) => Deno.core.ops.op_hypr_log_error(String(msg)),
-                    warn: (msg) => Deno.core.ops.op_hypr_log_warn(String(msg)),
-                },
-                _internal: {
-                    extensionId: null,
-                },
-            };
-            // Backwards compatibility
-            hypr.log.toString = () => "[object Function]";
-            const originalLog = hypr.log;
-            globalThis.hypr.log = Object.assign(
-                (msg) => Deno.core.ops.op_hypr_log(String(msg)),
-                originalLog
-            );
-            "#,
-        )
+    let http = reqwest::Client::builder()
+        // Each hop has to pass the network permission check, so redirects
+        // are handed back to the extension instead of being followed.
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .expect("Failed to build http client");
+    js_runtime.op_state().borrow_mut().put(HostState {
+        data,
+        grants: HashMap::new(),
+        http,
+    });
+
+    let init = js_runtime
+        .execute_script("<hypr:init>", INIT_SCRIPT)
         .expect("Failed to initialize hypr global");
+    let host = {
+        let scope = &mut js_runtime.handle_scope();
+        HostFunctions {
+            create_api: get_function(scope, &init, "createApi"),
+            dispatch: get_function(scope, &init, "dispatch"),
+            forget: get_function(scope, &init, "forget"),
+        }
+    };
 
     let mut extensions: HashMap<String, ExtensionState> = HashMap::new();
 
@@ -209,7 +440,8 @@ async fn runtime_loop(mut rx: mpsc::Receiver<RuntimeRequest>) {
                 extension,
                 responder,
             } => {
-                let result = load_extension_impl(&mut js_runtime, extension, &mut extensions);
+                let result =
+                    load_extension_impl(&mut js_runtime, &host, extension, &mut extensions).await;
                 let _ = responder.send(result);
             }
             RuntimeRequest::CallFunction {
@@ -236,6 +468,10 @@ async fn runtime_loop(mut rx: mpsc::Receiver<RuntimeRequest>) {
                 let result = execute_code_impl(&mut js_runtime, code);
                 let _ = responder.send(result);
             }
+            RuntimeRequest::Emit { event, responder } => {
+                let result = emit_impl(&mut js_runtime, &host, &extensions, event).await;
+                let _ = responder.send(result);
+            }
             RuntimeRequest::Shutdown => {
                 break;
             }
@@ -243,13 +479,53 @@ async fn runtime_loop(mut rx: mpsc::Receiver<RuntimeRequest>) {
     }
 }
 
-fn load_extension_impl(
+fn get_function(
+    scope: &mut v8::HandleScope,
+    object: &v8::Global<v8::Value>,
+    name: &str,
+) -> v8::Global<v8::Function> {
+    let object = v8::Local::new(scope, object);
+    let object = v8::Local::<v8::Object>::try_from(object).expect("init script returns an object");
+    let key = v8::String::new(scope, name).unwrap();
+    let value = object.get(scope, key.into()).unwrap();
+    let function = v8::Local::<v8::Function>::try_from(value).expect("host function");
+    v8::Global::new(scope, function)
+}
+
+fn to_v8_args(js_runtime: &mut JsRuntime, args: &[Value]) -> Result<Vec<v8::Global<v8::Value>>> {
+    let scope = &mut js_runtime.handle_scope();
+    let mut result = Vec::with_capacity(args.len());
+    for arg in args {
+        let v8_val = serde_v8::to_v8(scope, arg).map_err(|e| Error::RuntimeError(e.to_string()))?;
+        result.push(v8::Global::new(scope, v8_val));
+    }
+    Ok(result)
+}
+
+fn forget_grant(js_runtime: &mut JsRuntime, host: &HostFunctions, token: &str) {
+    js_runtime
+        .op_state()
+        .borrow_mut()
+        .borrow_mut::<HostState>()
+        .grants
+        .remove(token);
+
+    let scope = &mut js_runtime.handle_scope();
+    let forget = v8::Local::new(scope, &host.forget);
+    let recv = v8::undefined(scope).into();
+    let token = v8::String::new(scope, token).unwrap().into();
+    forget.call(scope, recv, &[token]);
+}
+
+async fn load_extension_impl(
     js_runtime: &mut JsRuntime,
+    host: &HostFunctions,
     extension: Extension,
     extensions: &mut HashMap<String, ExtensionState>,
 ) -> Result<()> {
     let entry_path = extension.entry_path();
     let code = std::fs::read_to_string(&entry_path)?;
+    let permissions = PermissionSet::for_extension(&extension)?;
 
     let context_json = serde_json::json!({
         "extensionId": extension.manifest.id,
@@ -263,31 +539,70 @@ fn load_extension_impl(
         }
     });
 
+    // `hypr` inside the extension is its own capability-bound API object;
+    // the global only carries logging.
     let wrapper = format!(
         r#"
-        (function() {{
+        (function(hypr) {{
             const __hypr_extension = {{}};
             const __hypr_context = {context};
-            hypr._internal.extensionId = __hypr_context.extensionId;
+            globalThis.hypr._internal.extensionId = __hypr_context.extensionId;
             {code}
             if (typeof __hypr_extension.activate === 'function') {{
                 __hypr_extension.activate(__hypr_context);
             }}
             return __hypr_extension;
-        }})()
+        }})
         "#,
         context = context_json,
         code = code
     );
 
     let script_name: &'static str = Box::leak(extension.manifest.id.clone().into_boxed_str());
-    let result = js_runtime
+    let factory = js_runtime
         .execute_script(script_name, wrapper)
         .map_err(|e| Error::RuntimeError(e.to_string()))?;
+    let factory = {
+        let scope = &mut js_runtime.handle_scope();
+        let local = v8::Local::new(scope, factory);
+        let func = v8::Local::<v8::Function>::try_from(local)
+            .map_err(|e| Error::RuntimeError(e.to_string()))?;
+        v8::Global::new(scope, func)
+    };
+
+    let token = uuid::Uuid::new_v4().simple().to_string();
+    if let Some(previous) = extensions.get(&extension.manifest.id) {
+        let previous = previous.token.clone();
+        forget_grant(js_runtime, host, &previous);
+    }
+    js_runtime
+        .op_state()
+        .borrow_mut()
+        .borrow_mut::<HostState>()
+        .grants
+        .insert(token.clone(), Arc::new(permissions));
+
+    let api_args = to_v8_args(
+        js_runtime,
+        &[
+            Value::from(token.clone()),
+            Value::from(extension.manifest.id.clone()),
+        ],
+    )?;
+    let loaded = match js_runtime.call_with_args(&host.create_api, &api_args).await {
+        Ok(api) => js_runtime.call_with_args(&factory, &[api]).await,
+        Err(e) => Err(e),
+    };
+    let result = match loaded {
+        Ok(result) => result,
+        Err(e) => {
+            forget_grant(js_runtime, host, &token);
+            return Err(Error::RuntimeError(e.to_string()));
+        }
+    };
 
     let scope = &mut js_runtime.handle_scope();
     let local = v8::Local::new(scope, result);
-
     let mut functions = HashMap::new();
 
     if let Ok(obj) = v8::Local::<v8::Object>::try_from(local)
@@ -310,6 +625,7 @@ fn load_extension_impl(
         extension.manifest.id.clone(),
         ExtensionState {
             extension: extension.clone(),
+            token,
             functions,
         },
     );
@@ -324,6 +640,40 @@ fn load_extension_impl(
     Ok(())
 }
 
+async fn emit_impl(
+    js_runtime: &mut JsRuntime,
+    host: &HostFunctions,
+    extensions: &HashMap<String, ExtensionState>,
+    event: HostEvent,
+) -> Result<()> {
+    let payload = serde_json::to_value(&event)?;
+
+    for (extension_id, state) in extensions {
+        let args = to_v8_args(
+            js_runtime,
+            &[
+                Value::from(state.token.clone()),
+                Value::from(event.name()),
+                payload.clone(),
+            ],
+        )?;
+
+        let result = js_runtime
+            .call_with_args(&host.dispatch, &args)
+            .await
+            .map_err(|e| Error::RuntimeError(e.to_string()))?;
+
+        let scope = &mut js_runtime.handle_scope();
+        let local = v8::Local::new(scope, result);
+        let errors: Vec<String> = serde_v8::from_v8(scope, local).unwrap_or_default();
+        for error in errors {
+            tracing::warn!(extension_id, event = event.name(), %error, "extension_event_handler_failed");
+        }
+    }
+
+    Ok(())
+}
+
 fn execute_code_impl(js_runtime: &mut JsRuntime, code: String) -> Result<Value> {
     let result = js_runtime
         .execute_script("<hypr:execute_code>", code)
@@ -353,16 +703,7 @@ async fn call_function_impl(
         .get(function_name)
         .ok_or_else(|| Error::RuntimeError(format!("Function not found: {}", function_name)))?;
 
-    let v8_args = {
-        let scope = &mut js_runtime.handle_scope();
-        let mut result = Vec::with_capacity(args.len());
-        for arg in &args {
-            let v8_val =
-                serde_v8::to_v8(scope, arg).map_err(|e| Error::RuntimeError(e.to_string()))?;
-            result.push(v8::Global::new(scope, v8_val));
-        }
-        result
-    };
+    let v8_args = to_v8_args(js_runtime, &args)?;
 
     let result = js_runtime
         .call_with_args(func, &v8_args)
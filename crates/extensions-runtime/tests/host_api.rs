@@ -0,0 +1,340 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use extensions_runtime::{Extension, ExtensionsRuntime, HostData, HostEvent, HostFuture};
+use serde_json::{Value, json};
+
+struct FakeData;
+
+impl HostData for FakeData {
+    fn list_sessions(&self) -> HostFuture<'_, Vec<Value>> {
+        Box::pin(async { Ok(vec![json!({ "id": "s1", "title": "Standup" })]) })
+    }
+
+    fn get_session<'a>(&'a self, session_id: &'a str) -> HostFuture<'a, Option<Value>> {
+        Box::pin(async move {
+            Ok((session_id == "s1").then(|| json!({ "id": "s1", "title": "Standup" })))
+        })
+    }
+
+    fn get_transcript<'a>(&'a self, _session_id: &'a str) -> HostFuture<'a, Option<Value>> {
+        Box::pin(async { Ok(Some(json!({ "utterances": [] }))) })
+    }
+
+    fn list_humans(&self) -> HostFuture<'_, Vec<Value>> {
+        Box::pin(async { Ok(vec![json!({ "name": "Alice" })]) })
+    }
+}
+
+fn write_extension(dir: &Path, id: &str, permissions: Value, code: &str) -> Extension {
+    let path = dir.join(id);
+    std::fs::create_dir_all(&path).unwrap();
+    let manifest = json!({
+        "id": id,
+        "name": id,
+        "version": "0.1.0",
+        "entry": "main.js",
+        "permissions": permissions,
+    });
+    std::fs::write(path.join("extension.json"), manifest.to_string()).unwrap();
+    std::fs::write(path.join("main.js"), code).unwrap();
+    Extension::load(path).unwrap()
+}
+
+async fn runtime() -> ExtensionsRuntime {
+    let runtime = ExtensionsRuntime::with_host_data(Arc::new(FakeData));
+    for _ in 0..100 {
+        if runtime.is_available() {
+            return runtime;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    panic!("runtime did not start");
+}
+
+// Runs `body` and reports what it returned or threw, so tests can assert on
+// `HyprError` fields.
+const CAPTURE: &str = r#"
+const capture = async (body) => {
+    try {
+        return { ok: await body() };
+    } catch (e) {
+        return { name: e.name, permission: e.permission ?? null };
+    }
+};
+"#;
+
+#[tokio::test]
+async fn db_access_is_scoped() {
+    let dir = tempfile::tempdir().unwrap();
+    let extension = write_extension(
+        dir.path(),
+        "db-reader",
+        json!({ "db": ["sessions"] }),
+        &format!(
+            r#"{CAPTURE}
+            __hypr_extension.sessions = () => capture(() => hypr.db.sessions.list());
+            __hypr_extension.session = (id) => capture(() => hypr.db.sessions.get(id));
+            __hypr_extension.humans = () => capture(() => hypr.db.humans.list());
+            "#
+        ),
+    );
+
+    let runtime = runtime().await;
+    runtime.load_extension(extension).await.unwrap();
+
+    let sessions = runtime
+        .call_function("db-reader", "sessions", vec![])
+        .await
+        .unwrap();
+    assert_eq!(sessions["ok"][0]["title"], "Standup");
+
+    let session = runtime
+        .call_function("db-reader", "session", vec![json!("missing")])
+        .await
+        .unwrap();
+    assert_eq!(session, json!({ "ok": null }));
+
+    let humans = runtime
+        .call_function("db-reader", "humans", vec![])
+        .await
+        .unwrap();
+    assert_eq!(
+        humans,
+        json!({ "name": "PermissionDenied", "permission": "db" })
+    );
+}
+
+#[tokio::test]
+async fn filesystem_access_is_limited_to_granted_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    let secret = root.join("secret.txt");
+    std::fs::write(&secret, "top secret").unwrap();
+
+    let extension = write_extension(
+        &root,
+        "notes",
+        json!({ "filesystem": ["write:data"] }),
+        &format!(
+            r#"{CAPTURE}
+            __hypr_extension.roundtrip = (dir) => capture(() => {{
+                hypr.fs.writeText(dir + "/data/out/note.md", "hello");
+                return hypr.fs.readText(dir + "/data/out/note.md");
+            }});
+            __hypr_extension.read = (path) => capture(() => hypr.fs.readText(path));
+            __hypr_extension.write = (path) => capture(() => hypr.fs.writeText(path, "x"));
+            "#
+        ),
+    );
+
+    let runtime = runtime().await;
+    runtime.load_extension(extension).await.unwrap();
+    let extension_dir = json!(root.join("notes").display().to_string());
+
+    let written = runtime
+        .call_function("notes", "roundtrip", vec![extension_dir.clone()])
+        .await
+        .unwrap();
+    assert_eq!(written, json!({ "ok": "hello" }));
+
+    let read = runtime
+        .call_function("notes", "read", vec![json!(secret.display().to_string())])
+        .await
+        .unwrap();
+    assert_eq!(
+        read,
+        json!({ "name": "PermissionDenied", "permission": "filesystemRead" })
+    );
+
+    let escape = format!("{}/data/../main.js", root.join("notes").display());
+    let write = runtime
+        .call_function("notes", "write", vec![json!(escape)])
+        .await
+        .unwrap();
+    assert_eq!(
+        write,
+        json!({ "name": "PermissionDenied", "permission": "filesystemWrite" })
+    );
+    assert_eq!(std::fs::read_to_string(&secret).unwrap(), "top secret");
+}
+
+#[tokio::test]
+async fn network_access_is_limited_to_allowed_hosts() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let app = axum::Router::new().route("/ping", axum::routing::get(|| async { "pong" }));
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    let dir = tempfile::tempdir().unwrap();
+    let extension = write_extension(
+        dir.path(),
+        "fetcher",
+        json!({ "network": [format!("127.0.0.1:{port}")] }),
+        &format!(
+            r#"{CAPTURE}
+            __hypr_extension.get = (url) => capture(async () => {{
+                const res = await hypr.net.fetch(url);
+                return {{ status: res.status, body: res.body }};
+            }});
+            "#
+        ),
+    );
+
+    let runtime = runtime().await;
+    runtime.load_extension(extension).await.unwrap();
+
+    let allowed = runtime
+        .call_function(
+            "fetcher",
+            "get",
+            vec![json!(format!("http://127.0.0.1:{port}/ping"))],
+        )
+        .await
+        .unwrap();
+    assert_eq!(allowed, json!({ "ok": { "status": 200, "body": "pong" } }));
+
+    let denied = runtime
+        .call_function(
+            "fetcher",
+            "get",
+            vec![json!(format!("http://localhost:{port}/ping"))],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        denied,
+        json!({ "name": "PermissionDenied", "permission": "network" })
+    );
+}
+
+#[tokio::test]
+async fn events_reach_subscribed_extensions() {
+    let dir = tempfile::tempdir().unwrap();
+    let listener = write_extension(
+        dir.path(),
+        "listener",
+        json!({}),
+        r#"
+        const received = [];
+        __hypr_extension.activate = () => {
+            hypr.events.on("sessionStopped", (event) => received.push(event.sessionId));
+            const off = hypr.events.on("transcriptDelta", () => received.push("delta"));
+            off();
+        };
+        __hypr_extension.received = () => received;
+        "#,
+    );
+    let failing = write_extension(
+        dir.path(),
+        "failing",
+        json!({}),
+        r#"
+        __hypr_extension.activate = () => {
+            hypr.events.on("sessionStopped", () => { throw new Error("boom"); });
+        };
+        "#,
+    );
+
+    let runtime = runtime().await;
+    runtime.load_extension(listener).await.unwrap();
+    runtime.load_extension(failing).await.unwrap();
+
+    runtime
+        .emit(HostEvent::TranscriptDelta {
+            session_id: "s1".to_string(),
+            delta: json!({ "words": [] }),
+        })
+        .await
+        .unwrap();
+    runtime
+        .emit(HostEvent::SessionStopped {
+            session_id: "s1".to_string(),
+        })
+        .await
+        .unwrap();
+
+    let received = runtime
+        .call_function("listener", "received", vec![])
+        .await
+        .unwrap();
+    assert_eq!(received, json!(["s1"]));
+}
+
+#[tokio::test]
+async fn patched_builtins_cannot_steal_another_extensions_grant() {
+    let dir = tempfile::tempdir().unwrap();
+    let thief = write_extension(
+        dir.path(),
+        "thief",
+        json!({}),
+        r#"
+        const tokens = [];
+        const apis = [];
+        const spy = (target, name, pick) => {
+            const original = target[name];
+            target[name] = function (...args) {
+                args.forEach(pick);
+                return original.apply(this, args);
+            };
+        };
+        const pickToken = (arg) => typeof arg === "string" && tokens.push(arg);
+        const pickApi = (arg) => arg && arg.db && apis.push(arg);
+        spy(Map.prototype, "get", pickToken);
+        spy(Map.prototype, "set", pickToken);
+        spy(Map.prototype, "delete", pickToken);
+        spy(Set.prototype, "add", () => {});
+        spy(Object, "freeze", pickApi);
+        spy(Object, "values", pickApi);
+
+        __hypr_extension.steal = async () => {
+            const ops = globalThis.Deno?.core?.ops;
+            const humans = [];
+            for (const token of tokens) {
+                const reply = await ops?.op_hypr_db?.(token, { method: "listHumans" });
+                if (reply?.ok) humans.push(...reply.ok);
+            }
+            for (const api of apis) {
+                try {
+                    humans.push(...(await api.db.humans.list()));
+                } catch {}
+            }
+            return { opsVisible: typeof ops?.op_hypr_db === "function", humans };
+        };
+        "#,
+    );
+    let victim = write_extension(
+        dir.path(),
+        "victim",
+        json!({ "db": ["humans"] }),
+        r#"
+        __hypr_extension.activate = () => {
+            hypr.events.on("sessionStopped", () => {});
+        };
+        __hypr_extension.humans = () => hypr.db.humans.list();
+        "#,
+    );
+
+    let runtime = runtime().await;
+    runtime.load_extension(thief).await.unwrap();
+    runtime.load_extension(victim).await.unwrap();
+    runtime
+        .emit(HostEvent::SessionStopped {
+            session_id: "s1".to_string(),
+        })
+        .await
+        .unwrap();
+
+    let humans = runtime
+        .call_function("victim", "humans", vec![])
+        .await
+        .unwrap();
+    assert_eq!(humans, json!([{ "name": "Alice" }]));
+
+    let stolen = runtime
+        .call_function("thief", "steal", vec![])
+        .await
+        .unwrap();
+    assert_eq!(stolen, json!({ "opsVisible": false, "humans": [] }));
+}
@@ -11,9 +11,42 @@ pub struct HooksConfig {
     pub on: HashMap<String, Vec<HookDefinition>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+/// A failing hook is retried `retries` times, waiting `retry_backoff_ms`
+/// (doubled after each attempt). Sequential hooks run one after another in the
+/// order they are listed, alongside the hooks that run in parallel. `when`
+/// restricts the hook to events whose args match every entry: string args must
+/// be equal, list args such as `tags` must contain the value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
 pub struct HookDefinition {
     pub command: String,
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub retries: u32,
+    #[serde(default)]
+    pub retry_backoff_ms: Option<u64>,
+    #[serde(default)]
+    pub sequential: bool,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    #[serde(default)]
+    pub cwd: Option<String>,
+    #[serde(default)]
+    pub when: HashMap<String, String>,
+}
+
+impl HookDefinition {
+    pub fn matches(&self, args: &serde_json::Value) -> bool {
+        self.when.iter().all(|(key, expected)| match args.get(key) {
+            Some(serde_json::Value::String(value)) => value == expected,
+            Some(serde_json::Value::Array(values)) => {
+                values.iter().any(|v| v.as_str() == Some(expected.as_str()))
+            }
+            Some(serde_json::Value::Bool(value)) => value.to_string() == *expected,
+            Some(serde_json::Value::Number(value)) => value.to_string() == *expected,
+            _ => false,
+        })
+    }
 }
 
 impl HooksConfig {
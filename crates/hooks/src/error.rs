@@ -10,4 +10,6 @@ pub enum Error {
     ConfigParse(String),
     #[error("unsupported config version: {0}")]
     UnsupportedVersion(u8),
+    #[error("failed to write execution log: {0}")]
+    Log(String),
 }
@@ -10,6 +10,24 @@ pub enum HookEvent {
     #[serde(rename = "beforeListeningStarted")]
     #[specta(rename = "beforeListeningStarted")]
     BeforeListeningStarted { args: BeforeListeningStartedArgs },
+    #[serde(rename = "calendarEventStarting")]
+    #[specta(rename = "calendarEventStarting")]
+    CalendarEventStarting { args: CalendarEventStartingArgs },
+    #[serde(rename = "enhancedNoteGenerated")]
+    #[specta(rename = "enhancedNoteGenerated")]
+    EnhancedNoteGenerated { args: EnhancedNoteGeneratedArgs },
+    #[serde(rename = "exportCompleted")]
+    #[specta(rename = "exportCompleted")]
+    ExportCompleted { args: ExportCompletedArgs },
+    #[serde(rename = "sessionCreated")]
+    #[specta(rename = "sessionCreated")]
+    SessionCreated { args: SessionCreatedArgs },
+    #[serde(rename = "sessionDeleted")]
+    #[specta(rename = "sessionDeleted")]
+    SessionDeleted { args: SessionDeletedArgs },
+    #[serde(rename = "transcriptFinalized")]
+    #[specta(rename = "transcriptFinalized")]
+    TranscriptFinalized { args: TranscriptFinalizedArgs },
 }
 
 impl HookEvent {
@@ -17,6 +35,12 @@ impl HookEvent {
         match self {
             HookEvent::AfterListeningStopped { .. } => "afterListeningStopped",
             HookEvent::BeforeListeningStarted { .. } => "beforeListeningStarted",
+            HookEvent::CalendarEventStarting { .. } => "calendarEventStarting",
+            HookEvent::EnhancedNoteGenerated { .. } => "enhancedNoteGenerated",
+            HookEvent::ExportCompleted { .. } => "exportCompleted",
+            HookEvent::SessionCreated { .. } => "sessionCreated",
+            HookEvent::SessionDeleted { .. } => "sessionDeleted",
+            HookEvent::TranscriptFinalized { .. } => "transcriptFinalized",
         }
     }
 
@@ -24,8 +48,40 @@ impl HookEvent {
         match self {
             HookEvent::AfterListeningStopped { args } => args.to_cli_args(),
             HookEvent::BeforeListeningStarted { args } => args.to_cli_args(),
+            HookEvent::CalendarEventStarting { args } => args.to_cli_args(),
+            HookEvent::EnhancedNoteGenerated { args } => args.to_cli_args(),
+            HookEvent::ExportCompleted { args } => args.to_cli_args(),
+            HookEvent::SessionCreated { args } => args.to_cli_args(),
+            HookEvent::SessionDeleted { args } => args.to_cli_args(),
+            HookEvent::TranscriptFinalized { args } => args.to_cli_args(),
         }
     }
+
+    pub fn args_value(&self) -> serde_json::Value {
+        match self {
+            HookEvent::AfterListeningStopped { args } => serde_json::to_value(args),
+            HookEvent::BeforeListeningStarted { args } => serde_json::to_value(args),
+            HookEvent::CalendarEventStarting { args } => serde_json::to_value(args),
+            HookEvent::EnhancedNoteGenerated { args } => serde_json::to_value(args),
+            HookEvent::ExportCompleted { args } => serde_json::to_value(args),
+            HookEvent::SessionCreated { args } => serde_json::to_value(args),
+            HookEvent::SessionDeleted { args } => serde_json::to_value(args),
+            HookEvent::TranscriptFinalized { args } => serde_json::to_value(args),
+        }
+        .unwrap_or(serde_json::Value::Null)
+    }
+
+    /// JSON written to the hook's stdin: `{ "event": "<name>", "args": { ... } }`.
+    ///
+    /// Unlike the CLI flags, this carries every field, including lists such as
+    /// `participants`.
+    pub fn stdin_payload(&self) -> String {
+        serde_json::json!({
+            "event": self.condition_key(),
+            "args": self.args_value(),
+        })
+        .to_string()
+    }
 }
 
 pub trait HookArgs {
@@ -80,3 +136,192 @@ impl HookArgs for BeforeListeningStartedArgs {
         args
     }
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct HookParticipant {
+    pub human_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+/// Fired when a calendar event with a linked meeting is about to start.
+/// `participants` is only delivered in the stdin payload.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct CalendarEventStartingArgs {
+    pub event_id: String,
+    pub title: String,
+    pub started_at: String,
+    pub ended_at: String,
+    pub participants: Vec<HookParticipant>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meeting_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+}
+
+impl HookArgs for CalendarEventStartingArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(12);
+        push_cli_arg(&mut args, stringify!(event_id), &self.event_id);
+        push_cli_arg(&mut args, stringify!(title), &self.title);
+        push_cli_arg(&mut args, stringify!(started_at), &self.started_at);
+        push_cli_arg(&mut args, stringify!(ended_at), &self.ended_at);
+
+        if let Some(meeting_link) = &self.meeting_link {
+            push_cli_arg(&mut args, stringify!(meeting_link), meeting_link);
+        }
+        if let Some(session_id) = &self.session_id {
+            push_cli_arg(&mut args, stringify!(session_id), session_id);
+        }
+
+        args
+    }
+}
+
+/// Fired after an enhanced note has been generated and saved.
+/// `participants` and `tags` are only delivered in the stdin payload.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct EnhancedNoteGeneratedArgs {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub participants: Vec<HookParticipant>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub resource_dir: String,
+    pub note_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+}
+
+impl HookArgs for EnhancedNoteGeneratedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(10);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+
+        if let Some(title) = &self.title {
+            push_cli_arg(&mut args, stringify!(title), title);
+        }
+
+        push_cli_arg(&mut args, stringify!(resource_dir), &self.resource_dir);
+        push_cli_arg(&mut args, stringify!(note_path), &self.note_path);
+
+        if let Some(template_id) = &self.template_id {
+            push_cli_arg(&mut args, stringify!(template_id), template_id);
+        }
+
+        args
+    }
+}
+
+/// Fired after a session has been exported to a file.
+/// `tags` is only delivered in the stdin payload.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ExportCompletedArgs {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub format: String,
+    pub output_path: String,
+}
+
+impl HookArgs for ExportCompletedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(8);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+
+        if let Some(title) = &self.title {
+            push_cli_arg(&mut args, stringify!(title), title);
+        }
+
+        push_cli_arg(&mut args, stringify!(format), &self.format);
+        push_cli_arg(&mut args, stringify!(output_path), &self.output_path);
+
+        args
+    }
+}
+
+/// Fired after a new session has been created.
+/// `tags` is only delivered in the stdin payload.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct SessionCreatedArgs {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub resource_dir: String,
+}
+
+impl HookArgs for SessionCreatedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(6);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+
+        if let Some(title) = &self.title {
+            push_cli_arg(&mut args, stringify!(title), title);
+        }
+
+        push_cli_arg(&mut args, stringify!(resource_dir), &self.resource_dir);
+
+        args
+    }
+}
+
+/// Fired after a session has been deleted. Its files may already be gone.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct SessionDeletedArgs {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+impl HookArgs for SessionDeletedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(4);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+
+        if let Some(title) = &self.title {
+            push_cli_arg(&mut args, stringify!(title), title);
+        }
+
+        args
+    }
+}
+
+/// Fired once the transcript of a session is final and written to disk.
+/// `participants` and `tags` are only delivered in the stdin payload.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct TranscriptFinalizedArgs {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub participants: Vec<HookParticipant>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub resource_dir: String,
+    pub transcript_path: String,
+}
+
+impl HookArgs for TranscriptFinalizedArgs {
+    fn to_cli_args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(8);
+        push_cli_arg(&mut args, stringify!(session_id), &self.session_id);
+
+        if let Some(title) = &self.title {
+            push_cli_arg(&mut args, stringify!(title), title);
+        }
+
+        push_cli_arg(&mut args, stringify!(resource_dir), &self.resource_dir);
+        push_cli_arg(
+            &mut args,
+            stringify!(transcript_path),
+            &self.transcript_path,
+        );
+
+        args
+    }
+}
@@ -1,11 +1,19 @@
 mod config;
 mod error;
 mod event;
+mod log;
 mod naming;
 mod runner;
 
 pub use config::{HookDefinition, HooksConfig};
 pub use error::{Error, Result};
-pub use event::{AfterListeningStoppedArgs, BeforeListeningStartedArgs, HookArgs, HookEvent};
+pub use event::{
+    AfterListeningStoppedArgs, BeforeListeningStartedArgs, CalendarEventStartingArgs,
+    EnhancedNoteGeneratedArgs, ExportCompletedArgs, HookArgs, HookEvent, HookParticipant,
+    SessionCreatedArgs, SessionDeletedArgs, TranscriptFinalizedArgs,
+};
+pub use log::{
+    HookExecution, MAX_LOG_ENTRIES, append_executions, read_executions, rotate_executions,
+};
 pub use naming::cli_flag;
 pub use runner::{HookResult, run_hooks_for_event};
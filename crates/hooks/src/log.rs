@@ -0,0 +1,163 @@
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::{Error, Result};
+use crate::runner::HookResult;
+
+/// Oldest entries are dropped once the log grows past this.
+pub const MAX_LOG_ENTRIES: usize = 500;
+const MAX_OUTPUT_CHARS: usize = 2000;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct HookExecution {
+    pub event: String,
+    pub command: String,
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    pub attempts: u32,
+    pub duration_ms: u64,
+    pub finished_at_ms: u64,
+    pub stderr: String,
+}
+
+impl HookExecution {
+    pub fn from_result(event: &str, result: &HookResult) -> Self {
+        let finished_at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        Self {
+            event: event.to_string(),
+            command: result.command.clone(),
+            success: result.success,
+            exit_code: result.exit_code,
+            attempts: result.attempts,
+            duration_ms: result.duration_ms,
+            finished_at_ms,
+            stderr: result.stderr.chars().take(MAX_OUTPUT_CHARS).collect(),
+        }
+    }
+}
+
+/// Appends to a JSON-lines log at `path` without rewriting it. Callers
+/// serialize appends and call [`rotate_executions`] to bound its size.
+pub fn append_executions(path: &Path, executions: &[HookExecution]) -> Result<()> {
+    if executions.is_empty() {
+        return Ok(());
+    }
+
+    let mut buf = String::new();
+    for execution in executions {
+        buf.push_str(&serde_json::to_string(execution).map_err(|e| Error::Log(e.to_string()))?);
+        buf.push('\n');
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| Error::Log(e.to_string()))?;
+    }
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| Error::Log(e.to_string()))?;
+    file.write_all(buf.as_bytes())
+        .map_err(|e| Error::Log(e.to_string()))
+}
+
+/// Keeps the newest `MAX_LOG_ENTRIES` once the log has grown past them,
+/// replacing the file atomically.
+pub fn rotate_executions(path: &Path) -> Result<()> {
+    let lines = read_lines(path)?;
+    if lines.len() <= MAX_LOG_ENTRIES {
+        return Ok(());
+    }
+
+    let mut content = lines[lines.len() - MAX_LOG_ENTRIES..].join("\n");
+    content.push('\n');
+
+    let tmp = path.with_extension("jsonl.tmp");
+    std::fs::write(&tmp, content).map_err(|e| Error::Log(e.to_string()))?;
+    std::fs::rename(&tmp, path).map_err(|e| Error::Log(e.to_string()))
+}
+
+/// Returns up to `limit` entries, newest first. Unreadable lines are skipped.
+pub fn read_executions(path: &Path, limit: usize) -> Result<Vec<HookExecution>> {
+    Ok(read_lines(path)?
+        .iter()
+        .rev()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .take(limit)
+        .collect())
+}
+
+fn read_lines(path: &Path) -> Result<Vec<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string)
+            .collect()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(Error::Log(e.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execution(command: &str) -> HookExecution {
+        HookExecution {
+            event: "sessionCreated".to_string(),
+            command: command.to_string(),
+            success: false,
+            exit_code: Some(1),
+            attempts: 1,
+            duration_ms: 3,
+            finished_at_ms: 0,
+            stderr: String::new(),
+        }
+    }
+
+    #[test]
+    fn appends_and_reads_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hooks").join("executions.jsonl");
+
+        assert!(read_executions(&path, 10).unwrap().is_empty());
+
+        append_executions(&path, &[execution("a"), execution("b")]).unwrap();
+        append_executions(&path, &[execution("c")]).unwrap();
+
+        let commands: Vec<_> = read_executions(&path, 2)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.command)
+            .collect();
+        assert_eq!(commands, vec!["c", "b"]);
+    }
+
+    #[test]
+    fn keeps_newest_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("executions.jsonl");
+
+        let executions: Vec<_> = (0..MAX_LOG_ENTRIES + 5)
+            .map(|i| execution(&i.to_string()))
+            .collect();
+        append_executions(&path, &executions).unwrap();
+        assert_eq!(
+            read_executions(&path, usize::MAX).unwrap().len(),
+            MAX_LOG_ENTRIES + 5
+        );
+
+        rotate_executions(&path).unwrap();
+        let all = read_executions(&path, usize::MAX).unwrap();
+        assert_eq!(all.len(), MAX_LOG_ENTRIES);
+        assert_eq!(all[0].command, (MAX_LOG_ENTRIES + 4).to_string());
+        assert_eq!(all.last().unwrap().command, "5");
+    }
+}
@@ -1,10 +1,12 @@
+use std::collections::HashMap;
 use std::ffi::OsString;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use crate::config::HooksConfig;
+use crate::config::{HookDefinition, HooksConfig};
 use crate::event::HookEvent;
 
-const HOOK_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_HOOK_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(500);
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
 pub struct HookResult {
@@ -13,71 +15,276 @@ pub struct HookResult {
     pub exit_code: Option<i32>,
     pub stdout: String,
     pub stderr: String,
+    pub attempts: u32,
+    pub duration_ms: u64,
 }
 
 pub async fn run_hooks_for_event(config: &HooksConfig, event: HookEvent) -> Vec<HookResult> {
     let condition_key = event.condition_key();
     let cli_args = event.cli_args();
+    let payload = event.stdin_payload();
+    let event_args = event.args_value();
 
     let Some(hooks) = config.on.get(condition_key) else {
         return vec![];
     };
 
-    let futures: Vec<_> = hooks
+    let (sequential, parallel): (Vec<_>, Vec<_>) = hooks
         .iter()
-        .map(|hook_def| {
-            let command = hook_def.command.clone();
-            let args = cli_args.clone();
-            async move { execute_hook(&command, &args).await }
-        })
-        .collect();
-
-    futures_util::future::join_all(futures).await
+        .enumerate()
+        .filter(|(_, hook_def)| hook_def.matches(&event_args))
+        .partition(|(_, hook_def)| hook_def.sequential);
+
+    let parallel = futures_util::future::join_all(parallel.into_iter().map(|(idx, hook_def)| {
+        let cli_args = &cli_args;
+        let payload = payload.as_str();
+        async move { (idx, run_hook(hook_def, cli_args, payload).await) }
+    }));
+
+    let sequential = async {
+        let mut results = Vec::with_capacity(sequential.len());
+        for (idx, hook_def) in sequential {
+            results.push((idx, run_hook(hook_def, &cli_args, &payload).await));
+        }
+        results
+    };
+
+    let (mut results, sequential) = futures_util::future::join(parallel, sequential).await;
+    results.extend(sequential);
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, result)| result).collect()
 }
 
-async fn execute_hook(command: &str, args: &[OsString]) -> HookResult {
-    let expanded = shellexpand::full(command)
-        .map(|s| s.into_owned())
-        .unwrap_or_else(|_| command.to_string());
+async fn run_hook(hook: &HookDefinition, args: &[OsString], payload: &str) -> HookResult {
+    let started = Instant::now();
+    let mut backoff = hook
+        .retry_backoff_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_RETRY_BACKOFF);
 
-    let parts: Vec<&str> = expanded.split_whitespace().collect();
+    let mut attempts = 1;
+    let mut result = execute_hook(hook, args, payload).await;
 
-    if parts.is_empty() {
-        return HookResult {
-            command: command.to_string(),
-            success: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: "empty command".to_string(),
-        };
+    while !result.success && attempts <= hook.retries {
+        tokio::time::sleep(backoff).await;
+        backoff = backoff.saturating_mul(2);
+        attempts += 1;
+        result = execute_hook(hook, args, payload).await;
+    }
+
+    HookResult {
+        attempts,
+        duration_ms: started.elapsed().as_millis() as u64,
+        ..result
+    }
+}
+
+fn failure(command: &str, stderr: String) -> HookResult {
+    HookResult {
+        command: command.to_string(),
+        success: false,
+        exit_code: None,
+        stdout: String::new(),
+        stderr,
+        attempts: 1,
+        duration_ms: 0,
+    }
+}
+
+/// Part of a command-line word, tagged with how it was quoted.
+#[derive(Debug, PartialEq)]
+enum Piece {
+    Bare(String),
+    DoubleQuoted(String),
+    SingleQuoted(String),
+}
+
+fn push_piece(word: &mut Option<Vec<Piece>>, piece: Piece) {
+    let word = word.get_or_insert_with(Vec::new);
+    match (word.last_mut(), piece) {
+        (Some(Piece::Bare(last)), Piece::Bare(s)) => last.push_str(&s),
+        (_, piece) => word.push(piece),
+    }
+}
+
+/// Splits a command line into words with POSIX shell quoting rules, keeping
+/// track of quoting so that expansion can leave single-quoted text alone.
+/// Returns `None` on unbalanced quotes or a trailing backslash.
+fn split_command(command: &str) -> Option<Vec<Vec<Piece>>> {
+    let mut words = Vec::new();
+    let mut word: Option<Vec<Piece>> = None;
+    let mut chars = command.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => words.extend(word.take()),
+            '#' if word.is_none() => {
+                chars.by_ref().find(|&c| c == '\n');
+            }
+            '\'' => {
+                let mut s = String::new();
+                loop {
+                    match chars.next()? {
+                        '\'' => break,
+                        c => s.push(c),
+                    }
+                }
+                push_piece(&mut word, Piece::SingleQuoted(s));
+            }
+            '"' => {
+                let mut s = String::new();
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => match chars.next()? {
+                            c @ ('"' | '\\') => s.push(c),
+                            c @ ('$' | '`') => {
+                                push_piece(&mut word, Piece::DoubleQuoted(std::mem::take(&mut s)));
+                                push_piece(&mut word, Piece::SingleQuoted(c.to_string()));
+                            }
+                            '\n' => {}
+                            c => {
+                                s.push('\\');
+                                s.push(c);
+                            }
+                        },
+                        c => s.push(c),
+                    }
+                }
+                push_piece(&mut word, Piece::DoubleQuoted(s));
+            }
+            '\\' => match chars.next()? {
+                '\n' => {}
+                c => push_piece(&mut word, Piece::SingleQuoted(c.to_string())),
+            },
+            c => push_piece(&mut word, Piece::Bare(c.to_string())),
+        }
     }
 
-    let mut cmd = tokio::process::Command::new(parts[0]);
+    words.extend(word);
+    Some(words)
+}
 
-    if parts.len() > 1 {
-        cmd.args(&parts[1..]);
+/// Environment a hook runs with: its own `env`, then `inherited` overrides,
+/// then the app's environment.
+struct HookEnv<'a> {
+    hook: &'a HashMap<String, String>,
+    inherited: &'a HashMap<String, String>,
+}
+
+impl HookEnv<'_> {
+    fn get(&self, name: &str) -> Option<String> {
+        self.hook
+            .get(name)
+            .or_else(|| self.inherited.get(name))
+            .cloned()
+            .or_else(|| std::env::var(name).ok())
     }
 
+    /// Resolves `$VAR` for `shellexpand`. Unknown variables are left as
+    /// written.
+    fn lookup(&self) -> impl FnMut(&str) -> Result<Option<String>, std::convert::Infallible> + '_ {
+        move |name| Ok(self.get(name))
+    }
+
+    fn home_dir(&self) -> Option<String> {
+        self.hook
+            .get("HOME")
+            .or_else(|| self.inherited.get("HOME"))
+            .cloned()
+            .or_else(|| dirs::home_dir().map(|p| p.to_string_lossy().into_owned()))
+    }
+}
+
+fn expand(value: &str, env: &HookEnv) -> String {
+    match shellexpand::full_with_context(value, || env.home_dir(), env.lookup()) {
+        Ok(expanded) => expanded.into_owned(),
+        Err(e) => match e.cause {},
+    }
+}
+
+fn expand_vars(value: &str, env: &HookEnv) -> String {
+    match shellexpand::env_with_context(value, env.lookup()) {
+        Ok(expanded) => expanded.into_owned(),
+        Err(e) => match e.cause {},
+    }
+}
+
+/// `~` is only expanded at the start of an unquoted word, `$VAR` anywhere
+/// outside single quotes.
+fn expand_word(pieces: &[Piece], env: &HookEnv) -> String {
+    let mut out = String::new();
+    for (i, piece) in pieces.iter().enumerate() {
+        match piece {
+            Piece::Bare(s) if i == 0 => out.push_str(&expand(s, env)),
+            Piece::Bare(s) | Piece::DoubleQuoted(s) => out.push_str(&expand_vars(s, env)),
+            Piece::SingleQuoted(s) => out.push_str(s),
+        }
+    }
+    out
+}
+
+async fn execute_hook(hook: &HookDefinition, args: &[OsString], payload: &str) -> HookResult {
+    execute_hook_with_env(hook, args, payload, &HashMap::new()).await
+}
+
+async fn execute_hook_with_env(
+    hook: &HookDefinition,
+    args: &[OsString],
+    payload: &str,
+    inherited: &HashMap<String, String>,
+) -> HookResult {
+    let command = hook.command.as_str();
+    let env = HookEnv {
+        hook: &hook.env,
+        inherited,
+    };
+
+    let Some(words) = split_command(command) else {
+        return failure(command, "invalid command: unbalanced quotes".to_string());
+    };
+    let parts: Vec<String> = words.iter().map(|word| expand_word(word, &env)).collect();
+
+    if parts.is_empty() {
+        return failure(command, "empty command".to_string());
+    }
+
+    let mut cmd = tokio::process::Command::new(&parts[0]);
+    cmd.args(&parts[1..]);
     cmd.args(args);
+    cmd.envs(inherited);
+    cmd.envs(&hook.env);
+
+    if let Some(cwd) = &hook.cwd {
+        cmd.current_dir(expand(cwd, &env));
+    }
+
+    let timeout = hook
+        .timeout_secs
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_HOOK_TIMEOUT);
 
     let mut child = match cmd
+        .stdin(std::process::Stdio::piped())
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true)
         .spawn()
     {
         Ok(child) => child,
-        Err(e) => {
-            return HookResult {
-                command: command.to_string(),
-                success: false,
-                exit_code: None,
-                stdout: String::new(),
-                stderr: format!("failed to spawn command: {}", e),
-            };
+        Err(e) => return failure(command, format!("failed to spawn command: {}", e)),
+    };
+
+    // Hooks are free to ignore stdin, so a broken pipe here is not an error.
+    let stdin = child.stdin.take();
+    let wait = async {
+        if let Some(mut stdin) = stdin {
+            let _ = tokio::io::AsyncWriteExt::write_all(&mut stdin, payload.as_bytes()).await;
         }
+        child.wait().await
     };
 
-    match tokio::time::timeout(HOOK_TIMEOUT, child.wait()).await {
+    match tokio::time::timeout(timeout, wait).await {
         Ok(Ok(status)) => {
             let stdout = match child.stdout.take() {
                 Some(mut stdout) => {
@@ -101,25 +308,18 @@ async fn execute_hook(command: &str, args: &[OsString]) -> HookResult {
                 exit_code: status.code(),
                 stdout,
                 stderr,
+                attempts: 1,
+                duration_ms: 0,
             }
         }
-        Ok(Err(e)) => HookResult {
-            command: command.to_string(),
-            success: false,
-            exit_code: None,
-            stdout: String::new(),
-            stderr: format!("failed to wait for command: {}", e),
-        },
+        Ok(Err(e)) => failure(command, format!("failed to wait for command: {}", e)),
         Err(_) => {
             let _ = child.kill().await;
             let _ = child.wait().await;
-            HookResult {
-                command: command.to_string(),
-                success: false,
-                exit_code: None,
-                stdout: String::new(),
-                stderr: format!("hook timed out after {} seconds", HOOK_TIMEOUT.as_secs()),
-            }
+            failure(
+                command,
+                format!("hook timed out after {} seconds", timeout.as_secs()),
+            )
         }
     }
 }
@@ -128,9 +328,27 @@ async fn execute_hook(command: &str, args: &[OsString]) -> HookResult {
 mod tests {
     use super::*;
 
+    fn hook(command: &str) -> HookDefinition {
+        HookDefinition {
+            command: command.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn session_created(tags: &[&str]) -> HookEvent {
+        HookEvent::SessionCreated {
+            args: crate::SessionCreatedArgs {
+                session_id: "abc".to_string(),
+                title: None,
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                resource_dir: "/tmp".to_string(),
+            },
+        }
+    }
+
     #[tokio::test]
     async fn empty_command() {
-        let result = execute_hook("", &[]).await;
+        let result = execute_hook(&hook(""), &[], "").await;
         assert!(!result.success);
         assert_eq!(result.stderr, "empty command");
     }
@@ -138,7 +356,7 @@ mod tests {
     #[tokio::test]
     #[cfg(unix)]
     async fn successful_command() {
-        let result = execute_hook("echo hello", &[]).await;
+        let result = execute_hook(&hook("echo hello"), &[], "").await;
         assert!(result.success);
         assert_eq!(result.exit_code, Some(0));
         assert_eq!(result.stdout.trim(), "hello");
@@ -147,7 +365,7 @@ mod tests {
     #[tokio::test]
     #[cfg(unix)]
     async fn failed_command() {
-        let result = execute_hook("false", &[]).await;
+        let result = execute_hook(&hook("false"), &[], "").await;
         assert!(!result.success);
         assert_eq!(result.exit_code, Some(1));
     }
@@ -156,7 +374,7 @@ mod tests {
     #[cfg(unix)]
     async fn with_cli_args() {
         let args = vec![OsString::from("world")];
-        let result = execute_hook("echo", &args).await;
+        let result = execute_hook(&hook("echo"), &args, "").await;
         assert!(result.success);
         assert_eq!(result.stdout.trim(), "world");
     }
@@ -165,7 +383,7 @@ mod tests {
     #[cfg(unix)]
     async fn expands_home_env_var() {
         let home = std::env::var("HOME").unwrap();
-        let result = execute_hook("echo $HOME", &[]).await;
+        let result = execute_hook(&hook("echo $HOME"), &[], "").await;
         assert!(result.success);
         assert_eq!(result.stdout.trim(), home);
     }
@@ -173,14 +391,207 @@ mod tests {
     #[tokio::test]
     #[cfg(unix)]
     async fn expands_tilde_in_command_path() {
-        let result = execute_hook("~/../../bin/echo tilde_works", &[]).await;
+        let result = execute_hook(&hook("~/../../bin/echo tilde_works"), &[], "").await;
         assert!(result.success);
         assert_eq!(result.stdout.trim(), "tilde_works");
     }
 
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn receives_payload_on_stdin() {
+        let result = execute_hook(&hook("cat"), &[], r#"{"event":"sessionCreated"}"#).await;
+        assert!(result.success);
+        assert_eq!(result.stdout, r#"{"event":"sessionCreated"}"#);
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn runs_hooks_with_json_payload() {
+        let config = HooksConfig::from_str(
+            r#"{"version":0,"on":{"sessionDeleted":[{"command":"sh -c cat"}]}}"#,
+        )
+        .unwrap();
+        let event = HookEvent::SessionDeleted {
+            args: crate::SessionDeletedArgs {
+                session_id: "abc".to_string(),
+                title: Some("Standup".to_string()),
+            },
+        };
+
+        let results = run_hooks_for_event(&config, event).await;
+        assert_eq!(results.len(), 1);
+
+        let payload: serde_json::Value = serde_json::from_str(&results[0].stdout).unwrap();
+        assert_eq!(payload["event"], "sessionDeleted");
+        assert_eq!(payload["args"]["session_id"], "abc");
+        assert_eq!(payload["args"]["title"], "Standup");
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn respects_quoted_args() {
+        let result = execute_hook(&hook(r#"printf "%s|" "hello world" 'a b'"#), &[], "").await;
+        assert!(result.success);
+        assert_eq!(result.stdout, "hello world|a b|");
+    }
+
+    #[tokio::test]
+    async fn unbalanced_quotes() {
+        let result = execute_hook(&hook(r#"echo "oops"#), &[], "").await;
+        assert!(!result.success);
+        assert!(result.stderr.contains("unbalanced quotes"));
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn applies_env_and_cwd() {
+        let inherited = [("HOOK_GREETING".to_string(), "from-parent".to_string())].into();
+
+        let dir = tempfile::tempdir().unwrap();
+        let hook = HookDefinition {
+            env: [
+                ("HOOK_GREETING".to_string(), "hi".to_string()),
+                (
+                    "HOOK_DIR".to_string(),
+                    dir.path().to_string_lossy().to_string(),
+                ),
+            ]
+            .into(),
+            cwd: Some("$HOOK_DIR".to_string()),
+            ..hook(r#"sh -c 'echo $HOOK_GREETING; pwd'"#)
+        };
+
+        let result = execute_hook_with_env(&hook, &[], "", &inherited).await;
+        assert!(result.success);
+
+        let lines: Vec<_> = result.stdout.lines().collect();
+        assert_eq!(lines[0], "hi");
+        assert_eq!(
+            std::fs::canonicalize(lines[1]).unwrap(),
+            std::fs::canonicalize(dir.path()).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn expands_hook_env_before_parent_env() {
+        let inherited = [
+            ("HOOK_TARGET".to_string(), "parent".to_string()),
+            ("HOOK_OTHER".to_string(), "other".to_string()),
+        ]
+        .into();
+
+        let hook = HookDefinition {
+            env: [("HOOK_TARGET".to_string(), "hook".to_string())].into(),
+            ..hook(
+                r#"printf "%s|" $HOOK_TARGET "$HOOK_TARGET" '$HOOK_TARGET' \$HOOK_TARGET $HOOK_OTHER"#,
+            )
+        };
+
+        let result = execute_hook_with_env(&hook, &[], "", &inherited).await;
+        assert!(result.success);
+        assert_eq!(result.stdout, "hook|hook|$HOOK_TARGET|$HOOK_TARGET|other|");
+    }
+
+    #[test]
+    fn splits_like_a_shell() {
+        let words = split_command(r#"a "b c"'d' e\ f # comment"#).unwrap();
+        assert_eq!(
+            words,
+            vec![
+                vec![Piece::Bare("a".to_string())],
+                vec![
+                    Piece::DoubleQuoted("b c".to_string()),
+                    Piece::SingleQuoted("d".to_string()),
+                ],
+                vec![
+                    Piece::Bare("e".to_string()),
+                    Piece::SingleQuoted(" ".to_string()),
+                    Piece::Bare("f".to_string()),
+                ],
+            ]
+        );
+        assert!(split_command("echo 'oops").is_none());
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn times_out() {
+        let hook = HookDefinition {
+            timeout_secs: Some(1),
+            ..hook("sleep 10")
+        };
+
+        let result = execute_hook(&hook, &[], "").await;
+        assert!(!result.success);
+        assert_eq!(result.stderr, "hook timed out after 1 seconds");
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn retries_until_success() {
+        let dir = tempfile::tempdir().unwrap();
+        let hook = HookDefinition {
+            retries: 3,
+            retry_backoff_ms: Some(10),
+            cwd: Some(dir.path().to_string_lossy().to_string()),
+            ..hook(r#"sh -c 'echo x >> attempts; test $(wc -l < attempts) -ge 2'"#)
+        };
+
+        let result = run_hook(&hook, &[], "").await;
+        assert!(result.success);
+        assert_eq!(result.attempts, 2);
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn runs_sequential_hooks_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let cwd = dir.path().to_string_lossy().to_string();
+        let step = |command: &str| HookDefinition {
+            sequential: true,
+            cwd: Some(cwd.clone()),
+            ..hook(command)
+        };
+
+        let config = HooksConfig {
+            version: 0,
+            on: [(
+                "sessionCreated".to_string(),
+                vec![
+                    step(r#"sh -c 'sleep 0.2; echo first >> order'"#),
+                    step(r#"sh -c 'echo second >> order'"#),
+                ],
+            )]
+            .into(),
+        };
+
+        let results = run_hooks_for_event(&config, session_created(&[])).await;
+        assert!(results.iter().all(|r| r.success));
+
+        let order = std::fs::read_to_string(dir.path().join("order")).unwrap();
+        assert_eq!(order, "first\nsecond\n");
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn skips_hooks_whose_filter_does_not_match() {
+        let config = HooksConfig::from_str(
+            r#"{"version":0,"on":{"sessionCreated":[{"command":"true","when":{"tags":"customer"}}]}}"#,
+        )
+        .unwrap();
+
+        let results = run_hooks_for_event(&config, session_created(&["internal"])).await;
+        assert!(results.is_empty());
+
+        let results =
+            run_hooks_for_event(&config, session_created(&["internal", "customer"])).await;
+        assert_eq!(results.len(), 1);
+    }
+
     #[tokio::test]
     async fn nonexistent_command() {
-        let result = execute_hook("nonexistent_command_12345", &[]).await;
+        let result = execute_hook(&hook("nonexistent_command_12345"), &[], "").await;
         assert!(!result.success);
         assert!(result.stderr.contains("failed to spawn command"));
     }
@@ -16,6 +16,7 @@ pub struct Collection {
     pub enhanced_notes: Vec<EnhancedNote>,
     pub tags: Vec<Tag>,
     pub tag_mappings: Vec<TagMapping>,
+    pub attachments: Vec<Attachment>,
 }
 
 impl std::fmt::Display for Collection {
@@ -29,6 +30,7 @@ impl std::fmt::Display for Collection {
         writeln!(f, "enhanced_notes: {}", self.enhanced_notes.len())?;
         writeln!(f, "tags: {}", self.tags.len())?;
         writeln!(f, "tag_mappings: {}", self.tag_mappings.len())?;
+        writeln!(f, "attachments: {}", self.attachments.len())?;
 
         if let Some(s) = self.sessions.first() {
             writeln!(f, "\n[First Session]")?;
@@ -274,3 +276,15 @@ common_derives! {
         pub session_id: String,
     }
 }
+
+common_derives! {
+    pub struct Attachment {
+        pub id: String,
+        #[serde(default)]
+        pub session_id: String,
+        #[serde(default)]
+        pub file_name: String,
+        #[serde(default)]
+        pub source_path: String,
+    }
+}
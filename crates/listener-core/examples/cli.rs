@@ -81,6 +81,14 @@ impl ListenerRuntime for CliRuntime {
             SessionDataEvent::MicMuted { value, .. } => {
                 eprintln!("[data] mic muted={value}");
             }
+            SessionDataEvent::SpeakerIdentified {
+                channel,
+                speaker_index,
+                human_id,
+                ..
+            } => {
+                eprintln!("[data] channel {channel} speaker {speaker_index} = {human_id}");
+            }
         }
     }
 }
@@ -143,6 +151,8 @@ async fn main() {
         base_url,
         api_key,
         keywords: vec![],
+        voiceprints: vec![],
+        vocabulary: vec![],
     };
 
     let started = ractor::call!(root_ref, RootMsg::StartSession, params)
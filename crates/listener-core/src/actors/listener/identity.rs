@@ -0,0 +1,278 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use hypr_pyannote_local::embedding::EmbeddingExtractor;
+use hypr_pyannote_local::identity::{SessionSpeakers, SpeakerMatch, Voiceprint, VoiceprintStore};
+use owhisper_interface::stream::StreamResponse;
+
+use crate::actors::SAMPLE_RATE;
+
+/// Audio kept per channel, long enough to cover a finalized utterance.
+const BUFFER_SECS: usize = 30;
+/// Shorter utterances yield unreliable embeddings.
+const MIN_UTTERANCE_SECS: f64 = 1.0;
+/// Once a speaker has this many embeddings, further utterances are skipped.
+const MAX_SAMPLES_PER_SPEAKER: u32 = 8;
+
+/// Recent PCM samples of one audio channel, indexed from stream start.
+#[derive(Default)]
+struct ChannelAudio {
+    samples: VecDeque<i16>,
+    offset: usize,
+}
+
+impl ChannelAudio {
+    fn push(&mut self, bytes: &[u8]) {
+        self.samples.extend(
+            bytes
+                .chunks_exact(2)
+                .map(|b| i16::from_le_bytes([b[0], b[1]])),
+        );
+
+        let max = BUFFER_SECS * SAMPLE_RATE as usize;
+        if self.samples.len() > max {
+            let excess = self.samples.len() - max;
+            self.samples.drain(..excess);
+            self.offset += excess;
+        }
+    }
+
+    fn slice(&self, start: f64, end: f64) -> Option<Vec<i16>> {
+        let from = (start * SAMPLE_RATE as f64) as usize;
+        let to = (end * SAMPLE_RATE as f64) as usize;
+        if from < self.offset || to > self.offset + self.samples.len() || to <= from {
+            return None;
+        }
+
+        Some(
+            self.samples
+                .range(from - self.offset..to - self.offset)
+                .copied()
+                .collect(),
+        )
+    }
+}
+
+/// A finalized utterance of one speaker, waiting to be embedded.
+pub(super) struct EmbeddingJob {
+    pub(super) channel: i32,
+    pub(super) speaker_index: i32,
+    samples: Vec<i16>,
+}
+
+/// Loads the embedding model on first use. `compute` blocks on ONNX
+/// inference, so it must run off the actor, e.g. in `spawn_blocking`.
+#[derive(Clone, Default)]
+pub(super) struct SpeakerEmbedder {
+    extractor: Arc<Mutex<Option<EmbeddingExtractor>>>,
+}
+
+impl SpeakerEmbedder {
+    pub(super) fn compute(&self, job: EmbeddingJob) -> Option<Vec<f32>> {
+        let mut guard = self.extractor.lock().unwrap_or_else(|e| e.into_inner());
+        let extractor = guard.get_or_insert_with(EmbeddingExtractor::new);
+        match extractor.compute(job.samples.into_iter()) {
+            Ok(embedding) => Some(embedding),
+            Err(e) => {
+                tracing::warn!(error = ?e, "speaker_embedding_failed");
+                None
+            }
+        }
+    }
+}
+
+/// Collects finalized utterances per diarized speaker and matches their
+/// embeddings against known voiceprints. Speakers are keyed by the channel
+/// the frontend sees.
+pub(super) struct SpeakerIdentifier {
+    store: VoiceprintStore,
+    audio: BTreeMap<i32, ChannelAudio>,
+    speakers: BTreeMap<i32, SessionSpeakers>,
+    sample_counts: BTreeMap<(i32, i32), u32>,
+    resolved: BTreeMap<(i32, i32), String>,
+}
+
+impl SpeakerIdentifier {
+    pub(super) fn new(voiceprints: Vec<Voiceprint>) -> Self {
+        Self {
+            store: VoiceprintStore::new(voiceprints),
+            audio: BTreeMap::new(),
+            speakers: BTreeMap::new(),
+            sample_counts: BTreeMap::new(),
+            resolved: BTreeMap::new(),
+        }
+    }
+
+    pub(super) fn push_audio(&mut self, audio_channel: i32, bytes: &[u8]) {
+        self.audio.entry(audio_channel).or_default().push(bytes);
+    }
+
+    /// Cuts each speaker's span out of a final `response` for embedding.
+    /// `audio_channel` is the stream channel the words were transcribed from,
+    /// before any remapping.
+    pub(super) fn observe(
+        &mut self,
+        response: &StreamResponse,
+        audio_channel: i32,
+    ) -> Vec<EmbeddingJob> {
+        let StreamResponse::TranscriptResponse {
+            is_final: true,
+            channel,
+            channel_index,
+            ..
+        } = response
+        else {
+            return vec![];
+        };
+        let Some(alt) = channel.alternatives.first() else {
+            return vec![];
+        };
+        let ch = channel_index.first().copied().unwrap_or(0);
+
+        let mut jobs = Vec::new();
+        for (speaker, (start, end)) in speaker_spans(
+            alt.words
+                .iter()
+                .filter_map(|w| w.speaker.map(|speaker| (speaker, w.start, w.end))),
+        ) {
+            if end - start < MIN_UTTERANCE_SECS {
+                continue;
+            }
+
+            let count = self.sample_counts.entry((ch, speaker)).or_default();
+            if *count >= MAX_SAMPLES_PER_SPEAKER {
+                continue;
+            }
+
+            let Some(samples) = self
+                .audio
+                .get(&audio_channel)
+                .and_then(|audio| audio.slice(start, end))
+            else {
+                continue;
+            };
+
+            *count += 1;
+            jobs.push(EmbeddingJob {
+                channel: ch,
+                speaker_index: speaker,
+                samples,
+            });
+        }
+        jobs
+    }
+
+    /// Folds a computed embedding into the speaker and returns speakers whose
+    /// identity is new or changed.
+    pub(super) fn add_embedding(
+        &mut self,
+        channel: i32,
+        speaker_index: i32,
+        embedding: &[f32],
+    ) -> Vec<(i32, i32, SpeakerMatch)> {
+        self.speakers
+            .entry(channel)
+            .or_default()
+            .add(speaker_index, embedding);
+        self.resolve(channel)
+    }
+
+    /// Enrols a user label ("speaker 2 = Alice") so the voice is recognized
+    /// from now on. Returns the updated voiceprint for persisting.
+    pub(super) fn label(
+        &mut self,
+        channel: i32,
+        speaker_index: i32,
+        human_id: String,
+    ) -> Option<Voiceprint> {
+        let voiceprint = self
+            .speakers
+            .get(&channel)?
+            .label(&mut self.store, speaker_index, human_id.clone())?
+            .clone();
+        self.resolved.insert((channel, speaker_index), human_id);
+        Some(voiceprint)
+    }
+
+    fn resolve(&mut self, channel: i32) -> Vec<(i32, i32, SpeakerMatch)> {
+        let Some(speakers) = self.speakers.get(&channel) else {
+            return vec![];
+        };
+
+        let mut changed = Vec::new();
+        for (speaker_index, m) in speakers.resolve(&self.store) {
+            let key = (channel, speaker_index);
+            if self.resolved.get(&key) == Some(&m.human_id) {
+                continue;
+            }
+            self.resolved.insert(key, m.human_id.clone());
+            changed.push((channel, speaker_index, m));
+        }
+        changed
+    }
+}
+
+/// Earliest start and latest end per speaker, in seconds.
+fn speaker_spans(words: impl Iterator<Item = (i32, f64, f64)>) -> BTreeMap<i32, (f64, f64)> {
+    let mut spans: BTreeMap<i32, (f64, f64)> = BTreeMap::new();
+    for (speaker, start, end) in words {
+        spans
+            .entry(speaker)
+            .and_modify(|(s, e)| {
+                *s = s.min(start);
+                *e = e.max(end);
+            })
+            .or_insert((start, end));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speaker_spans() {
+        let spans = speaker_spans([(0, 0.5, 1.0), (1, 1.0, 1.4), (0, 1.5, 2.5)].into_iter());
+        assert_eq!(spans[&0], (0.5, 2.5));
+        assert_eq!(spans[&1], (1.0, 1.4));
+    }
+
+    #[test]
+    fn test_channel_audio_slice() {
+        let mut audio = ChannelAudio::default();
+        let second: Vec<u8> = (0..SAMPLE_RATE as i16)
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        audio.push(&second);
+
+        let slice = audio.slice(0.5, 1.0).unwrap();
+        assert_eq!(slice.len(), SAMPLE_RATE as usize / 2);
+        assert_eq!(slice[0], (SAMPLE_RATE / 2) as i16);
+        assert!(audio.slice(0.5, 1.5).is_none());
+
+        for _ in 0..BUFFER_SECS {
+            audio.push(&second);
+        }
+        assert!(audio.slice(0.0, 0.5).is_none());
+        assert!(
+            audio
+                .slice(BUFFER_SECS as f64, BUFFER_SECS as f64 + 1.0)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_add_embedding_reports_changes_once() {
+        let alice = [1.0, 0.1, 0.0];
+        let mut identifier = SpeakerIdentifier::new(vec![Voiceprint::new("alice", &alice)]);
+
+        let identified = identifier.add_embedding(0, 2, &alice);
+        assert_eq!(identified.len(), 1);
+        assert_eq!((identified[0].0, identified[0].1), (0, 2));
+        assert_eq!(identified[0].2.human_id, "alice");
+
+        assert!(identifier.add_embedding(0, 2, &[0.9, 0.2, 0.0]).is_empty());
+        assert!(identifier.add_embedding(1, 0, &[0.0, 1.0, 0.0]).is_empty());
+    }
+}
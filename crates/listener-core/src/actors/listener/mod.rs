@@ -1,14 +1,17 @@
 mod adapters;
+mod identity;
 mod stream;
 
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime};
 
 use bytes::Bytes;
-use ractor::{Actor, ActorName, ActorProcessingErr, ActorRef, SupervisionEvent};
+use ractor::{Actor, ActorName, ActorProcessingErr, ActorRef, RpcReplyPort, SupervisionEvent};
 use tokio::time::error::Elapsed;
 use tracing::Instrument;
 
+use hypr_pyannote_local::identity::Voiceprint;
+use hypr_transcript::{CorrectorChain, VocabularyCorrector};
 use owhisper_interface::stream::StreamResponse;
 use owhisper_interface::{ControlMessage, MixedMessage};
 
@@ -18,6 +21,7 @@ use crate::{
 };
 
 use adapters::spawn_rx_task;
+use identity::{SpeakerEmbedder, SpeakerIdentifier};
 
 pub(super) const LISTEN_STREAM_TIMEOUT: Duration = Duration::from_secs(15 * 60);
 pub(super) const LISTEN_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
@@ -30,6 +34,8 @@ pub enum ListenerMsg {
     StreamError(String),
     StreamEnded,
     StreamTimeout(Elapsed),
+    SpeakerEmbedding(i32, i32, Vec<f32>),
+    LabelSpeaker(i32, i32, String, RpcReplyPort<Option<Voiceprint>>),
 }
 
 #[derive(Clone)]
@@ -41,6 +47,8 @@ pub struct ListenerArgs {
     pub base_url: String,
     pub api_key: String,
     pub keywords: Vec<String>,
+    pub voiceprints: Vec<Voiceprint>,
+    pub vocabulary: Vec<String>,
     pub mode: crate::actors::ChannelMode,
     pub session_started_at: Instant,
     pub session_started_at_unix: SystemTime,
@@ -50,6 +58,9 @@ pub struct ListenerArgs {
 pub struct ListenerState {
     pub args: ListenerArgs,
     tx: ChannelSender,
+    identifier: SpeakerIdentifier,
+    embedder: SpeakerEmbedder,
+    corrector: CorrectorChain,
     rx_task: tokio::task::JoinHandle<()>,
     shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
 }
@@ -111,6 +122,9 @@ impl Actor for ListenerActor {
             });
 
             let state = ListenerState {
+                identifier: SpeakerIdentifier::new(args.voiceprints.clone()),
+                embedder: SpeakerEmbedder::default(),
+                corrector: corrector_chain(&args.vocabulary),
                 args,
                 tx,
                 rx_task,
@@ -146,12 +160,15 @@ impl Actor for ListenerActor {
 
         match message {
             ListenerMsg::AudioSingle(audio) => {
+                state.identifier.push_audio(0, &audio);
                 if let ChannelSender::Single(tx) = &state.tx {
                     let _ = tx.try_send(MixedMessage::Audio(audio));
                 }
             }
 
             ListenerMsg::AudioDual(mic, spk) => {
+                state.identifier.push_audio(0, &mic);
+                state.identifier.push_audio(1, &spk);
                 if let ChannelSender::Dual(tx) = &state.tx {
                     let _ = tx.try_send(MixedMessage::Audio((mic, spk)));
                 }
@@ -196,6 +213,13 @@ impl Actor for ListenerActor {
                     return Ok(());
                 }
 
+                let audio_channel = match &response {
+                    StreamResponse::TranscriptResponse { channel_index, .. } => {
+                        channel_index.first().copied().unwrap_or(0)
+                    }
+                    _ => 0,
+                };
+
                 match state.args.mode {
                     crate::actors::ChannelMode::MicOnly => {
                         response.remap_channel_index(0, 2);
@@ -206,6 +230,26 @@ impl Actor for ListenerActor {
                     crate::actors::ChannelMode::MicAndSpeaker => {}
                 }
 
+                let jobs = state.identifier.observe(&response, audio_channel);
+                if !jobs.is_empty() {
+                    let embedder = state.embedder.clone();
+                    let myself = myself.clone();
+                    tokio::task::spawn_blocking(move || {
+                        for job in jobs {
+                            let (channel, speaker_index) = (job.channel, job.speaker_index);
+                            if let Some(embedding) = embedder.compute(job) {
+                                let _ = myself.cast(ListenerMsg::SpeakerEmbedding(
+                                    channel,
+                                    speaker_index,
+                                    embedding,
+                                ));
+                            }
+                        }
+                    });
+                }
+
+                state.corrector.correct_response(&mut response);
+
                 state
                     .args
                     .runtime
@@ -215,6 +259,29 @@ impl Actor for ListenerActor {
                     });
             }
 
+            ListenerMsg::SpeakerEmbedding(channel, speaker_index, embedding) => {
+                let identified = state
+                    .identifier
+                    .add_embedding(channel, speaker_index, &embedding);
+                for (channel, speaker_index, m) in identified {
+                    state
+                        .args
+                        .runtime
+                        .emit_data(SessionDataEvent::SpeakerIdentified {
+                            session_id: state.args.session_id.clone(),
+                            channel,
+                            speaker_index,
+                            human_id: m.human_id,
+                            similarity: m.similarity,
+                        });
+                }
+            }
+
+            ListenerMsg::LabelSpeaker(channel, speaker_index, human_id, reply) => {
+                let voiceprint = state.identifier.label(channel, speaker_index, human_id);
+                let _ = reply.send(voiceprint);
+            }
+
             ListenerMsg::StreamError(error) => {
                 tracing::info!("listen_stream_error: {}", error);
                 stop_with_degraded_error(&myself, DegradedError::StreamError { message: error });
@@ -259,6 +326,21 @@ impl Actor for ListenerActor {
     }
 }
 
+/// Fixes the user's product names and other terms in final responses
+/// before they reach the transcript.
+fn corrector_chain(vocabulary: &[String]) -> CorrectorChain {
+    let corrector = vocabulary
+        .iter()
+        .fold(VocabularyCorrector::new(), |corrector, entry| {
+            corrector.with_entry(entry)
+        });
+    if corrector.is_empty() {
+        CorrectorChain::new()
+    } else {
+        CorrectorChain::new().with(corrector)
+    }
+}
+
 fn stop_with_degraded_error(myself: &ActorRef<ListenerMsg>, error: DegradedError) {
     let reason = serde_json::to_string(&error).ok();
     myself.stop(reason);
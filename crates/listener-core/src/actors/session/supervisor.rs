@@ -126,6 +126,8 @@ impl Actor for SessionActor {
                     base_url: state.ctx.params.base_url.clone(),
                     api_key: state.ctx.params.api_key.clone(),
                     keywords: state.ctx.params.keywords.clone(),
+                    voiceprints: state.ctx.params.voiceprints.clone(),
+                    vocabulary: state.ctx.params.vocabulary.clone(),
                     mode,
                     session_started_at: state.ctx.started_at_instant,
                     session_started_at_unix: state.ctx.started_at_system,
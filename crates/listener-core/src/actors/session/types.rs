@@ -21,6 +21,15 @@ pub struct SessionParams {
     pub base_url: String,
     pub api_key: String,
     pub keywords: Vec<String>,
+    /// Known voices to recognize, loaded by the host rather than the frontend.
+    #[serde(default, skip_serializing)]
+    #[cfg_attr(feature = "specta", specta(skip))]
+    pub voiceprints: Vec<hypr_pyannote_local::identity::Voiceprint>,
+    /// Spelling fixes from the user's settings, one `aliases => Term` entry
+    /// each, loaded by the host.
+    #[serde(default, skip_serializing)]
+    #[cfg_attr(feature = "specta", specta(skip))]
+    pub vocabulary: Vec<String>,
 }
 
 #[derive(Clone)]
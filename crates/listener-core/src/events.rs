@@ -66,4 +66,12 @@ pub enum SessionDataEvent {
         session_id: String,
         response: Box<StreamResponse>,
     },
+    #[serde(rename = "speaker_identified")]
+    SpeakerIdentified {
+        session_id: String,
+        channel: i32,
+        speaker_index: i32,
+        human_id: String,
+        similarity: f32,
+    },
 }
@@ -45,6 +45,13 @@ pub struct BatchParams {
     pub languages: Vec<hypr_language::Language>,
     #[serde(default)]
     pub keywords: Vec<String>,
+    /// Label speakers in the transcript.
+    #[serde(default = "default_diarize")]
+    pub diarize: bool,
+}
+
+fn default_diarize() -> bool {
+    true
 }
 
 pub async fn run_batch(runtime: Arc<dyn BatchRuntime>, params: BatchParams) -> crate::Result<()> {
@@ -66,6 +73,7 @@ pub async fn run_batch(runtime: Arc<dyn BatchRuntime>, params: BatchParams) -> c
         sample_rate: metadata.sample_rate,
         languages: params.languages.clone(),
         keywords: params.keywords.clone(),
+        diarize: params.diarize,
         custom_query: None,
     };
 
@@ -269,13 +277,15 @@ impl BatchState {
 struct BatchActor;
 
 impl BatchActor {
-    fn name() -> ActorName {
-        "batch_actor".into()
+    // Scoped to the session so several files can be transcribed at once.
+    fn name(session_id: &str) -> ActorName {
+        format!("batch_actor:{session_id}").into()
     }
 }
 
 async fn spawn_batch_actor(args: BatchArgs) -> Result<ActorRef<BatchMsg>, SpawnErr> {
-    let (batch_ref, _) = Actor::spawn(Some(BatchActor::name()), BatchActor, args).await?;
+    let name = BatchActor::name(&args.session_id);
+    let (batch_ref, _) = Actor::spawn(Some(name), BatchActor, args).await?;
     Ok(batch_ref)
 }
 
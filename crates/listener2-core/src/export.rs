@@ -0,0 +1,378 @@
+use std::fmt::Write as _;
+
+use crate::VttWord;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptFormat {
+    Srt,
+    Vtt,
+    Txt,
+}
+
+impl TranscriptFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Vtt => "vtt",
+            Self::Txt => "txt",
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(default)]
+pub struct CueOptions {
+    pub max_line_length: u32,
+    pub max_lines: u32,
+    pub max_cue_duration_ms: u64,
+    pub max_pause_ms: u64,
+}
+
+impl Default for CueOptions {
+    fn default() -> Self {
+        Self {
+            max_line_length: 42,
+            max_lines: 2,
+            max_cue_duration_ms: 6000,
+            max_pause_ms: 1200,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub speaker: Option<String>,
+    pub lines: Vec<String>,
+}
+
+impl Cue {
+    pub fn text(&self) -> String {
+        self.lines.join(" ")
+    }
+}
+
+fn word_text(word: &VttWord) -> Option<&str> {
+    let text = word.text.trim();
+    (!text.is_empty()).then_some(text)
+}
+
+/// `prefix_len` is reserved on the first line for the `Speaker: ` label the
+/// renderers put in front of it.
+fn wrap_lines(words: &[&str], max_line_length: usize, prefix_len: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for word in words {
+        let limit = if lines.is_empty() {
+            max_line_length.saturating_sub(prefix_len)
+        } else {
+            max_line_length
+        };
+        if !current.is_empty() && current.len() + 1 + word.len() > limit {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+fn speaker_prefix_len(speaker: Option<&String>) -> usize {
+    speaker.map_or(0, |speaker| speaker.len() + ": ".len())
+}
+
+/// Groups words into readable cues, starting a new cue on speaker change, on a
+/// pause longer than `max_pause_ms`, or when the cue would exceed its duration
+/// or no longer wrap into `max_lines` lines of `max_line_length` characters.
+pub fn group_words_into_cues(words: &[VttWord], options: &CueOptions) -> Vec<Cue> {
+    let max_line_length = options.max_line_length.max(1) as usize;
+    let max_lines = options.max_lines.max(1) as usize;
+
+    let mut cues = Vec::new();
+    let mut pending: Vec<&str> = Vec::new();
+    let mut start_ms = 0u64;
+    let mut end_ms = 0u64;
+    let mut speaker: Option<&String> = None;
+
+    let mut flush =
+        |pending: &mut Vec<&str>, start_ms: u64, end_ms: u64, speaker: Option<&String>| {
+            if pending.is_empty() {
+                return;
+            }
+            cues.push(Cue {
+                start_ms,
+                end_ms,
+                speaker: speaker.cloned(),
+                lines: wrap_lines(pending, max_line_length, speaker_prefix_len(speaker)),
+            });
+            pending.clear();
+        };
+
+    for word in words {
+        let Some(text) = word_text(word) else {
+            continue;
+        };
+
+        if !pending.is_empty() {
+            let speaker_changed = word.speaker.as_ref() != speaker;
+            let paused = word.start_ms.saturating_sub(end_ms) > options.max_pause_ms;
+            let too_long = word.end_ms.saturating_sub(start_ms) > options.max_cue_duration_ms;
+            let too_wide = {
+                let mut candidate = pending.clone();
+                candidate.push(text);
+                wrap_lines(&candidate, max_line_length, speaker_prefix_len(speaker)).len()
+                    > max_lines
+            };
+
+            if speaker_changed || paused || too_long || too_wide {
+                flush(&mut pending, start_ms, end_ms, speaker);
+            }
+        }
+
+        if pending.is_empty() {
+            start_ms = word.start_ms;
+            end_ms = word.start_ms;
+            speaker = word.speaker.as_ref();
+        }
+
+        pending.push(text);
+        end_ms = end_ms.max(word.end_ms);
+    }
+
+    flush(&mut pending, start_ms, end_ms, speaker);
+    cues
+}
+
+fn format_timestamp(ms: u64, fraction_separator: char) -> String {
+    let hours = ms / 3_600_000;
+    let minutes = (ms / 60_000) % 60;
+    let seconds = (ms / 1000) % 60;
+    let millis = ms % 1000;
+    format!("{hours:02}:{minutes:02}:{seconds:02}{fraction_separator}{millis:03}")
+}
+
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+pub fn render_srt(cues: &[Cue]) -> String {
+    let mut out = String::new();
+
+    for (i, cue) in cues.iter().enumerate() {
+        let _ = writeln!(out, "{}", i + 1);
+        let _ = writeln!(
+            out,
+            "{} --> {}",
+            format_timestamp(cue.start_ms, ','),
+            format_timestamp(cue.end_ms, ',')
+        );
+        for (line_idx, line) in cue.lines.iter().enumerate() {
+            match (&cue.speaker, line_idx) {
+                (Some(speaker), 0) => {
+                    let _ = writeln!(out, "{speaker}: {line}");
+                }
+                _ => {
+                    let _ = writeln!(out, "{line}");
+                }
+            }
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+pub fn render_vtt(cues: &[Cue]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+
+    for cue in cues {
+        let _ = writeln!(
+            out,
+            "{} --> {}",
+            format_timestamp(cue.start_ms, '.'),
+            format_timestamp(cue.end_ms, '.')
+        );
+        for (line_idx, line) in cue.lines.iter().enumerate() {
+            match (&cue.speaker, line_idx) {
+                (Some(speaker), 0) => {
+                    let _ = writeln!(out, "<v {}>{}", escape_vtt(speaker), escape_vtt(line));
+                }
+                _ => {
+                    let _ = writeln!(out, "{}", escape_vtt(line));
+                }
+            }
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Renders one paragraph per speaker turn, e.g. `Alice: Hello there.`
+pub fn render_text(words: &[VttWord]) -> String {
+    let mut paragraphs: Vec<(Option<&String>, Vec<&str>)> = Vec::new();
+
+    for word in words {
+        let Some(text) = word_text(word) else {
+            continue;
+        };
+
+        match paragraphs.last_mut() {
+            Some((speaker, texts)) if *speaker == word.speaker.as_ref() => texts.push(text),
+            _ => paragraphs.push((word.speaker.as_ref(), vec![text])),
+        }
+    }
+
+    paragraphs
+        .into_iter()
+        .map(|(speaker, texts)| match speaker {
+            Some(speaker) => format!("{}: {}", speaker, texts.join(" ")),
+            None => texts.join(" "),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n"
+}
+
+pub fn render_transcript(
+    words: &[VttWord],
+    format: TranscriptFormat,
+    options: &CueOptions,
+) -> String {
+    match format {
+        TranscriptFormat::Srt => render_srt(&group_words_into_cues(words, options)),
+        TranscriptFormat::Vtt => render_vtt(&group_words_into_cues(words, options)),
+        TranscriptFormat::Txt => render_text(words),
+    }
+}
+
+pub fn export_transcript_file<P: AsRef<std::path::Path>>(
+    words: &[VttWord],
+    format: TranscriptFormat,
+    options: &CueOptions,
+    path: P,
+) -> std::result::Result<(), String> {
+    let content = render_transcript(words, format, options);
+    std::fs::write(path.as_ref(), content).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start_ms: u64, end_ms: u64, speaker: Option<&str>) -> VttWord {
+        VttWord {
+            text: text.to_string(),
+            start_ms,
+            end_ms,
+            speaker: speaker.map(|s| s.to_string()),
+        }
+    }
+
+    fn sample() -> Vec<VttWord> {
+        vec![
+            word(" Hello", 0, 400, Some("Alice")),
+            word(" there.", 450, 900, Some("Alice")),
+            word(" Hi", 1000, 1300, Some("Bob")),
+            word(" Alice.", 1350, 1800, Some("Bob")),
+            word(" Later", 5000, 5400, Some("Bob")),
+        ]
+    }
+
+    #[test]
+    fn test_groups_by_speaker_and_pause() {
+        let cues = group_words_into_cues(&sample(), &CueOptions::default());
+
+        assert_eq!(cues.len(), 3);
+        assert_eq!(cues[0].text(), "Hello there.");
+        assert_eq!(cues[0].speaker.as_deref(), Some("Alice"));
+        assert_eq!((cues[0].start_ms, cues[0].end_ms), (0, 900));
+        assert_eq!(cues[1].text(), "Hi Alice.");
+        assert_eq!(cues[2].text(), "Later");
+    }
+
+    #[test]
+    fn test_splits_on_line_length_and_duration() {
+        let words: Vec<_> = (0..20)
+            .map(|i| word("word", i * 300, i * 300 + 250, None))
+            .collect();
+
+        let options = CueOptions {
+            max_line_length: 10,
+            max_lines: 2,
+            max_cue_duration_ms: 60_000,
+            max_pause_ms: 1000,
+        };
+        let cues = group_words_into_cues(&words, &options);
+        assert!(cues.iter().all(|c| c.lines.len() <= 2));
+        assert!(cues.iter().all(|c| c.lines.iter().all(|l| l.len() <= 10)));
+
+        let options = CueOptions {
+            max_cue_duration_ms: 1000,
+            ..CueOptions::default()
+        };
+        let cues = group_words_into_cues(&words, &options);
+        assert!(cues.iter().all(|c| c.end_ms - c.start_ms <= 1000));
+    }
+
+    #[test]
+    fn test_speaker_prefix_fits_line_length() {
+        let words: Vec<_> = ["one", "two", "three", "four", "five", "six"]
+            .iter()
+            .enumerate()
+            .map(|(i, text)| word(text, i as u64 * 300, i as u64 * 300 + 250, Some("Alice")))
+            .collect();
+        let options = CueOptions {
+            max_line_length: 16,
+            ..CueOptions::default()
+        };
+
+        let out = render_transcript(&words, TranscriptFormat::Srt, &options);
+        let text_lines: Vec<_> = out
+            .lines()
+            .filter(|line| {
+                !line.is_empty() && !line.contains("-->") && line.parse::<u32>().is_err()
+            })
+            .collect();
+        assert_eq!(text_lines[0], "Alice: one two");
+        assert!(
+            text_lines.iter().all(|line| line.len() <= 16),
+            "{text_lines:?}"
+        );
+    }
+
+    #[test]
+    fn test_render_srt() {
+        let out = render_transcript(&sample(), TranscriptFormat::Srt, &CueOptions::default());
+        assert!(out.starts_with("1\n00:00:00,000 --> 00:00:00,900\nAlice: Hello there.\n\n2\n"));
+    }
+
+    #[test]
+    fn test_render_vtt_voice_tags() {
+        let words = vec![word("a<b", 61_000, 62_500, Some("Tom & Jerry"))];
+        let out = render_transcript(&words, TranscriptFormat::Vtt, &CueOptions::default());
+        assert_eq!(
+            out,
+            "WEBVTT\n\n00:01:01.000 --> 00:01:02.500\n<v Tom &amp; Jerry>a&lt;b\n\n"
+        );
+    }
+
+    #[test]
+    fn test_render_text() {
+        let out = render_transcript(&sample(), TranscriptFormat::Txt, &CueOptions::default());
+        assert_eq!(out, "Alice: Hello there.\n\nBob: Hi Alice. Later\n");
+    }
+}
@@ -1,12 +1,14 @@
 mod batch;
 mod error;
 mod events;
+mod export;
 mod runtime;
 mod subtitle;
 
 pub use batch::{BatchParams, BatchProvider, run_batch};
 pub use error::*;
 pub use events::*;
+pub use export::*;
 pub use runtime::*;
 pub use subtitle::*;
 
@@ -31,11 +31,16 @@ impl From<TimedSubtitleFile> for Subtitle {
         let tokens = vtt
             .events()
             .iter()
-            .map(|cue| Token {
-                text: cue.text.clone(),
-                start_time: i64::from(cue.start) as u64,
-                end_time: i64::from(cue.end) as u64,
-                speaker: cue.identifier.as_ref().filter(|s| !s.is_empty()).cloned(),
+            .map(|cue| {
+                let (voice, text) = parse_cue_text(&cue.text);
+                Token {
+                    text,
+                    start_time: i64::from(cue.start) as u64,
+                    end_time: i64::from(cue.end) as u64,
+                    speaker: voice
+                        .or_else(|| cue.identifier.clone())
+                        .filter(|s| !s.is_empty()),
+                }
             })
             .collect();
 
@@ -43,6 +48,42 @@ impl From<TimedSubtitleFile> for Subtitle {
     }
 }
 
+/// Splits `<v Speaker>` voice spans (as written by [`crate::render_vtt`])
+/// out of a cue's text, returning the first speaker and the plain text with
+/// wrapped lines joined back together.
+fn parse_cue_text(text: &str) -> (Option<String>, String) {
+    let mut speaker = None;
+    let mut lines = Vec::new();
+
+    for line in text.lines() {
+        let mut line = line.trim();
+        if let Some(rest) = line.strip_prefix("<v")
+            && let Some((tag, body)) = rest.split_once('>')
+            && (tag.is_empty() || tag.starts_with([' ', '.']))
+        {
+            // `<v.class Name>`: the annotation follows the first space.
+            let name = tag.split_once(' ').map(|(_, name)| name.trim());
+            if speaker.is_none() {
+                speaker = name.filter(|n| !n.is_empty()).map(unescape_vtt);
+            }
+            line = body;
+        }
+        let line = line.trim_end_matches("</v>").trim();
+        if !line.is_empty() {
+            lines.push(unescape_vtt(line));
+        }
+    }
+
+    (speaker, lines.join(" "))
+}
+
+fn unescape_vtt(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
 pub fn parse_subtitle_from_path<P: AsRef<std::path::Path>>(
     path: P,
 ) -> std::result::Result<Subtitle, String> {
@@ -54,27 +95,74 @@ pub fn export_words_to_vtt_file<P: AsRef<std::path::Path>>(
     words: Vec<VttWord>,
     path: P,
 ) -> std::result::Result<(), String> {
-    use aspasia::{Moment, webvtt::WebVttCue};
-
-    let cues: Vec<WebVttCue> = words
-        .into_iter()
-        .map(|word| {
-            let start_i64 = i64::try_from(word.start_ms)
-                .map_err(|_| format!("start_ms {} exceeds i64::MAX", word.start_ms))?;
-            let end_i64 = i64::try_from(word.end_ms)
-                .map_err(|_| format!("end_ms {} exceeds i64::MAX", word.end_ms))?;
-
-            Ok(WebVttCue {
-                identifier: word.speaker,
-                text: word.text,
-                settings: None,
-                start: Moment::from(start_i64),
-                end: Moment::from(end_i64),
-            })
-        })
-        .collect::<Result<_, String>>()?;
+    crate::export_transcript_file(
+        &words,
+        crate::TranscriptFormat::Vtt,
+        &crate::CueOptions::default(),
+        path,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let vtt = WebVttSubtitle::builder().cues(cues).build();
-    vtt.export(path.as_ref()).map_err(|e| e.to_string())?;
-    Ok(())
+    fn word(text: &str, start_ms: u64, end_ms: u64, speaker: Option<&str>) -> VttWord {
+        VttWord {
+            text: text.to_string(),
+            start_ms,
+            end_ms,
+            speaker: speaker.map(|s| s.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_cue_text() {
+        assert_eq!(
+            parse_cue_text("<v Tom &amp; Jerry>a&lt;b\nsecond line"),
+            (
+                Some("Tom & Jerry".to_string()),
+                "a<b second line".to_string()
+            )
+        );
+        assert_eq!(
+            parse_cue_text("<v.loud Bob>Hey</v>"),
+            (Some("Bob".to_string()), "Hey".to_string())
+        );
+        assert_eq!(parse_cue_text("no voice"), (None, "no voice".to_string()));
+    }
+
+    #[test]
+    fn test_vtt_round_trip() {
+        let words = vec![
+            word(" Hello", 0, 400, Some("Alice")),
+            word(" there.", 450, 900, Some("Alice")),
+            word(" Hi", 1000, 1300, Some("Tom & Jerry")),
+            word(" <you>.", 1350, 1800, Some("Tom & Jerry")),
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("transcript.vtt");
+        export_words_to_vtt_file(words, &path).unwrap();
+
+        let subtitle = parse_subtitle_from_path(&path).unwrap();
+        let cues: Vec<_> = subtitle
+            .tokens
+            .iter()
+            .map(|t| {
+                (
+                    t.speaker.as_deref(),
+                    t.text.as_str(),
+                    t.start_time,
+                    t.end_time,
+                )
+            })
+            .collect();
+        assert_eq!(
+            cues,
+            vec![
+                (Some("Alice"), "Hello there.", 0, 900),
+                (Some("Tom & Jerry"), "Hi <you>.", 1000, 1800),
+            ]
+        );
+    }
 }
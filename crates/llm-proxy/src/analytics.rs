@@ -1,9 +1,12 @@
 use hypr_analytics::{AnalyticsClient, AnalyticsPayload};
 
+use crate::model::CharTask;
+
 #[derive(Debug, Clone)]
 pub struct GenerationEvent {
     pub fingerprint: Option<String>,
     pub user_id: Option<String>,
+    pub task: Option<CharTask>,
     pub generation_id: String,
     pub model: String,
     pub input_tokens: u32,
@@ -44,6 +47,12 @@ impl AnalyticsReporter for AnalyticsClient {
                 payload
             };
 
+            let payload = if let Some(task) = event.task {
+                payload.with("char_task", task.to_string())
+            } else {
+                payload
+            };
+
             let payload = if let Some(user_id) = &event.user_id {
                 payload.with("user_id", user_id.clone())
             } else {
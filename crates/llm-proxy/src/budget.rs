@@ -0,0 +1,9 @@
+/// Tells the proxy how much a user has left to spend, so routing can move
+/// them to cheaper models before they run out.
+pub trait BudgetSource: Send + Sync {
+    /// Remaining USD in the user's current period, or `None` if unlimited.
+    fn remaining_usd<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Option<f64>> + Send + 'a>>;
+}
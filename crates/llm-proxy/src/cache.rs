@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{StatusCode, header},
+    response::Response,
+};
+use bytes::Bytes;
+use sha2::{Digest, Sha256};
+use tokio::sync::watch;
+
+use crate::CACHE_STATUS_HEADER;
+use crate::env::CacheEnv;
+use crate::model::CharTask;
+use crate::types::ChatCompletionRequest;
+
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+pub const DEFAULT_CACHE_MAX_BYTES: u64 = 64 * 1024 * 1024;
+pub const DEFAULT_CACHE_MAX_ENTRY_BYTES: usize = 1024 * 1024;
+
+/// Request fields that change how a response is delivered but not what it
+/// says.
+const IGNORED_FIELDS: &[&str] = &["stream", "stream_options", "user"];
+
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    pub ttl: Duration,
+    /// Upper bound on the total size of cached bodies.
+    pub max_bytes: u64,
+    /// Larger responses are still shared with coalesced requests, but not
+    /// kept.
+    pub max_entry_bytes: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: DEFAULT_CACHE_TTL,
+            max_bytes: DEFAULT_CACHE_MAX_BYTES,
+            max_entry_bytes: DEFAULT_CACHE_MAX_ENTRY_BYTES,
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    pub fn with_max_entry_bytes(mut self, max_entry_bytes: usize) -> Self {
+        self.max_entry_bytes = max_entry_bytes;
+        self
+    }
+}
+
+impl CacheEnv {
+    pub fn cache_config(&self) -> Option<CacheConfig> {
+        let ttl = Duration::from_secs(self.llm_cache_ttl_secs?);
+        let config = CacheConfig::default().with_ttl(ttl);
+        Some(match self.llm_cache_max_bytes {
+            Some(max_bytes) => config.with_max_bytes(max_bytes),
+            None => config,
+        })
+    }
+}
+
+/// Identifies requests that should get the same answer: same user, task and
+/// request body once delivery-only fields are dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey([u8; 32]);
+
+impl CacheKey {
+    pub(crate) fn new(
+        request: &ChatCompletionRequest,
+        task: Option<CharTask>,
+        user_id: Option<&str>,
+    ) -> Self {
+        let mut body = serde_json::to_value(request).unwrap_or_default();
+        if let Some(fields) = body.as_object_mut() {
+            for field in IGNORED_FIELDS {
+                fields.remove(*field);
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(user_id.unwrap_or_default());
+        hasher.update([0]);
+        hasher.update(task.map(|t| t.to_string()).unwrap_or_default());
+        hasher.update([0]);
+        hasher.update(body.to_string());
+        Self(hasher.finalize().into())
+    }
+}
+
+/// A successful upstream response, already in OpenAI format.
+#[derive(Debug, Clone)]
+pub(crate) enum CachedBody {
+    Json(Bytes),
+    Sse(Bytes),
+}
+
+impl CachedBody {
+    fn len(&self) -> usize {
+        match self {
+            Self::Json(b) | Self::Sse(b) => b.len(),
+        }
+    }
+
+    /// Streams can be replayed from either form; a plain JSON response can't
+    /// be rebuilt from a stream.
+    pub(crate) fn into_response(self, stream: bool) -> Option<Response> {
+        let (content_type, body) = match (self, stream) {
+            (Self::Json(body), false) => ("application/json", body),
+            (Self::Json(body), true) => ("text/event-stream", completion_to_sse(&body)?),
+            (Self::Sse(body), true) => ("text/event-stream", body),
+            (Self::Sse(_), false) => return None,
+        };
+
+        let mut builder = Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, content_type)
+            .header(CACHE_STATUS_HEADER, "hit");
+        if stream {
+            builder = builder.header(header::CACHE_CONTROL, "no-cache");
+        }
+        Some(builder.body(Body::from(body)).unwrap())
+    }
+}
+
+pub(crate) enum Lookup {
+    Hit(CachedBody),
+    /// Forward the request. With a [`CacheFill`], this request is the one
+    /// identical requests are waiting on.
+    Miss(Option<CacheFill>),
+}
+
+pub(crate) struct ResponseCache {
+    entries: moka::future::Cache<CacheKey, CachedBody>,
+    inflight: Mutex<HashMap<CacheKey, watch::Receiver<Option<CachedBody>>>>,
+    max_entry_bytes: usize,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(config: &CacheConfig) -> Self {
+        let entries = moka::future::Cache::builder()
+            .max_capacity(config.max_bytes)
+            .weigher(|_, body: &CachedBody| u32::try_from(body.len()).unwrap_or(u32::MAX))
+            .time_to_live(config.ttl)
+            .build();
+
+        Self {
+            entries,
+            inflight: Mutex::new(HashMap::new()),
+            max_entry_bytes: config.max_entry_bytes,
+        }
+    }
+
+    /// Waits for an identical request that is already in flight rather than
+    /// sending a second one upstream.
+    pub(crate) async fn lookup(self: &Arc<Self>, key: CacheKey) -> Lookup {
+        if let Some(body) = self.entries.get(&key).await {
+            return Lookup::Hit(body);
+        }
+
+        let mut rx = {
+            let mut inflight = self.inflight.lock().unwrap();
+            match inflight.get(&key) {
+                Some(rx) => rx.clone(),
+                None => {
+                    let (tx, rx) = watch::channel(None);
+                    inflight.insert(key, rx);
+                    return Lookup::Miss(Some(CacheFill {
+                        cache: self.clone(),
+                        key,
+                        tx,
+                    }));
+                }
+            }
+        };
+
+        match rx.wait_for(Option::is_some).await {
+            Ok(body) => Lookup::Hit(body.clone().expect("waited for a body")),
+            // The first request failed or was cancelled.
+            Err(_) => Lookup::Miss(None),
+        }
+    }
+}
+
+/// Held by the request that fills a cache entry. Dropping it without
+/// completing lets waiting requests go upstream themselves.
+pub(crate) struct CacheFill {
+    cache: Arc<ResponseCache>,
+    key: CacheKey,
+    tx: watch::Sender<Option<CachedBody>>,
+}
+
+impl CacheFill {
+    pub(crate) fn max_entry_bytes(&self) -> usize {
+        self.cache.max_entry_bytes
+    }
+
+    pub(crate) async fn complete(self, body: CachedBody) {
+        if body.len() <= self.cache.max_entry_bytes {
+            self.cache.entries.insert(self.key, body.clone()).await;
+        }
+        let _ = self.tx.send(Some(body));
+    }
+}
+
+impl Drop for CacheFill {
+    fn drop(&mut self) {
+        self.cache.inflight.lock().unwrap().remove(&self.key);
+    }
+}
+
+/// Replays a chat completion as the chunks a streaming request would have
+/// received.
+fn completion_to_sse(body: &[u8]) -> Option<Bytes> {
+    let completion: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let choices = completion.get("choices")?.as_array()?;
+
+    let chunk = |choices: serde_json::Value, usage: Option<&serde_json::Value>| {
+        let mut chunk = serde_json::json!({
+            "id": completion.get("id"),
+            "object": "chat.completion.chunk",
+            "created": completion.get("created"),
+            "model": completion.get("model"),
+            "choices": choices,
+        });
+        if let Some(usage) = usage {
+            chunk["usage"] = usage.clone();
+        }
+        format!("data: {chunk}\n\n")
+    };
+
+    let mut out = String::new();
+    for (position, choice) in choices.iter().enumerate() {
+        let index = choice.get("index").cloned().unwrap_or(position.into());
+        let message = choice.get("message").cloned().unwrap_or_default();
+
+        let mut delta = serde_json::json!({"role": "assistant"});
+        if let Some(content) = message.get("content").filter(|c| !c.is_null()) {
+            delta["content"] = content.clone();
+        }
+        if let Some(calls) = message.get("tool_calls").and_then(|c| c.as_array()) {
+            let calls: Vec<serde_json::Value> = calls
+                .iter()
+                .enumerate()
+                .map(|(i, call)| {
+                    let mut call = call.clone();
+                    call["index"] = i.into();
+                    call
+                })
+                .collect();
+            delta["tool_calls"] = calls.into();
+        }
+
+        out.push_str(&chunk(
+            serde_json::json!([{"index": index, "delta": delta, "finish_reason": null}]),
+            None,
+        ));
+        let is_last = position + 1 == choices.len();
+        out.push_str(&chunk(
+            serde_json::json!([{
+                "index": index,
+                "delta": {},
+                "finish_reason": choice.get("finish_reason"),
+            }]),
+            completion.get("usage").filter(|_| is_last),
+        ));
+    }
+    out.push_str("data: [DONE]\n\n");
+
+    Some(Bytes::from(out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(json: serde_json::Value) -> ChatCompletionRequest {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn key_ignores_delivery_fields() {
+        let a = request(serde_json::json!({
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 0.2
+        }));
+        let b = request(serde_json::json!({
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 0.2,
+            "stream": true,
+            "stream_options": {"include_usage": true}
+        }));
+        let c = request(serde_json::json!({
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 0.7
+        }));
+
+        let key = |r, task, user| CacheKey::new(r, task, user);
+        assert_eq!(key(&a, None, Some("u")), key(&b, None, Some("u")));
+        assert_ne!(key(&a, None, Some("u")), key(&c, None, Some("u")));
+        assert_ne!(key(&a, None, Some("u")), key(&a, None, Some("v")));
+        assert_ne!(
+            key(&a, None, Some("u")),
+            key(&a, Some(CharTask::Title), Some("u"))
+        );
+    }
+
+    #[test]
+    fn replays_completion_as_sse() {
+        let body = serde_json::json!({
+            "id": "gen-1",
+            "model": "openai/gpt-4.1-nano",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "hello"},
+                "finish_reason": "stop"
+            }],
+            "usage": {"prompt_tokens": 10, "completion_tokens": 1}
+        });
+        let sse = completion_to_sse(body.to_string().as_bytes()).unwrap();
+        let chunks: Vec<serde_json::Value> = std::str::from_utf8(&sse)
+            .unwrap()
+            .split("\n\n")
+            .filter_map(|event| event.strip_prefix("data: "))
+            .filter(|data| *data != "[DONE]")
+            .map(|data| serde_json::from_str(data).unwrap())
+            .collect();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0]["choices"][0]["delta"]["content"], "hello");
+        assert_eq!(chunks[1]["choices"][0]["finish_reason"], "stop");
+        assert_eq!(chunks[1]["usage"]["prompt_tokens"], 10);
+        assert!(sse.ends_with(b"data: [DONE]\n\n"));
+    }
+
+    #[tokio::test]
+    async fn coalesces_inflight_requests() {
+        let cache = Arc::new(ResponseCache::new(&CacheConfig::default()));
+        let key = CacheKey::new(&request(serde_json::json!({"messages": []})), None, None);
+
+        let Lookup::Miss(Some(fill)) = cache.lookup(key).await else {
+            panic!("first lookup should fill");
+        };
+        let waiter = tokio::spawn({
+            let cache = cache.clone();
+            async move { cache.lookup(key).await }
+        });
+        tokio::task::yield_now().await;
+
+        fill.complete(CachedBody::Json(Bytes::from_static(b"{}")))
+            .await;
+        assert!(matches!(
+            waiter.await.unwrap(),
+            Lookup::Hit(CachedBody::Json(_))
+        ));
+        assert!(matches!(cache.lookup(key).await, Lookup::Hit(_)));
+    }
+
+    #[tokio::test]
+    async fn failed_fill_releases_waiters() {
+        let cache = Arc::new(ResponseCache::new(&CacheConfig::default()));
+        let key = CacheKey::new(&request(serde_json::json!({"messages": []})), None, None);
+
+        let Lookup::Miss(Some(fill)) = cache.lookup(key).await else {
+            panic!("first lookup should fill");
+        };
+        let waiter = tokio::spawn({
+            let cache = cache.clone();
+            async move { cache.lookup(key).await }
+        });
+        tokio::task::yield_now().await;
+
+        drop(fill);
+        assert!(matches!(waiter.await.unwrap(), Lookup::Miss(None)));
+        assert!(matches!(cache.lookup(key).await, Lookup::Miss(Some(_))));
+    }
+}
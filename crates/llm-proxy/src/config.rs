@@ -1,10 +1,14 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use crate::analytics::AnalyticsReporter;
+use crate::budget::BudgetSource;
+use crate::cache::{CacheConfig, ResponseCache};
 use crate::env::ApiKey;
-use crate::model::{ModelContext, ModelResolver, StaticModelResolver};
+use crate::model::{ModelContext, ModelResolver, Route, StaticModelResolver};
 use crate::provider::{OpenRouterProvider, Provider};
+use crate::usage::UsageConfig;
 
 const DEFAULT_TIMEOUT_MS: u64 = 120_000;
 const DEFAULT_NUM_RETRIES: usize = 1;
@@ -25,6 +29,13 @@ impl Default for RetryConfig {
     }
 }
 
+/// A provider together with the key used to call it.
+#[derive(Clone)]
+pub struct Upstream {
+    pub provider: Arc<dyn Provider>,
+    pub api_key: String,
+}
+
 #[derive(Clone)]
 pub struct LlmProxyConfig {
     pub api_key: String,
@@ -32,6 +43,10 @@ pub struct LlmProxyConfig {
     resolver: Arc<dyn ModelResolver>,
     pub analytics: Option<Arc<dyn AnalyticsReporter>>,
     pub provider: Arc<dyn Provider>,
+    providers: HashMap<String, Upstream>,
+    pub budget: Option<Arc<dyn BudgetSource>>,
+    pub usage: Option<Arc<UsageConfig>>,
+    pub(crate) cache: Option<Arc<ResponseCache>>,
     pub retry_config: RetryConfig,
 }
 
@@ -43,6 +58,10 @@ impl LlmProxyConfig {
             resolver: Arc::new(StaticModelResolver::default()),
             analytics: None,
             provider: Arc::new(OpenRouterProvider::default()),
+            providers: HashMap::new(),
+            budget: None,
+            usage: None,
+            cache: None,
             retry_config: RetryConfig::default(),
         }
     }
@@ -51,6 +70,25 @@ impl LlmProxyConfig {
         self.resolver.resolve(ctx)
     }
 
+    pub fn route(&self, ctx: &ModelContext) -> Vec<Route> {
+        self.resolver.route(ctx)
+    }
+
+    /// The provider a route names, or the primary one for `None`.
+    pub fn upstream(&self, name: Option<&str>) -> Option<Upstream> {
+        match name {
+            Some(name) if name != self.provider.name() => self.providers.get(name).cloned(),
+            _ => Some(Upstream {
+                provider: self.provider.clone(),
+                api_key: self.api_key.clone(),
+            }),
+        }
+    }
+
+    pub(crate) fn reports_generations(&self) -> bool {
+        self.analytics.is_some() || self.usage.is_some()
+    }
+
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
@@ -71,6 +109,41 @@ impl LlmProxyConfig {
         self
     }
 
+    /// Registers another provider for routes to name, under
+    /// [`Provider::name`].
+    pub fn with_additional_provider(
+        mut self,
+        provider: Arc<dyn Provider>,
+        api_key: impl Into<ApiKey>,
+    ) -> Self {
+        self.providers.insert(
+            provider.name().to_string(),
+            Upstream {
+                provider,
+                api_key: api_key.into().0,
+            },
+        );
+        self
+    }
+
+    pub fn with_budget_source(mut self, budget: Arc<dyn BudgetSource>) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Records per-user usage and rejects requests over quota with a 429.
+    pub fn with_usage(mut self, usage: UsageConfig) -> Self {
+        self.usage = Some(Arc::new(usage));
+        self
+    }
+
+    /// Serves repeated identical requests from memory and coalesces
+    /// identical requests that are in flight at the same time.
+    pub fn with_cache(mut self, cache: CacheConfig) -> Self {
+        self.cache = Some(Arc::new(ResponseCache::new(&cache)));
+        self
+    }
+
     pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
         self.retry_config = retry_config;
         self
@@ -1,5 +1,33 @@
+use serde::Deserialize;
+
+use crate::model::CharTask;
+
 pub use hypr_api_env::OpenRouterEnv as Env;
 
+/// Org-wide per-user LLM quotas. Unset limits don't apply.
+#[derive(Clone, Default, Deserialize)]
+pub struct UsageEnv {
+    #[serde(default, deserialize_with = "hypr_api_env::optional_from_str")]
+    pub llm_daily_token_limit: Option<u64>,
+    #[serde(default, deserialize_with = "hypr_api_env::optional_from_str")]
+    pub llm_daily_cost_limit_usd: Option<f64>,
+    #[serde(default, deserialize_with = "hypr_api_env::optional_from_str")]
+    pub llm_monthly_token_limit: Option<u64>,
+    #[serde(default, deserialize_with = "hypr_api_env::optional_from_str")]
+    pub llm_monthly_cost_limit_usd: Option<f64>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub llm_usage_admin_token: Option<String>,
+}
+
+/// Response caching is off unless `LLM_CACHE_TTL_SECS` is set.
+#[derive(Clone, Default, Deserialize)]
+pub struct CacheEnv {
+    #[serde(default, deserialize_with = "hypr_api_env::optional_from_str")]
+    pub llm_cache_ttl_secs: Option<u64>,
+    #[serde(default, deserialize_with = "hypr_api_env::optional_from_str")]
+    pub llm_cache_max_bytes: Option<u64>,
+}
+
 pub struct ApiKey(pub String);
 
 impl From<&Env> for ApiKey {
@@ -19,3 +47,64 @@ impl From<String> for ApiKey {
         Self(s)
     }
 }
+
+/// Extra upstreams and model routing for self-hosted deployments. Without
+/// `LLM_ROUTES` requests keep going to OpenRouter with the built-in models.
+#[derive(Clone, Default, Deserialize)]
+pub struct RoutingEnv {
+    /// Chat completions URL of an OpenAI-compatible server, such as vLLM or
+    /// Ollama, registered under `LLM_OPENAI_COMPATIBLE_NAME`.
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub llm_openai_compatible_url: Option<String>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub llm_openai_compatible_name: Option<String>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub llm_openai_compatible_api_key: Option<String>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub llm_anthropic_api_key: Option<String>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub llm_anthropic_url: Option<String>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub llm_bedrock_api_key: Option<String>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub llm_bedrock_region: Option<String>,
+    /// JSON array of [`crate::ModelCandidate`]s in preference order.
+    #[serde(default, deserialize_with = "from_json")]
+    pub llm_routes: Vec<crate::ModelCandidate>,
+    /// Comma-separated `task=provider` pairs, e.g. `enhance=self-hosted`.
+    /// `primary` pins a task to OpenRouter.
+    #[serde(default, deserialize_with = "task_providers")]
+    pub llm_pinned_tasks: Vec<(CharTask, String)>,
+}
+
+fn from_json<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    match hypr_api_env::filter_empty(deserializer)? {
+        Some(s) => serde_json::from_str(&s).map_err(serde::de::Error::custom),
+        None => Ok(vec![]),
+    }
+}
+
+fn task_providers<'de, D>(deserializer: D) -> Result<Vec<(CharTask, String)>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let Some(s) = hypr_api_env::filter_empty(deserializer)? else {
+        return Ok(vec![]);
+    };
+
+    s.split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (task, provider) = pair.split_once('=').ok_or_else(|| {
+                serde::de::Error::custom(format!("expected task=provider, got {pair:?}"))
+            })?;
+            let task = task.trim().parse().map_err(serde::de::Error::custom)?;
+            Ok((task, provider.trim().to_string()))
+        })
+        .collect()
+}
@@ -11,20 +11,23 @@ use std::time::{Duration, Instant};
 use axum::{
     Json, Router,
     extract::{FromRequestParts, State},
-    http::{StatusCode, request::Parts},
+    http::{HeaderMap, HeaderValue, StatusCode, header, request::Parts},
     response::{IntoResponse, Response},
     routing::post,
 };
 use backon::{ExponentialBuilder, Retryable};
 use reqwest::Client;
 
-use crate::analytics::{AnalyticsReporter, GenerationEvent};
-use crate::config::LlmProxyConfig;
-use crate::model::{CharTask, ModelContext};
-use crate::types::{ChatCompletionRequest, ToolChoice, has_audio_content};
+use chrono::Utc;
+
+use crate::analytics::GenerationEvent;
+use crate::cache::{CacheKey, Lookup};
+use crate::config::{LlmProxyConfig, Upstream};
+use crate::model::{CharTask, ModelContext, Route};
+use crate::types::{ChatCompletionRequest, ToolChoice, estimate_input_tokens, has_audio_content};
 
 async fn report_with_cost(
-    analytics: &dyn AnalyticsReporter,
+    config: &LlmProxyConfig,
     provider: &dyn crate::provider::Provider,
     client: &Client,
     api_key: &str,
@@ -33,19 +36,24 @@ async fn report_with_cost(
     event.total_cost = provider
         .fetch_cost(client, api_key, &event.generation_id)
         .await;
-    analytics.report_generation(event).await;
+    if let Some(usage) = &config.usage {
+        usage.record(&event).await;
+    }
+    if let Some(analytics) = &config.analytics {
+        analytics.report_generation(event).await;
+    }
 }
 
-pub(super) fn spawn_analytics_report(
-    analytics: Option<Arc<dyn AnalyticsReporter>>,
+pub(super) fn spawn_generation_report(
+    config: LlmProxyConfig,
     provider: Arc<dyn crate::provider::Provider>,
     client: Client,
     api_key: String,
     event: GenerationEvent,
 ) {
-    if let Some(analytics) = analytics {
+    if config.reports_generations() {
         tokio::spawn(async move {
-            report_with_cost(&*analytics, &*provider, &client, &api_key, event).await;
+            report_with_cost(&config, &*provider, &client, &api_key, event).await;
         });
     }
 }
@@ -54,10 +62,16 @@ fn is_retryable_error(error: &reqwest::Error) -> bool {
     error.is_timeout() || error.is_connect()
 }
 
+#[derive(Debug, thiserror::Error)]
 enum ProxyError {
+    #[error("upstream request failed: {0}")]
     UpstreamRequest(reqwest::Error),
+    #[error("upstream request timed out")]
     Timeout,
+    #[error("failed to read response: {0}")]
     BodyRead(reqwest::Error),
+    #[error("failed to translate response: {0}")]
+    Translate(crate::provider::ProviderError),
 }
 
 impl IntoResponse for ProxyError {
@@ -105,6 +119,16 @@ impl IntoResponse for ProxyError {
                     "Failed to read response".to_string(),
                 )
             }
+            Self::Translate(e) => {
+                tracing::error!(error = %e, "response_translation_failed");
+                sentry::configure_scope(|scope| {
+                    scope.set_tag("upstream.status", "translation_failed");
+                });
+                (
+                    StatusCode::BAD_GATEWAY,
+                    "Failed to read response".to_string(),
+                )
+            }
         };
         (status, message).into_response()
     }
@@ -144,6 +168,7 @@ use hypr_analytics::{AuthenticatedUserId, DeviceFingerprint};
 pub struct AnalyticsContext {
     pub fingerprint: Option<String>,
     pub user_id: Option<String>,
+    pub task: Option<CharTask>,
 }
 
 impl<S> FromRequestParts<S> for AnalyticsContext
@@ -161,9 +186,15 @@ where
             .extensions
             .get::<AuthenticatedUserId>()
             .map(|id| id.0.clone());
+        let task = parts
+            .headers
+            .get(crate::CHAR_TASK_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<CharTask>().ok());
         Ok(AnalyticsContext {
             fingerprint,
             user_id,
+            task,
         })
     }
 }
@@ -171,46 +202,71 @@ where
 async fn completions_handler(
     State(state): State<AppState>,
     analytics_ctx: AnalyticsContext,
-    headers: axum::http::HeaderMap,
+    headers: HeaderMap,
     Json(request): Json<ChatCompletionRequest>,
 ) -> Response {
     let start_time = Instant::now();
-
-    let task = headers
-        .get(crate::CHAR_TASK_HEADER)
-        .and_then(|v| v.to_str().ok())
-        .and_then(|s| s.parse::<CharTask>().ok());
+    let task = analytics_ctx.task;
+    let stream = request.stream.unwrap_or(false);
 
     let needs_tool_calling = request.tools.as_ref().is_some_and(|t| !t.is_empty())
         && !matches!(&request.tool_choice, Some(ToolChoice::String(s)) if s == "none");
     let has_audio = has_audio_content(&request.messages);
 
+    let mut remaining_budget_usd = None;
+    if let Some(user_id) = &analytics_ctx.user_id {
+        if let Some(usage) = &state.config.usage {
+            match usage.check(user_id, Utc::now()).await {
+                Ok(remaining) => remaining_budget_usd = remaining,
+                Err(exceeded) => return exceeded.into_response(),
+            }
+        }
+        if let Some(budget) = &state.config.budget
+            && let Some(remaining) = budget.remaining_usd(user_id).await
+        {
+            remaining_budget_usd =
+                Some(remaining_budget_usd.map_or(remaining, |r: f64| r.min(remaining)));
+        }
+    }
+
+    let cache = state
+        .config
+        .cache
+        .clone()
+        .filter(|_| !skips_cache(&headers));
+    let mut fill = None;
+    if let Some(cache) = &cache {
+        let key = CacheKey::new(&request, task, analytics_ctx.user_id.as_deref());
+        match cache.lookup(key).await {
+            Lookup::Hit(body) => {
+                if let Some(response) = body.into_response(stream) {
+                    tracing::info!(stream = %stream, task = ?task, "llm_cache_hit");
+                    return response;
+                }
+            }
+            Lookup::Miss(f) => fill = f,
+        }
+    }
+
     let ctx = ModelContext {
         task,
         needs_tool_calling,
         has_audio,
+        input_tokens: estimate_input_tokens(&request),
+        remaining_budget_usd,
     };
-    let models = state.config.resolve(&ctx);
-
-    let stream = request.stream.unwrap_or(false);
+    let attempts = plan_attempts(&state.config, state.config.route(&ctx));
 
     tracing::info!(
         stream = %stream,
         has_tools = %needs_tool_calling,
         task = ?task,
         message_count = %request.messages.len(),
-        model_count = %models.len(),
-        provider = %state.config.provider.name(),
+        attempt_count = %attempts.len(),
         "llm_completion_request_received"
     );
 
-    let provider = &state.config.provider;
-
     sentry::configure_scope(|scope| {
-        scope.set_tag("llm.provider", provider.name());
-        if let Some(model) = models.first() {
-            scope.set_tag("llm.model", model);
-        }
         scope.set_tag("llm.stream", stream.to_string());
         scope.set_tag("llm.tool_calling", needs_tool_calling.to_string());
         if let Some(t) = &task {
@@ -218,7 +274,7 @@ async fn completions_handler(
         }
 
         let mut ctx = BTreeMap::new();
-        ctx.insert("model_count".into(), models.len().into());
+        ctx.insert("attempt_count".into(), attempts.len().into());
         ctx.insert("message_count".into(), request.messages.len().into());
         ctx.insert("has_tools".into(), needs_tool_calling.into());
         if let Some(t) = &task {
@@ -227,14 +283,146 @@ async fn completions_handler(
         scope.set_context("llm_request", sentry::protocol::Context::Other(ctx));
     });
 
-    let provider_request = match provider.build_request(&request, models, stream) {
-        Ok(req) => req,
-        Err(e) => {
-            tracing::error!(error = %e, "failed_to_build_provider_request");
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Invalid request").into_response();
+    if attempts.is_empty() {
+        tracing::error!(task = ?task, "no_llm_route_available");
+        return (StatusCode::SERVICE_UNAVAILABLE, "No model available").into_response();
+    }
+
+    let last = attempts.len() - 1;
+    for (index, attempt) in attempts.into_iter().enumerate() {
+        let provider = &attempt.upstream.provider;
+
+        sentry::configure_scope(|scope| {
+            scope.set_tag("llm.provider", provider.name());
+            if let Some(model) = attempt.models.first() {
+                scope.set_tag("llm.model", model);
+            }
+        });
+
+        let model = attempt.models.first().cloned().unwrap_or_default();
+        let url = provider.endpoint(&attempt.models, stream);
+        let provider_request = match provider.build_request(&request, attempt.models, stream) {
+            Ok(req) => req,
+            Err(e) if index < last => {
+                tracing::warn!(error = %e, provider = %provider.name(), "skipping_llm_provider");
+                continue;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "failed_to_build_provider_request");
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Invalid request").into_response();
+            }
+        };
+
+        let result = send_request(&state, &attempt.upstream, &url, &provider_request).await;
+
+        let response = match result {
+            Ok(resp) if index < last && should_fall_back(resp.status()) => {
+                tracing::warn!(
+                    upstream_status = %resp.status().as_u16(),
+                    provider = %provider.name(),
+                    model = %model,
+                    "falling_back_to_next_llm_provider"
+                );
+                continue;
+            }
+            Ok(resp) => resp,
+            Err(e) if index < last => {
+                tracing::warn!(
+                    error = %e,
+                    provider = %provider.name(),
+                    model = %model,
+                    "falling_back_to_next_llm_provider"
+                );
+                continue;
+            }
+            Err(e) => return e.into_response(),
+        };
+
+        let mut response = if stream {
+            handle_stream_response(
+                state,
+                attempt.upstream,
+                model,
+                response,
+                start_time,
+                analytics_ctx,
+                fill,
+            )
+            .await
+        } else {
+            handle_non_stream_response(
+                state,
+                attempt.upstream,
+                model,
+                response,
+                start_time,
+                analytics_ctx,
+                fill,
+            )
+            .await
+        };
+        if cache.is_some() {
+            response
+                .headers_mut()
+                .insert(crate::CACHE_STATUS_HEADER, HeaderValue::from_static("miss"));
         }
-    };
+        return response;
+    }
+
+    unreachable!("the last attempt always returns")
+}
 
+/// Clients can ask for a fresh answer with `Cache-Control: no-cache`.
+fn skips_cache(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::CACHE_CONTROL)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|directive| matches!(directive.trim(), "no-cache" | "no-store"))
+}
+
+struct Attempt {
+    upstream: Upstream,
+    models: Vec<String>,
+}
+
+/// One attempt per route, or per model when the provider can't fall back
+/// between models itself.
+fn plan_attempts(config: &LlmProxyConfig, routes: Vec<Route>) -> Vec<Attempt> {
+    let mut attempts = Vec::new();
+    for route in routes {
+        let Some(upstream) = config.upstream(route.provider.as_deref()) else {
+            tracing::warn!(provider = ?route.provider, "unknown_llm_provider");
+            continue;
+        };
+
+        if upstream.provider.handles_model_fallback() || route.models.len() <= 1 {
+            attempts.push(Attempt {
+                upstream,
+                models: route.models,
+            });
+        } else {
+            attempts.extend(route.models.into_iter().map(|model| Attempt {
+                upstream: upstream.clone(),
+                models: vec![model],
+            }));
+        }
+    }
+    attempts
+}
+
+fn should_fall_back(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+async fn send_request(
+    state: &AppState,
+    upstream: &Upstream,
+    url: &str,
+    body: &serde_json::Value,
+) -> Result<reqwest::Response, ProxyError> {
+    let provider = &upstream.provider;
     let retry_config = &state.config.retry_config;
     let backoff = ExponentialBuilder::default()
         .with_jitter()
@@ -245,18 +433,21 @@ async fn completions_handler(
         (|| async {
             let mut req_builder = state
                 .client
-                .post(provider.base_url())
-                .header("Content-Type", "application/json")
-                .header(
-                    "Authorization",
-                    provider.build_auth_header(&state.config.api_key),
+                .post(url)
+                .header("Content-Type", "application/json");
+
+            if !upstream.api_key.is_empty() {
+                req_builder = req_builder.header(
+                    provider.auth_header_name(),
+                    provider.build_auth_header(&upstream.api_key),
                 );
+            }
 
             for (key, value) in provider.additional_headers() {
                 req_builder = req_builder.header(key, value);
             }
 
-            req_builder.json(&provider_request).send().await
+            req_builder.json(body).send().await
         })
         .retry(backoff)
         .notify(|err, dur: Duration| {
@@ -272,15 +463,9 @@ async fn completions_handler(
     })
     .await;
 
-    let response = match result {
-        Ok(Ok(resp)) => resp,
-        Ok(Err(e)) => return ProxyError::UpstreamRequest(e).into_response(),
-        Err(_) => return ProxyError::Timeout.into_response(),
-    };
-
-    if stream {
-        handle_stream_response(state, response, start_time, analytics_ctx).await
-    } else {
-        handle_non_stream_response(state, response, start_time, analytics_ctx).await
+    match result {
+        Ok(Ok(resp)) => Ok(resp),
+        Ok(Err(e)) => Err(ProxyError::UpstreamRequest(e)),
+        Err(_) => Err(ProxyError::Timeout),
     }
 }
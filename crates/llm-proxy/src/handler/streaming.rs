@@ -6,22 +6,34 @@ use axum::{body::Body, response::Response};
 use futures_util::StreamExt;
 
 use crate::analytics::GenerationEvent;
+use crate::cache::{CacheFill, CachedBody};
+use crate::config::Upstream;
 
 use super::{AnalyticsContext, AppState, report_with_cost};
 
 pub(super) async fn handle_stream_response(
     state: AppState,
+    upstream: Upstream,
+    model: String,
     response: reqwest::Response,
     start_time: Instant,
     analytics_ctx: AnalyticsContext,
+    fill: Option<CacheFill>,
 ) -> Response {
     let status = response.status();
     let http_status = status.as_u16();
     let latency_ms = start_time.elapsed().as_millis();
-    let analytics = state.config.analytics.clone();
-    let api_key = state.config.api_key.clone();
+    let config = state.config.clone();
+    let reporting = config.reports_generations();
+    let api_key = upstream.api_key;
     let client = state.client.clone();
-    let provider = state.config.provider.clone();
+    let provider = upstream.provider;
+    let mut fill = fill.filter(|_| status.is_success());
+    let mut translator = if status.is_success() {
+        provider.stream_translator(&model)
+    } else {
+        None
+    };
 
     tracing::info!(
         http_status = %http_status,
@@ -43,29 +55,55 @@ pub(super) async fn handle_stream_response(
 
     let output_stream = stream! {
         let mut accumulator = crate::provider::StreamAccumulator::new();
+        let mut captured = fill.as_ref().map(|_| Vec::new());
 
         futures_util::pin_mut!(upstream);
 
         while let Some(chunk_result) = upstream.next().await {
             match chunk_result {
                 Ok(chunk) => {
-                    if analytics.is_some() {
+                    let chunk = match translator.as_mut() {
+                        Some(translator) => bytes::Bytes::from(translator.translate(&chunk)),
+                        None => chunk,
+                    };
+                    if chunk.is_empty() {
+                        continue;
+                    }
+                    if reporting {
                         provider.parse_stream_chunk(&chunk, &mut accumulator);
                     }
+                    capture(&mut captured, &mut fill, &chunk);
                     yield Ok::<_, std::io::Error>(chunk);
                 }
                 Err(e) => {
+                    fill = None;
                     yield Err(std::io::Error::other(e));
                     break;
                 }
             }
         }
 
-        if let Some(analytics) = analytics
+        if let Some(translator) = translator.as_mut() {
+            let tail = translator.finish();
+            if !tail.is_empty() {
+                if reporting {
+                    provider.parse_stream_chunk(&tail, &mut accumulator);
+                }
+                capture(&mut captured, &mut fill, &tail);
+                yield Ok(bytes::Bytes::from(tail));
+            }
+        }
+
+        if let (Some(fill), Some(captured)) = (fill.take(), captured.take()) {
+            fill.complete(CachedBody::Sse(captured.into())).await;
+        }
+
+        if reporting
             && let Some(generation_id) = accumulator.generation_id {
                 let event = GenerationEvent {
                     fingerprint: analytics_ctx.fingerprint,
                     user_id: analytics_ctx.user_id,
+                    task: analytics_ctx.task,
                     generation_id,
                     model: accumulator.model.unwrap_or_default(),
                     input_tokens: accumulator.input_tokens,
@@ -76,7 +114,7 @@ pub(super) async fn handle_stream_response(
                     provider_name: provider.name().to_string(),
                     base_url: provider.base_url().to_string(),
                 };
-                report_with_cost(&*analytics, &*provider, &client, &api_key, event).await;
+                report_with_cost(&config, &*provider, &client, &api_key, event).await;
             }
     };
 
@@ -88,3 +126,17 @@ pub(super) async fn handle_stream_response(
         .body(body)
         .unwrap()
 }
+
+/// Keeps a copy of the stream for the cache, giving up once it outgrows a
+/// cache entry so waiting requests can go upstream instead.
+fn capture(captured: &mut Option<Vec<u8>>, fill: &mut Option<CacheFill>, chunk: &[u8]) {
+    let (Some(buf), Some(f)) = (captured.as_mut(), fill.as_ref()) else {
+        return;
+    };
+    if buf.len() + chunk.len() > f.max_entry_bytes() {
+        *captured = None;
+        *fill = None;
+    } else {
+        buf.extend_from_slice(chunk);
+    }
+}
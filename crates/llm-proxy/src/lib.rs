@@ -1,21 +1,39 @@
 mod analytics;
+mod budget;
+mod cache;
 mod config;
 mod env;
 mod handler;
 pub mod model;
 mod openapi;
 pub mod provider;
+mod routing;
 mod types;
+pub mod usage;
 
 pub const CHAR_TASK_HEADER: &str = "x-char-task";
+/// Set to `hit` or `miss` on responses when caching is enabled.
+pub const CACHE_STATUS_HEADER: &str = "x-char-cache";
 
 pub use analytics::{AnalyticsReporter, GenerationEvent};
+pub use budget::BudgetSource;
+pub use cache::{
+    CacheConfig, DEFAULT_CACHE_MAX_BYTES, DEFAULT_CACHE_MAX_ENTRY_BYTES, DEFAULT_CACHE_TTL,
+};
 pub use config::*;
-pub use env::{ApiKey, Env};
+pub use env::{ApiKey, CacheEnv, Env, RoutingEnv, UsageEnv};
 pub use handler::{chat_completions_router, router};
 pub use hypr_analytics::{AuthenticatedUserId, DeviceFingerprint};
 pub use model::{
     CharTask, MODEL_KEY_AUDIO, MODEL_KEY_DEFAULT, MODEL_KEY_TOOL_CALLING, ModelContext,
-    ModelResolver, StaticModelResolver,
+    ModelResolver, Route, StaticModelResolver,
 };
 pub use openapi::openapi;
+pub use routing::{
+    DEFAULT_CONTEXT_WINDOW, DEFAULT_LOW_BUDGET_USD, ModelCandidate, PRIMARY_PROVIDER,
+    RoutingResolver,
+};
+pub use usage::{
+    InMemoryLedger, QuotaExceeded, QuotaLimits, QuotaPeriod, Quotas, SupabaseLedger, UsageConfig,
+    UsageLedger, usage_router,
+};
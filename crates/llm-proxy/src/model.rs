@@ -28,14 +28,36 @@ pub enum CharTask {
     Title,
 }
 
+#[derive(Default)]
 pub struct ModelContext {
     pub task: Option<CharTask>,
     pub needs_tool_calling: bool,
     pub has_audio: bool,
+    /// Rough size of the prompt, for skipping models whose context is too small.
+    pub input_tokens: u32,
+    /// What the user has left to spend this period, when budgets are tracked.
+    pub remaining_budget_usd: Option<f64>,
+}
+
+/// Models to try on one provider. `provider: None` is the config's primary
+/// provider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub provider: Option<String>,
+    pub models: Vec<String>,
 }
 
 pub trait ModelResolver: Send + Sync {
     fn resolve(&self, ctx: &ModelContext) -> Vec<String>;
+
+    /// Providers to try in order. The proxy moves to the next one when a
+    /// provider is unreachable or answers 429 or 5xx.
+    fn route(&self, ctx: &ModelContext) -> Vec<Route> {
+        vec![Route {
+            provider: None,
+            models: self.resolve(ctx),
+        }]
+    }
 }
 
 #[derive(Clone)]
@@ -251,6 +273,7 @@ mod tests {
                     task: *task,
                     needs_tool_calling: *needs_tool_calling,
                     has_audio: *has_audio,
+                    ..Default::default()
                 },
                 expected,
             );
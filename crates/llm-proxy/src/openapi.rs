@@ -1,6 +1,9 @@
 use utoipa::OpenApi;
 
 use crate::model::CharTask;
+use crate::usage::{
+    QuotaExceeded, QuotaLimits, QuotaPeriod, QuotaUsage, UsageParams, UsageResponse, UsageSummary,
+};
 
 #[utoipa::path(
     post,
@@ -12,7 +15,7 @@ use crate::model::CharTask;
     responses(
         (status = 200, description = "Chat completion response (streaming or non-streaming)"),
         (status = 401, description = "Unauthorized"),
-        (status = 429, description = "Rate limit exceeded"),
+        (status = 429, description = "Rate limit or usage quota exceeded; quota errors carry a `Retry-After` header", body = QuotaExceeded),
         (status = 502, description = "Upstream provider failed"),
         (status = 504, description = "Request timeout"),
     ),
@@ -20,10 +23,33 @@ use crate::model::CharTask;
 )]
 async fn _chat_completions_spec() {}
 
+#[utoipa::path(
+    get,
+    path = "/admin/llm/usage",
+    operation_id = "llm_usage",
+    params(UsageParams),
+    responses(
+        (status = 200, description = "Usage per user, task and model", body = UsageResponse),
+        (status = 400, description = "Invalid time range"),
+        (status = 401, description = "Missing or invalid admin token"),
+        (status = 502, description = "Usage ledger unavailable"),
+    ),
+    tag = "llm",
+)]
+async fn _usage_spec() {}
+
 #[derive(OpenApi)]
 #[openapi(
-    paths(_chat_completions_spec),
-    components(schemas(CharTask)),
+    paths(_chat_completions_spec, _usage_spec),
+    components(schemas(
+        CharTask,
+        QuotaExceeded,
+        QuotaLimits,
+        QuotaPeriod,
+        QuotaUsage,
+        UsageResponse,
+        UsageSummary
+    )),
     tags((name = "llm", description = "LLM chat completions proxy"))
 )]
 pub struct ApiDoc;
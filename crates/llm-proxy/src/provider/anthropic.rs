@@ -0,0 +1,437 @@
+use bytes::Bytes;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::types::ChatCompletionRequest;
+
+use super::convert::{self, ChunkWriter, Completion, Image, Part, ToolMode};
+use super::{
+    GenerationMetadata, Provider, ProviderError, StreamAccumulator, StreamTranslator, openai,
+};
+
+pub const ANTHROPIC_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+const DEFAULT_MAX_TOKENS: u32 = 4096;
+
+/// Anthropic's Messages API, translated to and from OpenAI chat completions.
+pub struct AnthropicProvider {
+    pub base_url: String,
+}
+
+impl Default for AnthropicProvider {
+    fn default() -> Self {
+        Self {
+            base_url: ANTHROPIC_URL.to_string(),
+        }
+    }
+}
+
+impl AnthropicProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+        }
+    }
+}
+
+impl Provider for AnthropicProvider {
+    fn name(&self) -> &str {
+        "anthropic"
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        models: Vec<String>,
+        stream: bool,
+    ) -> Result<serde_json::Value, ProviderError> {
+        let model = models
+            .into_iter()
+            .next()
+            .ok_or_else(|| ProviderError::InvalidRequest("no model".to_string()))?;
+        let conversation = convert::conversation(&request.messages)?;
+
+        let messages: Vec<Value> = conversation
+            .turns
+            .iter()
+            .map(|turn| {
+                json!({
+                    "role": if turn.assistant { "assistant" } else { "user" },
+                    "content": turn.parts.iter().map(content_block).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+
+        let mut body = json!({
+            "model": model,
+            "max_tokens": convert::max_tokens(request).unwrap_or(DEFAULT_MAX_TOKENS),
+            "messages": messages,
+            "stream": stream,
+        });
+
+        if let Some(system) = conversation.system {
+            body["system"] = Value::String(system);
+        }
+        if let Some(temperature) = request.temperature {
+            body["temperature"] = json!(temperature);
+        }
+        if let Some(top_p) = convert::top_p(request) {
+            body["top_p"] = json!(top_p);
+        }
+        let stop_sequences = convert::stop_sequences(request);
+        if !stop_sequences.is_empty() {
+            body["stop_sequences"] = json!(stop_sequences);
+        }
+
+        let tools = convert::tools(request);
+        if !tools.is_empty() {
+            body["tools"] = tools
+                .iter()
+                .map(|tool| {
+                    json!({
+                        "name": tool.name,
+                        "description": tool.description,
+                        "input_schema": tool.parameters,
+                    })
+                })
+                .collect();
+            body["tool_choice"] = match convert::tool_mode(request) {
+                ToolMode::Auto => json!({ "type": "auto" }),
+                ToolMode::Any => json!({ "type": "any" }),
+                ToolMode::None => json!({ "type": "none" }),
+                ToolMode::Named(name) => json!({ "type": "tool", "name": name }),
+            };
+        }
+
+        Ok(body)
+    }
+
+    fn translate_response(&self, _model: &str, body: Bytes) -> Result<Bytes, ProviderError> {
+        let parsed: MessageResponse =
+            serde_json::from_slice(&body).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        for block in parsed.content {
+            match block {
+                ResponseBlock::Text { text: t } => text.push_str(&t),
+                ResponseBlock::ToolUse { id, name, input } => {
+                    tool_calls.push(convert::tool_call(&id, &name, &input));
+                }
+                ResponseBlock::Other => {}
+            }
+        }
+
+        let completion = Completion {
+            id: parsed.id,
+            model: parsed.model,
+            text,
+            tool_calls,
+            stop_reason: parsed.stop_reason,
+            input_tokens: parsed.usage.input_tokens,
+            output_tokens: parsed.usage.output_tokens,
+        };
+        Ok(serde_json::to_vec(&completion.into_json())?.into())
+    }
+
+    fn stream_translator(&self, model: &str) -> Option<Box<dyn StreamTranslator>> {
+        Some(Box::new(AnthropicStream {
+            buffer: Vec::new(),
+            writer: ChunkWriter::new("", model),
+            started: false,
+        }))
+    }
+
+    fn parse_response(&self, body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+        openai::parse_completion(body)
+    }
+
+    fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator) {
+        openai::parse_completion_chunk(chunk, accumulator);
+    }
+
+    fn auth_header_name(&self) -> &str {
+        "x-api-key"
+    }
+
+    fn build_auth_header(&self, api_key: &str) -> String {
+        api_key.to_string()
+    }
+
+    fn additional_headers(&self) -> Vec<(String, String)> {
+        vec![(
+            "anthropic-version".to_string(),
+            ANTHROPIC_VERSION.to_string(),
+        )]
+    }
+}
+
+fn content_block(part: &Part) -> Value {
+    match part {
+        Part::Text(text) => json!({ "type": "text", "text": text }),
+        Part::Image(Image::Base64 { media_type, data }) => json!({
+            "type": "image",
+            "source": { "type": "base64", "media_type": media_type, "data": data },
+        }),
+        Part::Image(Image::Url(url)) => json!({
+            "type": "image",
+            "source": { "type": "url", "url": url },
+        }),
+        Part::ToolUse { id, name, input } => json!({
+            "type": "tool_use",
+            "id": id,
+            "name": name,
+            "input": input,
+        }),
+        Part::ToolResult { id, content } => json!({
+            "type": "tool_result",
+            "tool_use_id": id,
+            "content": content,
+        }),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MessageResponse {
+    id: String,
+    model: String,
+    content: Vec<ResponseBlock>,
+    stop_reason: Option<String>,
+    usage: Usage,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ResponseBlock {
+    Text {
+        text: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: Value,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Usage {
+    input_tokens: u32,
+    output_tokens: u32,
+}
+
+struct AnthropicStream {
+    buffer: Vec<u8>,
+    writer: ChunkWriter,
+    started: bool,
+}
+
+impl AnthropicStream {
+    fn event(&mut self, event: &Value) -> Vec<u8> {
+        let index = event.get("index").and_then(Value::as_u64).unwrap_or(0);
+
+        match event.get("type").and_then(Value::as_str) {
+            Some("message_start") => {
+                let message = &event["message"];
+                if let Some(id) = message.get("id").and_then(Value::as_str) {
+                    self.writer.id = id.to_string();
+                }
+                if let Some(model) = message.get("model").and_then(Value::as_str) {
+                    self.writer.model = model.to_string();
+                }
+                if let Some(tokens) = message
+                    .pointer("/usage/input_tokens")
+                    .and_then(Value::as_u64)
+                {
+                    self.writer.input_tokens = tokens as u32;
+                }
+                self.started = true;
+                self.writer.role()
+            }
+            Some("content_block_start") => {
+                let block = &event["content_block"];
+                match block.get("type").and_then(Value::as_str) {
+                    Some("tool_use") => self.writer.tool_start(
+                        index,
+                        block.get("id").and_then(Value::as_str).unwrap_or_default(),
+                        block
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default(),
+                    ),
+                    Some("text") => self.writer.text(
+                        block
+                            .get("text")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default(),
+                    ),
+                    _ => vec![],
+                }
+            }
+            Some("content_block_delta") => {
+                let delta = &event["delta"];
+                match delta.get("type").and_then(Value::as_str) {
+                    Some("text_delta") => self.writer.text(
+                        delta
+                            .get("text")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default(),
+                    ),
+                    Some("input_json_delta") => self.writer.tool_arguments(
+                        index,
+                        delta
+                            .get("partial_json")
+                            .and_then(Value::as_str)
+                            .unwrap_or_default(),
+                    ),
+                    _ => vec![],
+                }
+            }
+            Some("message_delta") => {
+                if let Some(stop_reason) =
+                    event.pointer("/delta/stop_reason").and_then(Value::as_str)
+                {
+                    self.writer.stop(stop_reason);
+                }
+                if let Some(tokens) = event
+                    .pointer("/usage/output_tokens")
+                    .and_then(Value::as_u64)
+                {
+                    self.writer.output_tokens = tokens as u32;
+                }
+                vec![]
+            }
+            Some("message_stop") => self.writer.finish(),
+            Some("error") => self.writer.error(
+                event
+                    .pointer("/error/message")
+                    .and_then(Value::as_str)
+                    .unwrap_or("upstream error"),
+            ),
+            _ => vec![],
+        }
+    }
+}
+
+impl StreamTranslator for AnthropicStream {
+    fn translate(&mut self, chunk: &[u8]) -> Vec<u8> {
+        self.buffer.extend(chunk.iter().filter(|b| **b != b'\r'));
+
+        let mut out = Vec::new();
+        while let Some(end) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+            let event: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            let Ok(event) = std::str::from_utf8(&event) else {
+                continue;
+            };
+            for line in event.lines() {
+                let Some(data) = line.strip_prefix("data:") else {
+                    continue;
+                };
+                if let Ok(parsed) = serde_json::from_str::<Value>(data.trim()) {
+                    out.extend(self.event(&parsed));
+                }
+            }
+        }
+        out
+    }
+
+    fn finish(&mut self) -> Vec<u8> {
+        if self.started {
+            self.writer.finish()
+        } else {
+            vec![]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_messages_request() {
+        let request: ChatCompletionRequest = serde_json::from_value(json!({
+            "messages": [
+                { "role": "system", "content": "Be brief." },
+                { "role": "user", "content": "Weather in Paris?" },
+                { "role": "assistant", "content": null, "tool_calls": [{
+                    "id": "call_1",
+                    "type": "function",
+                    "function": { "name": "weather", "arguments": "{\"city\":\"Paris\"}" },
+                }] },
+                { "role": "tool", "tool_call_id": "call_1", "content": "Sunny" },
+            ],
+            "tools": [{ "type": "function", "function": {
+                "name": "weather",
+                "parameters": { "type": "object" },
+            } }],
+            "tool_choice": "required",
+        }))
+        .unwrap();
+
+        let body = AnthropicProvider::default()
+            .build_request(&request, vec!["claude-haiku-4-5".into()], false)
+            .unwrap();
+
+        assert_eq!(body["model"], "claude-haiku-4-5");
+        assert_eq!(body["system"], "Be brief.");
+        assert_eq!(body["max_tokens"], DEFAULT_MAX_TOKENS);
+        assert_eq!(body["tool_choice"], json!({ "type": "any" }));
+        assert_eq!(
+            body["tools"][0]["input_schema"],
+            json!({ "type": "object" })
+        );
+
+        let messages = body["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[1]["content"][0]["type"], "tool_use");
+        assert_eq!(messages[1]["content"][0]["input"]["city"], "Paris");
+        assert_eq!(messages[2]["role"], "user");
+        assert_eq!(messages[2]["content"][0]["tool_use_id"], "call_1");
+    }
+
+    #[test]
+    fn translates_stream() {
+        let events = [
+            r#"{"type":"message_start","message":{"id":"msg_1","model":"claude-haiku-4-5","usage":{"input_tokens":12}}}"#,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hi"}}"#,
+            r#"{"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_1","name":"weather"}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"city\":"}}"#,
+            r#"{"type":"message_delta","delta":{"stop_reason":"tool_use"},"usage":{"output_tokens":9}}"#,
+            r#"{"type":"message_stop"}"#,
+        ];
+        let body: String = events
+            .iter()
+            .map(|data| format!("event: x\r\ndata: {data}\r\n\r\n"))
+            .collect();
+
+        let provider = AnthropicProvider::default();
+        let mut translator = provider.stream_translator("fallback").unwrap();
+        let mut out = Vec::new();
+        for chunk in body.as_bytes().chunks(7) {
+            out.extend(translator.translate(chunk));
+        }
+        out.extend(translator.finish());
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains(r#""content":"Hi""#));
+        assert!(out.contains(r#""id":"toolu_1""#));
+        assert!(out.contains(r#""arguments":"{\"city\":""#));
+        assert!(out.contains(r#""finish_reason":"tool_calls""#));
+        assert_eq!(out.matches("[DONE]").count(), 1);
+
+        let mut accumulator = StreamAccumulator::new();
+        provider.parse_stream_chunk(out.as_bytes(), &mut accumulator);
+        assert_eq!(accumulator.generation_id.as_deref(), Some("msg_1"));
+        assert_eq!(accumulator.model.as_deref(), Some("claude-haiku-4-5"));
+        assert_eq!(accumulator.input_tokens, 12);
+        assert_eq!(accumulator.output_tokens, 9);
+    }
+}
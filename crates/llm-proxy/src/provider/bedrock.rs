@@ -0,0 +1,507 @@
+use bytes::Bytes;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::types::ChatCompletionRequest;
+
+use super::convert::{self, ChunkWriter, Completion, Image, Part, ToolMode};
+use super::{
+    GenerationMetadata, Provider, ProviderError, StreamAccumulator, StreamTranslator, openai,
+};
+
+/// Bedrock's Converse API, authenticated with a Bedrock API key and
+/// translated to and from OpenAI chat completions.
+pub struct BedrockProvider {
+    pub base_url: String,
+}
+
+impl BedrockProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+        }
+    }
+
+    pub fn for_region(region: &str) -> Self {
+        Self::new(format!("https://bedrock-runtime.{region}.amazonaws.com"))
+    }
+}
+
+impl Provider for BedrockProvider {
+    fn name(&self) -> &str {
+        "bedrock"
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn endpoint(&self, models: &[String], stream: bool) -> String {
+        let model = models.first().map(String::as_str).unwrap_or_default();
+        format!(
+            "{}/model/{}/{}",
+            self.base_url.trim_end_matches('/'),
+            encode_path_segment(model),
+            if stream {
+                "converse-stream"
+            } else {
+                "converse"
+            }
+        )
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        _models: Vec<String>,
+        _stream: bool,
+    ) -> Result<serde_json::Value, ProviderError> {
+        let conversation = convert::conversation(&request.messages)?;
+
+        let messages = conversation
+            .turns
+            .iter()
+            .map(|turn| {
+                let content = turn
+                    .parts
+                    .iter()
+                    .map(content_block)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(json!({
+                    "role": if turn.assistant { "assistant" } else { "user" },
+                    "content": content,
+                }))
+            })
+            .collect::<Result<Vec<_>, ProviderError>>()?;
+
+        let mut body = json!({ "messages": messages });
+
+        if let Some(system) = conversation.system {
+            body["system"] = json!([{ "text": system }]);
+        }
+
+        let mut inference = serde_json::Map::new();
+        if let Some(max_tokens) = convert::max_tokens(request) {
+            inference.insert("maxTokens".into(), json!(max_tokens));
+        }
+        if let Some(temperature) = request.temperature {
+            inference.insert("temperature".into(), json!(temperature));
+        }
+        if let Some(top_p) = convert::top_p(request) {
+            inference.insert("topP".into(), json!(top_p));
+        }
+        let stop_sequences = convert::stop_sequences(request);
+        if !stop_sequences.is_empty() {
+            inference.insert("stopSequences".into(), json!(stop_sequences));
+        }
+        if !inference.is_empty() {
+            body["inferenceConfig"] = Value::Object(inference);
+        }
+
+        // Converse has no "none" tool choice, and rejects tool blocks in the
+        // history without a tool config, so "none" falls back to auto.
+        let tools = convert::tools(request);
+        if !tools.is_empty() {
+            let specs: Vec<Value> = tools
+                .iter()
+                .map(|tool| {
+                    json!({ "toolSpec": {
+                        "name": tool.name,
+                        "description": tool.description,
+                        "inputSchema": { "json": tool.parameters },
+                    } })
+                })
+                .collect();
+            let mut tool_config = json!({ "tools": specs });
+            match convert::tool_mode(request) {
+                ToolMode::Any => tool_config["toolChoice"] = json!({ "any": {} }),
+                ToolMode::Named(name) => {
+                    tool_config["toolChoice"] = json!({ "tool": { "name": name } })
+                }
+                ToolMode::Auto | ToolMode::None => {}
+            }
+            body["toolConfig"] = tool_config;
+        }
+
+        Ok(body)
+    }
+
+    fn translate_response(&self, model: &str, body: Bytes) -> Result<Bytes, ProviderError> {
+        let parsed: ConverseResponse =
+            serde_json::from_slice(&body).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        for block in parsed.output.message.content {
+            if let Some(t) = block.get("text").and_then(Value::as_str) {
+                text.push_str(t);
+            } else if let Some(tool_use) = block.get("toolUse") {
+                tool_calls.push(convert::tool_call(
+                    tool_use
+                        .get("toolUseId")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default(),
+                    tool_use
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default(),
+                    tool_use.get("input").unwrap_or(&Value::Null),
+                ));
+            }
+        }
+
+        let completion = Completion {
+            id: generation_id(),
+            model: model.to_string(),
+            text,
+            tool_calls,
+            stop_reason: parsed.stop_reason,
+            input_tokens: parsed.usage.input_tokens,
+            output_tokens: parsed.usage.output_tokens,
+        };
+        Ok(serde_json::to_vec(&completion.into_json())?.into())
+    }
+
+    fn stream_translator(&self, model: &str) -> Option<Box<dyn StreamTranslator>> {
+        Some(Box::new(BedrockStream {
+            buffer: Vec::new(),
+            writer: ChunkWriter::new(generation_id(), model),
+            started: false,
+        }))
+    }
+
+    fn parse_response(&self, body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+        openai::parse_completion(body)
+    }
+
+    fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator) {
+        openai::parse_completion_chunk(chunk, accumulator);
+    }
+}
+
+fn content_block(part: &Part) -> Result<Value, ProviderError> {
+    Ok(match part {
+        Part::Text(text) => json!({ "text": text }),
+        Part::Image(Image::Base64 { media_type, data }) => json!({ "image": {
+            "format": media_type.strip_prefix("image/").unwrap_or(media_type),
+            "source": { "bytes": data },
+        } }),
+        Part::Image(Image::Url(_)) => {
+            return Err(ProviderError::InvalidRequest(
+                "bedrock only accepts inline images".to_string(),
+            ));
+        }
+        Part::ToolUse { id, name, input } => json!({ "toolUse": {
+            "toolUseId": id,
+            "name": name,
+            "input": input,
+        } }),
+        Part::ToolResult { id, content } => json!({ "toolResult": {
+            "toolUseId": id,
+            "content": [{ "text": content }],
+        } }),
+    })
+}
+
+fn generation_id() -> String {
+    format!("bedrock-{}", uuid::Uuid::new_v4().simple())
+}
+
+fn encode_path_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConverseResponse {
+    output: ConverseOutput,
+    stop_reason: Option<String>,
+    #[serde(default)]
+    usage: ConverseUsage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConverseOutput {
+    message: ConverseMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConverseMessage {
+    content: Vec<Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ConverseUsage {
+    input_tokens: u32,
+    output_tokens: u32,
+}
+
+struct BedrockStream {
+    buffer: Vec<u8>,
+    writer: ChunkWriter,
+    started: bool,
+}
+
+impl BedrockStream {
+    fn event(&mut self, event_type: &str, payload: &Value) -> Vec<u8> {
+        let index = payload
+            .get("contentBlockIndex")
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+
+        match event_type {
+            "messageStart" => {
+                self.started = true;
+                self.writer.role()
+            }
+            "contentBlockStart" => match payload.pointer("/start/toolUse") {
+                Some(tool_use) => self.writer.tool_start(
+                    index,
+                    tool_use
+                        .get("toolUseId")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default(),
+                    tool_use
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default(),
+                ),
+                None => vec![],
+            },
+            "contentBlockDelta" => {
+                if let Some(text) = payload.pointer("/delta/text").and_then(Value::as_str) {
+                    self.writer.text(text)
+                } else if let Some(input) = payload
+                    .pointer("/delta/toolUse/input")
+                    .and_then(Value::as_str)
+                {
+                    self.writer.tool_arguments(index, input)
+                } else {
+                    vec![]
+                }
+            }
+            "messageStop" => {
+                if let Some(stop_reason) = payload.get("stopReason").and_then(Value::as_str) {
+                    self.writer.stop(stop_reason);
+                }
+                vec![]
+            }
+            // Usage arrives after `messageStop`, so this is the last event.
+            "metadata" => {
+                if let Some(usage) = payload.get("usage") {
+                    let tokens = |key| usage.get(key).and_then(Value::as_u64).unwrap_or(0) as u32;
+                    self.writer.input_tokens = tokens("inputTokens");
+                    self.writer.output_tokens = tokens("outputTokens");
+                }
+                self.writer.finish()
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl StreamTranslator for BedrockStream {
+    fn translate(&mut self, chunk: &[u8]) -> Vec<u8> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut out = Vec::new();
+        while let Some(message) = next_event_message(&mut self.buffer) {
+            let payload: Value = serde_json::from_slice(&message.payload).unwrap_or(Value::Null);
+            if message.message_type.as_deref() == Some("exception") {
+                let text = payload
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .or(message.exception_type.as_deref())
+                    .unwrap_or("upstream error");
+                out.extend(self.writer.error(text));
+            } else if let Some(event_type) = &message.event_type {
+                out.extend(self.event(event_type, &payload));
+            }
+        }
+        out
+    }
+
+    fn finish(&mut self) -> Vec<u8> {
+        if self.started {
+            self.writer.finish()
+        } else {
+            vec![]
+        }
+    }
+}
+
+struct EventMessage {
+    event_type: Option<String>,
+    message_type: Option<String>,
+    exception_type: Option<String>,
+    payload: Vec<u8>,
+}
+
+/// Pops one frame of the `application/vnd.amazon.eventstream` encoding:
+/// total length, headers length and prelude CRC (4 bytes each), headers,
+/// payload, then a 4-byte message CRC. CRCs aren't checked since TLS already
+/// covers integrity.
+fn next_event_message(buffer: &mut Vec<u8>) -> Option<EventMessage> {
+    const PRELUDE_LEN: usize = 12;
+    const CRC_LEN: usize = 4;
+
+    if buffer.len() < PRELUDE_LEN {
+        return None;
+    }
+    let read_u32 = |bytes: &[u8]| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let total_len = read_u32(&buffer[0..4]) as usize;
+    let headers_len = read_u32(&buffer[4..8]) as usize;
+    if buffer.len() < total_len {
+        return None;
+    }
+
+    let frame: Vec<u8> = buffer.drain(..total_len).collect();
+    if total_len < PRELUDE_LEN + headers_len + CRC_LEN {
+        return None;
+    }
+
+    let headers = parse_headers(&frame[PRELUDE_LEN..PRELUDE_LEN + headers_len]);
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+
+    Some(EventMessage {
+        event_type: header(":event-type"),
+        message_type: header(":message-type"),
+        exception_type: header(":exception-type"),
+        payload: frame[PRELUDE_LEN + headers_len..total_len - CRC_LEN].to_vec(),
+    })
+}
+
+/// Reads string headers, skipping the other value types.
+fn parse_headers(mut bytes: &[u8]) -> Vec<(String, String)> {
+    let mut headers = Vec::new();
+
+    while let Some((&name_len, rest)) = bytes.split_first() {
+        let name_len = name_len as usize;
+        if rest.len() < name_len + 1 {
+            break;
+        }
+        let name = String::from_utf8_lossy(&rest[..name_len]).into_owned();
+        let value_type = rest[name_len];
+        let rest = &rest[name_len + 1..];
+
+        let value_len = match value_type {
+            0 | 1 => 0,
+            2 => 1,
+            3 => 2,
+            4 => 4,
+            5 | 8 => 8,
+            9 => 16,
+            6 | 7 => {
+                if rest.len() < 2 {
+                    break;
+                }
+                2 + u16::from_be_bytes([rest[0], rest[1]]) as usize
+            }
+            _ => break,
+        };
+        if rest.len() < value_len {
+            break;
+        }
+
+        if value_type == 7 {
+            headers.push((
+                name,
+                String::from_utf8_lossy(&rest[2..value_len]).into_owned(),
+            ));
+        }
+        bytes = &rest[value_len..];
+    }
+
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(event_type: &str, payload: &Value) -> Vec<u8> {
+        let mut headers = Vec::new();
+        for (name, value) in [(":event-type", event_type), (":message-type", "event")] {
+            headers.push(name.len() as u8);
+            headers.extend_from_slice(name.as_bytes());
+            headers.push(7);
+            headers.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            headers.extend_from_slice(value.as_bytes());
+        }
+        let payload = payload.to_string().into_bytes();
+        let total_len = 12 + headers.len() + payload.len() + 4;
+
+        let mut frame = Vec::new();
+        frame.extend_from_slice(&(total_len as u32).to_be_bytes());
+        frame.extend_from_slice(&(headers.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&[0; 4]);
+        frame.extend_from_slice(&headers);
+        frame.extend_from_slice(&payload);
+        frame.extend_from_slice(&[0; 4]);
+        frame
+    }
+
+    #[test]
+    fn translates_event_stream() {
+        let mut body = Vec::new();
+        body.extend(frame("messageStart", &json!({ "role": "assistant" })));
+        body.extend(frame(
+            "contentBlockDelta",
+            &json!({ "contentBlockIndex": 0, "delta": { "text": "hel" } }),
+        ));
+        body.extend(frame(
+            "contentBlockDelta",
+            &json!({ "contentBlockIndex": 0, "delta": { "text": "lo" } }),
+        ));
+        body.extend(frame("messageStop", &json!({ "stopReason": "end_turn" })));
+        body.extend(frame(
+            "metadata",
+            &json!({ "usage": { "inputTokens": 7, "outputTokens": 2 } }),
+        ));
+
+        let provider = BedrockProvider::new("http://localhost");
+        let mut translator = provider.stream_translator("claude").unwrap();
+        // Split mid-frame to exercise buffering.
+        let (a, b) = body.split_at(body.len() / 2);
+        let mut out = translator.translate(a);
+        out.extend(translator.translate(b));
+        out.extend(translator.finish());
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(r#""content":"hel""#));
+        assert!(out.contains(r#""content":"lo""#));
+        assert!(out.contains(r#""finish_reason":"stop""#));
+        assert!(out.ends_with("data: [DONE]\n\n"));
+        assert_eq!(out.matches("[DONE]").count(), 1);
+
+        let mut accumulator = StreamAccumulator::new();
+        provider.parse_stream_chunk(out.as_bytes(), &mut accumulator);
+        assert_eq!(accumulator.model.as_deref(), Some("claude"));
+        assert_eq!(accumulator.input_tokens, 7);
+        assert_eq!(accumulator.output_tokens, 2);
+    }
+
+    #[test]
+    fn endpoint_encodes_model() {
+        let provider = BedrockProvider::for_region("us-east-1");
+        assert_eq!(
+            provider.endpoint(&["anthropic.claude-3-5-haiku-20241022-v1:0".into()], true),
+            "https://bedrock-runtime.us-east-1.amazonaws.com/model/anthropic.claude-3-5-haiku-20241022-v1%3A0/converse-stream"
+        );
+    }
+}
@@ -0,0 +1,444 @@
+//! Shared pieces for providers that don't speak OpenAI's format: reading an
+//! OpenAI request into turns and content blocks, and writing OpenAI
+//! responses and stream chunks back out.
+
+use serde_json::{Value, json};
+
+use crate::types::{ChatCompletionRequest, ChatMessage, Role, ToolChoice};
+
+use super::ProviderError;
+
+pub(super) enum Part {
+    Text(String),
+    Image(Image),
+    ToolUse {
+        id: String,
+        name: String,
+        input: Value,
+    },
+    ToolResult {
+        id: String,
+        content: String,
+    },
+}
+
+pub(super) enum Image {
+    Base64 { media_type: String, data: String },
+    Url(String),
+}
+
+pub(super) struct Turn {
+    pub assistant: bool,
+    pub parts: Vec<Part>,
+}
+
+pub(super) struct Conversation {
+    pub system: Option<String>,
+    pub turns: Vec<Turn>,
+}
+
+pub(super) struct Tool {
+    pub name: String,
+    pub description: Option<String>,
+    pub parameters: Value,
+}
+
+pub(super) enum ToolMode {
+    Auto,
+    Any,
+    None,
+    Named(String),
+}
+
+/// Splits out system messages and merges consecutive turns from the same
+/// side, since both Anthropic and Bedrock require user and assistant turns
+/// to alternate. Tool results become user turns.
+pub(super) fn conversation(messages: &[ChatMessage]) -> Result<Conversation, ProviderError> {
+    let mut system = Vec::new();
+    let mut turns: Vec<Turn> = Vec::new();
+
+    for message in messages {
+        let (assistant, parts) = match message.role {
+            Role::System => {
+                if let Some(content) = &message.content {
+                    system.push(text_of(content));
+                }
+                continue;
+            }
+            Role::User => (false, content_parts(message.content.as_ref())?),
+            Role::Assistant => {
+                let mut parts = content_parts(message.content.as_ref())?;
+                parts.extend(assistant_tool_calls(message));
+                (true, parts)
+            }
+            Role::Tool => {
+                let id = message
+                    .extra
+                    .get("tool_call_id")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| {
+                        ProviderError::InvalidRequest("tool message without tool_call_id".into())
+                    })?;
+                let content = message.content.as_ref().map(text_of).unwrap_or_default();
+                (
+                    false,
+                    vec![Part::ToolResult {
+                        id: id.to_string(),
+                        content,
+                    }],
+                )
+            }
+        };
+
+        if parts.is_empty() {
+            continue;
+        }
+
+        match turns.last_mut() {
+            Some(last) if last.assistant == assistant => last.parts.extend(parts),
+            _ => turns.push(Turn { assistant, parts }),
+        }
+    }
+
+    Ok(Conversation {
+        system: (!system.is_empty()).then(|| system.join("\n\n")),
+        turns,
+    })
+}
+
+fn content_parts(content: Option<&Value>) -> Result<Vec<Part>, ProviderError> {
+    let Some(content) = content else {
+        return Ok(vec![]);
+    };
+
+    if let Some(text) = content.as_str() {
+        return Ok(if text.is_empty() {
+            vec![]
+        } else {
+            vec![Part::Text(text.to_string())]
+        });
+    }
+
+    let Some(items) = content.as_array() else {
+        return Ok(vec![]);
+    };
+
+    let mut parts = Vec::with_capacity(items.len());
+    for item in items {
+        match item.get("type").and_then(Value::as_str) {
+            Some("text") => {
+                if let Some(text) = item.get("text").and_then(Value::as_str) {
+                    parts.push(Part::Text(text.to_string()));
+                }
+            }
+            Some("image_url") => {
+                let url = item
+                    .pointer("/image_url/url")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| ProviderError::InvalidRequest("image_url without url".into()))?;
+                parts.push(Part::Image(parse_image(url)));
+            }
+            Some(other) => {
+                return Err(ProviderError::InvalidRequest(format!(
+                    "unsupported content part: {other}"
+                )));
+            }
+            None => {}
+        }
+    }
+    Ok(parts)
+}
+
+fn parse_image(url: &str) -> Image {
+    url.strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+        .map(|(media_type, data)| Image::Base64 {
+            media_type: media_type.to_string(),
+            data: data.to_string(),
+        })
+        .unwrap_or_else(|| Image::Url(url.to_string()))
+}
+
+fn assistant_tool_calls(message: &ChatMessage) -> Vec<Part> {
+    let Some(calls) = message.extra.get("tool_calls").and_then(Value::as_array) else {
+        return vec![];
+    };
+
+    calls
+        .iter()
+        .filter_map(|call| {
+            let id = call.get("id")?.as_str()?;
+            let name = call.pointer("/function/name")?.as_str()?;
+            let input = call
+                .pointer("/function/arguments")
+                .and_then(Value::as_str)
+                .and_then(|args| serde_json::from_str(args).ok())
+                .unwrap_or_else(|| json!({}));
+            Some(Part::ToolUse {
+                id: id.to_string(),
+                name: name.to_string(),
+                input,
+            })
+        })
+        .collect()
+}
+
+pub(super) fn text_of(content: &Value) -> String {
+    match content {
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| item.get("text").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+pub(super) fn tools(request: &ChatCompletionRequest) -> Vec<Tool> {
+    request
+        .tools
+        .iter()
+        .flatten()
+        .filter_map(|tool| {
+            let function = tool.get("function")?;
+            Some(Tool {
+                name: function.get("name")?.as_str()?.to_string(),
+                description: function
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(String::from),
+                parameters: function
+                    .get("parameters")
+                    .cloned()
+                    .unwrap_or_else(|| json!({ "type": "object", "properties": {} })),
+            })
+        })
+        .collect()
+}
+
+pub(super) fn tool_mode(request: &ChatCompletionRequest) -> ToolMode {
+    match &request.tool_choice {
+        None => ToolMode::Auto,
+        Some(ToolChoice::String(choice)) => match choice.as_str() {
+            "none" => ToolMode::None,
+            "required" => ToolMode::Any,
+            _ => ToolMode::Auto,
+        },
+        Some(ToolChoice::Object { function, .. }) => function
+            .get("name")
+            .and_then(Value::as_str)
+            .map(|name| ToolMode::Named(name.to_string()))
+            .unwrap_or(ToolMode::Auto),
+    }
+}
+
+pub(super) fn max_tokens(request: &ChatCompletionRequest) -> Option<u32> {
+    request.max_tokens.or_else(|| {
+        request
+            .extra
+            .get("max_completion_tokens")
+            .and_then(Value::as_u64)
+            .map(|n| n as u32)
+    })
+}
+
+pub(super) fn top_p(request: &ChatCompletionRequest) -> Option<f64> {
+    request.extra.get("top_p").and_then(Value::as_f64)
+}
+
+pub(super) fn stop_sequences(request: &ChatCompletionRequest) -> Vec<String> {
+    match request.extra.get("stop") {
+        Some(Value::String(stop)) => vec![stop.clone()],
+        Some(Value::Array(stops)) => stops
+            .iter()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+pub(super) fn finish_reason(stop_reason: &str) -> &'static str {
+    match stop_reason {
+        "max_tokens" => "length",
+        "tool_use" => "tool_calls",
+        "content_filtered" | "guardrail_intervened" | "refusal" => "content_filter",
+        _ => "stop",
+    }
+}
+
+pub(super) fn tool_call(id: &str, name: &str, input: &Value) -> Value {
+    json!({
+        "id": id,
+        "type": "function",
+        "function": { "name": name, "arguments": input.to_string() },
+    })
+}
+
+pub(super) struct Completion {
+    pub id: String,
+    pub model: String,
+    pub text: String,
+    pub tool_calls: Vec<Value>,
+    pub stop_reason: Option<String>,
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+}
+
+impl Completion {
+    pub fn into_json(self) -> Value {
+        let mut message = json!({
+            "role": "assistant",
+            "content": if self.text.is_empty() && !self.tool_calls.is_empty() {
+                Value::Null
+            } else {
+                Value::String(self.text)
+            },
+        });
+        if !self.tool_calls.is_empty() {
+            message["tool_calls"] = Value::Array(self.tool_calls);
+        }
+
+        json!({
+            "id": self.id,
+            "object": "chat.completion",
+            "created": now_secs(),
+            "model": self.model,
+            "choices": [{
+                "index": 0,
+                "message": message,
+                "finish_reason": self.stop_reason.as_deref().map(finish_reason),
+            }],
+            "usage": usage(self.input_tokens, self.output_tokens),
+        })
+    }
+}
+
+fn usage(input_tokens: u32, output_tokens: u32) -> Value {
+    json!({
+        "prompt_tokens": input_tokens,
+        "completion_tokens": output_tokens,
+        "total_tokens": input_tokens + output_tokens,
+    })
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Writes OpenAI `chat.completion.chunk` events. Tool calls are numbered in
+/// the order their content blocks start.
+pub(super) struct ChunkWriter {
+    pub id: String,
+    pub model: String,
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    created: u64,
+    tool_blocks: Vec<u64>,
+    stop_reason: Option<String>,
+    done: bool,
+}
+
+impl ChunkWriter {
+    pub fn new(id: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            model: model.into(),
+            input_tokens: 0,
+            output_tokens: 0,
+            created: now_secs(),
+            tool_blocks: Vec::new(),
+            stop_reason: None,
+            done: false,
+        }
+    }
+
+    pub fn role(&self) -> Vec<u8> {
+        self.chunk(json!({ "role": "assistant", "content": "" }), None, None)
+    }
+
+    pub fn text(&self, text: &str) -> Vec<u8> {
+        if text.is_empty() {
+            return vec![];
+        }
+        self.chunk(json!({ "content": text }), None, None)
+    }
+
+    pub fn tool_start(&mut self, block: u64, id: &str, name: &str) -> Vec<u8> {
+        let index = self.tool_blocks.len();
+        self.tool_blocks.push(block);
+        self.chunk(
+            json!({ "tool_calls": [{
+                "index": index,
+                "id": id,
+                "type": "function",
+                "function": { "name": name, "arguments": "" },
+            }] }),
+            None,
+            None,
+        )
+    }
+
+    pub fn tool_arguments(&self, block: u64, arguments: &str) -> Vec<u8> {
+        let Some(index) = self.tool_blocks.iter().position(|b| *b == block) else {
+            return vec![];
+        };
+        self.chunk(
+            json!({ "tool_calls": [{
+                "index": index,
+                "function": { "arguments": arguments },
+            }] }),
+            None,
+            None,
+        )
+    }
+
+    pub fn stop(&mut self, stop_reason: &str) {
+        self.stop_reason = Some(stop_reason.to_string());
+    }
+
+    /// The closing chunk with the finish reason and usage, then `[DONE]`.
+    /// Only written once.
+    pub fn finish(&mut self) -> Vec<u8> {
+        if self.done {
+            return vec![];
+        }
+        self.done = true;
+
+        let finish_reason = finish_reason(self.stop_reason.as_deref().unwrap_or("end_turn"));
+        let mut out = self.chunk(
+            json!({}),
+            Some(finish_reason),
+            Some(usage(self.input_tokens, self.output_tokens)),
+        );
+        out.extend_from_slice(b"data: [DONE]\n\n");
+        out
+    }
+
+    pub fn error(&mut self, message: &str) -> Vec<u8> {
+        self.done = true;
+        sse(&json!({ "error": { "message": message } }))
+    }
+
+    fn chunk(&self, delta: Value, finish_reason: Option<&str>, usage: Option<Value>) -> Vec<u8> {
+        let mut chunk = json!({
+            "id": self.id,
+            "object": "chat.completion.chunk",
+            "created": self.created,
+            "model": self.model,
+            "choices": [{ "index": 0, "delta": delta, "finish_reason": finish_reason }],
+        });
+        if let Some(usage) = usage {
+            chunk["usage"] = usage;
+        }
+        sse(&chunk)
+    }
+}
+
+fn sse(value: &Value) -> Vec<u8> {
+    format!("data: {value}\n\n").into_bytes()
+}
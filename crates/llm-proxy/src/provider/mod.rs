@@ -1,7 +1,15 @@
+mod anthropic;
+mod bedrock;
+mod convert;
+mod openai;
 mod openrouter;
 
+pub use anthropic::AnthropicProvider;
+pub use bedrock::BedrockProvider;
+pub use openai::OpenAiCompatibleProvider;
 pub use openrouter::OpenRouterProvider;
 
+use bytes::Bytes;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
@@ -51,11 +59,34 @@ pub enum ProviderError {
     InvalidRequest(String),
 }
 
+/// Rewrites a provider's streaming body into OpenAI-style SSE. Chunks can
+/// split events anywhere, so implementations buffer until an event is whole.
+pub trait StreamTranslator: Send {
+    fn translate(&mut self, chunk: &[u8]) -> Vec<u8>;
+
+    fn finish(&mut self) -> Vec<u8> {
+        vec![]
+    }
+}
+
 pub trait Provider: Send + Sync {
     fn name(&self) -> &str;
 
     fn base_url(&self) -> &str;
 
+    /// Where to send a request for `models`. Most providers take the model in
+    /// the body; Bedrock puts it in the path.
+    fn endpoint(&self, models: &[String], stream: bool) -> String {
+        let _ = (models, stream);
+        self.base_url().to_string()
+    }
+
+    /// Whether the provider falls back between `models` itself. Otherwise
+    /// the proxy tries them one at a time.
+    fn handles_model_fallback(&self) -> bool {
+        false
+    }
+
     fn build_request(
         &self,
         request: &ChatCompletionRequest,
@@ -63,8 +94,21 @@ pub trait Provider: Send + Sync {
         stream: bool,
     ) -> Result<serde_json::Value, ProviderError>;
 
+    /// Turns a successful response body into an OpenAI chat completion.
+    fn translate_response(&self, model: &str, body: Bytes) -> Result<Bytes, ProviderError> {
+        let _ = model;
+        Ok(body)
+    }
+
+    fn stream_translator(&self, model: &str) -> Option<Box<dyn StreamTranslator>> {
+        let _ = model;
+        None
+    }
+
+    /// Parses the (already translated) response body.
     fn parse_response(&self, body: &[u8]) -> Result<GenerationMetadata, ProviderError>;
 
+    /// Parses an (already translated) stream chunk.
     fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator);
 
     fn fetch_cost(
@@ -77,6 +121,10 @@ pub trait Provider: Send + Sync {
         Box::pin(async { None })
     }
 
+    fn auth_header_name(&self) -> &str {
+        "Authorization"
+    }
+
     fn build_auth_header(&self, api_key: &str) -> String {
         format!("Bearer {}", api_key)
     }
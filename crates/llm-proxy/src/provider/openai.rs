@@ -0,0 +1,136 @@
+use serde::Deserialize;
+
+use crate::types::{ChatCompletionRequest, UsageInfo};
+
+use super::{GenerationMetadata, Provider, ProviderError, StreamAccumulator};
+
+pub const OPENAI_URL: &str = "https://api.openai.com/v1/chat/completions";
+
+/// Any server speaking OpenAI's chat completions API: OpenAI itself, or a
+/// local llama.cpp (`http://localhost:8080/v1/chat/completions`), Ollama
+/// (`http://localhost:11434/v1/chat/completions`) or LM Studio
+/// (`http://localhost:1234/v1/chat/completions`). Local servers usually need
+/// no API key; an empty key sends no `Authorization` header.
+pub struct OpenAiCompatibleProvider {
+    pub name: String,
+    pub base_url: String,
+}
+
+impl OpenAiCompatibleProvider {
+    pub fn new(name: impl Into<String>, base_url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            base_url: base_url.into(),
+        }
+    }
+
+    pub fn openai() -> Self {
+        Self::new("openai", OPENAI_URL)
+    }
+}
+
+impl Provider for OpenAiCompatibleProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        models: Vec<String>,
+        stream: bool,
+    ) -> Result<serde_json::Value, ProviderError> {
+        let model = models
+            .into_iter()
+            .next()
+            .ok_or_else(|| ProviderError::InvalidRequest("no model".to_string()))?;
+
+        let mut body = serde_json::to_value(request)?;
+        let obj = body.as_object_mut().unwrap();
+
+        obj.insert("model".to_string(), serde_json::Value::String(model));
+        obj.insert("stream".to_string(), serde_json::Value::Bool(stream));
+        if stream && !obj.contains_key("stream_options") {
+            obj.insert(
+                "stream_options".to_string(),
+                serde_json::json!({ "include_usage": true }),
+            );
+        }
+
+        Ok(body)
+    }
+
+    fn parse_response(&self, body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+        parse_completion(body)
+    }
+
+    fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator) {
+        parse_completion_chunk(chunk, accumulator);
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionResponse {
+    pub id: String,
+    pub model: Option<String>,
+    pub usage: Option<UsageInfo>,
+}
+
+pub(super) fn parse_completion(body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+    let parsed: ChatCompletionResponse =
+        serde_json::from_slice(body).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    Ok(GenerationMetadata {
+        generation_id: parsed.id,
+        model: parsed.model,
+        input_tokens: parsed.usage.as_ref().map(|u| u.input_tokens()).unwrap_or(0),
+        output_tokens: parsed
+            .usage
+            .as_ref()
+            .map(|u| u.output_tokens())
+            .unwrap_or(0),
+    })
+}
+
+pub(super) fn parse_completion_chunk(chunk: &[u8], accumulator: &mut StreamAccumulator) {
+    let Ok(text) = std::str::from_utf8(chunk) else {
+        return;
+    };
+
+    for line in text.lines() {
+        let Some(data) = line.strip_prefix("data: ") else {
+            continue;
+        };
+
+        if data.trim() == "[DONE]" {
+            continue;
+        }
+
+        let Ok(parsed) = serde_json::from_str::<serde_json::Value>(data) else {
+            continue;
+        };
+
+        if accumulator.generation_id.is_none() {
+            accumulator.generation_id = parsed.get("id").and_then(|v| v.as_str()).map(String::from);
+        }
+
+        if accumulator.model.is_none() {
+            accumulator.model = parsed
+                .get("model")
+                .and_then(|v| v.as_str())
+                .map(String::from);
+        }
+
+        if let Some(usage) = parsed
+            .get("usage")
+            .and_then(|u| serde_json::from_value::<UsageInfo>(u.clone()).ok())
+        {
+            accumulator.input_tokens = usage.input_tokens();
+            accumulator.output_tokens = usage.output_tokens();
+        }
+    }
+}
@@ -0,0 +1,462 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::config::Upstream;
+use crate::env::RoutingEnv;
+use crate::model::{CharTask, ModelContext, ModelResolver, Route};
+use crate::provider::{AnthropicProvider, BedrockProvider, OpenAiCompatibleProvider};
+
+pub const DEFAULT_CONTEXT_WINDOW: u32 = 128_000;
+pub const DEFAULT_LOW_BUDGET_USD: f64 = 0.5;
+/// Provider name that refers to the primary provider in env config.
+pub const PRIMARY_PROVIDER: &str = "primary";
+
+const DEFAULT_OPENAI_COMPATIBLE_NAME: &str = "self-hosted";
+const DEFAULT_BEDROCK_REGION: &str = "us-east-1";
+
+/// A model the router may pick, with what it can do and what it costs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModelCandidate {
+    pub model: String,
+    /// Name of the provider serving the model; `None` is the primary one.
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Tasks this model is reserved for. Empty means any task without its
+    /// own candidates.
+    #[serde(default)]
+    pub tasks: Vec<CharTask>,
+    #[serde(default = "default_context_window")]
+    pub context_window: u32,
+    #[serde(default)]
+    pub tool_calling: bool,
+    #[serde(default)]
+    pub audio: bool,
+    /// USD per million tokens. Zero for self-hosted models.
+    #[serde(default)]
+    pub input_cost_per_mtok: f64,
+    #[serde(default)]
+    pub output_cost_per_mtok: f64,
+}
+
+fn default_context_window() -> u32 {
+    DEFAULT_CONTEXT_WINDOW
+}
+
+impl ModelCandidate {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            provider: None,
+            tasks: vec![],
+            context_window: DEFAULT_CONTEXT_WINDOW,
+            tool_calling: false,
+            audio: false,
+            input_cost_per_mtok: 0.0,
+            output_cost_per_mtok: 0.0,
+        }
+    }
+
+    pub fn on_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    pub fn for_tasks(mut self, tasks: &[CharTask]) -> Self {
+        self.tasks = tasks.to_vec();
+        self
+    }
+
+    pub fn with_context_window(mut self, tokens: u32) -> Self {
+        self.context_window = tokens;
+        self
+    }
+
+    pub fn with_tool_calling(mut self) -> Self {
+        self.tool_calling = true;
+        self
+    }
+
+    pub fn with_audio(mut self) -> Self {
+        self.audio = true;
+        self
+    }
+
+    pub fn with_cost(mut self, input_per_mtok: f64, output_per_mtok: f64) -> Self {
+        self.input_cost_per_mtok = input_per_mtok;
+        self.output_cost_per_mtok = output_per_mtok;
+        self
+    }
+
+    fn cost(&self) -> f64 {
+        self.input_cost_per_mtok + self.output_cost_per_mtok
+    }
+
+    fn fits(&self, ctx: &ModelContext) -> bool {
+        (!ctx.needs_tool_calling || self.tool_calling)
+            && (!ctx.has_audio || self.audio)
+            && self.context_window >= ctx.input_tokens
+    }
+}
+
+/// Picks models from a candidate list in preference order, keeping those
+/// that fit the request's task, size, tool use and audio.
+///
+/// A task pinned to a provider only ever goes to that provider, even if none
+/// of its models fit, so self-hosted data never leaves the org's endpoint.
+/// Users close to their budget get the cheapest models first; users out of
+/// budget only get free ones, if there are any.
+#[derive(Clone)]
+pub struct RoutingResolver {
+    candidates: Vec<ModelCandidate>,
+    pinned: HashMap<CharTask, Option<String>>,
+    low_budget_usd: f64,
+}
+
+impl Default for RoutingResolver {
+    fn default() -> Self {
+        Self {
+            candidates: vec![],
+            pinned: HashMap::new(),
+            low_budget_usd: DEFAULT_LOW_BUDGET_USD,
+        }
+    }
+}
+
+impl RoutingResolver {
+    pub fn with_candidate(mut self, candidate: ModelCandidate) -> Self {
+        self.candidates.push(candidate);
+        self
+    }
+
+    pub fn pin_task(mut self, task: CharTask, provider: impl Into<String>) -> Self {
+        self.pinned.insert(task, Some(provider.into()));
+        self
+    }
+
+    pub fn pin_task_to_primary(mut self, task: CharTask) -> Self {
+        self.pinned.insert(task, None);
+        self
+    }
+
+    pub fn with_low_budget_threshold(mut self, usd: f64) -> Self {
+        self.low_budget_usd = usd;
+        self
+    }
+
+    fn select(&self, ctx: &ModelContext) -> Vec<&ModelCandidate> {
+        let pinned = ctx.task.and_then(|task| self.pinned.get(&task));
+        let pool: Vec<&ModelCandidate> = self
+            .candidates
+            .iter()
+            .filter(|c| pinned.is_none_or(|p| &c.provider == p))
+            .collect();
+
+        let for_task: Vec<&ModelCandidate> = pool
+            .iter()
+            .copied()
+            .filter(|c| ctx.task.is_some_and(|task| c.tasks.contains(&task)))
+            .collect();
+        let pool = if for_task.is_empty() {
+            pool.into_iter().filter(|c| c.tasks.is_empty()).collect()
+        } else {
+            for_task
+        };
+
+        let fitting: Vec<&ModelCandidate> = pool.iter().copied().filter(|c| c.fits(ctx)).collect();
+        let mut selected = if fitting.is_empty() && pinned.is_some() {
+            pool
+        } else {
+            fitting
+        };
+
+        if let Some(remaining) = ctx.remaining_budget_usd {
+            if remaining <= 0.0 && selected.iter().any(|c| c.cost() == 0.0) {
+                selected.retain(|c| c.cost() == 0.0);
+            }
+            if remaining < self.low_budget_usd {
+                selected.sort_by(|a, b| a.cost().total_cmp(&b.cost()));
+            }
+        }
+
+        selected
+    }
+}
+
+impl ModelResolver for RoutingResolver {
+    fn resolve(&self, ctx: &ModelContext) -> Vec<String> {
+        self.select(ctx)
+            .into_iter()
+            .map(|c| c.model.clone())
+            .collect()
+    }
+
+    fn route(&self, ctx: &ModelContext) -> Vec<Route> {
+        let mut routes: Vec<Route> = Vec::new();
+        for candidate in self.select(ctx) {
+            match routes.last_mut() {
+                Some(route) if route.provider == candidate.provider => {
+                    route.models.push(candidate.model.clone());
+                }
+                _ => routes.push(Route {
+                    provider: candidate.provider.clone(),
+                    models: vec![candidate.model.clone()],
+                }),
+            }
+        }
+        routes
+    }
+}
+
+impl RoutingEnv {
+    /// Providers to register next to the primary one, each under its own name.
+    pub fn upstreams(&self) -> Vec<Upstream> {
+        let mut upstreams = Vec::new();
+        if let Some(url) = &self.llm_openai_compatible_url {
+            let name = self
+                .llm_openai_compatible_name
+                .as_deref()
+                .unwrap_or(DEFAULT_OPENAI_COMPATIBLE_NAME);
+            upstreams.push(Upstream {
+                provider: Arc::new(OpenAiCompatibleProvider::new(name, url)),
+                api_key: self
+                    .llm_openai_compatible_api_key
+                    .clone()
+                    .unwrap_or_default(),
+            });
+        }
+        if let Some(api_key) = &self.llm_anthropic_api_key {
+            let provider = match &self.llm_anthropic_url {
+                Some(url) => AnthropicProvider::new(url),
+                None => AnthropicProvider::default(),
+            };
+            upstreams.push(Upstream {
+                provider: Arc::new(provider),
+                api_key: api_key.clone(),
+            });
+        }
+        if let Some(api_key) = &self.llm_bedrock_api_key {
+            let region = self
+                .llm_bedrock_region
+                .as_deref()
+                .unwrap_or(DEFAULT_BEDROCK_REGION);
+            upstreams.push(Upstream {
+                provider: Arc::new(BedrockProvider::for_region(region)),
+                api_key: api_key.clone(),
+            });
+        }
+        upstreams
+    }
+
+    /// A resolver over `LLM_ROUTES`, or `None` to keep the default models.
+    pub fn resolver(&self) -> Option<RoutingResolver> {
+        if self.llm_routes.is_empty() {
+            if !self.llm_pinned_tasks.is_empty() {
+                tracing::warn!("llm_pinned_tasks_ignored_without_routes");
+            }
+            return None;
+        }
+
+        let resolver = self.llm_routes.iter().cloned().fold(
+            RoutingResolver::default(),
+            |resolver, mut candidate| {
+                if candidate.provider.as_deref() == Some(PRIMARY_PROVIDER) {
+                    candidate.provider = None;
+                }
+                resolver.with_candidate(candidate)
+            },
+        );
+        let resolver = self
+            .llm_pinned_tasks
+            .iter()
+            .fold(resolver, |resolver, (task, provider)| {
+                if provider == PRIMARY_PROVIDER {
+                    resolver.pin_task_to_primary(*task)
+                } else {
+                    resolver.pin_task(*task, provider)
+                }
+            });
+        Some(resolver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver() -> RoutingResolver {
+        RoutingResolver::default()
+            .with_candidate(
+                ModelCandidate::new("anthropic/claude-sonnet-4.6")
+                    .with_context_window(200_000)
+                    .with_tool_calling()
+                    .with_cost(3.0, 15.0),
+            )
+            .with_candidate(
+                ModelCandidate::new("claude-haiku-4-5")
+                    .on_provider("anthropic")
+                    .with_context_window(200_000)
+                    .with_tool_calling()
+                    .with_cost(1.0, 5.0),
+            )
+            .with_candidate(
+                ModelCandidate::new("qwen3-8b")
+                    .on_provider("self-hosted")
+                    .with_context_window(32_000),
+            )
+            .with_candidate(
+                ModelCandidate::new("google/gemini-2.5-flash-lite")
+                    .for_tasks(&[CharTask::Title])
+                    .with_cost(0.1, 0.4),
+            )
+    }
+
+    fn models(routes: &[Route]) -> Vec<&str> {
+        routes
+            .iter()
+            .flat_map(|r| r.models.iter().map(String::as_str))
+            .collect()
+    }
+
+    #[test]
+    fn preference_order_grouped_by_provider() {
+        let routes = resolver().route(&ModelContext::default());
+        let providers: Vec<_> = routes.iter().map(|r| r.provider.as_deref()).collect();
+        assert_eq!(providers, [None, Some("anthropic"), Some("self-hosted")]);
+    }
+
+    #[test]
+    fn task_candidates_win() {
+        let ctx = ModelContext {
+            task: Some(CharTask::Title),
+            ..Default::default()
+        };
+        assert_eq!(
+            models(&resolver().route(&ctx)),
+            ["google/gemini-2.5-flash-lite"]
+        );
+    }
+
+    #[test]
+    fn filters_by_tools_and_context() {
+        let ctx = ModelContext {
+            needs_tool_calling: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            models(&resolver().route(&ctx)),
+            ["anthropic/claude-sonnet-4.6", "claude-haiku-4-5"]
+        );
+
+        let ctx = ModelContext {
+            input_tokens: 100_000,
+            ..Default::default()
+        };
+        assert!(!models(&resolver().route(&ctx)).contains(&"qwen3-8b"));
+    }
+
+    #[test]
+    fn pinned_task_stays_on_provider() {
+        let resolver = resolver().pin_task(CharTask::Enhance, "self-hosted");
+
+        let ctx = ModelContext {
+            task: Some(CharTask::Enhance),
+            ..Default::default()
+        };
+        let routes = resolver.route(&ctx);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].provider.as_deref(), Some("self-hosted"));
+
+        // Too big for the local model, but still never leaves the endpoint.
+        let ctx = ModelContext {
+            task: Some(CharTask::Enhance),
+            input_tokens: 100_000,
+            ..Default::default()
+        };
+        assert_eq!(models(&resolver.route(&ctx)), ["qwen3-8b"]);
+    }
+
+    #[test]
+    fn task_pinned_to_primary() {
+        let resolver = resolver().pin_task_to_primary(CharTask::Chat);
+        let ctx = ModelContext {
+            task: Some(CharTask::Chat),
+            ..Default::default()
+        };
+        let routes = resolver.route(&ctx);
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].provider, None);
+        assert_eq!(models(&routes), ["anthropic/claude-sonnet-4.6"]);
+    }
+
+    #[test]
+    fn routes_from_env() {
+        let env: RoutingEnv = serde_json::from_value(serde_json::json!({
+            "llm_openai_compatible_url": "http://localhost:8000/v1/chat/completions",
+            "llm_anthropic_api_key": "sk-ant",
+            "llm_routes": r#"[
+                {"model": "qwen3-8b", "provider": "self-hosted", "tasks": ["enhance"]},
+                {"model": "openai/gpt-4.1-mini", "provider": "primary", "tool_calling": true}
+            ]"#,
+            "llm_pinned_tasks": "enhance=self-hosted, chat=primary",
+        }))
+        .unwrap();
+
+        let names: Vec<_> = env
+            .upstreams()
+            .iter()
+            .map(|u| u.provider.name().to_string())
+            .collect();
+        assert_eq!(names, ["self-hosted", "anthropic"]);
+
+        let resolver = env.resolver().unwrap();
+        let enhance = ModelContext {
+            task: Some(CharTask::Enhance),
+            ..Default::default()
+        };
+        let routes = resolver.route(&enhance);
+        assert_eq!(routes[0].provider.as_deref(), Some("self-hosted"));
+        assert_eq!(models(&routes), ["qwen3-8b"]);
+
+        let chat = ModelContext {
+            task: Some(CharTask::Chat),
+            needs_tool_calling: true,
+            ..Default::default()
+        };
+        let routes = resolver.route(&chat);
+        assert_eq!(routes[0].provider, None);
+        assert_eq!(models(&routes), ["openai/gpt-4.1-mini"]);
+    }
+
+    #[test]
+    fn budget() {
+        let low = ModelContext {
+            remaining_budget_usd: Some(0.1),
+            ..Default::default()
+        };
+        assert_eq!(
+            models(&resolver().route(&low)),
+            [
+                "qwen3-8b",
+                "claude-haiku-4-5",
+                "anthropic/claude-sonnet-4.6"
+            ]
+        );
+
+        let exhausted = ModelContext {
+            remaining_budget_usd: Some(0.0),
+            ..Default::default()
+        };
+        assert_eq!(models(&resolver().route(&exhausted)), ["qwen3-8b"]);
+
+        let plenty = ModelContext {
+            remaining_budget_usd: Some(10.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            models(&resolver().route(&plenty)),
+            models(&resolver().route(&ModelContext::default()))
+        );
+    }
+}
@@ -61,6 +61,30 @@ pub fn has_audio_content(messages: &[ChatMessage]) -> bool {
     })
 }
 
+/// About four characters per token, which is close enough for picking a
+/// model with a big enough context window.
+pub fn estimate_input_tokens(request: &ChatCompletionRequest) -> u32 {
+    const CHARS_PER_TOKEN: usize = 4;
+
+    let content_chars: usize = request
+        .messages
+        .iter()
+        .filter_map(|msg| msg.content.as_ref())
+        .map(|content| match content {
+            serde_json::Value::String(text) => text.len(),
+            other => other.to_string().len(),
+        })
+        .sum();
+    let tool_chars: usize = request
+        .tools
+        .iter()
+        .flatten()
+        .map(|tool| tool.to_string().len())
+        .sum();
+
+    ((content_chars + tool_chars) / CHARS_PER_TOKEN) as u32
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UsageInfo {
     pub prompt_tokens: Option<u32>,
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::{QuotaPeriod, UsageConfig, UsageQuery, UsageSummary};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UsageParams {
+    /// Only this user's usage. All users when omitted.
+    pub user_id: Option<String>,
+    /// Defaults to the start of the current UTC month.
+    #[param(value_type = Option<String>, format = DateTime)]
+    pub since: Option<DateTime<Utc>>,
+    /// Defaults to now.
+    #[param(value_type = Option<String>, format = DateTime)]
+    pub until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UsageResponse {
+    #[schema(value_type = String, format = DateTime)]
+    pub since: DateTime<Utc>,
+    #[schema(value_type = String, format = DateTime)]
+    pub until: DateTime<Utc>,
+    pub rows: Vec<UsageSummary>,
+}
+
+/// `GET /usage` for operators, authenticated with the token set through
+/// [`UsageConfig::with_admin_token`] rather than a user session.
+pub fn usage_router(usage: UsageConfig) -> Router {
+    Router::new()
+        .route("/usage", get(usage_handler))
+        .with_state(Arc::new(usage))
+}
+
+async fn usage_handler(
+    State(usage): State<Arc<UsageConfig>>,
+    headers: HeaderMap,
+    Query(params): Query<UsageParams>,
+) -> Response {
+    if !is_admin(&usage, &headers) {
+        return (StatusCode::UNAUTHORIZED, "Unauthorized").into_response();
+    }
+
+    let now = Utc::now();
+    let query = UsageQuery {
+        user_id: params.user_id,
+        since: params
+            .since
+            .unwrap_or_else(|| QuotaPeriod::Monthly.start(now)),
+        until: params.until.unwrap_or(now),
+    };
+
+    if query.since >= query.until {
+        return (StatusCode::BAD_REQUEST, "`since` must be before `until`").into_response();
+    }
+
+    match usage.ledger().summarize(&query).await {
+        Ok(rows) => Json(UsageResponse {
+            since: query.since,
+            until: query.until,
+            rows,
+        })
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "usage_summary_failed");
+            (StatusCode::BAD_GATEWAY, "Failed to read usage").into_response()
+        }
+    }
+}
+
+fn is_admin(usage: &UsageConfig, headers: &HeaderMap) -> bool {
+    let Some(expected) = &usage.admin_token else {
+        return false;
+    };
+
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .is_some_and(|token| constant_time_eq(token.as_bytes(), expected.as_bytes()))
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+
+use super::{LedgerFuture, UsageLedger, UsageQuery, UsageRecord, UsageSummary, UsageTotals};
+
+/// Keeps usage in process memory, for tests and single-instance setups that
+/// don't need it to survive a restart.
+#[derive(Default)]
+pub struct InMemoryLedger {
+    records: Mutex<Vec<UsageRecord>>,
+}
+
+impl InMemoryLedger {
+    pub fn records(&self) -> Vec<UsageRecord> {
+        self.records.lock().unwrap().clone()
+    }
+}
+
+impl UsageLedger for InMemoryLedger {
+    fn record(&self, record: UsageRecord) -> LedgerFuture<'_, ()> {
+        self.records.lock().unwrap().push(record);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn totals<'a>(
+        &'a self,
+        user_id: &'a str,
+        since: DateTime<Utc>,
+    ) -> LedgerFuture<'a, UsageTotals> {
+        let totals = self
+            .records
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.user_id == user_id && r.created_at >= since)
+            .fold(UsageTotals::default(), |mut totals, r| {
+                totals.requests += 1;
+                totals.input_tokens += r.input_tokens as u64;
+                totals.output_tokens += r.output_tokens as u64;
+                totals.cost_usd += r.cost_usd;
+                totals
+            });
+        Box::pin(async move { Ok(totals) })
+    }
+
+    fn summarize<'a>(&'a self, query: &'a UsageQuery) -> LedgerFuture<'a, Vec<UsageSummary>> {
+        let mut groups: BTreeMap<(String, String, String), UsageSummary> = BTreeMap::new();
+
+        for r in self.records.lock().unwrap().iter() {
+            if r.created_at < query.since
+                || r.created_at >= query.until
+                || query.user_id.as_ref().is_some_and(|id| *id != r.user_id)
+            {
+                continue;
+            }
+
+            let key = (
+                r.user_id.clone(),
+                r.task.map(|t| t.to_string()).unwrap_or_default(),
+                r.model.clone(),
+            );
+            let summary = groups.entry(key).or_insert_with(|| UsageSummary {
+                user_id: r.user_id.clone(),
+                task: r.task,
+                model: r.model.clone(),
+                requests: 0,
+                input_tokens: 0,
+                output_tokens: 0,
+                cost_usd: 0.0,
+            });
+            summary.requests += 1;
+            summary.input_tokens += r.input_tokens as u64;
+            summary.output_tokens += r.output_tokens as u64;
+            summary.cost_usd += r.cost_usd;
+        }
+
+        let summaries = groups.into_values().collect();
+        Box::pin(async move { Ok(summaries) })
+    }
+}
@@ -0,0 +1,326 @@
+mod admin;
+mod memory;
+mod quota;
+mod supabase;
+
+pub use admin::{UsageParams, UsageResponse, usage_router};
+pub use memory::InMemoryLedger;
+pub use quota::{QuotaExceeded, QuotaLimits, QuotaPeriod, QuotaUsage, Quotas};
+pub use supabase::SupabaseLedger;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::analytics::GenerationEvent;
+use crate::model::CharTask;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UsageError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("{0}")]
+    Api(String),
+}
+
+pub type LedgerFuture<'a, T> =
+    std::pin::Pin<Box<dyn std::future::Future<Output = Result<T, UsageError>> + Send + 'a>>;
+
+/// One completed generation, as stored in the ledger.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageRecord {
+    pub user_id: String,
+    pub task: Option<CharTask>,
+    pub model: String,
+    pub provider: String,
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub cost_usd: f64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+pub struct UsageTotals {
+    pub requests: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+}
+
+impl UsageTotals {
+    pub fn tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UsageQuery {
+    pub user_id: Option<String>,
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+}
+
+/// Usage in a time range, per user, task and model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct UsageSummary {
+    pub user_id: String,
+    pub task: Option<CharTask>,
+    pub model: String,
+    pub requests: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+}
+
+pub trait UsageLedger: Send + Sync {
+    fn record(&self, record: UsageRecord) -> LedgerFuture<'_, ()>;
+
+    fn totals<'a>(
+        &'a self,
+        user_id: &'a str,
+        since: DateTime<Utc>,
+    ) -> LedgerFuture<'a, UsageTotals>;
+
+    fn summarize<'a>(&'a self, query: &'a UsageQuery) -> LedgerFuture<'a, Vec<UsageSummary>>;
+}
+
+/// USD per million tokens, for providers that don't report what a
+/// generation cost.
+#[derive(Debug, Clone, Copy)]
+pub struct ModelPrice {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+}
+
+/// Records every generation with a known user and enforces [`Quotas`]
+/// before requests are forwarded.
+#[derive(Clone)]
+pub struct UsageConfig {
+    ledger: Arc<dyn UsageLedger>,
+    quotas: Quotas,
+    user_quotas: HashMap<String, Quotas>,
+    prices: HashMap<String, ModelPrice>,
+    pub(crate) admin_token: Option<String>,
+}
+
+impl UsageConfig {
+    pub fn new(ledger: Arc<dyn UsageLedger>) -> Self {
+        Self {
+            ledger,
+            quotas: Quotas::default(),
+            user_quotas: HashMap::new(),
+            prices: HashMap::new(),
+            admin_token: None,
+        }
+    }
+
+    pub fn with_quotas(mut self, quotas: Quotas) -> Self {
+        self.quotas = quotas;
+        self
+    }
+
+    pub fn with_user_quotas(mut self, user_id: impl Into<String>, quotas: Quotas) -> Self {
+        self.user_quotas.insert(user_id.into(), quotas);
+        self
+    }
+
+    pub fn with_model_price(
+        mut self,
+        model: impl Into<String>,
+        input_per_mtok: f64,
+        output_per_mtok: f64,
+    ) -> Self {
+        self.prices.insert(
+            model.into(),
+            ModelPrice {
+                input_per_mtok,
+                output_per_mtok,
+            },
+        );
+        self
+    }
+
+    /// Bearer token for [`usage_router`]. Without one the endpoint refuses
+    /// every request.
+    pub fn with_admin_token(mut self, token: impl Into<String>) -> Self {
+        self.admin_token = Some(token.into());
+        self
+    }
+
+    pub fn ledger(&self) -> &dyn UsageLedger {
+        &*self.ledger
+    }
+
+    pub fn quotas_for(&self, user_id: &str) -> &Quotas {
+        self.user_quotas.get(user_id).unwrap_or(&self.quotas)
+    }
+
+    /// Checks the user's quotas, returning what they have left to spend in
+    /// USD if a cost limit applies. Ledger failures let the request through
+    /// rather than locking everyone out.
+    pub async fn check(
+        &self,
+        user_id: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<f64>, QuotaExceeded> {
+        let quotas = self.quotas_for(user_id);
+        let mut remaining_usd: Option<f64> = None;
+
+        // Monthly first: if both are exhausted, the daily reset doesn't help.
+        for (period, limits) in [
+            (QuotaPeriod::Monthly, &quotas.monthly),
+            (QuotaPeriod::Daily, &quotas.daily),
+        ] {
+            if limits.is_unlimited() {
+                continue;
+            }
+
+            let totals = match self.ledger.totals(user_id, period.start(now)).await {
+                Ok(totals) => totals,
+                Err(e) => {
+                    tracing::warn!(error = %e, period = %period, "usage_totals_unavailable");
+                    continue;
+                }
+            };
+
+            if let Some(exceeded) = limits.exceeded(period, &totals, now) {
+                return Err(exceeded);
+            }
+
+            if let Some(limit) = limits.cost_usd {
+                let left = (limit - totals.cost_usd).max(0.0);
+                remaining_usd = Some(remaining_usd.map_or(left, |r| r.min(left)));
+            }
+        }
+
+        Ok(remaining_usd)
+    }
+
+    pub(crate) async fn record(&self, event: &GenerationEvent) {
+        let Some(user_id) = &event.user_id else {
+            return;
+        };
+
+        let record = UsageRecord {
+            user_id: user_id.clone(),
+            task: event.task,
+            model: event.model.clone(),
+            provider: event.provider_name.clone(),
+            input_tokens: event.input_tokens,
+            output_tokens: event.output_tokens,
+            cost_usd: event.total_cost.unwrap_or_else(|| {
+                self.estimate_cost(&event.model, event.input_tokens, event.output_tokens)
+            }),
+            created_at: Utc::now(),
+        };
+
+        if let Err(e) = self.ledger.record(record).await {
+            tracing::error!(error = %e, generation_id = %event.generation_id, "usage_record_failed");
+        }
+    }
+
+    /// Providers often report a dated or prefixed variant of the requested
+    /// model, so the longest configured prefix wins.
+    fn estimate_cost(&self, model: &str, input_tokens: u32, output_tokens: u32) -> f64 {
+        const PER_MTOK: f64 = 1_000_000.0;
+
+        self.prices
+            .iter()
+            .filter(|(key, _)| model.starts_with(key.as_str()))
+            .max_by_key(|(key, _)| key.len())
+            .map(|(_, price)| {
+                (input_tokens as f64 * price.input_per_mtok
+                    + output_tokens as f64 * price.output_per_mtok)
+                    / PER_MTOK
+            })
+            .unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(user_id: &str, tokens: u32, cost_usd: f64, created_at: DateTime<Utc>) -> UsageRecord {
+        UsageRecord {
+            user_id: user_id.into(),
+            task: Some(CharTask::Chat),
+            model: "openai/gpt-4.1-nano".into(),
+            provider: "openrouter".into(),
+            input_tokens: tokens,
+            output_tokens: 0,
+            cost_usd,
+            created_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn check_quotas() {
+        let now: DateTime<Utc> = "2026-03-15T12:00:00Z".parse().unwrap();
+        let ledger = Arc::new(InMemoryLedger::default());
+        ledger
+            .record(record(
+                "alice",
+                800,
+                1.5,
+                "2026-03-15T09:00:00Z".parse().unwrap(),
+            ))
+            .await
+            .unwrap();
+        ledger
+            .record(record(
+                "alice",
+                500,
+                2.0,
+                "2026-03-02T09:00:00Z".parse().unwrap(),
+            ))
+            .await
+            .unwrap();
+
+        let usage = UsageConfig::new(ledger).with_quotas(Quotas {
+            daily: QuotaLimits {
+                tokens: Some(1_000),
+                cost_usd: None,
+            },
+            monthly: QuotaLimits {
+                tokens: None,
+                cost_usd: Some(5.0),
+            },
+        });
+
+        assert_eq!(usage.check("alice", now).await, Ok(Some(1.5)));
+        assert_eq!(usage.check("bob", now).await, Ok(Some(5.0)));
+
+        let tight = usage.clone().with_user_quotas(
+            "alice",
+            Quotas {
+                daily: QuotaLimits {
+                    tokens: Some(800),
+                    cost_usd: None,
+                },
+                ..Default::default()
+            },
+        );
+        let exceeded = tight.check("alice", now).await.unwrap_err();
+        assert_eq!(exceeded.period, QuotaPeriod::Daily);
+        assert_eq!(exceeded.used.tokens, 800);
+        assert_eq!(
+            exceeded.resets_at,
+            "2026-03-16T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn estimate_cost_by_prefix() {
+        let usage = UsageConfig::new(Arc::new(InMemoryLedger::default()))
+            .with_model_price("claude-haiku-4-5", 1.0, 5.0)
+            .with_model_price("claude", 100.0, 100.0);
+
+        let cost = usage.estimate_cost("claude-haiku-4-5-20251001", 1_000_000, 100_000);
+        assert!((cost - 1.5).abs() < 1e-9);
+        assert_eq!(usage.estimate_cost("qwen3-8b", 1_000, 1_000), 0.0);
+    }
+}
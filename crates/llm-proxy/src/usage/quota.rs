@@ -0,0 +1,161 @@
+use axum::{
+    Json,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Datelike, Months, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::env::UsageEnv;
+
+use super::UsageTotals;
+
+/// Quota windows follow UTC calendar days and months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum QuotaPeriod {
+    Daily,
+    Monthly,
+}
+
+impl QuotaPeriod {
+    pub fn start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let today = now.date_naive();
+        let day = match self {
+            Self::Daily => today,
+            Self::Monthly => today.with_day(1).unwrap_or(today),
+        };
+        day.and_time(chrono::NaiveTime::MIN).and_utc()
+    }
+
+    pub fn resets_at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let start = self.start(now);
+        match self {
+            Self::Daily => start + TimeDelta::days(1),
+            Self::Monthly => start
+                .checked_add_months(Months::new(1))
+                .unwrap_or(start + TimeDelta::days(31)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+pub struct QuotaLimits {
+    /// Input plus output tokens.
+    pub tokens: Option<u64>,
+    pub cost_usd: Option<f64>,
+}
+
+impl QuotaLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.tokens.is_none() && self.cost_usd.is_none()
+    }
+
+    pub(crate) fn exceeded(
+        &self,
+        period: QuotaPeriod,
+        totals: &UsageTotals,
+        now: DateTime<Utc>,
+    ) -> Option<QuotaExceeded> {
+        let over_tokens = self.tokens.is_some_and(|limit| totals.tokens() >= limit);
+        let over_cost = self.cost_usd.is_some_and(|limit| totals.cost_usd >= limit);
+
+        (over_tokens || over_cost).then(|| QuotaExceeded {
+            period,
+            limit: self.clone(),
+            used: QuotaUsage {
+                tokens: totals.tokens(),
+                cost_usd: totals.cost_usd,
+            },
+            resets_at: period.resets_at(now),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Quotas {
+    pub daily: QuotaLimits,
+    pub monthly: QuotaLimits,
+}
+
+impl From<&UsageEnv> for Quotas {
+    fn from(env: &UsageEnv) -> Self {
+        Self {
+            daily: QuotaLimits {
+                tokens: env.llm_daily_token_limit,
+                cost_usd: env.llm_daily_cost_limit_usd,
+            },
+            monthly: QuotaLimits {
+                tokens: env.llm_monthly_token_limit,
+                cost_usd: env.llm_monthly_cost_limit_usd,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct QuotaUsage {
+    pub tokens: u64,
+    pub cost_usd: f64,
+}
+
+/// Returned as a 429 with a `Retry-After` header.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct QuotaExceeded {
+    pub period: QuotaPeriod,
+    pub limit: QuotaLimits,
+    pub used: QuotaUsage,
+    #[schema(value_type = String, format = DateTime)]
+    pub resets_at: DateTime<Utc>,
+}
+
+impl IntoResponse for QuotaExceeded {
+    fn into_response(self) -> Response {
+        let retry_after = (self.resets_at - Utc::now()).num_seconds().max(0);
+
+        tracing::info!(
+            period = %self.period,
+            resets_at = %self.resets_at,
+            "llm_quota_exceeded"
+        );
+
+        let body = serde_json::json!({
+            "error": {
+                "type": "quota_exceeded",
+                "message": format!("{} LLM quota exceeded", self.period),
+                "quota": self,
+            }
+        });
+
+        (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after.to_string())],
+            Json(body),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_bounds() {
+        let now: DateTime<Utc> = "2026-01-31T18:30:00Z".parse().unwrap();
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+
+        assert_eq!(QuotaPeriod::Daily.start(now), at("2026-01-31T00:00:00Z"));
+        assert_eq!(
+            QuotaPeriod::Daily.resets_at(now),
+            at("2026-02-01T00:00:00Z")
+        );
+        assert_eq!(QuotaPeriod::Monthly.start(now), at("2026-01-01T00:00:00Z"));
+        assert_eq!(
+            QuotaPeriod::Monthly.resets_at(now),
+            at("2026-02-01T00:00:00Z")
+        );
+    }
+}
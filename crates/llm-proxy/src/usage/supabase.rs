@@ -0,0 +1,125 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::{
+    LedgerFuture, UsageError, UsageLedger, UsageQuery, UsageRecord, UsageSummary, UsageTotals,
+};
+
+/// Stores usage in the `llm_usage` table and aggregates it with the
+/// `llm_usage_totals` / `llm_usage_summary` functions.
+#[derive(Clone)]
+pub struct SupabaseLedger {
+    client: reqwest::Client,
+    base_url: String,
+    service_role_key: String,
+}
+
+#[derive(Serialize)]
+struct TotalsParams<'a> {
+    p_user_id: &'a str,
+    p_since: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+struct SummaryParams<'a> {
+    p_user_id: Option<&'a str>,
+    p_since: DateTime<Utc>,
+    p_until: DateTime<Utc>,
+}
+
+impl SupabaseLedger {
+    pub fn new(supabase_url: &str, service_role_key: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: supabase_url.trim_end_matches('/').to_string(),
+            service_role_key: service_role_key.to_string(),
+        }
+    }
+
+    fn auth_headers(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        builder
+            .header("Authorization", format!("Bearer {}", self.service_role_key))
+            .header("apikey", &self.service_role_key)
+    }
+
+    async fn rpc<P: Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+        function: &str,
+        params: &P,
+    ) -> Result<T, UsageError> {
+        let url = format!("{}/rest/v1/rpc/{function}", self.base_url);
+        let response = self
+            .auth_headers(self.client.post(&url))
+            .header("Accept", "application/json")
+            .json(params)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(UsageError::Api(format!(
+                "failed to call {function}: {status} {body}"
+            )));
+        }
+
+        Ok(response.json().await?)
+    }
+}
+
+impl UsageLedger for SupabaseLedger {
+    fn record(&self, record: UsageRecord) -> LedgerFuture<'_, ()> {
+        Box::pin(async move {
+            let url = format!("{}/rest/v1/llm_usage", self.base_url);
+            let response = self
+                .auth_headers(self.client.post(&url))
+                .header("Prefer", "return=minimal")
+                .json(&record)
+                .send()
+                .await?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(UsageError::Api(format!(
+                    "failed to insert usage: {status} {body}"
+                )));
+            }
+
+            Ok(())
+        })
+    }
+
+    fn totals<'a>(
+        &'a self,
+        user_id: &'a str,
+        since: DateTime<Utc>,
+    ) -> LedgerFuture<'a, UsageTotals> {
+        Box::pin(async move {
+            let rows: Vec<UsageTotals> = self
+                .rpc(
+                    "llm_usage_totals",
+                    &TotalsParams {
+                        p_user_id: user_id,
+                        p_since: since,
+                    },
+                )
+                .await?;
+            Ok(rows.into_iter().next().unwrap_or_default())
+        })
+    }
+
+    fn summarize<'a>(&'a self, query: &'a UsageQuery) -> LedgerFuture<'a, Vec<UsageSummary>> {
+        Box::pin(async move {
+            self.rpc(
+                "llm_usage_summary",
+                &SummaryParams {
+                    p_user_id: query.user_id.as_deref(),
+                    p_since: query.since,
+                    p_until: query.until,
+                },
+            )
+            .await
+        })
+    }
+}
@@ -149,6 +149,300 @@ mod error_handling {
     }
 }
 
+mod routing {
+    use super::*;
+
+    use llm_proxy::provider::{AnthropicProvider, OpenAiCompatibleProvider, OpenRouterProvider};
+    use llm_proxy::{CharTask, ModelCandidate, RoutingResolver};
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_unavailable(server: &MockServer, expected: u64) {
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    fn config(
+        openrouter: &MockServer,
+        anthropic: &MockServer,
+        local: &MockServer,
+    ) -> LlmProxyConfig {
+        let resolver = RoutingResolver::default()
+            .with_candidate(ModelCandidate::new("openai/gpt-4.1-nano"))
+            .with_candidate(ModelCandidate::new("claude-haiku-4-5").on_provider("anthropic"))
+            .with_candidate(
+                ModelCandidate::new("qwen3-8b")
+                    .on_provider("self-hosted")
+                    .for_tasks(&[CharTask::Enhance]),
+            )
+            .pin_task(CharTask::Enhance, "self-hosted");
+
+        LlmProxyConfig::new("test-api-key")
+            .with_provider(Arc::new(OpenRouterProvider::new(openrouter.uri())))
+            .with_additional_provider(
+                Arc::new(AnthropicProvider::new(anthropic.uri())),
+                "anthropic-key",
+            )
+            .with_additional_provider(
+                Arc::new(OpenAiCompatibleProvider::new("self-hosted", local.uri())),
+                "",
+            )
+            .with_model_resolver(Arc::new(resolver))
+    }
+
+    #[tokio::test]
+    async fn falls_back_across_providers() {
+        let openrouter = MockServer::start().await;
+        let anthropic = MockServer::start().await;
+        let local = MockServer::start().await;
+        mount_unavailable(&openrouter, 1).await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(header("x-api-key", "anthropic-key"))
+            .and(body_partial_json(
+                serde_json::json!({"model": "claude-haiku-4-5"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "msg_123",
+                "model": "claude-haiku-4-5",
+                "content": [{"type": "text", "text": "hello"}],
+                "stop_reason": "end_turn",
+                "usage": {"input_tokens": 10, "output_tokens": 1}
+            })))
+            .expect(1)
+            .mount(&anthropic)
+            .await;
+
+        let response = router(config(&openrouter, &anthropic, &local))
+            .oneshot(build_request(simple_message("Hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_to_json(response).await;
+        assert_eq!(body["id"], "msg_123");
+        assert_eq!(body["choices"][0]["message"]["content"], "hello");
+        assert_eq!(body["choices"][0]["finish_reason"], "stop");
+        assert_eq!(body["usage"]["prompt_tokens"], 10);
+    }
+
+    #[tokio::test]
+    async fn pinned_task_stays_on_own_endpoint() {
+        let openrouter = MockServer::start().await;
+        let anthropic = MockServer::start().await;
+        let local = MockServer::start().await;
+        mount_unavailable(&openrouter, 0).await;
+        mount_unavailable(&anthropic, 0).await;
+        mount_unavailable(&local, 1).await;
+
+        let mut request = build_request(simple_message("Enhance these notes"));
+        request
+            .headers_mut()
+            .insert(llm_proxy::CHAR_TASK_HEADER, "enhance".parse().unwrap());
+
+        let response = router(config(&openrouter, &anthropic, &local))
+            .oneshot(request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status().as_u16(), 503);
+    }
+}
+
+mod usage {
+    use super::*;
+
+    use axum::body::Body;
+    use axum::http::Request;
+    use llm_proxy::{
+        AuthenticatedUserId, InMemoryLedger, QuotaLimits, Quotas, UsageConfig, usage_router,
+    };
+
+    fn with_user(mut request: Request<Body>, user_id: &str) -> Request<Body> {
+        request
+            .extensions_mut()
+            .insert(AuthenticatedUserId(user_id.to_string()));
+        request
+    }
+
+    async fn wait_for_records(ledger: &InMemoryLedger, count: usize) {
+        let start = std::time::Instant::now();
+        while ledger.records().len() < count {
+            assert!(
+                start.elapsed() < std::time::Duration::from_secs(10),
+                "timed out waiting for usage records"
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_requests_over_quota() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_json_response(completion_response(
+                "gen-test-123",
+                "openai/gpt-4.1-nano",
+                "hello",
+            ))
+            .await;
+
+        let ledger = Arc::new(InMemoryLedger::default());
+        let usage = UsageConfig::new(ledger.clone()).with_quotas(Quotas {
+            daily: QuotaLimits {
+                tokens: Some(11),
+                cost_usd: None,
+            },
+            ..Default::default()
+        });
+        let app = router(harness.config().with_usage(usage));
+
+        let response = app
+            .clone()
+            .oneshot(with_user(build_request(simple_message("Hello")), "alice"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        wait_for_records(&ledger, 1).await;
+        let record = &ledger.records()[0];
+        assert_eq!(record.user_id, "alice");
+        assert_eq!(record.input_tokens, 10);
+        assert_eq!(record.output_tokens, 1);
+
+        let response = app
+            .oneshot(with_user(build_request(simple_message("Hello")), "alice"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().contains_key("retry-after"));
+
+        let body = response_to_json(response).await;
+        assert_eq!(body["error"]["type"], "quota_exceeded");
+        assert_eq!(body["error"]["quota"]["period"], "daily");
+        assert_eq!(body["error"]["quota"]["used"]["tokens"], 11);
+    }
+
+    #[tokio::test]
+    async fn admin_endpoint_requires_token() {
+        let ledger = Arc::new(InMemoryLedger::default());
+        let usage = UsageConfig::new(ledger).with_admin_token("secret");
+        let app = usage_router(usage);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::get("/usage")
+                    .header("Authorization", "Bearer wrong")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .oneshot(
+                Request::get("/usage?user_id=alice")
+                    .header("Authorization", "Bearer secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_to_json(response).await;
+        assert_eq!(body["rows"], serde_json::json!([]));
+    }
+}
+
+mod cache {
+    use super::*;
+
+    use llm_proxy::{CACHE_STATUS_HEADER, CacheConfig};
+
+    #[tokio::test]
+    async fn replays_cached_completion() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_json_response(completion_response(
+                "gen-cache-1",
+                "openai/gpt-4.1-nano",
+                "hello",
+            ))
+            .await;
+
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_cache(CacheConfig::default()),
+        );
+
+        let response = app
+            .clone()
+            .oneshot(build_request(simple_message("Hello")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get(CACHE_STATUS_HEADER).unwrap(), "miss");
+
+        let response = app
+            .clone()
+            .oneshot(build_request(simple_message("Hello")))
+            .await
+            .unwrap();
+        assert_eq!(response.headers().get(CACHE_STATUS_HEADER).unwrap(), "hit");
+        let body = response_to_json(response).await;
+        assert_eq!(body["id"], "gen-cache-1");
+        assert_eq!(body["choices"][0]["message"]["content"], "hello");
+
+        let response = app
+            .oneshot(build_request(stream_request("Hello")))
+            .await
+            .unwrap();
+        assert_eq!(response.headers().get(CACHE_STATUS_HEADER).unwrap(), "hit");
+        assert_eq!(
+            response.headers().get("Content-Type").unwrap(),
+            "text/event-stream"
+        );
+        let body = response_to_string(response).await;
+        assert!(body.contains("\"content\":\"hello\""));
+        assert!(body.ends_with("data: [DONE]\n\n"));
+    }
+
+    #[tokio::test]
+    async fn no_cache_header_bypasses_cache() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_json_response(completion_response(
+                "gen-cache-2",
+                "openai/gpt-4.1-nano",
+                "hello",
+            ))
+            .await;
+
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_cache(CacheConfig::default()),
+        );
+
+        let mut request = build_request(simple_message("Hello"));
+        request
+            .headers_mut()
+            .insert("Cache-Control", "no-cache".parse().unwrap());
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(CACHE_STATUS_HEADER).is_none());
+    }
+}
+
 mod e2e {
     use super::*;
 
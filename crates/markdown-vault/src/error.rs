@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("vault not found: {0}")]
+    VaultNotFound(String),
+
+    #[error("failed to read {path}: {source}")]
+    ReadFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("failed to convert {path}: {message}")]
+    ConvertNote { path: String, message: String },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
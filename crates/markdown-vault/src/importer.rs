@@ -0,0 +1,460 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use hypr_importer_core::ir::{
+    Attachment, Collection, Human, Session, SessionParticipant, Tag, TagMapping,
+};
+
+use crate::error::{Error, Result};
+use crate::links::{LinkedFile, asset_url, inline_tags, rewrite_body, wikilink_targets};
+use crate::note::{Note, parse_note};
+
+const ID_PREFIX: &str = "markdown-vault";
+
+struct VaultFile {
+    relative_path: PathBuf,
+    note: Note,
+    modified_at: Option<String>,
+}
+
+/// Imports every Markdown note under `path` as a session. Notes marked as
+/// people (`type: person`, or tagged `person`) become humans instead, and are
+/// linked to the sessions that mention them in `[[wikilinks]]` or list them as
+/// attendees. Ids are derived from vault paths and names, so re-importing the
+/// same vault updates rows instead of duplicating them.
+///
+/// Embedded files are linked where the caller copies them:
+/// `sessions_dir/<session id>/attachments/<file name>`.
+pub fn import_all_from_path(path: &Path, sessions_dir: &Path) -> Result<Collection> {
+    if !path.is_dir() {
+        return Err(Error::VaultNotFound(path.display().to_string()));
+    }
+    // Attachments must resolve inside the vault, through symlinks and `..`.
+    let path = &path.canonicalize().map_err(|source| Error::ReadFile {
+        path: path.display().to_string(),
+        source,
+    })?;
+
+    let mut markdown_paths = Vec::new();
+    let mut files_by_name: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    walk(path, path, &mut markdown_paths, &mut files_by_name)?;
+    markdown_paths.sort();
+
+    let files = markdown_paths
+        .into_iter()
+        .map(|relative_path| read_note(path, relative_path))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut builder = CollectionBuilder::default();
+
+    let people: HashMap<String, &VaultFile> = files
+        .iter()
+        .filter(|file| file.note.is_person)
+        .map(|file| (file.note.title.to_lowercase(), file))
+        .collect();
+    for file in people.values() {
+        builder.human(
+            &file.note.title,
+            file.note.email.clone(),
+            file.note.created_at.clone(),
+        );
+    }
+
+    for file in files.iter().filter(|file| !file.note.is_person) {
+        let session_id = stable_id(&file.relative_path.to_string_lossy());
+        let note_dir = file.relative_path.parent().unwrap_or(Path::new(""));
+
+        let mut attendees: Vec<(String, Option<String>)> = file
+            .note
+            .attendees
+            .iter()
+            .map(|attendee| parse_attendee(attendee))
+            .collect();
+        attendees.extend(
+            wikilink_targets(&file.note.body)
+                .into_iter()
+                .filter_map(|target| {
+                    let name = target.rsplit('/').next().unwrap_or(&target);
+                    people
+                        .get(&name.to_lowercase())
+                        .map(|person| (person.note.title.clone(), person.note.email.clone()))
+                }),
+        );
+        for (name, email) in attendees {
+            let email = email.or_else(|| {
+                people
+                    .get(&name.to_lowercase())
+                    .and_then(|p| p.note.email.clone())
+            });
+            let human_id = builder.human(&name, email, None);
+            builder.participant(&session_id, &human_id);
+        }
+
+        let mut tags = file.note.tags.clone();
+        tags.extend(inline_tags(&file.note.body));
+        for tag in tags {
+            builder.tag(&session_id, &tag);
+        }
+
+        let body = rewrite_body(&file.note.body, |target| {
+            let source = resolve_attachment(path, note_dir, target, &files_by_name)?;
+            let name = builder.attachment(&session_id, source);
+            let url = asset_url(
+                &sessions_dir
+                    .join(&session_id)
+                    .join("attachments")
+                    .join(&name),
+            );
+            Some(LinkedFile { name, url })
+        });
+
+        builder.collection.sessions.push(Session {
+            id: session_id,
+            user_id: String::new(),
+            created_at: file
+                .note
+                .created_at
+                .clone()
+                .or_else(|| file.modified_at.clone())
+                .unwrap_or_default(),
+            title: file.note.title.clone(),
+            raw_md: Some(to_tiptap_json(&body).map_err(|message| Error::ConvertNote {
+                path: file.relative_path.display().to_string(),
+                message,
+            })?),
+            enhanced_content: None,
+            folder_id: None,
+            event_id: None,
+        });
+    }
+
+    Ok(builder.collection)
+}
+
+fn walk(
+    root: &Path,
+    dir: &Path,
+    markdown_paths: &mut Vec<PathBuf>,
+    files_by_name: &mut HashMap<String, Vec<PathBuf>>,
+) -> Result<()> {
+    let entries = std::fs::read_dir(dir).map_err(|source| Error::ReadFile {
+        path: dir.display().to_string(),
+        source,
+    })?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        // `.obsidian`, `.trash`, `.logseq`, ...
+        if name.starts_with('.') {
+            continue;
+        }
+
+        if path.is_dir() {
+            walk(root, &path, markdown_paths, files_by_name)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+        {
+            markdown_paths.push(path.strip_prefix(root).unwrap_or(&path).to_path_buf());
+        } else {
+            files_by_name
+                .entry(name.to_lowercase())
+                .or_default()
+                .push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn read_note(root: &Path, relative_path: PathBuf) -> Result<VaultFile> {
+    let path = root.join(&relative_path);
+    let content = std::fs::read_to_string(&path).map_err(|source| Error::ReadFile {
+        path: path.display().to_string(),
+        source,
+    })?;
+
+    let modified_at = std::fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339());
+
+    let stem = relative_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    Ok(VaultFile {
+        note: parse_note(&stem, &content),
+        relative_path,
+        modified_at,
+    })
+}
+
+/// Obsidian resolves embeds relative to the note, then the vault root, then by
+/// file name anywhere in the vault. Files outside the (canonical) `root` are
+/// never attached.
+fn resolve_attachment(
+    root: &Path,
+    note_dir: &Path,
+    target: &str,
+    files_by_name: &HashMap<String, Vec<PathBuf>>,
+) -> Option<PathBuf> {
+    let candidates = [root.join(note_dir).join(target), root.join(target)];
+    let found = match candidates.into_iter().find(|p| p.is_file()) {
+        Some(found) => found,
+        None => {
+            let name = Path::new(target)
+                .file_name()?
+                .to_string_lossy()
+                .to_lowercase();
+            files_by_name.get(&name)?.first().cloned()?
+        }
+    };
+
+    let found = found.canonicalize().ok()?;
+    found.starts_with(root).then_some(found)
+}
+
+/// Splits `Alice Smith <alice@example.com>`; a bare email is used as the name.
+fn parse_attendee(attendee: &str) -> (String, Option<String>) {
+    if let Some((name, rest)) = attendee.split_once('<')
+        && let Some(email) = rest.strip_suffix('>')
+    {
+        let name = name.trim();
+        let email = email.trim().to_string();
+        let name = if name.is_empty() {
+            email.clone()
+        } else {
+            name.to_string()
+        };
+        return (name, Some(email));
+    }
+
+    let attendee = attendee.trim();
+    if attendee.contains('@') && !attendee.contains(char::is_whitespace) {
+        return (attendee.to_string(), Some(attendee.to_string()));
+    }
+    (attendee.to_string(), None)
+}
+
+fn to_tiptap_json(markdown: &str) -> std::result::Result<String, String> {
+    hypr_tiptap::md_to_tiptap_json(markdown).map(|json| json.to_string())
+}
+
+fn stable_id(key: &str) -> String {
+    uuid::Uuid::new_v5(
+        &uuid::Uuid::NAMESPACE_OID,
+        format!("{ID_PREFIX}:{key}").as_bytes(),
+    )
+    .to_string()
+}
+
+#[derive(Default)]
+struct CollectionBuilder {
+    collection: Collection,
+    human_ids: HashSet<String>,
+    tag_ids: HashSet<String>,
+    mapping_ids: HashSet<String>,
+    attachment_names: HashMap<(String, PathBuf), String>,
+}
+
+impl CollectionBuilder {
+    fn human(&mut self, name: &str, email: Option<String>, created_at: Option<String>) -> String {
+        let id = stable_id(&format!("human:{}", name.to_lowercase()));
+
+        if self.human_ids.insert(id.clone()) {
+            self.collection.humans.push(Human {
+                id: id.clone(),
+                user_id: String::new(),
+                created_at: created_at.unwrap_or_default(),
+                name: name.to_string(),
+                email,
+                org_id: None,
+                job_title: None,
+                linkedin_username: None,
+            });
+        }
+
+        id
+    }
+
+    fn participant(&mut self, session_id: &str, human_id: &str) {
+        let id = format!("{}_{}", session_id, human_id);
+        if self.mapping_ids.insert(id.clone()) {
+            self.collection.participants.push(SessionParticipant {
+                id,
+                user_id: String::new(),
+                session_id: session_id.to_string(),
+                human_id: human_id.to_string(),
+                source: "imported".to_string(),
+            });
+        }
+    }
+
+    fn tag(&mut self, session_id: &str, name: &str) {
+        let tag_id = stable_id(&format!("tag:{}", name.to_lowercase()));
+        if self.tag_ids.insert(tag_id.clone()) {
+            self.collection.tags.push(Tag {
+                id: tag_id.clone(),
+                user_id: String::new(),
+                name: name.to_string(),
+            });
+        }
+
+        let id = format!("{}_{}", tag_id, session_id);
+        if self.mapping_ids.insert(id.clone()) {
+            self.collection.tag_mappings.push(TagMapping {
+                id,
+                user_id: String::new(),
+                tag_id,
+                session_id: session_id.to_string(),
+            });
+        }
+    }
+
+    /// Registers `source` as an attachment of the session and returns its file
+    /// name there, numbering files that would otherwise collide.
+    fn attachment(&mut self, session_id: &str, source: PathBuf) -> String {
+        let key = (session_id.to_string(), source.clone());
+        if let Some(file_name) = self.attachment_names.get(&key) {
+            return file_name.clone();
+        }
+
+        let stem = source
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let extension = source
+            .extension()
+            .map(|e| format!(".{}", e.to_string_lossy()))
+            .unwrap_or_default();
+
+        let taken = |name: &str| {
+            self.collection
+                .attachments
+                .iter()
+                .any(|a| a.session_id == session_id && a.file_name == name)
+        };
+        let mut file_name = format!("{stem}{extension}");
+        let mut n = 1;
+        while taken(&file_name) {
+            n += 1;
+            file_name = format!("{stem} {n}{extension}");
+        }
+
+        self.collection.attachments.push(Attachment {
+            id: stable_id(&format!("attachment:{session_id}:{}", source.display())),
+            session_id: session_id.to_string(),
+            file_name: file_name.clone(),
+            source_path: source.to_string_lossy().to_string(),
+        });
+        self.attachment_names.insert(key, file_name.clone());
+        file_name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn imports_obsidian_vault() {
+        let vault = tempfile::tempdir().unwrap();
+        let root = vault.path();
+
+        write(
+            root,
+            "People/Alice Smith.md",
+            "---\ntags: [person]\nemail: alice@example.com\n---\n",
+        );
+        write(
+            root,
+            "Meetings/2024-03-01 Kickoff.md",
+            "---\ndate: 2024-03-01 10:00\ntags: customer\nattendees: [\"Bob <bob@example.com>\"]\n---\n\nWith [[Alice Smith]]. #followup\n\n![[whiteboard.png]]\n![[whiteboard.png]]",
+        );
+        write(root, "Meetings/Retro.md", "# Retro\n\nNo tags here.");
+        write(root, "assets/whiteboard.png", "png");
+        write(root, ".obsidian/workspace.md", "ignored");
+
+        let sessions_dir = Path::new("/app/sessions");
+        let collection = import_all_from_path(root, sessions_dir).unwrap();
+
+        assert_eq!(collection.sessions.len(), 2);
+        let kickoff = collection
+            .sessions
+            .iter()
+            .find(|s| s.title == "2024-03-01 Kickoff")
+            .unwrap();
+        assert_eq!(kickoff.created_at, "2024-03-01T10:00:00+00:00");
+        let attachment_url = asset_url(
+            &sessions_dir
+                .join(&kickoff.id)
+                .join("attachments")
+                .join("whiteboard.png"),
+        );
+        assert!(kickoff.raw_md.as_ref().unwrap().contains(&attachment_url));
+
+        let mut names: Vec<_> = collection.humans.iter().map(|h| h.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["Alice Smith", "Bob"]);
+        assert_eq!(
+            collection
+                .participants
+                .iter()
+                .filter(|p| p.session_id == kickoff.id)
+                .count(),
+            2
+        );
+
+        let mut tags: Vec<_> = collection.tags.iter().map(|t| t.name.as_str()).collect();
+        tags.sort();
+        assert_eq!(tags, vec!["customer", "followup"]);
+
+        assert_eq!(collection.attachments.len(), 1);
+        assert_eq!(collection.attachments[0].file_name, "whiteboard.png");
+        assert!(
+            collection.attachments[0]
+                .source_path
+                .ends_with("whiteboard.png")
+        );
+
+        let again = import_all_from_path(root, sessions_dir).unwrap();
+        assert_eq!(again.sessions[0].id, collection.sessions[0].id);
+    }
+
+    #[test]
+    fn ignores_files_outside_the_vault() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("vault");
+
+        write(dir.path(), "secret.png", "png");
+        write(&root, "Note.md", "![x](../secret.png)\n![[../secret.png]]");
+
+        let collection = import_all_from_path(&root, Path::new("/app/sessions")).unwrap();
+
+        assert!(collection.attachments.is_empty());
+        assert!(
+            collection.sessions[0]
+                .raw_md
+                .as_ref()
+                .unwrap()
+                .contains("../secret.png")
+        );
+    }
+
+    #[test]
+    fn missing_vault() {
+        let result =
+            import_all_from_path(Path::new("/nonexistent/vault"), Path::new("/app/sessions"));
+        assert!(matches!(result, Err(Error::VaultNotFound(_))));
+    }
+}
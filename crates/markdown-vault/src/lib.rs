@@ -0,0 +1,7 @@
+mod error;
+mod links;
+mod note;
+
+pub mod importer;
+
+pub use error::{Error, Result};
@@ -0,0 +1,198 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+// `[[target#heading|alias]]` / `![[embed]]`, or a Markdown image `![alt](src)`.
+static LINK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?P<bang>!?)\[\[(?P<target>[^\]|#]*)(?:#[^\]|]*)?(?:\|(?P<alias>[^\]]*))?\]\]",
+        r"|!\[(?P<alt>[^\]]*)\]\(<?(?P<src>[^)>]+?)>?\)",
+    ))
+    .unwrap()
+});
+static INLINE_TAG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[\s(])#([\p{L}\p{N}_/-]*[\p{L}_/-][\p{L}\p{N}_/-]*)").unwrap()
+});
+
+/// Targets of `[[wikilinks]]` and `![[embeds]]`, without heading or alias.
+pub(crate) fn wikilink_targets(body: &str) -> Vec<String> {
+    LINK.captures_iter(body)
+        .filter_map(|caps| caps.name("target"))
+        .map(|target| target.as_str().trim().to_string())
+        .filter(|target| !target.is_empty())
+        .collect()
+}
+
+/// `#tags` outside of fenced code blocks.
+pub(crate) fn inline_tags(body: &str) -> Vec<String> {
+    let mut in_code = false;
+    let mut tags = Vec::new();
+
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        tags.extend(
+            INLINE_TAG
+                .captures_iter(line)
+                .map(|caps| caps[1].to_string()),
+        );
+    }
+
+    tags
+}
+
+/// An embedded file as the imported note links to it.
+pub(crate) struct LinkedFile {
+    pub name: String,
+    pub url: String,
+}
+
+/// Turns vault-specific syntax into plain Markdown. Links become their display
+/// text; embedded files and local images are passed to `attach`, which returns
+/// the file to link to, or `None` to leave them untouched.
+pub(crate) fn rewrite_body(
+    body: &str,
+    mut attach: impl FnMut(&str) -> Option<LinkedFile>,
+) -> String {
+    LINK.replace_all(body, |caps: &Captures| {
+        let Some(target) = caps.name("target").map(|m| m.as_str().trim()) else {
+            return rewrite_image(caps, &mut attach);
+        };
+
+        if !caps["bang"].is_empty() && is_file(target) {
+            return match attach(target) {
+                Some(file) => image_or_link(&file),
+                None => caps[0].to_string(),
+            };
+        }
+
+        match caps.name("alias").map(|alias| alias.as_str().trim()) {
+            Some(alias) if !alias.is_empty() => alias.to_string(),
+            _ => display_name(target).to_string(),
+        }
+    })
+    .into_owned()
+}
+
+fn rewrite_image(caps: &Captures, attach: &mut impl FnMut(&str) -> Option<LinkedFile>) -> String {
+    let src = caps["src"].trim();
+    if src.contains("://") || src.starts_with("data:") {
+        return caps[0].to_string();
+    }
+
+    match attach(&src.replace("%20", " ")) {
+        Some(file) => format!("![{}]({})", &caps["alt"], file.url),
+        None => caps[0].to_string(),
+    }
+}
+
+fn is_file(target: &str) -> bool {
+    std::path::Path::new(target)
+        .extension()
+        .is_some_and(|ext| !ext.eq_ignore_ascii_case("md"))
+}
+
+fn display_name(target: &str) -> &str {
+    target.rsplit('/').next().unwrap_or(target)
+}
+
+/// The URL the webview loads `path` from, matching Tauri's `convertFileSrc`.
+pub(crate) fn asset_url(path: &std::path::Path) -> String {
+    let encoded: String = path
+        .to_string_lossy()
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'!'
+            | b'~'
+            | b'*'
+            | b'\''
+            | b'('
+            | b')' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect();
+
+    if cfg!(windows) {
+        format!("http://asset.localhost/{encoded}")
+    } else {
+        format!("asset://localhost/{encoded}")
+    }
+}
+
+fn image_or_link(file: &LinkedFile) -> String {
+    const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp", "svg", "bmp"];
+
+    let is_image = std::path::Path::new(&file.name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()));
+
+    if is_image {
+        format!("![{}]({})", file.name, file.url)
+    } else {
+        format!("[{}]({})", file.name, file.url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrites_links_and_embeds() {
+        let body = "Met [[People/Alice Smith|Alice]] and [[Bob#Notes]].\n![[diagram.png|300]]\n![[Other note]]\n![chart](assets/my%20chart.png) ![remote](https://example.com/a.png)";
+
+        let mut attached = vec![];
+        let out = rewrite_body(body, |target| {
+            attached.push(target.to_string());
+            let name = display_name(target).to_string();
+            Some(LinkedFile {
+                url: format!("files/{}", name.replace(' ', "%20")),
+                name,
+            })
+        });
+
+        assert_eq!(
+            out,
+            "Met Alice and Bob.\n![diagram.png](files/diagram.png)\nOther note\n![chart](files/my%20chart.png) ![remote](https://example.com/a.png)"
+        );
+        assert_eq!(attached, vec!["diagram.png", "assets/my chart.png"]);
+    }
+
+    #[test]
+    fn keeps_unresolved_embeds() {
+        let out = rewrite_body("![[missing.pdf]]", |_| None);
+        assert_eq!(out, "![[missing.pdf]]");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn asset_url_matches_convert_file_src() {
+        assert_eq!(
+            asset_url(std::path::Path::new(
+                "/vault/sessions/a b/attachments/c(1).png"
+            )),
+            "asset://localhost/%2Fvault%2Fsessions%2Fa%20b%2Fattachments%2Fc(1).png"
+        );
+    }
+
+    #[test]
+    fn finds_targets_and_tags() {
+        let body =
+            "# Heading\n[[Alice]] #customer and #q1-2024\n```\n#not-a-tag\n```\ncolor#fff #123";
+
+        assert_eq!(wikilink_targets(body), vec!["Alice"]);
+        assert_eq!(inline_tags(body), vec!["customer", "q1-2024"]);
+    }
+}
@@ -0,0 +1,246 @@
+use std::str::FromStr;
+
+use hypr_frontmatter::Document;
+use serde_yaml::{Mapping, Value};
+
+const TITLE_KEYS: &[&str] = &["title"];
+const DATE_KEYS: &[&str] = &["date", "created", "created_at", "createdAt", "created time"];
+const TAG_KEYS: &[&str] = &["tags", "tag"];
+const ATTENDEE_KEYS: &[&str] = &["attendees", "participants", "people", "attendee"];
+const TYPE_KEYS: &[&str] = &["type", "category"];
+const PERSON_MARKERS: &[&str] = &["person", "people", "contact"];
+
+/// A Markdown note after frontmatter (YAML, or Logseq `key:: value`
+/// properties) has been separated from the body.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Note {
+    pub title: String,
+    pub created_at: Option<String>,
+    pub tags: Vec<String>,
+    pub attendees: Vec<String>,
+    pub email: Option<String>,
+    pub is_person: bool,
+    pub body: String,
+}
+
+pub(crate) fn parse_note(stem: &str, source: &str) -> Note {
+    let (properties, body) = match Document::<Mapping>::from_str(source) {
+        Ok(doc) => (doc.frontmatter, doc.content),
+        Err(_) => split_logseq_properties(source),
+    };
+
+    let mut tags = string_list(get(&properties, TAG_KEYS));
+    let kind = get(&properties, TYPE_KEYS)
+        .and_then(Value::as_str)
+        .map(str::to_lowercase);
+    let is_person = kind.is_some_and(|k| PERSON_MARKERS.contains(&k.as_str()))
+        || tags
+            .iter()
+            .any(|t| PERSON_MARKERS.contains(&t.to_lowercase().as_str()));
+    tags.retain(|t| !PERSON_MARKERS.contains(&t.to_lowercase().as_str()));
+
+    let (title, body) = match get(&properties, TITLE_KEYS).and_then(Value::as_str) {
+        Some(title) if !title.trim().is_empty() => (title.trim().to_string(), body),
+        _ => title_from_body(&body).unwrap_or_else(|| (title_from_stem(stem), body)),
+    };
+
+    let created_at = get(&properties, DATE_KEYS)
+        .and_then(Value::as_str)
+        .and_then(parse_date)
+        .or_else(|| parse_date(stem.get(..10).unwrap_or(stem)));
+
+    Note {
+        title,
+        created_at,
+        tags,
+        attendees: string_list(get(&properties, ATTENDEE_KEYS)),
+        email: get(&properties, &["email"])
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        is_person,
+        body: body.trim().to_string(),
+    }
+}
+
+fn get<'a>(properties: &'a Mapping, keys: &[&str]) -> Option<&'a Value> {
+    properties.iter().find_map(|(key, value)| {
+        let key = key.as_str()?;
+        keys.iter()
+            .any(|k| k.eq_ignore_ascii_case(key))
+            .then_some(value)
+    })
+}
+
+/// Accepts YAML lists, comma-separated strings, `#tags` and `[[links]]`.
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    let mut items = Vec::new();
+    if let Some(value) = value {
+        collect_strings(value, &mut items);
+    }
+
+    items
+        .iter()
+        .map(|item| {
+            item.trim()
+                .trim_start_matches('#')
+                .trim_start_matches("[[")
+                .trim_end_matches("]]")
+                .trim()
+                .to_string()
+        })
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+// Unquoted `[[Alice]]` in YAML parses as a nested list, so recurse.
+fn collect_strings(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(s) => out.extend(s.split(',').map(str::to_string)),
+        Value::Sequence(seq) => seq.iter().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+/// Logseq pages start with `key:: value` lines instead of YAML frontmatter.
+fn split_logseq_properties(source: &str) -> (Mapping, String) {
+    let mut properties = Mapping::new();
+    let mut lines = source.lines().peekable();
+
+    while let Some(line) = lines.peek() {
+        let Some((key, value)) = line.trim_start_matches("- ").split_once(":: ") else {
+            break;
+        };
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            break;
+        }
+        properties.insert(
+            Value::String(key.trim().to_string()),
+            Value::String(value.trim().to_string()),
+        );
+        lines.next();
+    }
+
+    (properties, lines.collect::<Vec<_>>().join("\n"))
+}
+
+/// Uses a leading `# Heading` as the title, dropping it from the body so it
+/// isn't shown twice. Notion exports always start with one.
+fn title_from_body(body: &str) -> Option<(String, String)> {
+    let trimmed = body.trim_start();
+    let first_line = trimmed.lines().next()?;
+    let title = first_line.strip_prefix("# ")?.trim();
+    if title.is_empty() {
+        return None;
+    }
+
+    let rest = trimmed[first_line.len()..].to_string();
+    Some((title.to_string(), rest))
+}
+
+/// Notion appends a 32-character hex id to exported file names.
+fn title_from_stem(stem: &str) -> String {
+    match stem.rsplit_once(' ') {
+        Some((title, id)) if id.len() == 32 && id.chars().all(|c| c.is_ascii_hexdigit()) => {
+            title.to_string()
+        }
+        _ => stem.to_string(),
+    }
+}
+
+pub(crate) fn parse_date(s: &str) -> Option<String> {
+    let s = s.trim();
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Some(dt.to_rfc3339());
+    }
+
+    const DATETIME_FORMATS: &[&str] = &[
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+        "%B %d, %Y %I:%M %p",
+    ];
+    for format in DATETIME_FORMATS {
+        if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(s, format) {
+            return Some(naive.and_utc().to_rfc3339());
+        }
+    }
+
+    const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%B %d, %Y"];
+    for format in DATE_FORMATS {
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(s, format) {
+            return Some(date.and_hms_opt(0, 0, 0)?.and_utc().to_rfc3339());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn obsidian_frontmatter() {
+        let note = parse_note(
+            "weekly",
+            "---\ntitle: Weekly sync\ndate: 2024-03-01\ntags: [customer, \"#sales\"]\nattendees:\n  - \"[[Alice Smith]]\"\n  - Bob\n---\n\nNotes here.",
+        );
+
+        assert_eq!(note.title, "Weekly sync");
+        assert_eq!(
+            note.created_at.as_deref(),
+            Some("2024-03-01T00:00:00+00:00")
+        );
+        assert_eq!(note.tags, vec!["customer", "sales"]);
+        assert_eq!(note.attendees, vec!["Alice Smith", "Bob"]);
+        assert_eq!(note.body, "Notes here.");
+        assert!(!note.is_person);
+    }
+
+    #[test]
+    fn person_note() {
+        let note = parse_note(
+            "Alice Smith",
+            "---\ntype: person\nemail: alice@example.com\n---\n\nLikes tea.",
+        );
+
+        assert!(note.is_person);
+        assert_eq!(note.title, "Alice Smith");
+        assert_eq!(note.email.as_deref(), Some("alice@example.com"));
+    }
+
+    #[test]
+    fn logseq_properties() {
+        let note = parse_note(
+            "standup",
+            "title:: Standup\ntags:: [[eng]], [[daily]]\n\n- discussed release",
+        );
+
+        assert_eq!(note.title, "Standup");
+        assert_eq!(note.tags, vec!["eng", "daily"]);
+        assert_eq!(note.body, "- discussed release");
+    }
+
+    #[test]
+    fn notion_export() {
+        let note = parse_note(
+            "Kickoff 0123456789abcdef0123456789abcdef",
+            "# Kickoff\n\nCreated: March 5, 2024 3:00 PM\n\nAgenda",
+        );
+
+        assert_eq!(note.title, "Kickoff");
+        assert!(note.body.starts_with("Created:"));
+    }
+
+    #[test]
+    fn daily_note_date_from_file_name() {
+        let note = parse_note("2024-02-29", "Just text");
+        assert_eq!(note.title, "2024-02-29");
+        assert_eq!(
+            note.created_at.as_deref(),
+            Some("2024-02-29T00:00:00+00:00")
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use rmcp::{
-    ServerHandler,
-    transport::streamable_http_server::{
-        StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
+    ServerHandler, ServiceExt,
+    transport::{
+        stdio,
+        streamable_http_server::{
+            StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
+        },
     },
 };
 
@@ -16,3 +19,18 @@ where
         StreamableHttpServerConfig::default(),
     )
 }
+
+/// Serves over stdin/stdout until the client disconnects.
+pub async fn serve_stdio<S>(server: S) -> Result<(), std::io::Error>
+where
+    S: ServerHandler + Send + 'static,
+{
+    server
+        .serve(stdio())
+        .await
+        .map_err(std::io::Error::other)?
+        .waiting()
+        .await
+        .map_err(std::io::Error::other)?;
+    Ok(())
+}
@@ -0,0 +1,721 @@
+use std::fmt::Write as _;
+use std::io::Write as _;
+
+use markdown::mdast::{self, AlignKind, Node};
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use crate::image::{Image, ImageFormat};
+use crate::{Document, LINK_PROTOCOLS, Result, format_timestamp, safe_url};
+
+const RELATIONSHIP_IMAGE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
+const RELATIONSHIP_HYPERLINK: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+
+/// Usable width of a Letter/A4 page with 1" margins.
+const TEXT_WIDTH_TWIPS: u32 = 9360;
+const TEXT_WIDTH_EMU: u64 = 5_943_600;
+const EMU_PER_PIXEL: u64 = 9525;
+/// One level of list indentation.
+const INDENT_TWIPS: u32 = 720;
+
+const BULLET_NUM_ID: u32 = 1;
+/// Ordered lists get their own numbering instance from here on, so each one
+/// restarts at its own start value.
+const FIRST_ORDERED_NUM_ID: u32 = 2;
+
+pub(crate) fn render(doc: &Document) -> Result<Vec<u8>> {
+    let mut writer = Writer::new(doc);
+    writer.document();
+
+    let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let parts = [
+        ("[Content_Types].xml", content_types()),
+        ("_rels/.rels", ROOT_RELS.to_string()),
+        ("docProps/core.xml", core_properties(&doc.title)),
+        ("word/document.xml", writer.document_xml()),
+        ("word/_rels/document.xml.rels", writer.relationships_xml()),
+        ("word/styles.xml", STYLES.to_string()),
+        ("word/numbering.xml", writer.numbering_xml()),
+    ];
+    for (name, content) in parts {
+        zip.start_file(name, options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+    for (name, bytes) in &writer.media {
+        zip.start_file(format!("word/media/{name}"), options)?;
+        zip.write_all(bytes)?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct BlockContext {
+    style: Option<&'static str>,
+    /// Nesting level inside lists, starting at 0 for a top-level item.
+    list_level: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct RunFormat {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+    link: bool,
+}
+
+struct Relationship {
+    id: String,
+    kind: &'static str,
+    target: String,
+    external: bool,
+}
+
+struct Writer<'a> {
+    doc: &'a Document,
+    body: String,
+    relationships: Vec<Relationship>,
+    media: Vec<(String, Vec<u8>)>,
+    /// `(level, start)` of every ordered list, in numbering order.
+    ordered_lists: Vec<(u32, u32)>,
+    drawings: u32,
+}
+
+impl<'a> Writer<'a> {
+    fn new(doc: &'a Document) -> Self {
+        Self {
+            doc,
+            body: String::new(),
+            relationships: Vec::new(),
+            media: Vec::new(),
+            ordered_lists: Vec::new(),
+            drawings: 0,
+        }
+    }
+
+    fn document(&mut self) {
+        let doc = self.doc;
+        self.text_paragraph("Title", &doc.title);
+
+        let metadata = &doc.metadata;
+        let when: Vec<&str> = [metadata.created_at.as_deref(), metadata.duration.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect();
+        if !when.is_empty() {
+            self.text_paragraph("Meta", &when.join(" · "));
+        }
+        if let Some(event_title) = &metadata.event_title {
+            self.text_paragraph("Meta", event_title);
+        }
+        if !metadata.participants.is_empty() {
+            self.text_paragraph(
+                "Meta",
+                &format!("Participants: {}", metadata.participants.join(", ")),
+            );
+        }
+
+        for note in &doc.notes {
+            if let Some(title) = &note.title {
+                self.text_paragraph("Heading1", title);
+            }
+            self.block(&note.body, BlockContext::default());
+        }
+
+        if !doc.transcript.is_empty() {
+            self.text_paragraph("Heading1", "Transcript");
+            let colors = doc.speaker_colors();
+            for segment in &doc.transcript {
+                let speaker = segment.speaker.as_deref();
+                let color = colors[&speaker].trim_start_matches('#').to_uppercase();
+                let _ = write!(
+                    self.body,
+                    "<w:p><w:pPr><w:keepLines/></w:pPr><w:r><w:rPr><w:b/><w:color w:val=\"{color}\"/></w:rPr><w:t xml:space=\"preserve\">{}</w:t></w:r>",
+                    escape(speaker.unwrap_or("Unknown"))
+                );
+                if let Some(start_ms) = segment.start_ms {
+                    let _ = write!(
+                        self.body,
+                        "<w:r><w:rPr><w:color w:val=\"6B7280\"/><w:sz w:val=\"18\"/></w:rPr><w:t xml:space=\"preserve\">  {}</w:t></w:r>",
+                        format_timestamp(start_ms)
+                    );
+                }
+                let _ = write!(
+                    self.body,
+                    "<w:r><w:br/><w:t xml:space=\"preserve\">{}</w:t></w:r></w:p>",
+                    escape(&segment.text)
+                );
+            }
+        }
+    }
+
+    fn text_paragraph(&mut self, style: &str, text: &str) {
+        let _ = write!(
+            self.body,
+            "<w:p><w:pPr><w:pStyle w:val=\"{style}\"/></w:pPr><w:r><w:t xml:space=\"preserve\">{}</w:t></w:r></w:p>",
+            escape(text)
+        );
+    }
+
+    fn block(&mut self, node: &Node, context: BlockContext) {
+        match node {
+            Node::Root(root) => {
+                for child in &root.children {
+                    self.block(child, context);
+                }
+            }
+            Node::Paragraph(paragraph) => {
+                self.paragraph(context, None, "", &paragraph.children);
+            }
+            Node::Heading(heading) => {
+                let style = match heading.depth {
+                    1 => "Heading1",
+                    2 => "Heading2",
+                    3 => "Heading3",
+                    4 => "Heading4",
+                    5 => "Heading5",
+                    _ => "Heading6",
+                };
+                let context = BlockContext {
+                    style: Some(style),
+                    ..context
+                };
+                self.paragraph(context, None, "", &heading.children);
+            }
+            Node::List(list) => self.list(list, context),
+            Node::Blockquote(quote) => {
+                let context = BlockContext {
+                    style: Some("Quote"),
+                    ..context
+                };
+                for child in &quote.children {
+                    self.block(child, context);
+                }
+            }
+            Node::Code(code) => {
+                let lines: Vec<String> = code
+                    .value
+                    .lines()
+                    .map(|line| format!("<w:t xml:space=\"preserve\">{}</w:t>", escape(line)))
+                    .collect();
+                let _ = write!(
+                    self.body,
+                    "<w:p>{}<w:r>{}</w:r></w:p>",
+                    paragraph_properties(
+                        BlockContext {
+                            style: Some("Code"),
+                            ..context
+                        },
+                        None
+                    ),
+                    lines.join("<w:br/>")
+                );
+            }
+            Node::ThematicBreak(_) => {
+                self.body.push_str(
+                    "<w:p><w:pPr><w:pBdr><w:bottom w:val=\"single\" w:sz=\"6\" w:space=\"1\" w:color=\"D1D5DB\"/></w:pBdr></w:pPr></w:p>",
+                );
+            }
+            Node::Table(table) => self.table(table),
+            node => self.paragraph(context, None, "", std::slice::from_ref(node)),
+        }
+    }
+
+    /// `numbering` is `(num_id, level)` for the first paragraph of a list
+    /// item; `prefix` is prepended as plain text (task checkboxes).
+    fn paragraph(
+        &mut self,
+        context: BlockContext,
+        numbering: Option<(u32, u32)>,
+        prefix: &str,
+        children: &[Node],
+    ) {
+        let mut runs = String::new();
+        if !prefix.is_empty() {
+            runs.push_str(&text_run(prefix, RunFormat::default()));
+        }
+        runs.push_str(&self.runs(children, RunFormat::default()));
+
+        let _ = write!(
+            self.body,
+            "<w:p>{}{runs}</w:p>",
+            paragraph_properties(context, numbering)
+        );
+    }
+
+    fn list(&mut self, list: &mdast::List, context: BlockContext) {
+        let level = context.list_level.map_or(0, |level| level + 1);
+        let num_id = if list.ordered {
+            self.ordered_lists.push((level, list.start.unwrap_or(1)));
+            FIRST_ORDERED_NUM_ID + self.ordered_lists.len() as u32 - 1
+        } else {
+            BULLET_NUM_ID
+        };
+        let item_context = BlockContext {
+            style: context.style,
+            list_level: Some(level),
+        };
+
+        for item in &list.children {
+            let Node::ListItem(item) = item else {
+                continue;
+            };
+
+            // Task items show a checkbox in place of the bullet.
+            let (numbering, prefix) = match item.checked {
+                Some(true) => (None, "☒ "),
+                Some(false) => (None, "☐ "),
+                None => (Some((num_id, level)), ""),
+            };
+
+            let mut first = true;
+            for child in &item.children {
+                match child {
+                    Node::Paragraph(paragraph) if first => {
+                        self.paragraph(item_context, numbering, prefix, &paragraph.children);
+                    }
+                    child => {
+                        if first {
+                            self.paragraph(item_context, numbering, prefix, &[]);
+                        }
+                        self.block(child, item_context);
+                    }
+                }
+                first = false;
+            }
+        }
+    }
+
+    fn table(&mut self, table: &mdast::Table) {
+        let columns = table
+            .children
+            .iter()
+            .map(|row| row.children().map_or(0, Vec::len))
+            .max()
+            .unwrap_or(0)
+            .max(table.align.len());
+        if columns == 0 {
+            return;
+        }
+
+        let column_width = TEXT_WIDTH_TWIPS / columns as u32;
+        self.body.push_str("<w:tbl><w:tblPr><w:tblStyle w:val=\"TableGrid\"/><w:tblW w:w=\"5000\" w:type=\"pct\"/></w:tblPr><w:tblGrid>");
+        for _ in 0..columns {
+            let _ = write!(self.body, "<w:gridCol w:w=\"{column_width}\"/>");
+        }
+        self.body.push_str("</w:tblGrid>");
+
+        for (i, row) in table.children.iter().enumerate() {
+            let header = i == 0;
+            self.body.push_str("<w:tr>");
+            if header {
+                self.body.push_str("<w:trPr><w:tblHeader/></w:trPr>");
+            }
+
+            let cells = row.children().map(Vec::as_slice).unwrap_or_default();
+            for column in 0..columns {
+                self.body.push_str("<w:tc><w:tcPr>");
+                let _ = write!(self.body, "<w:tcW w:w=\"{column_width}\" w:type=\"dxa\"/>");
+                if header {
+                    self.body
+                        .push_str("<w:shd w:val=\"clear\" w:color=\"auto\" w:fill=\"F8FAFC\"/>");
+                }
+                self.body.push_str("</w:tcPr><w:p>");
+
+                let align = match table.align.get(column) {
+                    Some(AlignKind::Left) => Some("left"),
+                    Some(AlignKind::Right) => Some("right"),
+                    Some(AlignKind::Center) => Some("center"),
+                    _ => None,
+                };
+                if let Some(align) = align {
+                    let _ = write!(self.body, "<w:pPr><w:jc w:val=\"{align}\"/></w:pPr>");
+                }
+
+                let content = cells.get(column).and_then(Node::children);
+                if let Some(content) = content {
+                    let format = RunFormat {
+                        bold: header,
+                        ..Default::default()
+                    };
+                    let runs = self.runs(content, format);
+                    self.body.push_str(&runs);
+                }
+                self.body.push_str("</w:p></w:tc>");
+            }
+            self.body.push_str("</w:tr>");
+        }
+        self.body.push_str("</w:tbl>");
+    }
+
+    fn runs(&mut self, nodes: &[Node], format: RunFormat) -> String {
+        let mut out = String::new();
+        for node in nodes {
+            match node {
+                Node::Text(text) => out.push_str(&text_run(&text.value, format)),
+                Node::Strong(strong) => {
+                    let format = RunFormat {
+                        bold: true,
+                        ..format
+                    };
+                    out.push_str(&self.runs(&strong.children, format));
+                }
+                Node::Emphasis(emphasis) => {
+                    let format = RunFormat {
+                        italic: true,
+                        ..format
+                    };
+                    out.push_str(&self.runs(&emphasis.children, format));
+                }
+                Node::Delete(delete) => {
+                    let format = RunFormat {
+                        strike: true,
+                        ..format
+                    };
+                    out.push_str(&self.runs(&delete.children, format));
+                }
+                Node::InlineCode(code) => {
+                    let format = RunFormat {
+                        code: true,
+                        ..format
+                    };
+                    out.push_str(&text_run(&code.value, format));
+                }
+                Node::Break(_) => out.push_str("<w:r><w:br/></w:r>"),
+                // Links with an unsafe protocol keep only their text.
+                Node::Link(link)
+                    if !format.link && safe_url(&link.url, LINK_PROTOCOLS).is_some() =>
+                {
+                    let id = self.relationship(RELATIONSHIP_HYPERLINK, link.url.clone(), true);
+                    let format = RunFormat {
+                        link: true,
+                        ..format
+                    };
+                    let runs = self.runs(&link.children, format);
+                    let _ = write!(
+                        out,
+                        "<w:hyperlink r:id=\"{id}\" w:history=\"1\">{runs}</w:hyperlink>"
+                    );
+                }
+                Node::Image(image) => out.push_str(&self.image(image, format)),
+                node => {
+                    if let Some(children) = node.children() {
+                        out.push_str(&self.runs(children, format));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Embeds local PNG, JPEG and GIF images; anything else becomes a link
+    /// to its source (or just its alt text).
+    fn image(&mut self, image: &mdast::Image, format: RunFormat) -> String {
+        let embedded = Image::load(&image.url, self.doc.asset_dir()).filter(|loaded| {
+            matches!(
+                loaded.format,
+                ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::Gif
+            )
+        });
+        let Some((loaded, (width, height))) =
+            embedded.and_then(|loaded| loaded.dimensions().map(|size| (loaded, size)))
+        else {
+            let label = if image.alt.is_empty() {
+                "image"
+            } else {
+                &image.alt
+            };
+            let is_remote = image.url.starts_with("http://") || image.url.starts_with("https://");
+            if is_remote && !format.link {
+                let id = self.relationship(RELATIONSHIP_HYPERLINK, image.url.clone(), true);
+                let link_format = RunFormat {
+                    link: true,
+                    ..format
+                };
+                return format!(
+                    "<w:hyperlink r:id=\"{id}\" w:history=\"1\">{}</w:hyperlink>",
+                    text_run(label, link_format)
+                );
+            }
+            return text_run(
+                label,
+                RunFormat {
+                    italic: true,
+                    ..format
+                },
+            );
+        };
+
+        let (mut cx, mut cy) = (width as u64 * EMU_PER_PIXEL, height as u64 * EMU_PER_PIXEL);
+        if cx > TEXT_WIDTH_EMU {
+            cy = cy * TEXT_WIDTH_EMU / cx;
+            cx = TEXT_WIDTH_EMU;
+        }
+
+        self.drawings += 1;
+        let n = self.drawings;
+        let name = format!("image{n}.{}", loaded.format.extension());
+        let id = self.relationship(RELATIONSHIP_IMAGE, format!("media/{name}"), false);
+        self.media.push((name.clone(), loaded.bytes));
+
+        format!(
+            "<w:r><w:drawing><wp:inline distT=\"0\" distB=\"0\" distL=\"0\" distR=\"0\">\
+             <wp:extent cx=\"{cx}\" cy=\"{cy}\"/>\
+             <wp:docPr id=\"{n}\" name=\"Picture {n}\" descr=\"{alt}\"/>\
+             <wp:cNvGraphicFramePr><a:graphicFrameLocks noChangeAspect=\"1\"/></wp:cNvGraphicFramePr>\
+             <a:graphic><a:graphicData uri=\"http://schemas.openxmlformats.org/drawingml/2006/picture\">\
+             <pic:pic><pic:nvPicPr><pic:cNvPr id=\"{n}\" name=\"{name}\"/><pic:cNvPicPr/></pic:nvPicPr>\
+             <pic:blipFill><a:blip r:embed=\"{id}\"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill>\
+             <pic:spPr><a:xfrm><a:off x=\"0\" y=\"0\"/><a:ext cx=\"{cx}\" cy=\"{cy}\"/></a:xfrm>\
+             <a:prstGeom prst=\"rect\"><a:avLst/></a:prstGeom></pic:spPr></pic:pic>\
+             </a:graphicData></a:graphic></wp:inline></w:drawing></w:r>",
+            alt = escape(&image.alt),
+        )
+    }
+
+    fn relationship(&mut self, kind: &'static str, target: String, external: bool) -> String {
+        // rId1 and rId2 are the styles and numbering parts.
+        let id = format!("rId{}", self.relationships.len() + 3);
+        self.relationships.push(Relationship {
+            id: id.clone(),
+            kind,
+            target,
+            external,
+        });
+        id
+    }
+
+    fn document_xml(&self) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+             <w:document \
+             xmlns:w=\"http://schemas.openxmlformats.org/wordprocessingml/2006/main\" \
+             xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\" \
+             xmlns:wp=\"http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing\" \
+             xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\" \
+             xmlns:pic=\"http://schemas.openxmlformats.org/drawingml/2006/picture\">\
+             <w:body>{}\
+             <w:sectPr><w:pgSz w:w=\"12240\" w:h=\"15840\"/>\
+             <w:pgMar w:top=\"1440\" w:right=\"1440\" w:bottom=\"1440\" w:left=\"1440\" w:header=\"720\" w:footer=\"720\" w:gutter=\"0\"/>\
+             </w:sectPr></w:body></w:document>",
+            self.body
+        )
+    }
+
+    fn relationships_xml(&self) -> String {
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+             <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+             <Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles\" Target=\"styles.xml\"/>\
+             <Relationship Id=\"rId2\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering\" Target=\"numbering.xml\"/>",
+        );
+        for relationship in &self.relationships {
+            let _ = write!(
+                out,
+                "<Relationship Id=\"{}\" Type=\"{}\" Target=\"{}\"{}/>",
+                relationship.id,
+                relationship.kind,
+                escape(&relationship.target),
+                if relationship.external {
+                    " TargetMode=\"External\""
+                } else {
+                    ""
+                }
+            );
+        }
+        out.push_str("</Relationships>");
+        out
+    }
+
+    fn numbering_xml(&self) -> String {
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+             <w:numbering xmlns:w=\"http://schemas.openxmlformats.org/wordprocessingml/2006/main\">",
+        );
+
+        out.push_str(
+            "<w:abstractNum w:abstractNumId=\"0\"><w:multiLevelType w:val=\"hybridMultilevel\"/>",
+        );
+        for level in 0..9u32 {
+            let bullet = ["•", "◦", "▪"][level as usize % 3];
+            let _ = write!(
+                out,
+                "<w:lvl w:ilvl=\"{level}\"><w:start w:val=\"1\"/><w:numFmt w:val=\"bullet\"/><w:lvlText w:val=\"{bullet}\"/><w:lvlJc w:val=\"left\"/><w:pPr><w:ind w:left=\"{}\" w:hanging=\"360\"/></w:pPr></w:lvl>",
+                INDENT_TWIPS * (level + 1)
+            );
+        }
+        out.push_str("</w:abstractNum>");
+
+        out.push_str(
+            "<w:abstractNum w:abstractNumId=\"1\"><w:multiLevelType w:val=\"hybridMultilevel\"/>",
+        );
+        for level in 0..9u32 {
+            let format = ["decimal", "lowerLetter", "lowerRoman"][level as usize % 3];
+            let _ = write!(
+                out,
+                "<w:lvl w:ilvl=\"{level}\"><w:start w:val=\"1\"/><w:numFmt w:val=\"{format}\"/><w:lvlText w:val=\"%{}.\"/><w:lvlJc w:val=\"left\"/><w:pPr><w:ind w:left=\"{}\" w:hanging=\"360\"/></w:pPr></w:lvl>",
+                level + 1,
+                INDENT_TWIPS * (level + 1)
+            );
+        }
+        out.push_str("</w:abstractNum>");
+
+        let _ = write!(
+            out,
+            "<w:num w:numId=\"{BULLET_NUM_ID}\"><w:abstractNumId w:val=\"0\"/></w:num>"
+        );
+        for (i, (level, start)) in self.ordered_lists.iter().enumerate() {
+            let _ = write!(
+                out,
+                "<w:num w:numId=\"{}\"><w:abstractNumId w:val=\"1\"/><w:lvlOverride w:ilvl=\"{level}\"><w:startOverride w:val=\"{start}\"/></w:lvlOverride></w:num>",
+                FIRST_ORDERED_NUM_ID + i as u32
+            );
+        }
+
+        out.push_str("</w:numbering>");
+        out
+    }
+}
+
+fn paragraph_properties(context: BlockContext, numbering: Option<(u32, u32)>) -> String {
+    let style = context
+        .style
+        .or(context.list_level.map(|_| "ListParagraph"));
+
+    let mut out = String::from("<w:pPr>");
+    if let Some(style) = style {
+        let _ = write!(out, "<w:pStyle w:val=\"{style}\"/>");
+    }
+    match (numbering, context.list_level) {
+        (Some((num_id, level)), _) => {
+            let _ = write!(
+                out,
+                "<w:numPr><w:ilvl w:val=\"{level}\"/><w:numId w:val=\"{num_id}\"/></w:numPr>"
+            );
+        }
+        (None, Some(level)) => {
+            let _ = write!(out, "<w:ind w:left=\"{}\"/>", INDENT_TWIPS * (level + 1));
+        }
+        (None, None) => {}
+    }
+    out.push_str("</w:pPr>");
+    out
+}
+
+fn text_run(text: &str, format: RunFormat) -> String {
+    let mut properties = String::new();
+    if format.link {
+        properties.push_str("<w:rStyle w:val=\"Hyperlink\"/>");
+    } else if format.code {
+        properties.push_str("<w:rStyle w:val=\"InlineCode\"/>");
+    }
+    if format.bold {
+        properties.push_str("<w:b/>");
+    }
+    if format.italic {
+        properties.push_str("<w:i/>");
+    }
+    if format.strike {
+        properties.push_str("<w:strike/>");
+    }
+
+    let properties = if properties.is_empty() {
+        properties
+    } else {
+        format!("<w:rPr>{properties}</w:rPr>")
+    };
+    format!(
+        "<w:r>{properties}<w:t xml:space=\"preserve\">{}</w:t></w:r>",
+        escape(text)
+    )
+}
+
+/// Escapes markup and drops control characters XML can't carry.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn content_types() -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\
+         <Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>\
+         <Default Extension=\"xml\" ContentType=\"application/xml\"/>",
+    );
+    for format in [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::Gif] {
+        let _ = write!(
+            out,
+            "<Default Extension=\"{}\" ContentType=\"{}\"/>",
+            format.extension(),
+            format.mime()
+        );
+    }
+    out.push_str(
+        "<Override PartName=\"/word/document.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml\"/>\
+         <Override PartName=\"/word/styles.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml\"/>\
+         <Override PartName=\"/word/numbering.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml\"/>\
+         <Override PartName=\"/docProps/core.xml\" ContentType=\"application/vnd.openxmlformats-package.core-properties+xml\"/>\
+         </Types>",
+    );
+    out
+}
+
+fn core_properties(title: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <cp:coreProperties \
+         xmlns:cp=\"http://schemas.openxmlformats.org/package/2006/metadata/core-properties\" \
+         xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\
+         <dc:title>{}</dc:title><dc:creator>Char</dc:creator></cp:coreProperties>",
+        escape(title)
+    )
+}
+
+const ROOT_RELS: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+<Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument\" Target=\"word/document.xml\"/>\
+<Relationship Id=\"rId2\" Type=\"http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties\" Target=\"docProps/core.xml\"/>\
+</Relationships>";
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:docDefaults>
+<w:rPrDefault><w:rPr><w:rFonts w:ascii="Calibri" w:hAnsi="Calibri" w:eastAsia="Calibri" w:cs="Calibri"/><w:sz w:val="22"/><w:szCs w:val="22"/><w:lang w:val="en-US"/></w:rPr></w:rPrDefault>
+<w:pPrDefault><w:pPr><w:spacing w:after="120" w:line="276" w:lineRule="auto"/></w:pPr></w:pPrDefault>
+</w:docDefaults>
+<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:qFormat/></w:style>
+<w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:spacing w:after="80"/></w:pPr><w:rPr><w:b/><w:sz w:val="48"/><w:szCs w:val="48"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Meta"><w:name w:val="Meta"/><w:basedOn w:val="Normal"/><w:pPr><w:spacing w:after="40"/></w:pPr><w:rPr><w:color w:val="6B7280"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="360" w:after="120"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:b/><w:sz w:val="36"/><w:szCs w:val="36"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="280" w:after="100"/><w:outlineLvl w:val="1"/></w:pPr><w:rPr><w:b/><w:sz w:val="30"/><w:szCs w:val="30"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading3"><w:name w:val="heading 3"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="240" w:after="80"/><w:outlineLvl w:val="2"/></w:pPr><w:rPr><w:b/><w:sz w:val="26"/><w:szCs w:val="26"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading4"><w:name w:val="heading 4"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="200" w:after="60"/><w:outlineLvl w:val="3"/></w:pPr><w:rPr><w:b/><w:sz w:val="24"/><w:szCs w:val="24"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading5"><w:name w:val="heading 5"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="200" w:after="60"/><w:outlineLvl w:val="4"/></w:pPr><w:rPr><w:b/><w:i/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading6"><w:name w:val="heading 6"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="200" w:after="60"/><w:outlineLvl w:val="5"/></w:pPr><w:rPr><w:i/><w:color w:val="6B7280"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="ListParagraph"><w:name w:val="List Paragraph"/><w:basedOn w:val="Normal"/><w:qFormat/><w:pPr><w:spacing w:after="40"/><w:contextualSpacing/></w:pPr></w:style>
+<w:style w:type="paragraph" w:styleId="Quote"><w:name w:val="Quote"/><w:basedOn w:val="Normal"/><w:qFormat/><w:pPr><w:pBdr><w:left w:val="single" w:sz="12" w:space="8" w:color="D1D5DB"/></w:pBdr><w:ind w:left="360"/></w:pPr><w:rPr><w:color w:val="374151"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Code"><w:name w:val="Code"/><w:basedOn w:val="Normal"/><w:pPr><w:shd w:val="clear" w:color="auto" w:fill="F3F4F6"/><w:spacing w:after="120" w:line="240" w:lineRule="auto"/></w:pPr><w:rPr><w:rFonts w:ascii="Consolas" w:hAnsi="Consolas" w:cs="Consolas"/><w:sz w:val="19"/></w:rPr></w:style>
+<w:style w:type="character" w:default="1" w:styleId="DefaultParagraphFont"><w:name w:val="Default Paragraph Font"/><w:uiPriority w:val="1"/><w:semiHidden/></w:style>
+<w:style w:type="character" w:styleId="Hyperlink"><w:name w:val="Hyperlink"/><w:basedOn w:val="DefaultParagraphFont"/><w:rPr><w:color w:val="2563EB"/><w:u w:val="single"/></w:rPr></w:style>
+<w:style w:type="character" w:styleId="InlineCode"><w:name w:val="Inline Code"/><w:basedOn w:val="DefaultParagraphFont"/><w:rPr><w:rFonts w:ascii="Consolas" w:hAnsi="Consolas" w:cs="Consolas"/><w:sz w:val="20"/><w:shd w:val="clear" w:color="auto" w:fill="F3F4F6"/></w:rPr></w:style>
+<w:style w:type="table" w:default="1" w:styleId="TableNormal"><w:name w:val="Normal Table"/><w:semiHidden/><w:tblPr><w:tblInd w:w="0" w:type="dxa"/><w:tblCellMar><w:top w:w="0" w:type="dxa"/><w:left w:w="108" w:type="dxa"/><w:bottom w:w="0" w:type="dxa"/><w:right w:w="108" w:type="dxa"/></w:tblCellMar></w:tblPr></w:style>
+<w:style w:type="table" w:styleId="TableGrid"><w:name w:val="Table Grid"/><w:basedOn w:val="TableNormal"/><w:pPr><w:spacing w:before="60" w:after="60"/></w:pPr><w:tblPr><w:tblBorders><w:top w:val="single" w:sz="4" w:space="0" w:color="E5E7EB"/><w:left w:val="single" w:sz="4" w:space="0" w:color="E5E7EB"/><w:bottom w:val="single" w:sz="4" w:space="0" w:color="E5E7EB"/><w:right w:val="single" w:sz="4" w:space="0" w:color="E5E7EB"/><w:insideH w:val="single" w:sz="4" w:space="0" w:color="E5E7EB"/><w:insideV w:val="single" w:sz="4" w:space="0" w:color="E5E7EB"/></w:tblBorders></w:tblPr></w:style>
+</w:styles>
+"#;
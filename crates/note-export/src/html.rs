@@ -0,0 +1,313 @@
+use std::fmt::Write as _;
+use std::path::Path;
+
+use markdown::mdast::{self, AlignKind, Node};
+
+use crate::image::Image;
+use crate::{Document, IMAGE_PROTOCOLS, LINK_PROTOCOLS, format_timestamp, safe_url};
+
+const STYLE: &str = r#"
+body { margin: 0; background: #ffffff; color: #111827; }
+article { max-width: 46rem; margin: 0 auto; padding: 3rem 1.5rem; font: 16px/1.6 -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif; }
+header { margin-bottom: 2.5rem; }
+header h1 { margin: 0 0 0.5rem; font-size: 2rem; }
+.meta { margin: 0.2rem 0; color: #6b7280; }
+h1, h2, h3, h4, h5, h6 { line-height: 1.3; margin: 1.6em 0 0.6em; }
+a { color: #2563eb; }
+code { font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; font-size: 0.9em; background: #f3f4f6; padding: 0.1em 0.3em; border-radius: 4px; }
+pre { background: #f3f4f6; padding: 1rem; border-radius: 6px; overflow-x: auto; }
+pre code { background: none; padding: 0; }
+blockquote { margin: 1em 0; padding: 0.2em 1em; border-left: 3px solid #d1d5db; color: #374151; }
+table { border-collapse: collapse; margin: 1em 0; width: 100%; }
+th, td { border: 1px solid #e5e7eb; padding: 0.4em 0.7em; text-align: left; vertical-align: top; }
+th { background: #f8fafc; }
+img { max-width: 100%; }
+li > input[type="checkbox"] { margin-right: 0.4em; }
+hr { border: none; border-top: 1px solid #e5e7eb; margin: 2em 0; }
+.transcript p { margin: 0 0 0.9em; }
+.speaker { font-weight: 600; }
+.time { color: #6b7280; font-size: 0.85em; margin-left: 0.5em; }
+"#;
+
+pub(crate) fn render(doc: &Document) -> String {
+    let mut out = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\" />\n");
+    let _ = writeln!(out, "<title>{}</title>", escape(&doc.title));
+    let _ = writeln!(out, "<style>{STYLE}</style>\n</head>\n<body>\n<article>");
+
+    out.push_str("<header>\n");
+    let _ = writeln!(out, "<h1>{}</h1>", escape(&doc.title));
+    let metadata = &doc.metadata;
+    let when: Vec<&str> = [metadata.created_at.as_deref(), metadata.duration.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    if !when.is_empty() {
+        let _ = writeln!(out, "<p class=\"meta\">{}</p>", escape(&when.join(" · ")));
+    }
+    if let Some(event_title) = &metadata.event_title {
+        let _ = writeln!(out, "<p class=\"meta\">{}</p>", escape(event_title));
+    }
+    if !metadata.participants.is_empty() {
+        let _ = writeln!(
+            out,
+            "<p class=\"meta\">Participants: {}</p>",
+            escape(&metadata.participants.join(", "))
+        );
+    }
+    out.push_str("</header>\n");
+
+    for note in &doc.notes {
+        out.push_str("<section>\n");
+        if let Some(title) = &note.title {
+            let _ = writeln!(out, "<h2>{}</h2>", escape(title));
+        }
+        out.push_str(&render_body(&note.body, doc.asset_dir()));
+        out.push_str("</section>\n");
+    }
+
+    if !doc.transcript.is_empty() {
+        let colors = doc.speaker_colors();
+        out.push_str("<section class=\"transcript\">\n<h2>Transcript</h2>\n");
+        for segment in &doc.transcript {
+            let speaker = segment.speaker.as_deref();
+            let _ = write!(
+                out,
+                "<p><span class=\"speaker\" style=\"color: {}\">{}</span>",
+                colors[&speaker],
+                escape(speaker.unwrap_or("Unknown"))
+            );
+            if let Some(start_ms) = segment.start_ms {
+                let _ = write!(
+                    out,
+                    "<span class=\"time\">{}</span>",
+                    format_timestamp(start_ms)
+                );
+            }
+            let _ = writeln!(out, "<br />{}</p>", escape(&segment.text));
+        }
+        out.push_str("</section>\n");
+    }
+
+    out.push_str("</article>\n</body>\n</html>\n");
+    out
+}
+
+/// Note content as HTML, following the markup `markdown::to_html` produces
+/// for the same document.
+pub(crate) fn render_body(node: &Node, asset_dir: Option<&Path>) -> String {
+    let mut renderer = Renderer {
+        asset_dir,
+        out: String::new(),
+    };
+    renderer.block(node, false);
+    renderer.out
+}
+
+struct Renderer<'a> {
+    asset_dir: Option<&'a Path>,
+    out: String,
+}
+
+impl Renderer<'_> {
+    /// `tight` drops the `<p>` around paragraphs, as in tight list items.
+    fn block(&mut self, node: &Node, tight: bool) {
+        match node {
+            Node::Root(root) => self.blocks(&root.children, false),
+            Node::Paragraph(paragraph) => {
+                if tight {
+                    self.inlines(&paragraph.children);
+                } else {
+                    self.out.push_str("<p>");
+                    self.inlines(&paragraph.children);
+                    self.out.push_str("</p>\n");
+                }
+            }
+            Node::Heading(heading) => {
+                let _ = write!(self.out, "<h{}>", heading.depth);
+                self.inlines(&heading.children);
+                let _ = writeln!(self.out, "</h{}>", heading.depth);
+            }
+            Node::List(list) => self.list(list),
+            Node::Blockquote(quote) => {
+                self.out.push_str("<blockquote>\n");
+                self.blocks(&quote.children, false);
+                self.out.push_str("</blockquote>\n");
+            }
+            Node::Code(code) => {
+                self.out.push_str("<pre><code");
+                if let Some(lang) = &code.lang {
+                    let _ = write!(self.out, " class=\"language-{}\"", escape(lang));
+                }
+                let _ = write!(self.out, ">{}", escape(&code.value));
+                if !code.value.is_empty() {
+                    self.out.push('\n');
+                }
+                self.out.push_str("</code></pre>\n");
+            }
+            Node::ThematicBreak(_) => self.out.push_str("<hr />\n"),
+            Node::Table(table) => self.table(table),
+            // Block-level images from the editor.
+            Node::Image(_) if !tight => {
+                self.out.push_str("<p>");
+                self.inline(node);
+                self.out.push_str("</p>\n");
+            }
+            node => self.inline(node),
+        }
+    }
+
+    fn blocks(&mut self, nodes: &[Node], tight: bool) {
+        for (i, node) in nodes.iter().enumerate() {
+            // Tight list items run their first paragraph straight into the
+            // `<li>`; anything after it starts on a new line.
+            if tight && i > 0 {
+                self.out.push('\n');
+            }
+            self.block(node, tight);
+        }
+    }
+
+    fn list(&mut self, list: &mdast::List) {
+        let tag = if list.ordered { "ol" } else { "ul" };
+        match list.start {
+            Some(start) if list.ordered && start != 1 => {
+                let _ = writeln!(self.out, "<{tag} start=\"{start}\">");
+            }
+            _ => {
+                let _ = writeln!(self.out, "<{tag}>");
+            }
+        }
+
+        for item in &list.children {
+            let Node::ListItem(item) = item else {
+                continue;
+            };
+            self.out.push_str("<li>");
+            match item.checked {
+                Some(true) => self
+                    .out
+                    .push_str("<input type=\"checkbox\" disabled=\"\" checked=\"\" /> "),
+                Some(false) => self
+                    .out
+                    .push_str("<input type=\"checkbox\" disabled=\"\" /> "),
+                None => {}
+            }
+            let tight = !(list.spread || item.spread);
+            if !tight {
+                self.out.push('\n');
+            }
+            self.blocks(&item.children, tight);
+            if tight && item.children.len() > 1 {
+                self.out.push('\n');
+            }
+            self.out.push_str("</li>\n");
+        }
+
+        let _ = writeln!(self.out, "</{tag}>");
+    }
+
+    fn table(&mut self, table: &mdast::Table) {
+        self.out.push_str("<table>\n");
+        for (i, row) in table.children.iter().enumerate() {
+            let Node::TableRow(row) = row else {
+                continue;
+            };
+            let cell_tag = if i == 0 { "th" } else { "td" };
+            if i == 0 {
+                self.out.push_str("<thead>\n");
+            } else if i == 1 {
+                self.out.push_str("<tbody>\n");
+            }
+
+            self.out.push_str("<tr>\n");
+            for (column, cell) in row.children.iter().enumerate() {
+                let align = match table.align.get(column) {
+                    Some(AlignKind::Left) => " align=\"left\"",
+                    Some(AlignKind::Right) => " align=\"right\"",
+                    Some(AlignKind::Center) => " align=\"center\"",
+                    _ => "",
+                };
+                let _ = write!(self.out, "<{cell_tag}{align}>");
+                if let Some(children) = cell.children() {
+                    self.inlines(children);
+                }
+                let _ = writeln!(self.out, "</{cell_tag}>");
+            }
+            self.out.push_str("</tr>\n");
+
+            if i == 0 {
+                self.out.push_str("</thead>\n");
+            }
+        }
+        if table.children.len() > 1 {
+            self.out.push_str("</tbody>\n");
+        }
+        self.out.push_str("</table>\n");
+    }
+
+    fn inlines(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            self.inline(node);
+        }
+    }
+
+    fn inline(&mut self, node: &Node) {
+        match node {
+            Node::Text(text) => self.out.push_str(&escape(&text.value)),
+            Node::Strong(strong) => self.wrap("strong", &strong.children),
+            Node::Emphasis(emphasis) => self.wrap("em", &emphasis.children),
+            Node::Delete(delete) => self.wrap("del", &delete.children),
+            Node::InlineCode(code) => {
+                let _ = write!(self.out, "<code>{}</code>", escape(&code.value));
+            }
+            Node::Break(_) => self.out.push_str("<br />\n"),
+            Node::Link(link) => {
+                let href = safe_url(&link.url, LINK_PROTOCOLS).unwrap_or_default();
+                let _ = write!(self.out, "<a href=\"{}\"", escape(href));
+                if let Some(title) = &link.title {
+                    let _ = write!(self.out, " title=\"{}\"", escape(title));
+                }
+                self.out.push('>');
+                self.inlines(&link.children);
+                self.out.push_str("</a>");
+            }
+            Node::Image(image) => {
+                let src = Image::load(&image.url, self.asset_dir)
+                    .map(|image| image.data_uri())
+                    .unwrap_or_else(|| {
+                        safe_url(&image.url, IMAGE_PROTOCOLS)
+                            .unwrap_or_default()
+                            .to_string()
+                    });
+                let _ = write!(
+                    self.out,
+                    "<img src=\"{}\" alt=\"{}\"",
+                    escape(&src),
+                    escape(&image.alt)
+                );
+                if let Some(title) = &image.title {
+                    let _ = write!(self.out, " title=\"{}\"", escape(title));
+                }
+                self.out.push_str(" />");
+            }
+            node => {
+                if let Some(children) = node.children() {
+                    self.inlines(children);
+                }
+            }
+        }
+    }
+
+    fn wrap(&mut self, tag: &str, children: &[Node]) {
+        let _ = write!(self.out, "<{tag}>");
+        self.inlines(children);
+        let _ = write!(self.out, "</{tag}>");
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,223 @@
+use std::path::Path;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+pub(crate) struct Image {
+    pub bytes: Vec<u8>,
+    pub format: ImageFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    Svg,
+}
+
+impl ImageFormat {
+    fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if bytes.starts_with(&[0xff, 0xd8, 0xff]) {
+            Some(Self::Jpeg)
+        } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            Some(Self::Gif)
+        } else if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+            Some(Self::Webp)
+        } else if is_svg(bytes) {
+            Some(Self::Svg)
+        } else {
+            None
+        }
+    }
+
+    pub fn mime(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+            Self::Webp => "image/webp",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+            Self::Gif => "gif",
+            Self::Webp => "webp",
+            Self::Svg => "svg",
+        }
+    }
+}
+
+impl Image {
+    /// Reads a `data:` URI or a file inside `asset_dir`, given as a path or a
+    /// percent-encoded `file://` URL. Remote images are left to the caller,
+    /// since exports are made offline, and so is anything that resolves
+    /// outside the asset directory.
+    pub fn load(src: &str, asset_dir: Option<&Path>) -> Option<Self> {
+        let bytes = if let Some(data) = src.strip_prefix("data:") {
+            let (_, payload) = data.split_once(";base64,")?;
+            STANDARD.decode(payload.trim()).ok()?
+        } else if src.starts_with("http://") || src.starts_with("https://") {
+            return None;
+        } else {
+            let asset_dir = asset_dir?.canonicalize().ok()?;
+            let path = match src.strip_prefix("file://") {
+                Some(encoded) => urlencoding::decode(encoded).ok()?,
+                None => src.into(),
+            };
+            let path = asset_dir.join(path.as_ref()).canonicalize().ok()?;
+            if !path.starts_with(&asset_dir) {
+                return None;
+            }
+            std::fs::read(path).ok()?
+        };
+
+        let format = ImageFormat::detect(&bytes)?;
+        Some(Self { bytes, format })
+    }
+
+    pub fn data_uri(&self) -> String {
+        format!(
+            "data:{};base64,{}",
+            self.format.mime(),
+            STANDARD.encode(&self.bytes)
+        )
+    }
+
+    /// Pixel size, read from the file header.
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        let b = &self.bytes;
+        match self.format {
+            ImageFormat::Png if b.len() >= 24 => Some((
+                u32::from_be_bytes([b[16], b[17], b[18], b[19]]),
+                u32::from_be_bytes([b[20], b[21], b[22], b[23]]),
+            )),
+            ImageFormat::Gif if b.len() >= 10 => Some((
+                u16::from_le_bytes([b[6], b[7]]) as u32,
+                u16::from_le_bytes([b[8], b[9]]) as u32,
+            )),
+            ImageFormat::Jpeg => jpeg_dimensions(b),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the root element is `<svg>`, once any XML declaration, comments
+/// and doctype are skipped.
+fn is_svg(bytes: &[u8]) -> bool {
+    let Ok(text) = std::str::from_utf8(bytes) else {
+        return false;
+    };
+    let mut rest = text.trim_start_matches('\u{feff}');
+    loop {
+        rest = rest.trim_start();
+        let end = if rest.starts_with("<?") {
+            rest.find("?>").map(|i| i + 2)
+        } else if rest.starts_with("<!--") {
+            rest.find("-->").map(|i| i + 3)
+        } else if rest.starts_with("<!") {
+            // A doctype may carry an internal subset in brackets.
+            match (rest.find('['), rest.find('>')) {
+                (Some(open), Some(close)) if open < close => rest.find("]>").map(|i| i + 2),
+                (_, close) => close.map(|i| i + 1),
+            }
+        } else {
+            break;
+        };
+        let Some(end) = end else {
+            return false;
+        };
+        rest = &rest[end..];
+    }
+
+    rest.strip_prefix("<svg").is_some_and(|tag| {
+        tag.starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+    })
+}
+
+/// Walks the JPEG segments up to the first start-of-frame marker.
+fn jpeg_dimensions(b: &[u8]) -> Option<(u32, u32)> {
+    let mut i = 2;
+    while i + 9 < b.len() {
+        if b[i] != 0xff {
+            return None;
+        }
+        let marker = b[i + 1];
+        let length = u16::from_be_bytes([b[i + 2], b[i + 3]]) as usize;
+        let is_frame = matches!(marker, 0xc0..=0xcf) && !matches!(marker, 0xc4 | 0xc8 | 0xcc);
+        if is_frame {
+            let height = u16::from_be_bytes([b[i + 5], b[i + 6]]) as u32;
+            let width = u16::from_be_bytes([b[i + 7], b[i + 8]]) as u32;
+            return Some((width, height));
+        }
+        i += 2 + length;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    #[test]
+    fn test_load_stays_in_asset_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let assets = root.path().join("assets");
+        std::fs::create_dir(&assets).unwrap();
+        std::fs::write(assets.join("chart.png"), PNG).unwrap();
+        std::fs::write(assets.join("my chart.png"), PNG).unwrap();
+        std::fs::write(root.path().join("secret.png"), PNG).unwrap();
+
+        let inside = assets.join("chart.png");
+        let outside = root.path().join("secret.png");
+        let file_url = format!("file://{}", inside.display());
+
+        assert!(Image::load("chart.png", Some(&assets)).is_some());
+        assert!(Image::load(inside.to_str().unwrap(), Some(&assets)).is_some());
+        assert!(Image::load(&file_url, Some(&assets)).is_some());
+        let encoded_url = format!("file://{}", assets.join("my%20chart.png").display());
+        assert!(Image::load(&encoded_url, Some(&assets)).is_some());
+
+        assert!(Image::load("../secret.png", Some(&assets)).is_none());
+        assert!(Image::load(outside.to_str().unwrap(), Some(&assets)).is_none());
+        assert!(Image::load(&format!("file://{}", outside.display()), Some(&assets)).is_none());
+        assert!(Image::load("chart.png", None).is_none());
+    }
+
+    #[test]
+    fn test_detect_svg() {
+        let long_comment = format!("<!--{}-->", "x".repeat(1024));
+        let svg = [
+            "<svg xmlns=\"http://www.w3.org/2000/svg\"/>".to_string(),
+            "\u{feff}<?xml version=\"1.0\"?>\n<svg>".to_string(),
+            format!("<?xml version=\"1.0\"?>{long_comment}\n<svg width=\"1\">"),
+            "<!DOCTYPE svg [<!ENTITY a \"b\">]>\n<svg>".to_string(),
+        ];
+        for text in &svg {
+            assert_eq!(
+                ImageFormat::detect(text.as_bytes()),
+                Some(ImageFormat::Svg),
+                "{text}"
+            );
+        }
+
+        let not_svg = [
+            "<html><body><svg></svg></body></html>",
+            "<svgfoo>",
+            "<!-- <svg>",
+            "plain text mentioning <svg>",
+        ];
+        for text in not_svg {
+            assert_eq!(ImageFormat::detect(text.as_bytes()), None, "{text}");
+        }
+    }
+}
@@ -0,0 +1,312 @@
+mod docx;
+mod error;
+mod html;
+mod image;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+
+use markdown::mdast;
+
+pub use error::{Error, Result};
+
+/// Speaker colours, cycled through in order of first appearance. Shared
+/// with the PDF export so a speaker looks the same in every format.
+pub const SPEAKER_COLORS: &[&str] = &[
+    "#2563eb", "#db2777", "#059669", "#d97706", "#7c3aed", "#0891b2", "#dc2626", "#4d7c0f",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct Metadata {
+    pub created_at: Option<String>,
+    pub event_title: Option<String>,
+    pub participants: Vec<String>,
+    pub duration: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TranscriptSegment {
+    pub speaker: Option<String>,
+    pub text: String,
+    /// Offset from the start of the recording.
+    pub start_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+struct Note {
+    title: Option<String>,
+    body: mdast::Node,
+}
+
+/// A session ready to be written out: metadata, any number of notes (as
+/// tiptap JSON) and the transcript.
+#[derive(Debug, Clone)]
+pub struct Document {
+    title: String,
+    metadata: Metadata,
+    notes: Vec<Note>,
+    transcript: Vec<TranscriptSegment>,
+    asset_dir: Option<PathBuf>,
+}
+
+impl Document {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            metadata: Metadata::default(),
+            notes: Vec::new(),
+            transcript: Vec::new(),
+            asset_dir: None,
+        }
+    }
+
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Appends a note, converted through [`hypr_tiptap::tiptap_json_to_mdast`].
+    pub fn with_note(mut self, title: Option<&str>, note: &serde_json::Value) -> Self {
+        self.notes.push(Note {
+            title: title.map(str::to_string),
+            body: hypr_tiptap::tiptap_json_to_mdast(note),
+        });
+        self
+    }
+
+    /// Appends a note written as GitHub-flavored Markdown.
+    pub fn with_markdown_note(mut self, title: Option<&str>, md: &str) -> Self {
+        // Only MDX syntax can fail to parse, and it isn't enabled.
+        let body = markdown::to_mdast(md, &markdown::ParseOptions::gfm()).unwrap_or_else(|_| {
+            mdast::Node::Root(mdast::Root {
+                children: Vec::new(),
+                position: None,
+            })
+        });
+        self.notes.push(Note {
+            title: title.map(str::to_string),
+            body,
+        });
+        self
+    }
+
+    pub fn with_transcript(mut self, transcript: Vec<TranscriptSegment>) -> Self {
+        self.transcript = transcript;
+        self
+    }
+
+    /// Directory that relative image paths in the notes are resolved
+    /// against.
+    pub fn with_asset_dir(mut self, asset_dir: impl Into<PathBuf>) -> Self {
+        self.asset_dir = Some(asset_dir.into());
+        self
+    }
+
+    /// A standalone HTML page; local images are inlined as data URIs.
+    pub fn to_html(&self) -> String {
+        html::render(self)
+    }
+
+    /// A Word document; local images are embedded, remote ones become links.
+    pub fn to_docx(&self) -> Result<Vec<u8>> {
+        docx::render(self)
+    }
+
+    fn asset_dir(&self) -> Option<&Path> {
+        self.asset_dir.as_deref()
+    }
+
+    fn speaker_colors(&self) -> HashMap<Option<&str>, &'static str> {
+        speaker_colors(self.transcript.iter().map(|s| s.speaker.as_deref()))
+    }
+}
+
+/// Colour per speaker, assigned in order of first appearance so the same
+/// speaker keeps the same colour across the document.
+pub fn speaker_colors<K: Eq + Hash>(
+    speakers: impl IntoIterator<Item = K>,
+) -> HashMap<K, &'static str> {
+    let mut colors = HashMap::new();
+    for speaker in speakers {
+        let next = SPEAKER_COLORS[colors.len() % SPEAKER_COLORS.len()];
+        colors.entry(speaker).or_insert(next);
+    }
+    colors
+}
+
+/// Protocols allowed in link targets and image sources, as in
+/// `markdown::to_html`.
+const LINK_PROTOCOLS: &[&str] = &["http", "https", "irc", "ircs", "mailto", "xmpp"];
+const IMAGE_PROTOCOLS: &[&str] = &["http", "https"];
+
+/// `url`, or nothing if its protocol isn't one of `protocols`. URLs without
+/// a protocol are relative and kept.
+fn safe_url<'a>(url: &'a str, protocols: &[&str]) -> Option<&'a str> {
+    let end = url.find(['?', '#', '/']).unwrap_or(url.len());
+    match url[..end].split_once(':') {
+        Some((protocol, _)) if !protocols.contains(&protocol.to_lowercase().as_str()) => None,
+        _ => Some(url),
+    }
+}
+
+/// `MM:SS`, or `H:MM:SS` once past the hour.
+pub fn format_timestamp(ms: u64) -> String {
+    let seconds = ms / 1000;
+    let (hours, minutes, seconds) = (seconds / 3600, (seconds / 60) % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    fn document(md: &str) -> Document {
+        let note = hypr_tiptap::md_to_tiptap_json(md).unwrap();
+        Document::new("Weekly sync").with_note(None, &note)
+    }
+
+    #[test]
+    fn test_html_matches_markdown() {
+        let md = [
+            "# Weekly sync",
+            "Shipped the **new exporter**, see [the PR](https://example.com/pr) and `cargo test`.",
+            "## Decisions",
+            "- Keep *Markdown* as the source of truth\n- Drop ~~PDF only~~ exports",
+            "3. Review\n4. Merge",
+            "- [x] Write the DOCX writer\n- [ ] Update docs",
+            "| Task | Owner |\n| --- | --- |\n| Ship it | Alice |",
+            "> Notes are Markdown on disk.",
+            "```rust\nfn main() {}\n```",
+            "---",
+        ]
+        .join("\n\n");
+
+        let doc = document(&md);
+        let expected = markdown::to_html_with_options(&md, &markdown::Options::gfm()).unwrap();
+        assert_eq!(
+            html::render_body(&doc.notes[0].body, None).trim_end(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_markdown_note() {
+        let md = "## Decisions\n\n- [x] Ship **it**\n\n| A | B |\n| --- | --- |\n| 1 | 2 |";
+        let doc = Document::new("Weekly sync").with_markdown_note(Some("Summary"), md);
+        let expected = markdown::to_html_with_options(md, &markdown::Options::gfm()).unwrap();
+        assert_eq!(
+            html::render_body(&doc.notes[0].body, None).trim_end(),
+            expected
+        );
+        assert!(doc.to_html().contains("<h2>Summary</h2>"));
+    }
+
+    #[test]
+    fn test_unsafe_links_dropped() {
+        assert_eq!(
+            safe_url("https://example.com/a:b", LINK_PROTOCOLS),
+            Some("https://example.com/a:b")
+        );
+        assert_eq!(
+            safe_url("notes/a.md#x:y", LINK_PROTOCOLS),
+            Some("notes/a.md#x:y")
+        );
+        assert_eq!(
+            safe_url("MAILTO:a@example.com", LINK_PROTOCOLS),
+            Some("MAILTO:a@example.com")
+        );
+        assert_eq!(safe_url("JavaScript:alert(1)", LINK_PROTOCOLS), None);
+        assert_eq!(safe_url("data:text/html,<script>", IMAGE_PROTOCOLS), None);
+
+        let md = "[click](javascript:alert(1)) ![x](vbscript:msgbox)";
+        let doc = document(md);
+        let expected = markdown::to_html_with_options(md, &markdown::Options::gfm()).unwrap();
+        assert_eq!(
+            html::render_body(&doc.notes[0].body, None).trim_end(),
+            expected
+        );
+        assert!(!doc.to_html().contains("javascript:"));
+    }
+
+    #[test]
+    fn test_html_inlines_local_images() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("chart.png"), png(2, 1)).unwrap();
+
+        let doc = document("![Chart](chart.png)").with_asset_dir(dir.path());
+        let html = doc.to_html();
+        assert!(html.contains("<img src=\"data:image/png;base64,"));
+        assert!(html.contains("alt=\"Chart\""));
+    }
+
+    #[test]
+    fn test_docx() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("chart.png"), png(2, 1)).unwrap();
+
+        let md = [
+            "## Decisions",
+            "- Keep Markdown",
+            "1. Review",
+            "- [x] Write the DOCX writer",
+            "| Task | Owner |\n| --- | --- |\n| Ship it | Alice |",
+            "![Chart](chart.png)",
+        ]
+        .join("\n\n");
+        let doc = document(&md)
+            .with_asset_dir(dir.path())
+            .with_transcript(vec![TranscriptSegment {
+                speaker: Some("Alice".to_string()),
+                text: "Let's ship it.".to_string(),
+                start_ms: Some(65_000),
+            }]);
+
+        let bytes = doc.to_docx().unwrap();
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+        assert!(archive.by_name("word/media/image1.png").is_ok());
+
+        let mut xml = String::new();
+        archive
+            .by_name("word/document.xml")
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+
+        let expected = [
+            "<w:pStyle w:val=\"Heading2\"/>",
+            "<w:t xml:space=\"preserve\">Decisions</w:t>",
+            "<w:numId w:val=\"1\"/>",
+            "<w:numId w:val=\"2\"/>",
+            "☒ ",
+            "<w:tbl>",
+            "<w:tblHeader/>",
+            "<a:blip r:embed=\"rId3\"/>",
+            "Transcript",
+            "01:05",
+            "Let's ship it.",
+        ];
+        let mut rest = xml.as_str();
+        for needle in expected {
+            let i = rest
+                .find(needle)
+                .unwrap_or_else(|| panic!("{needle:?} missing or out of order"));
+            rest = &rest[i + needle.len()..];
+        }
+    }
+
+    /// Just enough of a PNG for the header to be read.
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        bytes.extend(width.to_be_bytes());
+        bytes.extend(height.to_be_bytes());
+        bytes.extend([8, 6, 0, 0, 0]);
+        bytes
+    }
+}
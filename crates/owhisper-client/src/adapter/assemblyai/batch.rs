@@ -161,7 +161,7 @@ impl AssemblyAIAdapter {
             audio_url: upload_result.upload_url,
             language_code,
             language_detection,
-            speaker_labels: Some(true),
+            speaker_labels: Some(params.diarize),
             multichannel: None,
             keyterms_prompt: params.keywords.clone(),
         };
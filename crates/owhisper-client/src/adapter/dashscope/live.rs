@@ -12,6 +12,8 @@ const VAD_THRESHOLD: f32 = 0.5;
 const VAD_PREFIX_PADDING_MS: u32 = 300;
 const VAD_SILENCE_DURATION_MS: u32 = 500;
 const DEFAULT_SAMPLE_RATE: u32 = 16000;
+// Qwen3-ASR reads biasing context as free text in `corpus.text`.
+const CORPUS_MAX_CHARS: usize = 2000;
 
 impl RealtimeSttAdapter for DashScopeAdapter {
     fn provider_name(&self) -> &'static str {
@@ -94,6 +96,8 @@ impl RealtimeSttAdapter for DashScopeAdapter {
                     language,
                     input_audio_format: "pcm".to_string(),
                     input_sample_rate: sample_rate,
+                    corpus: crate::adapter::keywords::keyword_prompt(params, CORPUS_MAX_CHARS)
+                        .map(|text| Corpus { text }),
                 }),
                 turn_detection: Some(TurnDetection {
                     detection_type: VAD_DETECTION_TYPE.to_string(),
@@ -231,6 +235,13 @@ struct TranscriptionConfig {
     language: Option<String>,
     input_audio_format: String,
     input_sample_rate: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    corpus: Option<Corpus>,
+}
+
+#[derive(Debug, Serialize)]
+struct Corpus {
+    text: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -363,9 +374,11 @@ impl DashScopeAdapter {
 #[cfg(test)]
 mod tests {
     use hypr_language::ISO639;
+    use hypr_ws_client::client::Message;
 
     use super::DashScopeAdapter;
     use crate::ListenClient;
+    use crate::adapter::RealtimeSttAdapter;
     use crate::test_utils::{UrlTestCase, run_url_test_cases};
 
     const API_BASE: &str = "wss://dashscope-intl.aliyuncs.com";
@@ -385,6 +398,35 @@ mod tests {
         );
     }
 
+    fn extract_initial_message_json(
+        params: &owhisper_interface::ListenParams,
+    ) -> serde_json::Value {
+        let msg = DashScopeAdapter::default()
+            .initial_message(None, params, 1)
+            .unwrap();
+        match msg {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            _ => panic!("Expected text message"),
+        }
+    }
+
+    #[test]
+    fn test_initial_message_keywords_as_corpus() {
+        let params = owhisper_interface::ListenParams {
+            keywords: vec!["Hyprnote".to_string(), "Kubernetes".to_string()],
+            ..Default::default()
+        };
+
+        let json = extract_initial_message_json(&params);
+        assert_eq!(
+            json["session"]["transcription"]["corpus"]["text"],
+            "Glossary: Hyprnote, Kubernetes."
+        );
+
+        let json = extract_initial_message_json(&Default::default());
+        assert!(json["session"]["transcription"].get("corpus").is_none());
+    }
+
     #[tokio::test]
     #[ignore]
     async fn test_build_single() {
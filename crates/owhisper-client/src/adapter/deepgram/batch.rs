@@ -80,6 +80,26 @@ mod tests {
     use super::*;
     use crate::http_client::create_client;
 
+    #[test]
+    fn test_batch_url_diarize() {
+        let url = |diarize| {
+            let params = ListenParams {
+                diarize,
+                ..Default::default()
+            };
+            build_batch_url(
+                "https://api.deepgram.com/v1",
+                &params,
+                &DeepgramLanguageStrategy,
+                &DeepgramKeywordStrategy,
+            )
+            .to_string()
+        };
+
+        assert!(url(true).contains("diarize=true"));
+        assert!(url(false).contains("diarize=false"));
+    }
+
     #[tokio::test]
     #[ignore]
     async fn test_deepgram_batch_transcription() {
@@ -123,7 +123,7 @@ where
     builder
         .add("model", model)
         .add("encoding", "linear16")
-        .add_bool("diarize", true)
+        .add_bool("diarize", params.diarize)
         .add_bool("multichannel", false)
         .add_bool("punctuate", true)
         .add_bool("smart_format", true)
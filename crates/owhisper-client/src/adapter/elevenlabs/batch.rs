@@ -79,13 +79,17 @@ impl ElevenLabsAdapter {
         let mut form = reqwest::multipart::Form::new()
             .part("file", part)
             .text("model_id", model.to_string())
-            .text("diarize", "true")
+            .text("diarize", params.diarize.to_string())
             .text("timestamps_granularity", "word");
 
         if let Some(lang) = params.languages.first() {
             form = form.text("language_code", lang.iso639().code().to_string());
         }
 
+        for keyterm in Self::keyterms(params) {
+            form = form.text("keyterms", keyterm.to_string());
+        }
+
         let url = Self::batch_api_url(api_base);
         tracing::info!(path = %file_path.display(), url = %url, "uploading file to ElevenLabs");
 
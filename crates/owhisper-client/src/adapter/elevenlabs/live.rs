@@ -69,10 +69,18 @@ impl RealtimeSttAdapter for ElevenLabsAdapter {
     fn initial_message(
         &self,
         _api_key: Option<&str>,
-        _params: &ListenParams,
+        params: &ListenParams,
         _channels: u8,
     ) -> Option<Message> {
-        None
+        // `previous_text` is only accepted with the first chunk, so it goes
+        // out on an empty one before any audio.
+        let chunk = AudioChunk {
+            message_type: "input_audio_chunk",
+            audio_base_64: String::new(),
+            previous_text: Some(Self::keyword_prompt(params)?),
+        };
+        let json = serde_json::to_string(&chunk).ok()?;
+        Some(Message::Text(json.into()))
     }
 
     fn audio_to_message(&self, audio: bytes::Bytes) -> Message {
@@ -82,6 +90,7 @@ impl RealtimeSttAdapter for ElevenLabsAdapter {
                 &base64::engine::general_purpose::STANDARD,
                 &audio,
             ),
+            previous_text: None,
         };
         let json = serde_json::to_string(&chunk).unwrap();
         Message::Text(json.into())
@@ -146,6 +155,8 @@ impl RealtimeSttAdapter for ElevenLabsAdapter {
 struct AudioChunk<'a> {
     message_type: &'a str,
     audio_base_64: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_text: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -234,8 +245,11 @@ impl ElevenLabsAdapter {
 mod tests {
     use hypr_language::ISO639;
 
+    use hypr_ws_client::client::Message;
+
     use super::ElevenLabsAdapter;
     use crate::ListenClient;
+    use crate::adapter::RealtimeSttAdapter;
     use crate::test_utils::{UrlTestCase, run_dual_test, run_single_test, run_url_test_cases};
 
     const API_BASE: &str = "https://api.elevenlabs.io";
@@ -292,6 +306,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_initial_message_keywords_as_previous_text() {
+        let params = owhisper_interface::ListenParams {
+            keywords: vec!["Hyprnote".to_string(), "Kubernetes".to_string()],
+            ..Default::default()
+        };
+
+        let Some(Message::Text(text)) =
+            ElevenLabsAdapter::default().initial_message(None, &params, 1)
+        else {
+            panic!("Expected text message");
+        };
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["message_type"], "input_audio_chunk");
+        assert_eq!(json["audio_base_64"], "");
+        assert_eq!(json["previous_text"], "Glossary: Hyprnote, Kubernetes.");
+
+        assert!(
+            ElevenLabsAdapter::default()
+                .initial_message(None, &Default::default(), 1)
+                .is_none()
+        );
+    }
+
     macro_rules! single_test {
         ($name:ident, $params:expr) => {
             #[tokio::test]
@@ -4,9 +4,20 @@ mod language;
 mod live;
 
 use crate::providers::Provider;
+use owhisper_interface::ListenParams;
 use serde::Deserialize;
 
 use super::LanguageSupport;
+use super::keywords::{KeywordLimits, limited_keywords};
+
+// https://elevenlabs.io/docs/api-reference/speech-to-text/convert
+const KEYTERM_LIMITS: KeywordLimits = KeywordLimits {
+    max_terms: 100,
+    max_term_chars: 50,
+};
+
+// Realtime `previous_text` is meant for a short lead-in, not a transcript.
+const PREVIOUS_TEXT_MAX_CHARS: usize = 800;
 
 #[derive(Clone, Default)]
 pub struct ElevenLabsAdapter;
@@ -28,6 +39,16 @@ impl ElevenLabsAdapter {
         Self::language_support_batch(languages).is_supported()
     }
 
+    pub(crate) fn keyterms(params: &ListenParams) -> Vec<&str> {
+        limited_keywords(params, KEYTERM_LIMITS)
+    }
+
+    /// The realtime endpoint has no keyterms, so keywords go in as
+    /// `previous_text` context instead.
+    pub(crate) fn keyword_prompt(params: &ListenParams) -> Option<String> {
+        super::keywords::keyword_prompt(params, PREVIOUS_TEXT_MAX_CHARS)
+    }
+
     pub(crate) fn build_ws_url_from_base(api_base: &str) -> (url::Url, Vec<(String, String)>) {
         super::build_ws_url_from_base_with(Provider::ElevenLabs, api_base, |parsed| {
             super::build_url_with_scheme(
@@ -134,6 +155,22 @@ mod tests {
         assert!(!Provider::ElevenLabs.matches_url("https://api.assemblyai.com"));
     }
 
+    #[test]
+    fn test_keyterms() {
+        let long = "x".repeat(51);
+        let mut keywords = vec!["Hyprnote".to_string(), long];
+        keywords.extend((0..120).map(|i| format!("term{i}")));
+        let params = ListenParams {
+            keywords,
+            ..Default::default()
+        };
+
+        let keyterms = ElevenLabsAdapter::keyterms(&params);
+        assert_eq!(keyterms.len(), 100);
+        assert_eq!(keyterms[0], "Hyprnote");
+        assert_eq!(keyterms[1], "term0");
+    }
+
     #[test]
     fn test_batch_api_url_empty_uses_default() {
         let url = ElevenLabsAdapter::batch_api_url("");
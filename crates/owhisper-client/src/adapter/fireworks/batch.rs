@@ -73,6 +73,10 @@ impl FireworksAdapter {
             form = form.text("language", lang.iso639().code().to_string());
         }
 
+        if let Some(prompt) = Self::keyword_prompt(params) {
+            form = form.text("prompt", prompt);
+        }
+
         form = form.text("response_format", "verbose_json");
         form = form.text("timestamp_granularities", "word");
 
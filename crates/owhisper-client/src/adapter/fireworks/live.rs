@@ -42,6 +42,10 @@ impl RealtimeSttAdapter for FireworksAdapter {
             if let Some(lang) = params.languages.first() {
                 query_pairs.append_pair("language", lang.iso639().code());
             }
+
+            if let Some(prompt) = Self::keyword_prompt(params) {
+                query_pairs.append_pair("prompt", &prompt);
+            }
         }
 
         url
@@ -245,6 +249,7 @@ mod tests {
 
     use super::FireworksAdapter;
     use crate::ListenClient;
+    use crate::adapter::RealtimeSttAdapter;
     use crate::test_utils::{UrlTestCase, run_dual_test, run_single_test, run_url_test_cases};
 
     const API_BASE: &str = "https://api.fireworks.ai";
@@ -299,6 +304,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_keywords_as_prompt() {
+        let params = owhisper_interface::ListenParams {
+            keywords: vec!["Hyprnote".to_string(), "Kubernetes".to_string()],
+            ..Default::default()
+        };
+
+        let url = FireworksAdapter::default().build_ws_url(API_BASE, &params, 1);
+        let prompt = url
+            .query_pairs()
+            .find(|(k, _)| k == "prompt")
+            .map(|(_, v)| v.into_owned());
+        assert_eq!(prompt.as_deref(), Some("Glossary: Hyprnote, Kubernetes."));
+
+        let url = FireworksAdapter::default().build_ws_url(API_BASE, &Default::default(), 1);
+        assert!(!url.query_pairs().any(|(k, _)| k == "prompt"));
+    }
+
     #[tokio::test]
     #[ignore]
     async fn test_build_single() {
@@ -1,10 +1,15 @@
 mod batch;
 mod live;
 
+use owhisper_interface::ListenParams;
+
 use crate::providers::Provider;
 
 use super::{LanguageQuality, LanguageSupport};
 
+// Fireworks serves Whisper models, which read at most 224 prompt tokens.
+const PROMPT_MAX_CHARS: usize = 800;
+
 #[derive(Clone, Default)]
 pub struct FireworksAdapter;
 
@@ -27,6 +32,11 @@ impl FireworksAdapter {
         Self::language_support_batch(languages).is_supported()
     }
 
+    /// Fireworks has no keyword API, so vocabulary is passed as a prompt.
+    pub(crate) fn keyword_prompt(params: &ListenParams) -> Option<String> {
+        super::keywords::keyword_prompt(params, PROMPT_MAX_CHARS)
+    }
+
     pub(crate) fn api_host(api_base: &str) -> String {
         if api_base.is_empty() {
             return Provider::Fireworks.default_api_host().to_string();
@@ -220,7 +220,7 @@ impl GladiaAdapter {
             audio_url: upload_result.audio_url,
             model,
             language_config,
-            diarization: Some(true),
+            diarization: Some(params.diarize),
             custom_vocabulary,
             name_consistency: Some(true),
         };
@@ -0,0 +1,87 @@
+use owhisper_interface::ListenParams;
+
+/// How much biasing vocabulary a provider accepts.
+///
+/// `ListenParams::keywords` is expected to be ranked (see
+/// `VocabularyBuilder`), so truncation keeps the most important terms.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct KeywordLimits {
+    pub max_terms: usize,
+    pub max_term_chars: usize,
+}
+
+/// Keywords that fit `limits`, skipping terms that are too long.
+pub(crate) fn limited_keywords(params: &ListenParams, limits: KeywordLimits) -> Vec<&str> {
+    params
+        .keywords
+        .iter()
+        .map(|k| k.trim())
+        .filter(|k| !k.is_empty() && k.chars().count() <= limits.max_term_chars)
+        .take(limits.max_terms)
+        .collect()
+}
+
+/// Render keywords as a prompt for providers that only offer prompt
+/// biasing. Whole terms are dropped from the tail to stay within
+/// `max_chars`. Returns `None` when there's nothing to send.
+pub(crate) fn keyword_prompt(params: &ListenParams, max_chars: usize) -> Option<String> {
+    const PREFIX: &str = "Glossary: ";
+
+    let mut prompt = String::from(PREFIX);
+    for keyword in params.keywords.iter().map(|k| k.trim()) {
+        if keyword.is_empty() {
+            continue;
+        }
+        let separator = if prompt.len() == PREFIX.len() {
+            ""
+        } else {
+            ", "
+        };
+        if prompt.len() + separator.len() + keyword.len() + 1 > max_chars {
+            break;
+        }
+        prompt.push_str(separator);
+        prompt.push_str(keyword);
+    }
+
+    if prompt.len() == PREFIX.len() {
+        return None;
+    }
+    prompt.push('.');
+    Some(prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(keywords: &[&str]) -> ListenParams {
+        ListenParams {
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_limited_keywords() {
+        let params = params(&["Hyprnote", " ", "a very long keyword", "Char", "Deepgram"]);
+        let limits = KeywordLimits {
+            max_terms: 2,
+            max_term_chars: 10,
+        };
+        assert_eq!(limited_keywords(&params, limits), ["Hyprnote", "Char"]);
+    }
+
+    #[test]
+    fn test_keyword_prompt() {
+        assert_eq!(keyword_prompt(&params(&[]), 100), None);
+        assert_eq!(
+            keyword_prompt(&params(&["Hyprnote", "Char", "Kubernetes"]), 100).as_deref(),
+            Some("Glossary: Hyprnote, Char, Kubernetes.")
+        );
+        assert_eq!(
+            keyword_prompt(&params(&["Hyprnote", "Char", "Kubernetes"]), 26).as_deref(),
+            Some("Glossary: Hyprnote, Char.")
+        );
+    }
+}
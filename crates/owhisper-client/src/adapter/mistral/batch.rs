@@ -108,6 +108,10 @@ async fn do_transcribe_file(
         form = form.text("language", lang.iso639().code().to_string());
     }
 
+    for term in MistralAdapter::context_bias(params) {
+        form = form.text("context_bias", term.to_string());
+    }
+
     let base = if api_base.is_empty() {
         DEFAULT_API_BASE
     } else {
@@ -82,6 +82,10 @@ impl RealtimeSttAdapter for MistralAdapter {
                     encoding,
                     sample_rate,
                 },
+                context_bias: Self::context_bias(params)
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
             },
         };
 
@@ -169,6 +173,8 @@ struct SessionUpdateEvent {
 #[derive(Debug, Serialize)]
 struct SessionUpdateConfig {
     audio_format: AudioFormatConfig,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    context_bias: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -329,6 +335,7 @@ impl MistralAdapter {
 #[cfg(test)]
 mod tests {
     use hypr_language::ISO639;
+    use hypr_ws_client::client::Message;
 
     use super::MistralAdapter;
     use crate::ListenClient;
@@ -392,6 +399,33 @@ mod tests {
         run_dual_test_with_rate(client, "mistral", MISTRAL_SAMPLE_RATE).await;
     }
 
+    #[test]
+    fn test_initial_message_context_bias() {
+        let params = owhisper_interface::ListenParams {
+            keywords: vec!["Hyprnote".to_string(), "Kubernetes".to_string()],
+            sample_rate: MISTRAL_SAMPLE_RATE,
+            ..Default::default()
+        };
+
+        let Some(Message::Text(text)) = MistralAdapter::default().initial_message(None, &params, 1)
+        else {
+            panic!("Expected text message");
+        };
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            json["session"]["context_bias"],
+            serde_json::json!(["Hyprnote", "Kubernetes"])
+        );
+
+        let Some(Message::Text(text)) =
+            MistralAdapter::default().initial_message(None, &Default::default(), 1)
+        else {
+            panic!("Expected text message");
+        };
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert!(json["session"].get("context_bias").is_none());
+    }
+
     #[test]
     fn test_parse_session_created() {
         let adapter = MistralAdapter::default();
@@ -1,10 +1,19 @@
 mod batch;
 mod live;
 
+use owhisper_interface::ListenParams;
+
 use crate::providers::Provider;
 
+use super::keywords::{KeywordLimits, limited_keywords};
 use super::{LanguageQuality, LanguageSupport};
 
+// https://docs.mistral.ai/capabilities/audio_transcription
+const CONTEXT_BIAS_LIMITS: KeywordLimits = KeywordLimits {
+    max_terms: 100,
+    max_term_chars: 50,
+};
+
 const SUPPORTED_LANGUAGES: &[&str] = &[
     "en", "zh", "hi", "es", "ar", "fr", "pt", "ru", "de", "ja", "ko", "it", "nl",
 ];
@@ -59,6 +68,10 @@ impl MistralAdapter {
         Self::language_support_batch(languages).is_supported()
     }
 
+    pub(crate) fn context_bias(params: &ListenParams) -> Vec<&str> {
+        limited_keywords(params, CONTEXT_BIAS_LIMITS)
+    }
+
     pub(crate) fn build_ws_url_from_base(api_base: &str) -> (url::Url, Vec<(String, String)>) {
         super::build_ws_url_from_base_with(Provider::Mistral, api_base, |parsed| {
             let host = parsed
@@ -77,6 +90,16 @@ impl MistralAdapter {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_context_bias() {
+        let params = ListenParams {
+            keywords: vec!["Hyprnote".to_string(), "  ".to_string(), "Char".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(MistralAdapter::context_bias(&params), ["Hyprnote", "Char"]);
+        assert!(MistralAdapter::context_bias(&ListenParams::default()).is_empty());
+    }
+
     #[test]
     fn test_build_ws_url_from_base_empty() {
         let (url, params) = MistralAdapter::build_ws_url_from_base("");
@@ -9,6 +9,7 @@ mod fireworks;
 mod gladia;
 pub mod http;
 mod hyprnote;
+mod keywords;
 mod language;
 mod mistral;
 mod openai;
@@ -116,6 +116,10 @@ async fn do_transcribe_file(
         form = form.text("language", lang.iso639().code().to_string());
     }
 
+    if let Some(prompt) = OpenAIAdapter::keyword_prompt(params) {
+        form = form.text("prompt", prompt);
+    }
+
     let base = if api_base.is_empty() {
         DEFAULT_API_BASE
     } else {
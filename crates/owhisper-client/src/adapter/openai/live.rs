@@ -91,6 +91,7 @@ impl RealtimeSttAdapter for OpenAIAdapter {
                         transcription: Some(TranscriptionConfig {
                             model: model.to_string(),
                             language,
+                            prompt: OpenAIAdapter::keyword_prompt(params),
                         }),
                         turn_detection: Some(TurnDetection {
                             detection_type: VAD_DETECTION_TYPE.to_string(),
@@ -256,6 +257,8 @@ struct TranscriptionConfig {
     model: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompt: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -377,9 +380,11 @@ impl OpenAIAdapter {
 #[cfg(test)]
 mod tests {
     use hypr_language::ISO639;
+    use hypr_ws_client::client::Message;
 
     use super::OpenAIAdapter;
     use crate::ListenClient;
+    use crate::adapter::RealtimeSttAdapter;
     use crate::test_utils::{
         UrlTestCase, run_dual_test_with_rate, run_single_test_with_rate, run_url_test_cases,
     };
@@ -402,6 +407,35 @@ mod tests {
         );
     }
 
+    fn extract_initial_message_json(
+        params: &owhisper_interface::ListenParams,
+    ) -> serde_json::Value {
+        let msg = OpenAIAdapter::default()
+            .initial_message(None, params, 1)
+            .unwrap();
+        match msg {
+            Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            _ => panic!("Expected text message"),
+        }
+    }
+
+    #[test]
+    fn test_initial_message_keywords_as_prompt() {
+        let params = owhisper_interface::ListenParams {
+            keywords: vec!["Hyprnote".to_string(), "Kubernetes".to_string()],
+            sample_rate: OPENAI_SAMPLE_RATE,
+            ..Default::default()
+        };
+
+        let json = extract_initial_message_json(&params);
+        let transcription = &json["session"]["audio"]["input"]["transcription"];
+        assert_eq!(transcription["prompt"], "Glossary: Hyprnote, Kubernetes.");
+
+        let json = extract_initial_message_json(&Default::default());
+        let transcription = &json["session"]["audio"]["input"]["transcription"];
+        assert!(transcription.get("prompt").is_none());
+    }
+
     #[tokio::test]
     #[ignore]
     async fn test_build_single() {
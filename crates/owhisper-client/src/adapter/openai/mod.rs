@@ -1,10 +1,15 @@
 mod batch;
 mod live;
 
+use owhisper_interface::ListenParams;
+
 use crate::providers::Provider;
 
 use super::{LanguageQuality, LanguageSupport};
 
+// whisper-1 reads at most 224 prompt tokens; stay well under for all models.
+const PROMPT_MAX_CHARS: usize = 800;
+
 #[derive(Clone, Default)]
 pub struct OpenAIAdapter;
 
@@ -27,6 +32,11 @@ impl OpenAIAdapter {
         Self::language_support_batch(languages).is_supported()
     }
 
+    /// OpenAI has no keyword API, so vocabulary is passed as a prompt.
+    pub(crate) fn keyword_prompt(params: &ListenParams) -> Option<String> {
+        super::keywords::keyword_prompt(params, PROMPT_MAX_CHARS)
+    }
+
     pub(crate) fn build_ws_url_from_base(api_base: &str) -> (url::Url, Vec<(String, String)>) {
         if api_base.is_empty() {
             return (
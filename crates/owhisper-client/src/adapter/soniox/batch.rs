@@ -58,7 +58,7 @@ impl SonioxAdapter {
         let mut body = serde_json::json!({
             "model": model,
             "file_id": file_id,
-            "enable_speaker_diarization": true,
+            "enable_speaker_diarization": params.diarize,
             "enable_language_identification": true,
         });
 
@@ -82,7 +82,7 @@ impl RealtimeSttAdapter for SonioxAdapter {
             language_hints_strict: !language_hints.is_empty(),
             language_hints,
             enable_endpoint_detection: true,
-            enable_speaker_diarization: true,
+            enable_speaker_diarization: params.diarize,
             context,
         };
 
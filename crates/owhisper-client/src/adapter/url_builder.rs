@@ -37,7 +37,7 @@ impl QueryParamBuilder {
             .add("channels", channels)
             .add("sample_rate", params.sample_rate)
             .add("encoding", "linear16")
-            .add_bool("diarize", true)
+            .add_bool("diarize", params.diarize)
             .add_bool("punctuate", true)
             .add_bool("smart_format", true)
             .add_bool("numerals", true)
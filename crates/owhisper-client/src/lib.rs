@@ -6,12 +6,14 @@ mod http_client;
 mod live;
 pub(crate) mod polling;
 mod providers;
+mod vocabulary;
 
 #[cfg(test)]
 pub(crate) mod test_utils;
 
 pub use error_detection::ProviderError;
 pub use providers::{Auth, Provider, is_meta_model};
+pub use vocabulary::{MAX_VOCABULARY_TERMS, VocabularyBuilder};
 
 use std::marker::PhantomData;
 
@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+/// Upper bound on terms produced by `VocabularyBuilder::build`. Adapters
+/// truncate further to their provider's own limits.
+pub const MAX_VOCABULARY_TERMS: usize = 100;
+
+const GLOSSARY_SCORE: u32 = 100;
+const PARTICIPANT_SCORE: u32 = 80;
+const ORGANIZATION_SCORE: u32 = 70;
+const EVENT_TITLE_SCORE: u32 = 60;
+const TRANSCRIPT_SCORE_PER_MENTION: u32 = 10;
+const TRANSCRIPT_MAX_SCORE: u32 = 50;
+/// A proper noun must recur across past transcripts before it's worth biasing.
+const TRANSCRIPT_MIN_MENTIONS: u32 = 2;
+
+const MAX_TERM_CHARS: usize = 50;
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "at", "be", "but", "by", "do", "for", "from", "hey", "hi", "how", "i",
+    "i'd", "i'll", "i'm", "i've", "if", "in", "is", "it", "it's", "let's", "me", "my", "no", "not",
+    "of", "ok", "okay", "on", "or", "our", "so", "that", "the", "then", "there", "they", "this",
+    "to", "us", "we", "well", "what", "when", "where", "who", "why", "with", "yeah", "yes", "you",
+    "your", "call", "chat", "meeting", "sync", "weekly", "daily", "standup",
+];
+
+/// Builds `ListenParams::keywords` from what we know about a session.
+///
+/// Terms are ranked by source — the user's glossary first, then
+/// participant and organization names, the calendar event title, and
+/// finally proper nouns that recur in past transcripts. A term found in
+/// several sources accumulates their scores. Duplicates are merged
+/// case-insensitively, keeping the first spelling seen.
+#[derive(Debug, Default, Clone)]
+pub struct VocabularyBuilder {
+    terms: HashMap<String, Candidate>,
+    mentions: HashMap<String, Mention>,
+    next_order: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Mention {
+    term: String,
+    count: u32,
+    order: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Candidate {
+    term: String,
+    score: u32,
+    order: usize,
+}
+
+impl VocabularyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_glossary<I, S>(mut self, terms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for term in terms {
+            self.add(term.as_ref(), GLOSSARY_SCORE);
+        }
+        self
+    }
+
+    pub fn with_participants<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for name in names {
+            self.add(name.as_ref(), PARTICIPANT_SCORE);
+        }
+        self
+    }
+
+    pub fn with_organizations<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for name in names {
+            self.add(name.as_ref(), ORGANIZATION_SCORE);
+        }
+        self
+    }
+
+    /// Proper nouns in the event title, e.g. "Acme" in "Acme <> Char sync".
+    pub fn with_event_title(mut self, title: &str) -> Self {
+        for term in proper_nouns(title, false) {
+            self.add(&term, EVENT_TITLE_SCORE);
+        }
+        self
+    }
+
+    /// Count proper nouns in a past transcript. Only terms mentioned at
+    /// least twice across all transcripts make it into the vocabulary.
+    pub fn with_past_transcripts<I, S>(mut self, transcripts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for transcript in transcripts {
+            for term in proper_nouns(transcript.as_ref(), true) {
+                let order = self.mentions.len();
+                self.mentions
+                    .entry(term.to_lowercase())
+                    .or_insert(Mention {
+                        term,
+                        count: 0,
+                        order,
+                    })
+                    .count += 1;
+            }
+        }
+        self
+    }
+
+    /// Ranked terms, highest score first, capped at `MAX_VOCABULARY_TERMS`.
+    pub fn build(mut self) -> Vec<String> {
+        let mut mentions: Vec<Mention> = std::mem::take(&mut self.mentions).into_values().collect();
+        mentions.sort_by_key(|m| m.order);
+        for mention in mentions {
+            if mention.count >= TRANSCRIPT_MIN_MENTIONS {
+                let score =
+                    (mention.count * TRANSCRIPT_SCORE_PER_MENTION).min(TRANSCRIPT_MAX_SCORE);
+                self.add(&mention.term, score);
+            }
+        }
+
+        let mut candidates: Vec<Candidate> = self.terms.into_values().collect();
+        candidates.sort_by(|a, b| b.score.cmp(&a.score).then(a.order.cmp(&b.order)));
+        candidates
+            .into_iter()
+            .take(MAX_VOCABULARY_TERMS)
+            .map(|c| c.term)
+            .collect()
+    }
+
+    fn add(&mut self, term: &str, score: u32) {
+        let term = term.split_whitespace().collect::<Vec<_>>().join(" ");
+        if term.is_empty() || term.chars().count() > MAX_TERM_CHARS {
+            return;
+        }
+
+        let order = self.next_order;
+        let candidate = self
+            .terms
+            .entry(term.to_lowercase())
+            .or_insert_with(|| Candidate {
+                term,
+                score: 0,
+                order,
+            });
+        candidate.score += score;
+        self.next_order += 1;
+    }
+}
+
+/// Extract capitalized runs ("Acme Corp") and mixed-case tokens ("iPhone").
+///
+/// With `skip_sentence_start`, a capitalized word opening a sentence isn't
+/// counted on its own, since capitalization there says nothing.
+fn proper_nouns(text: &str, skip_sentence_start: bool) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut run: Vec<&str> = Vec::new();
+    let mut sentence_start = true;
+
+    for raw in text.split_whitespace() {
+        let token = raw.trim_matches(|c: char| !c.is_alphanumeric());
+        let ends_sentence = raw.ends_with(['.', '?', '!']);
+        let breaks_run = ends_sentence || raw.ends_with([',', ';', ':']);
+
+        let is_candidate = !token.is_empty()
+            && !STOPWORDS.contains(&token.to_lowercase().as_str())
+            && (is_mixed_case(token)
+                || (starts_upper(token) && !(skip_sentence_start && sentence_start)));
+
+        if is_candidate {
+            run.push(token);
+        } else if !run.is_empty() {
+            terms.push(run.join(" "));
+            run.clear();
+        }
+
+        if breaks_run && !run.is_empty() {
+            terms.push(run.join(" "));
+            run.clear();
+        }
+        sentence_start = ends_sentence;
+    }
+    if !run.is_empty() {
+        terms.push(run.join(" "));
+    }
+
+    terms
+}
+
+fn starts_upper(token: &str) -> bool {
+    token.chars().next().is_some_and(char::is_uppercase) && token.chars().count() > 1
+}
+
+fn is_mixed_case(token: &str) -> bool {
+    let mut chars = token.chars();
+    let first_lower = chars.next().is_some_and(char::is_lowercase);
+    first_lower && chars.any(char::is_uppercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranks_sources() {
+        let vocabulary = VocabularyBuilder::new()
+            .with_event_title("Acme <> Char weekly sync")
+            .with_participants(["Jane Doe", "john smith"])
+            .with_organizations(["Acme"])
+            .with_glossary(["Kubernetes"])
+            .with_past_transcripts([
+                "We talked to Postgres folks. The Postgres migration is done.",
+                "Okay so Redis is fine. Then iPhone. Then iPhone again.",
+            ])
+            .build();
+
+        assert_eq!(
+            vocabulary,
+            [
+                "Acme",
+                "Kubernetes",
+                "Jane Doe",
+                "john smith",
+                "Char",
+                "Postgres",
+                "iPhone"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extracts_proper_nouns() {
+        assert_eq!(
+            proper_nouns("Roadmap review with Acme Corp, then OpenAI.", true),
+            ["Acme Corp", "OpenAI"]
+        );
+        assert_eq!(
+            proper_nouns("Roadmap review with Acme Corp", false),
+            ["Roadmap", "Acme Corp"]
+        );
+    }
+
+    #[test]
+    fn test_caps_terms() {
+        let glossary: Vec<String> = (0..150).map(|i| format!("term{i}")).collect();
+        let vocabulary = VocabularyBuilder::new().with_glossary(&glossary).build();
+        assert_eq!(vocabulary.len(), MAX_VOCABULARY_TERMS);
+        assert_eq!(vocabulary[0], "term0");
+    }
+}
@@ -140,6 +140,9 @@ common_derives! {
         pub languages: Vec<hypr_language::Language>,
         #[serde(default)]
         pub keywords: Vec<String>,
+        /// Ask the provider to label speakers.
+        #[serde(default = "default_diarize")]
+        pub diarize: bool,
         #[serde(default)]
         #[cfg_attr(feature = "openapi", schema(value_type = Option<Object>))]
         pub custom_query: Option<std::collections::HashMap<String, String>>,
@@ -154,7 +157,12 @@ impl Default for ListenParams {
             sample_rate: 16000,
             languages: vec![],
             keywords: vec![],
+            diarize: true,
             custom_query: None,
         }
     }
 }
+
+fn default_diarize() -> bool {
+    true
+}
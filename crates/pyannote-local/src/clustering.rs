@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+
+/// Cosine distance below which two clusters are considered the same voice.
+/// Mirrors `identity::DEFAULT_MATCH_THRESHOLD` (similarity 0.5).
+pub const DEFAULT_CLUSTER_THRESHOLD: f32 = 0.5;
+
+/// Clusters with fewer members are folded into the nearest larger cluster.
+pub const DEFAULT_MIN_CLUSTER_SIZE: usize = 2;
+
+/// Average-linkage agglomerative clustering over cosine distance.
+///
+/// The number of speakers is estimated by cutting the dendrogram at
+/// `threshold`, then clamped to the optional speaker range. A fixed
+/// `num_speakers` skips the estimate altogether.
+#[derive(Debug, Clone)]
+pub struct AgglomerativeClustering {
+    threshold: f32,
+    num_speakers: Option<usize>,
+    min_speakers: usize,
+    max_speakers: Option<usize>,
+    min_cluster_size: usize,
+}
+
+impl Default for AgglomerativeClustering {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgglomerativeClustering {
+    pub fn new() -> Self {
+        Self {
+            threshold: DEFAULT_CLUSTER_THRESHOLD,
+            num_speakers: None,
+            min_speakers: 1,
+            max_speakers: None,
+            min_cluster_size: DEFAULT_MIN_CLUSTER_SIZE,
+        }
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn with_num_speakers(mut self, num_speakers: usize) -> Self {
+        self.num_speakers = Some(num_speakers.max(1));
+        self
+    }
+
+    pub fn with_speaker_range(mut self, min_speakers: usize, max_speakers: usize) -> Self {
+        self.min_speakers = min_speakers.max(1);
+        self.max_speakers = Some(max_speakers.max(self.min_speakers));
+        self
+    }
+
+    pub fn with_min_cluster_size(mut self, min_cluster_size: usize) -> Self {
+        self.min_cluster_size = min_cluster_size;
+        self
+    }
+
+    /// Assigns a cluster to each embedding. Labels are numbered by first
+    /// appearance, so the first embedding is always in cluster `0`.
+    pub fn cluster(&self, embeddings: &[Vec<f32>]) -> Vec<usize> {
+        let n = embeddings.len();
+        if n <= 1 {
+            return vec![0; n];
+        }
+
+        let normalized: Vec<Vec<f32>> = embeddings.iter().map(|e| normalize(e)).collect();
+        let mut merges = linkage(&normalized);
+        merges.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+        let estimated = n - merges
+            .iter()
+            .take_while(|m| m.distance <= self.threshold)
+            .count();
+        let n_clusters = match self.num_speakers {
+            Some(num_speakers) => num_speakers,
+            None => {
+                let max_speakers = self.max_speakers.unwrap_or(n);
+                estimated.clamp(self.min_speakers, max_speakers)
+            }
+        }
+        .min(n);
+
+        let mut sets = DisjointSet::new(n);
+        for merge in merges.iter().take(n - n_clusters) {
+            sets.union(merge.a, merge.b);
+        }
+        let mut labels: Vec<usize> = (0..n).map(|i| sets.find(i)).collect();
+
+        // Outliers only get absorbed while the speaker count is estimated;
+        // an explicit count is taken at face value.
+        if self.num_speakers.is_none() {
+            self.absorb_small_clusters(&normalized, &mut labels);
+        }
+
+        relabel(&labels)
+    }
+
+    fn absorb_small_clusters(&self, embeddings: &[Vec<f32>], labels: &mut [usize]) {
+        let mut members: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (i, &label) in labels.iter().enumerate() {
+            members.entry(label).or_default().push(i);
+        }
+
+        let (large, small): (Vec<_>, Vec<_>) = members
+            .into_iter()
+            .partition(|(_, m)| m.len() >= self.min_cluster_size);
+        if large.is_empty() || large.len() < self.min_speakers {
+            return;
+        }
+
+        let centroids: Vec<(usize, Vec<f32>)> = large
+            .iter()
+            .map(|(label, m)| (*label, centroid(embeddings, m)))
+            .collect();
+
+        for (_, indices) in small {
+            for i in indices {
+                let nearest = centroids
+                    .iter()
+                    .max_by(|a, b| dot(&embeddings[i], &a.1).total_cmp(&dot(&embeddings[i], &b.1)))
+                    .map(|(label, _)| *label);
+                if let Some(label) = nearest {
+                    labels[i] = label;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Merge {
+    a: usize,
+    b: usize,
+    distance: f32,
+}
+
+/// Average-linkage merges via the nearest-neighbour chain algorithm, which
+/// is O(n²) rather than the naive O(n³). Merges come out of order; sorting
+/// them by distance yields the dendrogram.
+fn linkage(embeddings: &[Vec<f32>]) -> Vec<Merge> {
+    let n = embeddings.len();
+    let mut distances = vec![0.0f32; n * n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let d = 1.0 - dot(&embeddings[i], &embeddings[j]);
+            distances[i * n + j] = d;
+            distances[j * n + i] = d;
+        }
+    }
+
+    let mut sizes = vec![1usize; n];
+    let mut active = vec![true; n];
+    let mut chain: Vec<usize> = Vec::with_capacity(n);
+    let mut merges = Vec::with_capacity(n - 1);
+
+    while merges.len() < n - 1 {
+        if chain.is_empty() {
+            chain.push(active.iter().position(|&a| a).unwrap());
+        }
+
+        let (a, b, distance) = loop {
+            let a = *chain.last().unwrap();
+            let previous = chain.len().checked_sub(2).map(|i| chain[i]);
+
+            let mut nearest = previous;
+            let mut nearest_distance = previous.map_or(f32::INFINITY, |p| distances[a * n + p]);
+            for k in (0..n).filter(|&k| active[k] && k != a) {
+                if distances[a * n + k] < nearest_distance {
+                    nearest = Some(k);
+                    nearest_distance = distances[a * n + k];
+                }
+            }
+
+            let nearest = nearest.unwrap();
+            if Some(nearest) == previous {
+                chain.truncate(chain.len() - 2);
+                break (a, nearest, nearest_distance);
+            }
+            chain.push(nearest);
+        };
+
+        // Lance–Williams update for average linkage; `a` now stands for both.
+        let (size_a, size_b) = (sizes[a] as f32, sizes[b] as f32);
+        for k in (0..n).filter(|&k| active[k] && k != a && k != b) {
+            let d =
+                (size_a * distances[a * n + k] + size_b * distances[b * n + k]) / (size_a + size_b);
+            distances[a * n + k] = d;
+            distances[k * n + a] = d;
+        }
+        sizes[a] += sizes[b];
+        active[b] = false;
+
+        merges.push(Merge { a, b, distance });
+    }
+
+    merges
+}
+
+struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            self.parents[i] = self.parents[self.parents[i]];
+            i = self.parents[i];
+        }
+        i
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a != b {
+            self.parents[b] = a;
+        }
+    }
+}
+
+fn relabel(labels: &[usize]) -> Vec<usize> {
+    let mut mapping: HashMap<usize, usize> = HashMap::new();
+    labels
+        .iter()
+        .map(|label| {
+            let next = mapping.len();
+            *mapping.entry(*label).or_insert(next)
+        })
+        .collect()
+}
+
+fn centroid(embeddings: &[Vec<f32>], members: &[usize]) -> Vec<f32> {
+    let mut sum = vec![0.0; embeddings[members[0]].len()];
+    for &i in members {
+        for (acc, x) in sum.iter_mut().zip(&embeddings[i]) {
+            *acc += x;
+        }
+    }
+    normalize(&sum)
+}
+
+fn normalize(embedding: &[f32]) -> Vec<f32> {
+    let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return embedding.to_vec();
+    }
+    embedding.iter().map(|x| x / norm).collect()
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voices() -> Vec<Vec<f32>> {
+        vec![
+            vec![1.0, 0.1, 0.0],
+            vec![0.0, 1.0, 0.1],
+            vec![0.95, 0.15, 0.05],
+            vec![0.1, 0.0, 1.0],
+            vec![0.05, 0.9, 0.0],
+            vec![0.0, 0.1, 0.95],
+            vec![0.9, 0.0, 0.1],
+        ]
+    }
+
+    #[test]
+    fn test_estimates_speaker_count() {
+        let labels = AgglomerativeClustering::new().cluster(&voices());
+        assert_eq!(labels, [0, 1, 0, 2, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_fixed_and_bounded_speaker_count() {
+        let labels = AgglomerativeClustering::new()
+            .with_num_speakers(2)
+            .cluster(&voices());
+        assert_eq!(labels.iter().max(), Some(&1));
+        assert_eq!(labels[0], labels[2]);
+        assert_eq!(labels[1], labels[4]);
+
+        let labels = AgglomerativeClustering::new()
+            .with_speaker_range(1, 2)
+            .cluster(&voices());
+        assert_eq!(labels.iter().max(), Some(&1));
+    }
+
+    #[test]
+    fn test_absorbs_outliers() {
+        let mut embeddings = voices();
+        embeddings.push(vec![-1.0, 0.0, 0.0]);
+
+        let labels = AgglomerativeClustering::new().cluster(&embeddings);
+        assert_eq!(labels.iter().max(), Some(&2));
+
+        let labels = AgglomerativeClustering::new()
+            .with_min_cluster_size(1)
+            .cluster(&embeddings);
+        assert_eq!(labels.iter().max(), Some(&3));
+    }
+
+    #[test]
+    fn test_trivial_inputs() {
+        let clustering = AgglomerativeClustering::new();
+        assert!(clustering.cluster(&[]).is_empty());
+        assert_eq!(clustering.cluster(&[vec![1.0, 0.0]]), [0]);
+    }
+}
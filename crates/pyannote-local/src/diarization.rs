@@ -0,0 +1,199 @@
+use crate::clustering::AgglomerativeClustering;
+use crate::embedding::EmbeddingExtractor;
+use crate::segmentation::Segment;
+
+/// Speech is embedded in windows of this length; a speaker change inside a
+/// segment is resolved at this granularity.
+const WINDOW_SECS: f64 = 1.5;
+/// Shorter audio yields unreliable embeddings and is left unattributed.
+const MIN_WINDOW_SECS: f64 = 0.5;
+/// Adjacent turns of the same speaker separated by less are joined.
+const MERGE_GAP_SECS: f64 = 0.5;
+/// How far from a turn an unattributed word may still inherit its speaker.
+const MAX_ASSIGN_DISTANCE_SECS: f64 = 1.0;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct SpeakerTurn {
+    pub start: f64,
+    pub end: f64,
+    pub speaker: usize,
+}
+
+/// Offline diarization: embeds each speech window and clusters the windows
+/// into speakers.
+///
+/// Takes the speech segments produced by `Segmenter::process`, so callers
+/// that already segment audio (e.g. for transcription) don't run the
+/// segmentation model twice.
+pub struct Diarizer {
+    extractor: EmbeddingExtractor,
+    clustering: AgglomerativeClustering,
+    sample_rate: u32,
+}
+
+impl Diarizer {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            extractor: EmbeddingExtractor::new(),
+            clustering: AgglomerativeClustering::new(),
+            sample_rate,
+        }
+    }
+
+    pub fn with_clustering(mut self, clustering: AgglomerativeClustering) -> Self {
+        self.clustering = clustering;
+        self
+    }
+
+    pub fn diarize(&mut self, segments: &[Segment]) -> Result<Vec<SpeakerTurn>, crate::Error> {
+        let mut windows = Vec::new();
+        let mut embeddings = Vec::new();
+
+        for segment in segments {
+            for (start, end, samples) in split_segment(segment, self.sample_rate) {
+                embeddings.push(self.extractor.compute(samples.iter().copied())?);
+                windows.push((start, end));
+            }
+        }
+
+        let speakers = self.clustering.cluster(&embeddings);
+        let turns = windows
+            .into_iter()
+            .zip(speakers)
+            .map(|((start, end), speaker)| SpeakerTurn {
+                start,
+                end,
+                speaker,
+            });
+
+        Ok(merge_turns(turns))
+    }
+}
+
+/// Speaker for the span `start..end` (in seconds): the one overlapping it
+/// most, or else the nearest turn within `MAX_ASSIGN_DISTANCE_SECS`.
+pub fn assign_speaker(turns: &[SpeakerTurn], start: f64, end: f64) -> Option<usize> {
+    let overlapping = turns
+        .iter()
+        .map(|t| (t.speaker, t.end.min(end) - t.start.max(start)))
+        .filter(|(_, overlap)| *overlap > 0.0)
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+    if let Some((speaker, _)) = overlapping {
+        return Some(speaker);
+    }
+
+    turns
+        .iter()
+        .map(|t| (t.speaker, (t.start - end).max(start - t.end)))
+        .filter(|(_, distance)| *distance <= MAX_ASSIGN_DISTANCE_SECS)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(speaker, _)| speaker)
+}
+
+/// Cut a segment into `WINDOW_SECS` windows, folding a short tail into the
+/// last window. Segments shorter than `MIN_WINDOW_SECS` yield nothing.
+fn split_segment(segment: &Segment, sample_rate: u32) -> Vec<(f64, f64, &[i16])> {
+    let window = (WINDOW_SECS * sample_rate as f64) as usize;
+    let min_window = (MIN_WINDOW_SECS * sample_rate as f64) as usize;
+    let samples = segment.samples.as_slice();
+
+    if samples.len() < min_window {
+        return vec![];
+    }
+
+    let mut bounds = Vec::new();
+    let mut offset = 0;
+    while offset < samples.len() {
+        let mut end = (offset + window).min(samples.len());
+        if samples.len() - end < min_window {
+            end = samples.len();
+        }
+        bounds.push((offset, end));
+        offset = end;
+    }
+
+    bounds
+        .into_iter()
+        .map(|(from, to)| {
+            (
+                segment.start + from as f64 / sample_rate as f64,
+                segment.start + to as f64 / sample_rate as f64,
+                &samples[from..to],
+            )
+        })
+        .collect()
+}
+
+fn merge_turns(turns: impl IntoIterator<Item = SpeakerTurn>) -> Vec<SpeakerTurn> {
+    let mut merged: Vec<SpeakerTurn> = Vec::new();
+    for turn in turns {
+        match merged.last_mut() {
+            Some(last)
+                if last.speaker == turn.speaker && turn.start - last.end < MERGE_GAP_SECS =>
+            {
+                last.end = turn.end;
+            }
+            _ => merged.push(turn),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(start: f64, end: f64, speaker: usize) -> SpeakerTurn {
+        SpeakerTurn {
+            start,
+            end,
+            speaker,
+        }
+    }
+
+    #[test]
+    fn test_split_segment() {
+        let segment = Segment {
+            start: 2.0,
+            end: 5.3,
+            samples: vec![0; 33],
+        };
+        let windows: Vec<(f64, f64)> = split_segment(&segment, 10)
+            .into_iter()
+            .map(|(start, end, _)| (start, end))
+            .collect();
+        assert_eq!(windows, [(2.0, 3.5), (3.5, 5.3)]);
+
+        let short = Segment {
+            start: 0.0,
+            end: 0.4,
+            samples: vec![0; 4],
+        };
+        assert!(split_segment(&short, 10).is_empty());
+    }
+
+    #[test]
+    fn test_merge_turns() {
+        let merged = merge_turns([
+            turn(0.0, 1.5, 0),
+            turn(1.5, 3.0, 0),
+            turn(3.0, 4.5, 1),
+            turn(6.0, 7.5, 1),
+        ]);
+        assert_eq!(
+            merged,
+            [turn(0.0, 3.0, 0), turn(3.0, 4.5, 1), turn(6.0, 7.5, 1)]
+        );
+    }
+
+    #[test]
+    fn test_assign_speaker() {
+        let turns = [turn(0.0, 3.0, 0), turn(3.0, 4.5, 1), turn(6.0, 7.5, 0)];
+
+        assert_eq!(assign_speaker(&turns, 0.5, 1.0), Some(0));
+        assert_eq!(assign_speaker(&turns, 2.8, 4.0), Some(1));
+        assert_eq!(assign_speaker(&turns, 5.6, 5.9), Some(0));
+        assert_eq!(assign_speaker(&turns, 10.0, 11.0), None);
+        assert_eq!(assign_speaker(&[], 0.0, 1.0), None);
+    }
+}
@@ -48,9 +48,12 @@ impl EmbeddingExtractor {
         Ok(embeddings)
     }
 
-    pub fn cluster(&self, _n_clusters: usize, embeddings: &[f32]) -> Vec<usize> {
-        let assignments = vec![0; embeddings.len()];
-        assignments
+    /// Groups embeddings into exactly `n_clusters` speakers. Use
+    /// `AgglomerativeClustering` directly to estimate the count instead.
+    pub fn cluster(&self, n_clusters: usize, embeddings: &[Vec<f32>]) -> Vec<usize> {
+        crate::clustering::AgglomerativeClustering::new()
+            .with_num_speakers(n_clusters)
+            .cluster(embeddings)
     }
 }
 
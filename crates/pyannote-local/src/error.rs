@@ -2,6 +2,10 @@ use serde::{Serialize, ser::Serializer};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
     #[error(transparent)]
     HyprOnnxError(#[from] hypr_onnx::Error),
     #[error(transparent)]
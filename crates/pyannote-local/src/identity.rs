@@ -0,0 +1,322 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use simsimd::SpatialSimilarity;
+
+/// File the desktop app and CLI keep voiceprints in, under the app data dir.
+pub const VOICEPRINTS_FILENAME: &str = "voiceprints.json";
+
+/// Cosine similarity above which an embedding is attributed to a known voice.
+pub const DEFAULT_MATCH_THRESHOLD: f32 = 0.5;
+
+/// Running-mean voice embedding for one person, keyed by `humans.id`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Voiceprint {
+    pub human_id: String,
+    pub embedding: Vec<f32>,
+    pub sample_count: u32,
+}
+
+impl Voiceprint {
+    pub fn new(human_id: impl Into<String>, embedding: &[f32]) -> Self {
+        Self {
+            human_id: human_id.into(),
+            embedding: normalize(embedding),
+            sample_count: 1,
+        }
+    }
+
+    /// Folds another embedding of the same voice into the centroid.
+    pub fn update(&mut self, embedding: &[f32]) {
+        if self.embedding.len() != embedding.len() || self.sample_count == 0 {
+            self.embedding = normalize(embedding);
+            self.sample_count = 1;
+            return;
+        }
+
+        let n = self.sample_count as f32;
+        let sample = normalize(embedding);
+        for (acc, x) in self.embedding.iter_mut().zip(sample) {
+            *acc = (*acc * n + x) / (n + 1.0);
+        }
+        self.embedding = normalize(&self.embedding);
+        self.sample_count = self.sample_count.saturating_add(1);
+    }
+
+    pub fn similarity(&self, embedding: &[f32]) -> f32 {
+        cosine_similarity(&self.embedding, embedding)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct SpeakerMatch {
+    pub human_id: String,
+    pub similarity: f32,
+}
+
+/// Known voices, matched against embeddings extracted by `EmbeddingExtractor`.
+#[derive(Debug, Clone)]
+pub struct VoiceprintStore {
+    voiceprints: Vec<Voiceprint>,
+    threshold: f32,
+}
+
+impl Default for VoiceprintStore {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl VoiceprintStore {
+    pub fn new(voiceprints: Vec<Voiceprint>) -> Self {
+        Self {
+            voiceprints,
+            threshold: DEFAULT_MATCH_THRESHOLD,
+        }
+    }
+
+    /// Reads voiceprints saved by `save`. A missing file is an empty store.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, crate::Error> {
+        match std::fs::read(path) {
+            Ok(bytes) => Ok(Self::new(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Writes all voiceprints, replacing the file atomically.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), crate::Error> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec(&self.voiceprints)?)?;
+        std::fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn voiceprints(&self) -> &[Voiceprint] {
+        &self.voiceprints
+    }
+
+    pub fn get(&self, human_id: &str) -> Option<&Voiceprint> {
+        self.voiceprints.iter().find(|v| v.human_id == human_id)
+    }
+
+    /// Records that `embedding` belongs to `human_id`, creating the voiceprint
+    /// on first use. Returns the updated voiceprint so callers can persist it.
+    pub fn enroll(&mut self, human_id: impl Into<String>, embedding: &[f32]) -> &Voiceprint {
+        let human_id = human_id.into();
+
+        match self.voiceprints.iter().position(|v| v.human_id == human_id) {
+            Some(idx) => {
+                self.voiceprints[idx].update(embedding);
+                &self.voiceprints[idx]
+            }
+            None => {
+                self.voiceprints.push(Voiceprint::new(human_id, embedding));
+                self.voiceprints.last().unwrap()
+            }
+        }
+    }
+
+    /// Replaces the stored voiceprint of `voiceprint.human_id`, or adds it.
+    pub fn upsert(&mut self, voiceprint: Voiceprint) {
+        match self
+            .voiceprints
+            .iter_mut()
+            .find(|v| v.human_id == voiceprint.human_id)
+        {
+            Some(existing) => *existing = voiceprint,
+            None => self.voiceprints.push(voiceprint),
+        }
+    }
+
+    pub fn remove(&mut self, human_id: &str) -> Option<Voiceprint> {
+        let idx = self
+            .voiceprints
+            .iter()
+            .position(|v| v.human_id == human_id)?;
+        Some(self.voiceprints.remove(idx))
+    }
+
+    pub fn identify(&self, embedding: &[f32]) -> Option<SpeakerMatch> {
+        self.voiceprints
+            .iter()
+            .map(|v| SpeakerMatch {
+                human_id: v.human_id.clone(),
+                similarity: v.similarity(embedding),
+            })
+            .filter(|m| m.similarity >= self.threshold)
+            .max_by(|a, b| a.similarity.total_cmp(&b.similarity))
+    }
+}
+
+/// Per-session embeddings for each diarized `speaker_index`.
+#[derive(Debug, Clone, Default)]
+pub struct SessionSpeakers {
+    speakers: BTreeMap<i32, Voiceprint>,
+}
+
+impl SessionSpeakers {
+    pub fn add(&mut self, speaker_index: i32, embedding: &[f32]) {
+        self.speakers
+            .entry(speaker_index)
+            .and_modify(|v| v.update(embedding))
+            .or_insert_with(|| Voiceprint::new(String::new(), embedding));
+    }
+
+    pub fn embedding(&self, speaker_index: i32) -> Option<&[f32]> {
+        self.speakers
+            .get(&speaker_index)
+            .map(|v| v.embedding.as_slice())
+    }
+
+    /// Applies a user label ("speaker 2 = Alice") to the store, so later
+    /// sessions recognize the voice. Returns `None` if the speaker was never heard.
+    pub fn label<'a>(
+        &self,
+        store: &'a mut VoiceprintStore,
+        speaker_index: i32,
+        human_id: impl Into<String>,
+    ) -> Option<&'a Voiceprint> {
+        let embedding = self.embedding(speaker_index)?;
+        Some(store.enroll(human_id, embedding))
+    }
+
+    /// Matches session speakers against known voices. Each person is assigned
+    /// to at most one speaker, preferring the most similar pair.
+    pub fn resolve(&self, store: &VoiceprintStore) -> BTreeMap<i32, SpeakerMatch> {
+        let mut candidates: Vec<(i32, SpeakerMatch)> = self
+            .speakers
+            .iter()
+            .flat_map(|(&speaker_index, speaker)| {
+                store.voiceprints.iter().filter_map(move |v| {
+                    let similarity = v.similarity(&speaker.embedding);
+                    (similarity >= store.threshold).then(|| {
+                        (
+                            speaker_index,
+                            SpeakerMatch {
+                                human_id: v.human_id.clone(),
+                                similarity,
+                            },
+                        )
+                    })
+                })
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.1.similarity.total_cmp(&a.1.similarity));
+
+        let mut resolved = BTreeMap::new();
+        for (speaker_index, candidate) in candidates {
+            if resolved.contains_key(&speaker_index)
+                || resolved
+                    .values()
+                    .any(|m: &SpeakerMatch| m.human_id == candidate.human_id)
+            {
+                continue;
+            }
+            resolved.insert(speaker_index, candidate);
+        }
+        resolved
+    }
+}
+
+fn normalize(embedding: &[f32]) -> Vec<f32> {
+    let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return embedding.to_vec();
+    }
+    embedding.iter().map(|x| x / norm).collect()
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return -1.0;
+    }
+    f32::cosine(a, b)
+        .map(|distance| 1.0 - distance as f32)
+        .unwrap_or(-1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: [f32; 3] = [1.0, 0.1, 0.0];
+    const BOB: [f32; 3] = [0.0, 1.0, 0.1];
+
+    #[test]
+    fn test_identify_known_voice() {
+        let mut store = VoiceprintStore::default();
+        store.enroll("alice", &ALICE);
+        store.enroll("bob", &BOB);
+
+        let m = store.identify(&[0.9, 0.2, 0.0]).unwrap();
+        assert_eq!(m.human_id, "alice");
+        assert!(m.similarity > 0.9);
+
+        assert!(store.identify(&[0.0, 0.0, 1.0]).is_none());
+    }
+
+    #[test]
+    fn test_enroll_updates_centroid() {
+        let mut store = VoiceprintStore::default();
+        store.enroll("alice", &[1.0, 0.0]);
+        let voiceprint = store.enroll("alice", &[0.0, 1.0]);
+
+        assert_eq!(voiceprint.sample_count, 2);
+        approx::assert_abs_diff_eq!(voiceprint.embedding[0], voiceprint.embedding[1]);
+        assert_eq!(store.voiceprints().len(), 1);
+    }
+
+    #[test]
+    fn test_label_then_resolve_next_session() {
+        let mut store = VoiceprintStore::default();
+
+        let mut first = SessionSpeakers::default();
+        first.add(0, &BOB);
+        first.add(2, &ALICE);
+        first.label(&mut store, 2, "alice").unwrap();
+        assert!(first.label(&mut store, 5, "carol").is_none());
+
+        let mut second = SessionSpeakers::default();
+        second.add(0, &[0.95, 0.15, 0.0]);
+        second.add(1, &[0.9, 0.05, 0.05]);
+        second.add(3, &BOB);
+
+        let resolved = second.resolve(&store);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[&0].human_id, "alice");
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(VOICEPRINTS_FILENAME);
+        assert!(
+            VoiceprintStore::load(&path)
+                .unwrap()
+                .voiceprints()
+                .is_empty()
+        );
+
+        let mut store = VoiceprintStore::default();
+        store.enroll("alice", &ALICE);
+        store.save(&path).unwrap();
+
+        let mut loaded = VoiceprintStore::load(&path).unwrap();
+        assert_eq!(loaded.voiceprints(), store.voiceprints());
+
+        loaded.upsert(Voiceprint::new("alice", &BOB));
+        loaded.upsert(Voiceprint::new("bob", &BOB));
+        assert_eq!(loaded.voiceprints().len(), 2);
+        assert_eq!(
+            loaded.get("alice").unwrap(),
+            &Voiceprint::new("alice", &BOB)
+        );
+    }
+}
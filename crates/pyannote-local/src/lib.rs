@@ -1,4 +1,7 @@
+pub mod clustering;
+pub mod diarization;
 pub mod embedding;
+pub mod identity;
 pub mod segmentation;
 
 mod error;
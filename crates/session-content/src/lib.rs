@@ -0,0 +1,11 @@
+mod error;
+mod load;
+mod types;
+
+pub use error::{Error, Result};
+pub use load::{load_session_content, read_session_meta};
+pub use types::*;
+
+pub const SESSION_META_FILE: &str = "_meta.json";
+pub const SESSION_MEMO_FILE: &str = "_memo.md";
+pub const SESSION_TRANSCRIPT_FILE: &str = "transcript.json";
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use hypr_frontmatter::{Document, Error as FrontmatterError};
+use serde_yaml::Value;
+
+use crate::{
+    Result, SESSION_MEMO_FILE, SESSION_META_FILE, SESSION_TRANSCRIPT_FILE, SessionContent,
+    SessionMeta, SessionNote, Transcript,
+};
+
+pub fn read_session_meta(session_dir: &Path) -> Result<SessionMeta> {
+    let content = std::fs::read_to_string(session_dir.join(SESSION_META_FILE))?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+pub fn load_session_content(session_id: &str, session_dir: &Path) -> SessionContent {
+    let mut content = SessionContent {
+        session_id: session_id.to_string(),
+        ..Default::default()
+    };
+
+    let Ok(entries) = std::fs::read_dir(session_dir) else {
+        return content;
+    };
+    let mut paths: Vec<_> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let Some(name) = path.file_name().and_then(|v| v.to_str()) else {
+            continue;
+        };
+        let Ok(file_content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+
+        if name == SESSION_META_FILE {
+            content.meta = serde_json::from_str::<SessionMeta>(&file_content).ok();
+            continue;
+        }
+
+        if name == SESSION_TRANSCRIPT_FILE {
+            content.transcript = serde_json::from_str::<Transcript>(&file_content).ok();
+            continue;
+        }
+
+        if !name.ends_with(".md") {
+            continue;
+        }
+
+        let Some((frontmatter, body)) = parse_markdown(&file_content) else {
+            continue;
+        };
+        let frontmatter_str = |key: &str| frontmatter.get(key).and_then(Value::as_str);
+
+        // The memo belongs to its directory; notes must name the session.
+        if name == SESSION_MEMO_FILE {
+            if frontmatter_str("session_id").is_none_or(|id| id == session_id) {
+                content.memo = Some(body);
+            }
+            continue;
+        }
+
+        if frontmatter_str("session_id") != Some(session_id) {
+            continue;
+        }
+
+        content.notes.push(SessionNote {
+            id: frontmatter_str("id").unwrap_or_default().to_string(),
+            template_id: frontmatter_str("template_id").map(str::to_string),
+            position: frontmatter.get("position").and_then(Value::as_i64),
+            title: frontmatter_str("title").map(str::to_string),
+            content: body,
+        });
+    }
+
+    content
+}
+
+/// Frontmatter and body. A file without frontmatter is all body.
+fn parse_markdown(content: &str) -> Option<(HashMap<String, Value>, String)> {
+    match Document::<HashMap<String, Value>>::from_str(content) {
+        Ok(doc) => Some((doc.frontmatter, doc.content)),
+        Err(FrontmatterError::MissingOpeningDelimiter) => {
+            Some((HashMap::new(), content.to_string()))
+        }
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Speaker;
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn loads_session_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        write(
+            root,
+            "_meta.json",
+            r#"{"id":"s1","user_id":"u","created_at":"2024-03-05T10:00:00Z","title":"Acme renewal","participants":[{"id":"p","user_id":"u","session_id":"s1","human_id":"alice","source":"manual"}],"tags":["customer"]}"#,
+        );
+        write(
+            root,
+            "_memo.md",
+            "---\nid: s1\nsession_id: s1\n---\n\nPricing pushback.",
+        );
+        write(
+            root,
+            "Summary.md",
+            "---\nid: n1\nsession_id: s1\ntemplate_id: t1\nposition: 2\ntitle: Summary\n---\n\n- Wants a discount",
+        );
+        write(
+            root,
+            "Stray.md",
+            "---\nid: n2\nsession_id: other\n---\n\nNot this session",
+        );
+        write(
+            root,
+            "transcript.json",
+            r#"{"transcripts":[{"id":"t","session_id":"s1","started_at":0,"words":[{"id":"w1","text":" Hi","start_ms":0,"end_ms":400,"channel":1}],"speaker_hints":[{"id":"h","word_id":"w1","type":"user_speaker_assignment","value":"{\"human_id\":\"alice\"}"}]}]}"#,
+        );
+
+        let content = load_session_content("s1", root);
+
+        let meta = content.meta.unwrap();
+        assert_eq!(meta.title.as_deref(), Some("Acme renewal"));
+        assert_eq!(meta.participants[0].human_id, "alice");
+        assert_eq!(meta.tags, vec!["customer"]);
+
+        assert_eq!(content.memo.as_deref(), Some("Pricing pushback."));
+
+        assert_eq!(content.notes.len(), 1);
+        let note = &content.notes[0];
+        assert_eq!(note.id, "n1");
+        assert_eq!(note.template_id.as_deref(), Some("t1"));
+        assert_eq!(note.position, Some(2));
+        assert_eq!(note.title.as_deref(), Some("Summary"));
+        assert_eq!(note.content, "- Wants a discount");
+
+        let transcript = &content.transcript.unwrap().transcripts[0];
+        assert_eq!(transcript.words[0].text, " Hi");
+        assert_eq!(
+            transcript.speaker_hints[0].speaker(),
+            Some(Speaker::Human("alice".to_string()))
+        );
+    }
+
+    #[test]
+    fn tolerates_missing_and_broken_files() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "_meta.json", "{");
+
+        let content = load_session_content("s1", dir.path());
+        assert!(content.meta.is_none());
+        assert!(content.transcript.is_none());
+        assert!(read_session_meta(dir.path()).is_err());
+
+        let content = load_session_content("s1", &dir.path().join("missing"));
+        assert_eq!(content.session_id, "s1");
+        assert!(content.notes.is_empty());
+    }
+}
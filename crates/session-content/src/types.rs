@@ -0,0 +1,134 @@
+use serde::Deserialize;
+
+/// `_meta.json`, as the desktop app writes it.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SessionMeta {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub event: Option<serde_json::Value>,
+    #[serde(default)]
+    pub event_id: Option<String>,
+    #[serde(default)]
+    pub participants: Vec<SessionParticipant>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionParticipant {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub session_id: String,
+    pub human_id: String,
+    #[serde(default)]
+    pub source: String,
+}
+
+/// `transcript.json`: one entry per recording of the session.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Transcript {
+    #[serde(default)]
+    pub transcripts: Vec<TranscriptEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TranscriptEntry {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub session_id: String,
+    #[serde(default)]
+    pub started_at: Option<f64>,
+    #[serde(default)]
+    pub ended_at: Option<f64>,
+    #[serde(default)]
+    pub words: Vec<TranscriptWord>,
+    #[serde(default)]
+    pub speaker_hints: Vec<SpeakerHint>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TranscriptWord {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub text: String,
+    pub start_ms: f64,
+    pub end_ms: f64,
+    #[serde(default)]
+    pub channel: i64,
+}
+
+/// Who spoke a word, either assigned by the user or guessed by the provider.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpeakerHint {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub word_id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Speaker {
+    Human(String),
+    Index(i64),
+}
+
+impl SpeakerHint {
+    pub fn speaker(&self) -> Option<Speaker> {
+        // Stored either as an object or as its JSON-encoded string.
+        let value = match &self.value {
+            serde_json::Value::String(s) => serde_json::from_str(s).unwrap_or_default(),
+            value => value.clone(),
+        };
+
+        match self.kind.as_str() {
+            "user_speaker_assignment" => value
+                .get("human_id")
+                .and_then(|v| v.as_str())
+                .map(|id| Speaker::Human(id.to_string())),
+            "provider_speaker_index" => value
+                .get("speaker_index")
+                .and_then(|v| v.as_i64())
+                .map(Speaker::Index),
+            _ => None,
+        }
+    }
+}
+
+/// A generated note, with its Markdown body.
+#[derive(Debug, Clone)]
+pub struct SessionNote {
+    pub id: String,
+    pub template_id: Option<String>,
+    pub position: Option<i64>,
+    pub title: Option<String>,
+    pub content: String,
+}
+
+/// Everything in a session directory. Files that are missing or don't
+/// parse are left out.
+#[derive(Debug, Clone, Default)]
+pub struct SessionContent {
+    pub session_id: String,
+    pub meta: Option<SessionMeta>,
+    /// The user's own notes, as Markdown.
+    pub memo: Option<String>,
+    pub transcript: Option<Transcript>,
+    pub notes: Vec<SessionNote>,
+}
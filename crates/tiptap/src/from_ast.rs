@@ -3,8 +3,14 @@ use markdown::mdast;
 pub fn mdast_to_markdown(node: &mdast::Node) -> Result<String, String> {
     let task_items = collect_task_items(node);
 
+    // Tables are written here rather than by `mdast_util_to_markdown`; each
+    // one is swapped for a placeholder that is filled in at the end.
+    let mut node = node.clone();
+    let mut tables = Vec::new();
+    extract_tables(&mut node, &mut tables);
+
     let md = mdast_util_to_markdown::to_markdown_with_options(
-        node,
+        &node,
         &mdast_util_to_markdown::Options {
             bullet: '-',
             ..Default::default()
@@ -13,7 +19,83 @@ pub fn mdast_to_markdown(node: &mdast::Node) -> Result<String, String> {
     .map_err(|e| e.to_string())?;
 
     let md = inject_task_checkboxes(&md, &task_items);
-    Ok(unescape_markdown(&md))
+    let mut md = unescape_markdown(&md);
+    for (i, table) in tables.iter().enumerate() {
+        md = md.replace(&table_placeholder(i), &table_to_markdown(table)?);
+    }
+    Ok(md)
+}
+
+fn table_placeholder(index: usize) -> String {
+    format!("<!--table-{index}-->")
+}
+
+fn extract_tables(node: &mut mdast::Node, tables: &mut Vec<mdast::Table>) {
+    let Some(children) = node.children_mut() else {
+        return;
+    };
+
+    for child in children.iter_mut() {
+        if let mdast::Node::Table(table) = child {
+            let value = table_placeholder(tables.len());
+            tables.push(table.clone());
+            *child = mdast::Node::Html(mdast::Html {
+                value,
+                position: None,
+            });
+        } else {
+            extract_tables(child, tables);
+        }
+    }
+}
+
+/// A GFM pipe table; the first row is the header.
+fn table_to_markdown(table: &mdast::Table) -> Result<String, String> {
+    let mut rows = Vec::new();
+    for row in &table.children {
+        let mut cells = Vec::new();
+        for cell in row.children().into_iter().flatten() {
+            let paragraph = mdast::Node::Paragraph(mdast::Paragraph {
+                children: cell.children().cloned().unwrap_or_default(),
+                position: None,
+            });
+            let text = mdast_to_markdown(&paragraph)?;
+            cells.push(text.trim().replace('\n', " ").replace('|', "\\|"));
+        }
+        rows.push(cells);
+    }
+
+    let columns = rows
+        .iter()
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+        .max(table.align.len());
+    if columns == 0 {
+        return Ok(String::new());
+    }
+
+    let line = |cells: &[String]| {
+        let mut cells = cells.to_vec();
+        cells.resize(columns, String::new());
+        format!("| {} |", cells.join(" | "))
+    };
+    let delimiter: Vec<String> = (0..columns)
+        .map(|i| {
+            match table.align.get(i) {
+                Some(mdast::AlignKind::Left) => ":--",
+                Some(mdast::AlignKind::Right) => "--:",
+                Some(mdast::AlignKind::Center) => ":-:",
+                _ => "---",
+            }
+            .to_string()
+        })
+        .collect();
+
+    let mut lines = vec![line(rows.first().map(Vec::as_slice).unwrap_or_default())];
+    lines.push(line(&delimiter));
+    lines.extend(rows.iter().skip(1).map(|row| line(row)));
+    Ok(lines.join("\n"))
 }
 
 fn unescape_markdown(md: &str) -> String {
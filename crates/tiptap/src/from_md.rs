@@ -36,6 +36,7 @@ fn convert_block_node(node: &mdast::Node) -> Option<Value> {
         mdast::Node::Blockquote(b) => Some(convert_blockquote(b)),
         mdast::Node::ThematicBreak(_) => Some(json!({ "type": "horizontalRule" })),
         mdast::Node::Image(img) => Some(convert_image(img)),
+        mdast::Node::Table(t) => Some(convert_table(t)),
         _ => None,
     }
 }
@@ -171,6 +172,43 @@ fn convert_blockquote(b: &mdast::Blockquote) -> Value {
     })
 }
 
+/// The first row of a GFM table is its header.
+fn convert_table(t: &mdast::Table) -> Value {
+    let rows: Vec<Value> = t
+        .children
+        .iter()
+        .enumerate()
+        .filter_map(|(i, row)| {
+            let mdast::Node::TableRow(row) = row else {
+                return None;
+            };
+            let cell_type = if i == 0 { "tableHeader" } else { "tableCell" };
+            let cells: Vec<Value> = row
+                .children
+                .iter()
+                .filter_map(|cell| {
+                    let mdast::Node::TableCell(cell) = cell else {
+                        return None;
+                    };
+                    let content = convert_inline_nodes(&cell.children);
+                    let paragraph = if content.is_empty() {
+                        json!({ "type": "paragraph" })
+                    } else {
+                        json!({ "type": "paragraph", "content": content })
+                    };
+                    Some(json!({ "type": cell_type, "content": [paragraph] }))
+                })
+                .collect();
+            Some(json!({ "type": "tableRow", "content": cells }))
+        })
+        .collect();
+
+    json!({
+        "type": "table",
+        "content": rows
+    })
+}
+
 fn convert_image(img: &mdast::Image) -> Value {
     let mut attrs = json!({ "src": img.url });
     if !img.alt.is_empty() {
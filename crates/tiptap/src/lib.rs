@@ -215,6 +215,52 @@ mod tests {
         insta::assert_snapshot!(to_md(json), @r#"![example image](https://example.com/image.png "Example")"#);
     }
 
+    #[test]
+    fn test_table() {
+        let cell = |cell_type: &str, content: serde_json::Value| {
+            serde_json::json!({
+                "type": cell_type,
+                "content": [{ "type": "paragraph", "content": content }]
+            })
+        };
+        let json = serde_json::json!({
+            "type": "doc",
+            "content": [
+                {
+                    "type": "paragraph",
+                    "content": [{ "type": "text", "text": "Owners:" }]
+                },
+                {
+                    "type": "table",
+                    "content": [
+                        {
+                            "type": "tableRow",
+                            "content": [
+                                cell("tableHeader", serde_json::json!([{ "type": "text", "text": "Task" }])),
+                                cell("tableHeader", serde_json::json!([{ "type": "text", "text": "Owner" }]))
+                            ]
+                        },
+                        {
+                            "type": "tableRow",
+                            "content": [
+                                cell("tableCell", serde_json::json!([{ "type": "text", "text": "Ship it", "marks": [{ "type": "bold" }] }])),
+                                cell("tableCell", serde_json::json!([{ "type": "text", "text": "Alice | Bob" }]))
+                            ]
+                        }
+                    ]
+                }
+            ]
+        });
+
+        insta::assert_snapshot!(to_md(json), @r"
+        Owners:
+
+        | Task | Owner |
+        | --- | --- |
+        | **Ship it** | Alice \| Bob |
+        ");
+    }
+
     #[test]
     fn test_md_to_tiptap_basic() {
         let md = "# Hello\n\nWorld";
@@ -804,6 +850,19 @@ mod tests {
         assert_eq!(result.trim(), md);
     }
 
+    #[test]
+    fn test_roundtrip_table() {
+        let md = "| Task | Owner |\n| --- | --- |\n| Ship it | Alice |";
+        let json = md_to_tiptap_json(md).unwrap();
+        assert_eq!(json["content"][0]["type"], "table");
+        assert_eq!(
+            json["content"][0]["content"][0]["content"][0]["type"],
+            "tableHeader"
+        );
+        let result = to_md(json);
+        assert_eq!(result.trim(), md);
+    }
+
     #[test]
     fn test_roundtrip_complex_document() {
         let md =
@@ -32,6 +32,7 @@ fn convert_node(node: &serde_json::Value) -> Option<mdast::Node> {
         "horizontalRule" => Some(convert_horizontal_rule()),
         "hardBreak" => Some(convert_hard_break()),
         "image" => Some(convert_image(node)),
+        "table" => Some(convert_table(node)),
         "text" => convert_text(node),
         _ => None,
     }
@@ -195,6 +196,66 @@ fn convert_hard_break() -> mdast::Node {
     mdast::Node::Break(mdast::Break { position: None })
 }
 
+fn convert_table(node: &serde_json::Value) -> mdast::Node {
+    let rows: Vec<mdast::Node> = node
+        .get("content")
+        .and_then(|c| c.as_array())
+        .map(|rows| {
+            rows.iter()
+                .filter(|row| row.get("type").and_then(|t| t.as_str()) == Some("tableRow"))
+                .map(convert_table_row)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let columns = rows
+        .iter()
+        .map(|row| row.children().map_or(0, |cells| cells.len()))
+        .max()
+        .unwrap_or(0);
+
+    mdast::Node::Table(mdast::Table {
+        align: vec![mdast::AlignKind::None; columns],
+        children: rows,
+        position: None,
+    })
+}
+
+fn convert_table_row(node: &serde_json::Value) -> mdast::Node {
+    let cells = node
+        .get("content")
+        .and_then(|c| c.as_array())
+        .map(|cells| cells.iter().map(convert_table_cell).collect())
+        .unwrap_or_default();
+
+    mdast::Node::TableRow(mdast::TableRow {
+        children: cells,
+        position: None,
+    })
+}
+
+/// Table cells hold blocks in tiptap but only inline content in mdast, so
+/// paragraphs are joined with line breaks.
+fn convert_table_cell(node: &serde_json::Value) -> mdast::Node {
+    let mut children = Vec::new();
+    for block in node
+        .get("content")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+    {
+        if !children.is_empty() {
+            children.push(convert_hard_break());
+        }
+        children.extend(convert_inline_content(block));
+    }
+
+    mdast::Node::TableCell(mdast::TableCell {
+        children,
+        position: None,
+    })
+}
+
 fn convert_image(node: &serde_json::Value) -> mdast::Node {
     let attrs = node.get("attrs");
     let url = attrs
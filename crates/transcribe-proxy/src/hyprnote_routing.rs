@@ -5,6 +5,7 @@ use owhisper_client::{AdapterKind, LanguageSupport, Provider};
 
 const DEFAULT_NUM_RETRIES: usize = 2;
 const DEFAULT_MAX_DELAY_SECS: u64 = 5;
+const DEFAULT_REPLAY_BUFFER_SECS: u64 = 10;
 
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
@@ -21,10 +22,26 @@ impl Default for RetryConfig {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct FailoverConfig {
+    pub enabled: bool,
+    pub replay_buffer_secs: u64,
+}
+
+impl Default for FailoverConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            replay_buffer_secs: DEFAULT_REPLAY_BUFFER_SECS,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HyprnoteRoutingConfig {
     pub priorities: Vec<Provider>,
     pub retry_config: RetryConfig,
+    pub failover: FailoverConfig,
 }
 
 impl Default for HyprnoteRoutingConfig {
@@ -40,6 +57,7 @@ impl Default for HyprnoteRoutingConfig {
                 Provider::OpenAI,
             ],
             retry_config: RetryConfig::default(),
+            failover: FailoverConfig::default(),
         }
     }
 }
@@ -47,6 +65,7 @@ impl Default for HyprnoteRoutingConfig {
 pub struct HyprnoteRouter {
     priorities: Vec<Provider>,
     retry_config: RetryConfig,
+    failover: FailoverConfig,
 }
 
 impl HyprnoteRouter {
@@ -54,6 +73,7 @@ impl HyprnoteRouter {
         Self {
             priorities: config.priorities,
             retry_config: config.retry_config,
+            failover: config.failover,
         }
     }
 
@@ -125,6 +145,10 @@ impl HyprnoteRouter {
     pub fn retry_config(&self) -> &RetryConfig {
         &self.retry_config
     }
+
+    pub fn failover_config(&self) -> &FailoverConfig {
+        &self.failover
+    }
 }
 
 impl Default for HyprnoteRouter {
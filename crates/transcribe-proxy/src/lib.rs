@@ -17,7 +17,7 @@ pub use env::{ApiKeys, Env};
 pub use error::*;
 pub use hypr_analytics::{AuthenticatedUserId, DeviceFingerprint};
 pub use hyprnote_routing::{
-    HyprnoteRouter, HyprnoteRoutingConfig, RetryConfig, is_retryable_error,
+    FailoverConfig, HyprnoteRouter, HyprnoteRoutingConfig, RetryConfig, is_retryable_error,
 };
 pub use openapi::openapi;
 pub use provider_selector::{ProviderSelector, SelectedProvider};
@@ -2,9 +2,10 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 
-use owhisper_client::Auth;
+use owhisper_client::{Auth, Provider};
 pub use tokio_tungstenite::tungstenite::ClientRequestBuilder;
 
+use super::failover::UpstreamTarget;
 use super::handler::WebSocketProxy;
 use super::types::{FirstMessageTransformer, InitialMessage, OnCloseCallback, ResponseTransformer};
 use crate::config::DEFAULT_CONNECT_TIMEOUT_MS;
@@ -174,17 +175,21 @@ impl<S: HasHeaders> WebSocketProxyBuilder<S> {
 }
 
 impl WebSocketProxyBuilder<WithUrl> {
-    pub fn build(self) -> Result<WebSocketProxy, crate::ProxyError> {
-        let uri = self
-            .state
+    fn request(state: WithUrl) -> Result<ClientRequestBuilder, crate::ProxyError> {
+        let uri = state
             .url
             .parse()
             .map_err(|e| crate::ProxyError::InvalidRequest(format!("{}", e)))?;
 
         let mut request = ClientRequestBuilder::new(uri);
-        for (key, value) in self.state.headers {
+        for (key, value) in state.headers {
             request = request.with_header(&key, &value);
         }
+        Ok(request)
+    }
+
+    pub fn build(self) -> Result<WebSocketProxy, crate::ProxyError> {
+        let request = Self::request(self.state)?;
 
         Ok(Self::build_from(
             request,
@@ -196,6 +201,26 @@ impl WebSocketProxyBuilder<WithUrl> {
             self.on_close,
         ))
     }
+
+    /// One link of a failover chain, configured exactly like a standalone
+    /// proxy. `connect_timeout` and `on_close` are set on the
+    /// [`FailoverProxy`](super::FailoverProxy) instead.
+    pub fn build_target(self, provider: Provider) -> Result<UpstreamTarget, crate::ProxyError> {
+        let control_message_types = if self.control_message_types.is_empty() {
+            None
+        } else {
+            Some(Arc::new(self.control_message_types))
+        };
+
+        Ok(UpstreamTarget {
+            provider,
+            request: Self::request(self.state)?,
+            control_message_types,
+            transform_first_message: self.transform_first_message,
+            initial_message: self.initial_message,
+            response_transformer: self.response_transformer,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -482,6 +507,31 @@ mod tests {
         assert_eq!(transformer("test"), None);
     }
 
+    #[test]
+    fn test_build_target_keeps_settings() {
+        let target = WebSocketProxyBuilder::default()
+            .control_message_types(&["KeepAlive"])
+            .transform_first_message(|msg| format!("auth:{}", msg))
+            .initial_message(r#"{"init":true}"#)
+            .response_transformer(|raw| Some(raw.to_string()))
+            .upstream_url("wss://api.example.com/listen")
+            .header("Authorization", "Bearer token")
+            .build_target(Provider::Deepgram)
+            .unwrap();
+
+        assert_eq!(target.provider, Provider::Deepgram);
+        assert!(
+            target
+                .control_message_types
+                .as_ref()
+                .is_some_and(|types| types.contains("KeepAlive"))
+        );
+        let transformer = target.transform_first_message.unwrap();
+        assert_eq!(transformer("hi".to_string()), "auth:hi");
+        assert!(target.initial_message.is_some());
+        assert!(target.response_transformer.is_some());
+    }
+
     #[test]
     fn test_chaining_new_options() {
         let builder = WebSocketProxyBuilder::default()
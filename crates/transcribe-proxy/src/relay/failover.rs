@@ -0,0 +1,543 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::body::Body;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::http::Response;
+use axum::response::IntoResponse;
+use futures_util::{SinkExt, StreamExt};
+use sentry::SentryFutureExt;
+use tokio_tungstenite::tungstenite::ClientRequestBuilder;
+use tokio_tungstenite::tungstenite::Message as TungsteniteMessage;
+use tokio_tungstenite::{connect_async, tungstenite::client::IntoClientRequest};
+
+use owhisper_client::Provider;
+
+use super::pending::{FlushError, PendingState, QueuedPayload};
+use super::replay_buffer::{AudioReplayBuffer, TimestampRebaser};
+use super::types::{
+    ClientReceiver, ClientSender, ControlMessageTypes, DEFAULT_CLOSE_CODE, FirstMessageTransformer,
+    InitialMessage, ProviderOnCloseCallback, ResponseTransformer, UpstreamReceiver, UpstreamSender,
+    convert, is_control_message,
+};
+
+const NORMAL_CLOSE_CODE: u16 = 1000;
+
+/// Built with [`WebSocketProxyBuilder::build_target`](super::builder::WebSocketProxyBuilder::build_target).
+#[derive(Clone)]
+pub struct UpstreamTarget {
+    pub provider: Provider,
+    pub request: ClientRequestBuilder,
+    pub control_message_types: Option<ControlMessageTypes>,
+    pub transform_first_message: Option<FirstMessageTransformer>,
+    pub initial_message: Option<InitialMessage>,
+    pub response_transformer: Option<ResponseTransformer>,
+}
+
+struct ActiveUpstream {
+    index: usize,
+    sender: UpstreamSender,
+    receiver: UpstreamReceiver,
+    connected_at: Instant,
+    /// Applied to the first text message this upstream receives.
+    first_message_transformer: Option<FirstMessageTransformer>,
+}
+
+enum ForwardResult {
+    Sent,
+    SendFailed,
+    Rejected(&'static str),
+}
+
+enum RelayStep {
+    Client(Option<Result<Message, axum::Error>>),
+    Upstream(Option<Result<TungsteniteMessage, tokio_tungstenite::tungstenite::Error>>),
+}
+
+enum UpstreamEvent {
+    Forward(String),
+    Skip,
+    Closed,
+    Dropped(u16, String),
+}
+
+/// Relays a single-channel session to the first reachable target and, if that
+/// upstream drops mid-stream, reconnects to the next one in the chain and
+/// replays the most recent audio so the client sees one continuous session.
+#[derive(Clone)]
+pub struct FailoverProxy {
+    targets: Arc<Vec<UpstreamTarget>>,
+    replay_window_secs: u64,
+    sample_rate: u32,
+    channels: u8,
+    connect_timeout: Duration,
+    on_close: Option<ProviderOnCloseCallback>,
+}
+
+impl FailoverProxy {
+    pub fn new(
+        targets: Vec<UpstreamTarget>,
+        replay_window_secs: u64,
+        sample_rate: u32,
+        channels: u8,
+        connect_timeout: Duration,
+        on_close: Option<ProviderOnCloseCallback>,
+    ) -> Self {
+        Self {
+            targets: Arc::new(targets),
+            replay_window_secs,
+            sample_rate,
+            channels,
+            connect_timeout,
+            on_close,
+        }
+    }
+
+    pub async fn handle_upgrade(&self, ws: WebSocketUpgrade) -> Response<Body> {
+        let proxy = self.clone();
+        let hub = sentry::Hub::current();
+        ws.on_upgrade(move |socket| {
+            async move {
+                if let Err(e) = proxy.handle(socket).await {
+                    tracing::error!(error = %e, "failover_proxy_error");
+                }
+            }
+            .bind_hub(sentry::Hub::new_from_top(hub))
+        })
+        .into_response()
+    }
+
+    async fn handle(&self, client_socket: WebSocket) -> Result<(), crate::ProxyError> {
+        let upstream = self.connect_from(0).await?;
+
+        let start_time = Instant::now();
+        let served = self.run_relay(client_socket, upstream).await;
+
+        // Usage is attributed to whichever provider served each stretch.
+        if let Some(on_close) = &self.on_close {
+            for (provider, duration) in served {
+                on_close(provider, duration).await;
+            }
+        }
+
+        tracing::info!(
+            duration_secs = %start_time.elapsed().as_secs_f64(),
+            "failover_proxy_closed"
+        );
+
+        Ok(())
+    }
+
+    async fn connect_target(
+        &self,
+        target: &UpstreamTarget,
+    ) -> Result<(UpstreamSender, UpstreamReceiver), crate::ProxyError> {
+        let req = target
+            .request
+            .clone()
+            .into_client_request()
+            .map_err(|e| crate::ProxyError::InvalidRequest(e.to_string()))?;
+
+        tracing::info!(provider = ?target.provider, "connecting_to_upstream");
+
+        let (stream, _) = match tokio::time::timeout(self.connect_timeout, connect_async(req)).await
+        {
+            Ok(Ok(v)) => v,
+            Ok(Err(e)) => return Err(crate::ProxyError::ConnectionFailed(e.to_string())),
+            Err(_) => return Err(crate::ProxyError::ConnectionTimeout),
+        };
+
+        let (mut sender, receiver) = stream.split();
+        if let Some(msg) = &target.initial_message {
+            sender
+                .send(TungsteniteMessage::Text(msg.as_str().into()))
+                .await
+                .map_err(|e| crate::ProxyError::ConnectionFailed(e.to_string()))?;
+        }
+
+        Ok((sender, receiver))
+    }
+
+    async fn connect_from(&self, start: usize) -> Result<ActiveUpstream, crate::ProxyError> {
+        let mut last_error = crate::ProxyError::ConnectionFailed("no upstream available".into());
+
+        for (index, target) in self.targets.iter().enumerate().skip(start) {
+            match self.connect_target(target).await {
+                Ok((sender, receiver)) => {
+                    return Ok(ActiveUpstream {
+                        index,
+                        sender,
+                        receiver,
+                        connected_at: Instant::now(),
+                        first_message_transformer: target.transform_first_message.clone(),
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        provider = ?target.provider,
+                        "upstream_connect_failed"
+                    );
+                    last_error = e;
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+
+    async fn fail_over(
+        &self,
+        from: usize,
+        buffer: &AudioReplayBuffer,
+        rebaser: &mut TimestampRebaser,
+    ) -> Option<ActiveUpstream> {
+        let mut start = from + 1;
+
+        'targets: loop {
+            let mut upstream = self.connect_from(start).await.ok()?;
+
+            for chunk in buffer.chunks() {
+                if upstream
+                    .sender
+                    .send(TungsteniteMessage::Binary(chunk.to_vec().into()))
+                    .await
+                    .is_err()
+                {
+                    tracing::warn!(
+                        provider = ?self.targets[upstream.index].provider,
+                        "failover_replay_failed"
+                    );
+                    start = upstream.index + 1;
+                    continue 'targets;
+                }
+            }
+
+            rebaser.set_offset(buffer.start_secs());
+
+            tracing::warn!(
+                from = ?self.targets[from].provider,
+                to = ?self.targets[upstream.index].provider,
+                replayed_secs = %buffer.buffered_secs(),
+                "upstream_failover"
+            );
+
+            return Some(upstream);
+        }
+    }
+
+    fn classify_upstream(
+        target: &UpstreamTarget,
+        msg_opt: Option<Result<TungsteniteMessage, tokio_tungstenite::tungstenite::Error>>,
+        client_closing: bool,
+    ) -> UpstreamEvent {
+        let msg = match msg_opt {
+            Some(Ok(msg)) => msg,
+            Some(Err(e)) => {
+                tracing::error!(error = %e, "upstream_receive_error");
+                return UpstreamEvent::Dropped(
+                    DEFAULT_CLOSE_CODE,
+                    format!("upstream_error: {}", e),
+                );
+            }
+            None if client_closing => return UpstreamEvent::Closed,
+            None => {
+                return UpstreamEvent::Dropped(
+                    DEFAULT_CLOSE_CODE,
+                    "upstream_disconnected".to_string(),
+                );
+            }
+        };
+
+        match msg {
+            TungsteniteMessage::Text(text) => {
+                if let Some(upstream_err) = Provider::detect_any_error(text.as_bytes()) {
+                    tracing::warn!(
+                        error_code = upstream_err.http_code,
+                        provider_code = ?upstream_err.provider_code,
+                        error_message = %upstream_err.message,
+                        "upstream_error_detected"
+                    );
+                    return UpstreamEvent::Dropped(
+                        upstream_err.to_ws_close_code(),
+                        upstream_err.message.clone(),
+                    );
+                }
+
+                match &target.response_transformer {
+                    Some(t) => t(text.as_str())
+                        .map(UpstreamEvent::Forward)
+                        .unwrap_or(UpstreamEvent::Skip),
+                    None => UpstreamEvent::Forward(text.to_string()),
+                }
+            }
+            TungsteniteMessage::Close(frame) => {
+                let (code, reason) = convert::extract_tungstenite_close(frame, "upstream_closed");
+                if code == NORMAL_CLOSE_CODE || client_closing {
+                    UpstreamEvent::Closed
+                } else {
+                    UpstreamEvent::Dropped(code, reason)
+                }
+            }
+            _ => UpstreamEvent::Skip,
+        }
+    }
+
+    async fn close_client(client_tx: &mut ClientSender, code: u16, reason: String) {
+        let _ = client_tx.send(convert::to_axum_close(code, reason)).await;
+    }
+
+    /// Queues the payload under the same limits as [`WebSocketProxy`](super::WebSocketProxy)
+    /// and flushes it upstream.
+    async fn forward(
+        &self,
+        pending: &mut PendingState,
+        upstream: &mut ActiveUpstream,
+        data: Vec<u8>,
+        is_text: bool,
+    ) -> ForwardResult {
+        let is_control = self.targets[upstream.index]
+            .control_message_types
+            .as_ref()
+            .is_some_and(|types| is_control_message(&data, types));
+        let size = data.len();
+
+        if let Err(reason) = pending.enqueue(QueuedPayload { data, is_text }, is_control) {
+            tracing::warn!(
+                reason = %reason,
+                payload_size_bytes = %size,
+                is_control = %is_control,
+                "pending_queue_enqueue_failed"
+            );
+            return ForwardResult::Rejected(reason);
+        }
+
+        match pending.flush_to(&mut upstream.sender).await {
+            Ok(()) => ForwardResult::Sent,
+            Err(FlushError::SendFailed) => ForwardResult::SendFailed,
+            Err(FlushError::InvalidUtf8) => ForwardResult::Rejected("invalid_utf8_in_message"),
+        }
+    }
+
+    /// Returns how long each provider served the session, in order.
+    async fn run_relay(
+        &self,
+        client_socket: WebSocket,
+        mut upstream: ActiveUpstream,
+    ) -> Vec<(Provider, Duration)> {
+        let (mut client_tx, mut client_rx): (ClientSender, ClientReceiver) = client_socket.split();
+
+        let mut served = Vec::new();
+        let mut pending = PendingState::default();
+
+        let mut buffer =
+            AudioReplayBuffer::new(self.replay_window_secs, self.sample_rate, self.channels);
+        let mut rebaser = TimestampRebaser::default();
+        let mut client_closing = false;
+
+        loop {
+            let step = tokio::select! {
+                biased;
+                msg_opt = client_rx.next(), if !client_closing => RelayStep::Client(msg_opt),
+                msg_opt = upstream.receiver.next() => RelayStep::Upstream(msg_opt),
+            };
+
+            match step {
+                RelayStep::Client(msg_opt) => {
+                    let Some(Ok(msg)) = msg_opt else {
+                        let _ = upstream
+                            .sender
+                            .send(convert::to_tungstenite_close(
+                                DEFAULT_CLOSE_CODE,
+                                "client_disconnected".to_string(),
+                            ))
+                            .await;
+                        break;
+                    };
+
+                    let result = match msg {
+                        Message::Binary(bytes) => {
+                            buffer.push(&bytes);
+                            self.forward(&mut pending, &mut upstream, bytes.to_vec(), false)
+                                .await
+                        }
+                        Message::Text(text) => {
+                            let text = match upstream.first_message_transformer.take() {
+                                Some(transform) => transform(text.to_string()),
+                                None => text.to_string(),
+                            };
+                            self.forward(&mut pending, &mut upstream, text.into_bytes(), true)
+                                .await
+                        }
+                        Message::Close(frame) => {
+                            client_closing = true;
+                            let (code, reason) =
+                                convert::extract_axum_close(frame, "client_closed");
+                            match upstream
+                                .sender
+                                .send(convert::to_tungstenite_close(code, reason))
+                                .await
+                            {
+                                Ok(()) => ForwardResult::Sent,
+                                Err(_) => ForwardResult::SendFailed,
+                            }
+                        }
+                        Message::Ping(_) | Message::Pong(_) => ForwardResult::Sent,
+                    };
+
+                    if let ForwardResult::Rejected(reason) = result {
+                        let _ = upstream
+                            .sender
+                            .send(convert::to_tungstenite_close(
+                                DEFAULT_CLOSE_CODE,
+                                reason.to_string(),
+                            ))
+                            .await;
+                        Self::close_client(&mut client_tx, DEFAULT_CLOSE_CODE, reason.to_string())
+                            .await;
+                        break;
+                    }
+
+                    if matches!(result, ForwardResult::SendFailed) && !client_closing {
+                        let (provider, served_for) = Self::served(&self.targets, &upstream);
+                        match self.fail_over(upstream.index, &buffer, &mut rebaser).await {
+                            Some(next) => {
+                                served.push((provider, served_for));
+                                upstream = next;
+                            }
+                            None => {
+                                Self::close_client(
+                                    &mut client_tx,
+                                    DEFAULT_CLOSE_CODE,
+                                    "upstream_send_failed".to_string(),
+                                )
+                                .await;
+                                break;
+                            }
+                        }
+                    }
+                }
+                RelayStep::Upstream(msg_opt) => {
+                    let target = &self.targets[upstream.index];
+                    match Self::classify_upstream(target, msg_opt, client_closing) {
+                        UpstreamEvent::Forward(text) => {
+                            let Some(text) = rebaser.rebase_text(&text) else {
+                                continue;
+                            };
+                            if client_tx.send(Message::Text(text.into())).await.is_err() {
+                                let _ = upstream
+                                    .sender
+                                    .send(convert::to_tungstenite_close(
+                                        DEFAULT_CLOSE_CODE,
+                                        "client_send_failed".to_string(),
+                                    ))
+                                    .await;
+                                break;
+                            }
+                        }
+                        UpstreamEvent::Skip => {}
+                        UpstreamEvent::Closed => {
+                            Self::close_client(
+                                &mut client_tx,
+                                NORMAL_CLOSE_CODE,
+                                "upstream_closed".to_string(),
+                            )
+                            .await;
+                            break;
+                        }
+                        UpstreamEvent::Dropped(code, reason) => {
+                            if client_closing {
+                                Self::close_client(&mut client_tx, code, reason).await;
+                                break;
+                            }
+
+                            let _ = upstream.sender.close().await;
+                            let (provider, served_for) = Self::served(&self.targets, &upstream);
+                            match self.fail_over(upstream.index, &buffer, &mut rebaser).await {
+                                Some(next) => {
+                                    served.push((provider, served_for));
+                                    upstream = next;
+                                }
+                                None => {
+                                    Self::close_client(&mut client_tx, code, reason).await;
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        served.push(Self::served(&self.targets, &upstream));
+        served
+    }
+
+    fn served(targets: &[UpstreamTarget], upstream: &ActiveUpstream) -> (Provider, Duration) {
+        (
+            targets[upstream.index].provider,
+            upstream.connected_at.elapsed(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(provider: Provider) -> UpstreamTarget {
+        UpstreamTarget {
+            provider,
+            request: ClientRequestBuilder::new("ws://127.0.0.1:1".parse().unwrap()),
+            control_message_types: None,
+            transform_first_message: None,
+            initial_message: None,
+            response_transformer: None,
+        }
+    }
+
+    #[test]
+    fn test_classify_normal_close() {
+        let event = FailoverProxy::classify_upstream(
+            &target(Provider::Deepgram),
+            Some(Ok(convert::to_tungstenite_close(1000, "done".to_string()))),
+            false,
+        );
+        assert!(matches!(event, UpstreamEvent::Closed));
+    }
+
+    #[test]
+    fn test_classify_abnormal_close_is_dropped() {
+        let event = FailoverProxy::classify_upstream(
+            &target(Provider::Deepgram),
+            Some(Ok(convert::to_tungstenite_close(4000, "gone".to_string()))),
+            false,
+        );
+        assert!(matches!(event, UpstreamEvent::Dropped(4000, _)));
+    }
+
+    #[test]
+    fn test_classify_disconnect_while_client_closing() {
+        let event = FailoverProxy::classify_upstream(&target(Provider::Deepgram), None, true);
+        assert!(matches!(event, UpstreamEvent::Closed));
+
+        let event = FailoverProxy::classify_upstream(&target(Provider::Deepgram), None, false);
+        assert!(matches!(
+            event,
+            UpstreamEvent::Dropped(DEFAULT_CLOSE_CODE, _)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_connect_from_exhausts_targets() {
+        let proxy = FailoverProxy::new(
+            vec![target(Provider::Deepgram), target(Provider::Soniox)],
+            5,
+            16000,
+            1,
+            Duration::from_millis(200),
+            None,
+        );
+        assert!(proxy.connect_from(0).await.is_err());
+    }
+}
@@ -1,12 +1,15 @@
 mod builder;
 mod channel_split;
+mod failover;
 mod handler;
 mod pending;
+mod replay_buffer;
 mod types;
 mod upstream_error;
 
-pub use builder::ClientRequestBuilder;
+pub use builder::{ClientRequestBuilder, WebSocketProxyBuilder, WithUrl};
 pub use channel_split::ChannelSplitProxy;
+pub use failover::{FailoverProxy, UpstreamTarget};
 pub use handler::WebSocketProxy;
-pub use types::{InitialMessage, OnCloseCallback, ResponseTransformer};
+pub use types::{InitialMessage, OnCloseCallback, ProviderOnCloseCallback, ResponseTransformer};
 pub use upstream_error::{UpstreamError, detect_upstream_error};
@@ -0,0 +1,292 @@
+use std::collections::VecDeque;
+
+use owhisper_interface::stream::StreamResponse;
+
+const BYTES_PER_SAMPLE: u64 = 2;
+
+/// Keeps the most recent audio sent upstream so it can be replayed into a
+/// fallback provider after the active one drops mid-session.
+pub struct AudioReplayBuffer {
+    chunks: VecDeque<Vec<u8>>,
+    buffered_bytes: u64,
+    max_bytes: u64,
+    total_bytes: u64,
+    bytes_per_sec: u64,
+}
+
+impl AudioReplayBuffer {
+    pub fn new(window_secs: u64, sample_rate: u32, channels: u8) -> Self {
+        let bytes_per_sec = (sample_rate as u64) * (channels.max(1) as u64) * BYTES_PER_SAMPLE;
+        Self {
+            chunks: VecDeque::new(),
+            buffered_bytes: 0,
+            max_bytes: bytes_per_sec * window_secs,
+            total_bytes: 0,
+            bytes_per_sec,
+        }
+    }
+
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.total_bytes += chunk.len() as u64;
+        self.buffered_bytes += chunk.len() as u64;
+        self.chunks.push_back(chunk.to_vec());
+
+        while self.buffered_bytes > self.max_bytes {
+            let Some(front) = self.chunks.pop_front() else {
+                break;
+            };
+            self.buffered_bytes -= front.len() as u64;
+        }
+    }
+
+    pub fn chunks(&self) -> impl Iterator<Item = &[u8]> {
+        self.chunks.iter().map(|c| c.as_slice())
+    }
+
+    /// Position, in seconds of session audio, of the first buffered byte.
+    pub fn start_secs(&self) -> f64 {
+        self.bytes_to_secs(self.total_bytes - self.buffered_bytes)
+    }
+
+    pub fn buffered_secs(&self) -> f64 {
+        self.bytes_to_secs(self.buffered_bytes)
+    }
+
+    fn bytes_to_secs(&self, bytes: u64) -> f64 {
+        if self.bytes_per_sec == 0 {
+            return 0.0;
+        }
+        bytes as f64 / self.bytes_per_sec as f64
+    }
+}
+
+/// Shifts responses from a replacement upstream onto the session timeline and
+/// drops anything that was already finalized by a previous upstream.
+#[derive(Default)]
+pub struct TimestampRebaser {
+    offset_secs: f64,
+    finalized_until: f64,
+}
+
+impl TimestampRebaser {
+    pub fn set_offset(&mut self, offset_secs: f64) {
+        self.offset_secs = offset_secs;
+    }
+
+    pub fn rebase(&mut self, mut response: StreamResponse) -> Option<StreamResponse> {
+        response.apply_offset(self.offset_secs);
+
+        if let StreamResponse::TranscriptResponse {
+            start,
+            duration,
+            is_final,
+            channel,
+            ..
+        } = &mut response
+        {
+            let watermark = self.finalized_until;
+            let end = *start + *duration;
+            if end <= watermark {
+                return None;
+            }
+
+            if *start < watermark {
+                let mut dropped = false;
+                for alt in &mut channel.alternatives {
+                    let before = alt.words.len();
+                    alt.words.retain(|w| w.end > watermark);
+                    if alt.words.len() != before {
+                        dropped = true;
+                        alt.transcript = alt
+                            .words
+                            .iter()
+                            .map(|w| w.punctuated_word.as_deref().unwrap_or(&w.word))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                    }
+                }
+                if dropped {
+                    *duration = end - watermark;
+                    *start = watermark;
+                }
+            }
+
+            if *is_final {
+                self.finalized_until = self.finalized_until.max(end);
+            }
+        }
+
+        Some(response)
+    }
+
+    /// Rebases a transformed upstream payload, which is either a single
+    /// `StreamResponse` or an array of them. Unparseable payloads pass through.
+    pub fn rebase_text(&mut self, text: &str) -> Option<String> {
+        if let Ok(response) = serde_json::from_str::<StreamResponse>(text) {
+            let rebased = self.rebase(response)?;
+            return serde_json::to_string(&rebased).ok();
+        }
+
+        if let Ok(responses) = serde_json::from_str::<Vec<StreamResponse>>(text) {
+            let rebased: Vec<_> = responses
+                .into_iter()
+                .filter_map(|r| self.rebase(r))
+                .collect();
+            return match rebased.len() {
+                0 => None,
+                1 => serde_json::to_string(&rebased[0]).ok(),
+                _ => serde_json::to_string(&rebased).ok(),
+            };
+        }
+
+        Some(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use owhisper_interface::stream::{Alternatives, Channel, Metadata, Word};
+
+    fn word(text: &str, start: f64, end: f64) -> Word {
+        Word {
+            word: text.to_string(),
+            start,
+            end,
+            confidence: 1.0,
+            speaker: None,
+            punctuated_word: None,
+            language: None,
+        }
+    }
+
+    fn transcript(start: f64, duration: f64, is_final: bool, words: Vec<Word>) -> StreamResponse {
+        StreamResponse::TranscriptResponse {
+            start,
+            duration,
+            is_final,
+            speech_final: is_final,
+            from_finalize: false,
+            channel: Channel {
+                alternatives: vec![Alternatives {
+                    transcript: words
+                        .iter()
+                        .map(|w| w.word.clone())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    words,
+                    confidence: 1.0,
+                    languages: vec![],
+                }],
+            },
+            metadata: Metadata::default(),
+            channel_index: vec![0, 1],
+        }
+    }
+
+    #[test]
+    fn test_buffer_keeps_window() {
+        // 1 second == 32000 bytes at 16kHz mono linear16
+        let mut buffer = AudioReplayBuffer::new(1, 16000, 1);
+        for _ in 0..4 {
+            buffer.push(&[0; 16000]);
+        }
+
+        assert_eq!(buffer.chunks().count(), 2);
+        assert_eq!(buffer.buffered_secs(), 1.0);
+        assert_eq!(buffer.start_secs(), 1.0);
+    }
+
+    #[test]
+    fn test_buffer_accounts_for_channels() {
+        let mut buffer = AudioReplayBuffer::new(10, 16000, 2);
+        buffer.push(&[0; 64000]);
+
+        assert_eq!(buffer.buffered_secs(), 1.0);
+        assert_eq!(buffer.start_secs(), 0.0);
+    }
+
+    #[test]
+    fn test_buffer_zero_window_keeps_nothing() {
+        let mut buffer = AudioReplayBuffer::new(0, 16000, 1);
+        buffer.push(&[0; 3200]);
+
+        assert_eq!(buffer.chunks().count(), 0);
+        assert_eq!(buffer.start_secs(), 0.1);
+    }
+
+    #[test]
+    fn test_rebaser_applies_offset() {
+        let mut rebaser = TimestampRebaser::default();
+        rebaser.set_offset(10.0);
+
+        let rebased = rebaser
+            .rebase(transcript(1.0, 1.0, false, vec![word("hi", 1.0, 1.5)]))
+            .unwrap();
+
+        match rebased {
+            StreamResponse::TranscriptResponse { start, channel, .. } => {
+                assert_eq!(start, 11.0);
+                assert_eq!(channel.alternatives[0].words[0].start, 11.0);
+            }
+            _ => panic!("expected transcript"),
+        }
+    }
+
+    #[test]
+    fn test_rebaser_drops_already_finalized_audio() {
+        let mut rebaser = TimestampRebaser::default();
+        rebaser
+            .rebase(transcript(0.0, 5.0, true, vec![word("hello", 4.0, 5.0)]))
+            .unwrap();
+
+        rebaser.set_offset(3.0);
+        assert!(
+            rebaser
+                .rebase(transcript(0.0, 2.0, true, vec![word("hello", 1.0, 2.0)]))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_rebaser_trims_overlapping_words() {
+        let mut rebaser = TimestampRebaser::default();
+        rebaser
+            .rebase(transcript(0.0, 5.0, true, vec![word("hello", 4.0, 5.0)]))
+            .unwrap();
+
+        rebaser.set_offset(3.0);
+        let rebased = rebaser
+            .rebase(transcript(
+                0.0,
+                4.0,
+                true,
+                vec![word("hello", 1.0, 2.0), word("world", 2.5, 3.5)],
+            ))
+            .unwrap();
+
+        match rebased {
+            StreamResponse::TranscriptResponse {
+                start,
+                duration,
+                channel,
+                ..
+            } => {
+                assert_eq!(start, 5.0);
+                assert_eq!(duration, 2.0);
+                assert_eq!(channel.alternatives[0].words.len(), 1);
+                assert_eq!(channel.alternatives[0].transcript, "world");
+            }
+            _ => panic!("expected transcript"),
+        }
+    }
+
+    #[test]
+    fn test_rebase_text_passes_through_unknown() {
+        let mut rebaser = TimestampRebaser::default();
+        assert_eq!(
+            rebaser.rebase_text("not json"),
+            Some("not json".to_string())
+        );
+    }
+}
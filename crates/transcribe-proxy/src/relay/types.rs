@@ -6,12 +6,17 @@ use std::time::Duration;
 
 use axum::extract::ws::WebSocket;
 use futures_util::stream::{SplitSink, SplitStream};
+use owhisper_client::Provider;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
 
 pub const DEFAULT_CLOSE_CODE: u16 = 1011;
 
 pub type OnCloseCallback =
     Arc<dyn Fn(Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+/// Like [`OnCloseCallback`], for relays that may switch provider mid-session;
+/// called once per provider with the time it served.
+pub type ProviderOnCloseCallback =
+    Arc<dyn Fn(Provider, Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
 pub type ControlMessageTypes = Arc<HashSet<&'static str>>;
 pub type FirstMessageTransformer = Arc<dyn Fn(String) -> String + Send + Sync>;
 pub type InitialMessage = Arc<String>;
@@ -6,7 +6,7 @@ use owhisper_client::Provider;
 use crate::config::SttProxyConfig;
 use crate::provider_selector::SelectedProvider;
 use crate::query_params::QueryParams;
-use crate::relay::{OnCloseCallback, WebSocketProxy};
+use crate::relay::{OnCloseCallback, ProviderOnCloseCallback, WebSocketProxy};
 
 pub enum ProxyBuildError {
     SessionInitFailed(String),
@@ -75,28 +75,41 @@ pub fn build_on_close_callback(
     provider: Provider,
     analytics_ctx: &super::AnalyticsContext,
 ) -> Option<OnCloseCallback> {
+    let on_close = build_provider_on_close_callback(config, analytics_ctx)?;
+    Some(Arc::new(move |duration: std::time::Duration| {
+        on_close(provider, duration)
+    }))
+}
+
+/// Reports usage against the provider passed at close time, for relays that
+/// can switch provider mid-session.
+pub fn build_provider_on_close_callback(
+    config: &SttProxyConfig,
+    analytics_ctx: &super::AnalyticsContext,
+) -> Option<ProviderOnCloseCallback> {
     let analytics = config.analytics.as_ref()?;
     let analytics = analytics.clone();
-    let provider_name = format!("{:?}", provider).to_lowercase();
     let fingerprint = analytics_ctx.fingerprint.clone();
     let user_id = analytics_ctx.user_id.clone();
 
-    Some(Arc::new(move |duration: std::time::Duration| {
-        let analytics = analytics.clone();
-        let provider_name = provider_name.clone();
-        let fingerprint = fingerprint.clone();
-        let user_id = user_id.clone();
-        Box::pin(async move {
-            analytics
-                .report_stt(crate::analytics::SttEvent {
-                    fingerprint,
-                    user_id,
-                    provider: provider_name,
-                    duration,
-                })
-                .await;
-        }) as Pin<Box<dyn std::future::Future<Output = ()> + Send>>
-    }))
+    Some(Arc::new(
+        move |provider: Provider, duration: std::time::Duration| {
+            let analytics = analytics.clone();
+            let provider_name = format!("{:?}", provider).to_lowercase();
+            let fingerprint = fingerprint.clone();
+            let user_id = user_id.clone();
+            Box::pin(async move {
+                analytics
+                    .report_stt(crate::analytics::SttEvent {
+                        fingerprint,
+                        user_id,
+                        provider: provider_name,
+                        duration,
+                    })
+                    .await;
+            }) as Pin<Box<dyn std::future::Future<Output = ()> + Send>>
+        },
+    ))
 }
 
 pub fn build_proxy_with_url(
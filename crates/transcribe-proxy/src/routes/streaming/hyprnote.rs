@@ -10,13 +10,17 @@ use owhisper_interface::ListenParams;
 use crate::config::SttProxyConfig;
 use crate::provider_selector::SelectedProvider;
 use crate::query_params::{QueryParams, QueryValue};
-use crate::relay::{ChannelSplitProxy, WebSocketProxy};
+use crate::relay::{
+    ChannelSplitProxy, FailoverProxy, UpstreamTarget, WebSocketProxy, WebSocketProxyBuilder,
+    WithUrl,
+};
 use crate::routes::AppState;
 use crate::routes::model_resolution::resolve_model;
 
 use super::AnalyticsContext;
 use super::common::{
-    ProxyBuildError, build_on_close_callback, finalize_proxy_builder, parse_param,
+    ProxyBuildError, build_on_close_callback, build_provider_on_close_callback,
+    finalize_proxy_builder, parse_param,
 };
 use super::session::init_session;
 
@@ -106,6 +110,111 @@ fn build_response_transformer(
 pub enum StreamingProxy {
     Single(WebSocketProxy),
     ChannelSplit(ChannelSplitProxy),
+    Failover(FailoverProxy),
+}
+
+/// The adapter-configured proxy builder shared by single-provider sessions
+/// and every link of a failover chain.
+fn adapter_proxy_builder(
+    selected: &SelectedProvider,
+    api_base: &str,
+    listen_params: &ListenParams,
+    channels: u8,
+    config: &SttProxyConfig,
+) -> WebSocketProxyBuilder<WithUrl> {
+    let provider = selected.provider();
+    let upstream_url = build_upstream_url_with_adapter(provider, api_base, listen_params, channels);
+    let initial_message = build_initial_message_with_adapter(
+        provider,
+        Some(selected.api_key()),
+        listen_params,
+        channels,
+    );
+
+    let mut builder = WebSocketProxy::builder()
+        .upstream_url(upstream_url.as_str())
+        .connect_timeout(config.connect_timeout)
+        .control_message_types(provider.control_message_types())
+        .response_transformer(build_response_transformer(provider))
+        .apply_auth(selected);
+
+    if let Some(msg) = initial_message {
+        builder = builder.initial_message(msg);
+    }
+    builder
+}
+
+fn build_upstream_target(
+    selected: &SelectedProvider,
+    client_params: &QueryParams,
+    config: &SttProxyConfig,
+    is_fallback: bool,
+) -> Option<UpstreamTarget> {
+    let provider = selected.provider();
+    if matches!(provider.auth(), Auth::SessionInit { .. }) && selected.upstream_url().is_none() {
+        return None;
+    }
+
+    let channels: u8 = parse_param(client_params, "channels", 1);
+    if channels > 2 || (channels > 1 && !provider.supports_native_multichannel()) {
+        return None;
+    }
+
+    let mut listen_params = build_listen_params(client_params);
+    // An explicit model was chosen for the primary provider; fallbacks use their own default.
+    if is_fallback {
+        listen_params.model = None;
+    }
+    resolve_model(provider, &mut listen_params);
+
+    let api_base = selected
+        .upstream_url()
+        .unwrap_or(provider.default_api_base());
+    adapter_proxy_builder(selected, api_base, &listen_params, channels, config)
+        .build_target(provider)
+        .ok()
+}
+
+fn build_failover_proxy(
+    state: &AppState,
+    selected: &SelectedProvider,
+    params: &QueryParams,
+    analytics_ctx: &AnalyticsContext,
+) -> Option<StreamingProxy> {
+    let failover = state.router.as_ref()?.failover_config();
+    if !failover.enabled {
+        return None;
+    }
+
+    let primary = build_upstream_target(selected, params, &state.config, false)?;
+    let fallbacks: Vec<_> = state
+        .resolve_hyprnote_provider_chain(params)
+        .iter()
+        .filter(|s| s.provider() != selected.provider())
+        .filter_map(|s| build_upstream_target(s, params, &state.config, true))
+        .collect();
+
+    if fallbacks.is_empty() {
+        return None;
+    }
+
+    tracing::debug!(
+        primary = ?primary.provider,
+        fallbacks = ?fallbacks.iter().map(|t| t.provider).collect::<Vec<_>>(),
+        "failover_chain"
+    );
+
+    let targets = std::iter::once(primary).chain(fallbacks).collect();
+    let on_close = build_provider_on_close_callback(&state.config, analytics_ctx);
+
+    Some(StreamingProxy::Failover(FailoverProxy::new(
+        targets,
+        failover.replay_buffer_secs,
+        parse_param(params, "sample_rate", 16000),
+        parse_param(params, "channels", 1),
+        state.config.connect_timeout,
+        on_close,
+    )))
 }
 
 fn build_proxy_with_adapter(
@@ -136,27 +245,7 @@ fn build_proxy_with_adapter(
         );
     }
 
-    let upstream_url =
-        build_upstream_url_with_adapter(provider, api_base, &listen_params, channels);
-
-    let initial_message = build_initial_message_with_adapter(
-        provider,
-        Some(selected.api_key()),
-        &listen_params,
-        channels,
-    );
-
-    let mut builder = WebSocketProxy::builder()
-        .upstream_url(upstream_url.as_str())
-        .connect_timeout(config.connect_timeout)
-        .control_message_types(provider.control_message_types())
-        .response_transformer(build_response_transformer(provider))
-        .apply_auth(selected);
-
-    if let Some(msg) = initial_message {
-        builder = builder.initial_message(msg);
-    }
-
+    let builder = adapter_proxy_builder(selected, api_base, &listen_params, channels, config);
     let proxy = finalize_proxy_builder!(builder, provider, config, analytics_ctx)?;
     Ok(StreamingProxy::Single(proxy))
 }
@@ -260,6 +349,10 @@ pub async fn build_proxy(
     params: &QueryParams,
     analytics_ctx: AnalyticsContext,
 ) -> Result<StreamingProxy, ProxyBuildError> {
+    if let Some(proxy) = build_failover_proxy(state, selected, params, &analytics_ctx) {
+        return Ok(proxy);
+    }
+
     let provider = selected.provider();
     let api_base = selected
         .upstream_url()
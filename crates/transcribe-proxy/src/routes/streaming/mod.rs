@@ -142,5 +142,6 @@ pub async fn handler(
     match proxy {
         hyprnote::StreamingProxy::Single(p) => p.handle_upgrade(ws).await.into_response(),
         hyprnote::StreamingProxy::ChannelSplit(p) => p.handle_upgrade(ws).await.into_response(),
+        hyprnote::StreamingProxy::Failover(p) => p.handle_upgrade(ws).await.into_response(),
     }
 }
@@ -0,0 +1,247 @@
+mod common;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+
+use common::{
+    Direction, MessageKind, MockAnalytics, MockUpstreamConfig, WsMessage, WsRecording,
+    load_fixture, start_mock_server_with_config,
+};
+use owhisper_client::Provider;
+use owhisper_interface::stream::StreamResponse;
+use transcribe_proxy::{HyprnoteRoutingConfig, SttProxyConfig};
+
+const TEST_RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Deepgram transcribes "Hello world" and then the connection dies without a close frame.
+fn killed_deepgram_recording() -> WsRecording {
+    let mut recording = WsRecording::default();
+    for msg in load_fixture("deepgram_normal.jsonl")
+        .messages
+        .into_iter()
+        .filter(|m| matches!(m.kind, MessageKind::Text))
+        .take(3)
+    {
+        recording.push(msg);
+    }
+    recording
+}
+
+fn soniox_recording() -> WsRecording {
+    let mut recording = WsRecording::default();
+    recording.push(WsMessage::text(
+        Direction::ServerToClient,
+        100,
+        r#"{"tokens":[{"text":"Testing","start_ms":1200,"end_ms":1550,"confidence":0.9,"is_final":true},{"text":" Soniox","start_ms":1600,"end_ms":2000,"confidence":0.9,"is_final":true}]}"#,
+    ));
+    recording.push(WsMessage::close(
+        Direction::ServerToClient,
+        200,
+        1000,
+        "normal closure",
+    ));
+    recording
+}
+
+async fn start_proxy(
+    deepgram_upstream: &str,
+    soniox_upstream: &str,
+    analytics: MockAnalytics,
+) -> SocketAddr {
+    let mut env = transcribe_proxy::Env::default();
+    env.stt.deepgram_api_key = Some("test-key".to_string());
+    env.stt.soniox_api_key = Some("test-key".to_string());
+
+    let supabase_env = hypr_api_env::SupabaseEnv {
+        supabase_url: String::new(),
+        supabase_anon_key: String::new(),
+        supabase_service_role_key: String::new(),
+    };
+
+    let routing = HyprnoteRoutingConfig {
+        priorities: vec![Provider::Deepgram, Provider::Soniox],
+        ..Default::default()
+    };
+
+    let config = SttProxyConfig::new(&env, &supabase_env)
+        .with_default_provider(Provider::Deepgram)
+        .with_hyprnote_routing(routing)
+        .with_upstream_url(Provider::Deepgram, deepgram_upstream)
+        .with_upstream_url(Provider::Soniox, soniox_upstream)
+        .with_analytics(Arc::new(analytics));
+
+    common::start_server(config).await
+}
+
+fn transcript_responses(messages: &[String]) -> Vec<StreamResponse> {
+    messages
+        .iter()
+        .filter_map(|m| serde_json::from_str::<StreamResponse>(m).ok())
+        .filter(|r| matches!(r, StreamResponse::TranscriptResponse { .. }))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_failover_to_next_provider_when_upstream_dies() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let deepgram =
+        start_mock_server_with_config(killed_deepgram_recording(), MockUpstreamConfig::default())
+            .await
+            .expect("failed to start deepgram mock");
+    let soniox = start_mock_server_with_config(
+        soniox_recording(),
+        MockUpstreamConfig::default()
+            .use_timing(true)
+            .max_delay_ms(100),
+    )
+    .await
+    .expect("failed to start soniox mock");
+
+    let analytics = MockAnalytics::default();
+    let events = analytics.events.clone();
+    let proxy_addr = start_proxy(&deepgram.ws_url(), &soniox.ws_url(), analytics).await;
+
+    let url = format!(
+        "ws://{proxy_addr}/listen?provider=hyprnote&language=en&encoding=linear16&sample_rate=16000&channels=1"
+    );
+    let (ws_stream, _) = connect_async(&url).await.expect("failed to connect");
+    let (mut sender, mut receiver) = ws_stream.split();
+
+    let audio_task = tokio::spawn(async move {
+        for _ in 0..10 {
+            if sender
+                .send(Message::Binary(vec![0u8; 3200].into()))
+                .await
+                .is_err()
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        sender
+    });
+
+    let mut messages = Vec::new();
+    let mut close_code = None;
+    let _ = tokio::time::timeout(TEST_RESPONSE_TIMEOUT, async {
+        while let Some(Ok(msg)) = receiver.next().await {
+            match msg {
+                Message::Text(text) => messages.push(text.to_string()),
+                Message::Close(frame) => {
+                    close_code = frame.map(|f| u16::from(f.code));
+                    break;
+                }
+                _ => {}
+            }
+        }
+    })
+    .await;
+    let _ = audio_task.await;
+
+    assert!(
+        messages.iter().any(|m| m.contains("Hello world")),
+        "expected transcript from the primary provider"
+    );
+    assert!(
+        messages.iter().any(|m| m.contains("Soniox")),
+        "expected transcript from the fallback provider"
+    );
+    assert!(
+        !soniox.captured_requests().is_empty(),
+        "expected the fallback provider to be connected"
+    );
+    assert_eq!(close_code, Some(1000));
+
+    let finals: Vec<(f64, f64)> = transcript_responses(&messages)
+        .into_iter()
+        .filter_map(|r| match r {
+            StreamResponse::TranscriptResponse {
+                start,
+                duration,
+                is_final: true,
+                ..
+            } => Some((start, start + duration)),
+            _ => None,
+        })
+        .collect();
+    assert!(
+        finals.windows(2).all(|w| w[1].0 >= w[0].1 - f64::EPSILON),
+        "final transcripts should not overlap: {:?}",
+        finals
+    );
+
+    // Usage is reported once the relay winds down.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let providers: Vec<String> = events
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|event| event.provider.clone())
+        .collect();
+    assert_eq!(providers, vec!["deepgram", "soniox"]);
+}
+
+#[tokio::test]
+async fn test_failover_disabled_closes_session() {
+    let _ = tracing_subscriber::fmt::try_init();
+
+    let deepgram =
+        start_mock_server_with_config(killed_deepgram_recording(), MockUpstreamConfig::default())
+            .await
+            .expect("failed to start deepgram mock");
+    let soniox = start_mock_server_with_config(soniox_recording(), MockUpstreamConfig::default())
+        .await
+        .expect("failed to start soniox mock");
+
+    let mut env = transcribe_proxy::Env::default();
+    env.stt.deepgram_api_key = Some("test-key".to_string());
+    env.stt.soniox_api_key = Some("test-key".to_string());
+
+    let mut routing = HyprnoteRoutingConfig {
+        priorities: vec![Provider::Deepgram, Provider::Soniox],
+        ..Default::default()
+    };
+    routing.failover.enabled = false;
+
+    let config = SttProxyConfig::new(
+        &env,
+        &hypr_api_env::SupabaseEnv {
+            supabase_url: String::new(),
+            supabase_anon_key: String::new(),
+            supabase_service_role_key: String::new(),
+        },
+    )
+    .with_default_provider(Provider::Deepgram)
+    .with_hyprnote_routing(routing)
+    .with_upstream_url(Provider::Deepgram, deepgram.ws_url())
+    .with_upstream_url(Provider::Soniox, soniox.ws_url());
+    let proxy_addr = common::start_server(config).await;
+
+    let url = format!(
+        "ws://{proxy_addr}/listen?provider=hyprnote&language=en&encoding=linear16&sample_rate=16000&channels=1"
+    );
+    let (ws_stream, _) = connect_async(&url).await.expect("failed to connect");
+    let (_sender, mut receiver) = ws_stream.split();
+
+    let mut messages = Vec::new();
+    let _ = tokio::time::timeout(TEST_RESPONSE_TIMEOUT, async {
+        while let Some(Ok(msg)) = receiver.next().await {
+            match msg {
+                Message::Text(text) => messages.push(text.to_string()),
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+    })
+    .await;
+
+    assert!(messages.iter().any(|m| m.contains("Hello world")));
+    assert!(!messages.iter().any(|m| m.contains("Soniox")));
+    assert!(soniox.captured_requests().is_empty());
+}
@@ -1,2 +1,5 @@
 #[derive(Debug, thiserror::Error)]
-pub enum Error {}
+pub enum Error {
+    #[error(transparent)]
+    Diarization(#[from] hypr_pyannote_local::Error),
+}
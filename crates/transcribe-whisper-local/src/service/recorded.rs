@@ -1,8 +1,11 @@
-use owhisper_interface::Word2;
+use hypr_pyannote_local::diarization::{Diarizer, SpeakerTurn, assign_speaker};
+use owhisper_interface::{SpeakerIdentity, Word2};
 
+/// Transcribes a recording, labelling speakers unless `diarize` is off.
 pub fn process_recorded(
     model_path: impl AsRef<std::path::Path>,
     audio_path: impl AsRef<std::path::Path>,
+    diarize: bool,
 ) -> Result<Vec<Word2>, crate::Error> {
     let samples = {
         use rodio::Source;
@@ -22,11 +25,17 @@ pub fn process_recorded(
     let mut model = hypr_whisper_local::Whisper::builder()
         .model_path(model_path.as_ref().to_str().unwrap())
         .languages(vec![])
+        .token_timestamps(true)
         .build()
         .unwrap();
 
     let mut segmenter = hypr_pyannote_local::segmentation::Segmenter::new(16000).unwrap();
     let segments = segmenter.process(&samples, 16000).unwrap();
+    let turns = if diarize {
+        Diarizer::new(16000).diarize(&segments)?
+    } else {
+        vec![]
+    };
 
     let mut words = Vec::new();
 
@@ -36,23 +45,45 @@ pub fn process_recorded(
         let whisper_segments = model.transcribe(&audio_f32).unwrap();
 
         for whisper_segment in whisper_segments {
-            let start_sec: f64 = segment.start + whisper_segment.start();
-            let end_sec: f64 = segment.start + whisper_segment.end();
-            let start_ms = (start_sec * 1000.0) as u64;
-            let end_ms = (end_sec * 1000.0) as u64;
-
-            let word = Word2 {
-                text: whisper_segment.text().to_string(),
-                speaker: None,
-                confidence: Some(whisper_segment.confidence()),
-                start_ms: Some(start_ms),
-                end_ms: Some(end_ms),
-            };
-
-            // TODO
-            words.push(word.clone());
+            let offset = segment.start;
+
+            // Speakers are assigned per word, so a segment spanning a change
+            // of speaker is split between them.
+            if whisper_segment.words().is_empty() {
+                words.push(word(
+                    &turns,
+                    whisper_segment.text(),
+                    offset + whisper_segment.start(),
+                    offset + whisper_segment.end(),
+                    whisper_segment.confidence(),
+                ));
+            } else {
+                words.extend(whisper_segment.words().iter().map(|w| {
+                    word(
+                        &turns,
+                        &w.text,
+                        offset + w.start,
+                        offset + w.end,
+                        whisper_segment.confidence(),
+                    )
+                }));
+            }
         }
     }
 
     Ok(words)
 }
+
+fn word(turns: &[SpeakerTurn], text: &str, start_sec: f64, end_sec: f64, confidence: f32) -> Word2 {
+    let speaker = assign_speaker(turns, start_sec, end_sec)
+        .and_then(|index| u8::try_from(index).ok())
+        .map(|index| SpeakerIdentity::Unassigned { index });
+
+    Word2 {
+        text: text.to_string(),
+        speaker,
+        confidence: Some(confidence),
+        start_ms: Some((start_sec * 1000.0) as u64),
+        end_ms: Some((end_sec * 1000.0) as u64),
+    }
+}
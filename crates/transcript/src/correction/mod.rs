@@ -0,0 +1,395 @@
+mod numbers;
+mod profanity;
+mod punctuation;
+mod vocabulary;
+
+pub use numbers::NumberCorrector;
+pub use profanity::ProfanityCorrector;
+pub use punctuation::PunctuationCorrector;
+pub use vocabulary::VocabularyCorrector;
+
+use std::collections::{BTreeMap, HashMap};
+
+use owhisper_interface::stream::StreamResponse;
+
+use super::processor::TranscriptProcessor;
+use super::types::{FinalizedWord, TranscriptDelta, WordState};
+use super::words::spacing_from_transcript;
+
+/// A synchronous correction source that rewrites finalized words.
+///
+/// Correctors receive the words of a single channel, in time order, and may
+/// edit text, merge adjacent words, or leave them untouched. When words are
+/// merged the result keeps the first word's `id` and spans the merged range.
+///
+/// `previous` is the last word already emitted on the channel before this
+/// batch (after correction), for correctors that need sentence context.
+pub trait TranscriptCorrector: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn correct(&self, words: &mut Vec<FinalizedWord>, previous: Option<&FinalizedWord>);
+}
+
+/// Ordered list of correctors run over every finalized batch.
+///
+/// Correctors run in insertion order, each seeing the previous one's output.
+/// A sensible order is vocabulary → numbers → punctuation → profanity, so
+/// that casing and masking apply to the final wording.
+///
+/// The chain is an external correction source: it drives the processor's
+/// `submit_correction` / `apply_correction` API so corrected words follow
+/// the usual `Final`→`Pending`→`Final` lifecycle on the frontend.
+#[derive(Default)]
+pub struct CorrectorChain {
+    correctors: Vec<Box<dyn TranscriptCorrector>>,
+    previous: HashMap<i32, FinalizedWord>,
+}
+
+impl CorrectorChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, corrector: impl TranscriptCorrector + 'static) -> Self {
+        self.correctors.push(Box::new(corrector));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.correctors.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.correctors.iter().map(|c| c.name())
+    }
+
+    /// Run every corrector over one channel's words.
+    ///
+    /// Returns `None` when the chain left the words unchanged. Returned words
+    /// are always `Final`.
+    pub fn apply(&mut self, words: &[FinalizedWord]) -> Option<Vec<FinalizedWord>> {
+        let channel = words.first()?.channel;
+        let previous = self.previous.get(&channel);
+
+        let mut corrected = words.to_vec();
+        for corrector in &self.correctors {
+            corrector.correct(&mut corrected, previous);
+        }
+        for word in &mut corrected {
+            word.state = WordState::Final;
+        }
+
+        let changed = corrected.len() != words.len()
+            || corrected.iter().zip(words).any(|(a, b)| a.text != b.text);
+
+        let last = corrected.last().or(words.last()).cloned();
+        if let Some(last) = last {
+            self.previous.insert(channel, last);
+        }
+
+        changed.then_some(corrected)
+    }
+
+    /// Correct the `Final` words of a delta just returned by the processor.
+    ///
+    /// For every channel whose words changed, returns a pending delta (words
+    /// re-emitted as `Pending`) followed by the resolving delta (corrected
+    /// `Final` words replacing them). Emit these after `delta` itself.
+    /// Words already `Pending` on another correction source are skipped.
+    pub fn run(
+        &mut self,
+        processor: &mut TranscriptProcessor,
+        delta: &TranscriptDelta,
+    ) -> Vec<TranscriptDelta> {
+        if self.correctors.is_empty() {
+            return vec![];
+        }
+
+        let mut by_channel: BTreeMap<i32, Vec<FinalizedWord>> = BTreeMap::new();
+        for word in delta
+            .new_words
+            .iter()
+            .filter(|w| w.state == WordState::Final)
+        {
+            by_channel
+                .entry(word.channel)
+                .or_default()
+                .push(word.clone());
+        }
+
+        let mut deltas = Vec::new();
+        for words in by_channel.into_values() {
+            let Some(corrected) = self.apply(&words) else {
+                continue;
+            };
+
+            let (job_id, pending) = processor.submit_correction(words);
+            deltas.push(pending);
+            deltas.push(processor.apply_correction(job_id, corrected));
+        }
+
+        deltas
+    }
+
+    /// Correct a final streaming response in place, for hosts that forward
+    /// responses rather than deltas, such as the listener feeding the
+    /// desktop. Returns whether the response changed.
+    ///
+    /// Partial responses are left alone; the provider still revises them.
+    pub fn correct_response(&mut self, response: &mut StreamResponse) -> bool {
+        if self.correctors.is_empty() {
+            return false;
+        }
+        let StreamResponse::TranscriptResponse {
+            is_final: true,
+            channel,
+            channel_index,
+            ..
+        } = response
+        else {
+            return false;
+        };
+        let Some(alt) = channel.alternatives.first_mut() else {
+            return false;
+        };
+
+        // Tokens without a leading space continue the previous word, as in
+        // `TranscriptProcessor::process`. `sources` keeps each word's first
+        // token so rewritten words carry its confidence and speaker.
+        let ch = channel_index.first().copied().unwrap_or(0);
+        let spaced = spacing_from_transcript(&alt.words, &alt.transcript);
+        let mut words: Vec<FinalizedWord> = Vec::new();
+        let mut sources = Vec::new();
+        for (i, (token, text)) in alt.words.iter().zip(spaced).enumerate() {
+            let end_ms = (token.end * 1000.0).round() as i64;
+            match words.last_mut() {
+                Some(last) if !text.starts_with(' ') => {
+                    last.text.push_str(&text);
+                    last.end_ms = end_ms;
+                }
+                _ => {
+                    words.push(FinalizedWord {
+                        id: sources.len().to_string(),
+                        text,
+                        start_ms: (token.start * 1000.0).round() as i64,
+                        end_ms,
+                        channel: ch,
+                        state: WordState::Final,
+                    });
+                    sources.push(i);
+                }
+            }
+        }
+
+        let Some(corrected) = self.apply(&words) else {
+            return false;
+        };
+
+        alt.words = corrected
+            .iter()
+            .map(|word| {
+                let source = word.id.parse::<usize>().map_or(0, |i| sources[i]);
+                let text = word.text.trim_start().to_string();
+                owhisper_interface::stream::Word {
+                    word: split_affixes(&text).1.to_string(),
+                    start: word.start_ms as f64 / 1000.0,
+                    end: word.end_ms as f64 / 1000.0,
+                    punctuated_word: Some(text),
+                    ..alt.words[source].clone()
+                }
+            })
+            .collect();
+        alt.transcript = corrected
+            .iter()
+            .map(|word| word.text.as_str())
+            .collect::<String>()
+            .trim_start()
+            .to_string();
+        true
+    }
+
+    /// Forget per-channel sentence context, e.g. when a new session starts.
+    pub fn reset(&mut self) {
+        self.previous.clear();
+    }
+}
+
+// ── Shared helpers ────────────────────────────────────────────────────────────
+
+/// Split word text into `(prefix, core, suffix)`, where `prefix` is leading
+/// whitespace and punctuation and `suffix` is trailing punctuation.
+pub(super) fn split_affixes(text: &str) -> (&str, &str, &str) {
+    let start = text
+        .char_indices()
+        .find(|(_, c)| c.is_alphanumeric())
+        .map_or(text.len(), |(i, _)| i);
+    let end = text
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_alphanumeric())
+        .map_or(start, |(i, c)| i + c.len_utf8());
+
+    (&text[..start], &text[start..end], &text[end..])
+}
+
+/// Lowercased core of a word, for case- and punctuation-insensitive matching.
+pub(super) fn normalized(text: &str) -> String {
+    split_affixes(text).1.to_lowercase()
+}
+
+/// Replace `words[start..end]` with a single word carrying `text`.
+///
+/// The merged word keeps the first word's id and channel and spans the
+/// whole range.
+pub(super) fn merge_range(words: &mut Vec<FinalizedWord>, start: usize, end: usize, text: String) {
+    let end_ms = words[end - 1].end_ms;
+    words.drain(start + 1..end);
+    let word = &mut words[start];
+    word.text = text;
+    word.end_ms = end_ms;
+}
+
+#[cfg(test)]
+pub(super) fn test_words(text: &str) -> Vec<FinalizedWord> {
+    text.split(' ')
+        .enumerate()
+        .map(|(i, w)| FinalizedWord {
+            id: format!("w{i}"),
+            text: format!(" {w}"),
+            start_ms: i as i64 * 300,
+            end_ms: i as i64 * 300 + 250,
+            channel: 0,
+            state: WordState::Final,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(super) fn joined(words: &[FinalizedWord]) -> String {
+    words.iter().map(|w| w.text.as_str()).collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_affixes() {
+        assert_eq!(split_affixes(" \"hello,\""), (" \"", "hello", ",\""));
+        assert_eq!(split_affixes(" don't."), (" ", "don't", "."));
+        assert_eq!(split_affixes(" ..."), (" ...", "", ""));
+    }
+
+    #[test]
+    fn chain_emits_pending_then_final() {
+        let mut processor = TranscriptProcessor::new();
+        let mut chain = CorrectorChain::new()
+            .with(VocabularyCorrector::new().with_alias("hyper note", "Hyprnote"))
+            .with(NumberCorrector::new());
+
+        let delta = TranscriptDelta {
+            new_words: test_words("hyper note has twenty five users"),
+            hints: vec![],
+            replaced_ids: vec![],
+            partials: vec![],
+        };
+
+        let deltas = chain.run(&mut processor, &delta);
+        assert_eq!(deltas.len(), 2);
+
+        let pending = &deltas[0];
+        assert!(
+            pending
+                .new_words
+                .iter()
+                .all(|w| w.state == WordState::Pending)
+        );
+        assert_eq!(pending.replaced_ids.len(), 6);
+
+        let resolved = &deltas[1];
+        assert_eq!(joined(&resolved.new_words), " Hyprnote has 25 users");
+        assert!(
+            resolved
+                .new_words
+                .iter()
+                .all(|w| w.state == WordState::Final)
+        );
+        assert_eq!(resolved.replaced_ids, pending.replaced_ids);
+        assert_eq!(resolved.new_words[0].end_ms, delta.new_words[1].end_ms);
+    }
+
+    #[test]
+    fn corrects_final_responses_in_place() {
+        use owhisper_interface::stream::{Alternatives, Channel, Word};
+
+        let token = |word: &str, start: f64, speaker: i32| Word {
+            word: word.to_lowercase(),
+            start,
+            end: start + 0.25,
+            confidence: 0.9,
+            speaker: Some(speaker),
+            punctuated_word: Some(word.to_string()),
+            language: None,
+        };
+        let response = |is_final: bool| StreamResponse::TranscriptResponse {
+            start: 0.0,
+            duration: 1.0,
+            is_final,
+            speech_final: is_final,
+            from_finalize: false,
+            channel: Channel {
+                alternatives: vec![Alternatives {
+                    transcript: "We use hyper note.".to_string(),
+                    words: vec![
+                        token("We", 0.0, 0),
+                        token("use", 0.3, 0),
+                        token("hyper", 0.6, 1),
+                        token("note.", 0.9, 1),
+                    ],
+                    confidence: 0.9,
+                    languages: vec![],
+                }],
+            },
+            metadata: Default::default(),
+            channel_index: vec![0, 1],
+        };
+        let mut chain = CorrectorChain::new()
+            .with(VocabularyCorrector::new().with_alias("hyper note", "Hyprnote"));
+
+        let mut partial = response(false);
+        assert!(!chain.correct_response(&mut partial));
+
+        let mut last = response(true);
+        assert!(chain.correct_response(&mut last));
+        let StreamResponse::TranscriptResponse { channel, .. } = last else {
+            unreachable!();
+        };
+        let alt = &channel.alternatives[0];
+        assert_eq!(alt.transcript, "We use Hyprnote.");
+        assert_eq!(alt.words.len(), 3);
+        assert_eq!(alt.words[2].punctuated_word.as_deref(), Some("Hyprnote."));
+        assert_eq!(alt.words[2].word, "Hyprnote");
+        assert_eq!(alt.words[2].speaker, Some(1));
+        assert_eq!((alt.words[2].start, alt.words[2].end), (0.6, 1.15));
+    }
+
+    #[test]
+    fn chain_skips_unchanged_and_pending_words() {
+        let mut processor = TranscriptProcessor::new();
+        let mut chain = CorrectorChain::new().with(NumberCorrector::new());
+
+        let mut words = test_words("nothing to see");
+        words.extend(test_words("twenty").into_iter().map(|w| FinalizedWord {
+            state: WordState::Pending,
+            ..w
+        }));
+        let delta = TranscriptDelta {
+            new_words: words,
+            hints: vec![],
+            replaced_ids: vec![],
+            partials: vec![],
+        };
+
+        assert!(chain.run(&mut processor, &delta).is_empty());
+    }
+}
@@ -0,0 +1,248 @@
+use super::{TranscriptCorrector, merge_range, normalized, split_affixes};
+use crate::types::FinalizedWord;
+
+/// Rewrites spelled-out cardinal numbers as digits.
+///
+/// "twenty five" → "25", "two hundred and ten" → "210",
+/// "fifteen percent" → "15%". Lone numbers below ten stay spelled out
+/// ("one of them", "no one"), following common style guides.
+#[derive(Debug, Clone, Default)]
+pub struct NumberCorrector;
+
+impl NumberCorrector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Unit(u64),
+    Teen(u64),
+    Tens(u64),
+    Hundred,
+    Scale(u64),
+    And,
+}
+
+fn token(word: &str) -> Option<Token> {
+    let unit = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let teen = [
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    let tens = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    if let Some(n) = unit.iter().position(|w| *w == word) {
+        return Some(Token::Unit(n as u64));
+    }
+    if let Some(n) = teen.iter().position(|w| *w == word) {
+        return Some(Token::Teen(10 + n as u64));
+    }
+    if let Some(n) = tens.iter().position(|w| *w == word) {
+        return Some(Token::Tens(20 + 10 * n as u64));
+    }
+    match word {
+        "hundred" => Some(Token::Hundred),
+        "thousand" => Some(Token::Scale(1_000)),
+        "million" => Some(Token::Scale(1_000_000)),
+        "billion" => Some(Token::Scale(1_000_000_000)),
+        "and" => Some(Token::And),
+        _ => None,
+    }
+}
+
+/// Incremental parser for one spoken number.
+#[derive(Debug, Default, Clone)]
+struct Number {
+    total: u64,
+    current: u64,
+    last: Option<Token>,
+    last_scale: Option<u64>,
+    tokens: usize,
+}
+
+impl Number {
+    /// Feed one token. Returns `false` (leaving state untouched) when the
+    /// token can't continue this number.
+    fn push(&mut self, token: Token) -> bool {
+        use Token::*;
+
+        let allowed = match (self.last, token) {
+            (_, And) => matches!(self.last, Some(Hundred | Scale(_))),
+            (None, Unit(_) | Teen(_) | Tens(_)) => true,
+            (None, _) => false,
+            (Some(Unit(0)), _) => false,
+            (Some(Unit(_) | Teen(_)), Hundred) => true,
+            (Some(Tens(_)), Unit(n)) => n != 0,
+            (Some(Hundred | Scale(_) | And), Unit(n)) => n != 0,
+            (Some(Hundred | Scale(_) | And), Teen(_) | Tens(_)) => true,
+            (Some(Unit(_) | Teen(_) | Tens(_) | Hundred), Scale(s)) => {
+                self.last_scale.is_none_or(|last| s < last)
+            }
+            _ => false,
+        };
+        if !allowed {
+            return false;
+        }
+
+        match token {
+            Unit(n) | Teen(n) | Tens(n) => self.current += n,
+            Hundred => self.current *= 100,
+            Scale(s) => {
+                self.total += self.current * s;
+                self.current = 0;
+                self.last_scale = Some(s);
+            }
+            And => {}
+        }
+        self.last = Some(token);
+        self.tokens += 1;
+        true
+    }
+
+    fn value(&self) -> u64 {
+        self.total + self.current
+    }
+}
+
+fn format_number(n: u64) -> String {
+    let digits = n.to_string();
+    if n < 10_000 {
+        return digits;
+    }
+
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+impl NumberCorrector {
+    /// Parse the longest number starting at `words[at]`. Returns the value,
+    /// the number of words consumed, and whether a trailing "percent"
+    /// was included.
+    fn parse_at(&self, words: &[FinalizedWord], at: usize) -> Option<(u64, usize, bool)> {
+        let mut number = Number::default();
+        let mut consumed = 0;
+
+        for word in &words[at..] {
+            let core = normalized(&word.text);
+            // "twenty-five" arrives as one word.
+            let parts: Option<Vec<Token>> = core.split('-').map(token).collect();
+            let Some(parts) = parts else { break };
+
+            // Don't split a hyphenated word across two numbers.
+            let mut next = number.clone();
+            if !parts.into_iter().all(|t| next.push(t)) {
+                break;
+            }
+            number = next;
+            consumed += 1;
+
+            if !split_affixes(&word.text).2.is_empty() {
+                break;
+            }
+        }
+
+        // A dangling "and" belongs to the surrounding sentence.
+        while consumed > 0 && normalized(&words[at + consumed - 1].text) == "and" {
+            consumed -= 1;
+            number.tokens -= 1;
+        }
+        if consumed == 0 {
+            return None;
+        }
+
+        let last_text = &words[at + consumed - 1].text;
+        let percent = split_affixes(last_text).2.is_empty()
+            && words
+                .get(at + consumed)
+                .is_some_and(|w| normalized(&w.text) == "percent");
+
+        let value = number.value();
+        if value < 10 && number.tokens == 1 && !percent {
+            return None;
+        }
+
+        Some((value, consumed, percent))
+    }
+}
+
+impl TranscriptCorrector for NumberCorrector {
+    fn name(&self) -> &'static str {
+        "numbers"
+    }
+
+    fn correct(&self, words: &mut Vec<FinalizedWord>, _previous: Option<&FinalizedWord>) {
+        let mut i = 0;
+        while i < words.len() {
+            let Some((value, consumed, percent)) = self.parse_at(words, i) else {
+                i += 1;
+                continue;
+            };
+
+            let end = i + consumed + usize::from(percent);
+            let prefix = split_affixes(&words[i].text).0;
+            let suffix = split_affixes(&words[end - 1].text).2;
+            let sign = if percent { "%" } else { "" };
+            let text = format!("{prefix}{}{sign}{suffix}", format_number(value));
+
+            merge_range(words, i, end, text);
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{joined, test_words};
+    use super::*;
+
+    fn corrected(text: &str) -> String {
+        let mut words = test_words(text);
+        NumberCorrector::new().correct(&mut words, None);
+        joined(&words)
+    }
+
+    #[test]
+    fn converts_cardinals() {
+        assert_eq!(corrected("about twenty five people"), " about 25 people");
+        assert_eq!(corrected("twenty-five"), " 25");
+        assert_eq!(
+            corrected("two hundred and ten thousand users"),
+            " 210,000 users"
+        );
+        assert_eq!(
+            corrected("one million two hundred thousand and five"),
+            " 1,200,005"
+        );
+        assert_eq!(corrected("fifteen hundred"), " 1500");
+        assert_eq!(corrected("up fifteen percent."), " up 15%.");
+    }
+
+    #[test]
+    fn leaves_small_and_ambiguous_numbers() {
+        assert_eq!(corrected("one of them"), " one of them");
+        assert_eq!(corrected("no one and nothing"), " no one and nothing");
+        assert_eq!(corrected("twenty thirty"), " 20 30");
+        assert_eq!(corrected("ten, eleven"), " 10, 11");
+        assert_eq!(corrected("five hundred and"), " 500 and");
+    }
+}
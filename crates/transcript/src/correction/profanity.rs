@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use super::{TranscriptCorrector, normalized, split_affixes};
+use crate::types::FinalizedWord;
+
+const DEFAULT_WORDS: &[&str] = &[
+    "asshole",
+    "assholes",
+    "bitch",
+    "bitches",
+    "bullshit",
+    "cunt",
+    "dick",
+    "fuck",
+    "fucked",
+    "fucker",
+    "fucking",
+    "fucks",
+    "motherfucker",
+    "shit",
+    "shits",
+    "shitty",
+];
+
+/// Masks profanity, keeping the first letter: "shit" → "s***".
+#[derive(Debug, Clone)]
+pub struct ProfanityCorrector {
+    words: HashSet<String>,
+    mask: char,
+}
+
+impl ProfanityCorrector {
+    /// Corrector with a small built-in English word list.
+    pub fn new() -> Self {
+        Self {
+            words: DEFAULT_WORDS.iter().map(|w| w.to_string()).collect(),
+            mask: '*',
+        }
+    }
+
+    /// Corrector with no words; add them with `with_word`.
+    pub fn empty() -> Self {
+        Self {
+            words: HashSet::new(),
+            mask: '*',
+        }
+    }
+
+    pub fn with_word(mut self, word: impl AsRef<str>) -> Self {
+        let word = normalized(word.as_ref());
+        if !word.is_empty() {
+            self.words.insert(word);
+        }
+        self
+    }
+
+    pub fn with_mask(mut self, mask: char) -> Self {
+        self.mask = mask;
+        self
+    }
+}
+
+impl Default for ProfanityCorrector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TranscriptCorrector for ProfanityCorrector {
+    fn name(&self) -> &'static str {
+        "profanity"
+    }
+
+    fn correct(&self, words: &mut Vec<FinalizedWord>, _previous: Option<&FinalizedWord>) {
+        for word in words.iter_mut() {
+            if !self.words.contains(&normalized(&word.text)) {
+                continue;
+            }
+
+            let (prefix, core, suffix) = split_affixes(&word.text);
+            let mut chars = core.chars();
+            let first = chars.next().unwrap_or_default();
+            let masked: String = std::iter::repeat_n(self.mask, chars.count()).collect();
+            word.text = format!("{prefix}{first}{masked}{suffix}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{joined, test_words};
+    use super::*;
+
+    #[test]
+    fn masks_listed_words() {
+        let corrector = ProfanityCorrector::new().with_word("Darn");
+
+        let mut words = test_words("Shit, that darn build. shitake");
+        corrector.correct(&mut words, None);
+        assert_eq!(joined(&words), " S***, that d*** build. shitake");
+    }
+}
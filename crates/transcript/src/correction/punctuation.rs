@@ -0,0 +1,138 @@
+use super::{TranscriptCorrector, split_affixes};
+use crate::types::FinalizedWord;
+
+/// Pause after which an unpunctuated word is treated as ending a sentence.
+const DEFAULT_SENTENCE_PAUSE_MS: i64 = 800;
+
+/// Restores sentence punctuation and casing for models that emit neither.
+///
+/// - A period is added after a word followed by a long pause when the word
+///   has no punctuation of its own.
+/// - The first word of each sentence is capitalized.
+/// - The pronoun "i" and its contractions are capitalized.
+///
+/// The last word of a batch never gets a period, since the pause after it
+/// isn't known yet.
+#[derive(Debug, Clone)]
+pub struct PunctuationCorrector {
+    sentence_pause_ms: i64,
+}
+
+impl PunctuationCorrector {
+    pub fn new() -> Self {
+        Self {
+            sentence_pause_ms: DEFAULT_SENTENCE_PAUSE_MS,
+        }
+    }
+
+    pub fn with_sentence_pause_ms(mut self, ms: i64) -> Self {
+        self.sentence_pause_ms = ms;
+        self
+    }
+}
+
+impl Default for PunctuationCorrector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TranscriptCorrector for PunctuationCorrector {
+    fn name(&self) -> &'static str {
+        "punctuation"
+    }
+
+    fn correct(&self, words: &mut Vec<FinalizedWord>, previous: Option<&FinalizedWord>) {
+        let mut sentence_start = match previous {
+            None => true,
+            Some(prev) => {
+                ends_sentence(&prev.text)
+                    || words
+                        .first()
+                        .is_some_and(|w| w.start_ms - prev.end_ms >= self.sentence_pause_ms)
+            }
+        };
+
+        for i in 0..words.len() {
+            let next_start = words.get(i + 1).map(|w| w.start_ms);
+            let word = &mut words[i];
+
+            let (_, core, suffix) = split_affixes(&word.text);
+            if core.is_empty() {
+                sentence_start |= ends_sentence(&word.text);
+                continue;
+            }
+
+            let is_pronoun = is_first_person(&core.to_lowercase());
+            let punctuated = !suffix.is_empty();
+            if sentence_start || is_pronoun {
+                word.text = capitalize_core(&word.text);
+            }
+
+            let long_pause =
+                next_start.is_some_and(|start| start - word.end_ms >= self.sentence_pause_ms);
+            if long_pause && !punctuated {
+                word.text.push('.');
+            }
+
+            sentence_start = ends_sentence(&word.text);
+        }
+    }
+}
+
+fn ends_sentence(text: &str) -> bool {
+    let suffix = split_affixes(text).2;
+    suffix.contains(['.', '?', '!', '…'])
+}
+
+fn is_first_person(core: &str) -> bool {
+    matches!(
+        core,
+        "i" | "i'm" | "i've" | "i'll" | "i'd" | "i’m" | "i’ve" | "i’ll" | "i’d"
+    )
+}
+
+fn capitalize_core(text: &str) -> String {
+    let (prefix, core, suffix) = split_affixes(text);
+    let mut chars = core.chars();
+    let Some(first) = chars.next() else {
+        return text.to_string();
+    };
+    format!("{prefix}{}{}{suffix}", first.to_uppercase(), chars.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{joined, test_words};
+    use super::*;
+
+    #[test]
+    fn restores_sentences_at_pauses() {
+        let mut words = test_words("so i think we ship it then we test");
+        words[5].end_ms = words[5].start_ms + 100;
+        for w in &mut words[6..] {
+            w.start_ms += 1000;
+            w.end_ms += 1000;
+        }
+
+        PunctuationCorrector::new().correct(&mut words, None);
+        assert_eq!(joined(&words), " So I think we ship it. Then we test");
+    }
+
+    #[test]
+    fn continues_sentence_from_previous_batch() {
+        let previous = test_words("hello").pop().unwrap();
+        let mut words = test_words("x there");
+        words.remove(0);
+
+        PunctuationCorrector::new().correct(&mut words, Some(&previous));
+        assert_eq!(joined(&words), " there");
+
+        let previous = FinalizedWord {
+            text: " hello.".into(),
+            ..previous
+        };
+        PunctuationCorrector::new().correct(&mut words, Some(&previous));
+        assert_eq!(joined(&words), " There");
+    }
+}
@@ -0,0 +1,155 @@
+use super::{TranscriptCorrector, merge_range, normalized, split_affixes};
+use crate::types::FinalizedWord;
+
+/// Replaces misheard names and product terms with their canonical spelling.
+///
+/// Each term matches itself case-insensitively (fixing casing alone) plus
+/// any number of aliases, which may span several words ("hyper note" →
+/// "Hyprnote"). Matching ignores surrounding punctuation; the leading
+/// prefix of the first word and trailing suffix of the last are kept.
+#[derive(Debug, Clone, Default)]
+pub struct VocabularyCorrector {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    pattern: Vec<String>,
+    replacement: String,
+}
+
+impl VocabularyCorrector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a canonical term. Occurrences with different casing are fixed.
+    pub fn with_term(self, term: impl Into<String>) -> Self {
+        let term = term.into();
+        self.with_alias(term.clone(), term)
+    }
+
+    /// Add a (possibly multi-word) spelling that should become `term`.
+    pub fn with_alias(mut self, alias: impl AsRef<str>, term: impl Into<String>) -> Self {
+        let pattern: Vec<String> = alias.as_ref().split_whitespace().map(normalized).collect();
+        if pattern.iter().all(|p| !p.is_empty()) && !pattern.is_empty() {
+            self.entries.push(Entry {
+                pattern,
+                replacement: term.into(),
+            });
+            // Longest patterns win when several match at the same position.
+            self.entries
+                .sort_by_key(|e| std::cmp::Reverse(e.pattern.len()));
+        }
+        self
+    }
+
+    /// Add one line of the user's vocabulary setting: a bare term, or
+    /// comma-separated aliases and the term they become, as in
+    /// `hyper note, hyper notes => Hyprnote`.
+    pub fn with_entry(self, entry: &str) -> Self {
+        let Some((aliases, term)) = entry.split_once("=>") else {
+            return self.with_term(entry.trim());
+        };
+
+        let term = term.trim();
+        if term.is_empty() {
+            return self;
+        }
+        aliases
+            .split(',')
+            .map(str::trim)
+            .filter(|alias| !alias.is_empty())
+            .fold(self.with_term(term), |corrector, alias| {
+                corrector.with_alias(alias, term)
+            })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn match_at(&self, cores: &[String], words: &[FinalizedWord], at: usize) -> Option<&Entry> {
+        self.entries.iter().find(|entry| {
+            let end = at + entry.pattern.len();
+            end <= cores.len()
+                && cores[at..end] == entry.pattern[..]
+                // Punctuation inside a phrase means the words belong apart.
+                && words[at..end - 1]
+                    .iter()
+                    .all(|w| split_affixes(&w.text).2.is_empty())
+        })
+    }
+}
+
+impl TranscriptCorrector for VocabularyCorrector {
+    fn name(&self) -> &'static str {
+        "vocabulary"
+    }
+
+    fn correct(&self, words: &mut Vec<FinalizedWord>, _previous: Option<&FinalizedWord>) {
+        let mut cores: Vec<String> = words.iter().map(|w| normalized(&w.text)).collect();
+
+        let mut i = 0;
+        while i < words.len() {
+            let Some(entry) = self.match_at(&cores, words, i) else {
+                i += 1;
+                continue;
+            };
+
+            let len = entry.pattern.len();
+            let prefix = split_affixes(&words[i].text).0;
+            let suffix = split_affixes(&words[i + len - 1].text).2;
+            let text = format!("{prefix}{}{suffix}", entry.replacement);
+
+            merge_range(words, i, i + len, text);
+            cores.drain(i + 1..i + len);
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{joined, test_words};
+    use super::*;
+
+    #[test]
+    fn replaces_terms_and_aliases() {
+        let corrector = VocabularyCorrector::new()
+            .with_term("Kubernetes")
+            .with_alias("hyper note", "Hyprnote")
+            .with_alias("hyper", "Hypr");
+
+        let mut words = test_words("ship hyper note, then hyper. kubernetes?");
+        corrector.correct(&mut words, None);
+        assert_eq!(joined(&words), " ship Hyprnote, then Hypr. Kubernetes?");
+        assert_eq!(words[1].id, "w1");
+        assert_eq!(words[1].end_ms, 850);
+    }
+
+    #[test]
+    fn parses_setting_entries() {
+        let corrector = [
+            "Kubernetes",
+            "hyper note, hipper note => Hyprnote",
+            " => x",
+            "",
+        ]
+        .into_iter()
+        .fold(VocabularyCorrector::new(), |c, entry| c.with_entry(entry));
+
+        let mut words = test_words("kubernetes on hipper note");
+        corrector.correct(&mut words, None);
+        assert_eq!(joined(&words), " Kubernetes on Hyprnote");
+    }
+
+    #[test]
+    fn does_not_match_across_punctuation() {
+        let corrector = VocabularyCorrector::new().with_alias("hyper note", "Hyprnote");
+
+        let mut words = test_words("too hyper. note that");
+        corrector.correct(&mut words, None);
+        assert_eq!(joined(&words), " too hyper. note that");
+    }
+}
@@ -1,7 +1,12 @@
 mod accumulator;
+mod correction;
 mod processor;
 mod types;
 mod words;
 
+pub use correction::{
+    CorrectorChain, NumberCorrector, ProfanityCorrector, PunctuationCorrector, TranscriptCorrector,
+    VocabularyCorrector,
+};
 pub use processor::TranscriptProcessor;
 pub use types::{FinalizedWord, PartialWord, RawWord, SpeakerHint, TranscriptDelta, WordState};
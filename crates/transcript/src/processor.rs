@@ -3,7 +3,7 @@ use std::collections::{BTreeMap, HashMap};
 use owhisper_interface::{batch::Response as BatchResponse, stream::StreamResponse};
 
 use super::accumulator::ChannelState;
-use super::types::{FinalizedWord, PartialWord, TranscriptDelta, WordState};
+use super::types::{FinalizedWord, PartialWord, SpeakerHint, TranscriptDelta, WordState};
 use super::words::{assemble, assemble_batch, finalize_words};
 
 /// Stateful processor that converts raw `StreamResponse`s into
@@ -23,12 +23,13 @@ use super::words::{assemble, assemble_batch, finalize_words};
 /// - **Inline** (cactus cloud handoff): the streaming protocol itself carries
 ///   handoff/correction metadata. Handled automatically inside `process()`.
 ///
-/// - **External** (LLM postprocessor, future sources): the caller finalizes
+/// - **External** (LLM postprocessor, `CorrectorChain`): the caller finalizes
 ///   words via `process()`, then calls `submit_correction` / `apply_correction`
 ///   to manage the pending→final lifecycle.
 pub struct TranscriptProcessor {
     channels: BTreeMap<i32, ChannelState>,
     pending_corrections: HashMap<u64, Vec<String>>,
+    speaker_identities: HashMap<(i32, i32), String>,
     next_job_id: u64,
 }
 
@@ -37,6 +38,7 @@ impl TranscriptProcessor {
         Self {
             channels: BTreeMap::new(),
             pending_corrections: HashMap::new(),
+            speaker_identities: HashMap::new(),
             next_job_id: 1,
         }
     }
@@ -97,7 +99,8 @@ impl TranscriptProcessor {
                 WordState::Final
             };
 
-            let (new_words, hints) = channel_state.apply_final(raw_words, word_state);
+            let (new_words, mut hints) = channel_state.apply_final(raw_words, word_state);
+            self.identify_hints(ch, &mut hints);
 
             let replaced_ids = if is_cloud_corrected && cloud_job_id != 0 {
                 self.resolve_job(cloud_job_id)
@@ -134,6 +137,28 @@ impl TranscriptProcessor {
         }
     }
 
+    // ── Speaker identity ────────────────────────────────────────────────────
+
+    /// Attribute a diarized speaker on `channel` to a known person (e.g. a
+    /// voiceprint match). Hints emitted afterwards carry `human_id`.
+    /// Passing `None` clears the attribution.
+    pub fn set_speaker_identity(
+        &mut self,
+        channel: i32,
+        speaker_index: i32,
+        human_id: Option<String>,
+    ) {
+        match human_id {
+            Some(human_id) => {
+                self.speaker_identities
+                    .insert((channel, speaker_index), human_id);
+            }
+            None => {
+                self.speaker_identities.remove(&(channel, speaker_index));
+            }
+        }
+    }
+
     // ── Generic correction API ──────────────────────────────────────────────
 
     /// Submit already-emitted `Final` words for asynchronous correction.
@@ -193,8 +218,14 @@ impl TranscriptProcessor {
         let mut new_words = vec![];
         let mut hints = vec![];
 
-        for state in self.channels.values_mut() {
-            let (words, word_hints) = state.drain();
+        for (&ch, state) in self.channels.iter_mut() {
+            let (words, mut word_hints) = state.drain();
+            for hint in &mut word_hints {
+                hint.human_id = self
+                    .speaker_identities
+                    .get(&(ch, hint.speaker_index))
+                    .cloned();
+            }
             new_words.extend(words);
             hints.extend(word_hints);
         }
@@ -243,6 +274,15 @@ impl TranscriptProcessor {
 
     // ── Internal ────────────────────────────────────────────────────────────
 
+    fn identify_hints(&self, channel: i32, hints: &mut [SpeakerHint]) {
+        for hint in hints {
+            hint.human_id = self
+                .speaker_identities
+                .get(&(channel, hint.speaker_index))
+                .cloned();
+        }
+    }
+
     fn register_job(&mut self, job_id: u64, word_ids: Vec<String>) {
         self.pending_corrections.insert(job_id, word_ids);
     }
@@ -270,3 +310,82 @@ impl Default for TranscriptProcessor {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn final_response(channel: i32, words: &[(&str, f64, i32)]) -> StreamResponse {
+        let words: Vec<serde_json::Value> = words
+            .iter()
+            .map(|(word, start, speaker)| {
+                serde_json::json!({
+                    "word": word,
+                    "start": start,
+                    "end": start + 0.3,
+                    "confidence": 0.9,
+                    "speaker": speaker,
+                    "punctuated_word": word,
+                    "language": null,
+                })
+            })
+            .collect();
+        let transcript: Vec<&str> = words.iter().filter_map(|w| w["word"].as_str()).collect();
+
+        serde_json::from_value(serde_json::json!({
+            "type": "Results",
+            "start": 0.0,
+            "duration": 1.0,
+            "is_final": true,
+            "speech_final": true,
+            "from_finalize": false,
+            "channel": {
+                "alternatives": [{
+                    "transcript": transcript.join(" "),
+                    "words": words,
+                    "confidence": 0.9,
+                }],
+            },
+            "metadata": {
+                "request_id": "",
+                "model_info": { "name": "", "version": "", "arch": "" },
+                "model_uuid": "",
+            },
+            "channel_index": [channel, 2],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn hints_carry_speaker_identity() {
+        let mut processor = TranscriptProcessor::new();
+        processor.set_speaker_identity(1, 0, Some("alice".to_string()));
+        processor.set_speaker_identity(1, 2, Some("carol".to_string()));
+        processor.set_speaker_identity(1, 2, None);
+
+        let mut hints = Vec::new();
+        for response in [
+            final_response(
+                1,
+                &[("hello", 0.0, 0), ("there", 0.4, 1), ("again", 0.8, 2)],
+            ),
+            final_response(0, &[("hi", 1.0, 0), ("you", 1.4, 0)]),
+            final_response(1, &[("so", 1.2, 2), ("ok", 1.6, 0)]),
+        ] {
+            if let Some(delta) = processor.process(&response) {
+                hints.extend(delta.hints);
+            }
+        }
+
+        let identities: Vec<(i32, Option<&str>)> = hints
+            .iter()
+            .map(|h| (h.speaker_index, h.human_id.as_deref()))
+            .collect();
+        assert!(identities.contains(&(0, Some("alice"))));
+        assert!(identities.contains(&(1, None)));
+        assert!(identities.contains(&(2, None)));
+        assert!(!identities.contains(&(2, Some("carol"))));
+        // Channel 0's speaker 0 is a different person than channel 1's.
+        assert!(identities.contains(&(0, None)));
+    }
+}
@@ -43,6 +43,9 @@ pub struct FinalizedWord {
 pub struct SpeakerHint {
     pub word_id: String,
     pub speaker_index: i32,
+    /// `humans.id` of the recognized voice behind `speaker_index`, if any.
+    #[serde(default)]
+    pub human_id: Option<String>,
 }
 
 /// Delta emitted to the frontend after processing.
@@ -83,7 +83,7 @@ pub(super) fn assemble_batch(raw: &[batch::Word], transcript: &str, channel: i32
 /// The transcript is the oracle: if a token is found, the whitespace before it
 /// is prepended verbatim. If not found, a space is forced ("unknown = word
 /// boundary").
-fn spacing_from_transcript(raw: &[Word], transcript: &str) -> Vec<String> {
+pub(super) fn spacing_from_transcript(raw: &[Word], transcript: &str) -> Vec<String> {
     spacing_from_slice(
         raw.iter()
             .map(|w| (w.word.as_str(), w.punctuated_word.as_deref())),
@@ -229,6 +229,7 @@ pub(super) fn finalize_words(
             hints.push(SpeakerHint {
                 word_id: id.clone(),
                 speaker_index,
+                human_id: None,
             });
         }
 
@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("session not found: {0}")]
+    SessionNotFound(String),
+
+    #[error("search failed: {0}")]
+    Search(String),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Frontmatter(#[from] hypr_frontmatter::Error),
+
+    #[error(transparent)]
+    SessionContent(#[from] hypr_session_content::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Result, Vault};
+
+const EVENTS_FILE: &str = "events.json";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Event {
+    pub id: String,
+    pub title: String,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    pub is_all_day: bool,
+    pub location: Option<String>,
+    pub meeting_link: Option<String>,
+    pub description: Option<String>,
+    pub participants: Vec<EventParticipant>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventParticipant {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub is_organizer: Option<bool>,
+    pub is_current_user: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct EventRow {
+    title: Option<String>,
+    started_at: Option<String>,
+    ended_at: Option<String>,
+    is_all_day: Option<bool>,
+    location: Option<String>,
+    meeting_link: Option<String>,
+    description: Option<String>,
+    participants: serde_json::Value,
+}
+
+impl Vault {
+    /// Calendar events that haven't ended by `now` and start before `until`,
+    /// soonest first.
+    pub fn upcoming_events(
+        &self,
+        now: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        limit: usize,
+    ) -> Result<Vec<Event>> {
+        let rows: HashMap<String, EventRow> =
+            match std::fs::read_to_string(self.root.join(EVENTS_FILE)) {
+                Ok(content) => serde_json::from_str(&content)?,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+                Err(e) => return Err(e.into()),
+            };
+
+        let mut events: Vec<(DateTime<Utc>, Event)> = rows
+            .into_iter()
+            .filter_map(|(id, row)| {
+                let started_at = parse_time(row.started_at.as_deref()?)?;
+                let ended_at = row.ended_at.as_deref().and_then(parse_time);
+
+                if ended_at.unwrap_or(started_at) < now {
+                    return None;
+                }
+                if until.is_some_and(|until| started_at > until) {
+                    return None;
+                }
+
+                Some((started_at, row.into_event(id)))
+            })
+            .collect();
+
+        events.sort_by_key(|(started_at, _)| *started_at);
+        Ok(events
+            .into_iter()
+            .take(limit)
+            .map(|(_, event)| event)
+            .collect())
+    }
+}
+
+impl EventRow {
+    fn into_event(self, id: String) -> Event {
+        let non_empty = |s: Option<String>| s.filter(|s| !s.trim().is_empty());
+
+        // The app stores participants as a JSON array; older files kept the
+        // encoded string.
+        let participants = match self.participants {
+            serde_json::Value::String(s) => serde_json::from_str(&s).unwrap_or_default(),
+            value => serde_json::from_value(value).unwrap_or_default(),
+        };
+
+        Event {
+            id,
+            title: non_empty(self.title).unwrap_or_else(|| "Untitled".to_string()),
+            started_at: self.started_at.unwrap_or_default(),
+            ended_at: non_empty(self.ended_at),
+            is_all_day: self.is_all_day.unwrap_or(false),
+            location: non_empty(self.location),
+            meeting_link: non_empty(self.meeting_link),
+            description: non_empty(self.description),
+            participants,
+        }
+    }
+}
+
+fn parse_time(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upcoming_events_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(EVENTS_FILE),
+            r#"{
+                "past": { "title": "Retro", "started_at": "2024-03-01T09:00:00Z", "ended_at": "2024-03-01T10:00:00Z" },
+                "ongoing": { "title": "Standup", "started_at": "2024-03-05T08:50:00Z", "ended_at": "2024-03-05T09:10:00Z" },
+                "later": { "title": "Acme call", "started_at": "2024-03-06T15:00:00Z", "participants": [{ "name": "Alice", "email": "alice@acme.com" }] },
+                "far": { "title": "Offsite", "started_at": "2024-04-01T09:00:00Z" }
+            }"#,
+        )
+        .unwrap();
+
+        let vault = Vault::new(dir.path());
+        let now = parse_time("2024-03-05T09:00:00Z").unwrap();
+
+        let events = vault
+            .upcoming_events(now, Some(now + chrono::Duration::days(7)), 10)
+            .unwrap();
+        let ids: Vec<_> = events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["ongoing", "later"]);
+        assert_eq!(
+            events[1].participants[0].email.as_deref(),
+            Some("alice@acme.com")
+        );
+
+        assert_eq!(vault.upcoming_events(now, None, 1).unwrap().len(), 1);
+    }
+}
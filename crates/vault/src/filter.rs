@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+
+use crate::Human;
+
+/// Narrows sessions by date, tag and participant. Unset criteria match
+/// every session.
+#[derive(Debug, Clone, Default)]
+pub struct SessionFilter {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    /// Every tag must be present, compared case-insensitively.
+    pub tags: Vec<String>,
+    /// Case-insensitive substring of a participant's name or email.
+    pub participant: Option<String>,
+}
+
+impl SessionFilter {
+    pub(crate) fn matches(
+        &self,
+        created_at: Option<&str>,
+        tags: &[String],
+        participants: &[Human],
+    ) -> bool {
+        in_range(created_at, self.since, self.until)
+            && self
+                .tags
+                .iter()
+                .all(|wanted| tags.iter().any(|tag| tag.eq_ignore_ascii_case(wanted)))
+            && self.participant.as_deref().is_none_or(|needle| {
+                let needle = needle.to_lowercase();
+                participants.iter().any(|human| {
+                    human.name.to_lowercase().contains(&needle)
+                        || human
+                            .emails
+                            .iter()
+                            .any(|email| email.to_lowercase().contains(&needle))
+                })
+            })
+    }
+}
+
+/// Sessions without a parseable `created_at` only match an open range.
+pub(crate) fn in_range(
+    created_at: Option<&str>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+) -> bool {
+    if since.is_none() && until.is_none() {
+        return true;
+    }
+
+    let Some(created_at) = created_at.and_then(|s| DateTime::parse_from_rfc3339(s).ok()) else {
+        return false;
+    };
+
+    since.is_none_or(|since| created_at >= since) && until.is_none_or(|until| created_at <= until)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vault;
+    use crate::session::tests::fixture;
+
+    #[test]
+    fn filters_by_tag_participant_and_date() {
+        let dir = fixture();
+        let vault = Vault::new(dir.path());
+
+        let ids = |filter: SessionFilter| -> Vec<String> {
+            vault
+                .find_sessions(&filter)
+                .unwrap()
+                .into_iter()
+                .map(|s| s.id)
+                .collect()
+        };
+
+        assert_eq!(ids(SessionFilter::default()), vec!["s2", "s1"]);
+        assert_eq!(
+            ids(SessionFilter {
+                tags: vec!["Customer".to_string()],
+                ..Default::default()
+            }),
+            vec!["s1"]
+        );
+        assert_eq!(
+            ids(SessionFilter {
+                participant: Some("ACME.com".to_string()),
+                ..Default::default()
+            }),
+            vec!["s1"]
+        );
+        assert_eq!(
+            ids(SessionFilter {
+                until: DateTime::parse_from_rfc3339("2024-03-06T00:00:00Z")
+                    .ok()
+                    .map(|dt| dt.with_timezone(&Utc)),
+                ..Default::default()
+            }),
+            vec!["s1"]
+        );
+        assert!(
+            ids(SessionFilter {
+                tags: vec!["customer".to_string(), "internal".to_string()],
+                ..Default::default()
+            })
+            .is_empty()
+        );
+    }
+}
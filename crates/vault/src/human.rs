@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use hypr_frontmatter::Document;
+use serde::{Deserialize, Serialize};
+
+use crate::{Result, Vault};
+
+const HUMANS_DIR: &str = "humans";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Human {
+    pub id: String,
+    pub name: String,
+    pub emails: Vec<String>,
+    pub job_title: Option<String>,
+    pub org_id: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct HumanFrontmatter {
+    name: Option<String>,
+    emails: Vec<String>,
+    email: Option<String>,
+    job_title: Option<String>,
+    org_id: Option<String>,
+}
+
+impl Vault {
+    /// Every contact in `humans/`, sorted by name. Unreadable files are skipped.
+    pub fn humans(&self) -> Result<Vec<Human>> {
+        let dir = self.root.join(HUMANS_DIR);
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut humans: Vec<Human> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+                    return None;
+                }
+                let id = path.file_stem()?.to_str()?.to_string();
+                let content = std::fs::read_to_string(&path).ok()?;
+                parse_human(id, &content)
+            })
+            .collect();
+
+        humans.sort_by_key(|human| human.name.to_lowercase());
+        Ok(humans)
+    }
+
+    pub(crate) fn humans_by_id(&self) -> Result<HashMap<String, Human>> {
+        Ok(self
+            .humans()?
+            .into_iter()
+            .map(|human| (human.id.clone(), human))
+            .collect())
+    }
+}
+
+fn parse_human(id: String, content: &str) -> Option<Human> {
+    let doc = Document::<HumanFrontmatter>::from_str(content).ok()?;
+    let frontmatter = doc.frontmatter;
+
+    let mut emails = frontmatter.emails;
+    if emails.is_empty() {
+        emails.extend(frontmatter.email);
+    }
+    emails.retain(|email| !email.trim().is_empty());
+
+    Some(Human {
+        name: frontmatter.name.unwrap_or_default(),
+        emails,
+        job_title: frontmatter.job_title.filter(|s| !s.is_empty()),
+        org_id: frontmatter.org_id.filter(|s| !s.is_empty()),
+        id,
+    })
+}
@@ -0,0 +1,39 @@
+mod error;
+mod event;
+mod filter;
+mod human;
+mod search;
+mod session;
+mod transcript;
+mod vocabulary;
+
+#[cfg(feature = "mcp")]
+pub mod mcp;
+
+use std::path::{Path, PathBuf};
+
+pub use error::{Error, Result};
+pub use event::{Event, EventParticipant};
+pub use filter::SessionFilter;
+pub use human::Human;
+pub use search::{SearchFuture, SearchMatch, SearchQuery, SessionSearch};
+pub use session::{Note, Session, SessionSummary};
+pub use transcript::Utterance;
+pub use vocabulary::SessionVocabularyContext;
+
+/// Read access to the meeting vault the desktop app writes to disk, plus
+/// appending to session notes. Works without the app running.
+#[derive(Debug, Clone)]
+pub struct Vault {
+    root: PathBuf,
+}
+
+impl Vault {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use rmcp::{
+    ErrorData as McpError, ServerHandler, handler::server::tool::ToolRouter,
+    handler::server::wrapper::Parameters, model::*, tool, tool_handler, tool_router,
+};
+
+use crate::{SessionSearch, Vault};
+
+use super::tools::{
+    self, AppendToNoteParams, GetSessionParams, ListParticipantsParams, ListUpcomingEventsParams,
+    SearchSessionsParams,
+};
+
+/// Exposes a local vault to MCP clients. Searches scan the vault's files
+/// unless a [`SessionSearch`] backed by an index is provided.
+#[derive(Clone)]
+pub struct VaultMcpServer {
+    vault: Vault,
+    search: Arc<dyn SessionSearch>,
+    tool_router: ToolRouter<Self>,
+}
+
+impl VaultMcpServer {
+    pub fn new(vault: Vault) -> Self {
+        Self {
+            search: Arc::new(vault.clone()),
+            vault,
+            tool_router: Self::tool_router(),
+        }
+    }
+
+    pub fn with_search(mut self, search: Arc<dyn SessionSearch>) -> Self {
+        self.search = search;
+        self
+    }
+}
+
+#[tool_router]
+impl VaultMcpServer {
+    #[tool(
+        description = "Search the user's recorded meetings by keywords and/or date range. Returns session ids, titles, dates and a matching snippet. Use get_session to read one.",
+        annotations(
+            read_only_hint = true,
+            destructive_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn search_sessions(
+        &self,
+        Parameters(params): Parameters<SearchSessionsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::search_sessions(self.search.as_ref(), params).await
+    }
+
+    #[tool(
+        description = "Get a meeting by session id: title, date, participants, the user's notes, generated summaries and the speaker-attributed transcript.",
+        annotations(
+            read_only_hint = true,
+            destructive_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn get_session(
+        &self,
+        Parameters(params): Parameters<GetSessionParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::get_session(&self.vault, params).await
+    }
+
+    #[tool(
+        description = "List the people in a meeting, or search all of the user's contacts. Returns names, emails and job titles.",
+        annotations(
+            read_only_hint = true,
+            destructive_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn list_participants(
+        &self,
+        Parameters(params): Parameters<ListParticipantsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::list_participants(&self.vault, params).await
+    }
+
+    #[tool(
+        description = "List the user's upcoming calendar events, soonest first, including ones in progress.",
+        annotations(
+            read_only_hint = true,
+            destructive_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn list_upcoming_events(
+        &self,
+        Parameters(params): Parameters<ListUpcomingEventsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::list_upcoming_events(&self.vault, params).await
+    }
+
+    #[tool(
+        description = "Append Markdown to the end of the user's note for a meeting. Existing content is never changed.",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn append_to_note(
+        &self,
+        Parameters(params): Parameters<AppendToNoteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::append_to_note(&self.vault, params).await
+    }
+}
+
+#[tool_handler]
+impl ServerHandler for VaultMcpServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            protocol_version: ProtocolVersion::V_2024_11_05,
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            server_info: Implementation {
+                name: "char-vault".to_string(),
+                title: None,
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Char meeting vault. Search the user's recorded meetings, read their notes and transcripts, look up participants and upcoming events, and append to meeting notes. All data is local."
+                    .to_string(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use rmcp::{
+    ErrorData as McpError,
+    model::*,
+    schemars::{self, JsonSchema},
+};
+use serde::Deserialize;
+
+use crate::Vault;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct AppendToNoteParams {
+    #[schemars(description = "The session whose note to append to")]
+    pub session_id: String,
+    #[schemars(description = "Markdown to add at the end of the user's note")]
+    pub markdown: String,
+}
+
+pub(crate) async fn append_to_note(
+    vault: &Vault,
+    params: AppendToNoteParams,
+) -> Result<CallToolResult, McpError> {
+    if params.markdown.trim().is_empty() {
+        return Err(McpError::invalid_params("markdown is empty", None));
+    }
+
+    vault
+        .append_to_memo(&params.session_id, &params.markdown)
+        .map_err(super::vault_error)?;
+
+    Ok(CallToolResult::success(vec![Content::text(
+        serde_json::json!({ "success": true }).to_string(),
+    )]))
+}
@@ -0,0 +1,31 @@
+use rmcp::{
+    ErrorData as McpError,
+    model::*,
+    schemars::{self, JsonSchema},
+};
+use serde::Deserialize;
+
+use crate::Vault;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct GetSessionParams {
+    #[schemars(description = "The session id (from search_sessions results)")]
+    pub session_id: String,
+    #[schemars(description = "Include the speaker-attributed transcript (default true)")]
+    pub include_transcript: Option<bool>,
+}
+
+pub(crate) async fn get_session(
+    vault: &Vault,
+    params: GetSessionParams,
+) -> Result<CallToolResult, McpError> {
+    let mut session = vault
+        .session(&params.session_id)
+        .map_err(super::vault_error)?;
+
+    if !params.include_transcript.unwrap_or(true) {
+        session.transcript.clear();
+    }
+
+    super::json_result(&session)
+}
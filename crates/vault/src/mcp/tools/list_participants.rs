@@ -0,0 +1,45 @@
+use rmcp::{
+    ErrorData as McpError,
+    model::*,
+    schemars::{self, JsonSchema},
+};
+use serde::Deserialize;
+
+use crate::Vault;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct ListParticipantsParams {
+    #[schemars(
+        description = "List the participants of this session. Without it, lists all contacts."
+    )]
+    pub session_id: Option<String>,
+    #[schemars(description = "Only people whose name or email contains this text")]
+    pub query: Option<String>,
+}
+
+pub(crate) async fn list_participants(
+    vault: &Vault,
+    params: ListParticipantsParams,
+) -> Result<CallToolResult, McpError> {
+    let mut humans = match &params.session_id {
+        Some(session_id) => {
+            vault
+                .session(session_id)
+                .map_err(super::vault_error)?
+                .participants
+        }
+        None => vault.humans().map_err(super::vault_error)?,
+    };
+
+    if let Some(query) = params.query.as_deref().map(str::to_lowercase) {
+        humans.retain(|human| {
+            human.name.to_lowercase().contains(&query)
+                || human
+                    .emails
+                    .iter()
+                    .any(|email| email.to_lowercase().contains(&query))
+        });
+    }
+
+    super::json_result(&humans)
+}
@@ -0,0 +1,37 @@
+use rmcp::{
+    ErrorData as McpError,
+    model::*,
+    schemars::{self, JsonSchema},
+};
+use serde::Deserialize;
+
+use crate::Vault;
+
+const DEFAULT_DAYS: i64 = 7;
+const DEFAULT_LIMIT: usize = 20;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct ListUpcomingEventsParams {
+    #[schemars(description = "How many days ahead to look (default 7)")]
+    pub days: Option<u32>,
+    #[schemars(description = "Maximum number of events to return (default 20)")]
+    pub limit: Option<usize>,
+}
+
+pub(crate) async fn list_upcoming_events(
+    vault: &Vault,
+    params: ListUpcomingEventsParams,
+) -> Result<CallToolResult, McpError> {
+    let now = chrono::Utc::now();
+    let days = params.days.map(i64::from).unwrap_or(DEFAULT_DAYS);
+
+    let events = vault
+        .upcoming_events(
+            now,
+            Some(now + chrono::Duration::days(days)),
+            params.limit.unwrap_or(DEFAULT_LIMIT),
+        )
+        .map_err(super::vault_error)?;
+
+    super::json_result(&events)
+}
@@ -0,0 +1,27 @@
+mod append_to_note;
+mod get_session;
+mod list_participants;
+mod list_upcoming_events;
+mod search_sessions;
+
+pub(crate) use append_to_note::{AppendToNoteParams, append_to_note};
+pub(crate) use get_session::{GetSessionParams, get_session};
+pub(crate) use list_participants::{ListParticipantsParams, list_participants};
+pub(crate) use list_upcoming_events::{ListUpcomingEventsParams, list_upcoming_events};
+pub(crate) use search_sessions::{SearchSessionsParams, search_sessions};
+
+use rmcp::{ErrorData as McpError, model::*};
+use serde::Serialize;
+
+fn json_result(value: &impl Serialize) -> Result<CallToolResult, McpError> {
+    Ok(CallToolResult::success(vec![Content::text(
+        serde_json::to_string(value).map_err(|e| McpError::internal_error(e.to_string(), None))?,
+    )]))
+}
+
+fn vault_error(error: crate::Error) -> McpError {
+    match error {
+        crate::Error::SessionNotFound(_) => McpError::invalid_params(error.to_string(), None),
+        _ => McpError::internal_error(error.to_string(), None),
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rmcp::{
+    ErrorData as McpError,
+    model::*,
+    schemars::{self, JsonSchema},
+};
+use serde::Deserialize;
+
+use crate::{SearchQuery, SessionSearch};
+
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 50;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct SearchSessionsParams {
+    #[schemars(
+        description = "Keywords to look for in titles, notes and transcripts. Leave empty to list sessions in the date range."
+    )]
+    #[serde(default)]
+    pub query: String,
+    #[schemars(
+        description = "Only sessions created at or after this time (RFC 3339 or YYYY-MM-DD)"
+    )]
+    pub since: Option<String>,
+    #[schemars(description = "Only sessions created before this time (RFC 3339 or YYYY-MM-DD)")]
+    pub until: Option<String>,
+    #[schemars(description = "Maximum number of sessions to return (default 10, max 50)")]
+    pub limit: Option<usize>,
+}
+
+pub(crate) async fn search_sessions(
+    search: &dyn SessionSearch,
+    params: SearchSessionsParams,
+) -> Result<CallToolResult, McpError> {
+    let query = SearchQuery {
+        query: params.query.trim().to_string(),
+        since: params.since.as_deref().map(parse_time).transpose()?,
+        until: params.until.as_deref().map(parse_time).transpose()?,
+        limit: params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+    };
+
+    let matches = search.search(&query).await.map_err(super::vault_error)?;
+    super::json_result(&matches)
+}
+
+fn parse_time(s: &str) -> Result<DateTime<Utc>, McpError> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+        .ok_or_else(|| McpError::invalid_params(format!("invalid date: {s}"), None))
+}
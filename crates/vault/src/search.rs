@@ -0,0 +1,181 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::filter::in_range;
+use crate::{Result, Session, Vault};
+
+const SNIPPET_CONTEXT_CHARS: usize = 80;
+
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    /// Empty matches every session in the date range.
+    pub query: String,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchMatch {
+    pub session_id: String,
+    pub title: String,
+    pub created_at: Option<String>,
+    pub score: f32,
+    pub snippet: Option<String>,
+}
+
+pub type SearchFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<SearchMatch>>> + Send + 'a>>;
+
+/// Finds sessions for a query. The desktop app backs this with its search
+/// index; [`Vault`] itself implements it by scanning files.
+pub trait SessionSearch: Send + Sync {
+    fn search<'a>(&'a self, query: &'a SearchQuery) -> SearchFuture<'a>;
+}
+
+impl SessionSearch for Vault {
+    fn search<'a>(&'a self, query: &'a SearchQuery) -> SearchFuture<'a> {
+        Box::pin(async move { self.scan(query) })
+    }
+}
+
+impl Vault {
+    /// Case-insensitive match of every query term against titles, notes and
+    /// transcripts. Title hits weigh more.
+    pub fn scan(&self, query: &SearchQuery) -> Result<Vec<SearchMatch>> {
+        let terms: Vec<String> = query
+            .query
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect();
+
+        let mut matches: Vec<SearchMatch> = self
+            .sessions()?
+            .into_iter()
+            .filter(|session| in_range(session.created_at.as_deref(), query.since, query.until))
+            .filter_map(|session| score(&session, &terms))
+            .collect();
+
+        matches.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| b.created_at.cmp(&a.created_at))
+        });
+        matches.truncate(query.limit);
+        Ok(matches)
+    }
+}
+
+fn score(session: &Session, terms: &[String]) -> Option<SearchMatch> {
+    let title = session.title.to_lowercase();
+    let body = searchable_text(session);
+    let body_lower = body.to_lowercase();
+
+    let mut score = 0.0;
+    for term in terms {
+        let in_title = title.matches(term.as_str()).count();
+        let in_body = body_lower.matches(term.as_str()).count();
+        if in_title + in_body == 0 {
+            return None;
+        }
+        score += (3 * in_title + in_body) as f32;
+    }
+
+    Some(SearchMatch {
+        session_id: session.id.clone(),
+        title: session.title.clone(),
+        created_at: session.created_at.clone(),
+        score,
+        snippet: terms.first().and_then(|term| snippet(&body, term)),
+    })
+}
+
+fn searchable_text(session: &Session) -> String {
+    let mut parts = vec![session.memo.clone()];
+    parts.extend(session.notes.iter().map(|note| note.content.clone()));
+    parts.extend(
+        session
+            .transcript
+            .iter()
+            .map(|u| format!("{}: {}", u.speaker, u.text)),
+    );
+    parts.join("\n")
+}
+
+fn snippet(text: &str, term: &str) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = text.to_lowercase().chars().collect();
+    let needle: Vec<char> = term.chars().collect();
+
+    // Lowercasing can change the character count; fall back to the start.
+    let position = if lower.len() == chars.len() {
+        lower
+            .windows(needle.len())
+            .position(|w| w == needle.as_slice())?
+    } else {
+        0
+    };
+
+    let start = position.saturating_sub(SNIPPET_CONTEXT_CHARS);
+    let end = (position + needle.len() + SNIPPET_CONTEXT_CHARS).min(chars.len());
+    let fragment: String = chars[start..end].iter().collect();
+    let fragment = fragment.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    Some(format!(
+        "{}{}{}",
+        if start > 0 { "…" } else { "" },
+        fragment,
+        if end < chars.len() { "…" } else { "" }
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::tests::fixture;
+
+    fn query(q: &str) -> SearchQuery {
+        SearchQuery {
+            query: q.to_string(),
+            limit: 10,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn matches_notes_and_transcripts() {
+        let dir = fixture();
+        let vault = Vault::new(dir.path());
+
+        let hits = vault.scan(&query("price")).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].session_id, "s1");
+        assert_eq!(
+            hits[0].snippet.as_deref(),
+            Some("Pricing pushback. - Wants a discount Alice Smith: The price is too high.")
+        );
+
+        assert!(vault.scan(&query("price offsite")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn empty_query_lists_sessions_in_range() {
+        let dir = fixture();
+        let vault = Vault::new(dir.path());
+
+        let mut q = query("");
+        q.since = DateTime::parse_from_rfc3339("2024-03-06T00:00:00Z")
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc));
+
+        let ids: Vec<_> = vault
+            .scan(&q)
+            .unwrap()
+            .into_iter()
+            .map(|hit| hit.session_id)
+            .collect();
+        assert_eq!(ids, vec!["s2"]);
+    }
+}
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hypr_frontmatter::Document;
+use hypr_session_content::{
+    SESSION_MEMO_FILE, SESSION_META_FILE, SessionMeta, load_session_content, read_session_meta,
+};
+use serde::Serialize;
+use serde_yaml::{Mapping, Value};
+
+use crate::transcript::utterances;
+use crate::{Error, Human, Result, SessionFilter, Utterance, Vault};
+
+const SESSIONS_DIR: &str = "sessions";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionSummary {
+    pub id: String,
+    pub title: String,
+    pub created_at: Option<String>,
+    pub event_title: Option<String>,
+    pub participants: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Session {
+    pub id: String,
+    pub title: String,
+    pub created_at: Option<String>,
+    pub event: Option<serde_json::Value>,
+    pub participants: Vec<Human>,
+    pub tags: Vec<String>,
+    /// The user's own notes, as Markdown.
+    pub memo: String,
+    /// Generated notes (summaries and templates), as Markdown.
+    pub notes: Vec<Note>,
+    pub transcript: Vec<Utterance>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Note {
+    pub title: Option<String>,
+    pub content: String,
+}
+
+fn title(meta: &SessionMeta) -> String {
+    meta.title
+        .as_deref()
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .unwrap_or("Untitled")
+        .to_string()
+}
+
+pub(crate) fn event_title(meta: &SessionMeta) -> Option<String> {
+    meta.event
+        .as_ref()?
+        .get("title")?
+        .as_str()
+        .map(str::to_string)
+}
+
+pub(crate) fn participants<'a>(
+    meta: &'a SessionMeta,
+    humans: &'a HashMap<String, Human>,
+) -> impl Iterator<Item = Human> + 'a {
+    meta.participants.iter().map(|p| {
+        humans.get(&p.human_id).cloned().unwrap_or_else(|| Human {
+            id: p.human_id.clone(),
+            name: String::new(),
+            emails: vec![],
+            job_title: None,
+            org_id: None,
+        })
+    })
+}
+
+impl Vault {
+    /// All sessions, newest first. Sessions can be nested in folders.
+    pub fn list_sessions(&self) -> Result<Vec<SessionSummary>> {
+        self.find_sessions(&SessionFilter::default())
+    }
+
+    /// Sessions matching `filter`, newest first.
+    pub fn find_sessions(&self, filter: &SessionFilter) -> Result<Vec<SessionSummary>> {
+        let humans = self.humans_by_id()?;
+
+        let mut sessions: Vec<SessionSummary> = self
+            .session_dirs()?
+            .into_iter()
+            .filter_map(|(id, dir)| {
+                let meta = read_session_meta(&dir).ok()?;
+                let participants: Vec<Human> = participants(&meta, &humans).collect();
+                if !filter.matches(meta.created_at.as_deref(), &meta.tags, &participants) {
+                    return None;
+                }
+
+                Some(SessionSummary {
+                    title: title(&meta),
+                    event_title: event_title(&meta),
+                    participants: participants
+                        .into_iter()
+                        .map(|human| human.name)
+                        .filter(|name| !name.is_empty())
+                        .collect(),
+                    created_at: meta.created_at,
+                    tags: meta.tags,
+                    id,
+                })
+            })
+            .collect();
+
+        sessions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(sessions)
+    }
+
+    pub fn session(&self, session_id: &str) -> Result<Session> {
+        let dir = self.session_dir(session_id)?;
+        let humans = self.humans_by_id()?;
+        load_session(session_id, &dir, &humans)
+    }
+
+    pub(crate) fn sessions(&self) -> Result<Vec<Session>> {
+        let humans = self.humans_by_id()?;
+        Ok(self
+            .session_dirs()?
+            .into_iter()
+            .filter_map(|(id, dir)| load_session(&id, &dir, &humans).ok())
+            .collect())
+    }
+
+    /// Appends Markdown to the end of the session's memo, creating it if
+    /// needed. The app picks up the change through its file watcher.
+    pub fn append_to_memo(&self, session_id: &str, markdown: &str) -> Result<()> {
+        let path = self.session_dir(session_id)?.join(SESSION_MEMO_FILE);
+
+        let mut doc = match std::fs::read_to_string(&path) {
+            Ok(content) => Document::<Mapping>::from_str(&content)
+                .unwrap_or_else(|_| Document::new(Mapping::new(), content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                Document::new(Mapping::new(), String::new())
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        for key in ["id", "session_id"] {
+            doc.frontmatter
+                .entry(Value::String(key.to_string()))
+                .or_insert_with(|| Value::String(session_id.to_string()));
+        }
+
+        let existing = doc.content.trim_end();
+        doc.content = if existing.is_empty() {
+            format!("{}\n", markdown.trim())
+        } else {
+            format!("{existing}\n\n{}\n", markdown.trim())
+        };
+
+        hypr_storage::fs::atomic_write(&path, &doc.render()?)?;
+        Ok(())
+    }
+
+    /// The directory holding the session's files; relative attachment paths
+    /// in its notes resolve against it.
+    pub fn session_dir(&self, session_id: &str) -> Result<PathBuf> {
+        self.session_dirs()?
+            .into_iter()
+            .find(|(id, _)| id == session_id)
+            .map(|(_, dir)| dir)
+            .ok_or_else(|| Error::SessionNotFound(session_id.to_string()))
+    }
+
+    /// `(id, dir)` for every directory under `sessions/` holding a `_meta.json`.
+    pub(crate) fn session_dirs(&self) -> Result<Vec<(String, PathBuf)>> {
+        let mut found = Vec::new();
+        let mut pending = vec![self.root.join(SESSIONS_DIR)];
+
+        while let Some(dir) = pending.pop() {
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if !path.is_dir() {
+                    continue;
+                }
+                if path.join(SESSION_META_FILE).is_file() {
+                    if let Some(id) = path.file_name().and_then(|name| name.to_str()) {
+                        found.push((id.to_string(), path.clone()));
+                    }
+                } else {
+                    pending.push(path);
+                }
+            }
+        }
+
+        Ok(found)
+    }
+}
+
+impl Session {
+    /// The whole session as one Markdown document: details, the user's
+    /// notes, generated notes and the transcript.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {}\n\n", self.title);
+
+        if let Some(created_at) = &self.created_at {
+            let _ = writeln!(out, "- Date: {created_at}");
+        }
+        if let Some(event_title) = self
+            .event
+            .as_ref()
+            .and_then(|event| event.get("title"))
+            .and_then(|title| title.as_str())
+        {
+            let _ = writeln!(out, "- Event: {event_title}");
+        }
+        if !self.participants.is_empty() {
+            let participants: Vec<String> = self
+                .participants
+                .iter()
+                .map(|human| {
+                    let name = if human.name.is_empty() {
+                        &human.id
+                    } else {
+                        &human.name
+                    };
+                    match human.emails.first() {
+                        Some(email) => format!("{name} <{email}>"),
+                        None => name.clone(),
+                    }
+                })
+                .collect();
+            let _ = writeln!(out, "- Participants: {}", participants.join(", "));
+        }
+        if !self.tags.is_empty() {
+            let _ = writeln!(out, "- Tags: {}", self.tags.join(", "));
+        }
+
+        if !self.memo.is_empty() {
+            let _ = write!(out, "\n## Notes\n\n{}\n", self.memo);
+        }
+        for note in &self.notes {
+            let title = note.title.as_deref().unwrap_or("Generated notes");
+            let _ = write!(out, "\n## {title}\n\n{}\n", note.content);
+        }
+
+        if !self.transcript.is_empty() {
+            out.push_str("\n## Transcript\n\n");
+            for utterance in &self.transcript {
+                let _ = writeln!(
+                    out,
+                    "**{}** `{}` {}\n",
+                    utterance.speaker,
+                    format_offset(utterance.start_ms),
+                    utterance.text
+                );
+            }
+            out.truncate(out.trim_end().len());
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+/// `mm:ss`, or `h:mm:ss` past the first hour.
+fn format_offset(ms: i64) -> String {
+    let seconds = ms.max(0) / 1000;
+    let (hours, minutes, seconds) = (seconds / 3600, (seconds / 60) % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+fn load_session(session_id: &str, dir: &Path, humans: &HashMap<String, Human>) -> Result<Session> {
+    let content = load_session_content(session_id, dir);
+    // Only re-read to surface why `_meta.json` didn't load.
+    let meta = match content.meta {
+        Some(meta) => meta,
+        None => read_session_meta(dir)?,
+    };
+
+    Ok(Session {
+        id: session_id.to_string(),
+        title: title(&meta),
+        created_at: meta.created_at.clone(),
+        participants: participants(&meta, humans).collect(),
+        event: meta.event,
+        tags: meta.tags,
+        memo: content
+            .memo
+            .map(|memo| memo.trim().to_string())
+            .unwrap_or_default(),
+        notes: content
+            .notes
+            .into_iter()
+            .map(|note| Note {
+                title: note.title,
+                content: note.content.trim().to_string(),
+            })
+            .collect(),
+        transcript: content
+            .transcript
+            .map(|transcript| utterances(transcript, humans))
+            .unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    pub(crate) fn fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        write(
+            root,
+            "humans/alice.md",
+            "---\nname: Alice Smith\nemails:\n  - alice@acme.com\njob_title: CTO\n---\n",
+        );
+        write(
+            root,
+            "sessions/s1/_meta.json",
+            r#"{"id":"s1","user_id":"u","created_at":"2024-03-05T10:00:00Z","title":"Acme renewal","participants":[{"id":"p","user_id":"u","session_id":"s1","human_id":"alice","source":"manual"}],"tags":["customer"]}"#,
+        );
+        write(
+            root,
+            "sessions/s1/_memo.md",
+            "---\nid: s1\nsession_id: s1\n---\n\nPricing pushback.",
+        );
+        write(
+            root,
+            "sessions/s1/Summary.md",
+            "---\nid: n1\nsession_id: s1\ntitle: Summary\n---\n\n- Wants a discount",
+        );
+        write(
+            root,
+            "sessions/s1/transcript.json",
+            r#"{"transcripts":[{"id":"t","session_id":"s1","started_at":0,"words":[{"id":"w1","text":" The price is too high.","start_ms":0,"end_ms":900,"channel":1}],"speaker_hints":[{"id":"h","word_id":"w1","type":"user_speaker_assignment","value":"{\"human_id\":\"alice\"}"}]}]}"#,
+        );
+        write(
+            root,
+            "sessions/Work/s2/_meta.json",
+            r#"{"id":"s2","user_id":"u","created_at":"2024-03-07T09:00:00Z","title":"","participants":[]}"#,
+        );
+
+        dir
+    }
+
+    #[test]
+    fn lists_nested_sessions_newest_first() {
+        let dir = fixture();
+        let sessions = Vault::new(dir.path()).list_sessions().unwrap();
+
+        let ids: Vec<_> = sessions.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["s2", "s1"]);
+        assert_eq!(sessions[0].title, "Untitled");
+        assert_eq!(sessions[1].participants, vec!["Alice Smith"]);
+        assert_eq!(sessions[1].tags, vec!["customer"]);
+    }
+
+    #[test]
+    fn loads_session_content() {
+        let dir = fixture();
+        let session = Vault::new(dir.path()).session("s1").unwrap();
+
+        assert_eq!(session.memo, "Pricing pushback.");
+        assert_eq!(session.notes.len(), 1);
+        assert_eq!(session.notes[0].title.as_deref(), Some("Summary"));
+        assert_eq!(session.participants[0].emails, vec!["alice@acme.com"]);
+        assert_eq!(session.transcript[0].speaker, "Alice Smith");
+        assert_eq!(session.transcript[0].text, "The price is too high.");
+
+        assert!(matches!(
+            Vault::new(dir.path()).session("missing"),
+            Err(Error::SessionNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let dir = fixture();
+        let session = Vault::new(dir.path()).session("s1").unwrap();
+
+        assert_eq!(
+            session.to_markdown(),
+            "# Acme renewal\n\n\
+             - Date: 2024-03-05T10:00:00Z\n\
+             - Participants: Alice Smith <alice@acme.com>\n\
+             - Tags: customer\n\n\
+             ## Notes\n\nPricing pushback.\n\n\
+             ## Summary\n\n- Wants a discount\n\n\
+             ## Transcript\n\n\
+             **Alice Smith** `00:00` The price is too high.\n"
+        );
+    }
+
+    #[test]
+    fn appends_to_memo() {
+        let dir = fixture();
+        let vault = Vault::new(dir.path());
+
+        vault.append_to_memo("s1", "Follow up on Friday.").unwrap();
+        vault.append_to_memo("s2", "First line").unwrap();
+
+        assert_eq!(
+            vault.session("s1").unwrap().memo,
+            "Pricing pushback.\n\nFollow up on Friday."
+        );
+
+        let created =
+            std::fs::read_to_string(dir.path().join("sessions/Work/s2/_memo.md")).unwrap();
+        let doc = Document::<Mapping>::from_str(&created).unwrap();
+        assert_eq!(
+            doc.frontmatter.get("session_id").and_then(Value::as_str),
+            Some("s2")
+        );
+        assert_eq!(doc.content, "First line\n");
+    }
+}
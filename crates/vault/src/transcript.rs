@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use hypr_session_content::{Speaker, SpeakerHint, Transcript};
+use serde::Serialize;
+
+use crate::Human;
+
+/// Consecutive words from the same speaker.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Utterance {
+    pub speaker: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub text: String,
+}
+
+/// Labels speakers the way the app does: assigned participants by name,
+/// the microphone channel as "You", then diarized indices and channels.
+pub(crate) fn utterances(
+    mut transcript: Transcript,
+    humans: &HashMap<String, Human>,
+) -> Vec<Utterance> {
+    transcript.transcripts.sort_by(|a, b| {
+        a.started_at
+            .unwrap_or(0.0)
+            .total_cmp(&b.started_at.unwrap_or(0.0))
+    });
+
+    let mut utterances: Vec<Utterance> = Vec::new();
+
+    for entry in transcript.transcripts {
+        let speakers = speaker_by_word(&entry.speaker_hints);
+
+        for word in entry.words {
+            let speaker = word.id.as_ref().and_then(|id| speakers.get(id.as_str()));
+            let label = speaker_label(speaker, word.channel, humans);
+            let text = word.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            match utterances.last_mut() {
+                Some(last) if last.speaker == label => {
+                    last.text.push(' ');
+                    last.text.push_str(text);
+                    last.end_ms = word.end_ms as i64;
+                }
+                _ => utterances.push(Utterance {
+                    speaker: label,
+                    start_ms: word.start_ms as i64,
+                    end_ms: word.end_ms as i64,
+                    text: text.to_string(),
+                }),
+            }
+        }
+    }
+
+    utterances
+}
+
+fn speaker_by_word(hints: &[SpeakerHint]) -> HashMap<&str, Speaker> {
+    let mut speakers = HashMap::new();
+
+    for hint in hints {
+        if let Some(speaker) = hint.speaker() {
+            // A user assignment always wins over the provider's guess.
+            let existing = speakers.get(hint.word_id.as_str());
+            if !matches!(existing, Some(Speaker::Human(_))) {
+                speakers.insert(hint.word_id.as_str(), speaker);
+            }
+        }
+    }
+
+    speakers
+}
+
+fn speaker_label(
+    speaker: Option<&Speaker>,
+    channel: i64,
+    humans: &HashMap<String, Human>,
+) -> String {
+    if let Some(Speaker::Human(id)) = speaker {
+        return humans
+            .get(id)
+            .map(|human| human.name.clone())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| id.clone());
+    }
+
+    if channel == 0 {
+        return "You".to_string();
+    }
+
+    match speaker {
+        Some(Speaker::Index(index)) => format!("Speaker {}", index + 1),
+        _ if channel == 1 => "Speaker B".to_string(),
+        _ => "Speaker C".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_words_by_speaker() {
+        let transcript: Transcript = serde_json::from_value(serde_json::json!({
+            "transcripts": [{
+                "id": "t1",
+                "session_id": "s1",
+                "started_at": 0,
+                "words": [
+                    { "id": "w1", "text": " Hello", "start_ms": 0, "end_ms": 400, "channel": 0 },
+                    { "id": "w2", "text": " there.", "start_ms": 400, "end_ms": 800, "channel": 0 },
+                    { "id": "w3", "text": " Hi", "start_ms": 900, "end_ms": 1200, "channel": 1 },
+                    { "id": "w4", "text": " all.", "start_ms": 1200, "end_ms": 1500, "channel": 1 },
+                    { "id": "w5", "text": " Thanks", "start_ms": 1600, "end_ms": 2000, "channel": 1 }
+                ],
+                "speaker_hints": [
+                    { "id": "h1", "word_id": "w3", "type": "user_speaker_assignment", "value": "{\"human_id\":\"alice\"}" },
+                    { "id": "h2", "word_id": "w4", "type": "user_speaker_assignment", "value": { "human_id": "alice" } },
+                    { "id": "h3", "word_id": "w5", "type": "provider_speaker_index", "value": { "speaker_index": 1 } }
+                ]
+            }]
+        }))
+        .unwrap();
+
+        let humans = HashMap::from([(
+            "alice".to_string(),
+            Human {
+                id: "alice".to_string(),
+                name: "Alice".to_string(),
+                emails: vec![],
+                job_title: None,
+                org_id: None,
+            },
+        )]);
+
+        let utterances = utterances(transcript, &humans);
+        let lines: Vec<_> = utterances
+            .iter()
+            .map(|u| (u.speaker.as_str(), u.text.as_str(), u.start_ms, u.end_ms))
+            .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                ("You", "Hello there.", 0, 800),
+                ("Alice", "Hi all.", 900, 1500),
+                ("Speaker 2", "Thanks", 1600, 2000),
+            ]
+        );
+    }
+}
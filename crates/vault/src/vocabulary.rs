@@ -0,0 +1,147 @@
+use std::str::FromStr;
+
+use hypr_frontmatter::Document;
+use hypr_session_content::{SESSION_TRANSCRIPT_FILE, Transcript, read_session_meta};
+use serde::{Deserialize, Serialize};
+
+use crate::session::{event_title, participants};
+use crate::{Result, Vault};
+
+const ORGANIZATIONS_DIR: &str = "organizations";
+
+/// Raw material for a session's speech-to-text vocabulary.
+///
+/// Ranking and truncation happen downstream; this only gathers terms.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SessionVocabularyContext {
+    pub event_title: Option<String>,
+    pub participants: Vec<String>,
+    pub organizations: Vec<String>,
+    pub past_transcripts: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct OrganizationFrontmatter {
+    name: Option<String>,
+}
+
+impl Vault {
+    /// Gathers vocabulary sources for `session_id`: the calendar event and
+    /// participants (with their organizations) from its `_meta.json`, and
+    /// the transcripts of up to `past_sessions` other sessions, newest first.
+    /// A session the app hasn't written yet only yields past transcripts.
+    pub fn session_vocabulary_context(
+        &self,
+        session_id: &str,
+        past_sessions: usize,
+    ) -> Result<SessionVocabularyContext> {
+        let humans = self.humans_by_id()?;
+
+        let mut sessions: Vec<_> = self
+            .session_dirs()?
+            .into_iter()
+            .filter_map(|(id, dir)| {
+                let meta = read_session_meta(&dir).ok()?;
+                Some((id, dir, meta))
+            })
+            .collect();
+        sessions.sort_by(|a, b| b.2.created_at.cmp(&a.2.created_at));
+
+        let mut context = SessionVocabularyContext::default();
+
+        if let Some((_, _, meta)) = sessions.iter().find(|(id, ..)| id == session_id) {
+            context.event_title = event_title(meta).filter(|title| !title.trim().is_empty());
+
+            for human in participants(meta, &humans) {
+                if let Some(organization) = human
+                    .org_id
+                    .as_deref()
+                    .and_then(|id| self.organization_name(id))
+                {
+                    push_unique(&mut context.organizations, organization);
+                }
+                push_unique(&mut context.participants, human.name);
+            }
+        }
+
+        context.past_transcripts = sessions
+            .iter()
+            .filter(|(id, ..)| id != session_id)
+            .filter_map(|(_, dir, _)| {
+                let content = std::fs::read_to_string(dir.join(SESSION_TRANSCRIPT_FILE)).ok()?;
+                let transcript: Transcript = serde_json::from_str(&content).ok()?;
+                let text = transcript_text(&transcript);
+                (!text.is_empty()).then_some(text)
+            })
+            .take(past_sessions)
+            .collect();
+
+        Ok(context)
+    }
+
+    fn organization_name(&self, organization_id: &str) -> Option<String> {
+        let path = self
+            .root
+            .join(ORGANIZATIONS_DIR)
+            .join(format!("{organization_id}.md"));
+        let content = std::fs::read_to_string(path).ok()?;
+        let doc = Document::<OrganizationFrontmatter>::from_str(&content).ok()?;
+        doc.frontmatter.name
+    }
+}
+
+fn push_unique(terms: &mut Vec<String>, term: String) {
+    let term = term.trim();
+    if !term.is_empty() && !terms.iter().any(|existing| existing == term) {
+        terms.push(term.to_string());
+    }
+}
+
+fn transcript_text(transcript: &Transcript) -> String {
+    transcript
+        .transcripts
+        .iter()
+        .flat_map(|entry| &entry.words)
+        .map(|word| word.text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::tests::{fixture, write};
+
+    #[test]
+    fn gathers_session_vocabulary() {
+        let dir = fixture();
+        let root = dir.path();
+        write(
+            root,
+            "humans/alice.md",
+            "---\nname: Alice Smith\norg_id: acme\n---\n",
+        );
+        write(root, "organizations/acme.md", "---\nname: Acme\n---\n");
+        write(
+            root,
+            "sessions/s3/_meta.json",
+            r#"{"id":"s3","user_id":"u","created_at":"2024-03-08T09:00:00Z","title":"","event":{"title":"Acme sync"},"participants":[{"id":"p","user_id":"u","session_id":"s3","human_id":"alice","source":"manual"}]}"#,
+        );
+
+        let context = Vault::new(root)
+            .session_vocabulary_context("s3", 5)
+            .unwrap();
+
+        assert_eq!(context.event_title.as_deref(), Some("Acme sync"));
+        assert_eq!(context.participants, vec!["Alice Smith"]);
+        assert_eq!(context.organizations, vec!["Acme"]);
+        assert_eq!(context.past_transcripts, vec!["The price is too high."]);
+
+        let unsaved = Vault::new(root)
+            .session_vocabulary_context("new", 0)
+            .unwrap();
+        assert_eq!(unsaved, SessionVocabularyContext::default());
+    }
+}
@@ -4,13 +4,13 @@ use lazy_static::lazy_static;
 use regex::Regex;
 
 use whisper_rs::{
-    FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperState,
-    WhisperTokenId,
+    FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters, WhisperSegment,
+    WhisperState, WhisperTokenId,
 };
 
 use hypr_whisper::Language;
 
-use crate::Segment;
+use crate::{Segment, Word};
 
 lazy_static! {
     static ref TRAILING_DOTS: Regex = Regex::new(r"\.{2,}$").unwrap();
@@ -20,6 +20,7 @@ lazy_static! {
 pub struct WhisperBuilder {
     model_path: Option<String>,
     languages: Option<Vec<Language>>,
+    token_timestamps: bool,
 }
 
 impl WhisperBuilder {
@@ -33,6 +34,13 @@ impl WhisperBuilder {
         self
     }
 
+    /// Time every word of a segment, at some cost in speed. Needed to tell
+    /// apart speakers within a segment.
+    pub fn token_timestamps(mut self, enabled: bool) -> Self {
+        self.token_timestamps = enabled;
+        self
+    }
+
     pub fn build(self) -> Result<Whisper, crate::Error> {
         unsafe { Self::suppress_log() };
 
@@ -55,6 +63,7 @@ impl WhisperBuilder {
         let ctx = WhisperContext::new_with_params(&model_path, context_param)?;
         let state = ctx.create_state()?;
         let token_beg = ctx.token_beg();
+        let token_eot = ctx.token_eot();
 
         Ok(Whisper {
             id: uuid::Uuid::new_v4().to_string(),
@@ -63,6 +72,8 @@ impl WhisperBuilder {
             dynamic_prompt: "".to_string(),
             state,
             token_beg,
+            token_eot,
+            token_timestamps: self.token_timestamps,
         })
     }
 
@@ -86,6 +97,8 @@ pub struct Whisper {
     dynamic_prompt: String,
     state: WhisperState,
     token_beg: WhisperTokenId,
+    token_eot: WhisperTokenId,
+    token_timestamps: bool,
 }
 
 impl Whisper {
@@ -126,7 +139,7 @@ impl Whisper {
             }
 
             p.set_no_timestamps(true);
-            p.set_token_timestamps(false);
+            p.set_token_timestamps(self.token_timestamps);
             p.set_split_on_word(true);
 
             p.set_temperature(0.0);
@@ -171,6 +184,11 @@ impl Whisper {
                 // https://github.com/ggml-org/whisper.cpp/pull/971/files#diff-2d3599a9fad195f2c3c60bd06691bc1815325b3560b5feda41a91fa71194e805R310-R327
                 // We previously implemented it based on above, but after updating to v1.7.6, the API has changed, and we're still unable to figure it out. We're not using it anyway.
                 confidence: 1.0,
+                words: if self.token_timestamps {
+                    self.words(&segment)?
+                } else {
+                    vec![]
+                },
                 ..Default::default()
             });
         }
@@ -191,6 +209,40 @@ impl Whisper {
         Ok(segments)
     }
 
+    /// Joins the segment's text tokens into words: with `split_on_word`, a
+    /// token starting with a space begins a new word.
+    fn words(&self, segment: &WhisperSegment) -> Result<Vec<Word>, crate::Error> {
+        let mut words: Vec<Word> = Vec::new();
+
+        for i in 0..segment.n_tokens() {
+            let Some(token) = segment.get_token(i) else {
+                continue;
+            };
+            if token.token_id() >= self.token_eot {
+                continue;
+            }
+
+            let data = token.token_data();
+            let (start, end) = ((data.t0 as f64) / 100.0, (data.t1 as f64) / 100.0);
+            let text = token.to_str_lossy()?;
+
+            match words.last_mut() {
+                Some(word) if !text.starts_with(char::is_whitespace) => {
+                    word.text.push_str(&text);
+                    word.end = end;
+                }
+                _ if text.trim().is_empty() => {}
+                _ => words.push(Word {
+                    text: text.into_owned(),
+                    start,
+                    end,
+                }),
+            }
+        }
+
+        Ok(words)
+    }
+
     fn get_language(&mut self, audio: &[f32]) -> Result<Option<String>, crate::Error> {
         if self.languages.is_empty() {
             tracing::info!("no_language_specified");
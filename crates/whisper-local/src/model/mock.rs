@@ -1,4 +1,4 @@
-use crate::Segment;
+use crate::{Segment, Word};
 use hypr_whisper::Language;
 
 #[derive(Default)]
@@ -16,6 +16,10 @@ impl WhisperBuilder {
         self
     }
 
+    pub fn token_timestamps(self, _enabled: bool) -> Self {
+        self
+    }
+
     pub fn build(self) -> Result<Whisper, crate::Error> {
         Ok(Whisper {})
     }
@@ -34,6 +38,11 @@ impl Whisper {
             end: 1.0,
             confidence: 1.0,
             meta: None,
+            words: vec![Word {
+                text: "mock".to_string(),
+                start: 0.0,
+                end: 1.0,
+            }],
         }])
     }
 }
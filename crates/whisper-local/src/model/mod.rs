@@ -16,6 +16,16 @@ pub struct Segment {
     pub end: f64,
     pub confidence: f32,
     pub meta: Option<serde_json::Value>,
+    /// Word timings within the segment, only filled when the model was built
+    /// with token timestamps.
+    pub words: Vec<Word>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Word {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
 }
 
 impl Segment {
@@ -46,4 +56,8 @@ impl Segment {
     pub fn meta(&self) -> Option<serde_json::Value> {
         self.meta.clone()
     }
+
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
 }
@@ -275,6 +275,10 @@ export const generalSchema = z.object({
   current_stt_model: z.string().optional(),
   timezone: z.string().optional(),
   week_start: z.string().optional(),
+  pdf_company_name: z.string().optional(),
+  pdf_accent_color: z.string().optional(),
+  semantic_search: z.boolean().default(false),
+  transcript_vocabulary: jsonObject(z.array(z.string()).default([])),
 });
 
 export const aiProviderSchema = z
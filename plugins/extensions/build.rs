@@ -5,6 +5,7 @@ const COMMANDS: &[&str] = &[
     "list_extensions",
     "get_extensions_dir",
     "get_extension",
+    "emit_event",
 ];
 
 fn main() {
@@ -53,6 +53,14 @@ async getExtension(extensionId: string) : Promise<Result<ExtensionInfo, Error>>
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async emitEvent(event: ExtensionEvent) : Promise<Result<null, Error>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:extensions|emit_event", { event }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -66,8 +74,10 @@ async getExtension(extensionId: string) : Promise<Result<ExtensionInfo, Error>>
 
 /** user-defined types **/
 
-export type Error = { ExtensionNotFound: string } | { RuntimeError: string } | { InvalidManifest: string } | { Io: string } | "RuntimeUnavailable"
+export type Error = { ExtensionNotFound: string } | { RuntimeError: string } | { InvalidManifest: string } | { Io: string } | "RuntimeUnavailable" | { PermissionDenied: string }
+export type ExtensionEvent = { type: "transcriptDelta"; sessionId: string; delta: JsonValue } | { type: "sessionStopped"; sessionId: string }
 export type ExtensionInfo = { id: string; name: string; version: string; api_version: string; description: string | null; path: string; panels: PanelInfo[] }
+export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type PanelInfo = { id: string; title: string; entry: string; entry_path: string | null; styles_path: string | null }
 
 /** tauri-specta globals **/
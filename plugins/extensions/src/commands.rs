@@ -2,7 +2,7 @@ use std::path::PathBuf;
 
 use tauri_plugin_settings::SettingsPluginExt;
 
-use crate::{Error, ExtensionInfo, ExtensionsPluginExt, PanelInfo};
+use crate::{Error, ExtensionEvent, ExtensionInfo, ExtensionsPluginExt, PanelInfo};
 
 #[tauri::command]
 #[specta::specta]
@@ -146,3 +146,12 @@ pub async fn get_extension<R: tauri::Runtime>(
         })
         .ok_or(Error::ExtensionNotFound(extension_id))
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn emit_event<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    event: ExtensionEvent,
+) -> Result<(), Error> {
+    app.extensions().emit_event(event).await
+}
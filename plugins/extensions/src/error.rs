@@ -12,6 +12,8 @@ pub enum Error {
     Io(String),
     #[error("Runtime unavailable: V8 engine failed to initialize")]
     RuntimeUnavailable,
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
 }
 
 impl From<hypr_extensions_runtime::Error> for Error {
@@ -29,6 +31,9 @@ impl From<hypr_extensions_runtime::Error> for Error {
                 Error::RuntimeError("Channel receive error".to_string())
             }
             hypr_extensions_runtime::Error::RuntimeUnavailable => Error::RuntimeUnavailable,
+            e @ hypr_extensions_runtime::Error::PermissionDenied { .. } => {
+                Error::PermissionDenied(e.to_string())
+            }
         }
     }
 }
@@ -58,6 +58,17 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Extensions<'a, R, M> {
 
         serde_json::to_string(&result).map_err(|e| crate::Error::RuntimeError(e.to_string()))
     }
+
+    pub async fn emit_event(&self, event: crate::ExtensionEvent) -> Result<(), crate::Error> {
+        let runtime = {
+            let state = self.manager.state::<ManagedState>();
+            let guard = state.lock().await;
+            guard.runtime.clone()
+        };
+
+        runtime.emit(event.into()).await?;
+        Ok(())
+    }
 }
 
 pub trait ExtensionsPluginExt<R: tauri::Runtime> {
@@ -0,0 +1,131 @@
+use hypr_extensions_runtime::{Error, HostData, HostFuture, Result};
+use hypr_vault::{Human, Session, SessionSummary, Utterance, Vault};
+use serde_json::{Value, json};
+use tauri_plugin_settings::SettingsPluginExt;
+
+/// `hypr.db` backed by the vault the app writes sessions and contacts to.
+pub struct VaultHostData<R: tauri::Runtime> {
+    app: tauri::AppHandle<R>,
+}
+
+impl<R: tauri::Runtime> VaultHostData<R> {
+    pub fn new(app: tauri::AppHandle<R>) -> Self {
+        Self { app }
+    }
+
+    // The vault is resolved on every call since the user can move it, and
+    // read on the blocking pool so file access never stalls the extensions
+    // thread.
+    async fn read<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(Vault) -> hypr_vault::Result<T> + Send + 'static,
+    {
+        let base = self
+            .app
+            .settings()
+            .fresh_vault_base()
+            .map_err(|e| Error::RuntimeError(e.to_string()))?;
+
+        tauri::async_runtime::spawn_blocking(move || f(Vault::new(base)))
+            .await
+            .map_err(|e| Error::RuntimeError(e.to_string()))?
+            .map_err(|e| Error::RuntimeError(e.to_string()))
+    }
+}
+
+/// Drops `SessionNotFound` so a missing session reads as `null`.
+fn found<T>(result: hypr_vault::Result<T>) -> hypr_vault::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(hypr_vault::Error::SessionNotFound(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn summary_value(session: SessionSummary) -> Value {
+    json!({
+        "id": session.id,
+        "title": session.title,
+        "createdAt": session.created_at,
+        "eventTitle": session.event_title,
+        "participants": session.participants,
+        "tags": session.tags,
+    })
+}
+
+/// Session metadata and notes; the transcript is only exposed through the
+/// `transcripts` scope.
+fn session_value(session: Session) -> Value {
+    json!({
+        "id": session.id,
+        "title": session.title,
+        "createdAt": session.created_at,
+        "event": session.event,
+        "participants": session.participants.into_iter().map(human_value).collect::<Vec<_>>(),
+        "tags": session.tags,
+        "memo": session.memo,
+        "notes": session
+            .notes
+            .into_iter()
+            .map(|note| json!({ "title": note.title, "content": note.content }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn transcript_value(session_id: String, transcript: Vec<Utterance>) -> Value {
+    json!({
+        "sessionId": session_id,
+        "utterances": transcript
+            .into_iter()
+            .map(|utterance| json!({
+                "speaker": utterance.speaker,
+                "text": utterance.text,
+                "startMs": utterance.start_ms,
+                "endMs": utterance.end_ms,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn human_value(human: Human) -> Value {
+    json!({
+        "id": human.id,
+        "name": human.name,
+        "emails": human.emails,
+        "jobTitle": human.job_title,
+        "orgId": human.org_id,
+    })
+}
+
+impl<R: tauri::Runtime> HostData for VaultHostData<R> {
+    fn list_sessions(&self) -> HostFuture<'_, Vec<Value>> {
+        Box::pin(async move {
+            let sessions = self.read(|vault| vault.list_sessions()).await?;
+            Ok(sessions.into_iter().map(summary_value).collect())
+        })
+    }
+
+    fn get_session<'a>(&'a self, session_id: &'a str) -> HostFuture<'a, Option<Value>> {
+        let id = session_id.to_string();
+        Box::pin(async move {
+            let session = self.read(move |vault| found(vault.session(&id))).await?;
+            Ok(session.map(session_value))
+        })
+    }
+
+    fn get_transcript<'a>(&'a self, session_id: &'a str) -> HostFuture<'a, Option<Value>> {
+        let id = session_id.to_string();
+        Box::pin(async move {
+            let session = self.read(move |vault| found(vault.session(&id))).await?;
+            Ok(session.map(|session| transcript_value(session.id, session.transcript)))
+        })
+    }
+
+    fn list_humans(&self) -> HostFuture<'_, Vec<Value>> {
+        Box::pin(async move {
+            let humans = self.read(|vault| vault.humans()).await?;
+            Ok(humans.into_iter().map(human_value).collect())
+        })
+    }
+}
@@ -1,9 +1,11 @@
 mod commands;
 mod error;
 mod ext;
+mod host_data;
 
 pub use error::*;
 pub use ext::*;
+pub use host_data::*;
 
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -32,6 +34,30 @@ pub struct PanelInfo {
     pub styles_path: Option<String>,
 }
 
+/// Host events forwarded to extensions subscribed through `hypr.events.on`.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ExtensionEvent {
+    #[serde(rename_all = "camelCase")]
+    TranscriptDelta {
+        session_id: String,
+        delta: serde_json::Value,
+    },
+    #[serde(rename_all = "camelCase")]
+    SessionStopped { session_id: String },
+}
+
+impl From<ExtensionEvent> for hypr_extensions_runtime::HostEvent {
+    fn from(event: ExtensionEvent) -> Self {
+        match event {
+            ExtensionEvent::TranscriptDelta { session_id, delta } => {
+                Self::TranscriptDelta { session_id, delta }
+            }
+            ExtensionEvent::SessionStopped { session_id } => Self::SessionStopped { session_id },
+        }
+    }
+}
+
 pub struct State {
     pub runtime: hypr_extensions_runtime::ExtensionsRuntime,
 }
@@ -48,6 +74,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_extensions::<tauri::Wry>,
             commands::get_extensions_dir::<tauri::Wry>,
             commands::get_extension::<tauri::Wry>,
+            commands::emit_event::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -59,7 +86,9 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
         .invoke_handler(specta_builder.invoke_handler())
         .setup(|app, _api| {
             let state = State {
-                runtime: hypr_extensions_runtime::ExtensionsRuntime::new(),
+                runtime: hypr_extensions_runtime::ExtensionsRuntime::with_host_data(Arc::new(
+                    VaultHostData::new(app.clone()),
+                )),
             };
             app.manage(Arc::new(Mutex::new(state)));
             Ok(())
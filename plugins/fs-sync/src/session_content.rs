@@ -1,110 +1,101 @@
-use std::str::FromStr;
+use hypr_session_content::{Speaker, SpeakerHint, Transcript};
 
-use crate::frontmatter::ParsedDocument;
-use crate::types::{SessionContentData, SessionMetaData, SessionNoteData, TranscriptData};
-
-const SESSION_META_FILE: &str = "_meta.json";
-const SESSION_MEMO_FILE: &str = "_memo.md";
-const SESSION_TRANSCRIPT_FILE: &str = "transcript.json";
+use crate::types::{
+    SessionContentData, SessionMetaData, SessionMetaParticipant, SessionNoteData, TranscriptData,
+    TranscriptEntry, TranscriptSpeakerHint, TranscriptWord,
+};
 
 pub fn load_session_content(session_id: &str, session_dir: &std::path::Path) -> SessionContentData {
-    let mut content = SessionContentData {
-        session_id: session_id.to_string(),
-        meta: None,
-        raw_memo_tiptap_json: None,
-        transcript: None,
-        notes: vec![],
-    };
-
-    let entries = match std::fs::read_dir(session_dir) {
-        Ok(entries) => entries,
-        Err(_) => return content,
-    };
-
-    for entry in entries.flatten() {
-        let path = entry.path();
-        if !path.is_file() {
-            continue;
-        }
-
-        let name = match path.file_name().and_then(|v| v.to_str()) {
-            Some(name) => name,
-            None => continue,
-        };
-
-        let file_content = match std::fs::read_to_string(&path) {
-            Ok(value) => value,
-            Err(_) => continue,
-        };
-
-        if name == SESSION_META_FILE {
-            if let Ok(meta) = serde_json::from_str::<SessionMetaData>(&file_content) {
-                content.meta = Some(meta);
-            }
-            continue;
-        }
-
-        if name == SESSION_TRANSCRIPT_FILE {
-            if let Ok(transcript) = serde_json::from_str::<TranscriptData>(&file_content) {
-                content.transcript = Some(transcript);
-            }
-            continue;
-        }
-
-        if !name.ends_with(".md") {
-            continue;
-        }
-
-        let parsed = match ParsedDocument::from_str(&file_content) {
-            Ok(parsed) => parsed,
-            Err(_) => continue,
-        };
-
-        let tiptap_json = match hypr_tiptap::md_to_tiptap_json(&parsed.content) {
-            Ok(value) => value,
-            Err(_) => continue,
-        };
-
-        let frontmatter = parsed.frontmatter;
-        let id = frontmatter
-            .get("id")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-        let frontmatter_session_id = frontmatter
-            .get("session_id")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-
-        if frontmatter_session_id != session_id {
-            continue;
-        }
-
-        if name == SESSION_MEMO_FILE {
-            content.raw_memo_tiptap_json = Some(tiptap_json);
-            continue;
-        }
-
-        if id.is_empty() {
-            continue;
-        }
+    let content = hypr_session_content::load_session_content(session_id, session_dir);
+
+    SessionContentData {
+        session_id: content.session_id,
+        meta: content.meta.map(|meta| SessionMetaData {
+            id: meta.id,
+            user_id: meta.user_id,
+            created_at: meta.created_at,
+            title: meta.title,
+            event: meta.event,
+            event_id: meta.event_id,
+            participants: meta
+                .participants
+                .into_iter()
+                .map(|p| SessionMetaParticipant {
+                    id: p.id,
+                    user_id: p.user_id,
+                    session_id: p.session_id,
+                    human_id: p.human_id,
+                    source: p.source,
+                })
+                .collect(),
+            tags: meta.tags,
+        }),
+        raw_memo_tiptap_json: content
+            .memo
+            .and_then(|memo| hypr_tiptap::md_to_tiptap_json(&memo).ok()),
+        transcript: content.transcript.map(transcript_data),
+        notes: content
+            .notes
+            .into_iter()
+            .filter(|note| !note.id.is_empty())
+            .filter_map(|note| {
+                Some(SessionNoteData {
+                    tiptap_json: hypr_tiptap::md_to_tiptap_json(&note.content).ok()?,
+                    id: note.id,
+                    session_id: session_id.to_string(),
+                    template_id: note.template_id,
+                    position: note.position,
+                    title: note.title,
+                })
+            })
+            .collect(),
+    }
+}
 
-        content.notes.push(SessionNoteData {
-            id,
-            session_id: frontmatter_session_id,
-            template_id: frontmatter
-                .get("template_id")
-                .and_then(|v| v.as_str())
-                .map(|v| v.to_string()),
-            position: frontmatter.get("position").and_then(|v| v.as_i64()),
-            title: frontmatter
-                .get("title")
-                .and_then(|v| v.as_str())
-                .map(|v| v.to_string()),
-            tiptap_json,
-        });
+fn transcript_data(transcript: Transcript) -> TranscriptData {
+    TranscriptData {
+        transcripts: transcript
+            .transcripts
+            .into_iter()
+            .map(|entry| TranscriptEntry {
+                speaker_hints: entry
+                    .speaker_hints
+                    .iter()
+                    .filter_map(speaker_hint)
+                    .collect(),
+                id: entry.id,
+                user_id: entry.user_id,
+                created_at: entry.created_at,
+                session_id: entry.session_id,
+                started_at: entry.started_at.map(|v| v as i64),
+                ended_at: entry.ended_at.map(|v| v as i64),
+                words: entry
+                    .words
+                    .into_iter()
+                    .map(|word| TranscriptWord {
+                        id: word.id,
+                        text: word.text,
+                        start_ms: word.start_ms as i64,
+                        end_ms: word.end_ms as i64,
+                        channel: word.channel,
+                    })
+                    .collect(),
+            })
+            .collect(),
     }
+}
+
+/// Word-level hints on disk become single-word ranges.
+fn speaker_hint(hint: &SpeakerHint) -> Option<TranscriptSpeakerHint> {
+    let speaker_id = match hint.speaker()? {
+        Speaker::Human(human_id) => human_id,
+        Speaker::Index(index) => index.to_string(),
+    };
 
-    content
+    Some(TranscriptSpeakerHint {
+        id: hint.id.clone(),
+        speaker_id: Some(speaker_id),
+        start_word_id: hint.word_id.clone(),
+        end_word_id: hint.word_id.clone(),
+    })
 }
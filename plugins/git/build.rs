@@ -14,6 +14,10 @@ const COMMANDS: &[&str] = &[
     "check_conflicts",
     "abort_merge",
     "get_current_branch",
+    "sync",
+    "start_auto_sync",
+    "stop_auto_sync",
+    "get_auto_sync_status",
 ];
 
 fn main() {
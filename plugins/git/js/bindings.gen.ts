@@ -125,6 +125,38 @@ async getCurrentBranch(path: string) : Promise<Result<string, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async sync(path: string, options: SyncOptions) : Promise<Result<SyncResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:git|sync", { path, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async startAutoSync(config: AutoSyncConfig) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:git|start_auto_sync", { config }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async stopAutoSync() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:git|stop_auto_sync") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAutoSyncStatus() : Promise<Result<AutoSyncStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:git|get_auto_sync_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -138,14 +170,22 @@ async getCurrentBranch(path: string) : Promise<Result<string, string>> {
 
 /** user-defined types **/
 
+export type AutoSyncConfig = { path: string; interval_secs: number; options?: SyncOptions }
+export type AutoSyncStatus = { config: AutoSyncConfig | null; last_result: SyncResult | null; last_error: string | null }
 export type CommitInfo = { id: string; message: string; author: string; timestamp: number }
 export type ConflictInfo = { files: string[] }
 export type FileChangeType = "Added" | "Modified" | "Deleted" | "Renamed" | "Copied"
 export type FileStatus = { path: string; status: FileChangeType }
-export type PullResult = { Success: { commits_pulled: number } } | "AlreadyUpToDate" | { Conflicts: { files: string[] } }
+export type PullResult = { Success: { commits_pulled: number } } | { Merged: { commits_pulled: number; files: string[] } } | "AlreadyUpToDate" | { Conflicts: { files: string[] } }
 export type PushResult = { Success: { commits_pushed: number } } | "AlreadyUpToDate" | { Rejected: { reason: string } }
 export type RemoteInfo = { name: string; url: string }
 export type StatusInfo = { staged: FileStatus[]; unstaged: FileStatus[]; untracked: string[]; conflicted: string[]; has_changes: boolean }
+/**
+ * Without a remote, sync only commits. `branch` defaults to the current
+ * branch.
+ */
+export type SyncOptions = { remote_name: string | null; branch: string | null }
+export type SyncResult = { commit_id: string | null; pull: PullResult | null; push: PushResult | null; finished_at: number }
 
 /** tauri-specta globals **/
 
@@ -1,7 +1,10 @@
 use std::path::PathBuf;
 
 use crate::GitPluginExt;
-use crate::types::{CommitInfo, ConflictInfo, PullResult, PushResult, RemoteInfo, StatusInfo};
+use crate::types::{
+    AutoSyncConfig, AutoSyncStatus, CommitInfo, ConflictInfo, PullResult, PushResult, RemoteInfo,
+    StatusInfo, SyncOptions, SyncResult,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -158,3 +161,41 @@ pub(crate) async fn get_current_branch<R: tauri::Runtime>(
         .get_current_branch(&path)
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn sync<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: PathBuf,
+    options: SyncOptions,
+) -> Result<SyncResult, String> {
+    app.git().sync(&path, &options).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn start_auto_sync<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: AutoSyncConfig,
+) -> Result<(), String> {
+    app.git()
+        .start_auto_sync(config)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn stop_auto_sync<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.git().stop_auto_sync().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_auto_sync_status<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<AutoSyncStatus, String> {
+    Ok(app.git().auto_sync_status().await)
+}
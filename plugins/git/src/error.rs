@@ -12,6 +12,8 @@ pub enum Error {
     GixInit(Box<gix::init::Error>),
     #[error(transparent)]
     GixDiscover(Box<gix::discover::Error>),
+    #[error(transparent)]
+    Store2(#[from] tauri_plugin_store2::Error),
     #[error("{0}")]
     Custom(String),
 }
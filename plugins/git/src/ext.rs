@@ -1,10 +1,16 @@
 use std::path::Path;
 
-use crate::operations::{local, merge, remote};
-use crate::types::{CommitInfo, ConflictInfo, PullResult, PushResult, RemoteInfo, StatusInfo};
+use tauri::Manager;
+use tauri_plugin_store2::Store2PluginExt;
+
+use crate::operations::{local, merge, remote, sync};
+use crate::types::{
+    AutoSyncConfig, AutoSyncStatus, CommitInfo, ConflictInfo, PullResult, PushResult, RemoteInfo,
+    StatusInfo, SyncOptions, SyncResult,
+};
 
 pub struct Git<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
-    _manager: &'a M,
+    manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
 
@@ -78,6 +84,98 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Git<'a, R, M> {
     pub fn abort_merge(&self, path: &Path) -> Result<(), crate::Error> {
         merge::abort_merge(path)
     }
+
+    pub fn sync(&self, path: &Path, options: &SyncOptions) -> Result<SyncResult, crate::Error> {
+        sync::sync(path, options)
+    }
+
+    /// Replaces any running schedule and keeps it across restarts. The first
+    /// sync runs one interval after starting.
+    pub async fn start_auto_sync(&self, config: AutoSyncConfig) -> Result<(), crate::Error> {
+        if config.interval_secs == 0 {
+            return Err(crate::Error::Custom(
+                "Auto-sync interval must be greater than zero".to_string(),
+            ));
+        }
+
+        let store = self.manager.store2().scoped_store(crate::PLUGIN_NAME)?;
+        store.set(crate::StoreKey::AutoSync, &config)?;
+
+        self.schedule_auto_sync(config).await;
+        Ok(())
+    }
+
+    pub async fn stop_auto_sync(&self) -> Result<(), crate::Error> {
+        let store = self.manager.store2().scoped_store(crate::PLUGIN_NAME)?;
+        store.delete(crate::StoreKey::AutoSync)?;
+
+        self.cancel_auto_sync().await;
+        Ok(())
+    }
+
+    /// Restarts the schedule saved by `start_auto_sync`, if any.
+    pub async fn resume_auto_sync(&self) -> Result<(), crate::Error> {
+        let store = self.manager.store2().scoped_store(crate::PLUGIN_NAME)?;
+        if let Some(config) = store.get::<AutoSyncConfig>(crate::StoreKey::AutoSync)? {
+            self.schedule_auto_sync(config).await;
+        }
+        Ok(())
+    }
+
+    async fn schedule_auto_sync(&self, config: AutoSyncConfig) {
+        self.cancel_auto_sync().await;
+
+        let app = self.manager.app_handle().clone();
+        let task_config = config.clone();
+        let handle = tokio::spawn(async move {
+            let interval = std::time::Duration::from_secs(task_config.interval_secs.into());
+            loop {
+                tokio::time::sleep(interval).await;
+
+                let path = std::path::PathBuf::from(&task_config.path);
+                let options = task_config.options.clone();
+                let result = tokio::task::spawn_blocking(move || sync::sync(&path, &options)).await;
+
+                let state = app.state::<crate::SharedState>();
+                let mut state = state.lock().await;
+                match result {
+                    Ok(Ok(result)) => {
+                        state.last_sync = Some(result);
+                        state.last_error = None;
+                    }
+                    Ok(Err(e)) => {
+                        tracing::warn!(error = %e, "auto_sync_failed");
+                        state.last_error = Some(e.to_string());
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "auto_sync_panicked");
+                        state.last_error = Some(e.to_string());
+                    }
+                }
+            }
+        });
+
+        let state = self.manager.state::<crate::SharedState>();
+        state.lock().await.auto_sync = Some((config, handle));
+    }
+
+    async fn cancel_auto_sync(&self) {
+        let state = self.manager.state::<crate::SharedState>();
+        if let Some((_, handle)) = state.lock().await.auto_sync.take() {
+            handle.abort();
+        }
+    }
+
+    pub async fn auto_sync_status(&self) -> AutoSyncStatus {
+        let state = self.manager.state::<crate::SharedState>();
+        let state = state.lock().await;
+
+        AutoSyncStatus {
+            config: state.auto_sync.as_ref().map(|(config, _)| config.clone()),
+            last_result: state.last_sync.clone(),
+            last_error: state.last_error.clone(),
+        }
+    }
 }
 
 pub trait GitPluginExt<R: tauri::Runtime> {
@@ -92,7 +190,7 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> GitPluginExt<R> for T {
         Self: Sized,
     {
         Git {
-            _manager: self,
+            manager: self,
             _runtime: std::marker::PhantomData,
         }
     }
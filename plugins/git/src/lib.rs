@@ -1,14 +1,29 @@
+use tauri::Manager;
+
 mod commands;
 mod error;
 mod ext;
+mod merge_driver;
 mod operations;
+mod store;
 pub mod types;
 
 pub use error::{Error, Result};
 pub use ext::*;
 
+pub(crate) use store::*;
+
 const PLUGIN_NAME: &str = "git";
 
+pub type SharedState = std::sync::Arc<tokio::sync::Mutex<State>>;
+
+#[derive(Default)]
+pub struct State {
+    auto_sync: Option<(types::AutoSyncConfig, tokio::task::JoinHandle<()>)>,
+    last_sync: Option<types::SyncResult>,
+    last_error: Option<String>,
+}
+
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
@@ -28,6 +43,10 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::check_conflicts::<tauri::Wry>,
             commands::abort_merge::<tauri::Wry>,
             commands::get_current_branch::<tauri::Wry>,
+            commands::sync::<tauri::Wry>,
+            commands::start_auto_sync::<tauri::Wry>,
+            commands::stop_auto_sync::<tauri::Wry>,
+            commands::get_auto_sync_status::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -37,6 +56,18 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
+        .setup(|app, _api| {
+            app.manage(SharedState::default());
+
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = handle.git().resume_auto_sync().await {
+                    tracing::warn!(error = %e, "auto_sync_resume_failed");
+                }
+            });
+
+            Ok(())
+        })
         .build()
 }
 
@@ -0,0 +1,222 @@
+use serde_json::{Map, Value};
+
+/// Three-way merge of JSON documents. Objects are merged key by key, arrays of
+/// objects by their id and arrays of scalars as sets, so additions and removals
+/// from both sides survive. When both sides change the same scalar, ours wins.
+pub fn merge(base: Option<&Value>, ours: &Value, theirs: &Value) -> Value {
+    merge_value(None, base, ours, theirs)
+}
+
+fn merge_value(field: Option<&str>, base: Option<&Value>, ours: &Value, theirs: &Value) -> Value {
+    if ours == theirs || base == Some(theirs) {
+        return ours.clone();
+    }
+    if base == Some(ours) {
+        return theirs.clone();
+    }
+
+    match (ours, theirs) {
+        (Value::Object(o), Value::Object(t)) => {
+            Value::Object(merge_objects(base.and_then(Value::as_object), o, t))
+        }
+        (Value::Array(o), Value::Array(t)) => {
+            Value::Array(merge_arrays(field, base.and_then(Value::as_array), o, t))
+        }
+        _ => ours.clone(),
+    }
+}
+
+fn merge_objects(
+    base: Option<&Map<String, Value>>,
+    ours: &Map<String, Value>,
+    theirs: &Map<String, Value>,
+) -> Map<String, Value> {
+    let mut out = Map::new();
+    let keys = ours
+        .keys()
+        .chain(theirs.keys().filter(|k| !ours.contains_key(*k)));
+
+    for key in keys {
+        let base_value = base.and_then(|b| b.get(key));
+        let merged = match (ours.get(key), theirs.get(key)) {
+            (Some(o), Some(t)) => Some(merge_value(Some(key), base_value, o, t)),
+            // Present on one side only: either added there, or removed on the
+            // other side. A removal loses against a concurrent edit.
+            (Some(value), None) | (None, Some(value)) => {
+                (base_value != Some(value)).then(|| value.clone())
+            }
+            (None, None) => None,
+        };
+
+        if let Some(merged) = merged {
+            out.insert(key.clone(), merged);
+        }
+    }
+
+    out
+}
+
+// Participants get a fresh row id on every device, so the same person added on
+// both sides is matched by `human_id` instead.
+fn key_field(field: Option<&str>) -> &'static str {
+    match field {
+        Some("participants") => "human_id",
+        _ => "id",
+    }
+}
+
+fn merge_arrays(
+    field: Option<&str>,
+    base: Option<&Vec<Value>>,
+    ours: &[Value],
+    theirs: &[Value],
+) -> Vec<Value> {
+    let empty = Vec::new();
+    let base = base.unwrap_or(&empty);
+    let all = || base.iter().chain(ours).chain(theirs);
+
+    let key_field = key_field(field);
+    let key = |value: &Value| {
+        value
+            .get(key_field)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+
+    if all().all(|v| key(v).is_some()) {
+        let find =
+            |items: &[Value], k: &str| items.iter().find(|v| key(v).as_deref() == Some(k)).cloned();
+        merge_items(base, ours, theirs, |items, v| find(items, &key(v).unwrap()))
+    } else if all().all(|v| !v.is_object() && !v.is_array()) {
+        merge_items(base, ours, theirs, |items, v| {
+            items.iter().find(|i| *i == v).cloned()
+        })
+    } else {
+        ours.to_vec()
+    }
+}
+
+/// Keeps our order and appends items only theirs has. `find` locates the
+/// counterpart of an item in another list.
+fn merge_items(
+    base: &[Value],
+    ours: &[Value],
+    theirs: &[Value],
+    find: impl Fn(&[Value], &Value) -> Option<Value>,
+) -> Vec<Value> {
+    let mut out = Vec::new();
+
+    for o in ours {
+        let base_item = find(base, o);
+        match find(theirs, o) {
+            Some(t) => out.push(merge_value(None, base_item.as_ref(), o, &t)),
+            None if base_item.as_ref() != Some(o) => out.push(o.clone()),
+            None => {}
+        }
+    }
+
+    for t in theirs {
+        if find(ours, t).is_none() && find(base, t).as_ref() != Some(t) {
+            out.push(t.clone());
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn merges_session_meta() {
+        let base = json!({
+            "id": "s1",
+            "title": "Standup",
+            "tags": ["eng", "daily"],
+            "participants": [
+                { "id": "p1", "human_id": "alice", "source": "manual" },
+                { "id": "p2", "human_id": "bob", "source": "manual" }
+            ]
+        });
+        let ours = json!({
+            "id": "s1",
+            "title": "Standup (Mon)",
+            "tags": ["eng", "daily", "q3"],
+            "participants": [
+                { "id": "p1", "human_id": "alice", "source": "manual" },
+                { "id": "p2", "human_id": "bob", "source": "manual" },
+                { "id": "p3", "human_id": "carol", "source": "manual" }
+            ]
+        });
+        let theirs = json!({
+            "id": "s1",
+            "title": "Standup",
+            "tags": ["eng", "planning"],
+            "participants": [
+                { "id": "p1", "human_id": "alice", "source": "manual" },
+                { "id": "p9", "human_id": "carol", "source": "auto" }
+            ]
+        });
+
+        let merged = merge(Some(&base), &ours, &theirs);
+        assert_eq!(
+            merged,
+            json!({
+                "id": "s1",
+                "title": "Standup (Mon)",
+                "tags": ["eng", "q3", "planning"],
+                "participants": [
+                    { "id": "p1", "human_id": "alice", "source": "manual" },
+                    { "id": "p3", "human_id": "carol", "source": "manual" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn merges_transcript_words_by_id() {
+        let base = json!({ "transcripts": [
+            { "id": "t1", "words": [{ "id": "w1", "text": "hi", "start_ms": 0 }] }
+        ]});
+        let ours = json!({ "transcripts": [
+            { "id": "t1", "words": [
+                { "id": "w1", "text": "hi", "start_ms": 0 },
+                { "id": "w2", "text": "there", "start_ms": 500 }
+            ]}
+        ]});
+        let theirs = json!({ "transcripts": [
+            { "id": "t1", "words": [
+                { "id": "w1", "text": "Hi", "start_ms": 0 },
+                { "id": "w3", "text": "all", "start_ms": 900 }
+            ]},
+            { "id": "t2", "words": [] }
+        ]});
+
+        let merged = merge(Some(&base), &ours, &theirs);
+        assert_eq!(
+            merged,
+            json!({ "transcripts": [
+                { "id": "t1", "words": [
+                    { "id": "w1", "text": "Hi", "start_ms": 0 },
+                    { "id": "w2", "text": "there", "start_ms": 500 },
+                    { "id": "w3", "text": "all", "start_ms": 900 }
+                ]},
+                { "id": "t2", "words": [] }
+            ]})
+        );
+    }
+
+    #[test]
+    fn edit_wins_over_removal() {
+        let base = json!({ "event": { "title": "Sync" } });
+        let ours = json!({});
+        let theirs = json!({ "event": { "title": "Weekly sync" } });
+
+        assert_eq!(
+            merge(Some(&base), &ours, &theirs),
+            json!({ "event": { "title": "Weekly sync" } })
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Content-aware merging of vault files, used when both sides of a pull
+//! changed the same file.
+
+mod json;
+mod text;
+
+const SESSION_TRANSCRIPT_FILE: &str = "transcript.json";
+
+pub enum Resolution {
+    Merged(Vec<u8>),
+    /// Merged with conflict markers around the overlapping edits.
+    Conflicted(Vec<u8>),
+    /// Binary content that can't be merged.
+    Unmergeable,
+}
+
+/// Merges `ours` and `theirs` against their common ancestor `base`, which is
+/// `None` when the file was added on both sides. JSON files (`_meta.json`,
+/// `transcript.json`, ...) are merged structurally; everything else, including
+/// `_memo.md` and enhanced notes, gets a line-based three-way merge.
+pub fn merge_file(path: &str, base: Option<&[u8]>, ours: &[u8], theirs: &[u8]) -> Resolution {
+    if path.ends_with(".json")
+        && let Some(merged) = merge_json(path, base, ours, theirs)
+    {
+        return Resolution::Merged(merged);
+    }
+
+    let (Ok(ours), Ok(theirs), Ok(base)) = (
+        std::str::from_utf8(ours),
+        std::str::from_utf8(theirs),
+        std::str::from_utf8(base.unwrap_or_default()),
+    ) else {
+        return Resolution::Unmergeable;
+    };
+
+    match text::merge(base, ours, theirs) {
+        (merged, false) => Resolution::Merged(merged.into_bytes()),
+        (merged, true) => Resolution::Conflicted(merged.into_bytes()),
+    }
+}
+
+fn merge_json(path: &str, base: Option<&[u8]>, ours: &[u8], theirs: &[u8]) -> Option<Vec<u8>> {
+    let ours: serde_json::Value = serde_json::from_slice(ours).ok()?;
+    let theirs: serde_json::Value = serde_json::from_slice(theirs).ok()?;
+    let base: Option<serde_json::Value> = base.and_then(|b| serde_json::from_slice(b).ok());
+
+    let mut merged = json::merge(base.as_ref(), &ours, &theirs);
+    if path.rsplit('/').next() == Some(SESSION_TRANSCRIPT_FILE) {
+        sort_transcript_words(&mut merged);
+    }
+
+    serde_json::to_vec_pretty(&merged).ok()
+}
+
+// Words appended on different devices end up interleaved after a merge.
+fn sort_transcript_words(value: &mut serde_json::Value) {
+    let Some(transcripts) = value
+        .get_mut("transcripts")
+        .and_then(serde_json::Value::as_array_mut)
+    else {
+        return;
+    };
+
+    for words in transcripts
+        .iter_mut()
+        .filter_map(|t| t.get_mut("words").and_then(serde_json::Value::as_array_mut))
+    {
+        words.sort_by_key(|w| w.get("start_ms").and_then(serde_json::Value::as_i64));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merged(resolution: Resolution) -> String {
+        match resolution {
+            Resolution::Merged(bytes) => String::from_utf8(bytes).unwrap(),
+            _ => panic!("expected a clean merge"),
+        }
+    }
+
+    #[test]
+    fn sorts_merged_transcript_words() {
+        let base = br#"{"transcripts":[{"id":"t1","words":[{"id":"a","start_ms":0}]}]}"#;
+        let ours = br#"{"transcripts":[{"id":"t1","words":[{"id":"a","start_ms":0},{"id":"c","start_ms":20}]}]}"#;
+        let theirs = br#"{"transcripts":[{"id":"t1","words":[{"id":"a","start_ms":0},{"id":"b","start_ms":10}]}]}"#;
+
+        let out = merged(merge_file(
+            "sessions/s1/transcript.json",
+            Some(base),
+            ours,
+            theirs,
+        ));
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        let ids: Vec<_> = value["transcripts"][0]["words"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|w| w["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn falls_back_to_text_for_invalid_json() {
+        let out = merge_file("sessions/s1/_meta.json", Some(b"{\n"), b"{\n", b"{\n}\n");
+        assert_eq!(merged(out), "{\n}\n");
+    }
+
+    #[test]
+    fn merges_memo_text() {
+        let base = b"---\nid: m1\n---\n\nline one\nline two\n";
+        let ours = b"---\nid: m1\n---\n\nline one!\nline two\n";
+        let theirs = b"---\nid: m1\n---\n\nline one\nline two\nline three\n";
+
+        assert_eq!(
+            merged(merge_file("sessions/s1/_memo.md", Some(base), ours, theirs)),
+            "---\nid: m1\n---\n\nline one!\nline two\nline three\n"
+        );
+    }
+
+    #[test]
+    fn refuses_binary_files() {
+        let out = merge_file(
+            "sessions/s1/attachments/a.png",
+            None,
+            &[0xff, 0x00],
+            &[0xfe],
+        );
+        assert!(matches!(out, Resolution::Unmergeable));
+    }
+}
@@ -0,0 +1,184 @@
+use similar::{Algorithm, DiffOp, capture_diff_slices};
+
+const MARKER_OURS: &str = "<<<<<<< ours\n";
+const MARKER_SEPARATOR: &str = "=======\n";
+const MARKER_THEIRS: &str = ">>>>>>> theirs\n";
+
+/// Lines `start..end` of the base replaced by `lines`.
+struct Hunk<'a> {
+    start: usize,
+    end: usize,
+    lines: &'a [&'a str],
+}
+
+/// Line-based three-way merge. Changes that touch different parts of the base
+/// are combined; overlapping changes that differ are wrapped in conflict
+/// markers and reported through the returned flag.
+pub fn merge(base: &str, ours: &str, theirs: &str) -> (String, bool) {
+    let base: Vec<&str> = base.split_inclusive('\n').collect();
+    let ours: Vec<&str> = ours.split_inclusive('\n').collect();
+    let theirs: Vec<&str> = theirs.split_inclusive('\n').collect();
+
+    let ours_hunks = hunks(&base, &ours);
+    let theirs_hunks = hunks(&base, &theirs);
+
+    let mut out = String::new();
+    let mut conflicted = false;
+    let mut pos = 0;
+    let (mut i, mut j) = (0, 0);
+
+    while i < ours_hunks.len() || j < theirs_hunks.len() {
+        let (region_start, mut region_end) = match (ours_hunks.get(i), theirs_hunks.get(j)) {
+            (Some(a), Some(b)) if b.start < a.start => (b.start, b.end),
+            (Some(a), _) => (a.start, a.end),
+            (None, Some(b)) => (b.start, b.end),
+            (None, None) => unreachable!(),
+        };
+
+        let (first_ours, first_theirs) = (i, j);
+        loop {
+            if let Some(h) = ours_hunks.get(i).filter(|h| h.start <= region_end) {
+                region_end = region_end.max(h.end);
+                i += 1;
+            } else if let Some(h) = theirs_hunks.get(j).filter(|h| h.start <= region_end) {
+                region_end = region_end.max(h.end);
+                j += 1;
+            } else {
+                break;
+            }
+        }
+
+        out.extend(base[pos..region_start].iter().copied());
+
+        let ours_region = apply(&base, region_start, region_end, &ours_hunks[first_ours..i]);
+        let theirs_region = apply(
+            &base,
+            region_start,
+            region_end,
+            &theirs_hunks[first_theirs..j],
+        );
+
+        if first_theirs == j || ours_region == theirs_region {
+            out.push_str(&ours_region);
+        } else if first_ours == i {
+            out.push_str(&theirs_region);
+        } else {
+            conflicted = true;
+            out.push_str(MARKER_OURS);
+            push_terminated(&mut out, &ours_region);
+            out.push_str(MARKER_SEPARATOR);
+            push_terminated(&mut out, &theirs_region);
+            out.push_str(MARKER_THEIRS);
+        }
+
+        pos = region_end;
+    }
+
+    out.extend(base[pos..].iter().copied());
+    (out, conflicted)
+}
+
+fn hunks<'a>(base: &[&str], other: &'a [&'a str]) -> Vec<Hunk<'a>> {
+    let mut hunks: Vec<Hunk<'a>> = Vec::new();
+
+    for op in capture_diff_slices(Algorithm::Myers, base, other) {
+        let (start, end, new_range) = match op {
+            DiffOp::Equal { .. } => continue,
+            DiffOp::Delete {
+                old_index,
+                old_len,
+                new_index,
+            } => (old_index, old_index + old_len, new_index..new_index),
+            DiffOp::Insert {
+                old_index,
+                new_index,
+                new_len,
+            } => (old_index, old_index, new_index..new_index + new_len),
+            DiffOp::Replace {
+                old_index,
+                old_len,
+                new_index,
+                new_len,
+            } => (
+                old_index,
+                old_index + old_len,
+                new_index..new_index + new_len,
+            ),
+        };
+
+        match hunks.last_mut() {
+            // Adjacent ops (e.g. a delete followed by an insert) form one hunk.
+            Some(last) if last.end == start => {
+                let lines_start = new_range.end - (last.lines.len() + new_range.len());
+                last.end = end;
+                last.lines = &other[lines_start..new_range.end];
+            }
+            _ => hunks.push(Hunk {
+                start,
+                end,
+                lines: &other[new_range],
+            }),
+        }
+    }
+
+    hunks
+}
+
+fn apply(base: &[&str], start: usize, end: usize, hunks: &[Hunk]) -> String {
+    let mut out = String::new();
+    let mut pos = start;
+    for hunk in hunks {
+        out.extend(base[pos..hunk.start].iter().copied());
+        out.extend(hunk.lines.iter().copied());
+        pos = hunk.end;
+    }
+    out.extend(base[pos..end].iter().copied());
+    out
+}
+
+fn push_terminated(out: &mut String, text: &str) {
+    out.push_str(text);
+    if !text.is_empty() && !text.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combines_changes_to_different_lines() {
+        let base = "# Notes\n\nfirst\nsecond\nthird\n";
+        let ours = "# Notes\n\nfirst (edited)\nsecond\nthird\n";
+        let theirs = "# Notes\n\nfirst\nsecond\nthird\nfourth\n";
+
+        let (merged, conflicted) = merge(base, ours, theirs);
+        assert!(!conflicted);
+        assert_eq!(merged, "# Notes\n\nfirst (edited)\nsecond\nthird\nfourth\n");
+    }
+
+    #[test]
+    fn identical_changes_are_not_conflicts() {
+        let (merged, conflicted) = merge("a\nb\n", "a\nc\n", "a\nc\n");
+        assert!(!conflicted);
+        assert_eq!(merged, "a\nc\n");
+    }
+
+    #[test]
+    fn marks_overlapping_changes() {
+        let (merged, conflicted) = merge("a\nb\nc\n", "a\nours\nc\n", "a\ntheirs\nc\n");
+        assert!(conflicted);
+        assert_eq!(
+            merged,
+            "a\n<<<<<<< ours\nours\n=======\ntheirs\n>>>>>>> theirs\nc\n"
+        );
+    }
+
+    #[test]
+    fn handles_missing_trailing_newline() {
+        let (merged, conflicted) = merge("a\nb", "x\na\nb", "a\nb\nc");
+        assert!(!conflicted);
+        assert_eq!(merged, "x\na\nb\nc");
+    }
+}
@@ -95,7 +95,9 @@ pub fn status(path: &Path) -> Result<StatusInfo, crate::Error> {
 
             let index_mtime = entry.stat.mtime.secs as i64;
 
-            if mtime != index_mtime
+            // Size catches edits made within the same second as the last
+            // index update.
+            if (mtime != index_mtime || metadata.len() as u32 != entry.stat.size)
                 && let Ok(current_data) = std::fs::read(&full_path)
                 && let Ok(current_hash) = gix::objs::compute_hash(
                     repo.object_hash(),
@@ -269,107 +271,137 @@ pub fn reset(path: &Path, files: Vec<String>) -> Result<(), crate::Error> {
 pub fn commit(path: &Path, message: &str) -> Result<String, crate::Error> {
     let repo = gix::discover(path)?;
 
-    let tree_id = {
-        let index = repo
-            .index_or_empty()
-            .map_err(|e| crate::Error::Custom(e.to_string()))?;
+    if super::merge::check_conflicts(path)?.is_some() {
+        return Err(crate::Error::Custom(
+            "Resolve merge conflicts before committing".to_string(),
+        ));
+    }
 
-        let mut trees: std::collections::HashMap<Vec<u8>, gix::objs::Tree> =
-            std::collections::HashMap::new();
-
-        for entry in index.entries() {
-            let path_bytes = entry.path(&index);
-            let path_str =
-                std::str::from_utf8(path_bytes).map_err(|e| crate::Error::Custom(e.to_string()))?;
-            let parts: Vec<&str> = path_str.split('/').collect();
-
-            if parts.len() == 1 {
-                trees
-                    .entry(Vec::new())
-                    .or_insert_with(gix::objs::Tree::empty)
-                    .entries
-                    .push(gix::objs::tree::Entry {
-                        mode: gix::objs::tree::EntryKind::Blob.into(),
-                        filename: parts[0].as_bytes().into(),
-                        oid: entry.id,
-                    });
-            } else {
-                for i in 0..parts.len() {
-                    let parent_path = if i == 0 {
-                        Vec::new()
-                    } else {
-                        parts[..i].join("/").into_bytes()
-                    };
-
-                    if i == parts.len() - 1 {
-                        trees
-                            .entry(parent_path)
-                            .or_insert_with(gix::objs::Tree::empty)
-                            .entries
-                            .push(gix::objs::tree::Entry {
-                                mode: gix::objs::tree::EntryKind::Blob.into(),
-                                filename: parts[i].as_bytes().into(),
-                                oid: entry.id,
-                            });
-                    } else {
-                        trees
-                            .entry(parent_path)
-                            .or_insert_with(gix::objs::Tree::empty);
-                    }
-                }
-            }
-        }
+    let tree_id = write_index_tree(&repo)?;
+
+    let mut parents: Vec<gix::ObjectId> = repo
+        .head_id()
+        .ok()
+        .map(|id| id.detach())
+        .into_iter()
+        .collect();
 
-        let mut written_trees: std::collections::HashMap<Vec<u8>, gix::ObjectId> =
-            std::collections::HashMap::new();
-        let mut sorted_paths: Vec<Vec<u8>> = trees.keys().cloned().collect();
-        sorted_paths.sort_by_key(|b| std::cmp::Reverse(b.len()));
+    // Concluding a merge that `pull` left for manual resolution.
+    let merge_head = super::merge::read_merge_head(&repo)?;
+    parents.extend(merge_head);
 
-        for tree_path in sorted_paths {
-            let mut tree = trees.remove(&tree_path).unwrap();
+    let commit_id = repo
+        .commit("HEAD", message, tree_id, parents)
+        .map_err(|e| crate::Error::Custom(e.to_string()))?;
 
-            for entry in &mut tree.entries {
-                let child_path = if tree_path.is_empty() {
-                    std::str::from_utf8(&entry.filename)
-                        .unwrap()
-                        .as_bytes()
-                        .to_vec()
+    if merge_head.is_some() {
+        super::merge::clear_merge_state(&repo)?;
+    }
+
+    Ok(commit_id.to_string())
+}
+
+pub(super) fn write_index_tree(repo: &gix::Repository) -> Result<gix::ObjectId, crate::Error> {
+    let index = repo
+        .index_or_empty()
+        .map_err(|e| crate::Error::Custom(e.to_string()))?;
+
+    let mut trees: std::collections::HashMap<Vec<u8>, gix::objs::Tree> =
+        std::collections::HashMap::new();
+
+    for entry in index.entries() {
+        let path_bytes = entry.path(&index);
+        let path_str =
+            std::str::from_utf8(path_bytes).map_err(|e| crate::Error::Custom(e.to_string()))?;
+        let parts: Vec<&str> = path_str.split('/').collect();
+
+        if parts.len() == 1 {
+            trees
+                .entry(Vec::new())
+                .or_insert_with(gix::objs::Tree::empty)
+                .entries
+                .push(gix::objs::tree::Entry {
+                    mode: gix::objs::tree::EntryKind::Blob.into(),
+                    filename: parts[0].as_bytes().into(),
+                    oid: entry.id,
+                });
+        } else {
+            for i in 0..parts.len() {
+                let parent_path = if i == 0 {
+                    Vec::new()
                 } else {
-                    [&tree_path[..], b"/", &entry.filename[..]].concat()
+                    parts[..i].join("/").into_bytes()
                 };
 
-                if let Some(&child_tree_id) = written_trees.get(&child_path) {
-                    entry.mode = gix::objs::tree::EntryKind::Tree.into();
-                    entry.oid = child_tree_id;
+                if i == parts.len() - 1 {
+                    trees
+                        .entry(parent_path)
+                        .or_insert_with(gix::objs::Tree::empty)
+                        .entries
+                        .push(gix::objs::tree::Entry {
+                            mode: gix::objs::tree::EntryKind::Blob.into(),
+                            filename: parts[i].as_bytes().into(),
+                            oid: entry.id,
+                        });
+                } else {
+                    trees
+                        .entry(parent_path)
+                        .or_insert_with(gix::objs::Tree::empty);
                 }
             }
-
-            tree.entries.sort_by(|a, b| a.filename.cmp(&b.filename));
-
-            let tree_id = repo
-                .write_object(&tree)
-                .map_err(|e| crate::Error::Custom(e.to_string()))?;
-            written_trees.insert(tree_path, tree_id.into());
         }
+    }
 
-        written_trees
-            .get(&Vec::new())
-            .copied()
-            .ok_or_else(|| crate::Error::Custom("Failed to create root tree".to_string()))?
-    };
+    let mut written_trees: std::collections::HashMap<Vec<u8>, gix::ObjectId> =
+        std::collections::HashMap::new();
+    let mut sorted_paths: Vec<Vec<u8>> = trees.keys().cloned().collect();
+    sorted_paths.sort_by_key(|b| std::cmp::Reverse(b.len()));
+
+    // Deepest directories first, so each subtree is written before the tree
+    // that links to it.
+    for tree_path in sorted_paths {
+        let mut tree = trees.remove(&tree_path).unwrap();
+
+        // Git orders directories as if their name ended with `/`.
+        tree.entries.sort_by(|a, b| {
+            let key = |e: &gix::objs::tree::Entry| {
+                let mut name = e.filename.to_vec();
+                if e.mode.is_tree() {
+                    name.push(b'/');
+                }
+                name
+            };
+            key(a).cmp(&key(b))
+        });
 
-    let parents: Vec<gix::ObjectId> = repo
-        .head_id()
-        .ok()
-        .map(|id| id.detach())
-        .into_iter()
-        .collect();
+        let tree_id: gix::ObjectId = repo
+            .write_object(&tree)
+            .map_err(|e| crate::Error::Custom(e.to_string()))?
+            .into();
+
+        if !tree_path.is_empty() {
+            let (parent, name) = match tree_path.iter().rposition(|b| *b == b'/') {
+                Some(pos) => (tree_path[..pos].to_vec(), tree_path[pos + 1..].to_vec()),
+                None => (Vec::new(), tree_path.clone()),
+            };
+            trees
+                .entry(parent)
+                .or_insert_with(gix::objs::Tree::empty)
+                .entries
+                .push(gix::objs::tree::Entry {
+                    mode: gix::objs::tree::EntryKind::Tree.into(),
+                    filename: name.as_slice().into(),
+                    oid: tree_id,
+                });
+        }
 
-    let commit_id = repo
-        .commit("HEAD", message, tree_id, parents)
-        .map_err(|e| crate::Error::Custom(e.to_string()))?;
+        written_trees.insert(tree_path, tree_id);
+    }
 
-    Ok(commit_id.to_string())
+    written_trees
+        .get(&Vec::new())
+        .copied()
+        .ok_or_else(|| crate::Error::Custom("Failed to create root tree".to_string()))
 }
 
 pub fn log(path: &Path, limit: u32) -> Result<Vec<CommitInfo>, crate::Error> {
@@ -474,3 +506,90 @@ pub(super) fn create_stat_from_metadata(metadata: &std::fs::Metadata) -> gix::in
         size,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?}: {output:?}");
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        git(dir.path(), &["init", "--initial-branch=main"]);
+        git(dir.path(), &["config", "user.name", "Test"]);
+        git(dir.path(), &["config", "user.email", "test@example.com"]);
+        dir
+    }
+
+    #[test]
+    fn commit_writes_nested_trees_in_git_order() {
+        let dir = repo();
+        write(dir.path(), "top.md", "top");
+        write(dir.path(), "sessions/s1/_memo.md", "memo");
+        write(dir.path(), "sessions/s1.md", "sibling");
+        write(dir.path(), "sessions/s2/notes/a.md", "deep");
+
+        add(dir.path(), vec![".".to_string()]).unwrap();
+        commit(dir.path(), "initial").unwrap();
+
+        let files = git(dir.path(), &["ls-tree", "-r", "--name-only", "HEAD"]);
+        assert_eq!(
+            files.lines().collect::<Vec<_>>(),
+            vec![
+                "sessions/s1.md",
+                "sessions/s1/_memo.md",
+                "sessions/s2/notes/a.md",
+                "top.md",
+            ]
+        );
+        assert_eq!(
+            git(dir.path(), &["show", "HEAD:sessions/s2/notes/a.md"]),
+            "deep"
+        );
+
+        // `s1.md` must sort before the `s1` directory for git to accept the tree.
+        git(dir.path(), &["fsck", "--strict"]);
+    }
+
+    #[test]
+    fn status_detects_edit_within_the_same_second() {
+        let dir = repo();
+        write(dir.path(), "note.md", "one");
+        add(dir.path(), vec!["note.md".to_string()]).unwrap();
+        commit(dir.path(), "initial").unwrap();
+
+        let path = dir.path().join("note.md");
+        let mtime = std::fs::metadata(&path).unwrap().modified().unwrap();
+        std::fs::write(&path, "one two").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(mtime)
+            .unwrap();
+
+        let status = status(dir.path()).unwrap();
+        assert!(status.has_changes);
+        assert_eq!(status.unstaged.len(), 1);
+        assert_eq!(status.unstaged[0].path, "note.md");
+        assert!(matches!(
+            status.unstaged[0].status,
+            FileChangeType::Modified
+        ));
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
 use std::path::Path;
 
-use crate::types::ConflictInfo;
+use crate::merge_driver::{self, Resolution};
+use crate::types::{ConflictInfo, PullResult};
+
+const MERGE_FILES: &[&str] = &["MERGE_HEAD", "MERGE_MSG", "MERGE_MODE"];
 
 pub fn check_conflicts(path: &Path) -> Result<Option<ConflictInfo>, crate::Error> {
     let repo = gix::discover(path)?;
@@ -38,19 +42,7 @@ pub fn abort_merge(path: &Path) -> Result<(), crate::Error> {
     let repo = gix::discover(path)?;
     let git_dir = repo.git_dir();
 
-    let merge_head = git_dir.join("MERGE_HEAD");
-    let merge_msg = git_dir.join("MERGE_MSG");
-    let merge_mode = git_dir.join("MERGE_MODE");
-
-    if merge_head.exists() {
-        std::fs::remove_file(merge_head)?;
-    }
-    if merge_msg.exists() {
-        std::fs::remove_file(merge_msg)?;
-    }
-    if merge_mode.exists() {
-        std::fs::remove_file(merge_mode)?;
-    }
+    clear_merge_state(&repo)?;
 
     let head_commit = repo
         .head_id()
@@ -96,6 +88,304 @@ pub fn abort_merge(path: &Path) -> Result<(), crate::Error> {
     Ok(())
 }
 
+pub(super) fn read_merge_head(
+    repo: &gix::Repository,
+) -> Result<Option<gix::ObjectId>, crate::Error> {
+    let merge_head = repo.git_dir().join("MERGE_HEAD");
+    if !merge_head.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(merge_head)?;
+    let id = gix::ObjectId::from_hex(content.trim().as_bytes())
+        .map_err(|e| crate::Error::Custom(e.to_string()))?;
+    Ok(Some(id))
+}
+
+pub(super) fn clear_merge_state(repo: &gix::Repository) -> Result<(), crate::Error> {
+    for name in MERGE_FILES {
+        let path = repo.git_dir().join(name);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
+
+/// All commits reachable from `tip`, including itself.
+pub(super) fn ancestors(
+    repo: &gix::Repository,
+    tip: gix::ObjectId,
+) -> Result<HashSet<gix::ObjectId>, crate::Error> {
+    let mut seen = HashSet::from([tip]);
+    let mut queue = VecDeque::from([tip]);
+
+    while let Some(id) = queue.pop_front() {
+        for parent in parents(repo, id)? {
+            if seen.insert(parent) {
+                queue.push_back(parent);
+            }
+        }
+    }
+
+    Ok(seen)
+}
+
+/// The first commit reachable from `tip` (breadth-first) that is also in
+/// `other_ancestors`.
+fn merge_base(
+    repo: &gix::Repository,
+    tip: gix::ObjectId,
+    other_ancestors: &HashSet<gix::ObjectId>,
+) -> Result<Option<gix::ObjectId>, crate::Error> {
+    let mut seen = HashSet::from([tip]);
+    let mut queue = VecDeque::from([tip]);
+
+    while let Some(id) = queue.pop_front() {
+        if other_ancestors.contains(&id) {
+            return Ok(Some(id));
+        }
+        for parent in parents(repo, id)? {
+            if seen.insert(parent) {
+                queue.push_back(parent);
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn parents(repo: &gix::Repository, id: gix::ObjectId) -> Result<Vec<gix::ObjectId>, crate::Error> {
+    let commit = repo
+        .find_object(id)
+        .map_err(|e| crate::Error::Custom(e.to_string()))?
+        .try_into_commit()
+        .map_err(|e| crate::Error::Custom(e.to_string()))?;
+
+    let commit_ref = commit
+        .decode()
+        .map_err(|e| crate::Error::Custom(e.to_string()))?;
+
+    Ok(commit_ref.parents().collect())
+}
+
+fn commit_tree(repo: &gix::Repository, id: gix::ObjectId) -> Result<gix::ObjectId, crate::Error> {
+    Ok(repo
+        .find_object(id)
+        .map_err(|e| crate::Error::Custom(e.to_string()))?
+        .try_into_commit()
+        .map_err(|e| crate::Error::Custom(e.to_string()))?
+        .tree_id()
+        .map_err(|e| crate::Error::Custom(e.to_string()))?
+        .detach())
+}
+
+fn read_blob(repo: &gix::Repository, id: gix::ObjectId) -> Result<Vec<u8>, crate::Error> {
+    Ok(repo
+        .find_object(id)
+        .map_err(|e| crate::Error::Custom(e.to_string()))?
+        .try_into_blob()
+        .map_err(|e| crate::Error::Custom(e.to_string()))?
+        .data
+        .clone())
+}
+
+/// Every file in the tree, keyed by its slash-separated path.
+fn flatten_tree(
+    repo: &gix::Repository,
+    tree_id: gix::ObjectId,
+    parent_path: &str,
+    files: &mut BTreeMap<String, gix::ObjectId>,
+) -> Result<(), crate::Error> {
+    let tree_obj = repo
+        .find_object(tree_id)
+        .map_err(|e| crate::Error::Custom(e.to_string()))?
+        .try_into_tree()
+        .map_err(|e| crate::Error::Custom(e.to_string()))?;
+
+    let entries: Result<Vec<_>, _> = tree_obj.iter().collect();
+    let entries = entries.map_err(|e| crate::Error::Custom(e.to_string()))?;
+
+    for entry in entries {
+        let name = String::from_utf8_lossy(entry.inner.filename);
+        let entry_path = if parent_path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{parent_path}/{name}")
+        };
+
+        if entry.inner.mode.is_tree() {
+            flatten_tree(repo, entry.inner.oid.into(), &entry_path, files)?;
+        } else {
+            files.insert(entry_path, entry.inner.oid.into());
+        }
+    }
+
+    Ok(())
+}
+
+fn tree_files(
+    repo: &gix::Repository,
+    commit: Option<gix::ObjectId>,
+) -> Result<BTreeMap<String, gix::ObjectId>, crate::Error> {
+    let mut files = BTreeMap::new();
+    if let Some(commit) = commit {
+        flatten_tree(repo, commit_tree(repo, commit)?, "", &mut files)?;
+    }
+    Ok(files)
+}
+
+/// Merges `remote` into the checked-out `local` commit. Files changed on only
+/// one side are taken from that side, files changed on both go through the
+/// merge driver. Without conflicts the result is committed right away;
+/// otherwise the merged files (with conflict markers) are left in the working
+/// directory and the merge is concluded by the next `commit`.
+pub(super) fn merge_commits(
+    repo: &gix::Repository,
+    local: gix::ObjectId,
+    remote: gix::ObjectId,
+    local_ancestors: &HashSet<gix::ObjectId>,
+    commits_pulled: u32,
+) -> Result<PullResult, crate::Error> {
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| crate::Error::Custom("No working directory".to_string()))?;
+
+    let base = merge_base(repo, remote, local_ancestors)?;
+    let base_files = tree_files(repo, base)?;
+    let ours = tree_files(repo, Some(local))?;
+    let theirs = tree_files(repo, Some(remote))?;
+
+    let mut merged: BTreeMap<String, gix::ObjectId> = BTreeMap::new();
+    let mut merged_files = Vec::new();
+    let mut conflicts: Vec<(String, gix::ObjectId, gix::ObjectId)> = Vec::new();
+
+    let paths: std::collections::BTreeSet<&String> = ours.keys().chain(theirs.keys()).collect();
+    for path in paths {
+        let (b, o, t) = (base_files.get(path), ours.get(path), theirs.get(path));
+
+        let (o, t) = match (o, t) {
+            _ if o == t || b == t => {
+                merged.extend(o.map(|id| (path.clone(), *id)));
+                continue;
+            }
+            _ if b == o => {
+                merged.extend(t.map(|id| (path.clone(), *id)));
+                continue;
+            }
+            // Edited on one side, deleted on the other: keep the edit.
+            (Some(id), None) | (None, Some(id)) => {
+                merged.insert(path.clone(), *id);
+                continue;
+            }
+            (Some(o), Some(t)) => (*o, *t),
+            (None, None) => continue,
+        };
+
+        let base_data = b.map(|id| read_blob(repo, *id)).transpose()?;
+        let resolution = merge_driver::merge_file(
+            path,
+            base_data.as_deref(),
+            &read_blob(repo, o)?,
+            &read_blob(repo, t)?,
+        );
+
+        match resolution {
+            Resolution::Merged(data) => {
+                let id = repo
+                    .write_blob(&data)
+                    .map_err(|e| crate::Error::Custom(e.to_string()))?;
+                merged.insert(path.clone(), id.into());
+                merged_files.push(path.clone());
+            }
+            Resolution::Conflicted(data) => {
+                let id = repo
+                    .write_blob(&data)
+                    .map_err(|e| crate::Error::Custom(e.to_string()))?;
+                merged.insert(path.clone(), id.into());
+                conflicts.push((path.clone(), o, t));
+            }
+            Resolution::Unmergeable => {
+                merged.insert(path.clone(), o);
+                conflicts.push((path.clone(), o, t));
+            }
+        }
+    }
+
+    for path in ours.keys().filter(|p| !merged.contains_key(*p)) {
+        match std::fs::remove_file(workdir.join(path)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {}
+        }
+    }
+    for (path, id) in &merged {
+        if ours.get(path) != Some(id) {
+            let file_path = workdir.join(path);
+            if let Some(parent) = file_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&file_path, read_blob(repo, *id)?)?;
+        }
+    }
+
+    let mut new_state = gix::index::State::new(repo.object_hash());
+    for (path, id) in &merged {
+        let metadata = std::fs::metadata(workdir.join(path))?;
+        let stat = super::local::create_stat_from_metadata(&metadata);
+
+        match conflicts.iter().find(|(p, _, _)| p == path) {
+            // Stage 2 ("ours") and 3 ("theirs"), as `git merge` leaves them.
+            Some((_, o, t)) => {
+                for (stage, id) in [(2u32, o), (3u32, t)] {
+                    new_state.dangerously_push_entry(
+                        stat,
+                        *id,
+                        gix::index::entry::Flags::from_bits_retain(stage << 12),
+                        gix::index::entry::Mode::FILE,
+                        path.as_bytes().into(),
+                    );
+                }
+            }
+            None => new_state.dangerously_push_entry(
+                stat,
+                *id,
+                gix::index::entry::Flags::empty(),
+                gix::index::entry::Mode::FILE,
+                path.as_bytes().into(),
+            ),
+        }
+    }
+    new_state.sort_entries();
+
+    let index_path = repo.git_dir().join("index");
+    let new_index = gix::index::File::from_state(new_state, index_path.clone());
+    let file = std::fs::File::create(&index_path)?;
+    new_index
+        .write_to(file, gix::index::write::Options::default())
+        .map_err(|e| crate::Error::Custom(e.to_string()))?;
+
+    let message = format!("Merge {remote} into {local}");
+
+    if !conflicts.is_empty() {
+        let git_dir = repo.git_dir();
+        std::fs::write(git_dir.join("MERGE_HEAD"), format!("{remote}\n"))?;
+        std::fs::write(git_dir.join("MERGE_MSG"), format!("{message}\n"))?;
+
+        return Ok(PullResult::Conflicts {
+            files: conflicts.into_iter().map(|(path, _, _)| path).collect(),
+        });
+    }
+
+    let tree_id = super::local::write_index_tree(repo)?;
+    repo.commit("HEAD", message, tree_id, [local, remote])
+        .map_err(|e| crate::Error::Custom(e.to_string()))?;
+
+    Ok(PullResult::Merged {
+        commits_pulled,
+        files: merged_files,
+    })
+}
+
 pub(super) fn restore_tree_entry(
     repo: &gix::Repository,
     workdir: &Path,
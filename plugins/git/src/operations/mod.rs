@@ -1,3 +1,4 @@
 pub mod local;
 pub mod merge;
 pub mod remote;
+pub mod sync;
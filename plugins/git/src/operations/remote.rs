@@ -114,6 +114,24 @@ pub fn pull(path: &Path, remote_name: &str, branch: &str) -> Result<PullResult,
         return Ok(PullResult::AlreadyUpToDate);
     }
 
+    let local_ancestors = super::merge::ancestors(&repo, local_commit)?;
+    if local_ancestors.contains(&remote_commit) {
+        return Ok(PullResult::AlreadyUpToDate);
+    }
+
+    let remote_ancestors = super::merge::ancestors(&repo, remote_commit)?;
+    let commits_pulled = remote_ancestors.difference(&local_ancestors).count() as u32;
+
+    if !remote_ancestors.contains(&local_commit) {
+        return super::merge::merge_commits(
+            &repo,
+            local_commit,
+            remote_commit,
+            &local_ancestors,
+            commits_pulled,
+        );
+    }
+
     let head_ref = repo.git_dir().join("refs/heads").join(branch);
     std::fs::write(&head_ref, format!("{}\n", remote_commit))?;
 
@@ -153,5 +171,5 @@ pub fn pull(path: &Path, remote_name: &str, branch: &str) -> Result<PullResult,
         .write_to(file, options)
         .map_err(|e| crate::Error::Custom(e.to_string()))?;
 
-    Ok(PullResult::Success { commits_pulled: 1 })
+    Ok(PullResult::Success { commits_pulled })
 }
@@ -0,0 +1,216 @@
+use std::path::Path;
+
+use crate::types::{FileChangeType, PullResult, SyncOptions, SyncResult};
+
+/// Commits every change in the working directory, then pulls (merging with
+/// the merge driver) and pushes when a remote is configured. Does nothing
+/// while a merge is waiting for manual resolution.
+pub fn sync(path: &Path, options: &SyncOptions) -> Result<SyncResult, crate::Error> {
+    let mut result = SyncResult {
+        commit_id: None,
+        pull: None,
+        push: None,
+        finished_at: 0,
+    };
+
+    if let Some(conflicts) = super::merge::check_conflicts(path)? {
+        result.pull = Some(PullResult::Conflicts {
+            files: conflicts.files,
+        });
+        result.finished_at = now();
+        return Ok(result);
+    }
+
+    result.commit_id = commit_changes(path)?;
+
+    if let Some(remote_name) = &options.remote_name {
+        let branch = match &options.branch {
+            Some(branch) => branch.clone(),
+            None => super::local::get_current_branch(path)?,
+        };
+
+        let pull = super::remote::pull(path, remote_name, &branch)?;
+        let can_push = !matches!(pull, PullResult::Conflicts { .. });
+        result.pull = Some(pull);
+
+        if can_push {
+            result.push = Some(super::remote::push(path, remote_name, &branch)?);
+        }
+    }
+
+    result.finished_at = now();
+    Ok(result)
+}
+
+fn commit_changes(path: &Path) -> Result<Option<String>, crate::Error> {
+    let status = super::local::status(path)?;
+    if !status.has_changes {
+        return Ok(None);
+    }
+
+    let deleted: Vec<String> = status
+        .unstaged
+        .iter()
+        .filter(|f| matches!(f.status, FileChangeType::Deleted))
+        .map(|f| f.path.clone())
+        .collect();
+    let changed = status.unstaged.len() + status.untracked.len();
+
+    if !deleted.is_empty() {
+        super::local::reset(path, deleted)?;
+    }
+    super::local::add(path, vec![".".to_string()])?;
+
+    let message = format!("Auto-sync: {changed} file(s) changed");
+    super::local::commit(path, &message).map(Some)
+}
+
+fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?}: {output:?}");
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn read(dir: &Path, path: &str) -> String {
+        std::fs::read_to_string(dir.join(path)).unwrap()
+    }
+
+    const META: &str = "sessions/s1/_meta.json";
+    const MEMO: &str = "sessions/s1/_memo.md";
+
+    /// A bare `origin` plus two clones of it, `local` and `other`.
+    fn setup() -> (tempfile::TempDir, std::path::PathBuf, std::path::PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        git(
+            root,
+            &["init", "--bare", "--initial-branch=main", "origin.git"],
+        );
+        git(root, &["clone", "origin.git", "other"]);
+        let other = root.join("other");
+        git(&other, &["symbolic-ref", "HEAD", "refs/heads/main"]);
+        write(
+            &other,
+            META,
+            r#"{ "id": "s1", "tags": ["eng"], "participants": [] }"#,
+        );
+        write(&other, MEMO, "line one\nline two\n");
+        git(&other, &["add", "."]);
+        git(&other, &["commit", "-m", "initial"]);
+        git(&other, &["push", "origin", "main"]);
+
+        git(root, &["clone", "origin.git", "local"]);
+        let local = root.join("local");
+        git(&local, &["config", "user.name", "Local"]);
+        git(&local, &["config", "user.email", "local@example.com"]);
+
+        (dir, local, other)
+    }
+
+    fn options() -> SyncOptions {
+        SyncOptions {
+            remote_name: Some("origin".to_string()),
+            branch: Some("main".to_string()),
+        }
+    }
+
+    #[test]
+    fn merges_concurrent_session_edits() {
+        let (_dir, local, other) = setup();
+
+        write(
+            &other,
+            META,
+            r#"{ "id": "s1", "tags": ["eng", "planning"], "participants": [] }"#,
+        );
+        write(&other, MEMO, "line one\nline two\nline three\n");
+        git(&other, &["commit", "-am", "remote edit"]);
+        git(&other, &["push", "origin", "main"]);
+
+        write(
+            &local,
+            META,
+            r#"{ "id": "s1", "tags": ["eng", "q3"], "participants": [] }"#,
+        );
+        write(&local, MEMO, "line one!\nline two\n");
+
+        let result = sync(&local, &options()).unwrap();
+        assert!(result.commit_id.is_some());
+        match result.pull {
+            Some(PullResult::Merged { files, .. }) => {
+                assert_eq!(files, vec![MEMO.to_string(), META.to_string()])
+            }
+            other => panic!("unexpected pull result: {other:?}"),
+        }
+
+        let meta: serde_json::Value = serde_json::from_str(&read(&local, META)).unwrap();
+        assert_eq!(meta["tags"], serde_json::json!(["eng", "q3", "planning"]));
+        assert_eq!(read(&local, MEMO), "line one!\nline two\nline three\n");
+
+        let parents = git(&local, &["log", "-1", "--format=%P"]);
+        assert_eq!(parents.split_whitespace().count(), 2);
+        assert_eq!(
+            git(&local, &["show", &format!("HEAD:{MEMO}")]),
+            "line one!\nline two\nline three\n"
+        );
+    }
+
+    #[test]
+    fn leaves_overlapping_note_edits_for_resolution() {
+        let (_dir, local, other) = setup();
+
+        write(&other, MEMO, "line one (theirs)\nline two\n");
+        git(&other, &["commit", "-am", "remote edit"]);
+        git(&other, &["push", "origin", "main"]);
+
+        write(&local, MEMO, "line one (ours)\nline two\n");
+
+        let result = sync(&local, &options()).unwrap();
+        assert!(matches!(
+            result.pull,
+            Some(PullResult::Conflicts { ref files }) if files == &vec![MEMO.to_string()]
+        ));
+        assert!(read(&local, MEMO).contains("<<<<<<< ours\nline one (ours)\n"));
+
+        // Nothing is committed while the merge is pending.
+        let pending = sync(&local, &options()).unwrap();
+        assert!(pending.commit_id.is_none());
+        assert!(super::super::local::commit(&local, "too early").is_err());
+
+        write(&local, MEMO, "line one (both)\nline two\n");
+        super::super::local::add(&local, vec![MEMO.to_string()]).unwrap();
+        super::super::local::commit(&local, "Resolve").unwrap();
+
+        let parents = git(&local, &["log", "-1", "--format=%P"]);
+        assert_eq!(parents.split_whitespace().count(), 2);
+        assert!(
+            super::super::merge::check_conflicts(&local)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
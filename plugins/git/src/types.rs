@@ -49,9 +49,19 @@ pub struct RemoteStatus {
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub enum PullResult {
-    Success { commits_pulled: u32 },
+    Success {
+        commits_pulled: u32,
+    },
+    // Both sides had new commits. `files` were changed on both sides and
+    // merged by content.
+    Merged {
+        commits_pulled: u32,
+        files: Vec<String>,
+    },
     AlreadyUpToDate,
-    Conflicts { files: Vec<String> },
+    Conflicts {
+        files: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -65,3 +75,34 @@ pub enum PushResult {
 pub struct ConflictInfo {
     pub files: Vec<String>,
 }
+
+/// Without a remote, sync only commits. `branch` defaults to the current
+/// branch.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+pub struct SyncOptions {
+    pub remote_name: Option<String>,
+    pub branch: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SyncResult {
+    pub commit_id: Option<String>,
+    pub pull: Option<PullResult>,
+    pub push: Option<PushResult>,
+    pub finished_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AutoSyncConfig {
+    pub path: String,
+    pub interval_secs: u32,
+    #[serde(default)]
+    pub options: SyncOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AutoSyncStatus {
+    pub config: Option<AutoSyncConfig>,
+    pub last_result: Option<SyncResult>,
+    pub last_error: Option<String>,
+}
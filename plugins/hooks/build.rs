@@ -1,4 +1,4 @@
-const COMMANDS: &[&str] = &["run_event_hooks"];
+const COMMANDS: &[&str] = &["run_event_hooks", "list_hook_executions"];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
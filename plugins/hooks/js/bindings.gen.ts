@@ -13,6 +13,14 @@ async runEventHooks(event: HookEvent) : Promise<Result<HookResult[], string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async listHookExecutions(limit: number | null) : Promise<Result<HookExecution[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:hooks|list_hook_executions", { limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -28,10 +36,48 @@ async runEventHooks(event: HookEvent) : Promise<Result<HookResult[], string>> {
 
 export type AfterListeningStoppedArgs = { resource_dir: string; app_hyprnote: string; app_meeting?: string | null }
 export type BeforeListeningStartedArgs = { resource_dir: string; app_hyprnote: string; app_meeting?: string | null }
-export type HookDefinition = { command: string }
-export type HookEvent = { afterListeningStopped: { args: AfterListeningStoppedArgs } } | { beforeListeningStarted: { args: BeforeListeningStartedArgs } }
-export type HookResult = { command: string; success: boolean; exit_code: number | null; stdout: string; stderr: string }
+/**
+ * Fired when a calendar event with a linked meeting is about to start.
+ * `participants` is only delivered in the stdin payload.
+ */
+export type CalendarEventStartingArgs = { event_id: string; title: string; started_at: string; ended_at: string; participants: HookParticipant[]; meeting_link?: string | null; session_id?: string | null }
+/**
+ * Fired after an enhanced note has been generated and saved.
+ * `participants` and `tags` are only delivered in the stdin payload.
+ */
+export type EnhancedNoteGeneratedArgs = { session_id: string; title?: string | null; participants: HookParticipant[]; tags?: string[]; resource_dir: string; note_path: string; template_id?: string | null }
+/**
+ * Fired after a session has been exported to a file.
+ * `tags` is only delivered in the stdin payload.
+ */
+export type ExportCompletedArgs = { session_id: string; title?: string | null; tags?: string[]; format: string; output_path: string }
+/**
+ * A failing hook is retried `retries` times, waiting `retry_backoff_ms`
+ * (doubled after each attempt). Sequential hooks run one after another in the
+ * order they are listed, alongside the hooks that run in parallel. `when`
+ * restricts the hook to events whose args match every entry: string args must
+ * be equal, list args such as `tags` must contain the value.
+ */
+export type HookDefinition = { command: string; timeout_secs?: number | null; retries?: number; retry_backoff_ms?: number | null; sequential?: boolean; env?: Partial<{ [key in string]: string }>; cwd?: string | null; when?: Partial<{ [key in string]: string }> }
+export type HookEvent = { afterListeningStopped: { args: AfterListeningStoppedArgs } } | { beforeListeningStarted: { args: BeforeListeningStartedArgs } } | { calendarEventStarting: { args: CalendarEventStartingArgs } } | { enhancedNoteGenerated: { args: EnhancedNoteGeneratedArgs } } | { exportCompleted: { args: ExportCompletedArgs } } | { sessionCreated: { args: SessionCreatedArgs } } | { sessionDeleted: { args: SessionDeletedArgs } } | { transcriptFinalized: { args: TranscriptFinalizedArgs } }
+export type HookExecution = { event: string; command: string; success: boolean; exit_code: number | null; attempts: number; duration_ms: number; finished_at_ms: number; stderr: string }
+export type HookParticipant = { human_id: string; name: string; email?: string | null }
+export type HookResult = { command: string; success: boolean; exit_code: number | null; stdout: string; stderr: string; attempts: number; duration_ms: number }
 export type HooksConfig = { version: number; on?: Partial<{ [key in string]: HookDefinition[] }> }
+/**
+ * Fired after a new session has been created.
+ * `tags` is only delivered in the stdin payload.
+ */
+export type SessionCreatedArgs = { session_id: string; title?: string | null; tags?: string[]; resource_dir: string }
+/**
+ * Fired after a session has been deleted. Its files may already be gone.
+ */
+export type SessionDeletedArgs = { session_id: string; title?: string | null }
+/**
+ * Fired once the transcript of a session is final and written to disk.
+ * `participants` and `tags` are only delivered in the stdin payload.
+ */
+export type TranscriptFinalizedArgs = { session_id: string; title?: string | null; participants: HookParticipant[]; tags?: string[]; resource_dir: string; transcript_path: string }
 
 /** tauri-specta globals **/
 
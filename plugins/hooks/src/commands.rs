@@ -1,4 +1,4 @@
-use hypr_hooks::{HookEvent, HookResult};
+use hypr_hooks::{HookEvent, HookExecution, HookResult};
 
 use crate::HooksPluginExt;
 
@@ -13,3 +13,15 @@ pub(crate) async fn run_event_hooks<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_hook_executions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    limit: Option<u32>,
+) -> Result<Vec<HookExecution>, String> {
+    app.hooks()
+        .list_executions(limit.unwrap_or(100) as usize)
+        .await
+        .map_err(|e| e.to_string())
+}
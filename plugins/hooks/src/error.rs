@@ -11,6 +11,8 @@ pub enum Error {
     ConfigParse(String),
     #[error("unsupported config version: {0}")]
     UnsupportedVersion(u8),
+    #[error("failed to access execution log: {0}")]
+    Log(String),
 }
 
 impl From<hypr_hooks::Error> for Error {
@@ -19,6 +21,7 @@ impl From<hypr_hooks::Error> for Error {
             hypr_hooks::Error::ConfigLoad(s) => Error::ConfigLoad(s),
             hypr_hooks::Error::ConfigParse(s) => Error::ConfigParse(s),
             hypr_hooks::Error::UnsupportedVersion(v) => Error::UnsupportedVersion(v),
+            hypr_hooks::Error::Log(s) => Error::Log(s),
         }
     }
 }
@@ -1,7 +1,16 @@
-use hypr_hooks::{HookEvent, HookResult, run_hooks_for_event};
+use hypr_hooks::{
+    HookEvent, HookExecution, HookResult, append_executions, read_executions, rotate_executions,
+    run_hooks_for_event,
+};
+use tauri_plugin_settings::SettingsPluginExt;
 
 use crate::config;
 
+const EXECUTION_LOG_FILENAME: &str = "hook-executions.jsonl";
+
+// Hooks for concurrent events finish in any order; one writer at a time.
+static EXECUTION_LOG_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
 pub struct Hooks<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
     manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
@@ -10,7 +19,54 @@ pub struct Hooks<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
 impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Hooks<'a, R, M> {
     pub async fn handle_event(&self, event: HookEvent) -> crate::Result<Vec<HookResult>> {
         let config = config::load_config(self.manager).await?;
-        Ok(run_hooks_for_event(&config, event).await)
+        let condition_key = event.condition_key();
+        let results = run_hooks_for_event(&config, event).await;
+
+        let executions: Vec<_> = results
+            .iter()
+            .map(|result| HookExecution::from_result(condition_key, result))
+            .collect();
+        if let Err(e) = self.record_executions(executions).await {
+            tracing::warn!("failed to record hook executions: {}", e);
+        }
+
+        Ok(results)
+    }
+
+    pub async fn list_executions(&self, limit: usize) -> crate::Result<Vec<HookExecution>> {
+        let path = self.execution_log_path()?;
+        tokio::task::spawn_blocking(move || {
+            let _guard = EXECUTION_LOG_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            read_executions(&path, limit)
+        })
+        .await
+        .map_err(|e| crate::Error::Log(e.to_string()))?
+        .map_err(crate::Error::from)
+    }
+
+    async fn record_executions(&self, executions: Vec<HookExecution>) -> crate::Result<()> {
+        if executions.is_empty() {
+            return Ok(());
+        }
+
+        let path = self.execution_log_path()?;
+        tokio::task::spawn_blocking(move || {
+            let _guard = EXECUTION_LOG_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            append_executions(&path, &executions)?;
+            rotate_executions(&path)
+        })
+        .await
+        .map_err(|e| crate::Error::Log(e.to_string()))?
+        .map_err(crate::Error::from)
+    }
+
+    fn execution_log_path(&self) -> crate::Result<std::path::PathBuf> {
+        let base = self
+            .manager
+            .settings()
+            .default_base()
+            .map_err(|e| crate::Error::Log(e.to_string()))?;
+        Ok(base.join(EXECUTION_LOG_FILENAME))
     }
 }
 
@@ -16,6 +16,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .plugin_name(PLUGIN_NAME)
         .commands(tauri_specta::collect_commands![
             commands::run_event_hooks::<tauri::Wry>,
+            commands::list_hook_executions::<tauri::Wry>,
         ])
         .typ::<hypr_hooks::HooksConfig>()
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
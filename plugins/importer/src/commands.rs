@@ -1,5 +1,7 @@
 use crate::ext::ImporterPluginExt;
-use crate::types::{ImportDataResult, ImportSourceInfo, ImportSourceKind, ImportStats};
+use crate::types::{
+    ImportDataResult, ImportSource, ImportSourceInfo, ImportSourceKind, ImportStats,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -34,3 +36,30 @@ pub async fn run_import_dry<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_import_markdown_vault<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+    user_id: String,
+) -> Result<ImportDataResult, String> {
+    let source = ImportSource::markdown_vault(path.into());
+    app.importer()
+        .run_import_from_source(&source, user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_import_markdown_vault_dry<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+) -> Result<ImportStats, String> {
+    let source = ImportSource::markdown_vault(path.into());
+    app.importer()
+        .run_import_dry_from_source(&source)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -12,6 +12,9 @@ pub enum Error {
     #[error("json error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("markdown vault error: {0}")]
+    MarkdownVault(#[from] hypr_markdown_vault::Error),
+
     #[error("db parser error: {0}")]
     DbParser(#[from] hypr_db_parser::Error),
 
@@ -1,13 +1,15 @@
 use crate::types::{
-    ImportDataResult, ImportSource, ImportSourceInfo, ImportSourceKind, ImportStats,
+    Collection, ImportDataResult, ImportSource, ImportSourceInfo, ImportSourceKind, ImportStats,
 };
 use hypr_importer_core::output::to_tinybase_json;
+use tauri_plugin_settings::SettingsPluginExt;
 
-pub struct Importer<R: tauri::Runtime> {
+pub struct Importer<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
+    manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
 
-impl<R: tauri::Runtime> Importer<R> {
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Importer<'a, R, M> {
     pub fn list_available_sources(&self) -> Vec<ImportSourceInfo> {
         crate::sources::list_available_sources()
     }
@@ -30,7 +32,10 @@ impl<R: tauri::Runtime> Importer<R> {
             return Err(crate::Error::SourceNotAvailable(source.name.clone()));
         }
 
-        let data = crate::sources::import_all(source).await?;
+        let sessions_dir = self.sessions_dir()?;
+        let data = crate::sources::import_all(source, &sessions_dir).await?;
+        self.copy_attachments(&data, &sessions_dir).await?;
+
         let stats = ImportStats::from_data(&data);
         let tinybase_json = to_tinybase_json(&data, &user_id);
 
@@ -56,18 +61,57 @@ impl<R: tauri::Runtime> Importer<R> {
             return Err(crate::Error::SourceNotAvailable(source.name.clone()));
         }
 
-        let data = crate::sources::import_all(source).await?;
+        let data = crate::sources::import_all(source, &self.sessions_dir()?).await?;
         Ok(ImportStats::from_data(&data))
     }
+
+    fn sessions_dir(&self) -> Result<std::path::PathBuf, crate::Error> {
+        Ok(self
+            .manager
+            .app_handle()
+            .settings()
+            .cached_vault_base()?
+            .join("sessions")
+            .into_std_path_buf())
+    }
+
+    // Attachments are copied next to the session they belong to, where the
+    // session's notes link to them as `attachments/<file_name>`.
+    async fn copy_attachments(
+        &self,
+        data: &Collection,
+        sessions_dir: &std::path::Path,
+    ) -> Result<(), crate::Error> {
+        for attachment in &data.attachments {
+            let dir = sessions_dir
+                .join(&attachment.session_id)
+                .join("attachments");
+            let target = dir.join(&attachment.file_name);
+            if target.exists() {
+                continue;
+            }
+
+            tokio::fs::create_dir_all(&dir).await?;
+            tokio::fs::copy(&attachment.source_path, &target).await?;
+        }
+
+        Ok(())
+    }
 }
 
 pub trait ImporterPluginExt<R: tauri::Runtime> {
-    fn importer(&self) -> Importer<R>;
+    fn importer(&self) -> Importer<'_, R, Self>
+    where
+        Self: tauri::Manager<R> + Sized;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> ImporterPluginExt<R> for T {
-    fn importer(&self) -> Importer<R> {
+    fn importer(&self) -> Importer<'_, R, Self>
+    where
+        Self: Sized,
+    {
         Importer {
+            manager: self,
             _runtime: std::marker::PhantomData,
         }
     }
@@ -20,6 +20,8 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_available_sources::<Wry>,
             commands::run_import::<Wry>,
             commands::run_import_dry::<Wry>,
+            commands::run_import_markdown_vault::<Wry>,
+            commands::run_import_markdown_vault_dry::<Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
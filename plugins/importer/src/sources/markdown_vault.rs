@@ -0,0 +1,7 @@
+use crate::types::Collection;
+use std::path::Path;
+
+pub fn import_all_from_path(path: &Path, sessions_dir: &Path) -> Result<Collection, crate::Error> {
+    let data = hypr_markdown_vault::importer::import_all_from_path(path, sessions_dir)?;
+    Ok(data)
+}
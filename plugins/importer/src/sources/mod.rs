@@ -1,16 +1,26 @@
 mod as_is;
 mod granola;
 mod hyprnote;
+mod markdown_vault;
 
 pub use as_is::AsIsData;
 
+use std::path::Path;
+
 use crate::types::{Collection, ImportSource, ImportSourceInfo, TransformKind};
 
-pub async fn import_all(source: &ImportSource) -> Result<Collection, crate::Error> {
+/// `sessions_dir` is where imported sessions' attachments will be copied.
+pub async fn import_all(
+    source: &ImportSource,
+    sessions_dir: &Path,
+) -> Result<Collection, crate::Error> {
     match source.transform {
         TransformKind::HyprnoteV0 => hyprnote::v0::import_all_from_path(&source.path).await,
         TransformKind::Granola => granola::import_all_from_path(&source.path).await,
         TransformKind::AsIs => as_is::load_data(&source.path),
+        TransformKind::MarkdownVault => {
+            markdown_vault::import_all_from_path(&source.path, sessions_dir)
+        }
     }
 }
 
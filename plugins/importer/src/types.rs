@@ -2,8 +2,8 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 pub use hypr_importer_core::ir::{
-    Collection, EnhancedNote, Human, Organization, Session, SessionParticipant, Tag, TagMapping,
-    Template, TemplateSection, Transcript, Word,
+    Attachment, Collection, EnhancedNote, Human, Organization, Session, SessionParticipant, Tag,
+    TagMapping, Template, TemplateSection, Transcript, Word,
 };
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, specta::Type, PartialEq, Eq, Hash)]
@@ -12,6 +12,7 @@ pub enum TransformKind {
     HyprnoteV0,
     Granola,
     AsIs,
+    MarkdownVault,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type, PartialEq, Eq, Hash)]
@@ -75,6 +76,10 @@ impl ImportSource {
         })
     }
 
+    pub fn markdown_vault(path: PathBuf) -> Self {
+        Self::from_path(path, TransformKind::MarkdownVault)
+    }
+
     pub fn is_available(&self) -> bool {
         self.path.exists()
     }
@@ -143,6 +148,7 @@ pub struct ImportStats {
     pub participants_count: usize,
     pub templates_count: usize,
     pub enhanced_notes_count: usize,
+    pub attachments_count: usize,
 }
 
 impl ImportStats {
@@ -155,6 +161,7 @@ impl ImportStats {
             participants_count: data.participants.len(),
             templates_count: data.templates.len(),
             enhanced_notes_count: data.enhanced_notes.len(),
+            attachments_count: data.attachments.len(),
         }
     }
 }
@@ -6,6 +6,7 @@ const COMMANDS: &[&str] = &[
     "set_mic_muted",
     "start_session",
     "stop_session",
+    "label_speaker",
     "get_state",
     "run_batch",
     "is_supported_languages_live",
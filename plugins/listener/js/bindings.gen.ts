@@ -54,6 +54,14 @@ async stopSession() : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async labelSpeaker(channel: number, speakerIndex: number, humanId: string) : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:listener|label_speaker", { channel, speakerIndex, humanId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getState() : Promise<Result<State, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("plugin:listener|get_state") };
@@ -110,7 +118,7 @@ sessionProgressEvent: "plugin:listener:session-progress-event"
 /** user-defined types **/
 
 export type DegradedError = { type: "authentication_failed"; provider: string } | { type: "upstream_unavailable"; message: string } | { type: "connection_timeout" } | { type: "stream_error"; message: string }
-export type SessionDataEvent = { type: "audio_amplitude"; session_id: string; mic: number; speaker: number } | { type: "mic_muted"; session_id: string; value: boolean } | { type: "stream_response"; session_id: string; response: StreamResponse }
+export type SessionDataEvent = { type: "audio_amplitude"; session_id: string; mic: number; speaker: number } | { type: "mic_muted"; session_id: string; value: boolean } | { type: "stream_response"; session_id: string; response: StreamResponse } | { type: "speaker_identified"; session_id: string; channel: number; speaker_index: number; human_id: string; similarity: number }
 export type SessionErrorEvent = { type: "audio_error"; session_id: string; error: string; device: string | null; is_fatal: boolean } | { type: "connection_error"; session_id: string; error: string }
 export type SessionLifecycleEvent = { type: "inactive"; session_id: string; error: string | null } | { type: "active"; session_id: string; error?: DegradedError | null } | { type: "finalizing"; session_id: string }
 export type SessionParams = { session_id: string; languages: string[]; onboarding: boolean; record_enabled: boolean; model: string; base_url: string; api_key: string; keywords: string[] }
@@ -59,6 +59,20 @@ pub async fn stop_session<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn label_speaker<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    channel: i32,
+    speaker_index: i32,
+    human_id: String,
+) -> Result<bool, String> {
+    app.listener()
+        .label_speaker(channel, speaker_index, human_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_state<R: tauri::Runtime>(
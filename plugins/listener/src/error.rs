@@ -14,6 +14,12 @@ pub enum Error {
     CpalDevicesError(#[from] hypr_audio::cpal::DevicesError),
     #[error(transparent)]
     LocalSttError(#[from] tauri_plugin_local_stt::Error),
+    #[error(transparent)]
+    SettingsError(#[from] tauri_plugin_settings::Error),
+    #[error(transparent)]
+    VaultError(#[from] hypr_vault::Error),
+    #[error(transparent)]
+    VoiceprintError(#[from] hypr_pyannote_local::Error),
     #[error("no session")]
     NoneSession,
     #[error("start session failed")]
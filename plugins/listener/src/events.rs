@@ -85,6 +85,14 @@ common_event_derives! {
             session_id: String,
             response: Box<StreamResponse>,
         },
+        #[serde(rename = "speaker_identified")]
+        SpeakerIdentified {
+            session_id: String,
+            channel: i32,
+            speaker_index: i32,
+            human_id: String,
+            similarity: f32,
+        },
     }
 }
 
@@ -170,6 +178,19 @@ impl From<core::SessionDataEvent> for SessionDataEvent {
                 session_id,
                 response,
             },
+            core::SessionDataEvent::SpeakerIdentified {
+                session_id,
+                channel,
+                speaker_index,
+                human_id,
+                similarity,
+            } => SessionDataEvent::SpeakerIdentified {
+                session_id,
+                channel,
+                speaker_index,
+                human_id,
+                similarity,
+            },
         }
     }
 }
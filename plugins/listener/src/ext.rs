@@ -1,9 +1,21 @@
 use ractor::{ActorRef, call_t, registry};
 
-use hypr_listener_core::actors::{RootActor, RootMsg, SessionParams, SourceActor, SourceMsg};
+use hypr_listener_core::actors::{
+    ListenerActor, ListenerMsg, RootActor, RootMsg, SessionParams, SourceActor, SourceMsg,
+};
+use hypr_pyannote_local::identity::{VOICEPRINTS_FILENAME, Voiceprint, VoiceprintStore};
+use tauri_plugin_settings::SettingsPluginExt;
+
+/// How many earlier sessions are mined for recurring names.
+const VOCABULARY_PAST_SESSIONS: usize = 20;
+
+/// Where the desktop keeps the transcript vocabulary in `settings.json`.
+const VOCABULARY_SETTING: &str = "/transcription/vocabulary";
+
+/// Serializes read-modify-write of the voiceprints file across labels.
+static VOICEPRINTS_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
 
 pub struct Listener<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
-    #[allow(unused)]
     manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
@@ -59,7 +71,25 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener<'a, R, M> {
     }
 
     #[tracing::instrument(skip_all)]
-    pub async fn start_session(&self, params: SessionParams) {
+    pub async fn start_session(&self, mut params: SessionParams) {
+        match self.load_voiceprints().await {
+            Ok(voiceprints) => params.voiceprints = voiceprints,
+            Err(e) => tracing::warn!(error = %e, "failed_to_load_voiceprints"),
+        }
+
+        match self.load_vocabulary().await {
+            Ok(vocabulary) => params.vocabulary = vocabulary,
+            Err(e) => tracing::warn!(error = %e, "failed_to_load_vocabulary"),
+        }
+
+        match self
+            .build_vocabulary(&params.session_id, &params.keywords, &params.vocabulary)
+            .await
+        {
+            Ok(keywords) => params.keywords = keywords,
+            Err(e) => tracing::warn!(error = %e, "failed_to_build_vocabulary"),
+        }
+
         if let Some(cell) = registry::where_is(RootActor::name()) {
             let actor: ActorRef<RootMsg> = cell.into();
             let _ = ractor::call!(actor, RootMsg::StartSession, params);
@@ -73,6 +103,113 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener<'a, R, M> {
             let _ = ractor::call!(actor, RootMsg::StopSession);
         }
     }
+
+    /// Attributes a live speaker to a person and remembers their voice for
+    /// later sessions. Returns `false` if the speaker hasn't been heard long
+    /// enough to enrol.
+    #[tracing::instrument(skip_all)]
+    pub async fn label_speaker(
+        &self,
+        channel: i32,
+        speaker_index: i32,
+        human_id: String,
+    ) -> Result<bool, crate::Error> {
+        let Some(cell) = registry::where_is(ListenerActor::name()) else {
+            return Err(crate::Error::ActorNotFound(ListenerActor::name()));
+        };
+        let actor: ActorRef<ListenerMsg> = cell.into();
+
+        let voiceprint = call_t!(
+            actor,
+            ListenerMsg::LabelSpeaker,
+            1000,
+            channel,
+            speaker_index,
+            human_id
+        )
+        .map_err(|_| crate::Error::ActorNotFound(ListenerActor::name()))?;
+
+        let Some(voiceprint) = voiceprint else {
+            return Ok(false);
+        };
+
+        let path = self.voiceprints_path()?;
+        tokio::task::spawn_blocking(move || {
+            let _guard = VOICEPRINTS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            let mut store = VoiceprintStore::load(&path)?;
+            store.upsert(voiceprint);
+            store.save(&path)
+        })
+        .await
+        .map_err(|e| crate::Error::IoError(std::io::Error::other(e)))??;
+        Ok(true)
+    }
+
+    /// Ranks `keywords` from the frontend together with the session's
+    /// participants, organizations and calendar event from the vault, the
+    /// terms of the user's vocabulary and recurring names from past
+    /// transcripts.
+    async fn build_vocabulary(
+        &self,
+        session_id: &str,
+        keywords: &[String],
+        vocabulary: &[String],
+    ) -> Result<Vec<String>, crate::Error> {
+        let base = self.manager.settings().fresh_vault_base()?;
+        let session_id = session_id.to_string();
+        let context = tokio::task::spawn_blocking(move || {
+            hypr_vault::Vault::new(base)
+                .session_vocabulary_context(&session_id, VOCABULARY_PAST_SESSIONS)
+        })
+        .await
+        .map_err(|e| crate::Error::IoError(std::io::Error::other(e)))??;
+
+        // Aliases are mishearings; only the term they become is worth biasing.
+        let glossary: Vec<String> = vocabulary
+            .iter()
+            .map(|entry| {
+                entry
+                    .rsplit_once("=>")
+                    .map_or(entry.as_str(), |(_, term)| term)
+            })
+            .map(|term| term.trim().to_string())
+            .filter(|term| !term.is_empty())
+            .collect();
+
+        Ok(owhisper_client::VocabularyBuilder::new()
+            .with_glossary(&glossary)
+            .with_glossary(keywords)
+            .with_participants(&context.participants)
+            .with_organizations(&context.organizations)
+            .with_event_title(context.event_title.as_deref().unwrap_or_default())
+            .with_past_transcripts(&context.past_transcripts)
+            .build())
+    }
+
+    /// The user's spelling fixes for the transcript, edited in settings.
+    async fn load_vocabulary(&self) -> Result<Vec<String>, crate::Error> {
+        let settings = self.manager.settings().load().await?;
+        Ok(settings
+            .pointer(VOCABULARY_SETTING)
+            .and_then(|value| value.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.as_str().map(str::to_string))
+            .collect())
+    }
+
+    async fn load_voiceprints(&self) -> Result<Vec<Voiceprint>, crate::Error> {
+        let path = self.voiceprints_path()?;
+        let store = tokio::task::spawn_blocking(move || VoiceprintStore::load(path))
+            .await
+            .map_err(|e| crate::Error::IoError(std::io::Error::other(e)))??;
+        Ok(store.voiceprints().to_vec())
+    }
+
+    fn voiceprints_path(&self) -> Result<std::path::PathBuf, crate::Error> {
+        let base = self.manager.settings().global_base()?;
+        Ok(base.join(VOICEPRINTS_FILENAME).into_std_path_buf())
+    }
 }
 
 pub trait ListenerPluginExt<R: tauri::Runtime> {
@@ -2,6 +2,7 @@ const COMMANDS: &[&str] = &[
     "run_batch",
     "parse_subtitle",
     "export_to_vtt",
+    "export_transcript",
     "is_supported_languages_batch",
     "suggest_providers_for_languages_batch",
     "list_documented_language_codes_batch",
@@ -35,6 +35,19 @@ pub async fn export_to_vtt<R: tauri::Runtime>(
     app.listener2().export_to_vtt(session_id, words)
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn export_transcript<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    words: Vec<core::VttWord>,
+    format: core::TranscriptFormat,
+    options: Option<core::CueOptions>,
+) -> Result<String, String> {
+    app.listener2()
+        .export_transcript(session_id, words, format, options.unwrap_or_default())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn is_supported_languages_batch<R: tauri::Runtime>(
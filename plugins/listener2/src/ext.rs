@@ -27,6 +27,21 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener2<'a, R, M> {
         &self,
         session_id: String,
         words: Vec<core::VttWord>,
+    ) -> Result<String, String> {
+        self.export_transcript(
+            session_id,
+            words,
+            core::TranscriptFormat::Vtt,
+            core::CueOptions::default(),
+        )
+    }
+
+    pub fn export_transcript(
+        &self,
+        session_id: String,
+        words: Vec<core::VttWord>,
+        format: core::TranscriptFormat,
+        options: core::CueOptions,
     ) -> Result<String, String> {
         use tauri_plugin_settings::SettingsPluginExt;
 
@@ -39,10 +54,10 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener2<'a, R, M> {
 
         std::fs::create_dir_all(&session_dir).map_err(|e| e.to_string())?;
 
-        let vtt_path = session_dir.join("transcript.vtt");
+        let path = session_dir.join(format!("transcript.{}", format.extension()));
 
-        core::export_words_to_vtt_file(words, &vtt_path)?;
-        Ok(vtt_path.to_string())
+        core::export_transcript_file(&words, format, &options, &path)?;
+        Ok(path.to_string())
     }
 }
 
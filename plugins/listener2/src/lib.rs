@@ -9,7 +9,9 @@ mod ext;
 pub use events::*;
 pub use ext::*;
 
-pub use hypr_listener2_core::{BatchParams, BatchProvider, Subtitle, VttWord};
+pub use hypr_listener2_core::{
+    BatchParams, BatchProvider, CueOptions, Subtitle, TranscriptFormat, VttWord,
+};
 
 const PLUGIN_NAME: &str = "listener2";
 
@@ -26,6 +28,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::run_batch::<tauri::Wry>,
             commands::parse_subtitle::<tauri::Wry>,
             commands::export_to_vtt::<tauri::Wry>,
+            commands::export_transcript::<tauri::Wry>,
             commands::is_supported_languages_batch::<tauri::Wry>,
             commands::suggest_providers_for_languages_batch::<tauri::Wry>,
             commands::list_documented_language_codes_batch::<tauri::Wry>,
@@ -1,3 +1,5 @@
+const COMMANDS: &[&str] = &["start_server", "stop_server", "get_server_info"];
+
 fn main() {
-    tauri_plugin::Builder::new(&[]).build();
+    tauri_plugin::Builder::new(COMMANDS).build();
 }
@@ -11,7 +11,38 @@ import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webview
 
 /** user-defined commands **/
 
-export const commands = {};
+export const commands = {
+  async startServer(): Promise<Result<McpServerInfo, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("plugin:mcp|start_server"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async stopServer(): Promise<Result<null, string>> {
+    try {
+      return { status: "ok", data: await TAURI_INVOKE("plugin:mcp|stop_server") };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getServerInfo(): Promise<Result<McpServerInfo, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("plugin:mcp|get_server_info"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+};
 
 /** user-defined events **/
 
@@ -34,6 +65,7 @@ export type CreateIssueParams = {
   labels: string[] | null;
 };
 export type ListSubscriptionsParams = { status: string | null };
+export type McpServerInfo = { running: boolean; url: string; token: string };
 export type SearchIssueItem = {
   number: number;
   title: string;
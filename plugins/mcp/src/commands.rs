@@ -0,0 +1,24 @@
+use crate::McpPluginExt;
+use crate::types::McpServerInfo;
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn start_server<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<McpServerInfo, String> {
+    app.mcp().start_server().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn stop_server<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<(), String> {
+    app.mcp().stop_server().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_server_info<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<McpServerInfo, String> {
+    app.mcp().server_info().await.map_err(|e| e.to_string())
+}
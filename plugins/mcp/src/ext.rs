@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use hypr_vault::Vault;
+use hypr_vault::mcp::VaultMcpServer;
+use tauri_plugin_settings::SettingsPluginExt;
+
+use crate::search::TantivySearch;
+use crate::server::McpState;
+use crate::types::McpServerInfo;
+
+pub struct Mcp<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
+    manager: &'a M,
+    _runtime: std::marker::PhantomData<fn() -> R>,
+}
+
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Mcp<'a, R, M> {
+    fn state(&self) -> Arc<McpState> {
+        self.manager.state::<Arc<McpState>>().inner().clone()
+    }
+
+    /// Starts the localhost server over the current vault. Stays enabled
+    /// across restarts until stopped.
+    pub async fn start_server(&self) -> crate::Result<McpServerInfo> {
+        let vault = Vault::new(self.manager.settings().fresh_vault_base()?);
+        let search = TantivySearch::new(self.manager.app_handle().clone(), vault.clone());
+        let server = VaultMcpServer::new(vault).with_search(Arc::new(search));
+
+        self.state().start(server).await
+    }
+
+    pub async fn stop_server(&self) -> crate::Result<()> {
+        self.state().stop().await
+    }
+
+    pub async fn server_info(&self) -> crate::Result<McpServerInfo> {
+        self.state().info().await
+    }
+}
+
+pub trait McpPluginExt<R: tauri::Runtime> {
+    fn mcp(&self) -> Mcp<'_, R, Self>
+    where
+        Self: tauri::Manager<R> + Sized;
+}
+
+impl<R: tauri::Runtime, T: tauri::Manager<R>> McpPluginExt<R> for T {
+    fn mcp(&self) -> Mcp<'_, R, Self>
+    where
+        Self: Sized,
+    {
+        Mcp {
+            manager: self,
+            _runtime: std::marker::PhantomData,
+        }
+    }
+}
@@ -1,14 +1,31 @@
+mod commands;
 mod error;
+mod ext;
+mod search;
+mod server;
 pub mod types;
 
 pub use error::{Error, Result};
+pub use ext::*;
+pub use server::DEFAULT_PORT;
+
+use std::sync::Arc;
+
+use tauri::Manager;
+use tauri_plugin_settings::SettingsPluginExt;
 
 const PLUGIN_NAME: &str = "mcp";
+const CONFIG_FILENAME: &str = "mcp.json";
 
-fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
-    tauri_specta::Builder::<R>::new()
+fn make_specta_builder() -> tauri_specta::Builder<tauri::Wry> {
+    tauri_specta::Builder::<tauri::Wry>::new()
         .plugin_name(PLUGIN_NAME)
-        .commands(tauri_specta::collect_commands![])
+        .commands(tauri_specta::collect_commands![
+            commands::start_server::<tauri::Wry>,
+            commands::stop_server::<tauri::Wry>,
+            commands::get_server_info::<tauri::Wry>,
+        ])
+        .typ::<types::McpServerInfo>()
         .typ::<types::CreateIssueParams>()
         .typ::<types::CreateIssueOutput>()
         .typ::<types::AddCommentParams>()
@@ -23,11 +40,28 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
 
-pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
     let specta_builder = make_specta_builder();
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
+        .setup(|app, _api| {
+            let path = app.settings().default_base()?.join(CONFIG_FILENAME);
+            let state = Arc::new(server::McpState::new(path));
+            let enabled = state.is_enabled();
+            app.manage(state);
+
+            if enabled {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = app.mcp().start_server().await {
+                        tracing::error!("mcp_server_start_failed: {}", e);
+                    }
+                });
+            }
+
+            Ok(())
+        })
         .build()
 }
 
@@ -39,7 +73,7 @@ mod test {
     fn export_types() {
         const OUTPUT_FILE: &str = "./js/bindings.gen.ts";
 
-        make_specta_builder::<tauri::Wry>()
+        make_specta_builder()
             .export(
                 specta_typescript::Typescript::default()
                     .formatter(specta_typescript::formatter::prettier)
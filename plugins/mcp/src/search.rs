@@ -0,0 +1,73 @@
+use hypr_vault::{SearchFuture, SearchMatch, SearchQuery, SessionSearch, Vault};
+use tauri_plugin_tantivy::{
+    CreatedAtFilter, SearchFilters, SearchOptions, SearchRequest, TantivyPluginExt,
+};
+
+const SNIPPET_MAX_CHARS: usize = 200;
+
+/// Keyword search over the app's index. Listing by date alone goes to the
+/// vault, since the index needs a query.
+pub struct TantivySearch<R: tauri::Runtime> {
+    app: tauri::AppHandle<R>,
+    vault: Vault,
+}
+
+impl<R: tauri::Runtime> TantivySearch<R> {
+    pub fn new(app: tauri::AppHandle<R>, vault: Vault) -> Self {
+        Self { app, vault }
+    }
+}
+
+impl<R: tauri::Runtime> SessionSearch for TantivySearch<R> {
+    fn search<'a>(&'a self, query: &'a SearchQuery) -> SearchFuture<'a> {
+        Box::pin(async move {
+            if query.query.is_empty() {
+                return self.vault.scan(query);
+            }
+
+            let request = SearchRequest {
+                query: query.query.clone(),
+                collection: None,
+                filters: SearchFilters {
+                    created_at: (query.since.is_some() || query.until.is_some()).then(|| {
+                        CreatedAtFilter {
+                            gte: query.since.map(|t| t.timestamp_millis()),
+                            lte: query.until.map(|t| t.timestamp_millis()),
+                            ..Default::default()
+                        }
+                    }),
+                    doc_type: Some("session".to_string()),
+                    facet: None,
+                },
+                limit: query.limit,
+                options: SearchOptions {
+                    snippets: Some(true),
+                    snippet_max_chars: Some(SNIPPET_MAX_CHARS),
+                    ..Default::default()
+                },
+                mode: Default::default(),
+                query_embedding: None,
+            };
+
+            let result = self
+                .app
+                .tantivy()
+                .search(request)
+                .await
+                .map_err(|e| hypr_vault::Error::Search(e.to_string()))?;
+
+            Ok(result
+                .hits
+                .into_iter()
+                .map(|hit| SearchMatch {
+                    session_id: hit.document.id,
+                    title: hit.document.title,
+                    created_at: chrono::DateTime::from_timestamp_millis(hit.document.created_at)
+                        .map(|dt| dt.to_rfc3339()),
+                    score: hit.score,
+                    snippet: hit.content_snippet.map(|snippet| snippet.fragment),
+                })
+                .collect::<Vec<SearchMatch>>())
+        })
+    }
+}
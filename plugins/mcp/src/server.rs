@@ -0,0 +1,218 @@
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+};
+use hypr_vault::mcp::VaultMcpServer;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, oneshot};
+
+use crate::types::McpServerInfo;
+
+pub const DEFAULT_PORT: u16 = 39217;
+
+/// Persisted so agents configured with the URL and token keep working
+/// across restarts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct McpConfig {
+    enabled: bool,
+    port: u16,
+    token: String,
+}
+
+impl Default for McpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_PORT,
+            token: uuid::Uuid::new_v4().simple().to_string(),
+        }
+    }
+}
+
+struct RunningServer {
+    shutdown: oneshot::Sender<()>,
+}
+
+pub struct McpState {
+    config_path: PathBuf,
+    running: Mutex<Option<RunningServer>>,
+}
+
+impl McpState {
+    pub fn new(config_path: PathBuf) -> Self {
+        Self {
+            config_path,
+            running: Mutex::new(None),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.load_config().map(|c| c.enabled).unwrap_or(false)
+    }
+
+    pub async fn info(&self) -> crate::Result<McpServerInfo> {
+        let running = self.running.lock().await.is_some();
+        Ok(server_info(&self.load_config()?, running))
+    }
+
+    /// Serves `/mcp` on the loopback interface. Requests need the bearer
+    /// token and a loopback `Host`, which keeps web pages from reaching the
+    /// server through DNS rebinding.
+    pub async fn start(&self, server: VaultMcpServer) -> crate::Result<McpServerInfo> {
+        let mut running = self.running.lock().await;
+        let mut config = self.load_config()?;
+        if running.is_some() {
+            return Ok(server_info(&config, true));
+        }
+
+        let listener =
+            tokio::net::TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, config.port)))
+                .await?;
+
+        let service = hypr_mcp::create_service(move || Ok(server.clone()));
+        let router =
+            Router::new()
+                .nest_service("/mcp", service)
+                .layer(middleware::from_fn_with_state(
+                    Arc::<str>::from(config.token.as_str()),
+                    authorize,
+                ));
+
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let result = axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    shutdown_rx.await.ok();
+                })
+                .await;
+            if let Err(e) = result {
+                tracing::error!("mcp_server_error: {}", e);
+            }
+        });
+
+        *running = Some(RunningServer { shutdown });
+        config.enabled = true;
+        self.save_config(&config)?;
+
+        Ok(server_info(&config, true))
+    }
+
+    pub async fn stop(&self) -> crate::Result<()> {
+        if let Some(server) = self.running.lock().await.take() {
+            let _ = server.shutdown.send(());
+        }
+
+        let mut config = self.load_config()?;
+        config.enabled = false;
+        self.save_config(&config)
+    }
+
+    fn load_config(&self) -> crate::Result<McpConfig> {
+        match std::fs::read_to_string(&self.config_path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let config = McpConfig::default();
+                self.save_config(&config)?;
+                Ok(config)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save_config(&self, config: &McpConfig) -> crate::Result<()> {
+        if let Some(parent) = self.config_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.config_path, serde_json::to_string_pretty(config)?)?;
+        Ok(())
+    }
+}
+
+fn server_info(config: &McpConfig, running: bool) -> McpServerInfo {
+    McpServerInfo {
+        running,
+        url: format!("http://127.0.0.1:{}/mcp", config.port),
+        token: config.token.clone(),
+    }
+}
+
+async fn authorize(State(token): State<Arc<str>>, request: Request, next: Next) -> Response {
+    if let Err(status) = check_request(&token, request.headers()) {
+        return status.into_response();
+    }
+
+    next.run(request).await
+}
+
+fn check_request(token: &str, headers: &HeaderMap) -> Result<(), StatusCode> {
+    let header_str = |name| headers.get(name).and_then(|value| value.to_str().ok());
+
+    if !header_str(header::HOST).is_some_and(is_loopback_host) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let expected = format!("Bearer {token}");
+    let authorized = header_str(header::AUTHORIZATION)
+        .is_some_and(|value| constant_time_eq(value.as_bytes(), expected.as_bytes()));
+    if !authorized {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(())
+}
+
+fn is_loopback_host(host: &str) -> bool {
+    let name = match host.rsplit_once(':') {
+        Some((name, port)) if port.chars().all(|c| c.is_ascii_digit()) => name,
+        _ => host,
+    };
+    matches!(name, "localhost" | "127.0.0.1" | "[::1]")
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loopback_hosts() {
+        assert!(is_loopback_host("127.0.0.1:39217"));
+        assert!(is_loopback_host("localhost"));
+        assert!(is_loopback_host("[::1]:39217"));
+        assert!(!is_loopback_host("evil.example.com:39217"));
+        assert!(!is_loopback_host("127.0.0.1.evil.example.com"));
+    }
+
+    #[test]
+    fn requires_token_and_loopback_host() {
+        let headers = |host: &str, auth: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::HOST, host.parse().unwrap());
+            headers.insert(header::AUTHORIZATION, auth.parse().unwrap());
+            headers
+        };
+
+        assert_eq!(
+            check_request("secret", &headers("127.0.0.1:39217", "Bearer secret")),
+            Ok(())
+        );
+        assert_eq!(
+            check_request("secret", &headers("127.0.0.1:39217", "Bearer wrong")),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(
+            check_request("secret", &headers("attacker.test", "Bearer secret")),
+            Err(StatusCode::FORBIDDEN)
+        );
+    }
+}
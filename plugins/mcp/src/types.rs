@@ -77,3 +77,10 @@ pub struct CreateBillingPortalSessionParams {
 pub struct CreateBillingPortalSessionOutput {
     pub url: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct McpServerInfo {
+    pub running: bool,
+    pub url: String,
+    pub token: String,
+}
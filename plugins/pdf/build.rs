@@ -1,4 +1,4 @@
-const COMMANDS: &[&str] = &["export"];
+const COMMANDS: &[&str] = &["export", "export_document"];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -13,6 +13,14 @@ async export(path: string, input: PdfInput) : Promise<Result<null, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async exportDocument(path: string, input: PdfInput, format: DocumentFormat) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:pdf|export_document", { path, input, format }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -26,10 +34,37 @@ async export(path: string, input: PdfInput) : Promise<Result<null, string>> {
 
 /** user-defined types **/
 
-export type PdfInput = { enhancedMd: string; transcript: Transcript | null; metadata: PdfMetadata | null }
-export type PdfMetadata = { title: string; createdAt: string; participants: string[]; eventTitle: string | null; duration: string | null }
+/**
+ * Formats other than PDF, written by `hypr_note_export`.
+ */
+export type DocumentFormat = "docx" | "html"
+export type PdfBranding = { 
+/**
+ * Shown in the page header of every page after the cover.
+ */
+companyName: string; 
+/**
+ * Hex colour such as `#0f766e`, used for headings, links and the header.
+ */
+accentColor: string | null }
+export type PdfInput = { enhancedMd: string; transcript: Transcript | null; metadata: PdfMetadata | null; 
+/**
+ * Resolves the note's inline images in DOCX and HTML exports.
+ */
+sessionId?: string | null; options?: PdfOptions }
+export type PdfMetadata = { title: string; createdAt: string; participants: PdfParticipant[]; eventTitle: string | null; duration: string | null }
+export type PdfOptions = { tableOfContents?: boolean; 
+/**
+ * Appends the open and done action items found in the notes.
+ */
+actionItems?: boolean; branding?: PdfBranding | null }
+export type PdfParticipant = { name: string; email: string | null; jobTitle: string | null; organization: string | null }
 export type Transcript = { items: TranscriptItem[] }
-export type TranscriptItem = { speaker: string | null; text: string }
+export type TranscriptItem = { speaker: string | null; text: string; 
+/**
+ * Offset from the start of the recording.
+ */
+startMs?: number | null; endMs?: number | null }
 
 /** tauri-specta globals **/
 
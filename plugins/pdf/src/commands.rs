@@ -11,3 +11,16 @@ pub(crate) async fn export<R: tauri::Runtime>(
 ) -> Result<(), String> {
     app.pdf().export(&path, input).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn export_document<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: PathBuf,
+    input: crate::PdfInput,
+    format: crate::DocumentFormat,
+) -> Result<(), String> {
+    app.pdf()
+        .export_document(&path, input, format)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,147 @@
+use std::path::Path;
+
+use hypr_note_export::{Document, Metadata, TranscriptSegment};
+
+use crate::PdfInput;
+
+/// Prefixes `convertFileSrc` gives the files the desktop shows in notes,
+/// followed by the percent-encoded absolute path.
+const ASSET_URL_PREFIXES: [&str; 3] = [
+    "asset://localhost/",
+    "http://asset.localhost/",
+    "https://asset.localhost/",
+];
+
+/// `asset_dir` is the session directory; images outside it are dropped.
+pub fn build_document(input: &PdfInput, asset_dir: Option<&Path>) -> Document {
+    let title = input
+        .metadata
+        .as_ref()
+        .map(|metadata| metadata.title.as_str())
+        .unwrap_or("Untitled");
+    let mut document = Document::new(title);
+
+    if let Some(metadata) = &input.metadata {
+        document = document.with_metadata(Metadata {
+            created_at: Some(metadata.created_at.clone()).filter(|s| !s.is_empty()),
+            event_title: metadata.event_title.clone(),
+            participants: metadata
+                .participants
+                .iter()
+                .map(|participant| participant.name.clone())
+                .collect(),
+            duration: metadata.duration.clone(),
+        });
+    }
+
+    if !input.enhanced_md.trim().is_empty() {
+        document = document.with_markdown_note(None, &asset_urls_to_file_urls(&input.enhanced_md));
+    }
+    if let Some(asset_dir) = asset_dir {
+        document = document.with_asset_dir(asset_dir);
+    }
+
+    if let Some(transcript) = &input.transcript {
+        document = document.with_transcript(
+            transcript
+                .items
+                .iter()
+                .map(|item| TranscriptSegment {
+                    speaker: item.speaker.clone(),
+                    text: item.text.clone(),
+                    start_ms: item.start_ms,
+                })
+                .collect(),
+        );
+    }
+
+    document
+}
+
+/// Keeps the path percent-encoded, so it stays a single Markdown destination.
+fn asset_urls_to_file_urls(md: &str) -> String {
+    ASSET_URL_PREFIXES
+        .iter()
+        .fold(md.to_string(), |md, prefix| md.replace(prefix, "file://"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PdfMetadata, PdfParticipant, Transcript, TranscriptItem};
+
+    #[test]
+    fn test_build_document() {
+        let input = PdfInput {
+            enhanced_md: "## Decisions\n\n- Ship it".to_string(),
+            transcript: Some(Transcript {
+                items: vec![TranscriptItem {
+                    speaker: Some("Alice".to_string()),
+                    text: "Let's ship it.".to_string(),
+                    start_ms: Some(65_000),
+                    end_ms: Some(67_000),
+                }],
+            }),
+            metadata: Some(PdfMetadata {
+                title: "Weekly sync".to_string(),
+                created_at: String::new(),
+                participants: vec![PdfParticipant {
+                    name: "Alice".to_string(),
+                    email: None,
+                    job_title: None,
+                    organization: None,
+                }],
+                event_title: Some("Team standup".to_string()),
+                duration: Some("30m".to_string()),
+            }),
+            session_id: None,
+            options: Default::default(),
+        };
+
+        let html = build_document(&input, None).to_html();
+        for needle in [
+            "<title>Weekly sync</title>",
+            "<p class=\"meta\">30m</p>",
+            "Team standup",
+            "Participants: Alice",
+            "<h2>Decisions</h2>",
+            "01:05",
+            "Let's ship it.",
+        ] {
+            assert!(html.contains(needle), "{needle:?} missing");
+        }
+    }
+
+    #[test]
+    fn test_build_document_asset_images() {
+        let dir = tempfile::tempdir().unwrap();
+        let attachments = dir.path().join("attachments");
+        std::fs::create_dir(&attachments).unwrap();
+        let image = attachments.join("my chart.png");
+        std::fs::write(&image, b"\x89PNG\r\n\x1a\n").unwrap();
+
+        let src = format!(
+            "asset://localhost/{}",
+            image
+                .to_str()
+                .unwrap()
+                .replace('/', "%2F")
+                .replace(' ', "%20")
+        );
+        let input = PdfInput {
+            enhanced_md: format!("![Chart]({src})"),
+            transcript: None,
+            metadata: None,
+            session_id: None,
+            options: Default::default(),
+        };
+
+        let html = build_document(&input, Some(dir.path())).to_html();
+        assert!(html.contains("<img src=\"data:image/png;base64,"));
+        assert!(
+            !build_document(&input, None)
+                .to_html()
+                .contains("data:image")
+        );
+    }
+}
@@ -10,6 +10,12 @@ pub enum Error {
     TypstCompile(String),
     #[error("Typst PDF error: {0}")]
     TypstPdf(String),
+    #[error(transparent)]
+    NoteExport(#[from] hypr_note_export::Error),
+    #[error(transparent)]
+    Settings(#[from] tauri_plugin_settings::Error),
+    #[error(transparent)]
+    Vault(#[from] hypr_vault::Error),
 }
 
 impl Serialize for Error {
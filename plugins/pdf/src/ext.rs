@@ -1,7 +1,9 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use tauri_plugin_settings::SettingsPluginExt;
 
 pub struct Pdf<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
-    _manager: &'a M,
+    manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
 
@@ -17,6 +19,38 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Pdf<'a, R, M> {
         std::fs::write(path.as_ref(), pdf_bytes)?;
         Ok(())
     }
+
+    /// The same input as [`Self::export`], as a Word document or a
+    /// standalone HTML page. PDF-only options are ignored.
+    pub fn export_document(
+        &self,
+        path: impl AsRef<Path>,
+        input: impl Into<crate::PdfInput>,
+        format: crate::DocumentFormat,
+    ) -> Result<(), crate::Error> {
+        let input = input.into();
+        let asset_dir = match &input.session_id {
+            Some(session_id) => self.session_dir(session_id)?,
+            None => None,
+        };
+        let document = crate::document::build_document(&input, asset_dir.as_deref());
+        let bytes = match format {
+            crate::DocumentFormat::Docx => document.to_docx()?,
+            crate::DocumentFormat::Html => document.to_html().into_bytes(),
+        };
+        std::fs::write(path.as_ref(), bytes)?;
+        Ok(())
+    }
+
+    /// `None` for a session that has not been written to the vault yet.
+    fn session_dir(&self, session_id: &str) -> Result<Option<PathBuf>, crate::Error> {
+        let base = self.manager.settings().fresh_vault_base()?;
+        match hypr_vault::Vault::new(base).session_dir(session_id) {
+            Ok(dir) => Ok(Some(dir)),
+            Err(hypr_vault::Error::SessionNotFound(_)) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
 
 pub trait PdfPluginExt<R: tauri::Runtime> {
@@ -31,7 +65,7 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> PdfPluginExt<R> for T {
         Self: Sized,
     {
         Pdf {
-            _manager: self,
+            manager: self,
             _runtime: std::marker::PhantomData,
         }
     }
@@ -1,4 +1,5 @@
 mod commands;
+mod document;
 mod error;
 mod ext;
 mod types;
@@ -15,6 +16,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .plugin_name(PLUGIN_NAME)
         .commands(tauri_specta::collect_commands![
             commands::export::<tauri::Wry>,
+            commands::export_document::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -70,9 +72,13 @@ mod test {
                         items: vec![TranscriptItem {
                             speaker: Some("Speaker 1".to_string()),
                             text: "Hello, world!".to_string(),
+                            start_ms: Some(0),
+                            end_ms: Some(1200),
                         }],
                     }),
                     metadata: None,
+                    session_id: None,
+                    options: PdfOptions::default(),
                 },
             )
             .unwrap();
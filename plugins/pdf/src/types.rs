@@ -6,6 +6,11 @@ use specta::Type;
 pub struct TranscriptItem {
     pub speaker: Option<String>,
     pub text: String,
+    /// Offset from the start of the recording.
+    #[serde(default)]
+    pub start_ms: Option<u64>,
+    #[serde(default)]
+    pub end_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
@@ -14,20 +19,63 @@ pub struct Transcript {
     pub items: Vec<TranscriptItem>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfParticipant {
+    pub name: String,
+    pub email: Option<String>,
+    pub job_title: Option<String>,
+    pub organization: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct PdfMetadata {
     pub title: String,
     pub created_at: String,
-    pub participants: Vec<String>,
+    pub participants: Vec<PdfParticipant>,
     pub event_title: Option<String>,
     pub duration: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfBranding {
+    /// Shown in the page header of every page after the cover.
+    pub company_name: String,
+    /// Hex colour such as `#0f766e`, used for headings, links and the header.
+    pub accent_color: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfOptions {
+    #[serde(default)]
+    pub table_of_contents: bool,
+    /// Appends the open and done action items found in the notes.
+    #[serde(default)]
+    pub action_items: bool,
+    #[serde(default)]
+    pub branding: Option<PdfBranding>,
+}
+
+/// Formats other than PDF, written by `hypr_note_export`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum DocumentFormat {
+    Docx,
+    Html,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct PdfInput {
     pub enhanced_md: String,
     pub transcript: Option<Transcript>,
     pub metadata: Option<PdfMetadata>,
+    /// Resolves the note's inline images in DOCX and HTML exports.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    #[serde(default)]
+    pub options: PdfOptions,
 }
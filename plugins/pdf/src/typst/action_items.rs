@@ -0,0 +1,152 @@
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+/// Headings whose list items are all treated as action items.
+const ACTION_HEADINGS: &[&str] = &[
+    "action item",
+    "next step",
+    "follow-up",
+    "follow up",
+    "todo",
+    "to-do",
+    "to do",
+    "tasks",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionItem {
+    pub text: String,
+    pub done: bool,
+    /// The heading the item was listed under.
+    pub section: Option<String>,
+}
+
+struct OpenItem {
+    text: String,
+    done: Option<bool>,
+    /// Where the item goes once complete; nested items finish first.
+    position: usize,
+}
+
+/// Task-list items (`- [ ]`, `- [x]`) anywhere in the notes, plus every list
+/// item under an "Action items"-like heading.
+pub fn extract_action_items(md: &str) -> Vec<ActionItem> {
+    let parser = Parser::new_ext(md, Options::ENABLE_TASKLISTS);
+
+    let mut items = Vec::new();
+    let mut open: Vec<OpenItem> = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut section: Option<String> = None;
+    let mut action_section: Option<HeadingLevel> = None;
+
+    for event in parser {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading = Some(String::new()),
+            Event::End(TagEnd::Heading(level)) => {
+                let text = heading.take().unwrap_or_default().trim().to_string();
+                if action_section.is_some_and(|section_level| level <= section_level) {
+                    action_section = None;
+                }
+                if is_action_heading(&text) {
+                    action_section = Some(level);
+                }
+                section = Some(text);
+            }
+
+            Event::Start(Tag::Item) => open.push(OpenItem {
+                text: String::new(),
+                done: None,
+                position: items.len(),
+            }),
+            Event::TaskListMarker(checked) => {
+                if let Some(item) = open.last_mut() {
+                    item.done = Some(checked);
+                }
+            }
+            Event::End(TagEnd::Item) => {
+                let Some(item) = open.pop() else { continue };
+                let text = item.text.split_whitespace().collect::<Vec<_>>().join(" ");
+                if text.is_empty() || (item.done.is_none() && action_section.is_none()) {
+                    continue;
+                }
+                items.insert(
+                    item.position,
+                    ActionItem {
+                        text,
+                        done: item.done.unwrap_or(false),
+                        section: section.clone(),
+                    },
+                );
+            }
+            // A nested list collects its own items; keep the parent's text
+            // apart from whatever follows it.
+            Event::Start(Tag::List(_)) => {
+                if let Some(item) = open.last_mut() {
+                    item.text.push(' ');
+                }
+            }
+
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = heading.as_mut() {
+                    heading.push_str(&text);
+                } else if let Some(item) = open.last_mut() {
+                    item.text.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some(item) = open.last_mut() {
+                    item.text.push(' ');
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    items
+}
+
+fn is_action_heading(text: &str) -> bool {
+    let text = text.to_lowercase();
+    ACTION_HEADINGS.iter().any(|keyword| text.contains(keyword))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_action_items() {
+        let md = "\
+# Summary
+
+- Budget approved
+- [x] Send the deck
+
+## Action Items
+
+- Alice to draft the **contract**
+  - Check pricing with legal
+- [ ] Book the venue
+
+## Risks
+
+- Timeline is tight
+";
+
+        let items = extract_action_items(md);
+        let texts: Vec<(&str, bool, Option<&str>)> = items
+            .iter()
+            .map(|item| (item.text.as_str(), item.done, item.section.as_deref()))
+            .collect();
+
+        assert_eq!(
+            texts,
+            vec![
+                ("Send the deck", true, Some("Summary")),
+                ("Alice to draft the contract", false, Some("Action Items")),
+                ("Check pricing with legal", false, Some("Action Items")),
+                ("Book the venue", false, Some("Action Items")),
+            ]
+        );
+    }
+}
@@ -1,11 +1,15 @@
-use crate::PdfInput;
+use crate::{PdfBranding, PdfInput};
 
+use super::action_items::{ActionItem, extract_action_items};
 use super::markdown::markdown_to_typst;
+use super::transcript::{build_attendance, build_transcript};
 use super::utils::escape_typst_string;
 
-fn build_preamble() -> String {
-    r##"
-#let accent-color = rgb("#2563eb")
+const DEFAULT_ACCENT_COLOR: &str = "#2563eb";
+
+fn build_preamble(accent_color: &str) -> String {
+    let preamble = r##"
+#let accent-color = rgb("ACCENT_COLOR")
 #let muted-color = rgb("#6b7280")
 #let light-bg = rgb("#f8fafc")
 
@@ -52,14 +56,57 @@ fn build_preamble() -> String {
   it.body
 )
 
-"##
-    .to_string()
+"##;
+
+    preamble.replace("ACCENT_COLOR", accent_color)
+}
+
+/// `#rgb` or `#rrggbb`; anything else would break compilation.
+fn is_hex_color(color: &str) -> bool {
+    color
+        .strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
 }
 
+/// Company name on the left, document title on the right, on every page
+/// that follows.
+fn build_page_header(branding: &PdfBranding, title: Option<&str>) -> String {
+    format!(
+        "#set page(header: [\n  #set text(size: 9pt, fill: muted-color)\n  #text(weight: \"semibold\", fill: accent-color)[{}] #h(1fr) {}\n  #v(-0.6em)\n  #line(length: 100%, stroke: 0.5pt + rgb(\"#e5e7eb\"))\n])\n\n",
+        escape_typst_string(&branding.company_name),
+        escape_typst_string(title.unwrap_or_default()),
+    )
+}
+
+fn build_action_items(items: &[ActionItem]) -> String {
+    let mut content = String::from("= Appendix: Action items\n\n");
+    content.push_str("#table(\n  columns: (auto, 1fr, auto),\n");
+    content.push_str(
+        "  stroke: (x, y) => if y == 0 { (bottom: 0.5pt + muted-color) } else { none },\n  inset: 8pt,\n",
+    );
+    content.push_str("  table.header([*Status*], [*Item*], [*From*]),\n");
+
+    for item in items {
+        let text = escape_typst_string(&item.text);
+        let (status, text) = if item.done {
+            ("Done", format!("#text(fill: muted-color)[{text}]"))
+        } else {
+            ("Open", text)
+        };
+        content.push_str(&format!(
+            "  [{status}], [{text}], [#text(fill: muted-color)[{}]],\n",
+            escape_typst_string(item.section.as_deref().unwrap_or_default())
+        ));
+    }
+    content.push_str(")\n");
+
+    content
+}
+
+/// Participants are left to the attendance table that follows.
 fn build_cover_page(
     title: &str,
     created_at: &str,
-    participants: &[String],
     event_title: Option<&str>,
     duration: Option<&str>,
 ) -> String {
@@ -103,21 +150,6 @@ fn build_cover_page(
         cover.push_str("    #v(1em)\n");
     }
 
-    if !participants.is_empty() {
-        cover.push_str("    #block(fill: light-bg, inset: 12pt, radius: 6pt, width: 80%)[\n");
-        cover.push_str(
-            "      #text(size: 11pt, fill: muted-color)[Participants:]\n      #v(0.5em)\n",
-        );
-        for participant in participants {
-            let escaped_participant = escape_typst_string(participant);
-            cover.push_str(&format!(
-                "      #text(size: 11pt)[#sym.bullet {}]\n      #v(0.3em)\n",
-                escaped_participant
-            ));
-        }
-        cover.push_str("    ]\n");
-    }
-
     cover.push_str("    #v(1fr)\n");
 
     cover.push_str("    #text(size: 10pt, fill: muted-color)[Exported from Char]\n");
@@ -129,42 +161,135 @@ fn build_cover_page(
 }
 
 pub fn build_typst_content(input: &PdfInput) -> String {
-    let mut content = build_preamble();
+    let options = &input.options;
+    let accent_color = options
+        .branding
+        .as_ref()
+        .and_then(|branding| branding.accent_color.as_deref())
+        .filter(|color| is_hex_color(color))
+        .unwrap_or(DEFAULT_ACCENT_COLOR);
+    let mut content = build_preamble(accent_color);
 
     if let Some(metadata) = &input.metadata {
         let cover = build_cover_page(
             &metadata.title,
             &metadata.created_at,
-            &metadata.participants,
             metadata.event_title.as_deref(),
             metadata.duration.as_deref(),
         );
         content.push_str(&cover);
     }
 
+    if let Some(branding) = &options.branding {
+        let title = input.metadata.as_ref().map(|m| m.title.as_str());
+        content.push_str(&build_page_header(branding, title));
+    }
+
+    if options.table_of_contents {
+        content.push_str("#outline(title: [Contents], depth: 2)\n\n#pagebreak(weak: true)\n\n");
+    }
+
+    let transcript = input
+        .transcript
+        .as_ref()
+        .filter(|transcript| !transcript.items.is_empty());
+    let participants = input
+        .metadata
+        .as_ref()
+        .map(|m| m.participants.as_slice())
+        .unwrap_or_default();
+    let attendance = build_attendance(participants, transcript);
+    content.push_str(&attendance);
+
     let typst_content = markdown_to_typst(&input.enhanced_md);
     if !typst_content.trim().is_empty() {
         content.push_str(&typst_content);
     }
 
-    if let Some(transcript) = &input.transcript
-        && !transcript.items.is_empty()
-    {
-        if input.metadata.is_some() || !input.enhanced_md.trim().is_empty() {
-            content.push_str("\n#pagebreak()\n\n");
+    if let Some(transcript) = transcript {
+        if input.metadata.is_some() || !attendance.is_empty() || !typst_content.trim().is_empty() {
+            content.push_str("\n#pagebreak(weak: true)\n\n");
         }
-        content.push_str("= Transcript\n\n");
-
-        for item in &transcript.items {
-            let speaker = item.speaker.as_deref().unwrap_or("Unknown");
-            let escaped_speaker = escape_typst_string(speaker);
-            let escaped_text = escape_typst_string(&item.text);
-            content.push_str(&format!(
-                "#block(fill: light-bg, inset: 10pt, radius: 4pt, width: 100%, spacing: 0.8em)[#text(weight: \"semibold\", fill: accent-color)[{}:] {}]\n",
-                escaped_speaker, escaped_text
-            ));
+        content.push_str(&build_transcript(transcript));
+    }
+
+    if options.action_items {
+        let items = extract_action_items(&input.enhanced_md);
+        if !items.is_empty() {
+            content.push_str("\n#pagebreak(weak: true)\n\n");
+            content.push_str(&build_action_items(&items));
         }
     }
 
     content
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::typst::compile_to_pdf;
+    use crate::{PdfMetadata, PdfOptions, PdfParticipant, Transcript, TranscriptItem};
+
+    #[test]
+    fn test_compiles_with_all_options() {
+        let input = PdfInput {
+            enhanced_md: "# Summary\n\nBudget *approved*.\n\n## Action Items\n\n- [x] Send the deck\n- [ ] Book the venue @ 5$\n".to_string(),
+            transcript: Some(Transcript {
+                items: vec![
+                    TranscriptItem {
+                        speaker: Some("Alice".to_string()),
+                        text: "Hello [all] #1".to_string(),
+                        start_ms: Some(0),
+                        end_ms: Some(30_000),
+                    },
+                    TranscriptItem {
+                        speaker: None,
+                        text: "Hi".to_string(),
+                        start_ms: None,
+                        end_ms: None,
+                    },
+                ],
+            }),
+            metadata: Some(PdfMetadata {
+                title: "Kickoff".to_string(),
+                created_at: "Tuesday".to_string(),
+                participants: vec![
+                    PdfParticipant {
+                        name: "Alice".to_string(),
+                        email: None,
+                        job_title: Some("PM".to_string()),
+                        organization: Some("Acme".to_string()),
+                    },
+                    PdfParticipant {
+                        name: "Carol".to_string(),
+                        email: None,
+                        job_title: None,
+                        organization: None,
+                    },
+                ],
+                event_title: Some("Weekly".to_string()),
+                duration: Some("40m".to_string()),
+            }),
+            session_id: None,
+            options: PdfOptions {
+                table_of_contents: true,
+                action_items: true,
+                branding: Some(PdfBranding {
+                    company_name: "Acme Corp".to_string(),
+                    accent_color: Some("#0f766e".to_string()),
+                }),
+            },
+        };
+
+        let content = build_typst_content(&input);
+        assert!(content.contains("#outline(title: [Contents]"));
+        assert!(content.contains("rgb(\"#0f766e\")"));
+        assert!(content.contains("[Acme Corp]"));
+        assert!(content.contains("= Appendix: Action items"));
+        // Listed once, in the attendance table rather than on the cover too.
+        assert_eq!(content.matches("Carol").count(), 1);
+
+        let pdf = compile_to_pdf(&content).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+}
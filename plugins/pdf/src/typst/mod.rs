@@ -1,6 +1,8 @@
+mod action_items;
 mod compile;
 mod content;
 mod markdown;
+mod transcript;
 mod utils;
 mod world;
 
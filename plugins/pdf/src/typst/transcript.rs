@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use hypr_note_export::format_timestamp;
+
+use crate::{PdfParticipant, Transcript};
+
+use super::utils::escape_typst_string;
+
+const UNKNOWN_SPEAKER: &str = "Unknown";
+
+fn speaker_colors(transcript: &Transcript) -> HashMap<&str, &'static str> {
+    hypr_note_export::speaker_colors(
+        transcript
+            .items
+            .iter()
+            .map(|item| item.speaker.as_deref().unwrap_or(UNKNOWN_SPEAKER)),
+    )
+}
+
+/// Time each speaker held the floor, in order of first appearance. Items
+/// without both timestamps don't count.
+fn speaking_time(transcript: &Transcript) -> Vec<(&str, u64)> {
+    let mut totals: Vec<(&str, u64)> = Vec::new();
+    for item in &transcript.items {
+        let speaker = item.speaker.as_deref().unwrap_or(UNKNOWN_SPEAKER);
+        let duration = match (item.start_ms, item.end_ms) {
+            (Some(start), Some(end)) => end.saturating_sub(start),
+            _ => 0,
+        };
+        match totals.iter_mut().find(|(name, _)| *name == speaker) {
+            Some((_, total)) => *total += duration,
+            None => totals.push((speaker, duration)),
+        }
+    }
+    totals
+}
+
+pub fn build_transcript(transcript: &Transcript) -> String {
+    let colors = speaker_colors(transcript);
+    let mut content = String::from("= Transcript\n\n");
+
+    for item in &transcript.items {
+        let speaker = item.speaker.as_deref().unwrap_or(UNKNOWN_SPEAKER);
+        let color = colors[speaker];
+
+        let timestamp = item
+            .start_ms
+            .map(|ms| {
+                format!(
+                    " #h(0.6em) #text(size: 9pt, fill: muted-color)[{}]",
+                    format_timestamp(ms)
+                )
+            })
+            .unwrap_or_default();
+
+        content.push_str(&format!(
+            "#block(fill: light-bg, stroke: (left: 2pt + rgb(\"{color}\")), inset: 10pt, radius: 4pt, width: 100%, spacing: 0.8em, breakable: true)[#text(weight: \"semibold\", fill: rgb(\"{color}\"))[{}]{timestamp} #linebreak() {}]\n",
+            escape_typst_string(speaker),
+            escape_typst_string(&item.text),
+        ));
+    }
+
+    content
+}
+
+/// One row per participant, followed by transcript speakers who aren't on
+/// the invite. Speaking time is only shown when the transcript has timing.
+pub fn build_attendance(
+    participants: &[PdfParticipant],
+    transcript: Option<&Transcript>,
+) -> String {
+    let spoken = transcript.map(speaking_time).unwrap_or_default();
+    let total: u64 = spoken.iter().map(|(_, ms)| ms).sum();
+    let show_time = total > 0;
+
+    let mut rows: Vec<(String, String, Option<u64>)> = participants
+        .iter()
+        .map(|participant| {
+            let role = [
+                participant.job_title.as_deref(),
+                participant.organization.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ");
+            let ms = spoken
+                .iter()
+                .find(|(speaker, _)| *speaker == participant.name)
+                .map(|(_, ms)| *ms);
+            (participant.name.clone(), role, ms)
+        })
+        .collect();
+    for (speaker, ms) in &spoken {
+        if !participants.iter().any(|p| p.name == *speaker) {
+            rows.push((speaker.to_string(), String::new(), Some(*ms)));
+        }
+    }
+
+    if rows.is_empty() {
+        return String::new();
+    }
+
+    let mut content = String::from("= Attendance\n\n");
+    if show_time {
+        content.push_str("#table(\n  columns: (1fr, 1fr, auto),\n");
+    } else {
+        content.push_str("#table(\n  columns: (1fr, 1fr),\n");
+    }
+    content.push_str(
+        "  stroke: (x, y) => if y == 0 { (bottom: 0.5pt + muted-color) } else { none },\n  inset: 8pt,\n",
+    );
+    content.push_str("  table.header([*Name*], [*Role*]");
+    if show_time {
+        content.push_str(", [*Speaking time*]");
+    }
+    content.push_str("),\n");
+
+    for (name, role, ms) in rows {
+        content.push_str(&format!(
+            "  [{}], [{}]",
+            escape_typst_string(&name),
+            escape_typst_string(&role)
+        ));
+        if show_time {
+            let time = match ms {
+                Some(ms) if ms > 0 => format!(
+                    "{} ({}%)",
+                    format_timestamp(ms),
+                    (ms * 100 + total / 2) / total
+                ),
+                _ => "-".to_string(),
+            };
+            content.push_str(&format!(", [{time}]"));
+        }
+        content.push_str(",\n");
+    }
+    content.push_str(")\n\n");
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use hypr_note_export::SPEAKER_COLORS;
+
+    use super::*;
+    use crate::TranscriptItem;
+
+    fn item(speaker: &str, start_ms: u64, end_ms: u64) -> TranscriptItem {
+        TranscriptItem {
+            speaker: Some(speaker.to_string()),
+            text: "...".to_string(),
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        }
+    }
+
+    #[test]
+    fn test_speaking_time_and_colors() {
+        let transcript = Transcript {
+            items: vec![
+                item("Alice", 0, 30_000),
+                item("Bob", 30_000, 40_000),
+                item("Alice", 40_000, 3_700_000),
+            ],
+        };
+
+        assert_eq!(
+            speaking_time(&transcript),
+            vec![("Alice", 3_690_000), ("Bob", 10_000)]
+        );
+
+        let colors = speaker_colors(&transcript);
+        assert_eq!(colors["Alice"], SPEAKER_COLORS[0]);
+        assert_eq!(colors["Bob"], SPEAKER_COLORS[1]);
+
+        assert_eq!(format_timestamp(65_000), "01:05");
+        assert_eq!(format_timestamp(3_690_000), "1:01:30");
+    }
+}
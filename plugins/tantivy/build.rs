@@ -5,6 +5,11 @@ const COMMANDS: &[&str] = &[
     "update_document",
     "update_documents",
     "remove_document",
+    "load_embedding_model",
+    "is_embedding_model_downloaded",
+    "download_embedding_model",
+    "index_transcript",
+    "remove_transcript",
 ];
 
 fn main() {
@@ -53,6 +53,46 @@ async removeDocument(id: string, collection: string | null) : Promise<Result<nul
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async loadEmbeddingModel(modelPath: string, tokenizerPath: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:tantivy|load_embedding_model", { modelPath, tokenizerPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async isEmbeddingModelDownloaded() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:tantivy|is_embedding_model_downloaded") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async downloadEmbeddingModel(channel: TAURI_CHANNEL<number>) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:tantivy|download_embedding_model", { channel }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async indexTranscript(sessionId: string, title: string, createdAt: number, words: TranscriptWord[], collection: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:tantivy|index_transcript", { sessionId, title, createdAt, words, collection }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async removeTranscript(sessionId: string, collection: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:tantivy|remove_transcript", { sessionId, collection }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -68,13 +108,27 @@ async removeDocument(id: string, collection: string | null) : Promise<Result<nul
 
 export type CreatedAtFilter = { gte: number | null; lte: number | null; gt: number | null; lt: number | null; eq: number | null }
 export type HighlightRange = { start: number; end: number }
-export type SearchDocument = { id: string; doc_type: string; language: string | null; title: string; content: string; created_at: number; facets?: string[] }
+export type SearchDocument = { id: string; doc_type: string; language: string | null; title: string; content: string; created_at: number; facets?: string[]; /**
+ * One embedding per content chunk. Computed on indexing when empty and
+ * an embedding model is loaded.
+ */
+embeddings?: number[][] }
 export type SearchFilters = { created_at: CreatedAtFilter | null; doc_type: string | null; facet: string | null }
-export type SearchHit = { score: number; document: SearchDocument; title_snippet: Snippet | null; content_snippet: Snippet | null }
-export type SearchOptions = { fuzzy: boolean | null; distance: number | null; snippets: boolean | null; snippet_max_chars: number | null; phrase_slop: number | null }
-export type SearchRequest = { query: string; collection?: string | null; filters?: SearchFilters; limit?: number; options?: SearchOptions }
+export type SearchHit = { score: number; document: SearchDocument; title_snippet: Snippet | null; content_snippet: Snippet | null; segments?: SegmentMatch[] }
+/**
+ * `hybrid` fuses keyword and vector rankings with reciprocal rank fusion.
+ */
+export type SearchMode = "keyword" | "vector" | "hybrid"
+export type SearchOptions = { fuzzy: boolean | null; distance: number | null; snippets: boolean | null; snippet_max_chars: number | null; phrase_slop: number | null; group_segments?: boolean | null }
+export type SearchRequest = { query: string; collection?: string | null; filters?: SearchFilters; limit?: number; options?: SearchOptions; mode?: SearchMode; query_/**
+ * One embedding per content chunk. Computed on indexing when empty and
+ * an embedding model is loaded.
+ */
+embeddings?: number[][] }
 export type SearchResult = { hits: SearchHit[]; count: number }
+export type SegmentMatch = { session_id: string; start_ms: number; end_ms: number; speaker: string | null }
 export type Snippet = { fragment: string; highlights: HighlightRange[] }
+export type TranscriptWord = { text: string; start_ms: number; end_ms: number; channel?: number; speaker?: string | null }
 
 /** tauri-specta globals **/
 
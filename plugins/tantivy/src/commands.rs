@@ -1,4 +1,4 @@
-use crate::{SearchDocument, SearchRequest, SearchResult, TantivyPluginExt};
+use crate::{SearchDocument, SearchRequest, SearchResult, TantivyPluginExt, TranscriptWord};
 
 #[tauri::command]
 #[specta::specta]
@@ -75,3 +75,67 @@ pub(crate) async fn remove_document<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn load_embedding_model<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    model_path: String,
+    tokenizer_path: String,
+) -> Result<(), String> {
+    app.tantivy()
+        .load_embedding_model(model_path, tokenizer_path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn is_embedding_model_downloaded<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<bool, String> {
+    app.tantivy()
+        .is_embedding_model_downloaded()
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn download_embedding_model<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    channel: tauri::ipc::Channel<i8>,
+) -> Result<(), String> {
+    app.tantivy()
+        .download_embedding_model(channel)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn index_transcript<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    title: String,
+    created_at: i64,
+    words: Vec<TranscriptWord>,
+    collection: Option<String>,
+) -> Result<(), String> {
+    app.tantivy()
+        .index_transcript(collection, session_id, title, created_at, words)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn remove_transcript<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    collection: Option<String>,
+) -> Result<(), String> {
+    app.tantivy()
+        .remove_transcript(collection, session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
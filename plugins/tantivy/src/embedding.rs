@@ -0,0 +1,51 @@
+use std::path::{Path, PathBuf};
+
+/// Multilingual sentence embedding model, matching the languages the search
+/// tokenizers cover.
+const MODEL_BASE_URL: &str = "https://huggingface.co/sentence-transformers/paraphrase-multilingual-MiniLM-L12-v2/resolve/main";
+
+pub const MODEL_FILE: &str = "model.onnx";
+pub const TOKENIZER_FILE: &str = "tokenizer.json";
+
+/// Files fetched by `download_embedding_model`, smallest first.
+pub const MODEL_FILES: [(&str, &str); 2] = [
+    (TOKENIZER_FILE, "tokenizer.json"),
+    (MODEL_FILE, "onnx/model.onnx"),
+];
+
+pub fn model_dir(global_base: &Path) -> PathBuf {
+    global_base.join("models").join("embedding")
+}
+
+pub fn model_url(remote_path: &str) -> String {
+    format!("{MODEL_BASE_URL}/{remote_path}")
+}
+
+pub fn is_downloaded(dir: &Path) -> bool {
+    MODEL_FILES
+        .iter()
+        .all(|(file, _)| std::fs::metadata(dir.join(file)).is_ok_and(|metadata| metadata.len() > 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_downloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!is_downloaded(dir.path()));
+
+        std::fs::write(dir.path().join(TOKENIZER_FILE), "{}").unwrap();
+        std::fs::write(dir.path().join(MODEL_FILE), "").unwrap();
+        assert!(!is_downloaded(dir.path()));
+
+        std::fs::write(dir.path().join(MODEL_FILE), "onnx").unwrap();
+        assert!(is_downloaded(dir.path()));
+    }
+
+    #[test]
+    fn test_model_url() {
+        assert!(model_url("onnx/model.onnx").ends_with("/resolve/main/onnx/model.onnx"));
+    }
+}
@@ -14,6 +14,10 @@ pub enum Error {
     Tauri(#[from] tauri::Error),
     #[error(transparent)]
     Settings(#[from] tauri_plugin_settings::Error),
+    #[error(transparent)]
+    Embedding(#[from] hypr_embedding_local::Error),
+    #[error(transparent)]
+    Download(#[from] hypr_file::Error),
     #[error("Index not initialized")]
     IndexNotInitialized,
     #[error("Collection not found: {0}")]
@@ -22,6 +26,8 @@ pub enum Error {
     DocumentNotFound(String),
     #[error("Invalid document type: {0}")]
     InvalidDocumentType(String),
+    #[error("Embedding model not loaded")]
+    EmbeddingModelNotLoaded,
 }
 
 impl Serialize for Error {
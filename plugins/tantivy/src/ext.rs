@@ -1,20 +1,35 @@
-use tantivy::collector::{Count, TopDocs};
+use hypr_download_interface::DownloadProgress;
+use hypr_embedding_local::TextEmbedder;
+use simsimd::SpatialSimilarity;
+use tantivy::collector::{Count, DocSetCollector, TopDocs};
 use tantivy::query::{
-    BooleanQuery, BoostQuery, FuzzyTermQuery, Occur, PhraseQuery, Query, QueryParser, TermQuery,
+    AllQuery, BooleanQuery, BoostQuery, FuzzyTermQuery, Occur, PhraseQuery, Query, QueryParser,
+    TermQuery,
 };
-use tantivy::schema::{Facet, IndexRecordOption};
+use tantivy::schema::{Facet, Field, IndexRecordOption, Value};
 use tantivy::snippet::SnippetGenerator;
-use tantivy::{Index, ReloadPolicy, TantivyDocument, Term};
+use tantivy::{DocAddress, Index, ReloadPolicy, Searcher, TantivyDocument, Term};
 use tauri_plugin_settings::SettingsPluginExt;
 
+use crate::embedding;
 use crate::query::build_created_at_range_query;
-use crate::schema::{extract_search_document, get_fields};
+use crate::schema::{
+    build_segment_document, build_tantivy_document, extract_search_document, extract_segment_match,
+    get_fields,
+};
+use crate::segments::{
+    TRANSCRIPT_SEGMENT_DOC_TYPE, build_transcript_segments, group_segment_hits, segment_document_id,
+};
 use crate::tokenizer::register_tokenizers;
+use crate::vector::{decode_embedding, reciprocal_rank_fusion};
 use crate::{
     CollectionConfig, CollectionIndex, HighlightRange, IndexState, SearchDocument, SearchHit,
-    SearchRequest, SearchResult, Snippet,
+    SearchMode, SearchRequest, SearchResult, Snippet, TranscriptWord,
 };
 
+/// Number of candidates taken from each ranking before hybrid fusion.
+const HYBRID_CANDIDATES: usize = 100;
+
 pub fn detect_language(text: &str) -> hypr_language::Language {
     hypr_language::detect(text)
 }
@@ -151,7 +166,213 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
         collection.unwrap_or_else(|| "default".to_string())
     }
 
+    pub async fn load_embedding_model(
+        &self,
+        model_path: impl AsRef<std::path::Path>,
+        tokenizer_path: impl AsRef<std::path::Path>,
+    ) -> Result<(), crate::Error> {
+        let state = self.manager.state::<IndexState>();
+        let _loading = state.embedder_loading.write().await;
+        self.load_embedder(
+            model_path.as_ref().to_path_buf(),
+            tokenizer_path.as_ref().to_path_buf(),
+        )
+        .await
+    }
+
+    pub fn embedding_model_dir(&self) -> Result<std::path::PathBuf, crate::Error> {
+        let base = self.manager.app_handle().settings().global_base()?;
+        Ok(embedding::model_dir(base.as_std_path()))
+    }
+
+    pub fn is_embedding_model_downloaded(&self) -> Result<bool, crate::Error> {
+        Ok(embedding::is_downloaded(&self.embedding_model_dir()?))
+    }
+
+    /// Loads the downloaded model, if any. Embedding waits until this is done,
+    /// so documents indexed meanwhile still get embeddings.
+    pub(crate) async fn load_downloaded_embedding_model(
+        &self,
+        _loading: tokio::sync::OwnedRwLockWriteGuard<()>,
+    ) -> Result<(), crate::Error> {
+        let dir = self.embedding_model_dir()?;
+        if !embedding::is_downloaded(&dir) {
+            return Ok(());
+        }
+        self.load_embedder(
+            dir.join(embedding::MODEL_FILE),
+            dir.join(embedding::TOKENIZER_FILE),
+        )
+        .await
+    }
+
+    /// Downloads the embedding model into the app data directory and loads it.
+    /// Progress is reported in percent, -1 on failure.
+    pub async fn download_embedding_model(
+        &self,
+        channel: tauri::ipc::Channel<i8>,
+    ) -> Result<(), crate::Error> {
+        let dir = self.embedding_model_dir()?;
+
+        for (file, remote_path) in embedding::MODEL_FILES {
+            let path = dir.join(file);
+            if path.exists() {
+                continue;
+            }
+
+            let partial = dir.join(format!("{file}.part"));
+            let last_progress = std::sync::Mutex::new(0i8);
+            let report = file == embedding::MODEL_FILE;
+            let callback = |progress: DownloadProgress| {
+                if !report {
+                    return;
+                }
+                let current = match progress {
+                    DownloadProgress::Started => 0,
+                    DownloadProgress::Progress(downloaded, total) => {
+                        (downloaded as f64 / total.max(1) as f64 * 100.0) as i8
+                    }
+                    DownloadProgress::Finished => 100,
+                };
+                let mut last = last_progress.lock().unwrap_or_else(|e| e.into_inner());
+                if current != *last {
+                    *last = current;
+                    let _ = channel.send(current);
+                }
+            };
+
+            if let Err(e) = hypr_file::download_file_parallel(
+                embedding::model_url(remote_path),
+                &partial,
+                callback,
+            )
+            .await
+            {
+                let _ = channel.send(-1);
+                return Err(e.into());
+            }
+            std::fs::rename(&partial, &path)?;
+        }
+
+        let state = self.manager.state::<IndexState>();
+        let loading = state.embedder_loading.clone().write_owned().await;
+        self.load_downloaded_embedding_model(loading).await
+    }
+
+    async fn load_embedder(
+        &self,
+        model_path: std::path::PathBuf,
+        tokenizer_path: std::path::PathBuf,
+    ) -> Result<(), crate::Error> {
+        let embedder = tauri::async_runtime::spawn_blocking(move || {
+            TextEmbedder::from_files(model_path, tokenizer_path)
+        })
+        .await??;
+
+        let state = self.manager.state::<IndexState>();
+        *state.embedder.lock().unwrap_or_else(|e| e.into_inner()) = Some(embedder);
+
+        tracing::info!("Tantivy embedding model loaded");
+        Ok(())
+    }
+
+    /// Embeds `texts` on a blocking thread. Returns `None` if no model is loaded.
+    async fn embed(&self, texts: Vec<String>) -> Result<Option<Vec<Vec<f32>>>, crate::Error> {
+        let state = self.manager.state::<IndexState>();
+        let _loading = state.embedder_loading.read().await;
+        let embedder = state.embedder.clone();
+
+        tauri::async_runtime::spawn_blocking(move || -> Result<_, crate::Error> {
+            let mut guard = embedder.lock().unwrap_or_else(|e| e.into_inner());
+            let Some(embedder) = guard.as_mut() else {
+                return Ok(None);
+            };
+            let texts: Vec<&str> = texts.iter().map(String::as_str).collect();
+            Ok(Some(embedder.embed(&texts)?))
+        })
+        .await?
+    }
+
+    /// Embeds each text in overlapping chunks on a blocking thread. Returns
+    /// `None` if no model is loaded.
+    async fn embed_chunks(
+        &self,
+        texts: Vec<String>,
+    ) -> Result<Option<Vec<Vec<Vec<f32>>>>, crate::Error> {
+        let state = self.manager.state::<IndexState>();
+        let _loading = state.embedder_loading.read().await;
+        let embedder = state.embedder.clone();
+
+        tauri::async_runtime::spawn_blocking(move || -> Result<_, crate::Error> {
+            let mut guard = embedder.lock().unwrap_or_else(|e| e.into_inner());
+            let Some(embedder) = guard.as_mut() else {
+                return Ok(None);
+            };
+            let chunks = texts
+                .iter()
+                .map(|text| embedder.embed_chunks(text))
+                .collect::<Result<_, _>>()?;
+            Ok(Some(chunks))
+        })
+        .await?
+    }
+
+    async fn fill_missing_embeddings(
+        &self,
+        documents: &mut [SearchDocument],
+    ) -> Result<(), crate::Error> {
+        let missing: Vec<usize> = documents
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.embeddings.is_empty())
+            .map(|(i, _)| i)
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        let texts = missing
+            .iter()
+            .map(|&i| format!("{}\n\n{}", documents[i].title, documents[i].content))
+            .collect();
+
+        if let Some(embeddings) = self.embed_chunks(texts).await? {
+            for (i, embeddings) in missing.into_iter().zip(embeddings) {
+                documents[i].embeddings = embeddings;
+            }
+        }
+        Ok(())
+    }
+
+    async fn query_embedding(
+        &self,
+        request: &SearchRequest,
+    ) -> Result<Option<Vec<f32>>, crate::Error> {
+        if request.mode == SearchMode::Keyword {
+            return Ok(None);
+        }
+        if let Some(embedding) = &request.query_embedding {
+            return Ok(Some(embedding.clone()));
+        }
+
+        let embedding = self
+            .embed(vec![request.query.clone()])
+            .await?
+            .and_then(|mut embeddings| embeddings.pop());
+
+        match (request.mode, embedding) {
+            (SearchMode::Vector, None) => Err(crate::Error::EmbeddingModelNotLoaded),
+            (SearchMode::Hybrid, None) => {
+                tracing::warn!("No embedding model loaded, falling back to keyword search");
+                Ok(None)
+            }
+            (_, embedding) => Ok(embedding),
+        }
+    }
+
     pub async fn search(&self, request: SearchRequest) -> Result<SearchResult, crate::Error> {
+        let query_embedding = self.query_embedding(&request).await?;
+
         let collection_name = Self::get_collection_name(request.collection);
         let state = self.manager.state::<IndexState>();
         let guard = state.inner.read().await;
@@ -174,7 +395,7 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
         // Title boost factor (3x) to match Orama's title:3, content:1 behavior
         const TITLE_BOOST: f32 = 3.0;
 
-        let mut combined_query: Box<dyn Query> = if use_fuzzy {
+        let text_query: Result<Box<dyn Query>, crate::Error> = if use_fuzzy {
             let distance = request.options.distance.unwrap_or(1);
 
             // Parse query to extract phrases (quoted) and regular terms
@@ -266,31 +487,43 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
                 term_queries.push((Occur::Must, Box::new(term_field_query)));
             }
 
-            Box::new(BooleanQuery::new(term_queries))
+            Ok(Box::new(BooleanQuery::new(term_queries)))
         } else {
             let query_parser = QueryParser::for_index(index, vec![fields.title, fields.content]);
-            query_parser.parse_query(&request.query)?
+            query_parser.parse_query(&request.query).map_err(Into::into)
+        };
+
+        // Vector search only needs the text query for snippets
+        let text_query = match text_query {
+            Ok(query) => Some(query),
+            Err(_) if query_embedding.is_some() && request.mode == SearchMode::Vector => None,
+            Err(e) => return Err(e),
         };
 
+        let mut filter_queries: Vec<Box<dyn Query>> = Vec::new();
+
         // Apply created_at filter
-        if let Some(ref created_at_filter) = request.filters.created_at {
-            let range_query = build_created_at_range_query(fields.created_at, created_at_filter);
-            if let Some(rq) = range_query {
-                combined_query = Box::new(BooleanQuery::new(vec![
-                    (Occur::Must, combined_query),
-                    (Occur::Must, rq),
-                ]));
-            }
+        if let Some(ref created_at_filter) = request.filters.created_at
+            && let Some(rq) = build_created_at_range_query(fields.created_at, created_at_filter)
+        {
+            filter_queries.push(rq);
         }
 
-        // Apply doc_type filter
+        // Apply doc_type filter. Transcript segments only show up when asked
+        // for, so unfiltered searches keep returning one hit per entity.
         if let Some(ref doc_type) = request.filters.doc_type {
             let doc_type_term = Term::from_field_text(fields.doc_type, doc_type);
             let doc_type_query = TermQuery::new(doc_type_term, IndexRecordOption::Basic);
-            combined_query = Box::new(BooleanQuery::new(vec![
-                (Occur::Must, combined_query),
-                (Occur::Must, Box::new(doc_type_query)),
-            ]));
+            filter_queries.push(Box::new(doc_type_query));
+        } else {
+            let segment_term = Term::from_field_text(fields.doc_type, TRANSCRIPT_SEGMENT_DOC_TYPE);
+            filter_queries.push(Box::new(BooleanQuery::new(vec![
+                (Occur::Must, Box::new(AllQuery) as Box<dyn Query>),
+                (
+                    Occur::MustNot,
+                    Box::new(TermQuery::new(segment_term, IndexRecordOption::Basic)),
+                ),
+            ])));
         }
 
         // Apply facet filter
@@ -299,33 +532,63 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
         {
             let facet_term = Term::from_facet(fields.facets, &facet);
             let facet_query = TermQuery::new(facet_term, IndexRecordOption::Basic);
-            combined_query = Box::new(BooleanQuery::new(vec![
-                (Occur::Must, combined_query),
-                (Occur::Must, Box::new(facet_query)),
-            ]));
+            filter_queries.push(Box::new(facet_query));
         }
 
-        // Use tuple collector to get both top docs and total count
-        let (top_docs, count) = searcher.search(
-            &combined_query,
-            &(TopDocs::with_limit(request.limit), Count),
-        )?;
+        let combined_query = text_query.map(|query| with_filters(query, &filter_queries));
+
+        let (top_docs, mut count): (Vec<(f32, DocAddress)>, usize) =
+            match (&combined_query, &query_embedding) {
+                (Some(combined_query), None) => {
+                    // Use tuple collector to get both top docs and total count
+                    searcher.search(combined_query, &(TopDocs::with_limit(request.limit), Count))?
+                }
+                (None, None) => (vec![], 0),
+                (combined_query, Some(embedding)) => {
+                    let filter_query = with_filters(Box::new(AllQuery), &filter_queries);
+                    let mut vector_docs =
+                        rank_by_embedding(&searcher, &*filter_query, fields.embedding, embedding)?;
+
+                    match (request.mode, combined_query) {
+                        (SearchMode::Hybrid, Some(combined_query)) => {
+                            let candidates = request.limit.max(HYBRID_CANDIDATES);
+                            let keyword_docs = searcher
+                                .search(combined_query, &TopDocs::with_limit(candidates))?;
+                            vector_docs.truncate(candidates);
+
+                            let mut fused = reciprocal_rank_fusion(&[
+                                keyword_docs.into_iter().map(|(_, addr)| addr).collect(),
+                                vector_docs.into_iter().map(|(_, addr)| addr).collect(),
+                            ]);
+                            let count = fused.len();
+                            fused.truncate(request.limit);
+                            (fused, count)
+                        }
+                        _ => {
+                            let count = vector_docs.len();
+                            vector_docs.truncate(request.limit);
+                            (vector_docs, count)
+                        }
+                    }
+                }
+            };
 
         let generate_snippets = request.options.snippets.unwrap_or(false);
         let snippet_max_chars = request.options.snippet_max_chars.unwrap_or(150);
 
-        let (title_snippet_gen, content_snippet_gen) = if generate_snippets {
-            let mut title_gen =
-                SnippetGenerator::create(&searcher, &*combined_query, fields.title)?;
-            title_gen.set_max_num_chars(snippet_max_chars);
+        let (title_snippet_gen, content_snippet_gen) = match &combined_query {
+            Some(combined_query) if generate_snippets => {
+                let mut title_gen =
+                    SnippetGenerator::create(&searcher, &**combined_query, fields.title)?;
+                title_gen.set_max_num_chars(snippet_max_chars);
 
-            let mut content_gen =
-                SnippetGenerator::create(&searcher, &*combined_query, fields.content)?;
-            content_gen.set_max_num_chars(snippet_max_chars);
+                let mut content_gen =
+                    SnippetGenerator::create(&searcher, &**combined_query, fields.content)?;
+                content_gen.set_max_num_chars(snippet_max_chars);
 
-            (Some(title_gen), Some(content_gen))
-        } else {
-            (None, None)
+                (Some(title_gen), Some(content_gen))
+            }
+            _ => (None, None),
         };
 
         let mut hits = Vec::new();
@@ -368,10 +631,20 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
                     document: search_doc,
                     title_snippet,
                     content_snippet,
+                    segments: extract_segment_match(&fields, &retrieved_doc)
+                        .into_iter()
+                        .collect(),
                 });
             }
         }
 
+        if request.options.group_segments.unwrap_or(false) {
+            let ungrouped = hits.len();
+            hits = group_segment_hits(hits);
+            // Grouped hits were counted once per segment.
+            count = count.saturating_sub(ungrouped - hits.len());
+        }
+
         Ok(SearchResult { hits, count })
     }
 
@@ -406,8 +679,11 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
     pub async fn add_document(
         &self,
         collection: Option<String>,
-        document: SearchDocument,
+        mut document: SearchDocument,
     ) -> Result<(), crate::Error> {
+        self.fill_missing_embeddings(std::slice::from_mut(&mut document))
+            .await?;
+
         let collection_name = Self::get_collection_name(collection);
         let state = self.manager.state::<IndexState>();
         let mut guard = state.inner.write().await;
@@ -421,21 +697,7 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
         let writer = &mut collection_index.writer;
         let fields = get_fields(schema);
 
-        let mut doc = TantivyDocument::new();
-        doc.add_text(fields.id, &document.id);
-        doc.add_text(fields.doc_type, &document.doc_type);
-        doc.add_text(fields.language, document.language.as_deref().unwrap_or(""));
-        doc.add_text(fields.title, &document.title);
-        doc.add_text(fields.content, &document.content);
-        doc.add_i64(fields.created_at, document.created_at);
-
-        for facet_path in &document.facets {
-            if let Ok(facet) = Facet::from_text(facet_path) {
-                doc.add_facet(fields.facets, facet);
-            }
-        }
-
-        writer.add_document(doc)?;
+        writer.add_document(build_tantivy_document(&fields, &document))?;
         writer.commit()?;
 
         tracing::debug!(
@@ -450,8 +712,11 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
     pub async fn update_document(
         &self,
         collection: Option<String>,
-        document: SearchDocument,
+        mut document: SearchDocument,
     ) -> Result<(), crate::Error> {
+        self.fill_missing_embeddings(std::slice::from_mut(&mut document))
+            .await?;
+
         let collection_name = Self::get_collection_name(collection);
         let state = self.manager.state::<IndexState>();
         let mut guard = state.inner.write().await;
@@ -468,21 +733,7 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
         let id_term = Term::from_field_text(fields.id, &document.id);
         writer.delete_term(id_term);
 
-        let mut doc = TantivyDocument::new();
-        doc.add_text(fields.id, &document.id);
-        doc.add_text(fields.doc_type, &document.doc_type);
-        doc.add_text(fields.language, document.language.as_deref().unwrap_or(""));
-        doc.add_text(fields.title, &document.title);
-        doc.add_text(fields.content, &document.content);
-        doc.add_i64(fields.created_at, document.created_at);
-
-        for facet_path in &document.facets {
-            if let Ok(facet) = Facet::from_text(facet_path) {
-                doc.add_facet(fields.facets, facet);
-            }
-        }
-
-        writer.add_document(doc)?;
+        writer.add_document(build_tantivy_document(&fields, &document))?;
         writer.commit()?;
 
         tracing::debug!(
@@ -497,8 +748,10 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
     pub async fn update_documents(
         &self,
         collection: Option<String>,
-        documents: Vec<SearchDocument>,
+        mut documents: Vec<SearchDocument>,
     ) -> Result<(), crate::Error> {
+        self.fill_missing_embeddings(&mut documents).await?;
+
         let collection_name = Self::get_collection_name(collection);
         let state = self.manager.state::<IndexState>();
         let mut guard = state.inner.write().await;
@@ -518,21 +771,7 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
             let id_term = Term::from_field_text(fields.id, &document.id);
             writer.delete_term(id_term);
 
-            let mut doc = TantivyDocument::new();
-            doc.add_text(fields.id, &document.id);
-            doc.add_text(fields.doc_type, &document.doc_type);
-            doc.add_text(fields.language, document.language.as_deref().unwrap_or(""));
-            doc.add_text(fields.title, &document.title);
-            doc.add_text(fields.content, &document.content);
-            doc.add_i64(fields.created_at, document.created_at);
-
-            for facet_path in &document.facets {
-                if let Ok(facet) = Facet::from_text(facet_path) {
-                    doc.add_facet(fields.facets, facet);
-                }
-            }
-
-            writer.add_document(doc)?;
+            writer.add_document(build_tantivy_document(&fields, &document))?;
         }
 
         writer.commit()?;
@@ -546,6 +785,118 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
         Ok(())
     }
 
+    /// Replaces the transcript segments indexed for `session_id`. Each segment
+    /// becomes its own document so hits carry the matching time range.
+    pub async fn index_transcript(
+        &self,
+        collection: Option<String>,
+        session_id: String,
+        title: String,
+        created_at: i64,
+        words: Vec<TranscriptWord>,
+    ) -> Result<(), crate::Error> {
+        let segments = build_transcript_segments(&words);
+
+        let mut documents: Vec<SearchDocument> = segments
+            .iter()
+            .enumerate()
+            .map(|(index, segment)| SearchDocument {
+                id: segment_document_id(&session_id, index, segment),
+                doc_type: TRANSCRIPT_SEGMENT_DOC_TYPE.to_string(),
+                language: None,
+                title: title.clone(),
+                content: segment.text.clone(),
+                created_at,
+                facets: vec![],
+                embeddings: vec![],
+            })
+            .collect();
+        self.fill_missing_embeddings(&mut documents).await?;
+
+        let collection_name = Self::get_collection_name(collection);
+        let state = self.manager.state::<IndexState>();
+        let mut guard = state.inner.write().await;
+
+        let collection_index = guard
+            .collections
+            .get_mut(&collection_name)
+            .ok_or_else(|| crate::Error::CollectionNotFound(collection_name.clone()))?;
+
+        let schema = &collection_index.schema;
+        let writer = &mut collection_index.writer;
+        let fields = get_fields(schema);
+
+        let existing_segments = BooleanQuery::new(vec![
+            (
+                Occur::Must,
+                Box::new(TermQuery::new(
+                    Term::from_field_text(fields.session_id, &session_id),
+                    IndexRecordOption::Basic,
+                )) as Box<dyn Query>,
+            ),
+            (
+                Occur::Must,
+                Box::new(TermQuery::new(
+                    Term::from_field_text(fields.doc_type, TRANSCRIPT_SEGMENT_DOC_TYPE),
+                    IndexRecordOption::Basic,
+                )),
+            ),
+        ]);
+        writer.delete_query(Box::new(existing_segments))?;
+
+        for (segment, document) in segments.iter().zip(&documents) {
+            writer.add_document(build_segment_document(
+                &fields,
+                document,
+                &session_id,
+                segment,
+            ))?;
+        }
+
+        writer.commit()?;
+
+        tracing::debug!(
+            "Indexed {} transcript segments for session '{}' in collection '{}'",
+            segments.len(),
+            session_id,
+            collection_name
+        );
+
+        Ok(())
+    }
+
+    /// Removes every transcript segment indexed for `session_id`.
+    pub async fn remove_transcript(
+        &self,
+        collection: Option<String>,
+        session_id: String,
+    ) -> Result<(), crate::Error> {
+        let collection_name = Self::get_collection_name(collection);
+        let state = self.manager.state::<IndexState>();
+        let mut guard = state.inner.write().await;
+
+        let collection_index = guard
+            .collections
+            .get_mut(&collection_name)
+            .ok_or_else(|| crate::Error::CollectionNotFound(collection_name.clone()))?;
+
+        let schema = &collection_index.schema;
+        let writer = &mut collection_index.writer;
+        let fields = get_fields(schema);
+
+        let session_term = Term::from_field_text(fields.session_id, &session_id);
+        writer.delete_term(session_term);
+        writer.commit()?;
+
+        tracing::debug!(
+            "Removed transcript segments for session '{}' from collection '{}'",
+            session_id,
+            collection_name
+        );
+
+        Ok(())
+    }
+
     pub async fn remove_document(
         &self,
         collection: Option<String>,
@@ -578,6 +929,44 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
     }
 }
 
+fn with_filters(query: Box<dyn Query>, filter_queries: &[Box<dyn Query>]) -> Box<dyn Query> {
+    filter_queries.iter().fold(query, |query, filter| {
+        Box::new(BooleanQuery::new(vec![
+            (Occur::Must, query),
+            (Occur::Must, filter.box_clone()),
+        ]))
+    })
+}
+
+/// Brute-force cosine ranking over every document matching `filter_query`
+/// that has a stored embedding, best first. A document scores as its best
+/// matching chunk.
+fn rank_by_embedding(
+    searcher: &Searcher,
+    filter_query: &dyn Query,
+    embedding_field: Field,
+    query_embedding: &[f32],
+) -> Result<Vec<(f32, DocAddress)>, crate::Error> {
+    let addresses = searcher.search(filter_query, &DocSetCollector)?;
+
+    let mut scored = Vec::new();
+    for address in addresses {
+        let doc: TantivyDocument = searcher.doc(address)?;
+        let score = doc
+            .get_all(embedding_field)
+            .filter_map(|v| v.as_bytes())
+            .filter_map(|bytes| f32::cosine(query_embedding, &decode_embedding(bytes)))
+            .map(|distance| 1.0 - distance as f32)
+            .max_by(f32::total_cmp);
+        if let Some(score) = score {
+            scored.push((score, address));
+        }
+    }
+
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+    Ok(scored)
+}
+
 pub trait TantivyPluginExt<R: tauri::Runtime> {
     fn tantivy(&self) -> Tantivy<'_, R, Self>
     where
@@ -608,4 +997,52 @@ mod tests {
         let tokenizer_name = get_tokenizer_name_for_language(&lang);
         assert_eq!(tokenizer_name, "lang_en");
     }
+
+    #[test]
+    fn test_rank_by_embedding() {
+        let schema = crate::build_schema();
+        let index = Index::create_in_ram(schema.clone());
+        register_tokenizers(&index);
+        let fields = get_fields(&schema);
+
+        let mut writer = index.writer(15_000_000).unwrap();
+        for (id, embeddings) in [
+            ("a", vec![vec![1.0, 0.0]]),
+            ("b", vec![vec![1.0, 0.0], vec![0.6, 0.8]]),
+            ("c", vec![]),
+        ] {
+            let document = SearchDocument {
+                id: id.to_string(),
+                doc_type: "session".to_string(),
+                language: None,
+                title: id.to_string(),
+                content: String::new(),
+                created_at: 0,
+                facets: vec![],
+                embeddings,
+            };
+            writer
+                .add_document(build_tantivy_document(&fields, &document))
+                .unwrap();
+        }
+        writer.commit().unwrap();
+
+        let searcher = index.reader().unwrap().searcher();
+        let ranked =
+            rank_by_embedding(&searcher, &AllQuery, fields.embedding, &[0.0, 1.0]).unwrap();
+
+        let ids: Vec<String> = ranked
+            .iter()
+            .map(|(_, address)| {
+                let doc: TantivyDocument = searcher.doc(*address).unwrap();
+                doc.get_first(fields.id)
+                    .and_then(|v| v.as_str())
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(ids, vec!["b", "a"]);
+        assert!((ranked[0].0 - 0.8).abs() < 1e-4);
+        assert!(ranked[1].0.abs() < 1e-4);
+    }
 }
@@ -1,12 +1,16 @@
 mod commands;
+mod embedding;
 mod error;
 mod ext;
 mod query;
 mod schema;
+mod segments;
 mod tokenizer;
+mod vector;
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use tantivy::schema::Schema;
 use tantivy::{Index, IndexReader, IndexWriter};
 use tauri::Manager;
@@ -15,6 +19,7 @@ use tokio::sync::RwLock;
 pub use error::{Error, Result};
 pub use ext::*;
 pub use schema::build_schema;
+pub use segments::TRANSCRIPT_SEGMENT_DOC_TYPE;
 pub use tokenizer::get_tokenizer_name_for_language;
 
 const PLUGIN_NAME: &str = "tantivy";
@@ -29,6 +34,10 @@ pub struct SearchDocument {
     pub created_at: i64,
     #[serde(default)]
     pub facets: Vec<String>,
+    /// One embedding per content chunk. Computed on indexing when empty and
+    /// an embedding model is loaded.
+    #[serde(default)]
+    pub embeddings: Vec<Vec<f32>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
@@ -43,12 +52,33 @@ pub struct HighlightRange {
     pub end: usize,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct SegmentMatch {
+    pub session_id: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speaker: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 pub struct SearchHit {
     pub score: f32,
     pub document: SearchDocument,
     pub title_snippet: Option<Snippet>,
     pub content_snippet: Option<Snippet>,
+    #[serde(default)]
+    pub segments: Vec<SegmentMatch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct TranscriptWord {
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    #[serde(default)]
+    pub channel: i32,
+    #[serde(default)]
+    pub speaker: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
@@ -80,6 +110,18 @@ pub struct SearchOptions {
     pub snippets: Option<bool>,
     pub snippet_max_chars: Option<usize>,
     pub phrase_slop: Option<u32>,
+    #[serde(default)]
+    pub group_segments: Option<bool>,
+}
+
+/// `hybrid` fuses keyword and vector rankings with reciprocal rank fusion.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchMode {
+    #[default]
+    Keyword,
+    Vector,
+    Hybrid,
 }
 
 fn default_limit() -> usize {
@@ -97,9 +139,13 @@ pub struct SearchRequest {
     pub limit: usize,
     #[serde(default)]
     pub options: SearchOptions,
+    #[serde(default)]
+    pub mode: SearchMode,
+    #[serde(default)]
+    pub query_embedding: Option<Vec<f32>>,
 }
 
-pub const SCHEMA_VERSION: u32 = 1;
+pub const SCHEMA_VERSION: u32 = 3;
 
 pub struct CollectionConfig {
     pub name: String,
@@ -122,12 +168,17 @@ pub struct IndexStateInner {
 
 pub struct IndexState {
     pub inner: RwLock<IndexStateInner>,
+    pub embedder: Arc<Mutex<Option<hypr_embedding_local::TextEmbedder>>>,
+    /// Write-held while a model loads; embedding takes it for reading.
+    pub embedder_loading: Arc<RwLock<()>>,
 }
 
 impl Default for IndexState {
     fn default() -> Self {
         Self {
             inner: RwLock::new(IndexStateInner::default()),
+            embedder: Arc::new(Mutex::new(None)),
+            embedder_loading: Arc::new(RwLock::new(())),
         }
     }
 }
@@ -142,6 +193,11 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::update_document::<tauri::Wry>,
             commands::update_documents::<tauri::Wry>,
             commands::remove_document::<tauri::Wry>,
+            commands::load_embedding_model::<tauri::Wry>,
+            commands::is_embedding_model_downloaded::<tauri::Wry>,
+            commands::download_embedding_model::<tauri::Wry>,
+            commands::index_transcript::<tauri::Wry>,
+            commands::remove_transcript::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -152,7 +208,20 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
         .setup(|app, _api| {
-            app.manage(IndexState::default());
+            let state = IndexState::default();
+            let loading = state.embedder_loading.clone().try_write_owned()?;
+            app.manage(state);
+
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = handle
+                    .tantivy()
+                    .load_downloaded_embedding_model(loading)
+                    .await
+                {
+                    tracing::error!("Failed to load embedding model: {}", e);
+                }
+            });
 
             let handle = app.clone();
             tauri::async_runtime::spawn(async move {
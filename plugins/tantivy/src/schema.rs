@@ -1,9 +1,11 @@
 use tantivy::TantivyDocument;
 use tantivy::schema::{
-    FAST, FacetOptions, Field, STORED, STRING, Schema, TextFieldIndexing, TextOptions, Value,
+    FAST, Facet, FacetOptions, Field, STORED, STRING, Schema, TextFieldIndexing, TextOptions, Value,
 };
 
-use crate::SearchDocument;
+use crate::segments::TranscriptSegment;
+use crate::vector::encode_embedding;
+use crate::{SearchDocument, SegmentMatch};
 
 pub struct SchemaFields {
     pub id: Field,
@@ -13,6 +15,11 @@ pub struct SchemaFields {
     pub content: Field,
     pub created_at: Field,
     pub facets: Field,
+    pub embedding: Field,
+    pub session_id: Field,
+    pub start_ms: Field,
+    pub end_ms: Field,
+    pub speaker: Field,
 }
 
 pub fn build_schema() -> Schema {
@@ -32,6 +39,11 @@ pub fn build_schema() -> Schema {
     schema_builder.add_text_field("content", text_options);
     schema_builder.add_i64_field("created_at", FAST | STORED);
     schema_builder.add_facet_field("facets", FacetOptions::default());
+    schema_builder.add_bytes_field("embedding", STORED);
+    schema_builder.add_text_field("session_id", STRING | STORED);
+    schema_builder.add_i64_field("start_ms", STORED);
+    schema_builder.add_i64_field("end_ms", STORED);
+    schema_builder.add_text_field("speaker", STRING | STORED);
     schema_builder.build()
 }
 
@@ -44,6 +56,11 @@ pub fn get_fields(schema: &Schema) -> SchemaFields {
         content: schema.get_field("content").unwrap(),
         created_at: schema.get_field("created_at").unwrap(),
         facets: schema.get_field("facets").unwrap(),
+        embedding: schema.get_field("embedding").unwrap(),
+        session_id: schema.get_field("session_id").unwrap(),
+        start_ms: schema.get_field("start_ms").unwrap(),
+        end_ms: schema.get_field("end_ms").unwrap(),
+        speaker: schema.get_field("speaker").unwrap(),
     }
 }
 
@@ -75,9 +92,65 @@ pub fn extract_search_document(
         content,
         created_at,
         facets,
+        embeddings: vec![],
     })
 }
 
+pub fn extract_segment_match(fields: &SchemaFields, doc: &TantivyDocument) -> Option<SegmentMatch> {
+    let session_id = doc.get_first(fields.session_id)?.as_str()?.to_string();
+    let start_ms = doc.get_first(fields.start_ms)?.as_i64()?;
+    let end_ms = doc.get_first(fields.end_ms)?.as_i64()?;
+    let speaker = doc
+        .get_first(fields.speaker)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    Some(SegmentMatch {
+        session_id,
+        start_ms,
+        end_ms,
+        speaker,
+    })
+}
+
+pub fn build_segment_document(
+    fields: &SchemaFields,
+    document: &SearchDocument,
+    session_id: &str,
+    segment: &TranscriptSegment,
+) -> TantivyDocument {
+    let mut doc = build_tantivy_document(fields, document);
+    doc.add_text(fields.session_id, session_id);
+    doc.add_i64(fields.start_ms, segment.start_ms);
+    doc.add_i64(fields.end_ms, segment.end_ms);
+    if let Some(speaker) = &segment.speaker {
+        doc.add_text(fields.speaker, speaker);
+    }
+    doc
+}
+
+pub fn build_tantivy_document(fields: &SchemaFields, document: &SearchDocument) -> TantivyDocument {
+    let mut doc = TantivyDocument::new();
+    doc.add_text(fields.id, &document.id);
+    doc.add_text(fields.doc_type, &document.doc_type);
+    doc.add_text(fields.language, document.language.as_deref().unwrap_or(""));
+    doc.add_text(fields.title, &document.title);
+    doc.add_text(fields.content, &document.content);
+    doc.add_i64(fields.created_at, document.created_at);
+
+    for facet_path in &document.facets {
+        if let Ok(facet) = Facet::from_text(facet_path) {
+            doc.add_facet(fields.facets, facet);
+        }
+    }
+
+    for embedding in &document.embeddings {
+        doc.add_bytes(fields.embedding, encode_embedding(embedding).as_slice());
+    }
+
+    doc
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,5 +170,13 @@ mod tests {
             schema.get_field("content").is_ok(),
             "Schema should have a content field"
         );
+        assert!(
+            schema.get_field("embedding").is_ok(),
+            "Schema should have an embedding field"
+        );
+        assert!(
+            schema.get_field("session_id").is_ok(),
+            "Schema should have a session_id field"
+        );
     }
 }
@@ -0,0 +1,203 @@
+use crate::{SegmentMatch, TranscriptWord};
+
+pub const TRANSCRIPT_SEGMENT_DOC_TYPE: &str = "transcript_segment";
+
+const MAX_SEGMENT_MS: i64 = 30_000;
+const MAX_GAP_MS: i64 = 3_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptSegment {
+    pub channel: i32,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+/// Splits a transcript into searchable segments at channel or speaker
+/// changes, long pauses, or every `MAX_SEGMENT_MS` of speech.
+pub fn build_transcript_segments(words: &[TranscriptWord]) -> Vec<TranscriptSegment> {
+    let mut segments: Vec<TranscriptSegment> = Vec::new();
+
+    for word in words {
+        let text = word.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let continues = segments.last().is_some_and(|segment| {
+            segment.channel == word.channel
+                && segment.speaker == word.speaker
+                && word.start_ms - segment.end_ms <= MAX_GAP_MS
+                && word.end_ms - segment.start_ms <= MAX_SEGMENT_MS
+        });
+
+        match segments.last_mut() {
+            Some(segment) if continues => {
+                segment.text.push(' ');
+                segment.text.push_str(text);
+                segment.end_ms = segment.end_ms.max(word.end_ms);
+            }
+            _ => segments.push(TranscriptSegment {
+                channel: word.channel,
+                start_ms: word.start_ms,
+                end_ms: word.end_ms,
+                speaker: word.speaker.clone(),
+                text: text.to_string(),
+            }),
+        }
+    }
+
+    segments
+}
+
+/// Unique within a session: channels can overlap in time, and `index`
+/// disambiguates segments that start on the same millisecond.
+pub fn segment_document_id(session_id: &str, index: usize, segment: &TranscriptSegment) -> String {
+    format!(
+        "{}#{}:{}:{}",
+        session_id, segment.channel, segment.start_ms, index
+    )
+}
+
+/// Collapses segment hits of the same session into the first (best) hit,
+/// keeping every matching time range.
+pub fn group_segment_hits(hits: Vec<crate::SearchHit>) -> Vec<crate::SearchHit> {
+    let mut grouped: Vec<crate::SearchHit> = Vec::new();
+
+    for hit in hits {
+        let session_id = hit.segments.first().map(|s: &SegmentMatch| &s.session_id);
+
+        let existing = session_id.and_then(|session_id| {
+            grouped.iter_mut().find(|h| {
+                h.segments
+                    .first()
+                    .is_some_and(|s| &s.session_id == session_id)
+            })
+        });
+
+        match existing {
+            Some(existing) => existing.segments.extend(hit.segments),
+            None => grouped.push(hit),
+        }
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start_ms: i64, end_ms: i64, speaker: Option<&str>) -> TranscriptWord {
+        TranscriptWord {
+            text: text.to_string(),
+            start_ms,
+            end_ms,
+            channel: 0,
+            speaker: speaker.map(|s| s.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_build_transcript_segments() {
+        let words = vec![
+            word(" Let's", 0, 300, Some("Alice")),
+            word(" talk", 300, 600, Some("Alice")),
+            word(" pricing.", 600, 1000, Some("Alice")),
+            word(" Sure.", 1200, 1500, Some("Bob")),
+            word(" Later", 9000, 9400, Some("Bob")),
+        ];
+
+        let segments = build_transcript_segments(&words);
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].text, "Let's talk pricing.");
+        assert_eq!((segments[0].start_ms, segments[0].end_ms), (0, 1000));
+        assert_eq!(segments[1].speaker.as_deref(), Some("Bob"));
+        assert_eq!(segments[2].start_ms, 9000);
+    }
+
+    #[test]
+    fn test_build_transcript_segments_splits_long_turns() {
+        let words: Vec<_> = (0..100)
+            .map(|i| word(" word", i * 1000, i * 1000 + 800, None))
+            .collect();
+
+        let segments = build_transcript_segments(&words);
+        assert!(segments.len() > 1);
+        assert!(
+            segments
+                .iter()
+                .all(|s| s.end_ms - s.start_ms <= MAX_SEGMENT_MS)
+        );
+    }
+
+    #[test]
+    fn test_segment_document_ids_are_unique() {
+        let words = vec![
+            word(" Hi", 0, 400, None),
+            TranscriptWord {
+                channel: 1,
+                ..word(" Hello", 0, 400, None)
+            },
+            word(" there", 400, 800, Some("Alice")),
+        ];
+
+        let segments = build_transcript_segments(&words);
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[1].channel, 1);
+
+        let ids: std::collections::HashSet<String> = segments
+            .iter()
+            .enumerate()
+            .map(|(i, s)| segment_document_id("session", i, s))
+            .collect();
+        assert_eq!(ids.len(), segments.len());
+    }
+
+    fn segment_hit(session_id: &str, start_ms: i64) -> crate::SearchHit {
+        crate::SearchHit {
+            score: 1.0,
+            document: crate::SearchDocument {
+                id: format!("{}#0:{}:0", session_id, start_ms),
+                doc_type: TRANSCRIPT_SEGMENT_DOC_TYPE.to_string(),
+                language: None,
+                title: String::new(),
+                content: String::new(),
+                created_at: 0,
+                facets: vec![],
+                embeddings: vec![],
+            },
+            title_snippet: None,
+            content_snippet: None,
+            segments: vec![SegmentMatch {
+                session_id: session_id.to_string(),
+                start_ms,
+                end_ms: start_ms + 1000,
+                speaker: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_group_segment_hits() {
+        let hits = vec![
+            segment_hit("a", 0),
+            segment_hit("b", 0),
+            segment_hit("a", 5000),
+        ];
+
+        let grouped = group_segment_hits(hits);
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].document.id, "a#0:0:0");
+        assert_eq!(
+            grouped[0]
+                .segments
+                .iter()
+                .map(|s| s.start_ms)
+                .collect::<Vec<_>>(),
+            vec![0, 5000]
+        );
+        assert_eq!(grouped[1].segments.len(), 1);
+    }
+}
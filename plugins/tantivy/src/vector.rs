@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Constant from the original RRF paper; dampens the advantage of top ranks.
+const RRF_K: f32 = 60.0;
+
+pub fn encode_embedding(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+pub fn decode_embedding(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+/// Reciprocal rank fusion over several best-first rankings.
+pub fn reciprocal_rank_fusion<T: Copy + Eq + Hash + Ord>(rankings: &[Vec<T>]) -> Vec<(f32, T)> {
+    let mut scores: HashMap<T, f32> = HashMap::new();
+
+    for ranking in rankings {
+        for (rank, item) in ranking.iter().enumerate() {
+            *scores.entry(*item).or_default() += 1.0 / (RRF_K + rank as f32 + 1.0);
+        }
+    }
+
+    let mut fused: Vec<(f32, T)> = scores.into_iter().map(|(item, s)| (s, item)).collect();
+    fused.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedding_roundtrip() {
+        let embedding = vec![0.25, -1.5, 3.0];
+        assert_eq!(decode_embedding(&encode_embedding(&embedding)), embedding);
+    }
+
+    #[test]
+    fn test_reciprocal_rank_fusion() {
+        let keyword = vec![1, 2, 3];
+        let vector = vec![3, 4, 1];
+
+        let fused = reciprocal_rank_fusion(&[keyword, vector]);
+        let order: Vec<i32> = fused.iter().map(|(_, id)| *id).collect();
+
+        assert_eq!(order, vec![1, 3, 2, 4]);
+    }
+}
@@ -1,4 +1,15 @@
-const COMMANDS: &[&str] = &[];
+const COMMANDS: &[&str] = &[
+    "list_endpoints",
+    "add_endpoint",
+    "update_endpoint",
+    "remove_endpoint",
+    "dispatch_event",
+    "send_test_event",
+    "list_pending_deliveries",
+    "list_dead_letters",
+    "retry_dead_letter",
+    "clear_dead_letters",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -6,9 +6,81 @@
 
 
 export const commands = {
-async todo() : Promise<Result<string, string>> {
+async listEndpoints() : Promise<Result<WebhookEndpoint[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|todo") };
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|list_endpoints") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async addEndpoint(url: string, events: WebhookEventType[]) : Promise<Result<WebhookEndpoint, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|add_endpoint", { url, events }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateEndpoint(id: string, update: WebhookEndpointUpdate) : Promise<Result<WebhookEndpoint, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|update_endpoint", { id, update }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async removeEndpoint(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|remove_endpoint", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async dispatchEvent(event: HookEvent, content: WebhookContent | null) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|dispatch_event", { event, content }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async sendTestEvent(endpointId: string) : Promise<Result<DeliveryAttempt, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|send_test_event", { endpointId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listPendingDeliveries() : Promise<Result<WebhookDelivery[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|list_pending_deliveries") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listDeadLetters() : Promise<Result<WebhookDelivery[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|list_dead_letters") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async retryDeadLetter(deliveryId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|retry_dead_letter", { deliveryId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async clearDeadLetters() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:webhook|clear_dead_letters") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -26,7 +98,58 @@ async todo() : Promise<Result<string, string>> {
 
 /** user-defined types **/
 
-
+export type AfterListeningStoppedArgs = { resource_dir: string; app_hyprnote: string; app_meeting?: string | null }
+export type BeforeListeningStartedArgs = { resource_dir: string; app_hyprnote: string; app_meeting?: string | null }
+/**
+ * Fired when a calendar event with a linked meeting is about to start.
+ * `participants` is only delivered in the stdin payload.
+ */
+export type CalendarEventStartingArgs = { event_id: string; title: string; started_at: string; ended_at: string; participants: HookParticipant[]; meeting_link?: string | null; session_id?: string | null }
+export type DeliveryAttempt = { status: number | null; error: string | null; duration_ms: number }
+/**
+ * Fired after an enhanced note has been generated and saved.
+ * `participants` and `tags` are only delivered in the stdin payload.
+ */
+export type EnhancedNoteGeneratedArgs = { session_id: string; title?: string | null; participants: HookParticipant[]; tags?: string[]; resource_dir: string; note_path: string; template_id?: string | null }
+/**
+ * Fired after a session has been exported to a file.
+ * `tags` is only delivered in the stdin payload.
+ */
+export type ExportCompletedArgs = { session_id: string; title?: string | null; tags?: string[]; format: string; output_path: string }
+export type HookEvent = { afterListeningStopped: { args: AfterListeningStoppedArgs } } | { beforeListeningStarted: { args: BeforeListeningStartedArgs } } | { calendarEventStarting: { args: CalendarEventStartingArgs } } | { enhancedNoteGenerated: { args: EnhancedNoteGeneratedArgs } } | { exportCompleted: { args: ExportCompletedArgs } } | { sessionCreated: { args: SessionCreatedArgs } } | { sessionDeleted: { args: SessionDeletedArgs } } | { transcriptFinalized: { args: TranscriptFinalizedArgs } }
+export type HookParticipant = { human_id: string; name: string; email?: string | null }
+/**
+ * Fired after a new session has been created.
+ * `tags` is only delivered in the stdin payload.
+ */
+export type SessionCreatedArgs = { session_id: string; title?: string | null; tags?: string[]; resource_dir: string }
+/**
+ * Fired after a session has been deleted. Its files may already be gone.
+ */
+export type SessionDeletedArgs = { session_id: string; title?: string | null }
+/**
+ * Fired once the transcript of a session is final and written to disk.
+ * `participants` and `tags` are only delivered in the stdin payload.
+ */
+export type TranscriptFinalizedArgs = { session_id: string; title?: string | null; participants: HookParticipant[]; tags?: string[]; resource_dir: string; transcript_path: string }
+/**
+ * Session content delivered alongside the hook args, so receivers do not
+ * need access to the vault to read the note or transcript.
+ */
+export type WebhookContent = { note_markdown?: string | null; transcript?: WebhookTranscriptSegment[] | null }
+/**
+ * One event queued for one endpoint. The event payload lives in the
+ * [`crate::PayloadStore`] and is loaded when the delivery is attempted.
+ */
+export type WebhookDelivery = { id: string; endpoint_id: string; event_id: string; event_type: string; attempts: number; next_attempt_at_ms: number; last_status: number | null; last_error: string | null }
+export type WebhookEndpoint = { id: string; url: string; events: WebhookEventType[]; secret: string; enabled: boolean; created_at: string }
+export type WebhookEndpointUpdate = { url?: string | null; events?: WebhookEventType[] | null; enabled?: boolean | null }
+/**
+ * Events an endpoint can subscribe to. Serialized as the `event_type` of the
+ * delivered payload.
+ */
+export type WebhookEventType = "recording.stopped" | "transcript.finalized" | "summary.generated" | "webhook.test"
+export type WebhookTranscriptSegment = { speaker: string | null; text: string; start_ms: number; end_ms: number }
 
 /** tauri-specta globals **/
 
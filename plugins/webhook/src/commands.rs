@@ -1,7 +1,118 @@
+use hypr_hooks::HookEvent;
+
 use crate::WebhookPluginExt;
+use crate::types::{
+    DeliveryAttempt, WebhookContent, WebhookDelivery, WebhookEndpoint, WebhookEndpointUpdate,
+    WebhookEventType,
+};
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_endpoints<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<WebhookEndpoint>, String> {
+    Ok(app.webhook().list_endpoints().await)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn add_endpoint<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    url: String,
+    events: Vec<WebhookEventType>,
+) -> Result<WebhookEndpoint, String> {
+    app.webhook()
+        .add_endpoint(url, events)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn update_endpoint<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    update: WebhookEndpointUpdate,
+) -> Result<WebhookEndpoint, String> {
+    app.webhook()
+        .update_endpoint(&id, update)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn remove_endpoint<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.webhook()
+        .remove_endpoint(&id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn dispatch_event<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    event: HookEvent,
+    content: Option<WebhookContent>,
+) -> Result<u32, String> {
+    app.webhook()
+        .dispatch(&event, content.unwrap_or_default())
+        .await
+        .map(|queued| queued as u32)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn send_test_event<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    endpoint_id: String,
+) -> Result<DeliveryAttempt, String> {
+    app.webhook()
+        .send_test_event(&endpoint_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_pending_deliveries<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<WebhookDelivery>, String> {
+    Ok(app.webhook().list_pending_deliveries().await)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_dead_letters<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<WebhookDelivery>, String> {
+    Ok(app.webhook().list_dead_letters().await)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn retry_dead_letter<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    delivery_id: String,
+) -> Result<(), String> {
+    app.webhook()
+        .retry_dead_letter(&delivery_id)
+        .await
+        .map_err(|e| e.to_string())
+}
 
 #[tauri::command]
 #[specta::specta]
-pub async fn todo<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<String, String> {
-    app.webhook().todo()
+pub(crate) async fn clear_dead_letters<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.webhook()
+        .clear_dead_letters()
+        .await
+        .map_err(|e| e.to_string())
 }
@@ -0,0 +1,379 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+use tokio::sync::{Mutex, Notify};
+
+use crate::Result;
+use crate::openapi::WebhookEvent;
+use crate::payload::PayloadStore;
+use crate::signing::{EVENT_HEADER, ID_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER, sign};
+use crate::store::{WebhookStore, write};
+use crate::types::{DeliveryAttempt, WebhookDelivery, WebhookEndpoint, WebhookEventType};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+const MAX_ERROR_CHARS: usize = 500;
+/// Deliveries in flight at once, so one slow endpoint does not hold up the rest.
+const MAX_CONCURRENT_DELIVERIES: usize = 8;
+
+pub(crate) fn now_ms() -> u64 {
+    chrono::Utc::now().timestamp_millis().max(0) as u64
+}
+
+pub struct WebhookState {
+    path: PathBuf,
+    payloads: PayloadStore,
+    store: Mutex<WebhookStore>,
+    /// Held while the store file is written, so snapshots land in the order
+    /// they were taken without keeping `store` locked during the write.
+    save_lock: Mutex<()>,
+    notify: Notify,
+    client: reqwest::Client,
+}
+
+impl WebhookState {
+    pub fn load(path: PathBuf, payload_dir: PathBuf) -> Result<Self> {
+        let store = WebhookStore::load(&path)?;
+        let payloads = PayloadStore::new(payload_dir);
+        if let Err(e) = payloads.prune(&store.event_ids()) {
+            tracing::warn!("webhook_payload_prune_failed: {}", e);
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()?;
+
+        Ok(Self {
+            path,
+            payloads,
+            store: Mutex::new(store),
+            save_lock: Mutex::new(()),
+            notify: Notify::new(),
+            client,
+        })
+    }
+
+    pub async fn read<T>(&self, f: impl FnOnce(&WebhookStore) -> T) -> T {
+        f(&*self.store.lock().await)
+    }
+
+    /// Applies `f`, persists the store off the async runtime and removes the
+    /// payloads of events no delivery refers to any more. If the write fails
+    /// the change is kept in memory and reaches disk with the next update.
+    pub async fn update<T>(&self, f: impl FnOnce(&mut WebhookStore) -> Result<T>) -> Result<T> {
+        let mut store = self.store.lock().await;
+        let mut next = store.clone();
+        let value = f(&mut next)?;
+        let content = serde_json::to_vec_pretty(&next)?;
+        let dropped: Vec<String> = store
+            .event_ids()
+            .difference(&next.event_ids())
+            .cloned()
+            .collect();
+        *store = next;
+
+        let _save = self.save_lock.lock().await;
+        drop(store);
+
+        let path = self.path.clone();
+        let payloads = self.payloads.clone();
+        tokio::task::spawn_blocking(move || {
+            write(&path, &content)?;
+            payloads.remove(&dropped);
+            Ok::<_, crate::Error>(())
+        })
+        .await??;
+        Ok(value)
+    }
+
+    /// Writes the payload and queues it for every subscribed endpoint.
+    /// Returns the number of deliveries queued.
+    pub async fn enqueue(
+        &self,
+        event_type: WebhookEventType,
+        event: WebhookEvent,
+    ) -> Result<usize> {
+        if !self.read(|store| store.has_subscribers(event_type)).await {
+            return Ok(0);
+        }
+
+        let payloads = self.payloads.clone();
+        let payload = event.clone();
+        tokio::task::spawn_blocking(move || payloads.save(&payload)).await??;
+
+        let queued = self
+            .update(|store| Ok(store.enqueue(event_type, &event)))
+            .await?;
+        if queued == 0 {
+            let payloads = self.payloads.clone();
+            tokio::task::spawn_blocking(move || payloads.remove(&[event.id])).await?;
+        }
+        Ok(queued)
+    }
+
+    /// Wakes the worker so newly queued deliveries go out right away.
+    pub fn wake(&self) {
+        self.notify.notify_one();
+    }
+
+    pub async fn send(&self, endpoint: &WebhookEndpoint, event: &WebhookEvent) -> DeliveryAttempt {
+        deliver(&self.client, endpoint, event).await
+    }
+
+    /// Attempts every due delivery once, several at a time, and returns how
+    /// many were attempted.
+    pub async fn process_due(&self) -> Result<usize> {
+        let due = self.read(|store| store.due(now_ms())).await;
+        if due.is_empty() {
+            return Ok(0);
+        }
+
+        let attempts: Vec<(String, DeliveryAttempt)> = futures_util::stream::iter(&due)
+            .map(|(delivery, endpoint)| async move {
+                let attempt = self.attempt(delivery, endpoint).await;
+                if let Some(error) = &attempt.error {
+                    tracing::warn!(
+                        endpoint = %endpoint.url,
+                        attempts = delivery.attempts + 1,
+                        "webhook_delivery_failed: {}",
+                        error
+                    );
+                }
+                (delivery.id.clone(), attempt)
+            })
+            .buffer_unordered(MAX_CONCURRENT_DELIVERIES)
+            .collect()
+            .await;
+
+        self.update(|store| {
+            let now = now_ms();
+            for (id, attempt) in &attempts {
+                store.record_attempt(id, attempt, now);
+            }
+            Ok(())
+        })
+        .await?;
+
+        Ok(due.len())
+    }
+
+    async fn attempt(
+        &self,
+        delivery: &WebhookDelivery,
+        endpoint: &WebhookEndpoint,
+    ) -> DeliveryAttempt {
+        let payloads = self.payloads.clone();
+        let event_id = delivery.event_id.clone();
+        let event = match tokio::task::spawn_blocking(move || payloads.load(&event_id)).await {
+            Ok(Ok(event)) => event,
+            Ok(Err(e)) => return DeliveryAttempt::failed(format!("payload unavailable: {e}")),
+            Err(e) => return DeliveryAttempt::failed(e.to_string()),
+        };
+        self.send(endpoint, &event).await
+    }
+
+    /// Delivers due events until the app exits, polling for retries and
+    /// waking early whenever something is queued.
+    pub async fn run_worker(&self) {
+        loop {
+            if let Err(e) = self.process_due().await {
+                tracing::error!("webhook_worker_failed: {}", e);
+            }
+
+            tokio::select! {
+                _ = self.notify.notified() => {}
+                _ = tokio::time::sleep(POLL_INTERVAL) => {}
+            }
+        }
+    }
+}
+
+/// POSTs the event as JSON, signed with the endpoint secret. Any 2xx response
+/// counts as delivered.
+pub async fn deliver(
+    client: &reqwest::Client,
+    endpoint: &WebhookEndpoint,
+    event: &WebhookEvent,
+) -> DeliveryAttempt {
+    let started = Instant::now();
+    let body = serde_json::to_vec(event).unwrap_or_default();
+    let timestamp = chrono::Utc::now().timestamp().max(0) as u64;
+
+    let result = client
+        .post(&endpoint.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(ID_HEADER, &event.id)
+        .header(EVENT_HEADER, &event.event_type)
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(SIGNATURE_HEADER, sign(&endpoint.secret, timestamp, &body))
+        .body(body)
+        .send()
+        .await;
+
+    let (status, error) = match result {
+        Ok(response) if response.status().is_success() => (Some(response.status().as_u16()), None),
+        Ok(response) => {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            let error = format!("HTTP {}: {}", status, text.trim())
+                .chars()
+                .take(MAX_ERROR_CHARS)
+                .collect();
+            (Some(status.as_u16()), Some(error))
+        }
+        Err(e) => (None, Some(e.to_string())),
+    };
+
+    DeliveryAttempt {
+        status,
+        error,
+        duration_ms: started.elapsed().as_millis() as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, extract::State, http::HeaderMap, http::StatusCode, routing::post};
+
+    use super::*;
+    use crate::signing::verify;
+    use crate::types::WebhookEventType;
+
+    type Received = (HeaderMap, Vec<u8>);
+
+    #[derive(Clone, Default)]
+    struct Receiver {
+        status: Arc<std::sync::Mutex<u16>>,
+        requests: Arc<std::sync::Mutex<Vec<Received>>>,
+    }
+
+    async fn receive(
+        State(receiver): State<Receiver>,
+        headers: HeaderMap,
+        body: axum::body::Bytes,
+    ) -> StatusCode {
+        receiver
+            .requests
+            .lock()
+            .unwrap()
+            .push((headers, body.to_vec()));
+        StatusCode::from_u16(*receiver.status.lock().unwrap()).unwrap()
+    }
+
+    async fn spawn_receiver(status: u16) -> (String, Receiver) {
+        let receiver = Receiver::default();
+        *receiver.status.lock().unwrap() = status;
+
+        let app = Router::new()
+            .route("/hook", post(receive))
+            .with_state(receiver.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (format!("http://{}/hook", addr), receiver)
+    }
+
+    fn event() -> WebhookEvent {
+        WebhookEvent {
+            id: "evt_1".to_string(),
+            event_type: WebhookEventType::RecordingStopped.as_str().to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            data: serde_json::json!({ "resource_dir": "/tmp/session" }),
+        }
+    }
+
+    async fn state_with_endpoint(url: String) -> (tempfile::TempDir, WebhookState, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let state = WebhookState::load(
+            dir.path().join("webhooks.json"),
+            dir.path().join("payloads"),
+        )
+        .unwrap();
+        let endpoint = state
+            .update(|store| store.add_endpoint(url, vec![WebhookEventType::RecordingStopped]))
+            .await
+            .unwrap();
+        (dir, state, endpoint.secret)
+    }
+
+    #[tokio::test]
+    async fn delivers_signed_payload() {
+        let (url, receiver) = spawn_receiver(200).await;
+        let (_dir, state, secret) = state_with_endpoint(url).await;
+
+        state
+            .enqueue(WebhookEventType::RecordingStopped, event())
+            .await
+            .unwrap();
+        assert_eq!(state.process_due().await.unwrap(), 1);
+        assert!(state.read(|store| store.pending.is_empty()).await);
+        assert!(state.payloads.load("evt_1").is_err());
+
+        let requests = receiver.requests.lock().unwrap();
+        let (headers, body) = &requests[0];
+        let timestamp: u64 = headers[TIMESTAMP_HEADER].to_str().unwrap().parse().unwrap();
+        let signature = headers[SIGNATURE_HEADER].to_str().unwrap();
+        assert!(verify(&secret, timestamp, body, signature));
+        assert_eq!(headers[EVENT_HEADER], "recording.stopped");
+
+        let payload: WebhookEvent = serde_json::from_slice(body).unwrap();
+        assert_eq!(payload.data["resource_dir"], "/tmp/session");
+    }
+
+    #[tokio::test]
+    async fn schedules_retry_on_failure() {
+        let (url, receiver) = spawn_receiver(503).await;
+        let (dir, state, _) = state_with_endpoint(url).await;
+
+        state
+            .enqueue(WebhookEventType::RecordingStopped, event())
+            .await
+            .unwrap();
+        state.process_due().await.unwrap();
+
+        let pending = state.read(|store| store.pending.clone()).await;
+        assert_eq!(pending[0].attempts, 1);
+        assert_eq!(pending[0].last_status, Some(503));
+        assert!(pending[0].next_attempt_at_ms > now_ms());
+        assert_eq!(state.process_due().await.unwrap(), 0);
+        assert_eq!(receiver.requests.lock().unwrap().len(), 1);
+
+        let reloaded = WebhookStore::load(&dir.path().join("webhooks.json")).unwrap();
+        assert_eq!(reloaded.pending, pending);
+        assert_eq!(state.payloads.load("evt_1").unwrap().id, "evt_1");
+    }
+
+    #[tokio::test]
+    async fn slow_endpoint_does_not_hold_up_others() {
+        let slow = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let slow_url = format!("http://{}/hook", slow.local_addr().unwrap());
+        let (url, receiver) = spawn_receiver(200).await;
+        let (_dir, state, _) = state_with_endpoint(slow_url).await;
+        state
+            .update(|store| store.add_endpoint(url, vec![WebhookEventType::RecordingStopped]))
+            .await
+            .unwrap();
+        state
+            .enqueue(WebhookEventType::RecordingStopped, event())
+            .await
+            .unwrap();
+
+        let process = state.process_due();
+        tokio::pin!(process);
+        let delivered = async {
+            while receiver.requests.lock().unwrap().is_empty() {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        };
+        tokio::select! {
+            _ = &mut process => panic!("the slow endpoint should still be pending"),
+            result = tokio::time::timeout(Duration::from_secs(5), delivered) => result.unwrap(),
+        }
+        assert_eq!(receiver.requests.lock().unwrap().len(), 1);
+        drop(slow);
+    }
+}
@@ -3,7 +3,22 @@ use serde::{Serialize, ser::Serializer};
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, thiserror::Error)]
-pub enum Error {}
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Join(#[from] tokio::task::JoinError),
+    #[error("invalid webhook url: {0}")]
+    InvalidUrl(String),
+    #[error("webhook endpoint not found: {0}")]
+    EndpointNotFound(String),
+    #[error("webhook delivery not found: {0}")]
+    DeliveryNotFound(String),
+}
 
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1,12 +1,132 @@
+use std::sync::Arc;
+
+use hypr_hooks::HookEvent;
+
+use crate::delivery::WebhookState;
+use crate::openapi::WebhookEvent;
+use crate::types::{
+    DeliveryAttempt, WebhookContent, WebhookDelivery, WebhookEndpoint, WebhookEndpointUpdate,
+    WebhookEventType,
+};
+
 pub struct Webhook<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
     manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
 
 impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Webhook<'a, R, M> {
-    pub fn todo(&self) -> Result<String, String> {
-        let _ = self.manager;
-        Ok("Webhook todo functionality not yet implemented".to_string())
+    fn state(&self) -> Arc<WebhookState> {
+        self.manager.state::<Arc<WebhookState>>().inner().clone()
+    }
+
+    pub async fn list_endpoints(&self) -> Vec<WebhookEndpoint> {
+        self.state().read(|store| store.endpoints.clone()).await
+    }
+
+    pub async fn add_endpoint(
+        &self,
+        url: String,
+        events: Vec<WebhookEventType>,
+    ) -> crate::Result<WebhookEndpoint> {
+        self.state()
+            .update(|store| store.add_endpoint(url, events))
+            .await
+    }
+
+    pub async fn update_endpoint(
+        &self,
+        id: &str,
+        update: WebhookEndpointUpdate,
+    ) -> crate::Result<WebhookEndpoint> {
+        self.state()
+            .update(|store| store.update_endpoint(id, update))
+            .await
+    }
+
+    pub async fn remove_endpoint(&self, id: &str) -> crate::Result<()> {
+        self.state().update(|store| store.remove_endpoint(id)).await
+    }
+
+    /// Queues a hook event for subscribed endpoints. Events without a webhook
+    /// counterpart are ignored. Returns the number of deliveries queued.
+    pub async fn dispatch(
+        &self,
+        event: &HookEvent,
+        content: WebhookContent,
+    ) -> crate::Result<usize> {
+        let Some(event_type) = WebhookEventType::from_hook_event(event) else {
+            return Ok(0);
+        };
+
+        let event = new_event(event_type, event_data(event, content));
+        let state = self.state();
+        let queued = state.enqueue(event_type, event).await?;
+        if queued > 0 {
+            state.wake();
+        }
+        Ok(queued)
+    }
+
+    /// Sends a `webhook.test` event straight to the endpoint, bypassing the
+    /// queue, and reports how it went.
+    pub async fn send_test_event(&self, endpoint_id: &str) -> crate::Result<DeliveryAttempt> {
+        let state = self.state();
+        let endpoint = state
+            .read(|store| store.endpoint(endpoint_id).cloned())
+            .await?;
+
+        let event = new_event(
+            WebhookEventType::Test,
+            serde_json::json!({ "endpoint_id": endpoint.id }),
+        );
+        Ok(state.send(&endpoint, &event).await)
+    }
+
+    pub async fn list_pending_deliveries(&self) -> Vec<WebhookDelivery> {
+        self.state().read(|store| store.pending.clone()).await
+    }
+
+    pub async fn list_dead_letters(&self) -> Vec<WebhookDelivery> {
+        self.state().read(|store| store.dead_letters.clone()).await
+    }
+
+    pub async fn retry_dead_letter(&self, delivery_id: &str) -> crate::Result<()> {
+        let state = self.state();
+        state
+            .update(|store| store.retry_dead_letter(delivery_id))
+            .await?;
+        state.wake();
+        Ok(())
+    }
+
+    pub async fn clear_dead_letters(&self) -> crate::Result<()> {
+        self.state()
+            .update(|store| {
+                store.dead_letters.clear();
+                Ok(())
+            })
+            .await
+    }
+}
+
+/// The hook args with the session content merged in as top-level fields.
+fn event_data(event: &HookEvent, content: WebhookContent) -> serde_json::Value {
+    let mut data = event.args_value();
+    if let (Some(data), serde_json::Value::Object(content)) = (
+        data.as_object_mut(),
+        serde_json::to_value(content).unwrap_or_default(),
+    ) {
+        data.extend(content);
+    }
+    data
+}
+
+fn new_event(event_type: WebhookEventType, data: serde_json::Value) -> WebhookEvent {
+    WebhookEvent {
+        id: format!("evt_{}", uuid::Uuid::new_v4().simple()),
+        event_type: event_type.as_str().to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        data,
     }
 }
 
@@ -27,3 +147,47 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> WebhookPluginExt<R> for T {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::WebhookTranscriptSegment;
+
+    #[test]
+    fn merges_content_into_event_data() {
+        let event = HookEvent::TranscriptFinalized {
+            args: hypr_hooks::TranscriptFinalizedArgs {
+                session_id: "s1".to_string(),
+                title: Some("Standup".to_string()),
+                participants: vec![],
+                tags: vec![],
+                resource_dir: "/vault/sessions/s1".to_string(),
+                transcript_path: "/vault/sessions/s1/transcript.json".to_string(),
+            },
+        };
+        let content = WebhookContent {
+            note_markdown: Some("# Summary".to_string()),
+            transcript: Some(vec![WebhookTranscriptSegment {
+                speaker: Some("Alice".to_string()),
+                text: "Hello there".to_string(),
+                start_ms: 0,
+                end_ms: 800,
+            }]),
+        };
+
+        let data = event_data(&event, content);
+        assert_eq!(data["session_id"], "s1");
+        assert_eq!(data["title"], "Standup");
+        assert_eq!(data["note_markdown"], "# Summary");
+        assert_eq!(
+            data["transcript"],
+            serde_json::json!([
+                { "speaker": "Alice", "text": "Hello there", "start_ms": 0, "end_ms": 800 }
+            ])
+        );
+
+        let data = event_data(&event, WebhookContent::default());
+        assert!(data.get("note_markdown").is_none());
+        assert!(data.get("transcript").is_none());
+    }
+}
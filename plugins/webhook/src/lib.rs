@@ -1,25 +1,46 @@
 mod commands;
+mod delivery;
 mod error;
 mod ext;
 mod openapi;
+mod payload;
+mod signing;
+mod store;
+mod types;
 
+pub use delivery::*;
 pub use error::*;
 pub use ext::*;
 pub use openapi::*;
+pub use payload::*;
+pub use signing::*;
+pub use store::*;
+pub use types::*;
 
 const PLUGIN_NAME: &str = "webhook";
+const STORE_FILENAME: &str = "webhooks.json";
+const PAYLOAD_DIRNAME: &str = "webhook-payloads";
 
-use tauri::Manager;
+use std::sync::Arc;
 
-#[derive(Default)]
-pub struct State {}
+use tauri::Manager;
+use tauri_plugin_settings::SettingsPluginExt;
 
 fn make_specta_builder() -> tauri_specta::Builder<tauri::Wry> {
     tauri_specta::Builder::<tauri::Wry>::new()
         .plugin_name(PLUGIN_NAME)
         .events(tauri_specta::collect_events![])
         .commands(tauri_specta::collect_commands![
-            commands::todo::<tauri::Wry>,
+            commands::list_endpoints::<tauri::Wry>,
+            commands::add_endpoint::<tauri::Wry>,
+            commands::update_endpoint::<tauri::Wry>,
+            commands::remove_endpoint::<tauri::Wry>,
+            commands::dispatch_event::<tauri::Wry>,
+            commands::send_test_event::<tauri::Wry>,
+            commands::list_pending_deliveries::<tauri::Wry>,
+            commands::list_dead_letters::<tauri::Wry>,
+            commands::retry_dead_letter::<tauri::Wry>,
+            commands::clear_dead_letters::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -32,9 +53,13 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
         .setup(move |app, _api| {
             specta_builder.mount_events(app);
 
-            {
-                app.manage(State::default());
-            }
+            let base = app.settings().default_base()?;
+            let state = Arc::new(WebhookState::load(
+                base.join(STORE_FILENAME),
+                base.join(PAYLOAD_DIRNAME),
+            )?);
+            app.manage(state.clone());
+            tauri::async_runtime::spawn(async move { state.run_worker().await });
 
             Ok(())
         })
@@ -5,7 +5,7 @@ use utoipa::{
 };
 
 // Core webhook event structure
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, specta::Type)]
 pub struct WebhookEvent {
     /// Unique event identifier
     #[schema(example = "evt_01234567890")]
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::openapi::WebhookEvent;
+
+/// Event payloads, one JSON file per event. Kept out of [`crate::WebhookStore`]
+/// so recording an attempt never rewrites note and transcript content.
+#[derive(Debug, Clone)]
+pub struct PayloadStore {
+    dir: PathBuf,
+}
+
+impl PayloadStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, event_id: &str) -> PathBuf {
+        self.dir.join(format!("{event_id}.json"))
+    }
+
+    pub fn save(&self, event: &WebhookEvent) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.path(&event.id);
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec(event)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    pub fn load(&self, event_id: &str) -> Result<WebhookEvent> {
+        let content = std::fs::read(self.path(event_id))?;
+        Ok(serde_json::from_slice(&content)?)
+    }
+
+    pub fn remove(&self, event_ids: &[String]) {
+        for event_id in event_ids {
+            match std::fs::remove_file(self.path(event_id)) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => tracing::warn!("webhook_payload_remove_failed: {}", e),
+            }
+        }
+    }
+
+    /// Removes every payload not in `keep`, such as ones left behind when the
+    /// app exited between writing a payload and queueing its deliveries.
+    pub fn prune(&self, keep: &HashSet<String>) -> Result<()> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let orphaned: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| event_id(&entry.path()))
+            .filter(|id| !keep.contains(id))
+            .collect();
+        self.remove(&orphaned);
+        Ok(())
+    }
+}
+
+fn event_id(path: &Path) -> Option<String> {
+    if path.extension()? != "json" {
+        return None;
+    }
+    Some(path.file_stem()?.to_str()?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: &str) -> WebhookEvent {
+        WebhookEvent {
+            id: id.to_string(),
+            event_type: "transcript.finalized".to_string(),
+            timestamp: "2024-01-10T10:30:00Z".to_string(),
+            data: serde_json::json!({ "note_markdown": "# Summary" }),
+        }
+    }
+
+    #[test]
+    fn prunes_unreferenced_payloads() {
+        let dir = tempfile::tempdir().unwrap();
+        let payloads = PayloadStore::new(dir.path().join("payloads"));
+        payloads.prune(&HashSet::new()).unwrap();
+
+        payloads.save(&event("evt_a")).unwrap();
+        payloads.save(&event("evt_b")).unwrap();
+        assert_eq!(payloads.load("evt_a").unwrap(), event("evt_a"));
+
+        payloads
+            .prune(&HashSet::from(["evt_b".to_string()]))
+            .unwrap();
+        assert!(payloads.load("evt_a").is_err());
+        assert_eq!(payloads.load("evt_b").unwrap(), event("evt_b"));
+    }
+}
@@ -0,0 +1,355 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::openapi::WebhookEvent;
+use crate::types::{
+    DeliveryAttempt, WebhookDelivery, WebhookEndpoint, WebhookEndpointUpdate, WebhookEventType,
+};
+use crate::{Error, Result};
+
+/// Deliveries that still fail after this many attempts move to the dead-letter list.
+pub const MAX_ATTEMPTS: u32 = 8;
+/// Dead letters beyond this are dropped, oldest first.
+pub const MAX_DEAD_LETTERS: usize = 200;
+const BASE_BACKOFF: Duration = Duration::from_secs(30);
+const MAX_BACKOFF: Duration = Duration::from_secs(60 * 60);
+
+/// Delay before the next attempt after `attempts` failures.
+pub fn backoff(attempts: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+    BASE_BACKOFF.saturating_mul(factor).min(MAX_BACKOFF)
+}
+
+/// Endpoints and the delivery queue, persisted together as one JSON file so a
+/// queued delivery never outlives its endpoint across restarts. Payloads are
+/// stored separately, so the file stays small however large the events are.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct WebhookStore {
+    #[serde(default)]
+    pub endpoints: Vec<WebhookEndpoint>,
+    #[serde(default)]
+    pub pending: Vec<WebhookDelivery>,
+    #[serde(default)]
+    pub dead_letters: Vec<WebhookDelivery>,
+}
+
+impl WebhookStore {
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        write(path, &serde_json::to_vec_pretty(self)?)
+    }
+
+    /// Events still referenced by a pending delivery or a dead letter.
+    pub fn event_ids(&self) -> HashSet<String> {
+        self.pending
+            .iter()
+            .chain(&self.dead_letters)
+            .map(|d| d.event_id.clone())
+            .collect()
+    }
+
+    pub fn has_subscribers(&self, event_type: WebhookEventType) -> bool {
+        self.endpoints
+            .iter()
+            .any(|e| e.enabled && e.events.contains(&event_type))
+    }
+
+    pub fn endpoint(&self, id: &str) -> Result<&WebhookEndpoint> {
+        self.endpoints
+            .iter()
+            .find(|e| e.id == id)
+            .ok_or_else(|| Error::EndpointNotFound(id.to_string()))
+    }
+
+    pub fn add_endpoint(
+        &mut self,
+        url: String,
+        events: Vec<WebhookEventType>,
+    ) -> Result<WebhookEndpoint> {
+        validate_url(&url)?;
+
+        let endpoint = WebhookEndpoint {
+            id: uuid::Uuid::new_v4().to_string(),
+            url,
+            events,
+            secret: crate::signing::generate_secret(),
+            enabled: true,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+        self.endpoints.push(endpoint.clone());
+        Ok(endpoint)
+    }
+
+    pub fn update_endpoint(
+        &mut self,
+        id: &str,
+        update: WebhookEndpointUpdate,
+    ) -> Result<WebhookEndpoint> {
+        if let Some(url) = &update.url {
+            validate_url(url)?;
+        }
+
+        let endpoint = self
+            .endpoints
+            .iter_mut()
+            .find(|e| e.id == id)
+            .ok_or_else(|| Error::EndpointNotFound(id.to_string()))?;
+        if let Some(url) = update.url {
+            endpoint.url = url;
+        }
+        if let Some(events) = update.events {
+            endpoint.events = events;
+        }
+        if let Some(enabled) = update.enabled {
+            endpoint.enabled = enabled;
+        }
+        Ok(endpoint.clone())
+    }
+
+    /// Removes the endpoint along with everything still queued for it.
+    pub fn remove_endpoint(&mut self, id: &str) -> Result<()> {
+        let before = self.endpoints.len();
+        self.endpoints.retain(|e| e.id != id);
+        if self.endpoints.len() == before {
+            return Err(Error::EndpointNotFound(id.to_string()));
+        }
+
+        self.pending.retain(|d| d.endpoint_id != id);
+        self.dead_letters.retain(|d| d.endpoint_id != id);
+        Ok(())
+    }
+
+    /// Queues `event` for every enabled endpoint subscribed to its type and
+    /// returns how many deliveries were queued.
+    pub fn enqueue(&mut self, event_type: WebhookEventType, event: &WebhookEvent) -> usize {
+        let deliveries: Vec<_> = self
+            .endpoints
+            .iter()
+            .filter(|e| e.enabled && e.events.contains(&event_type))
+            .map(|e| WebhookDelivery {
+                id: uuid::Uuid::new_v4().to_string(),
+                endpoint_id: e.id.clone(),
+                event_id: event.id.clone(),
+                event_type: event.event_type.clone(),
+                attempts: 0,
+                next_attempt_at_ms: 0,
+                last_status: None,
+                last_error: None,
+            })
+            .collect();
+
+        let count = deliveries.len();
+        self.pending.extend(deliveries);
+        count
+    }
+
+    /// Pending deliveries whose next attempt is due, paired with their endpoint.
+    pub fn due(&self, now_ms: u64) -> Vec<(WebhookDelivery, WebhookEndpoint)> {
+        self.pending
+            .iter()
+            .filter(|d| d.next_attempt_at_ms <= now_ms)
+            .filter_map(|d| {
+                let endpoint = self.endpoint(&d.endpoint_id).ok()?;
+                endpoint.enabled.then(|| (d.clone(), endpoint.clone()))
+            })
+            .collect()
+    }
+
+    /// Applies the outcome of an attempt: drops the delivery on success,
+    /// otherwise schedules a retry or moves it to the dead-letter list.
+    pub fn record_attempt(&mut self, delivery_id: &str, attempt: &DeliveryAttempt, now_ms: u64) {
+        let Some(index) = self.pending.iter().position(|d| d.id == delivery_id) else {
+            return;
+        };
+
+        if attempt.succeeded() {
+            self.pending.remove(index);
+            return;
+        }
+
+        let delivery = &mut self.pending[index];
+        delivery.attempts += 1;
+        delivery.last_status = attempt.status;
+        delivery.last_error = attempt.error.clone();
+        delivery.next_attempt_at_ms = now_ms + backoff(delivery.attempts).as_millis() as u64;
+
+        if delivery.attempts >= MAX_ATTEMPTS {
+            let delivery = self.pending.remove(index);
+            self.dead_letters.push(delivery);
+            if self.dead_letters.len() > MAX_DEAD_LETTERS {
+                self.dead_letters
+                    .drain(..self.dead_letters.len() - MAX_DEAD_LETTERS);
+            }
+        }
+    }
+
+    /// Moves a dead letter back to the queue with a fresh attempt budget.
+    pub fn retry_dead_letter(&mut self, delivery_id: &str) -> Result<()> {
+        let index = self
+            .dead_letters
+            .iter()
+            .position(|d| d.id == delivery_id)
+            .ok_or_else(|| Error::DeliveryNotFound(delivery_id.to_string()))?;
+
+        let mut delivery = self.dead_letters.remove(index);
+        delivery.attempts = 0;
+        delivery.next_attempt_at_ms = 0;
+        self.pending.push(delivery);
+        Ok(())
+    }
+}
+
+/// Writes `content` to `path` through a temporary file, so a crash mid-write
+/// never leaves a truncated store behind.
+pub(crate) fn write(path: &Path, content: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+fn validate_url(url: &str) -> Result<()> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => Ok(()),
+        _ => Err(Error::InvalidUrl(url.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> WebhookEvent {
+        WebhookEvent {
+            id: "evt_1".to_string(),
+            event_type: "recording.stopped".to_string(),
+            timestamp: "2024-01-10T10:30:00Z".to_string(),
+            data: serde_json::json!({}),
+        }
+    }
+
+    fn failure() -> DeliveryAttempt {
+        DeliveryAttempt {
+            status: Some(500),
+            error: Some("HTTP 500".to_string()),
+            duration_ms: 1,
+        }
+    }
+
+    #[test]
+    fn backoff_grows_and_caps() {
+        assert_eq!(backoff(1), Duration::from_secs(30));
+        assert_eq!(backoff(2), Duration::from_secs(60));
+        assert_eq!(backoff(4), Duration::from_secs(240));
+        assert_eq!(backoff(20), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn enqueues_for_subscribed_endpoints() {
+        let mut store = WebhookStore::default();
+        let a = store
+            .add_endpoint(
+                "https://a.example.com/hook".to_string(),
+                vec![WebhookEventType::RecordingStopped],
+            )
+            .unwrap();
+        store
+            .add_endpoint(
+                "https://b.example.com/hook".to_string(),
+                vec![WebhookEventType::SummaryGenerated],
+            )
+            .unwrap();
+
+        assert_eq!(
+            store.enqueue(WebhookEventType::RecordingStopped, &event()),
+            1
+        );
+        assert_eq!(store.due(0)[0].1.id, a.id);
+
+        store
+            .update_endpoint(
+                &a.id,
+                WebhookEndpointUpdate {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!(store.due(0).is_empty());
+        assert_eq!(
+            store.enqueue(WebhookEventType::RecordingStopped, &event()),
+            0
+        );
+
+        assert!(!store.has_subscribers(WebhookEventType::RecordingStopped));
+        assert_eq!(store.event_ids(), HashSet::from(["evt_1".to_string()]));
+
+        store.remove_endpoint(&a.id).unwrap();
+        assert!(store.pending.is_empty());
+        assert!(store.event_ids().is_empty());
+    }
+
+    #[test]
+    fn rejects_non_http_urls() {
+        let mut store = WebhookStore::default();
+        assert!(matches!(
+            store.add_endpoint("file:///etc/passwd".to_string(), vec![]),
+            Err(Error::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn retries_then_dead_letters() {
+        let mut store = WebhookStore::default();
+        store
+            .add_endpoint(
+                "http://127.0.0.1:9/hook".to_string(),
+                vec![WebhookEventType::RecordingStopped],
+            )
+            .unwrap();
+        store.enqueue(WebhookEventType::RecordingStopped, &event());
+        let id = store.pending[0].id.clone();
+
+        store.record_attempt(&id, &failure(), 1_000);
+        assert_eq!(store.pending[0].attempts, 1);
+        assert_eq!(store.pending[0].next_attempt_at_ms, 31_000);
+        assert!(store.due(30_999).is_empty());
+        assert_eq!(store.due(31_000).len(), 1);
+
+        for _ in 1..MAX_ATTEMPTS {
+            store.record_attempt(&id, &failure(), 1_000);
+        }
+        assert!(store.pending.is_empty());
+        assert_eq!(store.dead_letters[0].attempts, MAX_ATTEMPTS);
+        assert_eq!(store.dead_letters[0].last_status, Some(500));
+
+        store.retry_dead_letter(&id).unwrap();
+        assert_eq!(store.pending[0].attempts, 0);
+        assert!(store.dead_letters.is_empty());
+    }
+
+    #[test]
+    fn persists_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("webhooks.json");
+        assert_eq!(WebhookStore::load(&path).unwrap(), WebhookStore::default());
+
+        let mut store = WebhookStore::default();
+        store
+            .add_endpoint("https://a.example.com".to_string(), vec![])
+            .unwrap();
+        store.save(&path).unwrap();
+        assert_eq!(WebhookStore::load(&path).unwrap(), store);
+    }
+}
@@ -0,0 +1,109 @@
+/// Events an endpoint can subscribe to. Serialized as the `event_type` of the
+/// delivered payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum WebhookEventType {
+    #[serde(rename = "recording.stopped")]
+    RecordingStopped,
+    #[serde(rename = "transcript.finalized")]
+    TranscriptFinalized,
+    #[serde(rename = "summary.generated")]
+    SummaryGenerated,
+    #[serde(rename = "webhook.test")]
+    Test,
+}
+
+impl WebhookEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::RecordingStopped => "recording.stopped",
+            Self::TranscriptFinalized => "transcript.finalized",
+            Self::SummaryGenerated => "summary.generated",
+            Self::Test => "webhook.test",
+        }
+    }
+
+    /// The webhook event a hook event is delivered as, if any.
+    pub fn from_hook_event(event: &hypr_hooks::HookEvent) -> Option<Self> {
+        use hypr_hooks::HookEvent;
+
+        match event {
+            HookEvent::AfterListeningStopped { .. } => Some(Self::RecordingStopped),
+            HookEvent::TranscriptFinalized { .. } => Some(Self::TranscriptFinalized),
+            HookEvent::EnhancedNoteGenerated { .. } => Some(Self::SummaryGenerated),
+            _ => None,
+        }
+    }
+}
+
+/// Session content delivered alongside the hook args, so receivers do not
+/// need access to the vault to read the note or transcript.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct WebhookContent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note_markdown: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transcript: Option<Vec<WebhookTranscriptSegment>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct WebhookTranscriptSegment {
+    pub speaker: Option<String>,
+    pub text: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct WebhookEndpoint {
+    pub id: String,
+    pub url: String,
+    pub events: Vec<WebhookEventType>,
+    pub secret: String,
+    pub enabled: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct WebhookEndpointUpdate {
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub events: Option<Vec<WebhookEventType>>,
+    #[serde(default)]
+    pub enabled: Option<bool>,
+}
+
+/// One event queued for one endpoint. The event payload lives in the
+/// [`crate::PayloadStore`] and is loaded when the delivery is attempted.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub endpoint_id: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub attempts: u32,
+    pub next_attempt_at_ms: u64,
+    pub last_status: Option<u16>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct DeliveryAttempt {
+    pub status: Option<u16>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl DeliveryAttempt {
+    pub fn failed(error: String) -> Self {
+        Self {
+            status: None,
+            error: Some(error),
+            duration_ms: 0,
+        }
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
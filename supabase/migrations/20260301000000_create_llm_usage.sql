@@ -0,0 +1,88 @@
+CREATE TABLE public.llm_usage (
+  id bigint GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  user_id uuid NOT NULL,
+  task text,
+  model text NOT NULL,
+  provider text NOT NULL,
+  input_tokens integer NOT NULL DEFAULT 0,
+  output_tokens integer NOT NULL DEFAULT 0,
+  cost_usd numeric(12, 6) NOT NULL DEFAULT 0,
+  created_at timestamptz NOT NULL DEFAULT now(),
+
+  CONSTRAINT llm_usage_user_id_fk
+    FOREIGN KEY (user_id) REFERENCES auth.users(id) ON DELETE CASCADE
+);
+
+ALTER TABLE public.llm_usage ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY "llm_usage_select_owner"
+  ON public.llm_usage AS PERMISSIVE
+  FOR SELECT TO authenticated
+  USING ((SELECT auth.uid()) = user_id);
+
+CREATE POLICY "llm_usage_service_all"
+  ON public.llm_usage AS PERMISSIVE
+  FOR ALL TO service_role
+  USING (true) WITH CHECK (true);
+
+CREATE INDEX llm_usage_user_id_created_at_idx
+  ON public.llm_usage (user_id, created_at);
+
+CREATE INDEX llm_usage_created_at_idx
+  ON public.llm_usage (created_at);
+
+CREATE OR REPLACE FUNCTION public.llm_usage_totals(p_user_id uuid, p_since timestamptz)
+RETURNS TABLE (
+  requests bigint,
+  input_tokens bigint,
+  output_tokens bigint,
+  cost_usd double precision
+)
+LANGUAGE sql
+STABLE
+AS $$
+  SELECT
+    count(*),
+    coalesce(sum(u.input_tokens), 0)::bigint,
+    coalesce(sum(u.output_tokens), 0)::bigint,
+    coalesce(sum(u.cost_usd), 0)::double precision
+  FROM public.llm_usage u
+  WHERE u.user_id = p_user_id
+    AND u.created_at >= p_since;
+$$;
+
+CREATE OR REPLACE FUNCTION public.llm_usage_summary(
+  p_since timestamptz,
+  p_until timestamptz,
+  p_user_id uuid DEFAULT NULL
+)
+RETURNS TABLE (
+  user_id uuid,
+  task text,
+  model text,
+  requests bigint,
+  input_tokens bigint,
+  output_tokens bigint,
+  cost_usd double precision
+)
+LANGUAGE sql
+STABLE
+AS $$
+  SELECT
+    u.user_id,
+    u.task,
+    u.model,
+    count(*),
+    sum(u.input_tokens)::bigint,
+    sum(u.output_tokens)::bigint,
+    sum(u.cost_usd)::double precision
+  FROM public.llm_usage u
+  WHERE u.created_at >= p_since
+    AND u.created_at < p_until
+    AND (p_user_id IS NULL OR u.user_id = p_user_id)
+  GROUP BY u.user_id, u.task, u.model
+  ORDER BY u.user_id, u.task, u.model;
+$$;
+
+REVOKE EXECUTE ON FUNCTION public.llm_usage_totals(uuid, timestamptz) FROM PUBLIC, anon, authenticated;
+REVOKE EXECUTE ON FUNCTION public.llm_usage_summary(timestamptz, timestamptz, uuid) FROM PUBLIC, anon, authenticated;